@@ -129,6 +129,42 @@ impl ConnectAuth {
     pub fn with_token(self, token: String) -> Self {
         ConnectAuth::Token(token)
     }
+
+    /// Whether `given` credentials satisfy this auth mode
+    ///
+    /// [`ConnectAuth::None`] accepts anything. Secrets are compared in
+    /// constant time so response timing does not leak how much of a guess
+    /// was right.
+    pub fn accepts(&self, given: &ConnectAuth) -> bool {
+        match (self, given) {
+            (ConnectAuth::None, _) => true,
+            (ConnectAuth::Secret(expected), ConnectAuth::Secret(given))
+            | (ConnectAuth::Token(expected), ConnectAuth::Token(given)) => {
+                constant_time_eq(expected.as_bytes(), given.as_bytes())
+            }
+            (
+                ConnectAuth::UserPass { username, password },
+                ConnectAuth::UserPass {
+                    username: given_user,
+                    password: given_pass,
+                },
+            ) => {
+                // Evaluate both so a wrong username takes as long as a wrong password
+                let user_ok = constant_time_eq(username.as_bytes(), given_user.as_bytes());
+                let pass_ok = constant_time_eq(password.as_bytes(), given_pass.as_bytes());
+                user_ok & pass_ok
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
 }
 
 /// Token storage for caching
@@ -183,6 +219,17 @@ impl CachedToken {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_accepts_matching_credentials_only() {
+        let secret = ConnectAuth::Secret("admin-key".to_string());
+        assert!(secret.accepts(&ConnectAuth::Secret("admin-key".to_string())));
+        assert!(!secret.accepts(&ConnectAuth::Secret("admin-kez".to_string())));
+        assert!(!secret.accepts(&ConnectAuth::Secret("admin".to_string())));
+        assert!(!secret.accepts(&ConnectAuth::Token("admin-key".to_string())));
+        assert!(!secret.accepts(&ConnectAuth::None));
+        assert!(ConnectAuth::None.accepts(&ConnectAuth::None));
+    }
+
     #[test]
     fn test_parse_connect_none() {
         let auth = ConnectAuth::from_connect_string("none").unwrap();
@@ -1,8 +1,13 @@
 //! Configuration loader with dual-location support
+//!
+//! Each location is read from `.aco/aco.toml`, or from the TOON config
+//! written by workspace initialization (`.aco/config.toon`) when there is
+//! no TOML file next to it.
 
 use crate::config::schema::AcoConfig;
+use crate::workspace::{parse_toon_config, ConfigFormat};
 use crate::{AcoError, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 /// Configuration loader that handles both user and project configs
@@ -55,7 +60,15 @@ impl ConfigLoader {
     }
 
     /// Load configuration from a specific path
-    async fn load_from_path(&self, path: &PathBuf) -> Result<AcoConfig> {
+    ///
+    /// Falls back to the sibling `config.toon` when `path` does not exist.
+    async fn load_from_path(&self, path: &Path) -> Result<AcoConfig> {
+        let toon_path = path.with_file_name(ConfigFormat::Toon.file_name());
+        if !path.exists() && toon_path.exists() {
+            let content = fs::read_to_string(&toon_path).await?;
+            return parse_toon_config(&content);
+        }
+
         if !path.exists() {
             return Err(AcoError::Config(format!(
                 "Config file not found: {}",
@@ -110,6 +123,28 @@ mod tests {
         assert!(project_path.ends_with(".aco/aco.toml"));
     }
 
+    #[tokio::test]
+    async fn test_load_reads_generated_toon_config() {
+        use crate::workspace::{WorkspaceInitConfig, WorkspaceInitializer};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        WorkspaceInitializer::new(WorkspaceInitConfig::new(temp_dir.path()))
+            .init()
+            .unwrap();
+
+        // Edit the generated file the way a user would
+        let toon_path = temp_dir.path().join(".aco").join("config.toon");
+        let content = std::fs::read_to_string(&toon_path).unwrap();
+        std::fs::write(&toon_path, content.replace("port: 8080", "port: 9090")).unwrap();
+
+        let loader = ConfigLoader::new();
+        let config = loader
+            .load_from_path(&temp_dir.path().join(".aco").join("aco.toml"))
+            .await
+            .unwrap();
+        assert_eq!(config.server.port, 9090);
+    }
+
     #[tokio::test]
     async fn test_load_returns_defaults_when_no_files() {
         let loader = ConfigLoader::new();
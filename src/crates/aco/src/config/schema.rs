@@ -37,6 +37,13 @@ pub struct ServerConfig {
 
     /// Enable TLS
     pub enable_tls: bool,
+
+    /// Credentials required for admin commands, as a connect string
+    /// (`secret:<key>`, `token:<jwt>`, `<user>:<pass>` or `none`)
+    ///
+    /// Admin commands are rejected while this is unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_auth: Option<String>,
 }
 
 impl Default for ServerConfig {
@@ -46,6 +53,7 @@ impl Default for ServerConfig {
             port: 8080,
             ws_path: "/ws".to_string(),
             enable_tls: false,
+            admin_auth: None,
         }
     }
 }
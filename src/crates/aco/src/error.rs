@@ -13,6 +13,10 @@ pub enum AcoError {
     #[error("Tool execution error: {0}")]
     ToolExecution(String),
 
+    /// Tool not registered, or disabled
+    #[error("Tool not found: {0}")]
+    ToolNotFound(String),
+
     /// Session error
     #[error("Session error: {0}")]
     Session(String),
@@ -17,7 +17,7 @@ pub mod workspace;
 pub use auth::{CachedToken, ConnectAuth};
 pub use config::{AcoConfig, ConfigLoader};
 pub use error::{AcoError, Result};
-pub use server::AcoServer;
+pub use server::{AcoServer, AdminCommand, AdminResponse, ToolHandle, ToolInfo, ToolRegistry};
 pub use session::SessionManager;
 pub use tui::{App, AppState, View, TuiConfig};
 pub use workspace::{
@@ -1,6 +1,6 @@
 //! aco client application - CLI entry point
 
-use aco::{AcoConfig, AcoServer, ConfigLoader, Result, TuiConfig};
+use aco::{AcoConfig, AcoServer, ConfigLoader, ConnectAuth, Result, TuiConfig};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -134,7 +134,7 @@ async fn main() -> Result<()> {
                 aco::tui::run(tui_config).await?;
             } else {
                 // Run server mode
-                let server = AcoServer::new().with_address(&server_address);
+                let server = build_server(&config, &server_address)?;
 
                 // Register all tools
                 register_tools(&server).await?;
@@ -150,7 +150,7 @@ async fn main() -> Result<()> {
             info!("Workspace: {}", workspace.display());
             info!("Address: {}", address);
 
-            let server = AcoServer::new().with_address(&address);
+            let server = build_server(&config, &address)?;
             register_tools(&server).await?;
             server.start().await?;
         }
@@ -193,26 +193,44 @@ async fn show_config(config: &AcoConfig) -> Result<()> {
     Ok(())
 }
 
+/// Create the server, enabling admin commands if credentials are configured
+///
+/// `ACO_ADMIN_AUTH` overrides `server.admin_auth` from the config file.
+fn build_server(config: &AcoConfig, address: &str) -> Result<AcoServer> {
+    let server = AcoServer::new().with_address(address);
+    let admin_auth = std::env::var("ACO_ADMIN_AUTH")
+        .ok()
+        .or_else(|| config.server.admin_auth.clone());
+
+    match admin_auth {
+        Some(auth) => Ok(server.with_admin_auth(ConnectAuth::from_connect_string(&auth)?)),
+        None => {
+            info!("No admin auth configured; admin commands are disabled");
+            Ok(server)
+        }
+    }
+}
+
 /// Register all available tools
 async fn register_tools(server: &AcoServer) -> Result<()> {
     // Filesystem tools
-    server.register_tool(Arc::new(FileReadTool)).await;
-    server.register_tool(Arc::new(FileWriteTool)).await;
-    server.register_tool(Arc::new(FsListTool)).await;
-    server.register_tool(Arc::new(FsCopyTool)).await;
-    server.register_tool(Arc::new(FsMoveTool)).await;
-    server.register_tool(Arc::new(FsDeleteTool)).await;
-    server.register_tool(Arc::new(FilePatchTool)).await;
-    server.register_tool(Arc::new(GrepTool)).await;
+    server.register_tool("file_read", Arc::new(FileReadTool)).await;
+    server.register_tool("file_write", Arc::new(FileWriteTool)).await;
+    server.register_tool("fs_list", Arc::new(FsListTool)).await;
+    server.register_tool("fs_copy", Arc::new(FsCopyTool)).await;
+    server.register_tool("fs_move", Arc::new(FsMoveTool)).await;
+    server.register_tool("fs_delete", Arc::new(FsDeleteTool)).await;
+    server.register_tool("file_patch", Arc::new(FilePatchTool)).await;
+    server.register_tool("grep", Arc::new(GrepTool)).await;
 
     // Git tools
-    server.register_tool(Arc::new(GitStatusTool)).await;
-    server.register_tool(Arc::new(GitDiffTool)).await;
-    server.register_tool(Arc::new(GitAddTool)).await;
-    server.register_tool(Arc::new(GitCommitTool)).await;
+    server.register_tool("git_status", Arc::new(GitStatusTool)).await;
+    server.register_tool("git_diff", Arc::new(GitDiffTool)).await;
+    server.register_tool("git_add", Arc::new(GitAddTool)).await;
+    server.register_tool("git_commit", Arc::new(GitCommitTool)).await;
 
     // Shell tools
-    server.register_tool(Arc::new(ShellExecTool)).await;
+    server.register_tool("shell_exec", Arc::new(ShellExecTool)).await;
 
     info!("Registered all tools");
 
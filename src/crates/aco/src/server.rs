@@ -1,15 +1,137 @@
-//! Server module (stub for future server implementation)
+//! Server module
+//!
+//! Holds the tool registry shared with [`SessionManager`]. Tools can be
+//! registered, unregistered and toggled while the server is running; sessions
+//! look tools up on every call, so changes apply without a restart.
 
+use crate::auth::ConnectAuth;
+use crate::error::{AcoError, Result};
+use crate::session::SessionManager;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::info;
+
+/// Handle to a registered tool
+pub type ToolHandle = Arc<dyn Any + Send + Sync>;
+
+/// A registered tool and whether it may currently be called
+#[derive(Clone)]
+struct ToolEntry {
+    tool: ToolHandle,
+    enabled: bool,
+}
+
+/// Summary of a registered tool, as reported by [`AdminCommand::ListTools`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolInfo {
+    /// Tool name
+    pub name: String,
+    /// Whether sessions may call the tool
+    pub enabled: bool,
+}
+
+/// Shared, runtime-mutable set of tools keyed by name
+#[derive(Clone, Default)]
+pub struct ToolRegistry {
+    tools: Arc<RwLock<BTreeMap<String, ToolEntry>>>,
+}
+
+impl ToolRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a tool, enabled, replacing any existing tool with the same name
+    pub async fn insert(&self, name: impl Into<String>, tool: ToolHandle) {
+        self.tools.write().await.insert(
+            name.into(),
+            ToolEntry {
+                tool,
+                enabled: true,
+            },
+        );
+    }
+
+    /// Remove a tool, returning whether it was registered
+    pub async fn remove(&self, name: &str) -> bool {
+        self.tools.write().await.remove(name).is_some()
+    }
+
+    /// Enable or disable a tool, returning whether it was registered
+    pub async fn set_enabled(&self, name: &str, enabled: bool) -> bool {
+        match self.tools.write().await.get_mut(name) {
+            Some(entry) => {
+                entry.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Look up an enabled tool
+    pub async fn get(&self, name: &str) -> Option<ToolHandle> {
+        self.tools
+            .read()
+            .await
+            .get(name)
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.tool.clone())
+    }
+
+    /// All registered tools, sorted by name
+    pub async fn list(&self) -> Vec<ToolInfo> {
+        self.tools
+            .read()
+            .await
+            .iter()
+            .map(|(name, entry)| ToolInfo {
+                name: name.clone(),
+                enabled: entry.enabled,
+            })
+            .collect()
+    }
+}
+
+/// Runtime administration commands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum AdminCommand {
+    /// List registered tools and their state
+    ListTools,
+    /// Allow sessions to call a tool again
+    EnableTool { name: String },
+    /// Keep a tool registered but reject calls to it
+    DisableTool { name: String },
+    /// Remove a tool from the registry
+    UnregisterTool { name: String },
+}
+
+/// Result of an [`AdminCommand`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum AdminResponse {
+    /// Registered tools, sorted by name
+    Tools { tools: Vec<ToolInfo> },
+    /// The command was applied
+    Ok,
+}
 
 pub struct AcoServer {
     address: String,
+    tools: ToolRegistry,
+    admin_auth: Option<ConnectAuth>,
 }
 
 impl AcoServer {
     pub fn new() -> Self {
         Self {
             address: "0.0.0.0:50051".to_string(),
+            tools: ToolRegistry::new(),
+            admin_auth: None,
         }
     }
 
@@ -18,8 +140,90 @@ impl AcoServer {
         self
     }
 
-    pub async fn register_tool(&self, _tool: Arc<dyn std::any::Any>) {
-        // Stub implementation - will be implemented in future tasks
+    /// Require these credentials for [`AcoServer::admin`]
+    ///
+    /// Until this is set every admin command is rejected. Passing
+    /// [`ConnectAuth::None`] explicitly opens admin commands to any caller.
+    pub fn with_admin_auth(mut self, auth: ConnectAuth) -> Self {
+        self.admin_auth = Some(auth);
+        self
+    }
+
+    /// Register a tool under `name`, replacing any existing one
+    pub async fn register_tool(&self, name: impl Into<String>, tool: ToolHandle) {
+        self.tools.insert(name, tool).await;
+    }
+
+    /// Remove a tool; sessions fail to call it from their next call on
+    pub async fn unregister_tool(&self, name: &str) -> Result<()> {
+        if self.tools.remove(name).await {
+            info!("Unregistered tool: {}", name);
+            Ok(())
+        } else {
+            Err(AcoError::ToolNotFound(name.to_string()))
+        }
+    }
+
+    /// Registered tools, sorted by name
+    pub async fn list_tools(&self) -> Vec<ToolInfo> {
+        self.tools.list().await
+    }
+
+    /// Session manager sharing this server's tool registry
+    pub fn session_manager(&self) -> SessionManager {
+        SessionManager::with_tools(self.tools.clone())
+    }
+
+    /// Run an admin command after checking `credentials` against the
+    /// configured admin auth
+    pub async fn admin(
+        &self,
+        credentials: &ConnectAuth,
+        command: AdminCommand,
+    ) -> Result<AdminResponse> {
+        self.authorize_admin(credentials)?;
+
+        match command {
+            AdminCommand::ListTools => Ok(AdminResponse::Tools {
+                tools: self.list_tools().await,
+            }),
+            AdminCommand::EnableTool { name } => self.set_tool_enabled(&name, true).await,
+            AdminCommand::DisableTool { name } => self.set_tool_enabled(&name, false).await,
+            AdminCommand::UnregisterTool { name } => {
+                self.unregister_tool(&name).await?;
+                Ok(AdminResponse::Ok)
+            }
+        }
+    }
+
+    async fn set_tool_enabled(&self, name: &str, enabled: bool) -> Result<AdminResponse> {
+        if self.tools.set_enabled(name, enabled).await {
+            info!(
+                "Tool {} {}",
+                name,
+                if enabled { "enabled" } else { "disabled" }
+            );
+            Ok(AdminResponse::Ok)
+        } else {
+            Err(AcoError::ToolNotFound(name.to_string()))
+        }
+    }
+
+    fn authorize_admin(&self, credentials: &ConnectAuth) -> Result<()> {
+        let Some(admin_auth) = &self.admin_auth else {
+            return Err(AcoError::Auth(
+                "Admin commands are disabled; configure server.admin_auth".to_string(),
+            ));
+        };
+
+        if admin_auth.accepts(credentials) {
+            Ok(())
+        } else {
+            Err(AcoError::Auth(format!(
+                "Admin command rejected for {}",
+                credentials
+            )))
+        }
     }
 
     pub async fn start(&self) -> crate::error::Result<()> {
@@ -27,3 +231,106 @@ impl AcoServer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tooling::runtime::ToolRequest;
+
+    struct EchoTool;
+
+    #[tokio::test]
+    async fn test_register_and_unregister_tool() {
+        let server = AcoServer::new();
+        server.register_tool("echo", Arc::new(EchoTool)).await;
+        assert_eq!(
+            server.list_tools().await,
+            vec![ToolInfo {
+                name: "echo".to_string(),
+                enabled: true
+            }]
+        );
+
+        server.unregister_tool("echo").await.unwrap();
+        assert!(server.list_tools().await.is_empty());
+        assert!(matches!(
+            server.unregister_tool("echo").await,
+            Err(AcoError::ToolNotFound(name)) if name == "echo"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_session_sees_unregistered_tool_as_not_found() {
+        let server = AcoServer::new();
+        server.register_tool("echo", Arc::new(EchoTool)).await;
+        let sessions = server.session_manager();
+
+        let tool = sessions
+            .resolve_tool(&ToolRequest::new("echo"))
+            .await
+            .unwrap();
+        assert!(tool.downcast_ref::<EchoTool>().is_some());
+
+        server.unregister_tool("echo").await.unwrap();
+        assert!(matches!(
+            sessions.resolve_tool(&ToolRequest::new("echo")).await,
+            Err(AcoError::ToolNotFound(name)) if name == "echo"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_rejected_until_auth_configured() {
+        let server = AcoServer::new();
+        server.register_tool("echo", Arc::new(EchoTool)).await;
+
+        let result = server.admin(&ConnectAuth::None, AdminCommand::ListTools).await;
+        assert!(matches!(result, Err(AcoError::Auth(_))));
+    }
+
+    #[tokio::test]
+    async fn test_admin_toggle_requires_matching_credentials() {
+        let server = AcoServer::new().with_admin_auth(ConnectAuth::Secret("admin-key".to_string()));
+        server.register_tool("echo", Arc::new(EchoTool)).await;
+        let sessions = server.session_manager();
+        let disable = AdminCommand::DisableTool {
+            name: "echo".to_string(),
+        };
+
+        let rejected = server
+            .admin(&ConnectAuth::Secret("wrong".to_string()), disable.clone())
+            .await;
+        assert!(matches!(rejected, Err(AcoError::Auth(_))));
+        assert!(sessions
+            .resolve_tool(&ToolRequest::new("echo"))
+            .await
+            .is_ok());
+
+        let admin = ConnectAuth::Secret("admin-key".to_string());
+        assert_eq!(
+            server.admin(&admin, disable).await.unwrap(),
+            AdminResponse::Ok
+        );
+        assert!(matches!(
+            sessions.resolve_tool(&ToolRequest::new("echo")).await,
+            Err(AcoError::ToolNotFound(_))
+        ));
+        assert_eq!(
+            server.admin(&admin, AdminCommand::ListTools).await.unwrap(),
+            AdminResponse::Tools {
+                tools: vec![ToolInfo {
+                    name: "echo".to_string(),
+                    enabled: false
+                }]
+            }
+        );
+
+        let enable = AdminCommand::EnableTool {
+            name: "echo".to_string(),
+        };
+        server.admin(&admin, enable).await.unwrap();
+        assert!(sessions
+            .resolve_tool(&ToolRequest::new("echo"))
+            .await
+            .is_ok());
+    }
+}
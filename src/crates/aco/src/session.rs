@@ -1,9 +1,213 @@
-//! Session management module (stub for future implementation)
+//! Session management module
+//!
+//! Sessions resolve tools through the server's [`ToolRegistry`] on every call,
+//! so tools registered, removed or toggled at runtime take effect immediately.
+//! Each session may also be confined to its own workspace root, which
+//! filesystem tool paths are resolved against.
 
-pub struct SessionManager;
+use crate::error::{AcoError, Result};
+use crate::server::{ToolHandle, ToolRegistry};
+use crate::workspace::PathValidator;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tooling::runtime::ToolRequest;
+
+/// Filesystem tools and the arguments holding paths they read or write
+///
+/// [`SessionManager::prepare_call`] resolves these against the session's
+/// workspace before a tool runs.
+pub const FILESYSTEM_TOOL_PATH_ARGS: &[(&str, &[&str])] = &[
+    ("file_read", &["path"]),
+    ("file_write", &["path"]),
+    ("file_patch", &["path"]),
+    ("fs_list", &["path"]),
+    ("fs_copy", &["path", "source", "destination"]),
+    ("fs_move", &["path", "source", "destination"]),
+    ("fs_delete", &["path"]),
+    ("grep", &["path"]),
+];
+
+pub struct SessionManager {
+    tools: ToolRegistry,
+    sandboxes: RwLock<HashMap<String, Arc<PathValidator>>>,
+}
 
 impl SessionManager {
     pub fn new() -> Self {
-        Self
+        Self::with_tools(ToolRegistry::new())
+    }
+
+    /// Create a session manager backed by a shared tool registry
+    pub fn with_tools(tools: ToolRegistry) -> Self {
+        Self {
+            tools,
+            sandboxes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Resolve the tool a request targets
+    ///
+    /// Fails with [`AcoError::ToolNotFound`] if the tool is not registered or
+    /// has been disabled.
+    pub async fn resolve_tool(&self, request: &ToolRequest) -> Result<ToolHandle> {
+        self.tools
+            .get(&request.tool)
+            .await
+            .ok_or_else(|| AcoError::ToolNotFound(request.tool.clone()))
+    }
+
+    /// Resolve the tool a request targets and confine its paths
+    ///
+    /// This is the entry point for running a tool on behalf of a session.
+    /// For filesystem tools every path argument is replaced by its resolved
+    /// location inside the session's workspace, so the tool never sees a
+    /// path that escapes it. Other tools pass through unchanged.
+    pub async fn prepare_call(&self, request: ToolRequest) -> Result<(ToolHandle, ToolRequest)> {
+        let tool = self.resolve_tool(&request).await?;
+
+        let Some((_, args)) = FILESYSTEM_TOOL_PATH_ARGS
+            .iter()
+            .find(|(name, _)| *name == request.tool)
+        else {
+            return Ok((tool, request));
+        };
+
+        let mut resolved = Vec::new();
+        for arg in args.iter().filter(|arg| request.args.contains_key(**arg)) {
+            let path = self.resolve_path(&request, arg).await?;
+            resolved.push((arg.to_string(), path));
+        }
+        if resolved.is_empty() {
+            return Err(AcoError::ToolExecution(format!(
+                "Tool '{}' requires a path argument",
+                request.tool
+            )));
+        }
+
+        let request = resolved.into_iter().fold(request, |request, (arg, path)| {
+            request.with_arg(arg, serde_json::json!(path.to_string_lossy()))
+        });
+        Ok((tool, request))
+    }
+
+    /// Confine `session_id` to the workspace at `root`
+    pub async fn open_session(
+        &self,
+        session_id: impl Into<String>,
+        root: impl AsRef<Path>,
+    ) -> Result<()> {
+        let validator = PathValidator::for_session(root)?;
+        self.sandboxes
+            .write()
+            .await
+            .insert(session_id.into(), Arc::new(validator));
+        Ok(())
+    }
+
+    /// Drop the workspace confinement of `session_id`
+    pub async fn close_session(&self, session_id: &str) {
+        self.sandboxes.write().await.remove(session_id);
+    }
+
+    /// Resolve the path argument `arg` of a filesystem tool request against
+    /// the requesting session's workspace
+    ///
+    /// Fails if the request has no open session, the argument is missing or
+    /// not a string, or the path escapes the session's root.
+    pub async fn resolve_path(&self, request: &ToolRequest, arg: &str) -> Result<PathBuf> {
+        let session_id = request.session_id.as_deref().ok_or_else(|| {
+            AcoError::Session(format!("Tool '{}' called without a session", request.tool))
+        })?;
+        let validator = self
+            .sandboxes
+            .read()
+            .await
+            .get(session_id)
+            .cloned()
+            .ok_or_else(|| AcoError::Session(format!("Unknown session: {}", session_id)))?;
+
+        let path = request
+            .args
+            .get(arg)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                AcoError::ToolExecution(format!(
+                    "Tool '{}' requires a string '{}' argument",
+                    request.tool, arg
+                ))
+            })?;
+
+        validator.resolve_path(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_sessions_are_confined_to_their_own_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let root_a = temp_dir.path().join("a");
+        let root_b = temp_dir.path().join("b");
+        std::fs::create_dir(&root_a).unwrap();
+        std::fs::create_dir(&root_b).unwrap();
+
+        let sessions = SessionManager::new();
+        sessions.open_session("a", &root_a).await.unwrap();
+        sessions.open_session("b", &root_b).await.unwrap();
+
+        let read_own = ToolRequest::new("file_read")
+            .with_session_id("a")
+            .with_arg("path", serde_json::json!("notes.txt"));
+        assert_eq!(
+            sessions.resolve_path(&read_own, "path").await.unwrap(),
+            root_a.canonicalize().unwrap().join("notes.txt")
+        );
+
+        let other = root_b.join("notes.txt");
+        let read_other = ToolRequest::new("file_read")
+            .with_session_id("a")
+            .with_arg("path", serde_json::json!(other.to_str().unwrap()));
+        assert!(sessions.resolve_path(&read_other, "path").await.is_err());
+
+        sessions.close_session("a").await;
+        assert!(matches!(
+            sessions.resolve_path(&read_own, "path").await,
+            Err(AcoError::Session(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_call_confines_filesystem_tool_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("a");
+        std::fs::create_dir(&root).unwrap();
+
+        let tools = ToolRegistry::new();
+        tools.insert("file_read", Arc::new(())).await;
+        tools.insert("git_status", Arc::new(())).await;
+        let sessions = SessionManager::with_tools(tools);
+        sessions.open_session("a", &root).await.unwrap();
+
+        let read = ToolRequest::new("file_read")
+            .with_session_id("a")
+            .with_arg("path", serde_json::json!("notes.txt"));
+        let (_, prepared) = sessions.prepare_call(read).await.unwrap();
+        let expected = root.canonicalize().unwrap().join("notes.txt");
+        assert_eq!(prepared.args["path"], serde_json::json!(expected.to_string_lossy()));
+
+        let escape = ToolRequest::new("file_read")
+            .with_session_id("a")
+            .with_arg("path", serde_json::json!("../b/notes.txt"));
+        assert!(sessions.prepare_call(escape).await.is_err());
+
+        // Non-filesystem tools are not rewritten
+        let status = ToolRequest::new("git_status").with_arg("path", serde_json::json!("../x"));
+        let (_, prepared) = sessions.prepare_call(status).await.unwrap();
+        assert_eq!(prepared.args["path"], serde_json::json!("../x"));
     }
 }
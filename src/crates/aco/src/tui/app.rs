@@ -2,10 +2,13 @@
 
 use crate::auth::ConnectAuth;
 use crate::error::Result;
+use crate::tui::stream::{self, MAX_EXECUTION_EVENTS};
 use crate::tui::{TuiConfig, TuiGrpcClient};
+use langgraph_core::{EventStream, StreamChunkStream};
 use ratatui::layout::Rect;
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, error::TryRecvError};
 use tracing::debug;
 
 /// Current view being displayed
@@ -116,6 +119,9 @@ pub struct App {
     /// Executing task/workflow ID
     pub executing_id: Option<String>,
 
+    /// Pending lines from an attached execution stream
+    stream_rx: Option<mpsc::Receiver<ExecutionEvent>>,
+
     /// Scroll position for lists
     pub scroll: usize,
 
@@ -205,6 +211,7 @@ impl App {
             workflows: Vec::new(),
             execution_events: Vec::new(),
             executing_id: None,
+            stream_rx: None,
             scroll: 0,
             selected: 0,
         }
@@ -278,49 +285,94 @@ impl App {
         self.state.last_refresh.elapsed() > Duration::from_secs(10)
     }
 
-    /// Start executing a task
+    /// Start executing a task, streaming its progress into the execution view
     pub async fn execute_task(&mut self, task_id: String) -> Result<()> {
         debug!("Starting task execution: {}", task_id);
-        self.executing_id = Some(task_id.clone());
-        self.execution_events.clear();
-        self.set_view(View::ExecutionStream);
-        self.set_status(format!("Executing task: {}", task_id));
-
-        // Start streaming execution events (async)
-        let events = self.grpc_client.execute_task(&task_id).await?;
-        for event in events {
-            self.add_execution_event(event);
-        }
-
+        let chunks = self.grpc_client.execute_task(&task_id).await?;
+        self.attach_stream(task_id, chunks);
         Ok(())
     }
 
-    /// Start executing a workflow
+    /// Start executing a workflow, streaming its events into the execution view
     pub async fn execute_workflow(&mut self, workflow_id: String) -> Result<()> {
         debug!("Starting workflow execution: {}", workflow_id);
-        self.executing_id = Some(workflow_id.clone());
+        let events = self.grpc_client.execute_workflow(&workflow_id).await?;
+        self.attach_event_stream(workflow_id, events);
+        Ok(())
+    }
+
+    /// Add an execution event
+    ///
+    /// Consecutive token chunks from one node are merged into a single line.
+    pub fn add_execution_event(&mut self, event: ExecutionEvent) {
+        stream::append_event(&mut self.execution_events, event, MAX_EXECUTION_EVENTS);
+    }
+
+    /// Show a running graph's stream chunks in the execution view
+    ///
+    /// Chunks are buffered in a bounded channel and applied by
+    /// [`App::drain_stream`]. Must be called from within a tokio runtime.
+    pub fn attach_stream(&mut self, id: impl Into<String>, chunks: StreamChunkStream) {
+        self.start_stream(id.into(), stream::spawn_chunk_forwarder(chunks));
+    }
+
+    /// Show a running graph's execution events in the execution view
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn attach_event_stream(&mut self, id: impl Into<String>, events: EventStream) {
+        self.start_stream(id.into(), stream::spawn_event_forwarder(events));
+    }
+
+    fn start_stream(&mut self, id: String, rx: mpsc::Receiver<ExecutionEvent>) {
+        self.set_status(format!("Streaming: {}", id));
+        self.executing_id = Some(id);
         self.execution_events.clear();
+        self.stream_rx = Some(rx);
         self.set_view(View::ExecutionStream);
-        self.set_status(format!("Executing workflow: {}", workflow_id));
+    }
 
-        // Start streaming execution events (async)
-        let events = self.grpc_client.execute_workflow(&workflow_id).await?;
-        for event in events {
-            self.add_execution_event(event);
+    /// Apply up to `max` pending stream events, returning how many were applied
+    ///
+    /// Never blocks; detaches the stream once it has ended.
+    pub fn drain_stream(&mut self, max: usize) -> usize {
+        let Some(rx) = self.stream_rx.as_mut() else {
+            return 0;
+        };
+
+        let mut pending = Vec::new();
+        let mut finished = false;
+        while pending.len() < max {
+            match rx.try_recv() {
+                Ok(event) => pending.push(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
         }
 
-        Ok(())
+        let applied = pending.len();
+        for event in pending {
+            self.add_execution_event(event);
+        }
+        if finished {
+            self.stream_rx = None;
+            self.set_status("Execution stream finished".to_string());
+        }
+        applied
     }
 
-    /// Add an execution event
-    pub fn add_execution_event(&mut self, event: ExecutionEvent) {
-        self.execution_events.push(event);
+    /// Whether an execution stream is attached
+    pub fn is_streaming(&self) -> bool {
+        self.stream_rx.is_some()
     }
 
     /// Clear execution events
     pub fn clear_execution(&mut self) {
         self.execution_events.clear();
         self.executing_id = None;
+        self.stream_rx = None;
     }
 
     /// Get executing ID
@@ -877,4 +929,75 @@ mod tests {
         app.page_down();
         assert_eq!(app.selected, 0);
     }
+
+    fn progress_line(i: usize) -> ExecutionEvent {
+        ExecutionEvent {
+            timestamp: String::new(),
+            event_type: "progress".to_string(),
+            message: i.to_string(),
+            status: "running".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_drain_stream_applies_at_most_limit_per_tick() {
+        let mut app = App::new(test_config());
+        let (tx, rx) = mpsc::channel(stream::STREAM_BUFFER);
+        for i in 0..100 {
+            tx.try_send(progress_line(i)).unwrap();
+        }
+        app.start_stream("run-1".to_string(), rx);
+
+        assert_eq!(app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK), 64);
+        assert_eq!(app.execution_events.len(), 64);
+        assert_eq!(app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK), 36);
+        assert_eq!(app.execution_events.last().unwrap().message, "99");
+
+        // Nothing pending: stays attached until the sender goes away
+        assert_eq!(app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK), 0);
+        assert!(app.is_streaming());
+        drop(tx);
+        assert_eq!(app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK), 0);
+        assert!(!app.is_streaming());
+    }
+
+    /// Tick the app like the event loop until the attached stream ends
+    async fn run_until_stream_ends(app: &mut App) {
+        while app.is_streaming() {
+            app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK);
+            tokio::time::sleep(Duration::from_millis(16)).await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_workflow_streams_graph_events() {
+        let mut app = App::new(test_config());
+        app.execute_workflow("wf-1".to_string()).await.unwrap();
+
+        assert_eq!(app.view(), View::ExecutionStream);
+        assert_eq!(app.executing_id(), Some("wf-1"));
+        run_until_stream_ends(&mut app).await;
+
+        let messages: Vec<&str> = app
+            .execution_events
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages.first(), Some(&"start_node"));
+        assert_eq!(messages.last(), Some(&"graph finished"));
+        assert_eq!(app.status(), "Execution stream finished");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_task_streams_tokens_into_one_line() {
+        let mut app = App::new(test_config());
+        app.execute_task("task-001".to_string()).await.unwrap();
+        run_until_stream_ends(&mut app).await;
+
+        assert!(app
+            .execution_events
+            .iter()
+            .any(|e| e.event_type == stream::TOKEN_EVENT_TYPE
+                && e.message == "Found 42 matching patterns in the dataset"));
+    }
 }
@@ -4,8 +4,18 @@
 //! specifically for the TUI, avoiding dependencies on broken client code.
 
 use crate::error::{AcoError, Result};
+use futures::stream::{self, Stream, StreamExt};
+use langgraph_core::{
+    EventStream, ExecutionEvent as GraphEvent, StreamChunk, StreamChunkStream, StreamEvent,
+    StreamMode,
+};
+use serde_json::json;
+use std::pin::Pin;
 use std::time::Duration;
 
+/// Delay between mock execution events, so they arrive like a live run
+const MOCK_EVENT_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Task info from server
 #[derive(Debug, Clone)]
 pub struct TaskInfo {
@@ -119,141 +129,90 @@ impl TuiGrpcClient {
         ])
     }
 
-    /// Execute a task and return execution events
-    pub async fn execute_task(&self, task_id: &str) -> Result<Vec<crate::tui::app::ExecutionEvent>> {
-        // For now, return mock execution events
+    /// Execute a task, streaming its progress
+    pub async fn execute_task(&self, task_id: &str) -> Result<StreamChunkStream> {
+        // For now, return a mock stream of execution events
         // TODO: Implement real gRPC streaming call
         tracing::debug!("Executing task {} on {}", task_id, self.server_url);
 
-        // Simulate execution delay
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-
-        Ok(vec![
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "started".to_string(),
-                message: format!("Task {} started", task_id),
-                status: "started".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Initializing execution environment...".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "output".to_string(),
-                message: "Processing data from input sources".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "tool_call".to_string(),
-                message: "Tool: file_read | Input: {\"path\": \"/data/input.txt\"}".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "tool_result".to_string(),
-                message: "Tool: file_read | Result: Successfully read 1024 bytes".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Analyzing data patterns...".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "output".to_string(),
-                message: "Found 42 matching patterns in the dataset".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "completed".to_string(),
-                message: format!("Task {} completed successfully", task_id),
-                status: "completed".to_string(),
-            },
-        ])
+        let chunk = |mode, event| StreamChunk::new(Vec::new(), mode, event, 0);
+        let token = |text: &str| {
+            chunk(
+                StreamMode::Messages,
+                StreamEvent::MessageChunk {
+                    chunk: text.to_string(),
+                    message_id: None,
+                    node: "agent".into(),
+                    metadata: None,
+                },
+            )
+        };
+
+        Ok(paced(vec![
+            chunk(
+                StreamMode::Tasks,
+                StreamEvent::TaskStart {
+                    task_id: task_id.to_string(),
+                    node: "agent".into(),
+                    input: json!({ "task_id": task_id }),
+                },
+            ),
+            chunk(
+                StreamMode::Custom,
+                StreamEvent::Custom {
+                    data: json!("Tool: file_read | Input: {\"path\": \"/data/input.txt\"}"),
+                },
+            ),
+            chunk(
+                StreamMode::Custom,
+                StreamEvent::Custom {
+                    data: json!("Tool: file_read | Result: Successfully read 1024 bytes"),
+                },
+            ),
+            token("Found 42 matching "),
+            token("patterns in "),
+            token("the dataset"),
+            chunk(
+                StreamMode::Tasks,
+                StreamEvent::TaskEnd {
+                    task_id: task_id.to_string(),
+                    node: "agent".into(),
+                    output: json!({ "matches": 42 }),
+                },
+            ),
+        ]))
     }
 
-    /// Execute a workflow and return execution events
-    pub async fn execute_workflow(&self, workflow_id: &str) -> Result<Vec<crate::tui::app::ExecutionEvent>> {
-        // For now, return mock execution events
+    /// Execute a workflow, streaming its graph execution events
+    pub async fn execute_workflow(&self, workflow_id: &str) -> Result<EventStream> {
+        // For now, return a mock stream of graph events
         // TODO: Implement real gRPC streaming call
         tracing::debug!("Executing workflow {} on {}", workflow_id, self.server_url);
 
-        // Simulate execution delay
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        let mut events = Vec::new();
+        for node in ["start_node", "process_node", "end_node"] {
+            events.push(GraphEvent::NodeStart { node: node.into() });
+            events.push(GraphEvent::NodeEnd {
+                node: node.into(),
+                output: json!({}),
+            });
+        }
+        events.push(GraphEvent::Complete {
+            final_state: json!({ "workflow_id": workflow_id }),
+        });
 
-        Ok(vec![
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "started".to_string(),
-                message: format!("Workflow {} started", workflow_id),
-                status: "started".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Entering node: start_node".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "output".to_string(),
-                message: "Node start_node completed successfully".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Entering node: process_node".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "tool_call".to_string(),
-                message: "Tool: data_processor | Input: {\"mode\": \"batch\"}".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "tool_result".to_string(),
-                message: "Tool: data_processor | Result: Processed 156 records".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Workflow checkpoint: step 1".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "progress".to_string(),
-                message: "Entering node: end_node".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "output".to_string(),
-                message: "Node end_node completed successfully".to_string(),
-                status: "in_progress".to_string(),
-            },
-            crate::tui::app::ExecutionEvent {
-                timestamp: chrono::Utc::now().to_rfc3339(),
-                event_type: "completed".to_string(),
-                message: format!("Workflow {} completed: Completed 3 nodes in 1 steps", workflow_id),
-                status: "completed".to_string(),
-            },
-        ])
+        Ok(paced(events))
     }
 }
 
+/// Stream `items` one at a time, [`MOCK_EVENT_INTERVAL`] apart
+fn paced<T: Send + 'static>(items: Vec<T>) -> Pin<Box<dyn Stream<Item = T> + Send>> {
+    Box::pin(stream::iter(items).then(|item| async move {
+        tokio::time::sleep(MOCK_EVENT_INTERVAL).await;
+        item
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
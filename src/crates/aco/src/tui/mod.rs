@@ -8,6 +8,7 @@ use std::path::PathBuf;
 pub mod app;
 pub mod events;
 pub mod grpc_client;
+pub mod stream;
 pub mod ui;
 
 pub use app::{App, AppState, View, TaskItem, WorkflowItem, ExecutionEvent};
@@ -77,6 +78,8 @@ pub async fn run(config: TuiConfig) -> Result<()> {
         // Handle events
         match event_handler.next() {
             Ok(Event::Tick) => {
+                app.drain_stream(stream::MAX_STREAM_EVENTS_PER_TICK);
+
                 // Auto-refresh on tick
                 if app.should_refresh() {
                     if let Err(e) = app.refresh_tasks().await {
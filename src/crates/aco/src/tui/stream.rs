@@ -0,0 +1,266 @@
+//! Live graph execution streaming for the TUI
+//!
+//! Converts langgraph stream chunks and execution events into
+//! [`ExecutionEvent`] lines for the execution pane. Streams are forwarded
+//! through a bounded channel that the UI drains a few events per tick, so a
+//! fast producer waits on the channel instead of starving the draw loop.
+
+use crate::tui::ExecutionEvent;
+use futures::StreamExt;
+use langgraph_core::interrupt::InterruptWhen;
+use langgraph_core::{EventStream, ExecutionEvent as GraphEvent, StreamChunkStream, StreamEvent};
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+/// Capacity of the channel between a stream and the UI
+pub const STREAM_BUFFER: usize = 256;
+
+/// Maximum number of stream events applied per UI tick
+pub const MAX_STREAM_EVENTS_PER_TICK: usize = 64;
+
+/// Maximum number of lines kept in the execution pane
+pub const MAX_EXECUTION_EVENTS: usize = 1000;
+
+/// Event type of token chunk lines; consecutive chunks from one node share a line
+pub const TOKEN_EVENT_TYPE: &str = "token";
+
+/// Format a stream event as an execution pane line
+///
+/// Returns `None` for events too large or noisy to show line by line
+/// (full state values and checkpoints).
+pub fn format_stream_event(event: &StreamEvent) -> Option<ExecutionEvent> {
+    match event {
+        StreamEvent::TaskStart { node, .. } => Some(line("started", node.to_string(), "running")),
+        StreamEvent::TaskEnd { node, .. } => Some(line("completed", node.to_string(), "success")),
+        StreamEvent::TaskError { node, error, .. } => {
+            Some(line("failed", format!("{}: {}", node, error), "error"))
+        }
+        StreamEvent::MessageChunk { chunk, node, .. } => {
+            Some(line(TOKEN_EVENT_TYPE, chunk.clone(), node.to_string()))
+        }
+        StreamEvent::Message { message, .. } => {
+            let content = message.get("content").unwrap_or(message);
+            Some(line("output", compact(content), "success"))
+        }
+        StreamEvent::Updates { node, update } => {
+            let keys = update
+                .as_object()
+                .map(|obj| obj.keys().cloned().collect::<Vec<_>>().join(", "))
+                .unwrap_or_else(|| compact(update));
+            Some(line(
+                "progress",
+                format!("{} updated {}", node, keys),
+                "running",
+            ))
+        }
+        StreamEvent::Custom { data } => Some(line("progress", compact(data), "running")),
+        StreamEvent::Values { .. } | StreamEvent::Checkpoint { .. } => None,
+    }
+}
+
+/// Format a graph execution event as an execution pane line
+///
+/// Returns `None` for full state updates.
+pub fn format_graph_event(event: &GraphEvent) -> Option<ExecutionEvent> {
+    match event {
+        GraphEvent::NodeStart { node } => Some(line("started", node.to_string(), "running")),
+        GraphEvent::NodeEnd { node, .. } => Some(line("completed", node.to_string(), "success")),
+        GraphEvent::StateUpdate { .. } => None,
+        GraphEvent::Error { node, error } => {
+            Some(line("failed", format!("{}: {}", node, error), "error"))
+        }
+        GraphEvent::Interrupted {
+            node,
+            when,
+            condition,
+            ..
+        } => {
+            let when = match (when, condition) {
+                (InterruptWhen::Before, _) => "before".to_string(),
+                (InterruptWhen::After, _) => "after".to_string(),
+                (InterruptWhen::Condition, Some(name)) => format!("on condition '{}' before", name),
+                (InterruptWhen::Condition, None) => "on condition before".to_string(),
+            };
+            Some(line(
+                "progress",
+                format!("interrupted {} {}", when, node),
+                "interrupted",
+            ))
+        }
+        GraphEvent::Complete { .. } => Some(line("completed", "graph finished", "success")),
+    }
+}
+
+/// Append `event` to the pane's lines
+///
+/// A token chunk from the same node as the previous token line is appended
+/// to that line. The oldest lines are dropped beyond `max_lines`.
+pub fn append_event(events: &mut Vec<ExecutionEvent>, event: ExecutionEvent, max_lines: usize) {
+    if let Some(last) = events.last_mut() {
+        if event.event_type == TOKEN_EVENT_TYPE
+            && last.event_type == TOKEN_EVENT_TYPE
+            && last.status == event.status
+        {
+            last.message.push_str(&event.message);
+            return;
+        }
+    }
+
+    events.push(event);
+    if events.len() > max_lines {
+        let excess = events.len() - max_lines;
+        events.drain(..excess);
+    }
+}
+
+/// Forward a stream chunk stream into a bounded channel of pane lines
+///
+/// Must be called from within a tokio runtime.
+pub fn spawn_chunk_forwarder(mut stream: StreamChunkStream) -> mpsc::Receiver<ExecutionEvent> {
+    let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(async move {
+        while let Some(chunk) = stream.next().await {
+            if let Some(event) = format_stream_event(&chunk.event) {
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    rx
+}
+
+/// Forward a graph event stream into a bounded channel of pane lines
+///
+/// Must be called from within a tokio runtime.
+pub fn spawn_event_forwarder(mut stream: EventStream) -> mpsc::Receiver<ExecutionEvent> {
+    let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(async move {
+        while let Some(event) = stream.next().await {
+            if let Some(event) = format_graph_event(&event) {
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    rx
+}
+
+fn line(event_type: &str, message: impl Into<String>, status: impl Into<String>) -> ExecutionEvent {
+    ExecutionEvent {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        event_type: event_type.to_string(),
+        message: message.into(),
+        status: status.into(),
+    }
+}
+
+fn compact(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_mixed_events_format_to_lines() {
+        let stream_events = vec![
+            StreamEvent::TaskStart {
+                task_id: "t1".to_string(),
+                node: "agent".into(),
+                input: json!({}),
+            },
+            StreamEvent::MessageChunk {
+                chunk: "Hel".to_string(),
+                message_id: None,
+                node: "agent".into(),
+                metadata: None,
+            },
+            StreamEvent::MessageChunk {
+                chunk: "lo".to_string(),
+                message_id: None,
+                node: "agent".into(),
+                metadata: None,
+            },
+            StreamEvent::Values {
+                state: json!({"messages": []}),
+            },
+            StreamEvent::Updates {
+                node: "agent".into(),
+                update: json!({"messages": ["Hello"]}),
+            },
+            StreamEvent::TaskEnd {
+                task_id: "t1".to_string(),
+                node: "agent".into(),
+                output: json!({}),
+            },
+            StreamEvent::TaskError {
+                task_id: "t2".to_string(),
+                node: "tools".into(),
+                error: "timeout".to_string(),
+            },
+        ];
+
+        let mut lines = Vec::new();
+        for event in &stream_events {
+            if let Some(line) = format_stream_event(event) {
+                append_event(&mut lines, line, MAX_EXECUTION_EVENTS);
+            }
+        }
+        if let Some(line) = format_graph_event(&GraphEvent::Complete {
+            final_state: json!({}),
+        }) {
+            append_event(&mut lines, line, MAX_EXECUTION_EVENTS);
+        }
+
+        let rendered: Vec<(&str, &str)> = lines
+            .iter()
+            .map(|l| (l.event_type.as_str(), l.message.as_str()))
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                ("started", "agent"),
+                ("token", "Hello"),
+                ("progress", "agent updated messages"),
+                ("completed", "agent"),
+                ("failed", "tools: timeout"),
+                ("completed", "graph finished"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_condition_interrupt_shows_condition_name() {
+        let line = format_graph_event(&GraphEvent::Interrupted {
+            node: "pay".into(),
+            when: InterruptWhen::Condition,
+            condition: Some("budget_exceeded".to_string()),
+            state: json!({}),
+        })
+        .unwrap();
+
+        assert_eq!(
+            line.message,
+            "interrupted on condition 'budget_exceeded' before pay"
+        );
+        assert_eq!(line.status, "interrupted");
+    }
+
+    #[test]
+    fn test_append_event_drops_oldest_lines() {
+        let mut lines = Vec::new();
+        for i in 0..5 {
+            append_event(&mut lines, line("progress", i.to_string(), "running"), 3);
+        }
+
+        let messages: Vec<&str> = lines.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["2", "3", "4"]);
+    }
+}
@@ -3,6 +3,7 @@
 //! This module handles initialization and validation of acolib workspaces.
 //! A workspace is a directory containing configuration, logs, and other state.
 
+use crate::config::AcoConfig;
 use crate::error::{AcoError, Result};
 use super::security::{PathValidator, SecurityConfig};
 use std::fs;
@@ -23,6 +24,29 @@ pub struct WorkspaceInitConfig {
 
     /// Security configuration for path validation
     pub security_config: SecurityConfig,
+
+    /// Format of the default config written to `.aco/`
+    pub config_format: ConfigFormat,
+}
+
+/// File format of a workspace's `.aco` config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigFormat {
+    /// Commented TOON, written to `.aco/config.toon`
+    #[default]
+    Toon,
+    /// TOML, written to `.aco/aco.toml`
+    Toml,
+}
+
+impl ConfigFormat {
+    /// File name of the config inside `.aco/`
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ConfigFormat::Toon => "config.toon",
+            ConfigFormat::Toml => "aco.toml",
+        }
+    }
 }
 
 impl WorkspaceInitConfig {
@@ -33,6 +57,7 @@ impl WorkspaceInitConfig {
             create_if_missing: true,
             validate_permissions: true,
             security_config: SecurityConfig::default(),
+            config_format: ConfigFormat::default(),
         }
     }
 
@@ -53,6 +78,12 @@ impl WorkspaceInitConfig {
         self.security_config = config;
         self
     }
+
+    /// Set the format of the generated default config
+    pub fn with_config_format(mut self, format: ConfigFormat) -> Self {
+        self.config_format = format;
+        self
+    }
 }
 
 /// Workspace initializer
@@ -76,8 +107,9 @@ impl WorkspaceInitializer {
     /// 1. Validates the root directory
     /// 2. Creates security validator
     /// 3. Creates necessary subdirectories with security validation
-    /// 4. Initializes workspace metadata
-    /// 5. Validates permissions
+    /// 4. Validates permissions
+    /// 5. Writes a default `.aco` config, unless one exists
+    /// 6. Initializes workspace metadata
     pub fn init(&mut self) -> Result<WorkspaceMetadata> {
         debug!(
             "Initializing workspace at {}",
@@ -102,6 +134,9 @@ impl WorkspaceInitializer {
             self.validate_write_permissions()?;
         }
 
+        // Write default config
+        self.write_default_config()?;
+
         // Create workspace metadata
         let metadata = self.create_workspace_metadata()?;
 
@@ -152,6 +187,7 @@ impl WorkspaceInitializer {
             "logs",         // Log files
             "config",       // Configuration files
             ".acolib/cache", // Cache directory
+            ".aco",         // Project config
         ];
 
         for subdir in subdirs {
@@ -193,12 +229,36 @@ impl WorkspaceInitializer {
         }
     }
 
+    /// Write the default config in the configured format
+    fn write_default_config(&self) -> Result<()> {
+        let path = self
+            .config
+            .root
+            .join(".aco")
+            .join(self.config.config_format.file_name());
+        if path.exists() {
+            return Ok(());
+        }
+
+        let content = match self.config.config_format {
+            ConfigFormat::Toon => default_toon_config()?,
+            ConfigFormat::Toml => toml::to_string_pretty(&AcoConfig::default())
+                .map_err(|e| AcoError::Config(format!("Failed to serialize config: {}", e)))?,
+        };
+
+        debug!("Writing default config: {}", path.display());
+        fs::write(&path, content)?;
+
+        Ok(())
+    }
+
     /// Create workspace metadata
     fn create_workspace_metadata(&self) -> Result<WorkspaceMetadata> {
         let metadata = WorkspaceMetadata {
             root: self.config.root.clone(),
             initialized_at: chrono::Utc::now(),
             version: "0.1.0".to_string(),
+            config_format: self.config.config_format,
         };
 
         // Write metadata to file (if needed in the future)
@@ -232,6 +292,9 @@ pub struct WorkspaceMetadata {
 
     /// Version of the workspace format
     pub version: String,
+
+    /// Format of the workspace's `.aco` config
+    pub config_format: ConfigFormat,
 }
 
 impl WorkspaceMetadata {
@@ -254,6 +317,57 @@ impl WorkspaceMetadata {
     pub fn metadata_file(&self) -> PathBuf {
         self.root.join(".acolib").join("workspace.toml")
     }
+
+    /// Get the `.aco` config file path
+    pub fn config_file(&self) -> PathBuf {
+        self.root.join(".aco").join(self.config_format.file_name())
+    }
+}
+
+/// Section comments for the generated TOON config, keyed by top-level key
+const TOON_SECTION_COMMENTS: &[(&str, &str)] = &[
+    ("server", "Local server the tool runtime listens on"),
+    ("client", "Connection to the orchestrator"),
+    ("tools", "Tool selection; an empty enabled_tools list enables every tool"),
+    ("ui", "Terminal output; log_level is one of trace, debug, info, warn, error"),
+];
+
+/// Render [`AcoConfig::default`] as TOON with a comment above each section
+///
+/// TOON itself has no comment syntax; read the result back with
+/// [`parse_toon_config`], which drops `#` lines before decoding.
+pub fn default_toon_config() -> Result<String> {
+    let value = serde_json::to_value(AcoConfig::default())?;
+    let encoded = rtoon::encode(&value, None);
+
+    let mut out = String::from("# aco configuration\n# Generated by workspace initialization\n");
+    for line in encoded.lines() {
+        let comment = TOON_SECTION_COMMENTS
+            .iter()
+            .find(|(key, _)| line.strip_prefix(key).is_some_and(|rest| rest.starts_with(':')));
+        if let Some((_, comment)) = comment {
+            out.push_str(&format!("\n# {}\n", comment));
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+/// Parse a TOON config, ignoring `#` comment lines and blank lines
+pub fn parse_toon_config(content: &str) -> Result<AcoConfig> {
+    let toon: Vec<&str> = content
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .collect();
+
+    let value = rtoon::decode(&toon.join("\n"), None)
+        .map_err(|e| AcoError::Config(format!("Invalid TOON config: {}", e)))?;
+    Ok(serde_json::from_value(value)?)
 }
 
 /// Workspace validator
@@ -310,10 +424,17 @@ impl WorkspaceValidator {
 
         info!("Workspace validated successfully at {}", root.display());
 
+        let config_format = if root.join(".aco").join(ConfigFormat::Toon.file_name()).exists() {
+            ConfigFormat::Toon
+        } else {
+            ConfigFormat::Toml
+        };
+
         Ok(WorkspaceMetadata {
             root: root.to_path_buf(),
             initialized_at: chrono::Utc::now(),
             version: "0.1.0".to_string(),
+            config_format,
         })
     }
 }
@@ -346,6 +467,49 @@ mod tests {
         assert!(metadata.metadata_file().exists());
     }
 
+    #[test]
+    fn test_init_writes_parseable_toon_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let mut initializer = WorkspaceInitializer::new(WorkspaceInitConfig::new(root));
+        let metadata = initializer.init().unwrap();
+
+        assert_eq!(metadata.config_format, ConfigFormat::Toon);
+        assert_eq!(metadata.config_file(), root.join(".aco").join("config.toon"));
+
+        let content = fs::read_to_string(metadata.config_file()).unwrap();
+        assert!(content.starts_with("# aco configuration"));
+        assert!(content.contains("# Connection to the orchestrator"));
+
+        let config = parse_toon_config(&content).unwrap();
+        assert_eq!(config.server.host, "127.0.0.1");
+        assert_eq!(config.server.port, 8080);
+        assert_eq!(config.client.orchestrator_url, "ws://127.0.0.1:8080/ws");
+        assert_eq!(config.tools.execution_timeout, 300);
+        assert_eq!(config.ui.log_level, "info");
+
+        assert_eq!(
+            WorkspaceValidator::validate(root).unwrap().config_format,
+            ConfigFormat::Toon
+        );
+    }
+
+    #[test]
+    fn test_init_with_toml_config_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let config = WorkspaceInitConfig::new(root).with_config_format(ConfigFormat::Toml);
+        let metadata = WorkspaceInitializer::new(config).init().unwrap();
+
+        assert_eq!(metadata.config_file(), root.join(".aco").join("aco.toml"));
+        let parsed: AcoConfig =
+            toml::from_str(&fs::read_to_string(metadata.config_file()).unwrap()).unwrap();
+        assert_eq!(parsed.server.port, 8080);
+        assert!(!root.join(".aco").join("config.toon").exists());
+    }
+
     #[test]
     fn test_workspace_idempotent() {
         let temp_dir = TempDir::new().unwrap();
@@ -7,6 +7,7 @@ pub mod initializer;
 pub mod security;
 
 pub use initializer::{
-    WorkspaceInitConfig, WorkspaceInitializer, WorkspaceMetadata, WorkspaceValidator,
+    default_toon_config, parse_toon_config, ConfigFormat, WorkspaceInitConfig,
+    WorkspaceInitializer, WorkspaceMetadata, WorkspaceValidator,
 };
 pub use security::{PathValidator, SecurityConfig};
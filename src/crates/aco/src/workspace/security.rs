@@ -7,9 +7,13 @@
 //! - Workspace boundary enforcement
 
 use crate::error::{AcoError, Result};
+use std::collections::VecDeque;
 use std::path::{Component, Path, PathBuf};
 use tracing::debug;
 
+/// Symlinks followed by [`PathValidator::resolve_path`] before giving up
+const MAX_SYMLINK_HOPS: usize = 40;
+
 /// Security configuration for workspace operations
 #[derive(Debug, Clone)]
 pub struct SecurityConfig {
@@ -84,6 +88,119 @@ impl PathValidator {
         })
     }
 
+    /// Create a validator confining a session to `root`
+    ///
+    /// Symlinks are allowed as long as they resolve inside `root`, and the
+    /// system blocklist is not applied since nothing outside `root` is
+    /// reachable anyway. Use [`PathValidator::resolve_path`] to map tool
+    /// arguments onto the session's workspace.
+    pub fn for_session(root: impl AsRef<Path>) -> Result<Self> {
+        let validator = Self::new(
+            root,
+            SecurityConfig {
+                allow_symlinks: true,
+                follow_symlinks: true,
+                blocked_paths: Vec::new(),
+            },
+        )?;
+        let workspace_root = validator.workspace_root.canonicalize()?;
+
+        Ok(Self {
+            workspace_root,
+            ..validator
+        })
+    }
+
+    /// Workspace root paths are checked against
+    pub fn workspace_root(&self) -> &Path {
+        &self.workspace_root
+    }
+
+    /// Resolve `path` against the workspace root, following symlinks
+    ///
+    /// Relative paths are joined onto the root. The path need not exist yet.
+    /// Each component is inspected with `symlink_metadata`, and symlinks,
+    /// dangling ones included, are replaced by their target before the
+    /// bounds check. Fails if the path contains `..`, crosses a symlink
+    /// while symlinks are not allowed, or resolves outside the root.
+    pub fn resolve_path(&self, path: impl AsRef<Path>) -> Result<PathBuf> {
+        let path = path.as_ref();
+
+        self.check_traversal_attempts(path)?;
+
+        let canonical_root = self
+            .workspace_root
+            .canonicalize()
+            .unwrap_or_else(|_| self.workspace_root.clone());
+        let full_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            canonical_root.join(path)
+        };
+
+        let mut pending: VecDeque<PathBuf> = full_path
+            .components()
+            .map(|c| PathBuf::from(c.as_os_str()))
+            .collect();
+        let mut resolved = PathBuf::new();
+        let mut links_followed = 0;
+
+        while let Some(component) = pending.pop_front() {
+            match component.components().next() {
+                Some(Component::CurDir) | None => continue,
+                Some(Component::ParentDir) => {
+                    resolved.pop();
+                    continue;
+                }
+                _ => {}
+            }
+
+            let candidate = resolved.join(&component);
+            let is_symlink = std::fs::symlink_metadata(&candidate)
+                .map(|meta| meta.file_type().is_symlink())
+                .unwrap_or(false);
+            if !is_symlink {
+                resolved = candidate;
+                continue;
+            }
+
+            if !self.config.allow_symlinks && candidate.starts_with(&canonical_root) {
+                return Err(AcoError::General(format!(
+                    "Symbolic links are not allowed: {}",
+                    candidate.display()
+                )));
+            }
+            links_followed += 1;
+            if links_followed > MAX_SYMLINK_HOPS {
+                return Err(AcoError::General(format!(
+                    "Too many symbolic links resolving {}",
+                    path.display()
+                )));
+            }
+
+            // Splice the link target in place of the link
+            let target = std::fs::read_link(&candidate)?;
+            if target.is_absolute() {
+                resolved = PathBuf::new();
+            }
+            for part in target.components().rev() {
+                pending.push_front(PathBuf::from(part.as_os_str()));
+            }
+        }
+
+        if !resolved.starts_with(&canonical_root) {
+            return Err(AcoError::General(format!(
+                "Path {} is outside workspace bounds: {}",
+                path.display(),
+                canonical_root.display()
+            )));
+        }
+
+        debug!("Resolved {} to {}", path.display(), resolved.display());
+
+        Ok(resolved)
+    }
+
     /// Validate that a path is safe to access
     pub fn validate_path(&self, path: impl AsRef<Path>) -> Result<()> {
         let path = path.as_ref();
@@ -766,6 +883,90 @@ mod tests {
         assert!(result.is_err(), "Symlink pointing outside workspace should be rejected");
     }
 
+    #[test]
+    fn test_session_resolves_in_root_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let validator = PathValidator::for_session(temp_dir.path()).unwrap();
+        std::fs::create_dir(temp_dir.path().join("src")).unwrap();
+
+        let resolved = validator.resolve_path("src/new_file.rs").unwrap();
+        assert_eq!(
+            resolved,
+            temp_dir.path().canonicalize().unwrap().join("src/new_file.rs")
+        );
+    }
+
+    #[test]
+    fn test_session_rejects_parent_dir_escape() {
+        let temp_dir = TempDir::new().unwrap();
+        let session_a = temp_dir.path().join("session-a");
+        let session_b = temp_dir.path().join("session-b");
+        std::fs::create_dir(&session_a).unwrap();
+        std::fs::create_dir(&session_b).unwrap();
+        std::fs::write(session_b.join("notes.txt"), "private").unwrap();
+
+        let validator = PathValidator::for_session(&session_a).unwrap();
+        let result = validator.resolve_path("../session-b/notes.txt");
+        assert!(result.unwrap_err().to_string().contains("traversal"));
+
+        let result = validator.resolve_path(session_b.join("notes.txt"));
+        assert!(result.unwrap_err().to_string().contains("outside workspace"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_rejects_symlink_outside_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path().join("workspace");
+        let outside = temp_dir.path().join("outside");
+        std::fs::create_dir(&workspace).unwrap();
+        std::fs::create_dir(&outside).unwrap();
+        std::fs::write(outside.join("secret.txt"), "secret").unwrap();
+        std::fs::write(workspace.join("inside.txt"), "ok").unwrap();
+
+        std::os::unix::fs::symlink(&outside, workspace.join("escape")).unwrap();
+        std::os::unix::fs::symlink(workspace.join("inside.txt"), workspace.join("alias.txt"))
+            .unwrap();
+
+        let validator = PathValidator::for_session(&workspace).unwrap();
+        assert!(validator.resolve_path("escape/secret.txt").is_err());
+        assert!(validator.resolve_path("escape/new.txt").is_err());
+        assert!(validator.resolve_path("alias.txt").is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_rejects_dangling_symlink_outside_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path().join("workspace");
+        std::fs::create_dir(&workspace).unwrap();
+
+        // Target does not exist yet; writing through the link would create it
+        let target = temp_dir.path().join("outside.txt");
+        std::os::unix::fs::symlink(&target, workspace.join("dangling.txt")).unwrap();
+        std::os::unix::fs::symlink("../outside-dir", workspace.join("relative")).unwrap();
+
+        let validator = PathValidator::for_session(&workspace).unwrap();
+        assert!(validator.resolve_path("dangling.txt").is_err());
+        assert!(validator.resolve_path("relative/new.txt").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_path_rejects_symlinks_when_not_allowed() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("inside.txt"), "ok").unwrap();
+        std::os::unix::fs::symlink(
+            temp_dir.path().join("inside.txt"),
+            temp_dir.path().join("alias.txt"),
+        )
+        .unwrap();
+
+        let validator = PathValidator::new(temp_dir.path(), SecurityConfig::default()).unwrap();
+        assert!(validator.resolve_path("alias.txt").is_err());
+        assert!(validator.resolve_path("inside.txt").is_ok());
+    }
+
     #[test]
     fn test_write_path_parent_not_writable() {
         let temp_dir = TempDir::new().unwrap();
@@ -68,6 +68,7 @@ use std::pin::Pin;
 ///                 checkpoint,
 ///                 metadata,
 ///                 parent_config: None,
+///                 pending_writes: None,
 ///             }))
 ///         } else {
 ///             Ok(None)
@@ -47,6 +47,15 @@ pub trait Channel: Send + Sync + Debug {
         false
     }
 
+    /// Whether the Pregel loop clears this channel once a superstep has run
+    /// with its value visible
+    ///
+    /// The loop clears the channel with an empty update, unless the same
+    /// superstep wrote a new value.
+    fn auto_consume(&self) -> bool {
+        false
+    }
+
     /// Notify the channel that the Pregel run is finishing
     ///
     /// Returns `true` if the channel was updated, `false` otherwise.
@@ -19,6 +19,9 @@ use std::collections::HashSet;
 pub struct EphemeralValueChannel {
     value: Option<serde_json::Value>,
     guard: bool,
+    /// If true, the Pregel loop clears the value after the superstep that reads it
+    #[serde(default)]
+    auto_consume: bool,
 }
 
 impl EphemeralValueChannel {
@@ -27,6 +30,7 @@ impl EphemeralValueChannel {
         Self {
             value: None,
             guard: true,
+            auto_consume: false,
         }
     }
 
@@ -35,8 +39,15 @@ impl EphemeralValueChannel {
         Self {
             value: None,
             guard: false,
+            auto_consume: false,
         }
     }
+
+    /// Have the Pregel loop clear the value after the superstep that reads it.
+    pub fn with_auto_consume(mut self, auto_consume: bool) -> Self {
+        self.auto_consume = auto_consume;
+        self
+    }
 }
 
 impl Default for EphemeralValueChannel {
@@ -87,6 +98,10 @@ impl Channel for EphemeralValueChannel {
         self.value.is_some()
     }
 
+    fn auto_consume(&self) -> bool {
+        self.auto_consume
+    }
+
     fn clone_box(&self) -> Box<dyn Channel> {
         Box::new(self.clone())
     }
@@ -234,6 +249,15 @@ impl Channel for UntrackedValueChannel {
 ///
 /// This channel collects values from named sources and only becomes available
 /// when all expected names have written.
+///
+/// A barrier that is only partially filled can be inspected with [`missing`] and
+/// cleared with [`reset`], which lets a supervisor node detect a barrier that will
+/// never complete and recover from it. The received set is part of the channel's
+/// checkpoint, so a partially-filled barrier survives a checkpoint/restore cycle
+/// and [`missing`] reports the same names after resuming.
+///
+/// [`missing`]: NamedBarrierValueChannel::missing
+/// [`reset`]: NamedBarrierValueChannel::reset
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NamedBarrierValueChannel {
     names: HashSet<String>,
@@ -248,6 +272,20 @@ impl NamedBarrierValueChannel {
             seen: HashSet::new(),
         }
     }
+
+    /// Clear all received signals, returning the barrier to its initial state.
+    ///
+    /// The expected names are kept, so the barrier can be filled again.
+    pub fn reset(&mut self) {
+        self.seen.clear();
+    }
+
+    /// Names that have not been signaled yet, in sorted order.
+    pub fn missing(&self) -> Vec<String> {
+        let mut missing: Vec<String> = self.names.difference(&self.seen).cloned().collect();
+        missing.sort();
+        missing
+    }
 }
 
 impl Channel for NamedBarrierValueChannel {
@@ -337,6 +375,20 @@ mod tests {
         assert!(!channel.is_available());
     }
 
+    #[test]
+    fn test_ephemeral_auto_consume_flag() {
+        let channel = EphemeralValueChannel::new();
+        assert!(!channel.auto_consume());
+
+        let channel = EphemeralValueChannel::new().with_auto_consume(true);
+        assert!(channel.auto_consume());
+
+        // Older serialized channels default to off
+        let restored: EphemeralValueChannel =
+            serde_json::from_value(serde_json::json!({"value": null, "guard": true})).unwrap();
+        assert!(!restored.auto_consume());
+    }
+
     #[test]
     fn test_any_value_channel() {
         let mut channel = AnyValueChannel::new();
@@ -396,4 +448,65 @@ mod tests {
         let result = channel.update(vec![serde_json::json!("task_b")]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_named_barrier_missing_partial_fill() {
+        let names: HashSet<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut channel = NamedBarrierValueChannel::new(names);
+        assert_eq!(channel.missing(), vec!["a", "b", "c"]);
+
+        channel.update(vec![serde_json::json!("b")]).unwrap();
+        assert!(!channel.is_available());
+        assert_eq!(channel.missing(), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_named_barrier_missing_full_fill() {
+        let names: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut channel = NamedBarrierValueChannel::new(names);
+
+        channel
+            .update(vec![serde_json::json!("a"), serde_json::json!("b")])
+            .unwrap();
+        assert!(channel.is_available());
+        assert!(channel.missing().is_empty());
+    }
+
+    #[test]
+    fn test_named_barrier_reset_and_refill() {
+        let names: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut channel = NamedBarrierValueChannel::new(names);
+
+        // Partially filled barrier can be reset
+        channel.update(vec![serde_json::json!("a")]).unwrap();
+        channel.reset();
+        assert!(!channel.is_available());
+        assert_eq!(channel.missing(), vec!["a", "b"]);
+
+        // Full barrier can be reset too
+        channel
+            .update(vec![serde_json::json!("a"), serde_json::json!("b")])
+            .unwrap();
+        assert!(channel.is_available());
+        channel.reset();
+        assert!(!channel.is_available());
+
+        // Re-fill after reset
+        assert!(channel.update(vec![serde_json::json!("b")]).unwrap());
+        assert!(channel.update(vec![serde_json::json!("a")]).unwrap());
+        assert!(channel.is_available());
+    }
+
+    #[test]
+    fn test_named_barrier_missing_survives_checkpoint() {
+        let names: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut channel = NamedBarrierValueChannel::new(names.clone());
+        channel.update(vec![serde_json::json!("a")]).unwrap();
+
+        let checkpoint = channel.checkpoint().unwrap();
+        let mut restored = NamedBarrierValueChannel::new(names);
+        restored.from_checkpoint(checkpoint).unwrap();
+
+        assert_eq!(restored.missing(), vec!["b"]);
+    }
 }
@@ -106,6 +106,9 @@ pub struct EphemeralValueChannel {
     value: Option<serde_json::Value>,
     /// If true, requires exactly one value per update (prevents multiple writes per step)
     guard: bool,
+    /// If true, the Pregel loop clears the value after the superstep that reads it
+    #[serde(default)]
+    auto_consume: bool,
 }
 
 impl EphemeralValueChannel {
@@ -113,11 +116,22 @@ impl EphemeralValueChannel {
         Self {
             value: None,
             guard: true,
+            auto_consume: false,
         }
     }
 
     pub fn with_guard(guard: bool) -> Self {
-        Self { value: None, guard }
+        Self {
+            value: None,
+            guard,
+            auto_consume: false,
+        }
+    }
+
+    /// Have the Pregel loop clear the value after the superstep that reads it
+    pub fn with_auto_consume(mut self, auto_consume: bool) -> Self {
+        self.auto_consume = auto_consume;
+        self
     }
 }
 
@@ -177,6 +191,10 @@ impl Channel for EphemeralValueChannel {
         }
     }
 
+    fn auto_consume(&self) -> bool {
+        self.auto_consume
+    }
+
     fn clone_box(&self) -> Box<dyn Channel> {
         Box::new(self.clone())
     }
@@ -760,6 +778,24 @@ impl NamedBarrierValueChannel {
             received: HashSet::new(),
         }
     }
+
+    /// Clear all received signals so the barrier can be filled again.
+    ///
+    /// The expected names are kept. Use this to recover from a barrier that
+    /// is partially filled and will never complete.
+    pub fn reset(&mut self) {
+        self.received.clear();
+    }
+
+    /// Names that have not been signaled yet, in sorted order.
+    ///
+    /// The received set is serialized by [`Channel::checkpoint`], so this
+    /// reports the same names after the channel is restored from a checkpoint.
+    pub fn missing(&self) -> Vec<String> {
+        let mut missing: Vec<String> = self.names.difference(&self.received).cloned().collect();
+        missing.sort();
+        missing
+    }
 }
 
 impl Channel for NamedBarrierValueChannel {
@@ -949,6 +985,24 @@ mod tests {
         assert!(result.is_array());
     }
 
+    #[test]
+    fn test_named_barrier_value_reset_and_missing() {
+        let mut channel = NamedBarrierValueChannel::new(vec![
+            "task1".to_string(),
+            "task2".to_string(),
+        ]);
+
+        channel.update(vec![json!("task2")]).unwrap();
+        assert_eq!(channel.missing(), vec!["task1"]);
+
+        channel.reset();
+        assert_eq!(channel.missing(), vec!["task1", "task2"]);
+
+        channel.update(vec![json!("task1"), json!("task2")]).unwrap();
+        assert!(channel.is_available());
+        assert!(channel.missing().is_empty());
+    }
+
     #[test]
     fn test_named_barrier_value_ignores_unknown() {
         let mut channel = NamedBarrierValueChannel::new(vec!["task1".to_string()]);
@@ -520,6 +520,9 @@ pub struct CheckpointConfig {
 }
 
 impl CheckpointConfig {
+    /// Reserved [`extra`](Self::extra) key for the request-scoped context
+    pub const REQUEST_CONTEXT_KEY: &'static str = "__request_context__";
+
     /// Create a new checkpoint configuration
     pub fn new() -> Self {
         Self::default()
@@ -542,6 +545,46 @@ impl CheckpointConfig {
         self.checkpoint_ns = Some(checkpoint_ns);
         self
     }
+
+    /// Set the maximum number of supersteps for a single invocation
+    ///
+    /// Stored under the `recursion_limit` key of [`extra`](Self::extra), matching
+    /// the Python LangGraph config key.
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.extra
+            .insert("recursion_limit".to_string(), serde_json::json!(limit));
+        self
+    }
+
+    /// Get the per-invocation recursion limit, if one was set
+    pub fn recursion_limit(&self) -> Option<usize> {
+        self.extra
+            .get("recursion_limit")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+    }
+
+    /// Attach a request-scoped context value for a single invocation
+    ///
+    /// Stored under the reserved [`REQUEST_CONTEXT_KEY`](Self::REQUEST_CONTEXT_KEY)
+    /// of [`extra`](Self::extra), so a user's own `context` key is left alone.
+    /// Every graph entry point takes it out before checkpointing, so it is
+    /// never persisted.
+    pub fn with_context(mut self, context: serde_json::Value) -> Self {
+        self.extra
+            .insert(Self::REQUEST_CONTEXT_KEY.to_string(), context);
+        self
+    }
+
+    /// Get the request-scoped context value, if one was set
+    pub fn context(&self) -> Option<&serde_json::Value> {
+        self.extra.get(Self::REQUEST_CONTEXT_KEY)
+    }
+
+    /// Remove and return the request-scoped context value
+    pub fn take_context(&mut self) -> Option<serde_json::Value> {
+        self.extra.remove(Self::REQUEST_CONTEXT_KEY)
+    }
 }
 
 /// A tuple containing a checkpoint and its associated data
@@ -558,6 +601,9 @@ pub struct CheckpointTuple {
 
     /// Parent configuration (if any)
     pub parent_config: Option<CheckpointConfig>,
+
+    /// Writes stored with `put_writes` that the checkpoint doesn't include yet
+    pub pending_writes: Option<Vec<PendingWrite>>,
 }
 
 impl CheckpointTuple {
@@ -572,6 +618,7 @@ impl CheckpointTuple {
             checkpoint,
             metadata,
             parent_config: None,
+            pending_writes: None,
         }
     }
 
@@ -580,6 +627,24 @@ impl CheckpointTuple {
         self.parent_config = Some(parent_config);
         self
     }
+
+    /// Set the pending writes
+    pub fn with_pending_writes(mut self, pending_writes: Vec<PendingWrite>) -> Self {
+        self.pending_writes = Some(pending_writes);
+        self
+    }
+
+    /// Pending write values grouped by channel, in the order they were written
+    ///
+    /// Shows what each channel will receive when the writes are applied, e.g.
+    /// on resuming an interrupted graph.
+    pub fn pending_by_channel(&self) -> HashMap<String, Vec<serde_json::Value>> {
+        let mut grouped: HashMap<String, Vec<serde_json::Value>> = HashMap::new();
+        for (_task_id, channel, value) in self.pending_writes.iter().flatten() {
+            grouped.entry(channel.clone()).or_default().push(value.clone());
+        }
+        grouped
+    }
 }
 
 #[cfg(test)]
@@ -616,6 +681,32 @@ mod tests {
         assert_eq!(metadata.extra.get("key"), Some(&serde_json::json!("value")));
     }
 
+    #[test]
+    fn test_pending_by_channel_groups_writes() {
+        let tuple = CheckpointTuple::new(
+            CheckpointConfig::new(),
+            Checkpoint::empty(),
+            CheckpointMetadata::new(),
+        )
+        .with_pending_writes(vec![
+            ("task-1".to_string(), "messages".to_string(), serde_json::json!("hi")),
+            ("task-2".to_string(), "count".to_string(), serde_json::json!(1)),
+            ("task-2".to_string(), "messages".to_string(), serde_json::json!("there")),
+        ]);
+
+        let grouped = tuple.pending_by_channel();
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped["messages"], vec![serde_json::json!("hi"), serde_json::json!("there")]);
+        assert_eq!(grouped["count"], vec![serde_json::json!(1)]);
+
+        let empty = CheckpointTuple::new(
+            CheckpointConfig::new(),
+            Checkpoint::empty(),
+            CheckpointMetadata::new(),
+        );
+        assert!(empty.pending_by_channel().is_empty());
+    }
+
     #[test]
     fn test_checkpoint_config() {
         let config = CheckpointConfig::new()
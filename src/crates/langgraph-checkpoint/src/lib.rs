@@ -342,5 +342,9 @@ pub use channels_extended::{
 };
 pub use error::{CheckpointError, Result};
 pub use memory::InMemoryCheckpointSaver;
-pub use serializer::SerializerProtocol;
+pub use serializer::{
+    delete_channels, dumps_checkpoint, load_channels, loads_checkpoint, offload_channels,
+    ChannelSerializer,
+    InMemoryChannelSerializer, SerializerProtocol,
+};
 pub use traits::{CheckpointSaver, CheckpointStream};
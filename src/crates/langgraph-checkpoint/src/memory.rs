@@ -404,8 +404,10 @@
 use crate::{
     checkpoint::{
         ChannelVersions, Checkpoint, CheckpointConfig, CheckpointMetadata, CheckpointTuple,
+        PendingWrite,
     },
     error::{CheckpointError, Result},
+    serializer::{delete_channels, load_channels, offload_channels, ChannelSerializer},
     traits::{CheckpointSaver, CheckpointStream},
 };
 use async_trait::async_trait;
@@ -424,6 +426,21 @@ struct CheckpointEntry {
     writes: Vec<(String, serde_json::Value, String)>, // (channel, value, task_id)
 }
 
+impl CheckpointEntry {
+    /// Stored writes as `(task_id, channel, value)`, or `None` if there are none
+    fn pending_writes(&self) -> Option<Vec<PendingWrite>> {
+        if self.writes.is_empty() {
+            return None;
+        }
+        Some(
+            self.writes
+                .iter()
+                .map(|(channel, value, task_id)| (task_id.clone(), channel.clone(), value.clone()))
+                .collect(),
+        )
+    }
+}
+
 /// Thread-safe in-memory checkpoint storage
 type CheckpointStorage = Arc<RwLock<HashMap<String, Vec<CheckpointEntry>>>>;
 
@@ -449,9 +466,19 @@ type CheckpointStorage = Arc<RwLock<HashMap<String, Vec<CheckpointEntry>>>>;
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct InMemoryCheckpointSaver {
     storage: CheckpointStorage,
+    channel_serializer: Option<Arc<dyn ChannelSerializer>>,
+}
+
+impl std::fmt::Debug for InMemoryCheckpointSaver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemoryCheckpointSaver")
+            .field("storage", &self.storage)
+            .field("channel_serializer", &self.channel_serializer.is_some())
+            .finish()
+    }
 }
 
 impl InMemoryCheckpointSaver {
@@ -459,7 +486,43 @@ impl InMemoryCheckpointSaver {
     pub fn new() -> Self {
         Self {
             storage: Arc::new(RwLock::new(HashMap::new())),
+            channel_serializer: None,
+        }
+    }
+
+    /// Offload the channels `serializer` handles instead of storing them inline
+    ///
+    /// Stored checkpoints hold references for those channels; `get_tuple` and
+    /// `list` load the values back in, and `delete_thread` and `clear` delete
+    /// them from the serializer.
+    pub fn with_channel_serializer(mut self, serializer: Arc<dyn ChannelSerializer>) -> Self {
+        self.channel_serializer = Some(serializer);
+        self
+    }
+
+    /// Build the tuple returned for a stored entry
+    fn to_tuple(&self, entry: &CheckpointEntry) -> Result<CheckpointTuple> {
+        let mut checkpoint = entry.checkpoint.clone();
+        if let Some(channels) = &self.channel_serializer {
+            load_channels(channels.as_ref(), &mut checkpoint)?;
         }
+        Ok(CheckpointTuple {
+            config: entry.config.clone(),
+            checkpoint,
+            metadata: entry.metadata.clone(),
+            parent_config: entry.parent_config.clone(),
+            pending_writes: entry.pending_writes(),
+        })
+    }
+
+    /// Delete the offloaded channel values of removed entries
+    fn delete_offloaded<'a>(&self, entries: impl IntoIterator<Item = &'a CheckpointEntry>) -> Result<()> {
+        if let Some(channels) = &self.channel_serializer {
+            for entry in entries {
+                delete_channels(channels.as_ref(), &entry.checkpoint)?;
+            }
+        }
+        Ok(())
     }
 
     /// Get the number of threads being tracked
@@ -478,8 +541,12 @@ impl InMemoryCheckpointSaver {
     }
 
     /// Clear all checkpoints (useful for testing)
+    ///
+    /// Offloaded channel values are deleted on a best-effort basis; the only
+    /// failure is a poisoned serializer lock.
     pub async fn clear(&self) {
-        self.storage.write().await.clear();
+        let removed = std::mem::take(&mut *self.storage.write().await);
+        let _ = self.delete_offloaded(removed.values().flatten());
     }
 }
 
@@ -503,22 +570,12 @@ impl CheckpointSaver for InMemoryCheckpointSaver {
             if let Some(checkpoint_id) = &config.checkpoint_id {
                 // Find specific checkpoint by ID
                 if let Some(entry) = entries.iter().find(|e| &e.checkpoint.id == checkpoint_id) {
-                    return Ok(Some(CheckpointTuple {
-                        config: entry.config.clone(),
-                        checkpoint: entry.checkpoint.clone(),
-                        metadata: entry.metadata.clone(),
-                        parent_config: entry.parent_config.clone(),
-                    }));
+                    return self.to_tuple(entry).map(Some);
                 }
             } else {
                 // Return the latest checkpoint
                 if let Some(entry) = entries.last() {
-                    return Ok(Some(CheckpointTuple {
-                        config: entry.config.clone(),
-                        checkpoint: entry.checkpoint.clone(),
-                        metadata: entry.metadata.clone(),
-                        parent_config: entry.parent_config.clone(),
-                    }));
+                    return self.to_tuple(entry).map(Some);
                 }
             }
         }
@@ -631,12 +688,7 @@ impl CheckpointSaver for InMemoryCheckpointSaver {
                         }
                     }
 
-                    results.push(Ok(CheckpointTuple {
-                        config: entry.config.clone(),
-                        checkpoint: entry.checkpoint.clone(),
-                        metadata: entry.metadata.clone(),
-                        parent_config: entry.parent_config.clone(),
-                    }));
+                    results.push(self.to_tuple(entry));
 
                     if let Some(lim) = limit {
                         if results.len() >= lim {
@@ -659,7 +711,7 @@ impl CheckpointSaver for InMemoryCheckpointSaver {
     async fn put(
         &self,
         config: &CheckpointConfig,
-        checkpoint: Checkpoint,
+        mut checkpoint: Checkpoint,
         metadata: CheckpointMetadata,
         _new_versions: ChannelVersions,
     ) -> Result<CheckpointConfig> {
@@ -668,6 +720,10 @@ impl CheckpointSaver for InMemoryCheckpointSaver {
             .as_ref()
             .ok_or_else(|| CheckpointError::Invalid("thread_id is required".to_string()))?;
 
+        if let Some(channels) = &self.channel_serializer {
+            offload_channels(channels.as_ref(), &mut checkpoint)?;
+        }
+
         let mut storage = self.storage.write().await;
         let entries = storage.entry(thread_id.clone()).or_insert_with(Vec::new);
 
@@ -729,9 +785,8 @@ impl CheckpointSaver for InMemoryCheckpointSaver {
     }
 
     async fn delete_thread(&self, thread_id: &str) -> Result<()> {
-        let mut storage = self.storage.write().await;
-        storage.remove(thread_id);
-        Ok(())
+        let removed = self.storage.write().await.remove(thread_id);
+        self.delete_offloaded(removed.iter().flatten())
     }
 }
 
@@ -764,6 +819,76 @@ mod tests {
         assert_eq!(tuple.checkpoint.id, checkpoint.id);
     }
 
+    #[tokio::test]
+    async fn test_channel_serializer_offloads_stored_values() {
+        use crate::serializer::{InMemoryChannelSerializer, CHANNEL_REF_KEY};
+
+        let channels = Arc::new(InMemoryChannelSerializer::new(["document"]));
+        let saver = InMemoryCheckpointSaver::new().with_channel_serializer(channels.clone());
+        let config = CheckpointConfig::new().with_thread_id("thread-1".to_string());
+
+        let mut checkpoint = Checkpoint::empty();
+        checkpoint
+            .channel_values
+            .insert("document".to_string(), serde_json::json!({"text": "x".repeat(1024)}));
+        checkpoint
+            .channel_values
+            .insert("user".to_string(), serde_json::json!({ CHANNEL_REF_KEY: "not-a-ref" }));
+
+        let saved_config = saver
+            .put(&config, checkpoint.clone(), CheckpointMetadata::new(), HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(channels.len(), 1);
+
+        // Only a reference is kept in the saver itself
+        let stored = &saver.storage.read().await["thread-1"][0].checkpoint;
+        assert_eq!(
+            stored.channel_values["document"],
+            serde_json::json!({ CHANNEL_REF_KEY: format!("{}/document", checkpoint.id) })
+        );
+
+        let tuple = saver.get_tuple(&saved_config).await.unwrap().unwrap();
+        assert_eq!(tuple.checkpoint.channel_values, checkpoint.channel_values);
+
+        let listed: Vec<_> = saver.list(Some(&config), None, None, None).await.unwrap().collect().await;
+        assert_eq!(listed[0].as_ref().unwrap().checkpoint.channel_values, checkpoint.channel_values);
+    }
+
+    #[tokio::test]
+    async fn test_deleting_checkpoints_deletes_offloaded_values() {
+        use crate::serializer::InMemoryChannelSerializer;
+
+        let channels = Arc::new(InMemoryChannelSerializer::new(["document"]));
+        let saver = InMemoryCheckpointSaver::new().with_channel_serializer(channels.clone());
+
+        for thread in ["thread-1", "thread-2"] {
+            let config = CheckpointConfig::new().with_thread_id(thread.to_string());
+            for _ in 0..2 {
+                let mut checkpoint = Checkpoint::empty();
+                checkpoint
+                    .channel_values
+                    .insert("document".to_string(), serde_json::json!("large"));
+                saver
+                    .put(&config, checkpoint, CheckpointMetadata::new(), HashMap::new())
+                    .await
+                    .unwrap();
+            }
+        }
+        assert_eq!(channels.len(), 4);
+
+        saver.delete_thread("thread-1").await.unwrap();
+        assert_eq!(channels.len(), 2);
+
+        // The other thread's values are still readable
+        let config = CheckpointConfig::new().with_thread_id("thread-2".to_string());
+        let tuple = saver.get_tuple(&config).await.unwrap().unwrap();
+        assert_eq!(tuple.checkpoint.channel_values["document"], "large");
+
+        saver.clear().await;
+        assert!(channels.is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_checkpoints() {
         let saver = InMemoryCheckpointSaver::new();
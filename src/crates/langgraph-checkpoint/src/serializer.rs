@@ -1,7 +1,10 @@
 //! Serialization protocol for checkpoints
 
-use crate::error::Result;
+use crate::checkpoint::Checkpoint;
+use crate::error::{CheckpointError, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
 
 /// Protocol for serializing and deserializing checkpoint data
 ///
@@ -65,6 +68,197 @@ impl SerializerProtocol for BincodeSerializer {
     }
 }
 
+/// Key marking a channel value that was offloaded by a [`ChannelSerializer`]
+pub const CHANNEL_REF_KEY: &str = "__channel_ref__";
+
+/// Key wrapping a stored channel value that would otherwise look like an envelope
+///
+/// A value such as `{"__channel_ref__": "x"}` written by a node is stored as
+/// `{"__channel_escaped__": {"__channel_ref__": "x"}}`, so loading returns it
+/// unchanged instead of treating it as a reference.
+pub const CHANNEL_ESCAPE_KEY: &str = "__channel_escaped__";
+
+/// Hook for storing selected channel values outside the checkpoint itself
+///
+/// Large channel values bloat every checkpoint they appear in. A
+/// `ChannelSerializer` moves the values of the channels it handles to a side
+/// store, leaving `{"__channel_ref__": <reference>}` in their place. Savers
+/// apply it with [`offload_channels`] and [`load_channels`], and release the
+/// stored values of deleted checkpoints with [`delete_channels`]; attach one to the
+/// in-memory saver with
+/// [`InMemoryCheckpointSaver::with_channel_serializer`](crate::InMemoryCheckpointSaver::with_channel_serializer).
+pub trait ChannelSerializer: Send + Sync {
+    /// Whether values of `channel` are stored by this serializer
+    fn handles(&self, channel: &str) -> bool;
+
+    /// Store a channel value, returning the reference kept in the checkpoint
+    fn offload(&self, checkpoint_id: &str, channel: &str, value: &serde_json::Value) -> Result<serde_json::Value>;
+
+    /// Load a channel value from a reference returned by [`offload`](Self::offload)
+    fn load(&self, reference: &serde_json::Value) -> Result<serde_json::Value>;
+
+    /// Remove the value stored under a reference returned by
+    /// [`offload`](Self::offload)
+    ///
+    /// Default implementation keeps the value, for stores that expire
+    /// values on their own.
+    fn delete(&self, reference: &serde_json::Value) -> Result<()> {
+        let _ = reference;
+        Ok(())
+    }
+}
+
+/// The reserved key and inner value of a single-key envelope object
+fn envelope(value: &serde_json::Value) -> Option<(&str, &serde_json::Value)> {
+    let (key, inner) = value
+        .as_object()
+        .filter(|obj| obj.len() == 1)?
+        .iter()
+        .next()?;
+    [CHANNEL_REF_KEY, CHANNEL_ESCAPE_KEY]
+        .contains(&key.as_str())
+        .then_some((key.as_str(), inner))
+}
+
+/// Replace the values of the channels `channels` handles with references
+///
+/// Other values shaped like an envelope are escaped with
+/// [`CHANNEL_ESCAPE_KEY`]. Reverse with [`load_channels`].
+pub fn offload_channels(channels: &dyn ChannelSerializer, checkpoint: &mut Checkpoint) -> Result<()> {
+    for (channel, value) in checkpoint.channel_values.iter_mut() {
+        if channels.handles(channel) {
+            let reference = channels.offload(&checkpoint.id, channel, value)?;
+            *value = serde_json::json!({ CHANNEL_REF_KEY: reference });
+        } else if envelope(value).is_some() {
+            *value = serde_json::json!({ CHANNEL_ESCAPE_KEY: value.take() });
+        }
+    }
+    Ok(())
+}
+
+/// Load offloaded channel values back in and unescape the rest
+pub fn load_channels(channels: &dyn ChannelSerializer, checkpoint: &mut Checkpoint) -> Result<()> {
+    for value in checkpoint.channel_values.values_mut() {
+        let loaded = match envelope(value) {
+            Some((CHANNEL_REF_KEY, reference)) => channels.load(reference)?,
+            Some((_, escaped)) => escaped.clone(),
+            None => continue,
+        };
+        *value = loaded;
+    }
+    Ok(())
+}
+
+/// Delete the offloaded values a stored checkpoint refers to
+///
+/// Call when the checkpoint itself is removed from storage.
+pub fn delete_channels(channels: &dyn ChannelSerializer, checkpoint: &Checkpoint) -> Result<()> {
+    for value in checkpoint.channel_values.values() {
+        if let Some((CHANNEL_REF_KEY, reference)) = envelope(value) {
+            channels.delete(reference)?;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize a checkpoint, offloading the channels `channels` handles
+pub fn dumps_checkpoint<S: SerializerProtocol>(
+    serializer: &S,
+    channels: &dyn ChannelSerializer,
+    checkpoint: &Checkpoint,
+) -> Result<Vec<u8>> {
+    let mut checkpoint = checkpoint.copy();
+    offload_channels(channels, &mut checkpoint)?;
+    serializer.dumps(&checkpoint)
+}
+
+/// Deserialize a checkpoint written by [`dumps_checkpoint`], loading offloaded
+/// channel values back in
+pub fn loads_checkpoint<S: SerializerProtocol>(
+    serializer: &S,
+    channels: &dyn ChannelSerializer,
+    data: &[u8],
+) -> Result<Checkpoint> {
+    let mut checkpoint: Checkpoint = serializer.loads(data)?;
+    load_channels(channels, &mut checkpoint)?;
+    Ok(checkpoint)
+}
+
+/// [`ChannelSerializer`] keeping offloaded values in memory
+///
+/// Values are stored as JSON bytes under `"{checkpoint_id}/{channel}"`, which
+/// is also the reference written to the checkpoint.
+#[derive(Debug, Default)]
+pub struct InMemoryChannelSerializer {
+    channels: HashSet<String>,
+    blobs: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryChannelSerializer {
+    /// Offload the values of the named channels
+    pub fn new<I, S>(channels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            channels: channels.into_iter().map(Into::into).collect(),
+            blobs: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Number of stored values
+    pub fn len(&self) -> usize {
+        self.blobs.read().map(|blobs| blobs.len()).unwrap_or(0)
+    }
+
+    /// Whether no values are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ChannelSerializer for InMemoryChannelSerializer {
+    fn handles(&self, channel: &str) -> bool {
+        self.channels.contains(channel)
+    }
+
+    fn offload(&self, checkpoint_id: &str, channel: &str, value: &serde_json::Value) -> Result<serde_json::Value> {
+        let key = format!("{}/{}", checkpoint_id, channel);
+        let bytes = serde_json::to_vec(value)?;
+        self.blobs
+            .write()
+            .map_err(|e| CheckpointError::Storage(e.to_string()))?
+            .insert(key.clone(), bytes);
+        Ok(serde_json::Value::String(key))
+    }
+
+    fn load(&self, reference: &serde_json::Value) -> Result<serde_json::Value> {
+        let key = reference.as_str().ok_or_else(|| {
+            CheckpointError::Invalid(format!("Channel reference must be a string, got {}", reference))
+        })?;
+        let blobs = self
+            .blobs
+            .read()
+            .map_err(|e| CheckpointError::Storage(e.to_string()))?;
+        let bytes = blobs
+            .get(key)
+            .ok_or_else(|| CheckpointError::NotFound(format!("Offloaded channel value '{}'", key)))?;
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    fn delete(&self, reference: &serde_json::Value) -> Result<()> {
+        let key = reference.as_str().ok_or_else(|| {
+            CheckpointError::Invalid(format!("Channel reference must be a string, got {}", reference))
+        })?;
+        self.blobs
+            .write()
+            .map_err(|e| CheckpointError::Storage(e.to_string()))?
+            .remove(key);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +311,61 @@ mod tests {
 
         assert_eq!(data, restored);
     }
+
+    #[test]
+    fn test_channel_serializer_offloads_and_rehydrates() {
+        let mut checkpoint = Checkpoint::empty();
+        let document = "x".repeat(64 * 1024);
+        checkpoint
+            .channel_values
+            .insert("document".to_string(), serde_json::json!({"text": document}));
+        checkpoint
+            .channel_values
+            .insert("status".to_string(), serde_json::json!("ready"));
+
+        let serializer = JsonSerializer::new();
+        let channels = InMemoryChannelSerializer::new(["document"]);
+
+        let bytes = dumps_checkpoint(&serializer, &channels, &checkpoint).unwrap();
+        assert!(bytes.len() < 1024, "large channel should not be inline");
+        assert_eq!(channels.len(), 1);
+
+        // The main checkpoint only holds a reference
+        let stored: Checkpoint = serializer.loads(&bytes).unwrap();
+        assert_eq!(
+            stored.channel_values["document"],
+            serde_json::json!({ CHANNEL_REF_KEY: format!("{}/document", checkpoint.id) })
+        );
+        assert_eq!(stored.channel_values["status"], serde_json::json!("ready"));
+
+        let restored = loads_checkpoint(&serializer, &channels, &bytes).unwrap();
+        assert_eq!(restored.channel_values, checkpoint.channel_values);
+    }
+
+    #[test]
+    fn test_envelope_shaped_values_round_trip() {
+        let mut checkpoint = Checkpoint::empty();
+        checkpoint
+            .channel_values
+            .insert("user".to_string(), serde_json::json!({ CHANNEL_REF_KEY: "not-a-ref" }));
+        checkpoint
+            .channel_values
+            .insert("nested".to_string(), serde_json::json!({ CHANNEL_ESCAPE_KEY: 1 }));
+        checkpoint
+            .channel_values
+            .insert("document".to_string(), serde_json::json!({ CHANNEL_REF_KEY: "offloaded" }));
+
+        let serializer = JsonSerializer::new();
+        let channels = InMemoryChannelSerializer::new(["document"]);
+
+        let bytes = dumps_checkpoint(&serializer, &channels, &checkpoint).unwrap();
+        let stored: Checkpoint = serializer.loads(&bytes).unwrap();
+        assert_eq!(
+            stored.channel_values["user"],
+            serde_json::json!({ CHANNEL_ESCAPE_KEY: { CHANNEL_REF_KEY: "not-a-ref" } })
+        );
+
+        let restored = loads_checkpoint(&serializer, &channels, &bytes).unwrap();
+        assert_eq!(restored.channel_values, checkpoint.channel_values);
+    }
 }
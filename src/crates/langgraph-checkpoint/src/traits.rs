@@ -148,6 +148,7 @@
 //!             checkpoint,
 //!             metadata,
 //!             parent_config: None,
+//!             pending_writes: None,
 //!         }))
 //!     }
 //!
@@ -295,6 +296,7 @@
 //!                 checkpoint,
 //!                 metadata,
 //!                 parent_config: None,
+//!                 pending_writes: None,
 //!             }))
 //!         } else {
 //!             Ok(None)
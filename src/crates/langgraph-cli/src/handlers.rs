@@ -0,0 +1,288 @@
+//! Handlers used by `langgraph run` and `langgraph viz`
+//!
+//! YAML graphs name their node handlers and conditions as strings, resolved
+//! against a [`YamlHandlers`] set. `run` starts from built-in demo handlers
+//! that match the `init`/`new` templates, and `--handlers <file>` registers
+//! more from a YAML file:
+//!
+//! ```yaml
+//! nodes:
+//!   summarize:
+//!     # Receives the state as JSON on stdin, prints the node output as JSON
+//!     command: ["python3", "summarize.py"]
+//! conditions:
+//!   by_intent:
+//!     # Routes to the branch named by the `intent` state key
+//!     key: intent
+//!     default: other
+//! ```
+//!
+//! Commands run in the handlers file's directory.
+
+use langgraph_core::send::ConditionalEdgeResult;
+use langgraph_core::yaml::{YamlEdgeDef, YamlGraphDef, YamlHandlers, YamlRouter};
+use langgraph_core::NodeExecutor;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+
+/// Handlers registered from a `--handlers` file
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HandlersConfig {
+    /// Node handlers by name
+    #[serde(default)]
+    pub nodes: BTreeMap<String, CommandHandlerDef>,
+    /// Conditions by name
+    #[serde(default)]
+    pub conditions: BTreeMap<String, KeyConditionDef>,
+}
+
+/// Node handler that runs an external command
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommandHandlerDef {
+    /// Program and arguments
+    pub command: Vec<String>,
+}
+
+/// Condition that routes on a string state key
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeyConditionDef {
+    /// State key holding the branch name
+    pub key: String,
+    /// Branch used when the key is missing or not a string; ends the graph
+    /// when unset
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+impl HandlersConfig {
+    /// Parse a handlers file
+    pub fn from_str(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    /// Build the handlers, running commands in `dir`
+    pub fn into_handlers(self, dir: &Path) -> Result<YamlHandlers, String> {
+        let mut handlers = YamlHandlers::new();
+        for (name, def) in self.nodes {
+            if def.command.is_empty() {
+                return Err(format!("Handler '{}' has an empty command", name));
+            }
+            handlers = handlers.with_node(name, command_node(def.command, dir.to_path_buf()));
+        }
+        for (name, def) in self.conditions {
+            handlers = handlers.with_condition(name, key_condition(def.key, def.default));
+        }
+        Ok(handlers)
+    }
+}
+
+/// Load the handlers registered in a `--handlers` file
+pub fn load_handlers(path: &Path) -> Result<YamlHandlers, Box<dyn std::error::Error>> {
+    let config = HandlersConfig::from_str(&std::fs::read_to_string(path)?)
+        .map_err(|e| format!("Invalid handlers file {}: {}", path.display(), e))?;
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    Ok(config.into_handlers(dir)?)
+}
+
+/// The built-in demo handlers
+///
+/// - `process_handler` sets `processed: true`
+/// - `router_handler` passes the state through unchanged
+/// - `path_a_handler` / `path_b_handler` set `path` to `"a"` / `"b"`
+/// - `route_condition` routes to the branch named by the `route` key,
+///   defaulting to `"a"`
+pub fn builtin_handlers() -> YamlHandlers {
+    YamlHandlers::new()
+        .with_node("process_handler", set_field("processed", Value::Bool(true)))
+        .with_node("router_handler", passthrough())
+        .with_node("path_a_handler", set_field("path", Value::from("a")))
+        .with_node("path_b_handler", set_field("path", Value::from("b")))
+        .with_condition(
+            "route_condition",
+            key_condition("route".to_string(), Some("a".to_string())),
+        )
+}
+
+/// Handlers accepting every name used by `graph_def`, for when only the
+/// graph's shape matters (e.g. `langgraph viz`)
+pub fn placeholder_handlers(graph_def: &YamlGraphDef) -> YamlHandlers {
+    let to_end: YamlRouter = Arc::new(|_: &Value| ConditionalEdgeResult::Node("__end__".into()));
+
+    let mut handlers = YamlHandlers::new();
+    for node in graph_def.nodes.values() {
+        handlers = handlers.with_node(node.handler.clone(), passthrough());
+    }
+    for edge in &graph_def.edges {
+        if let YamlEdgeDef::Conditional { condition, .. } = edge {
+            handlers = handlers.with_condition(condition.clone(), to_end.clone());
+        }
+    }
+    handlers
+}
+
+fn passthrough() -> NodeExecutor {
+    Arc::new(|state| Box::pin(async move { Ok(state) }))
+}
+
+/// Node handler that sets `key` to `value` on an object state
+fn set_field(key: &'static str, value: Value) -> NodeExecutor {
+    Arc::new(move |mut state| {
+        if let Some(obj) = state.as_object_mut() {
+            obj.insert(key.to_string(), value.clone());
+        }
+        Box::pin(async move { Ok(state) })
+    })
+}
+
+/// Condition routing to the branch named by the string at `key`
+fn key_condition(key: String, default: Option<String>) -> YamlRouter {
+    Arc::new(move |state: &Value| {
+        let route = state
+            .get(&key)
+            .and_then(Value::as_str)
+            .or(default.as_deref())
+            .unwrap_or("__end__");
+        ConditionalEdgeResult::Node(route.to_string())
+    })
+}
+
+/// Node handler piping the state through `command` as JSON
+fn command_node(command: Vec<String>, dir: PathBuf) -> NodeExecutor {
+    Arc::new(move |state| {
+        let command = command.clone();
+        let dir = dir.clone();
+        Box::pin(async move {
+            let mut child = tokio::process::Command::new(&command[0])
+                .args(&command[1..])
+                .current_dir(&dir)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .map_err(|e| format!("Failed to start {:?}: {}", command, e))?;
+
+            let input = serde_json::to_vec(&state)?;
+            let mut stdin = child.stdin.take().expect("stdin is piped");
+            // Write concurrently so a command printing before it has read
+            // all of its input can't deadlock on a full pipe. Commands that
+            // ignore their input close the pipe early, which is fine.
+            let write = async move {
+                match stdin.write_all(&input).await {
+                    Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e),
+                    _ => Ok(()),
+                }
+            };
+            let (written, output) = tokio::join!(write, child.wait_with_output());
+            let output = output?;
+
+            if !output.status.success() {
+                return Err(format!(
+                    "{:?} failed ({}): {}",
+                    command,
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )
+                .into());
+            }
+            written?;
+
+            serde_json::from_slice(&output.stdout)
+                .map_err(|e| format!("{:?} did not print a JSON value: {}", command, e).into())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_builtin_names() {
+        let handlers = builtin_handlers();
+
+        assert_eq!(
+            handlers.node_names(),
+            vec![
+                "path_a_handler",
+                "path_b_handler",
+                "process_handler",
+                "router_handler"
+            ]
+        );
+        assert_eq!(handlers.condition_names(), vec!["route_condition"]);
+    }
+
+    #[test]
+    fn test_config_registers_handlers() {
+        let config = HandlersConfig::from_str(
+            r#"
+nodes:
+  echo:
+    command: ["cat"]
+conditions:
+  by_intent:
+    key: intent
+"#,
+        )
+        .unwrap();
+        let handlers = builtin_handlers().merge(config.into_handlers(Path::new(".")).unwrap());
+
+        assert!(handlers.node_names().contains(&"echo"));
+        assert!(handlers.node_names().contains(&"process_handler"));
+        assert_eq!(
+            handlers.condition_names(),
+            vec!["by_intent", "route_condition"]
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_empty_command() {
+        let config = HandlersConfig::from_str("nodes:\n  broken:\n    command: []\n").unwrap();
+
+        let err = config.into_handlers(Path::new(".")).unwrap_err();
+        assert_eq!(err, "Handler 'broken' has an empty command");
+    }
+
+    #[test]
+    fn test_key_condition_falls_back_to_default() {
+        let with_default = key_condition("intent".to_string(), Some("other".to_string()));
+        let without_default = key_condition("intent".to_string(), None);
+
+        let route = |router: &YamlRouter, state: Value| match router(&state) {
+            ConditionalEdgeResult::Node(node) => node,
+            other => panic!("expected a node, got {:?}", other),
+        };
+        assert_eq!(route(&with_default, json!({"intent": "refund"})), "refund");
+        assert_eq!(route(&with_default, json!({})), "other");
+        assert_eq!(route(&without_default, json!({})), "__end__");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_command_node_pipes_state_as_json() {
+        let node = command_node(vec!["cat".to_string()], PathBuf::from("."));
+        let output = node(json!({"input": "hi"})).await.unwrap();
+        assert_eq!(output, json!({"input": "hi"}));
+
+        let failing = command_node(
+            vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "echo nope >&2; exit 3".to_string(),
+            ],
+            PathBuf::from("."),
+        );
+        let err = failing(json!({})).await.unwrap_err().to_string();
+        assert!(err.contains("nope"), "{}", err);
+    }
+}
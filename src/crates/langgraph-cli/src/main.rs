@@ -2,7 +2,10 @@
 //!
 //! CLI tool for rLangGraph development and management.
 
-use clap::{Parser, Subcommand};
+mod handlers;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use langgraph_core::VisualizationFormat;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -56,9 +59,45 @@ enum Commands {
         /// Input JSON
         #[arg(short, long)]
         input: Option<String>,
+
+        /// YAML file registering handlers beyond the built-in ones
+        #[arg(long)]
+        handlers: Option<PathBuf>,
+    },
+
+    /// Render a YAML graph definition as a diagram
+    Viz {
+        /// Path to YAML file
+        file: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = VizFormat::Mermaid)]
+        format: VizFormat,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        out: Option<PathBuf>,
     },
 }
 
+/// Diagram formats supported by `viz`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum VizFormat {
+    Mermaid,
+    Dot,
+    Ascii,
+}
+
+impl From<VizFormat> for VisualizationFormat {
+    fn from(format: VizFormat) -> Self {
+        match format {
+            VizFormat::Mermaid => VisualizationFormat::Mermaid,
+            VizFormat::Dot => VisualizationFormat::Dot,
+            VizFormat::Ascii => VisualizationFormat::Ascii,
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
@@ -81,9 +120,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Checking graph structure: {}", file.display());
             check_graph(&file)?;
         }
-        Commands::Run { file, input } => {
-            println!("Running: {}", file.display());
-            run_graph(&file, input.as_deref())?;
+        Commands::Run {
+            file,
+            input,
+            handlers,
+        } => {
+            eprintln!("Running: {}", file.display());
+            run_graph(&file, input.as_deref(), handlers.as_deref())?;
+        }
+        Commands::Viz { file, format, out } => {
+            visualize_graph(&file, format, out.as_ref())?;
         }
     }
 
@@ -181,6 +227,17 @@ edges:
 fn create_graph(name: &str, template: &str) -> Result<(), Box<dyn std::error::Error>> {
     use std::fs;
 
+    let yaml_content = template_yaml(name, template)?;
+
+    let filename = format!("graphs/{}.yaml", name);
+    fs::create_dir_all("graphs")?;
+    fs::write(&filename, yaml_content)?;
+
+    println!("✓ Created graph: {}", filename);
+    Ok(())
+}
+
+fn template_yaml(name: &str, template: &str) -> Result<String, Box<dyn std::error::Error>> {
     let yaml_content = match template {
         "simple" => format!(
             r#"name: {}
@@ -232,16 +289,11 @@ edges:
         }
     };
 
-    let filename = format!("graphs/{}.yaml", name);
-    fs::create_dir_all("graphs")?;
-    fs::write(&filename, yaml_content)?;
-
-    println!("✓ Created graph: {}", filename);
-    Ok(())
+    Ok(yaml_content)
 }
 
 fn validate_yaml(file: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-    use langgraph_core::yaml::YamlGraphDef;
+    use langgraph_core::yaml::{YamlEdgeDef, YamlGraphDef};
 
     let graph_def = YamlGraphDef::from_file(file)?;
     graph_def.validate()?;
@@ -250,6 +302,14 @@ fn validate_yaml(file: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
     println!("  Graph name: {}", graph_def.name);
     println!("  Nodes: {}", graph_def.nodes.len());
     println!("  Edges: {}", graph_def.edges.len());
+    let fan_outs = graph_def
+        .edges
+        .iter()
+        .filter(|edge| matches!(edge, YamlEdgeDef::FanOut { .. }))
+        .count();
+    if fan_outs > 0 {
+        println!("  Fan-out edges: {}", fan_outs);
+    }
     println!("  Entry point: {}", graph_def.entry);
 
     Ok(())
@@ -268,6 +328,17 @@ fn check_graph(file: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
         println!("  Description: {}", desc);
     }
     println!("  Entry point: {}", graph_def.entry);
+    if let Some(state) = &graph_def.state {
+        println!("\nState ({}):", state.len());
+        for (name, channel) in state {
+            match &channel.reducer {
+                Some(reducer) => {
+                    println!("  - {}: {:?} (reducer: {})", name, channel.channel_type, reducer)
+                }
+                None => println!("  - {}: {:?}", name, channel.channel_type),
+            }
+        }
+    }
     println!("\nNodes ({}):", graph_def.nodes.len());
     for (name, node) in &graph_def.nodes {
         println!("  - {}: {}", name, node.handler);
@@ -286,20 +357,99 @@ fn check_graph(file: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
                 from,
                 condition,
                 branches,
+                ..
             } => {
                 println!("  - {} -> [conditional: {}]", from, condition);
                 for (key, target) in branches {
                     println!("      {} -> {}", key, target);
                 }
             }
+            langgraph_core::yaml::YamlEdgeDef::FanOut { from, map_over, to } => {
+                println!("  - {} -> [fan-out over '{}'] -> {}", from, map_over, to);
+            }
         }
     }
 
     Ok(())
 }
 
-fn run_graph(_file: &PathBuf, _input: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Graph execution not yet implemented");
-    println!("This feature requires runtime handler resolution");
+fn run_graph(
+    file: &PathBuf,
+    input: Option<&str>,
+    handlers_file: Option<&std::path::Path>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use langgraph_core::yaml::YamlGraphDef;
+
+    let graph_def = YamlGraphDef::from_file(file)?;
+    graph_def.validate()?;
+
+    let input: serde_json::Value = match input {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| format!("Invalid --input JSON: {}", e))?,
+        None => serde_json::json!({}),
+    };
+
+    let mut handlers = handlers::builtin_handlers();
+    if let Some(path) = handlers_file {
+        handlers = handlers.merge(handlers::load_handlers(path)?);
+    }
+    let compiled = graph_def.compile(&handlers)?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let result = runtime.block_on(compiled.invoke(input))?;
+
+    println!("{}", serde_json::to_string_pretty(&result)?);
+    Ok(())
+}
+
+fn visualize_graph(
+    file: &PathBuf,
+    format: VizFormat,
+    out: Option<&PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let diagram = render_graph(file, format)?;
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, diagram)?;
+            eprintln!("✓ Wrote {:?} diagram to {}", format, path.display());
+        }
+        None => print!("{}", diagram),
+    }
+
     Ok(())
 }
+
+fn render_graph(file: &PathBuf, format: VizFormat) -> Result<String, Box<dyn std::error::Error>> {
+    use langgraph_core::yaml::YamlGraphDef;
+    use langgraph_core::{visualize, VisualizationOptions};
+
+    let graph_def = YamlGraphDef::from_file(file)?;
+    let graph = graph_def.build(&handlers::placeholder_handlers(&graph_def))?;
+
+    let options = VisualizationOptions {
+        format: format.into(),
+        ..Default::default()
+    }
+    .with_title(&graph_def.name);
+
+    Ok(visualize(graph.graph(), &options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_conditional_template_as_mermaid() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("routing.yaml");
+        std::fs::write(&file, template_yaml("routing", "conditional").unwrap()).unwrap();
+
+        let diagram = render_graph(&file, VizFormat::Mermaid).unwrap();
+
+        assert!(diagram.contains("graph TD"));
+        assert!(diagram.contains("router -->|a| path_a"));
+        assert!(diagram.contains("router -->|b| path_b"));
+    }
+}
@@ -5,6 +5,7 @@
 //! - Graph creation from templates
 //! - YAML validation
 //! - Graph structure checking
+//! - Running graphs with built-in and registered handlers
 
 use std::fs;
 use std::path::PathBuf;
@@ -636,3 +637,107 @@ fn test_graphs_directory_isolation() {
     assert!(!graphs_dir.join("main.rs").exists());
     assert!(!src_dir.join("graph.yaml").exists());
 }
+
+#[test]
+fn test_run_simple_graph_end_to_end() {
+    let temp_dir = create_test_dir();
+    let yaml_file = temp_dir.path().join("example.yaml");
+    create_example_yaml(&yaml_file);
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_langgraph"))
+        .arg("run")
+        .arg(&yaml_file)
+        .arg("--input")
+        .arg(r#"{"input": "Hello, LangGraph!"}"#)
+        .output()
+        .expect("Failed to run langgraph");
+
+    assert!(
+        output.status.success(),
+        "run failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let state: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(state["input"], "Hello, LangGraph!");
+    assert_eq!(state["processed"], true);
+}
+
+#[test]
+fn test_run_unknown_handler_lists_available() {
+    let temp_dir = create_test_dir();
+    let yaml_file = temp_dir.path().join("unknown.yaml");
+    create_example_yaml(&yaml_file);
+    let yaml_content = fs::read_to_string(&yaml_file)
+        .unwrap()
+        .replace("process_handler", "missing_handler");
+    fs::write(&yaml_file, yaml_content).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_langgraph"))
+        .arg("run")
+        .arg(&yaml_file)
+        .output()
+        .expect("Failed to run langgraph");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No handler registered for 'missing_handler'"));
+    assert!(stderr.contains("Available handlers: path_a_handler"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_with_registered_handlers() {
+    let temp_dir = create_test_dir();
+    let yaml_file = temp_dir.path().join("triage.yaml");
+    let yaml_content = r#"name: triage
+entry: classify
+
+nodes:
+  classify:
+    handler: "classify_handler"
+  refund:
+    handler: "process_handler"
+
+edges:
+  - from: "__start__"
+    to: "classify"
+  - from: "classify"
+    condition: "by_intent"
+    branches:
+      refund: "refund"
+  - from: "refund"
+    to: "__end__"
+"#;
+    fs::write(&yaml_file, yaml_content).unwrap();
+    fs::write(
+        temp_dir.path().join("classify.sh"),
+        "echo '{\"intent\": \"refund\"}'\n",
+    )
+    .unwrap();
+    let handlers_file = temp_dir.path().join("handlers.yaml");
+    let handlers_content = r#"nodes:
+  classify_handler:
+    command: ["sh", "classify.sh"]
+conditions:
+  by_intent:
+    key: intent
+"#;
+    fs::write(&handlers_file, handlers_content).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_langgraph"))
+        .arg("run")
+        .arg(&yaml_file)
+        .arg("--handlers")
+        .arg(&handlers_file)
+        .output()
+        .expect("Failed to run langgraph");
+
+    assert!(
+        output.status.success(),
+        "run failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let state: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(state["intent"], "refund");
+    assert_eq!(state["processed"], true);
+}
@@ -292,11 +292,12 @@
 //! - [`Send`](crate::Send) - Dynamic message passing
 //! - [`InterruptConfig`](crate::interrupt::InterruptConfig) - Human-in-the-loop configuration
 
-use crate::graph::{ChannelSpec, ChannelType, Graph, NodeExecutor, NodeId, NodeSpec, ReducerFn, END};
+use crate::graph::{ChannelSpec, ChannelType, Edge, Graph, NodeExecutor, NodeId, NodeSpec, ReducerFn, END, START};
 use crate::compiled::CompiledGraph;
 use crate::error::{GraphError, Result};
 use crate::interrupt::InterruptConfig;
-use std::collections::HashMap;
+use crate::pregel::{CachePolicy, SuperstepCallback};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 /// Builder for constructing state graphs with shared state management
@@ -382,6 +383,8 @@ use std::sync::Arc;
 /// ```
 pub struct StateGraph {
     graph: Graph,
+    /// Nodes each node may jump to by returning a `Command` goto
+    goto_destinations: HashMap<NodeId, Vec<NodeId>>,
 }
 
 impl StateGraph {
@@ -401,6 +404,7 @@ impl StateGraph {
     pub fn new() -> Self {
         let mut graph = Self {
             graph: Graph::new(),
+            goto_destinations: HashMap::new(),
         };
 
         // Add a default shared state channel for proper state sharing
@@ -625,6 +629,7 @@ impl StateGraph {
             reads: vec![],
             writes: vec![],
             subgraph: None,
+            cache_policy: None,
         };
 
         self.graph.add_node(id.clone(), spec);
@@ -667,6 +672,7 @@ impl StateGraph {
             reads: vec![],
             writes: vec![],
             subgraph: None,
+            cache_policy: None,
         };
         self.add_node_spec(id, spec)
     }
@@ -727,6 +733,7 @@ impl StateGraph {
             reads: vec![],
             writes: vec![],
             subgraph: Some(subgraph_arc),
+            cache_policy: None,
         };
 
         self.graph.add_node(id, spec);
@@ -825,6 +832,45 @@ impl StateGraph {
         self
     }
 
+    /// Cache results of an existing node, keyed by a hash of its input
+    ///
+    /// Repeated executions with identical input (within the same compiled
+    /// graph, across invocations) return the cached output instead of running
+    /// the node again.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node ID to cache
+    /// * `policy` - Cache key derivation and TTL
+    pub fn set_cache_policy(&mut self, node: impl Into<NodeId>, policy: CachePolicy) -> &mut Self {
+        if let Some(spec) = self.graph.nodes.get_mut(&node.into()) {
+            spec.cache_policy = Some(policy);
+        }
+        self
+    }
+
+    /// Declare the nodes `node` may jump to by returning a `Command` goto
+    ///
+    /// No edges are added. Compile-time cycle detection treats the
+    /// destinations like conditional branches, so a loop whose only way out
+    /// is a goto still compiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node ID returning the `Command`
+    /// * `destinations` - Node IDs (or `__end__`) its goto may target
+    pub fn add_goto_destinations<I, S>(&mut self, node: impl Into<NodeId>, destinations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<NodeId>,
+    {
+        self.goto_destinations
+            .entry(node.into())
+            .or_default()
+            .extend(destinations.into_iter().map(Into::into));
+        self
+    }
+
     /// Add a finish point (edge to END)
     ///
     /// # Arguments
@@ -892,6 +938,24 @@ impl StateGraph {
     /// - The graph has circular dependencies without exit conditions
     /// - Channel configurations are invalid
     ///
+    /// # Ownership
+    ///
+    /// Compiling consumes the builder, so a node or edge can't be added to a
+    /// graph that has already been compiled, where it would silently have no
+    /// effect. Build a new `StateGraph` instead:
+    ///
+    /// ```rust,compile_fail
+    /// use langgraph_core::StateGraph;
+    ///
+    /// let mut graph = StateGraph::new();
+    /// graph.add_node("process", |state| Box::pin(async move { Ok(state) }));
+    /// graph.add_edge("__start__", "process");
+    /// graph.add_edge("process", "__end__");
+    ///
+    /// let compiled = graph.compile().unwrap();
+    /// graph.add_node("late", |state| Box::pin(async move { Ok(state) })); // error: use of moved value
+    /// ```
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -933,7 +997,29 @@ impl StateGraph {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn compile(mut self) -> Result<CompiledGraph> {
+    pub fn compile(self) -> Result<CompiledGraph> {
+        self.compile_with_options(CompileOptions::default())
+    }
+
+    /// Compile the graph with explicit [`CompileOptions`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::StateGraph;
+    /// use langgraph_core::builder::CompileOptions;
+    ///
+    /// let mut graph = StateGraph::new();
+    /// graph.add_node("process", |state| Box::pin(async move { Ok(state) }));
+    /// graph.add_edge("__start__", "process");
+    /// graph.add_edge("process", "__end__");
+    ///
+    /// let compiled = graph
+    ///     .compile_with_options(CompileOptions { recursion_limit: 10, ..Default::default() })
+    ///     .unwrap();
+    /// assert_eq!(compiled.recursion_limit(), 10);
+    /// ```
+    pub fn compile_with_options(mut self, options: CompileOptions) -> Result<CompiledGraph> {
         // Fix up node specs to ensure proper state sharing in StateGraph
         // If there's a shared state channel, all nodes should read from and write to it
         let has_state_channel = self.graph.channels.contains_key("state");
@@ -974,10 +1060,14 @@ impl StateGraph {
         }
 
         // Validate the graph structure
-        self.graph.validate().map_err(GraphError::Validation)?;
+        self.validate()?;
 
         // Create compiled graph
-        CompiledGraph::new(self.graph)
+        let compiled = CompiledGraph::new(self.graph)?.with_recursion_limit(options.recursion_limit);
+        Ok(match options.on_superstep {
+            Some(callback) => compiled.with_on_superstep(callback),
+            None => compiled,
+        })
     }
 
     /// Compile the graph with a store for persistent state
@@ -995,7 +1085,7 @@ impl StateGraph {
     /// Returns an error if the graph structure is invalid
     pub fn compile_with_store(self, store: Arc<dyn crate::store::Store>) -> Result<CompiledGraph> {
         // Validate the graph structure
-        self.graph.validate().map_err(GraphError::Validation)?;
+        self.validate()?;
 
         // Create compiled graph with store
         CompiledGraph::new(self.graph).map(|g| g.with_store(store))
@@ -1016,12 +1106,35 @@ impl StateGraph {
     /// Returns an error if the graph structure is invalid
     pub fn compile_with_interrupts(self, interrupt_config: InterruptConfig) -> Result<CompiledGraph> {
         // Validate the graph structure
-        self.graph.validate().map_err(GraphError::Validation)?;
+        self.validate()?;
 
         // Create compiled graph with interrupts
         CompiledGraph::new_with_interrupts(self.graph, interrupt_config)
     }
 
+    /// Validate structure and reject cycles that can never reach `__end__`
+    fn validate(&self) -> Result<()> {
+        self.graph.validate().map_err(GraphError::Validation)?;
+
+        for (node, destinations) in &self.goto_destinations {
+            if let Some(unknown) = destinations
+                .iter()
+                .find(|to| *to != END && !self.graph.nodes.contains_key(*to))
+            {
+                return Err(GraphError::Validation(format!(
+                    "Goto destination '{}' of node '{}' does not exist",
+                    unknown, node
+                )));
+            }
+        }
+
+        if let Some(path) = find_unterminated_cycle(&self.graph, &self.goto_destinations) {
+            return Err(GraphError::CycleDetected { path });
+        }
+
+        Ok(())
+    }
+
     /// Get a reference to the underlying graph
     pub fn graph(&self) -> &Graph {
         &self.graph
@@ -1039,6 +1152,256 @@ impl Default for StateGraph {
     }
 }
 
+/// Default superstep limit per invocation, matching Python LangGraph's
+/// `recursion_limit` of 25.
+pub const DEFAULT_RECURSION_LIMIT: usize = 25;
+
+/// Options applied when compiling a [`StateGraph`]
+///
+/// # Example
+///
+/// ```rust
+/// use langgraph_core::builder::CompileOptions;
+///
+/// let options = CompileOptions {
+///     recursion_limit: 50,
+///     ..Default::default()
+/// };
+/// assert_eq!(options.recursion_limit, 50);
+/// ```
+#[derive(Clone)]
+pub struct CompileOptions {
+    /// Maximum number of supersteps per invocation before execution fails with
+    /// [`GraphError::RecursionLimitReached`] (default:
+    /// [`DEFAULT_RECURSION_LIMIT`], 25)
+    ///
+    /// Can be overridden for a single run with
+    /// [`CheckpointConfig::with_recursion_limit`](langgraph_checkpoint::CheckpointConfig::with_recursion_limit).
+    pub recursion_limit: usize,
+    /// Called after every superstep with its step number, task count, and
+    /// elapsed time, for profiling without subscribing to stream events
+    pub on_superstep: Option<SuperstepCallback>,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            on_superstep: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for CompileOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompileOptions")
+            .field("recursion_limit", &self.recursion_limit)
+            .field("on_superstep", &self.on_superstep.is_some())
+            .finish()
+    }
+}
+
+/// Find a cycle that has no conditional exit leading to `__end__`.
+///
+/// Cycles are found as strongly connected components over direct edges,
+/// declared conditional branches and declared goto destinations. A cycle is
+/// considered terminating when one of its nodes has a conditional branch or
+/// goto destination outside the cycle from which `__end__` is reachable.
+/// Conditional edges without declared branches may route anywhere, so they
+/// always count as an exit.
+///
+/// Returns the offending cycle as a path whose first node is repeated at the end.
+fn find_unterminated_cycle(
+    graph: &Graph,
+    goto_destinations: &HashMap<NodeId, Vec<NodeId>>,
+) -> Option<Vec<NodeId>> {
+    // Adjacency over every possible transition, sorted for deterministic output,
+    // and the subset of it a node chooses between at runtime
+    let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut choices: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut open_routers: HashSet<&str> = HashSet::new();
+    for (from, edges) in &graph.edges {
+        let targets = successors.entry(from.as_str()).or_default();
+        for edge in edges {
+            match edge {
+                Edge::Direct(to) => targets.push(to.as_str()),
+                Edge::Conditional { branches, .. } => {
+                    if branches.is_empty() {
+                        open_routers.insert(from.as_str());
+                    }
+                    targets.extend(branches.values().map(|to| to.as_str()));
+                    choices
+                        .entry(from.as_str())
+                        .or_default()
+                        .extend(branches.values().map(|to| to.as_str()));
+                }
+            }
+        }
+    }
+    for (from, destinations) in goto_destinations {
+        let destinations = destinations.iter().map(|to| to.as_str());
+        successors.entry(from.as_str()).or_default().extend(destinations.clone());
+        choices.entry(from.as_str()).or_default().extend(destinations);
+    }
+    for targets in successors.values_mut() {
+        targets.sort_unstable();
+        targets.dedup();
+    }
+
+    // Nodes from which END is reachable (walking edges backwards)
+    let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (from, targets) in &successors {
+        for to in targets {
+            predecessors.entry(to).or_default().push(from);
+        }
+    }
+    let mut reaches_end: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(END);
+    queue.extend(open_routers.iter().copied());
+    while let Some(node) = queue.pop_front() {
+        if reaches_end.insert(node) {
+            if let Some(preds) = predecessors.get(node) {
+                queue.extend(preds.iter().copied());
+            }
+        }
+    }
+
+    let mut nodes: Vec<&str> = graph.nodes.keys().map(|n| n.as_str()).collect();
+    nodes.sort_unstable();
+
+    for component in strongly_connected_components(&nodes, &successors) {
+        let members: HashSet<&str> = component.iter().copied().collect();
+        let is_cycle = component.len() > 1
+            || successors
+                .get(component[0])
+                .is_some_and(|targets| targets.contains(&component[0]));
+        if !is_cycle {
+            continue;
+        }
+
+        let has_exit = component.iter().any(|node| {
+            open_routers.contains(node)
+                || choices.get(node).is_some_and(|targets| {
+                    targets
+                        .iter()
+                        .any(|to| !members.contains(to) && reaches_end.contains(to))
+                })
+        });
+
+        if !has_exit {
+            return Some(cycle_path(&component, &members, &successors));
+        }
+    }
+
+    None
+}
+
+/// Tarjan's algorithm; components are returned with their members sorted.
+fn strongly_connected_components<'a>(
+    nodes: &[&'a str],
+    successors: &HashMap<&'a str, Vec<&'a str>>,
+) -> Vec<Vec<&'a str>> {
+    struct Tarjan<'a, 'g> {
+        successors: &'g HashMap<&'a str, Vec<&'a str>>,
+        index: HashMap<&'a str, usize>,
+        lowlink: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: HashSet<&'a str>,
+        components: Vec<Vec<&'a str>>,
+    }
+
+    impl<'a, 'g> Tarjan<'a, 'g> {
+        fn visit(&mut self, node: &'a str) {
+            let idx = self.index.len();
+            self.index.insert(node, idx);
+            self.lowlink.insert(node, idx);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let successors = self.successors;
+            for &next in successors.get(node).into_iter().flatten() {
+                if next == START || next == END {
+                    continue;
+                }
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.lowlink[node].min(self.lowlink[next]);
+                    self.lowlink.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.lowlink[node].min(self.index[next]);
+                    self.lowlink.insert(node, low);
+                }
+            }
+
+            if self.lowlink[node] == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        successors,
+        index: HashMap::new(),
+        lowlink: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        components: Vec::new(),
+    };
+    for &node in nodes {
+        if !tarjan.index.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Shortest cycle through the first (smallest) member of a component.
+fn cycle_path(
+    component: &[&str],
+    members: &HashSet<&str>,
+    successors: &HashMap<&str, Vec<&str>>,
+) -> Vec<NodeId> {
+    let start = component[0];
+    let mut parent: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        for &next in successors.get(node).into_iter().flatten() {
+            if next == start {
+                let mut chain = Vec::new();
+                let mut current = node;
+                while current != start {
+                    chain.push(current.to_string());
+                    current = parent[current];
+                }
+                chain.reverse();
+
+                let mut path = vec![start.to_string()];
+                path.extend(chain);
+                path.push(start.to_string());
+                return path;
+            }
+            if members.contains(next) && !parent.contains_key(next) {
+                parent.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    vec![start.to_string(), start.to_string()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1116,6 +1479,122 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_unconditional_cycle_detected() {
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("b", |state| Box::pin(async move { Ok(state) }));
+
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "a");
+
+        match graph.compile() {
+            Err(GraphError::CycleDetected { path }) => {
+                assert_eq!(path, vec!["a", "b", "a"]);
+            }
+            other => panic!("expected CycleDetected, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_conditional_loop_allowed() {
+        use crate::send::ConditionalEdgeResult;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("b", |state| Box::pin(async move { Ok(state) }));
+
+        let mut branches = HashMap::new();
+        branches.insert("again".to_string(), "a".to_string());
+        branches.insert("done".to_string(), END.to_string());
+
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", "b");
+        graph.add_conditional_edge(
+            "b",
+            |state| {
+                if state.get("done").and_then(|v| v.as_bool()).unwrap_or(false) {
+                    ConditionalEdgeResult::Node(END.to_string())
+                } else {
+                    ConditionalEdgeResult::Node("a".to_string())
+                }
+            },
+            branches,
+        );
+
+        assert!(graph.compile().is_ok());
+    }
+
+    #[test]
+    fn test_conditional_loop_without_exit_detected() {
+        use crate::send::ConditionalEdgeResult;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("b", |state| Box::pin(async move { Ok(state) }));
+
+        // Every declared branch leads back into the loop
+        let mut branches = HashMap::new();
+        branches.insert("again".to_string(), "a".to_string());
+
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", "b");
+        graph.add_conditional_edge(
+            "b",
+            |_| ConditionalEdgeResult::Node("a".to_string()),
+            branches,
+        );
+
+        assert!(matches!(
+            graph.compile(),
+            Err(GraphError::CycleDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn test_loop_with_goto_exit_allowed() {
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("b", |state| Box::pin(async move { Ok(state) }));
+
+        // `b` leaves the loop only by returning a Command goto
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "a");
+        graph.add_goto_destinations("b", [END]);
+
+        assert!(graph.compile().is_ok());
+    }
+
+    #[test]
+    fn test_goto_destinations_inside_loop_detected() {
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("b", |state| Box::pin(async move { Ok(state) }));
+
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "a");
+        graph.add_goto_destinations("b", ["a"]);
+
+        assert!(matches!(
+            graph.compile(),
+            Err(GraphError::CycleDetected { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_goto_destination_rejected() {
+        let mut graph = StateGraph::new();
+        graph.add_node("a", |state| Box::pin(async move { Ok(state) }));
+        graph.add_edge("__start__", "a");
+        graph.add_edge("a", END);
+        graph.add_goto_destinations("a", ["missing"]);
+
+        assert!(matches!(graph.compile(), Err(GraphError::Validation(_))));
+    }
+
     // ===== SUBGRAPH TESTS =====
 
     #[tokio::test]
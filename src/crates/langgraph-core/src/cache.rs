@@ -405,6 +405,7 @@
 //! - [Rust caching libraries](https://crates.io/keywords/cache) - External caching solutions
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -540,6 +541,55 @@ impl CacheMetrics {
     }
 }
 
+/// Thread-safe hit/miss counters for a single graph run
+///
+/// Created per invocation and shared by that run's cached nodes, which update
+/// it concurrently while a superstep executes nodes in parallel.
+#[derive(Debug, Default)]
+pub struct CacheMetricsRecorder {
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    entries: AtomicUsize,
+}
+
+impl CacheMetricsRecorder {
+    /// Create a recorder with all counters at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a cache hit
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a cache miss
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record the current number of cached entries
+    pub fn record_entries(&self, entries: usize) {
+        self.entries.store(entries, Ordering::Relaxed);
+    }
+
+    /// Reset hit and miss counters (entry count is kept)
+    pub fn reset(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// Current counters as [`CacheMetrics`]
+    pub fn snapshot(&self) -> CacheMetrics {
+        CacheMetrics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.entries.load(Ordering::Relaxed),
+            ..CacheMetrics::default()
+        }
+    }
+}
+
 /// Generic cache implementation
 pub struct Cache<K, V>
 where
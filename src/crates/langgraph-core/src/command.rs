@@ -687,6 +687,21 @@ impl Command {
         self
     }
 
+    /// Go to `target` with a partial state patch
+    ///
+    /// The patch is merged into state through the channels' reducers before
+    /// the target runs, so the target sees it in its input.
+    ///
+    /// ```rust
+    /// use langgraph_core::Command;
+    /// use serde_json::json;
+    ///
+    /// let cmd = Command::goto_with_update("review", json!({"priority": "high"}));
+    /// ```
+    pub fn goto_with_update(target: impl Into<GotoTarget>, patch: Value) -> Self {
+        Self::new().with_update(patch).with_goto(target)
+    }
+
     /// Check if command is empty (no operations)
     pub fn is_empty(&self) -> bool {
         self.graph.is_none()
@@ -770,6 +785,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_goto_with_update() {
+        let cmd = Command::goto_with_update("review", json!({"priority": "high"}));
+        assert_eq!(cmd.update, Some(json!({"priority": "high"})));
+        match cmd.goto {
+            Some(GotoTarget::Node(node)) => assert_eq!(node, "review"),
+            _ => panic!("Expected Node variant"),
+        }
+    }
+
     #[test]
     fn test_command_with_resume_single() {
         let cmd = Command::new().with_resume(json!({"approved": true}));
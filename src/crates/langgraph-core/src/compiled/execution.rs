@@ -3,9 +3,15 @@
 //! This module contains methods for executing compiled graphs.
 
 use super::CompiledGraph;
-use crate::error::Result;
-use langgraph_checkpoint::CheckpointConfig;
+use crate::cache::CacheMetricsRecorder;
+use crate::error::{GraphError, Result};
+use crate::inline_interrupt::InlineResumeValue;
+use crate::pregel::PregelLoop;
+use langgraph_checkpoint::checkpoint::CheckpointSource;
+use langgraph_checkpoint::{CheckpointConfig, CheckpointSaver};
 use serde_json::Value;
+use std::sync::Arc;
+use std::collections::HashMap;
 
 impl CompiledGraph {
     /// Execute the graph to completion with default configuration.
@@ -157,12 +163,23 @@ impl CompiledGraph {
         tracing::info!("Starting graph execution");
 
         // Build the Pregel execution context
-        let mut pregel_loop = self.build_pregel_loop(input)
+        let metrics = Arc::new(CacheMetricsRecorder::new());
+        let mut pregel_loop = self.build_pregel_loop(input, metrics.clone())
             .map_err(|e| {
                 tracing::error!(error = %e, "Failed to build Pregel loop");
                 e
             })?;
 
+        // Per-invocation recursion limit overrides the compiled default
+        if let Some(limit) = config.as_ref().and_then(|cfg| cfg.recursion_limit()) {
+            pregel_loop = pregel_loop.with_max_steps(limit);
+        }
+
+        let mut config = config;
+        pregel_loop = Self::apply_request_context(pregel_loop, config.as_mut());
+
+        let thread_id = config.as_ref().and_then(|cfg| cfg.thread_id.clone());
+
         // Set checkpointer if both saver and config are available
         if let (Some(saver), Some(cfg)) = (&self.checkpoint_saver, config) {
             tracing::debug!("Configuring checkpointer");
@@ -170,6 +187,217 @@ impl CompiledGraph {
         }
 
         // Set interrupt configuration
+        pregel_loop = self.apply_interrupt_config(pregel_loop);
+
+        // Run the Pregel loop
+        tracing::debug!("Running Pregel execution");
+        let result = pregel_loop.run().await;
+        self.cache_run_metrics.publish(&metrics, thread_id.as_deref());
+        let result = result
+            .map_err(|e| {
+                tracing::error!(error = %e, "Graph execution failed");
+                e
+            })?;
+
+        tracing::info!("Graph execution completed successfully");
+        Ok(result)
+    }
+
+    /// Fork execution from a historical checkpoint, creating a new branch.
+    ///
+    /// Loads the checkpoint `checkpoint_id` from the thread in `config`, applies
+    /// optional state edits, and continues execution from there. Checkpoints
+    /// written by the new branch record the forked checkpoint as their
+    /// `parent_config`, so the branch can be traced back to where it diverged.
+    /// The original thread's later checkpoints are left untouched and can still
+    /// be retrieved by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint_id` - Checkpoint to fork from (e.g. taken from
+    ///   [`get_state_history_chronological`](super::CompiledGraph::get_state_history_chronological))
+    /// * `input` - Optional state edits: a JSON object whose keys either name a
+    ///   channel (replacing its value) or are merged as fields into every
+    ///   object-valued state channel
+    /// * `config` - Checkpoint configuration with the thread_id
+    ///
+    /// # Returns
+    ///
+    /// Final state of the new branch.
+    ///
+    /// # Errors
+    ///
+    /// - [`GraphError::Configuration`] if no checkpoint saver is configured
+    /// - [`GraphError::Checkpoint`] if the checkpoint does not exist
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Run once, then pick a past checkpoint from history
+    /// compiled.invoke_with_config(json!({"counter": 0}), Some(config.clone())).await?;
+    /// let history: Vec<_> = compiled.get_state_history_chronological(&config).await?.collect().await;
+    /// let fork_id = history[0].as_ref()?.config.checkpoint_id.clone().unwrap();
+    ///
+    /// // Replay from that point with a different counter
+    /// let branch = compiled
+    ///     .invoke_from_checkpoint(&fork_id, Some(json!({"counter": 50})), config)
+    ///     .await?;
+    /// ```
+    #[tracing::instrument(skip(self, input, config))]
+    pub async fn invoke_from_checkpoint(
+        &self,
+        checkpoint_id: &str,
+        input: Option<Value>,
+        config: CheckpointConfig,
+    ) -> Result<Value> {
+        let Some(saver) = self.checkpoint_saver.clone() else {
+            return Err(GraphError::Configuration(
+                "No checkpoint saver configured".to_string()
+            ));
+        };
+
+        // Taken out before the config reaches the saver
+        let mut config = config;
+        let request_context = config.take_context();
+        let fork_config = config.clone().with_checkpoint_id(checkpoint_id.to_string());
+
+        // Apply state edits as a new checkpoint whose parent is the fork point
+        let resume_config = match input {
+            Some(Value::Object(edits)) if !edits.is_empty() => {
+                let tuple = saver.get_tuple(&fork_config).await?
+                    .ok_or_else(|| GraphError::Checkpoint(
+                        langgraph_checkpoint::CheckpointError::NotFound(
+                            format!("Checkpoint not found: {}", checkpoint_id)
+                        )
+                    ))?;
+
+                let mut checkpoint = tuple.checkpoint.copy();
+                checkpoint.id = uuid::Uuid::new_v4().to_string();
+                checkpoint.ts = chrono::Utc::now();
+                apply_state_edits(&mut checkpoint.channel_values, edits);
+
+                let mut metadata = tuple.metadata.clone();
+                metadata.source = Some(CheckpointSource::Update);
+                metadata.extra.insert("forked_from".to_string(), serde_json::json!(checkpoint_id));
+
+                saver.put(&fork_config, checkpoint, metadata, HashMap::new()).await?
+            }
+            _ => fork_config,
+        };
+
+        self.run_from_checkpoint(saver, resume_config, &config, request_context, None).await
+    }
+
+    /// Resume a thread whose node paused with an inline interrupt.
+    ///
+    /// Continues from the thread's latest checkpoint (or the one named in
+    /// `config`), which precedes the superstep that was interrupted. The
+    /// interrupted node runs again and receives `resume` from
+    /// [`Runtime::get_resume_value`](crate::runtime::Runtime::get_resume_value),
+    /// so [`interrupt_for_typed`](crate::inline_interrupt::interrupt_for_typed)
+    /// returns the payload instead of pausing.
+    ///
+    /// # Errors
+    ///
+    /// - [`GraphError::Configuration`] if no checkpoint saver is configured
+    /// - [`GraphError::Checkpoint`] if the thread has no checkpoint
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // First run pauses inside the node
+    /// let err = compiled.invoke_with_config(input, Some(config.clone())).await.unwrap_err();
+    /// assert!(matches!(err, GraphError::InlineInterrupt(_)));
+    ///
+    /// let resume = InlineResumeValue {
+    ///     action: ResumeAction::Continue,
+    ///     updates: Some(json!({"approved": true, "reviewer": "ada"})),
+    ///     inputs: None,
+    ///     metadata: None,
+    /// };
+    /// let result = compiled.resume_from_interrupt(resume, config).await?;
+    /// ```
+    #[tracing::instrument(skip(self, resume, config))]
+    pub async fn resume_from_interrupt(
+        &self,
+        resume: InlineResumeValue,
+        config: CheckpointConfig,
+    ) -> Result<Value> {
+        let Some(saver) = self.checkpoint_saver.clone() else {
+            return Err(GraphError::Configuration(
+                "No checkpoint saver configured".to_string()
+            ));
+        };
+
+        let mut config = config;
+        let request_context = config.take_context();
+        let tuple = saver.get_tuple(&config).await?
+            .ok_or_else(|| GraphError::Checkpoint(
+                langgraph_checkpoint::CheckpointError::NotFound(format!(
+                    "No checkpoint to resume for thread {:?}",
+                    config.thread_id
+                ))
+            ))?;
+
+        self.run_from_checkpoint(saver, tuple.config, &config, request_context, Some(resume)).await
+    }
+
+    /// Run a loop restored from `resume_config` to completion
+    async fn run_from_checkpoint(
+        &self,
+        saver: Arc<dyn CheckpointSaver>,
+        resume_config: CheckpointConfig,
+        config: &CheckpointConfig,
+        request_context: Option<Value>,
+        inline_resume: Option<InlineResumeValue>,
+    ) -> Result<Value> {
+        let metrics = Arc::new(CacheMetricsRecorder::new());
+        let (_, channels, nodes) = self.build_pregel_parts(Value::Null, metrics.clone())?;
+        let limit = config.recursion_limit().unwrap_or(self.recursion_limit);
+
+        let mut pregel_loop = PregelLoop::from_checkpoint(
+            saver,
+            resume_config,
+            channels,
+            nodes,
+            limit,
+            self.graph.edges.clone(),
+        ).await?;
+
+        if let Some(store) = &self.store {
+            pregel_loop = pregel_loop.with_store(store.clone());
+        }
+        if let Some(on_superstep) = &self.on_superstep {
+            pregel_loop = pregel_loop.with_on_superstep(on_superstep.clone());
+        }
+        if let Some(context) = request_context {
+            pregel_loop = pregel_loop.with_request_context(context);
+        }
+        if let Some(resume) = inline_resume {
+            pregel_loop = pregel_loop.with_inline_resume(resume);
+        }
+        pregel_loop = self.apply_interrupt_config(pregel_loop);
+
+        let result = pregel_loop.run().await;
+        self.cache_run_metrics.publish(&metrics, config.thread_id.as_deref());
+        result
+    }
+
+    /// Move the request-scoped context out of `config` and into the loop
+    ///
+    /// Nodes can then read it, and it never reaches the checkpointer.
+    pub(super) fn apply_request_context(
+        pregel_loop: PregelLoop,
+        config: Option<&mut CheckpointConfig>,
+    ) -> PregelLoop {
+        match config.and_then(CheckpointConfig::take_context) {
+            Some(context) => pregel_loop.with_request_context(context),
+            None => pregel_loop,
+        }
+    }
+
+    /// Configure interrupt points from this graph's [`InterruptConfig`](crate::InterruptConfig)
+    pub(super) fn apply_interrupt_config(&self, mut pregel_loop: PregelLoop) -> PregelLoop {
         if !self.interrupt_config.interrupt_before.is_empty() {
             tracing::debug!(
                 interrupt_before = ?self.interrupt_config.interrupt_before,
@@ -188,16 +416,38 @@ impl CompiledGraph {
                 self.interrupt_config.interrupt_after.iter().cloned().collect();
             pregel_loop = pregel_loop.with_interrupt_after(nodes);
         }
+        if !self.interrupt_config.interrupt_conditions.is_empty() {
+            tracing::debug!(
+                interrupt_conditions = ?self.interrupt_config.interrupt_conditions,
+                "Configuring interrupt points (conditions)"
+            );
+            pregel_loop = pregel_loop
+                .with_interrupt_conditions(self.interrupt_config.interrupt_conditions.clone());
+        }
+        pregel_loop
+    }
+}
 
-        // Run the Pregel loop
-        tracing::debug!("Running Pregel execution");
-        let result = pregel_loop.run().await
-            .map_err(|e| {
-                tracing::error!(error = %e, "Graph execution failed");
-                e
-            })?;
-
-        tracing::info!("Graph execution completed successfully");
-        Ok(result)
+/// Merge state edits into saved channel values.
+///
+/// Keys naming an existing channel replace that channel's value; all other keys
+/// are merged as fields into every object-valued, non-internal channel.
+fn apply_state_edits(
+    channel_values: &mut HashMap<String, Value>,
+    edits: serde_json::Map<String, Value>,
+) {
+    for (key, value) in edits {
+        if let Some(existing) = channel_values.get_mut(&key) {
+            *existing = value;
+            continue;
+        }
+        for (channel_name, channel_value) in channel_values.iter_mut() {
+            if channel_name.starts_with("__") {
+                continue;
+            }
+            if let Some(obj) = channel_value.as_object_mut() {
+                obj.insert(key.clone(), value.clone());
+            }
+        }
     }
 }
@@ -2,11 +2,16 @@
 //!
 //! This module contains the CompiledGraph type and its constructor/builder methods.
 
+use crate::builder::DEFAULT_RECURSION_LIMIT;
+use crate::cache::{Cache, CacheConfig, CacheMetrics, CacheMetricsRecorder, NodeCache};
 use crate::error::Result;
 use crate::graph::Graph;
 use crate::interrupt::InterruptConfig;
+use crate::pregel::SuperstepCallback;
 use langgraph_checkpoint::CheckpointSaver;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// Compiled graph ready for execution
 #[derive(Clone)]
@@ -15,6 +20,13 @@ pub struct CompiledGraph {
     pub(crate) checkpoint_saver: Option<Arc<dyn CheckpointSaver>>,
     pub(crate) interrupt_config: InterruptConfig,
     pub(crate) store: Option<Arc<dyn crate::store::Store>>,
+    pub(crate) recursion_limit: usize,
+    /// Results of nodes with a cache policy, shared by all invocations
+    pub(crate) node_cache: Arc<NodeCache>,
+    /// Node cache metrics published by finished runs
+    pub(crate) cache_run_metrics: Arc<RunCacheMetrics>,
+    /// Instrumentation callback passed to every PregelLoop
+    pub(crate) on_superstep: Option<SuperstepCallback>,
 }
 
 impl CompiledGraph {
@@ -25,6 +37,10 @@ impl CompiledGraph {
             checkpoint_saver: None,
             interrupt_config: InterruptConfig::default(),
             store: None,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            node_cache: Arc::new(new_node_cache()),
+            cache_run_metrics: Arc::new(RunCacheMetrics::default()),
+            on_superstep: None,
         })
     }
 
@@ -35,6 +51,10 @@ impl CompiledGraph {
             checkpoint_saver: None,
             interrupt_config,
             store: None,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            node_cache: Arc::new(new_node_cache()),
+            cache_run_metrics: Arc::new(RunCacheMetrics::default()),
+            on_superstep: None,
         })
     }
 
@@ -44,6 +64,44 @@ impl CompiledGraph {
         self
     }
 
+    /// Set the maximum number of supersteps per invocation
+    ///
+    /// Can be overridden for a single run with
+    /// [`CheckpointConfig::with_recursion_limit`](langgraph_checkpoint::CheckpointConfig::with_recursion_limit).
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
+    /// Set a callback invoked after every superstep of every run
+    ///
+    /// See [`SuperstepInfo`](crate::pregel::SuperstepInfo) for what it receives.
+    pub fn with_on_superstep(mut self, callback: SuperstepCallback) -> Self {
+        self.on_superstep = Some(callback);
+        self
+    }
+
+    /// Get the maximum number of supersteps per invocation
+    pub fn recursion_limit(&self) -> usize {
+        self.recursion_limit
+    }
+
+    /// Cache hit/miss counts for the most recently finished run
+    ///
+    /// Aggregates every node with a [`CachePolicy`](crate::pregel::CachePolicy);
+    /// only the node result cache is counted, not checkpoint or LLM caches.
+    /// Each invocation counts into its own recorder, so concurrent runs do not
+    /// mix their counts; `entries` is the number of results currently cached
+    /// across runs.
+    pub fn cache_metrics(&self) -> CacheMetrics {
+        self.cache_run_metrics.latest()
+    }
+
+    /// Whether any node in this graph caches its results
+    pub(crate) fn has_cached_nodes(&self) -> bool {
+        self.graph.nodes.values().any(|node| node.cache_policy.is_some())
+    }
+
     /// Set the store for persistent state access
     pub fn with_store(mut self, store: Arc<dyn crate::store::Store>) -> Self {
         self.store = Some(store);
@@ -129,3 +187,64 @@ impl crate::graph::SubgraphExecutor for CompiledGraph {
         "subgraph"
     }
 }
+
+/// Node result cache; entries expire only when their policy sets a TTL
+fn new_node_cache() -> NodeCache {
+    Cache::new(CacheConfig {
+        default_ttl: None,
+        ..CacheConfig::default()
+    })
+}
+
+/// Most threads whose last run's cache metrics are kept
+pub(crate) const MAX_THREAD_CACHE_METRICS: usize = 1024;
+
+/// Cache metrics published by finished runs of a [`CompiledGraph`]
+///
+/// Runs count into their own [`CacheMetricsRecorder`] and publish a snapshot
+/// when they finish, keyed by thread so a snapshot of one thread never
+/// reports another thread's run. Only the [`MAX_THREAD_CACHE_METRICS`]
+/// most recently published threads are kept.
+#[derive(Debug, Default)]
+pub(crate) struct RunCacheMetrics {
+    latest: Mutex<CacheMetrics>,
+    /// Per-thread metrics with the sequence number they were published at
+    by_thread: Mutex<HashMap<String, (u64, CacheMetrics)>>,
+    published: AtomicU64,
+}
+
+impl RunCacheMetrics {
+    /// Record the metrics of a finished run
+    pub(crate) fn publish(&self, recorder: &CacheMetricsRecorder, thread_id: Option<&str>) {
+        let metrics = recorder.snapshot();
+        if let Some(thread_id) = thread_id {
+            let seq = self.published.fetch_add(1, Ordering::Relaxed);
+            let mut by_thread = self.by_thread.lock().unwrap();
+            if by_thread.len() >= MAX_THREAD_CACHE_METRICS && !by_thread.contains_key(thread_id) {
+                let oldest = by_thread
+                    .iter()
+                    .min_by_key(|(_, (seq, _))| *seq)
+                    .map(|(thread_id, _)| thread_id.clone());
+                if let Some(oldest) = oldest {
+                    by_thread.remove(&oldest);
+                }
+            }
+            by_thread.insert(thread_id.to_string(), (seq, metrics.clone()));
+        }
+        *self.latest.lock().unwrap() = metrics;
+    }
+
+    /// Metrics of the most recently finished run
+    pub(crate) fn latest(&self) -> CacheMetrics {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Metrics of the most recently finished run on `thread_id`
+    pub(crate) fn for_thread(&self, thread_id: &str) -> Option<CacheMetrics> {
+        self.by_thread
+            .lock()
+            .unwrap()
+            .get(thread_id)
+            .map(|(_, metrics)| metrics.clone())
+    }
+}
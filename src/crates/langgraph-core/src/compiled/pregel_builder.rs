@@ -3,17 +3,26 @@
 //! This module contains private helpers for building PregelLoop instances.
 
 use super::CompiledGraph;
+use crate::cache::{CacheMetricsRecorder, NodeCache};
 use crate::error::Result;
 use crate::graph::{Edge, END, START, TASKS};
+use crate::managed::ManagedValueType;
 use crate::pregel::{
-    Checkpoint as PregelCheckpoint, ChannelVersion, LastValueChannel, NodeExecutor,
-    PregelLoop, PregelNodeSpec,
+    CacheKey, CachePolicy, Checkpoint as PregelCheckpoint, ChannelVersion, LastValueChannel,
+    NodeExecutor, PregelLoop, PregelNodeSpec,
 };
 use langgraph_checkpoint::{BinaryOperatorChannel, Channel, TopicChannel};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// Initial checkpoint, channels, and node specs that make up a PregelLoop
+pub(crate) type PregelParts = (
+    PregelCheckpoint,
+    HashMap<String, Box<dyn Channel>>,
+    HashMap<String, PregelNodeSpec>,
+);
+
 impl CompiledGraph {
     /// Build a PregelLoop from the graph structure
     ///
@@ -23,6 +32,7 @@ impl CompiledGraph {
     /// # Arguments
     ///
     /// * `input` - Initial state
+    /// * `metrics` - Recorder for this run's node cache hits and misses
     ///
     /// # Returns
     ///
@@ -30,7 +40,59 @@ impl CompiledGraph {
     pub(crate) fn build_pregel_loop(
         &self,
         input: Value,
+        metrics: Arc<CacheMetricsRecorder>,
     ) -> Result<PregelLoop> {
+        let (checkpoint, channels, pregel_nodes) = self.build_pregel_parts(input, metrics)?;
+
+        // Create PregelLoop with edges for conditional routing
+        let mut pregel_loop = PregelLoop::new_with_edges(
+            checkpoint,
+            channels,
+            pregel_nodes,
+            self.recursion_limit,
+            self.graph.edges.clone(),
+        );
+
+        // Add store if available
+        if let Some(store) = &self.store {
+            pregel_loop = pregel_loop.with_store(store.clone());
+        }
+        if let Some(on_superstep) = &self.on_superstep {
+            pregel_loop = pregel_loop.with_on_superstep(on_superstep.clone());
+        }
+
+        Ok(pregel_loop)
+    }
+
+    /// Nodes named as a branch destination of any conditional edge
+    ///
+    /// A node without direct predecessors used to get a START trigger even
+    /// when a router was its only way in. It then ran in the first superstep
+    /// next to the entry node, before any router had picked it; the tool node
+    /// of `MessageGraph::with_tools` ran ahead of the agent this way. Such
+    /// nodes get no triggers and run only when a router selects them.
+    fn conditional_targets(&self) -> HashSet<&String> {
+        self.graph
+            .edges
+            .values()
+            .flatten()
+            .filter_map(|edge| match edge {
+                Edge::Conditional { branches, .. } => Some(branches.values()),
+                Edge::Direct(_) => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Build the initial checkpoint, channels, and node specs for a PregelLoop
+    ///
+    /// Used directly when restoring from a saved checkpoint, where the
+    /// channels are repopulated from the checkpoint rather than `input`.
+    pub(crate) fn build_pregel_parts(
+        &self,
+        input: Value,
+        metrics: Arc<CacheMetricsRecorder>,
+    ) -> Result<PregelParts> {
         // 1. Create initial checkpoint
         let mut checkpoint = PregelCheckpoint::new();
 
@@ -56,6 +118,8 @@ impl CompiledGraph {
             }
         }
 
+        let conditional_targets = self.conditional_targets();
+
         // 3. Create channels: one per node (represents that node's output)
         let mut channels: HashMap<String, Box<dyn Channel>> = HashMap::new();
 
@@ -144,11 +208,13 @@ impl CompiledGraph {
         let mut pregel_nodes = HashMap::new();
 
         for (node_id, node_spec) in &self.graph.nodes {
-            // Determine which channels trigger this node (its predecessors)
-            let triggers = incoming_edges
-                .get(node_id)
-                .cloned()
-                .unwrap_or_else(|| vec![START.to_string()]);
+            // Determine which channels trigger this node (its predecessors).
+            // Without any, a node starts the graph unless it is only routed to.
+            let triggers = match incoming_edges.get(node_id) {
+                Some(predecessors) => predecessors.clone(),
+                None if conditional_targets.contains(node_id) => vec![],
+                None => vec![START.to_string()],
+            };
 
             // Wrap the existing executor in a Pregel-compatible adapter
             let executor_clone = node_spec.executor.clone();
@@ -160,6 +226,17 @@ impl CompiledGraph {
                 edges: edges_clone,
             };
 
+            let executor: Arc<dyn NodeExecutor> = match &node_spec.cache_policy {
+                Some(policy) => Arc::new(CachedNodeExecutor {
+                    inner: Arc::new(adapter),
+                    node_id: node_id.clone(),
+                    policy: policy.clone(),
+                    cache: self.node_cache.clone(),
+                    metrics: metrics.clone(),
+                }),
+                None => Arc::new(adapter),
+            };
+
             pregel_nodes.insert(
                 node_id.clone(),
                 PregelNodeSpec {
@@ -167,75 +244,65 @@ impl CompiledGraph {
                     triggers,
                     reads: node_spec.reads.clone(),
                     writes: node_spec.writes.clone(),
-                    executor: Arc::new(adapter),
+                    executor,
                 },
             );
         }
 
-        // 5. Create PregelLoop with edges for conditional routing
-        let mut pregel_loop = PregelLoop::new_with_edges(
-            checkpoint,
-            channels,
-            pregel_nodes,
-            100,
-            self.graph.edges.clone(),
-        );
-
-        // 6. Add store if available
-        if let Some(store) = &self.store {
-            pregel_loop = pregel_loop.with_store(store.clone());
-        }
-
-        Ok(pregel_loop)
+        Ok((checkpoint, channels, pregel_nodes))
     }
 
-    /// Execute multiple inputs in parallel
+    /// Invoke the graph on each input, running at most `concurrency` at once
     ///
-    /// # Arguments
+    /// Every input gets its own Pregel loop, and results are returned in input
+    /// order. A failing input doesn't stop the others; its error takes its
+    /// place in the output. A `concurrency` of 0 is treated as 1.
     ///
-    /// * `inputs` - Vector of initial states
-    ///
-    /// # Returns
+    /// # Example
     ///
-    /// Vector of final states after execution
-    pub async fn batch(&self, inputs: Vec<Value>) -> Result<Vec<Value>> {
-        self.batch_with_config(inputs, None).await
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # async fn example(compiled: langgraph_core::CompiledGraph) {
+    /// let inputs = vec![json!({"question": "a"}), json!({"question": "b"})];
+    /// for result in compiled.batch(inputs, 4).await {
+    ///     println!("{:?}", result);
+    /// }
+    /// # }
+    /// ```
+    pub async fn batch(&self, inputs: Vec<Value>, concurrency: usize) -> Vec<Result<Value>> {
+        self.batch_with_config(inputs, concurrency, None).await
     }
 
-    /// Execute multiple inputs in parallel with configuration
-    ///
-    /// # Arguments
-    ///
-    /// * `inputs` - Vector of initial states
-    /// * `config` - Optional checkpoint configuration
-    ///
-    /// # Returns
+    /// Invoke the graph on each input with a checkpoint configuration
     ///
-    /// Vector of final states after execution
+    /// Like [`batch`](Self::batch), but each invocation checkpoints under its
+    /// own thread so runs never read each other's state: input `i` runs on
+    /// thread `"{thread_id}-{i}"`, where `thread_id` comes from `config` or is
+    /// generated when unset.
     pub async fn batch_with_config(
         &self,
         inputs: Vec<Value>,
+        concurrency: usize,
         config: Option<langgraph_checkpoint::CheckpointConfig>,
-    ) -> Result<Vec<Value>> {
-        // Execute all inputs in parallel
-        let mut tasks = Vec::new();
-
-        for input in inputs {
-            let cfg = config.clone();
-            let future = self.invoke_with_config(input, cfg);
-            tasks.push(future);
-        }
-
-        // Wait for all tasks to complete
-        let results = futures::future::join_all(tasks).await;
-
-        // Collect results or return first error
-        let mut outputs = Vec::new();
-        for result in results {
-            outputs.push(result?);
-        }
-
-        Ok(outputs)
+    ) -> Vec<Result<Value>> {
+        use futures::stream::{self, StreamExt};
+
+        let base_thread = config.as_ref().map(|cfg| {
+            cfg.thread_id
+                .clone()
+                .unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+        });
+
+        stream::iter(inputs.into_iter().enumerate())
+            .map(|(index, input)| {
+                let cfg = config.clone().zip(base_thread.as_ref()).map(|(cfg, thread)| {
+                    cfg.with_thread_id(format!("{}-{}", thread, index))
+                });
+                self.invoke_with_config(input, cfg)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 }
 
@@ -257,13 +324,72 @@ impl NodeExecutor for GraphExecutorAdapterWithEdges {
         let _edges = self.edges.clone();
 
         Box::pin(async move {
-            // Execute the node
-            let result = executor(input)
-                .await
-                .map_err(|e| crate::error::GraphError::Execution(e.to_string()))?;
+            // Execute the node, keeping graph errors such as inline
+            // interrupts intact so the caller can act on them
+            let result = executor(input).await.map_err(|e| {
+                match e.downcast::<crate::error::GraphError>() {
+                    Ok(error) => *error,
+                    Err(e) => crate::error::GraphError::Execution(e.to_string()),
+                }
+            })?;
 
             // The result will be written to this node's channel by the loop
             Ok(result)
         })
     }
 }
+
+/// Executor wrapper that serves node results from the graph's node cache
+///
+/// The cache key is derived from the node's input (the values of the channels
+/// it reads) with managed values such as the current step stripped, so the same
+/// logical input hits the cache regardless of when the node runs.
+struct CachedNodeExecutor {
+    inner: Arc<dyn NodeExecutor>,
+    node_id: String,
+    policy: CachePolicy,
+    cache: Arc<NodeCache>,
+    metrics: Arc<CacheMetricsRecorder>,
+}
+
+impl CachedNodeExecutor {
+    fn cache_key(&self, input: &Value) -> CacheKey {
+        let mut relevant = input.clone();
+        if let Some(obj) = relevant.as_object_mut() {
+            for managed in [
+                ManagedValueType::RemainingSteps,
+                ManagedValueType::IsLastStep,
+                ManagedValueType::CurrentStep,
+            ] {
+                obj.remove(managed.state_key());
+            }
+        }
+        self.policy.cache_key(&self.node_id, &relevant)
+    }
+}
+
+impl NodeExecutor for CachedNodeExecutor {
+    fn execute(
+        &self,
+        input: Value,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+        Box::pin(async move {
+            let key = self.cache_key(&input);
+            let cache_key = format!("{}:{}", key.ns.join("|"), key.key);
+
+            if let Some(cached) = self.cache.get(&cache_key).await {
+                tracing::debug!(node = %self.node_id, "Node cache hit");
+                self.metrics.record_hit();
+                self.metrics.record_entries(self.cache.metrics().await.entries);
+                return Ok(cached);
+            }
+            self.metrics.record_miss();
+
+            let output = self.inner.execute(input).await?;
+            let ttl = key.ttl.map(std::time::Duration::from_secs);
+            self.cache.put_with_ttl(cache_key, output.clone(), ttl).await;
+            self.metrics.record_entries(self.cache.metrics().await.entries);
+            Ok(output)
+        })
+    }
+}
@@ -36,7 +36,8 @@ impl CompiledGraph {
         let tuple = saver.get_tuple(config).await
             .map_err(|e| GraphError::Checkpoint(e))?;
 
-        Ok(tuple.map(|t| self.checkpoint_tuple_to_snapshot(t)))
+        let latest = config.checkpoint_id.is_none();
+        Ok(tuple.map(|t| self.checkpoint_tuple_to_snapshot(t, latest)))
     }
 
     /// Traverse the complete execution history of a graph thread.
@@ -58,6 +59,7 @@ impl CompiledGraph {
     ///
     /// # See Also
     ///
+    /// - [`get_state_history_chronological`](Self::get_state_history_chronological) - Full history, oldest first
     /// - [`get_state`](Self::get_state) - Get single checkpoint
     /// - [`update_state`](Self::update_state) - Modify checkpoint state
     pub async fn get_state_history(
@@ -85,9 +87,78 @@ impl CompiledGraph {
         Ok(Box::pin(snapshot_stream))
     }
 
+    /// Traverse the complete execution history of a graph thread, oldest first.
+    ///
+    /// Returns a **stream of state snapshots** in chronological order, the
+    /// reverse of [`get_state_history`](Self::get_state_history).
+    /// Each snapshot's [`config`](StateSnapshot::config) carries its checkpoint
+    /// id, and [`parent_config`](StateSnapshot::parent_config) links to the
+    /// checkpoint it was derived from. Pass a snapshot's `config` to
+    /// [`get_state`](Self::get_state) or a resume path to continue from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Checkpoint configuration (thread_id required)
+    ///
+    /// # Returns
+    ///
+    /// Stream of [`StateSnapshot`] oldest-to-newest. Empty if no checkpoint
+    /// saver is configured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut history = compiled.get_state_history_chronological(&config).await?;
+    /// while let Some(snapshot) = history.next().await {
+    ///     let snapshot = snapshot?;
+    ///     println!("{:?} -> {:?}", snapshot.parent_config, snapshot.config.checkpoint_id);
+    /// }
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// - [`get_state_history`](Self::get_state_history) - Filtered, newest-first listing
+    /// - [`get_state`](Self::get_state) - Get single checkpoint
+    pub async fn get_state_history_chronological(&self, config: &CheckpointConfig) -> Result<StateSnapshotStream> {
+        let Some(saver) = self.checkpoint_saver.clone() else {
+            // No checkpointer configured, return empty stream
+            return Ok(Box::pin(futures::stream::empty()));
+        };
+
+        // Only the thread (and namespace) select the history, not a specific checkpoint
+        let mut thread_config = config.clone();
+        thread_config.checkpoint_id = None;
+
+        // Savers list newest first; collect so the order can be reversed
+        let tuples: Vec<_> = saver.list(Some(&thread_config), None, None, None).await
+            .map_err(GraphError::Checkpoint)?
+            .collect()
+            .await;
+
+        let snapshots: Vec<Result<StateSnapshot>> = tuples
+            .into_iter()
+            .rev()
+            .map(|result| {
+                result
+                    .map(Self::checkpoint_tuple_to_snapshot_static)
+                    .map_err(GraphError::Checkpoint)
+            })
+            .collect();
+
+        Ok(Box::pin(futures::stream::iter(snapshots)))
+    }
+
     /// Helper to convert a CheckpointTuple to a StateSnapshot (instance method)
-    fn checkpoint_tuple_to_snapshot(&self, tuple: CheckpointTuple) -> StateSnapshot {
-        Self::checkpoint_tuple_to_snapshot_static(tuple)
+    ///
+    /// `latest` marks the thread's current state, which carries the cache
+    /// metrics of the last run on that thread.
+    fn checkpoint_tuple_to_snapshot(&self, tuple: CheckpointTuple, latest: bool) -> StateSnapshot {
+        let mut snapshot = Self::checkpoint_tuple_to_snapshot_static(tuple);
+        if latest && self.has_cached_nodes() {
+            snapshot.cache_metrics = snapshot.config.thread_id.as_deref()
+                .and_then(|thread_id| self.cache_run_metrics.for_thread(thread_id));
+        }
+        snapshot
     }
 
     /// Helper to convert a CheckpointTuple to a StateSnapshot (static method)
@@ -121,6 +192,7 @@ impl CompiledGraph {
             metadata: Some(tuple.metadata),
             created_at: Some(tuple.checkpoint.ts.to_rfc3339()),
             parent_config: tuple.parent_config,
+            cache_metrics: None,
         }
     }
 
@@ -200,9 +272,11 @@ impl CompiledGraph {
         }
         metadata.extra.insert("manual_update".to_string(), serde_json::json!(true));
 
-        // Save updated checkpoint
+        // Save updated checkpoint; request-scoped context is never persisted
+        let mut config = config.clone();
+        config.take_context();
         let new_config = saver.put(
-            config,
+            &config,
             new_checkpoint,
             metadata,
             HashMap::new(), // No new channel versions for manual update
@@ -3,11 +3,13 @@
 //! This module contains methods for streaming graph execution events.
 
 use super::{CompiledGraph, EventStream, StreamChunkStream};
+use crate::cache::CacheMetricsRecorder;
 use crate::error::Result;
 use crate::stream::{StreamChunk, StreamMode};
 use super::types::ExecutionEvent;
 use langgraph_checkpoint::CheckpointConfig;
 use serde_json::Value;
+use std::sync::Arc;
 
 impl CompiledGraph {
     /// Stream execution events with default mode (Values)
@@ -46,31 +48,35 @@ impl CompiledGraph {
         let (tx, mut rx) = mpsc::channel(100);
 
         // Build Pregel loop with streaming enabled
-        let mut pregel_loop = self.build_pregel_loop(input)?;
+        let metrics = Arc::new(CacheMetricsRecorder::new());
+        let mut pregel_loop = self.build_pregel_loop(input, metrics.clone())?;
 
         // Configure streaming
         pregel_loop = pregel_loop.with_streaming_mux(modes, tx);
 
+        // Per-invocation recursion limit overrides the compiled default
+        if let Some(limit) = config.as_ref().and_then(|cfg| cfg.recursion_limit()) {
+            pregel_loop = pregel_loop.with_max_steps(limit);
+        }
+
+        let mut config = config;
+        pregel_loop = Self::apply_request_context(pregel_loop, config.as_mut());
+
+        let thread_id = config.as_ref().and_then(|cfg| cfg.thread_id.clone());
+        let run_metrics = self.cache_run_metrics.clone();
+
         // Set checkpointer if both saver and config are available
         if let (Some(saver), Some(cfg)) = (&self.checkpoint_saver, config) {
             pregel_loop = pregel_loop.with_checkpointer(saver.clone(), cfg);
         }
 
         // Set interrupt configuration
-        if !self.interrupt_config.interrupt_before.is_empty() {
-            let nodes: std::collections::HashSet<String> =
-                self.interrupt_config.interrupt_before.iter().cloned().collect();
-            pregel_loop = pregel_loop.with_interrupt_before(nodes);
-        }
-        if !self.interrupt_config.interrupt_after.is_empty() {
-            let nodes: std::collections::HashSet<String> =
-                self.interrupt_config.interrupt_after.iter().cloned().collect();
-            pregel_loop = pregel_loop.with_interrupt_after(nodes);
-        }
+        pregel_loop = self.apply_interrupt_config(pregel_loop);
 
         // Spawn the execution in a background task
         tokio::spawn(async move {
             let _ = pregel_loop.run().await;
+            run_metrics.publish(&metrics, thread_id.as_deref());
         });
 
         // Convert StreamChunk to ExecutionEvent
@@ -248,33 +254,37 @@ impl CompiledGraph {
         let (tx, rx) = mpsc::channel::<StreamChunk>(100);
 
         // Build Pregel loop with streaming enabled
-        let mut pregel_loop = self.build_pregel_loop(input)?;
+        let metrics = Arc::new(CacheMetricsRecorder::new());
+        let mut pregel_loop = self.build_pregel_loop(input, metrics.clone())?;
 
         // Configure streaming with new API
         pregel_loop = pregel_loop.with_streaming_mux(modes, tx);
 
+        // Per-invocation recursion limit overrides the compiled default
+        if let Some(limit) = config.as_ref().and_then(|cfg| cfg.recursion_limit()) {
+            pregel_loop = pregel_loop.with_max_steps(limit);
+        }
+
+        let mut config = config;
+        pregel_loop = Self::apply_request_context(pregel_loop, config.as_mut());
+
+        let thread_id = config.as_ref().and_then(|cfg| cfg.thread_id.clone());
+        let run_metrics = self.cache_run_metrics.clone();
+
         // Set checkpointer if both saver and config are available
         if let (Some(saver), Some(cfg)) = (&self.checkpoint_saver, config) {
             pregel_loop = pregel_loop.with_checkpointer(saver.clone(), cfg);
         }
 
         // Set interrupt configuration
-        if !self.interrupt_config.interrupt_before.is_empty() {
-            let nodes: std::collections::HashSet<String> =
-                self.interrupt_config.interrupt_before.iter().cloned().collect();
-            pregel_loop = pregel_loop.with_interrupt_before(nodes);
-        }
-        if !self.interrupt_config.interrupt_after.is_empty() {
-            let nodes: std::collections::HashSet<String> =
-                self.interrupt_config.interrupt_after.iter().cloned().collect();
-            pregel_loop = pregel_loop.with_interrupt_after(nodes);
-        }
+        pregel_loop = self.apply_interrupt_config(pregel_loop);
 
         // Spawn the execution in a background task
         tokio::spawn(async move {
             if let Err(e) = pregel_loop.run().await {
                 tracing::error!(error = %e, "Streaming execution failed");
             }
+            run_metrics.publish(&metrics, thread_id.as_deref());
         });
 
         // Return stream of chunks directly
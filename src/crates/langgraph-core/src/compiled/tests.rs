@@ -54,6 +54,7 @@ mod tests {
             interrupt_after: vec![],
             interrupt_before_all: false,
             interrupt_after_all: false,
+            interrupt_conditions: vec![],
         };
 
         let compiled = graph.compile().unwrap()
@@ -268,6 +269,7 @@ mod tests {
             interrupt_after: vec![],
             interrupt_before_all: false,
             interrupt_after_all: false,
+            interrupt_conditions: vec![],
         };
 
         let compiled = graph.compile().unwrap()
@@ -412,6 +414,7 @@ mod tests {
             interrupt_after: vec![],
             interrupt_before_all: false,
             interrupt_after_all: false,
+            interrupt_conditions: vec![],
         };
 
         let compiled = graph.compile().unwrap()
@@ -534,6 +537,7 @@ mod tests {
             interrupt_after: vec!["processing".to_string()],
             interrupt_before_all: false,
             interrupt_after_all: false,
+            interrupt_conditions: vec![],
         };
 
         let compiled = graph.compile().unwrap()
@@ -555,6 +559,70 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    /// Graph whose `review` node counts its runs, behind a conditional
+    /// breakpoint on low `confidence`
+    fn low_confidence_graph(
+        reviews: Arc<std::sync::atomic::AtomicUsize>,
+    ) -> crate::CompiledGraph {
+        let mut graph = StateGraph::new();
+        graph.add_node("draft", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("review", move |state| {
+            let reviews = reviews.clone();
+            Box::pin(async move {
+                reviews.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(state)
+            })
+        });
+        graph.add_edge("__start__", "draft");
+        graph.add_edge("draft", "review");
+        graph.add_edge("review", "__end__");
+
+        let interrupt_config = InterruptConfig::new().with_interrupt_condition(
+            "low_confidence",
+            |state: &serde_json::Value| state["confidence"].as_f64().is_some_and(|c| c < 0.5),
+        );
+        graph.compile().unwrap().with_interrupt_config(interrupt_config)
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_condition_pauses_compiled_graph() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let reviews = Arc::new(AtomicUsize::new(0));
+        let compiled = low_confidence_graph(reviews.clone());
+
+        let output = compiled.invoke(json!({"confidence": 0.9})).await.unwrap();
+        assert_eq!(output["confidence"], 0.9);
+        assert_eq!(reviews.load(Ordering::SeqCst), 1);
+
+        match compiled.invoke(json!({"confidence": 0.2})).await {
+            Err(GraphError::Interrupted { node, reason }) => {
+                assert_eq!(node, "draft");
+                assert!(reason.contains("low_confidence"));
+            }
+            other => panic!("Expected Interrupted error, got {:?}", other),
+        }
+        assert_eq!(reviews.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_condition_applies_to_streaming() {
+        use crate::stream::StreamMode;
+        use futures::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let reviews = Arc::new(AtomicUsize::new(0));
+        let compiled = low_confidence_graph(reviews.clone());
+
+        let stream = compiled
+            .stream_chunks_with_modes(json!({"confidence": 0.2}), vec![StreamMode::Values], None)
+            .await
+            .unwrap();
+        let _chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(reviews.load(Ordering::SeqCst), 0);
+    }
+
     /// Test: Resume from specific checkpoint ID
     ///
     /// Verifies that execution can resume from a specific historical checkpoint,
@@ -588,6 +656,7 @@ mod tests {
             interrupt_after: vec![],
             interrupt_before_all: false,
             interrupt_after_all: false,
+            interrupt_conditions: vec![],
         };
 
         let compiled = graph.compile().unwrap()
@@ -806,4 +875,668 @@ mod tests {
             assert!(!tuple.checkpoint.id.is_empty());
         }
     }
+
+    #[tokio::test]
+    async fn test_conditional_only_target_runs_only_when_routed_to() {
+        use crate::send::ConditionalEdgeResult;
+        use std::collections::HashMap;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let build = |route_to: &'static str, runs: Arc<AtomicUsize>| {
+            let mut graph = StateGraph::new();
+            graph.add_node("router", |state| Box::pin(async move { Ok(state) }));
+            graph.add_node("branch", move |state| {
+                let runs = runs.clone();
+                Box::pin(async move {
+                    runs.fetch_add(1, Ordering::SeqCst);
+                    Ok(state)
+                })
+            });
+            graph.add_edge("__start__", "router");
+            graph.add_edge("branch", "__end__");
+
+            // "branch" has no direct incoming edge, only this conditional one
+            let mut branches = HashMap::new();
+            branches.insert("branch".to_string(), "branch".to_string());
+            branches.insert("__end__".to_string(), "__end__".to_string());
+            graph.add_conditional_edge(
+                "router",
+                move |_| ConditionalEdgeResult::Node(route_to.to_string()),
+                branches,
+            );
+            graph.compile().unwrap()
+        };
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        build("__end__", runs.clone()).invoke(json!({"value": 1})).await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 0, "not routed to, so it must not start the graph");
+
+        build("branch", runs.clone()).invoke(json!({"value": 1})).await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_conditional_only_target_gets_no_start_trigger() {
+        use crate::cache::CacheMetricsRecorder;
+        use crate::send::ConditionalEdgeResult;
+        use std::collections::HashMap;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("router", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("branch", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("orphan", |state| Box::pin(async move { Ok(state) }));
+        graph.add_edge("__start__", "router");
+        graph.add_edge("branch", "__end__");
+        graph.add_edge("orphan", "__end__");
+
+        let mut branches = HashMap::new();
+        branches.insert("branch".to_string(), "branch".to_string());
+        branches.insert("__end__".to_string(), "__end__".to_string());
+        graph.add_conditional_edge(
+            "router",
+            |_| ConditionalEdgeResult::Node("__end__".to_string()),
+            branches,
+        );
+        let compiled = graph.compile().unwrap();
+
+        let (_, _, nodes) = compiled
+            .build_pregel_parts(json!({}), Arc::new(CacheMetricsRecorder::new()))
+            .unwrap();
+
+        // Only reachable through the router: no START trigger
+        assert!(nodes["branch"].triggers.is_empty());
+        // No incoming edge of any kind: still starts the graph
+        assert_eq!(nodes["orphan"].triggers, vec!["__start__".to_string()]);
+        assert_eq!(nodes["router"].triggers, vec!["__start__".to_string()]);
+    }
+
+    fn build_self_loop(counter: Arc<std::sync::atomic::AtomicUsize>) -> StateGraph {
+        use crate::send::ConditionalEdgeResult;
+        use std::collections::HashMap;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("loop", move |state| {
+            let counter = counter.clone();
+            Box::pin(async move {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(state)
+            })
+        });
+        graph.add_edge("__start__", "loop");
+
+        // Declares an exit so it compiles, but the router never takes it
+        let mut branches = HashMap::new();
+        branches.insert("again".to_string(), "loop".to_string());
+        branches.insert("done".to_string(), "__end__".to_string());
+        graph.add_conditional_edge(
+            "loop",
+            |_| ConditionalEdgeResult::Node("loop".to_string()),
+            branches,
+        );
+        graph
+    }
+
+    #[tokio::test]
+    async fn test_recursion_limit_from_compile_options() {
+        use crate::builder::CompileOptions;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let compiled = build_self_loop(counter.clone())
+            .compile_with_options(CompileOptions { recursion_limit: 5, ..Default::default() })
+            .unwrap();
+
+        let result = compiled.invoke(json!({"value": 1})).await;
+        match result {
+            Err(GraphError::RecursionLimitReached { limit, last_node }) => {
+                assert_eq!(limit, 5);
+                assert_eq!(last_node.as_deref(), Some("loop"));
+            }
+            other => panic!("expected RecursionLimitReached, got {:?}", other),
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_recursion_limit_default_and_per_invocation_override() {
+        use crate::builder::DEFAULT_RECURSION_LIMIT;
+        use langgraph_checkpoint::CheckpointConfig;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let compiled = build_self_loop(counter.clone()).compile().unwrap();
+        assert_eq!(compiled.recursion_limit(), DEFAULT_RECURSION_LIMIT);
+
+        let config = CheckpointConfig::new().with_recursion_limit(3);
+        let result = compiled.invoke_with_config(json!({"value": 1}), Some(config)).await;
+
+        assert!(matches!(
+            result,
+            Err(GraphError::RecursionLimitReached { limit: 3, .. })
+        ));
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_recursion_limit_counts_from_resume_point() {
+        use crate::builder::CompileOptions;
+        use crate::send::ConditionalEdgeResult;
+        use futures::StreamExt;
+        use langgraph_checkpoint::CheckpointConfig;
+        use std::collections::HashMap;
+        use std::sync::atomic::{AtomicI64, Ordering};
+
+        // Counts up until it reaches a target the test can raise between runs
+        let target = Arc::new(AtomicI64::new(3));
+        let mut graph = StateGraph::new();
+        graph.add_node("count", |mut state| Box::pin(async move {
+            let count = state["count"].as_i64().unwrap_or(0);
+            state["count"] = json!(count + 1);
+            Ok(state)
+        }));
+        graph.add_edge("__start__", "count");
+        let mut branches = HashMap::new();
+        branches.insert("again".to_string(), "count".to_string());
+        branches.insert("done".to_string(), "__end__".to_string());
+        let router_target = target.clone();
+        graph.add_conditional_edge(
+            "count",
+            move |state| {
+                if state["count"].as_i64().unwrap_or(0) >= router_target.load(Ordering::SeqCst) {
+                    ConditionalEdgeResult::Node("__end__".to_string())
+                } else {
+                    ConditionalEdgeResult::Node("count".to_string())
+                }
+            },
+            branches,
+        );
+
+        let compiled = graph
+            .compile_with_options(CompileOptions { recursion_limit: 4, ..Default::default() })
+            .unwrap()
+            .with_checkpointer(Arc::new(InMemoryCheckpointSaver::new()));
+        let config = CheckpointConfig::new().with_thread_id("resume-limit".to_string());
+
+        let result = compiled
+            .invoke_with_config(json!({"count": 0}), Some(config.clone()))
+            .await
+            .unwrap();
+        assert_eq!(result["count"], 3);
+
+        // Fork after the first step and finish in exactly `limit` more supersteps;
+        // counting from step 0 would hit the limit before the loop exits
+        let history: Vec<_> = compiled.get_state_history_chronological(&config).await.unwrap()
+            .map(|s| s.unwrap())
+            .collect()
+            .await;
+        let fork_point = history.iter()
+            .find(|s| s.values["state"]["count"] == 2)
+            .expect("checkpoint with count 2");
+        assert_eq!(fork_point.metadata.as_ref().and_then(|m| m.step), Some(1));
+        let fork_id = fork_point.config.checkpoint_id.clone().unwrap();
+
+        target.store(5, Ordering::SeqCst);
+        let forked = compiled
+            .invoke_from_checkpoint(&fork_id, None, config)
+            .await
+            .unwrap();
+        assert_eq!(forked["count"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_on_superstep_reports_each_superstep() {
+        use crate::builder::CompileOptions;
+        use crate::pregel::SuperstepInfo;
+        use std::sync::Mutex;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("fetch", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("left", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("right", |state| Box::pin(async move { Ok(state) }));
+        graph.add_edge("__start__", "fetch");
+        graph.add_edge("fetch", "left");
+        graph.add_edge("fetch", "right");
+        graph.add_edge("left", "__end__");
+        graph.add_edge("right", "__end__");
+
+        let infos: Arc<Mutex<Vec<SuperstepInfo>>> = Arc::new(Mutex::new(Vec::new()));
+        let recorded = infos.clone();
+        let compiled = graph
+            .compile_with_options(CompileOptions {
+                on_superstep: Some(Arc::new(move |info: SuperstepInfo| recorded.lock().unwrap().push(info))),
+                ..Default::default()
+            })
+            .unwrap();
+
+        compiled.invoke(json!({"value": 1})).await.unwrap();
+
+        let steps: Vec<(usize, usize)> = infos
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|info| (info.step, info.active_nodes))
+            .collect();
+        assert_eq!(steps, vec![(0, 1), (1, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_runs_inputs_concurrently_and_isolated() {
+        use langgraph_checkpoint::CheckpointConfig;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (node_running, node_peak) = (running.clone(), peak.clone());
+
+        let mut graph = StateGraph::new();
+        graph.add_node("double", move |state| {
+            let running = node_running.clone();
+            let peak = node_peak.clone();
+            Box::pin(async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+
+                let value = state["value"].as_i64().unwrap_or(0);
+                if value < 0 {
+                    return Err(GraphError::Execution("negative input".to_string()));
+                }
+                Ok(json!({"value": value * 2}))
+            })
+        });
+        graph.add_edge("__start__", "double");
+        graph.add_edge("double", "__end__");
+
+        let saver = Arc::new(InMemoryCheckpointSaver::new());
+        let compiled = graph.compile().unwrap().with_checkpointer(saver.clone());
+
+        let inputs = vec![json!({"value": 1}), json!({"value": -1}), json!({"value": 3}), json!({"value": 4})];
+        let config = CheckpointConfig::new().with_thread_id("eval".to_string());
+        let results = compiled.batch_with_config(inputs, 2, Some(config)).await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &json!({"value": 2}));
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap(), &json!({"value": 6}));
+        assert_eq!(results[3].as_ref().unwrap(), &json!({"value": 8}));
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+
+        // Each input checkpointed under its own thread
+        for (index, expected) in [(0, 2), (2, 6), (3, 8)] {
+            let thread = CheckpointConfig::new().with_thread_id(format!("eval-{}", index));
+            let tuple = saver.get_tuple(&thread).await.unwrap().unwrap();
+            assert_eq!(
+                tuple.checkpoint.channel_values.get("double"),
+                Some(&json!({"value": expected}))
+            );
+        }
+    }
+
+    fn build_cached_graph(runs: Arc<std::sync::atomic::AtomicUsize>) -> StateGraph {
+        use crate::pregel::CachePolicy;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("expensive", move |mut state| {
+            let runs = runs.clone();
+            Box::pin(async move {
+                runs.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let x = state["x"].as_i64().unwrap_or(0);
+                state["squared"] = json!(x * x);
+                Ok(state)
+            })
+        });
+        graph.set_cache_policy("expensive", CachePolicy::new());
+        graph.add_edge("__start__", "expensive");
+        graph.add_edge("expensive", "__end__");
+        graph
+    }
+
+    #[tokio::test]
+    async fn test_node_cache_skips_repeat_execution() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let compiled = build_cached_graph(runs.clone()).compile().unwrap();
+
+        let first = compiled.invoke(json!({"x": 7})).await.unwrap();
+        let second = compiled.invoke(json!({"x": 7})).await.unwrap();
+        assert_eq!(first["squared"], 49);
+        assert_eq!(second, first);
+        assert_eq!(runs.load(Ordering::SeqCst), 1, "Identical input should hit the cache");
+
+        // Different input misses
+        let third = compiled.invoke(json!({"x": 3})).await.unwrap();
+        assert_eq!(third["squared"], 9);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_metrics_report_hits_on_second_run() {
+        use std::sync::atomic::AtomicUsize;
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let compiled = build_cached_graph(runs)
+            .compile()
+            .unwrap()
+            .with_checkpointer(Arc::new(InMemoryCheckpointSaver::new()));
+        let config = langgraph_checkpoint::CheckpointConfig::new()
+            .with_thread_id("cache-metrics".to_string());
+
+        compiled.invoke_with_config(json!({"x": 4}), Some(config.clone())).await.unwrap();
+        let first = compiled.cache_metrics();
+        assert_eq!((first.hits, first.misses), (0, 1));
+
+        compiled.invoke_with_config(json!({"x": 4}), Some(config.clone())).await.unwrap();
+        let second = compiled.cache_metrics();
+        assert_eq!((second.hits, second.misses), (1, 0));
+        assert_eq!(second.entries, 1);
+        assert_eq!(second.hit_ratio(), 1.0);
+
+        // The latest snapshot carries the metrics of the run that produced it
+        let snapshot = compiled.get_state(&config).await.unwrap().unwrap();
+        assert_eq!(snapshot.cache_metrics.map(|m| m.hits), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_cache_metrics_are_kept_per_thread() {
+        use std::sync::atomic::AtomicUsize;
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let compiled = build_cached_graph(runs)
+            .compile()
+            .unwrap()
+            .with_checkpointer(Arc::new(InMemoryCheckpointSaver::new()));
+        let thread = |id: &str| {
+            langgraph_checkpoint::CheckpointConfig::new().with_thread_id(id.to_string())
+        };
+
+        // Warm the cache for x=2 only
+        compiled.invoke(json!({"x": 2})).await.unwrap();
+
+        let (hit, miss) = tokio::join!(
+            compiled.invoke_with_config(json!({"x": 2}), Some(thread("hit"))),
+            compiled.invoke_with_config(json!({"x": 5}), Some(thread("miss"))),
+        );
+        hit.unwrap();
+        miss.unwrap();
+
+        let hit = compiled.get_state(&thread("hit")).await.unwrap().unwrap();
+        let hit = hit.cache_metrics.unwrap();
+        assert_eq!((hit.hits, hit.misses), (1, 0));
+
+        let miss = compiled.get_state(&thread("miss")).await.unwrap().unwrap();
+        let miss = miss.cache_metrics.unwrap();
+        assert_eq!((miss.hits, miss.misses), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_request_context_is_readable_and_not_checkpointed() {
+        #[derive(serde::Deserialize)]
+        struct RequestInfo {
+            user_id: String,
+        }
+
+        let mut graph = StateGraph::new();
+        graph.add_node("greet", |mut state| {
+            let info = crate::runtime::get_context::<RequestInfo>();
+            Box::pin(async move {
+                state["user_id"] = json!(info.map(|info| info.user_id));
+                Ok(state)
+            })
+        });
+        graph.add_edge("__start__", "greet");
+        graph.add_edge("greet", "__end__");
+
+        let compiled = graph
+            .compile()
+            .unwrap()
+            .with_checkpointer(Arc::new(InMemoryCheckpointSaver::new()));
+        let config = langgraph_checkpoint::CheckpointConfig::new()
+            .with_thread_id("request-context".to_string());
+
+        let result = compiled
+            .invoke_with_config(
+                json!({"name": "ada"}),
+                Some(config.clone().with_context(json!({"user_id": "u-42", "trace_id": "t-1"}))),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result["user_id"], "u-42");
+        assert!(result.get("__request_context__").is_none());
+
+        let snapshot = compiled.get_state(&config).await.unwrap().unwrap();
+        assert!(!snapshot.values.to_string().contains("t-1"));
+        assert!(snapshot.config.context().is_none());
+
+        // Without a context the node sees nothing
+        let result = compiled.invoke(json!({"name": "ada"})).await.unwrap();
+        assert!(result["user_id"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_request_context_on_streaming_and_fork_paths() {
+        use crate::stream::StreamMode;
+        use futures::StreamExt;
+
+        let mut graph = StateGraph::new();
+        graph.add_node("load", |state| Box::pin(async move { Ok(state) }));
+        graph.add_node("greet", |mut state| {
+            let user_id = crate::runtime::get_context::<serde_json::Value>()
+                .and_then(|context| context.get("user_id").cloned());
+            Box::pin(async move {
+                state["user_id"] = user_id.unwrap_or(serde_json::Value::Null);
+                Ok(state)
+            })
+        });
+        graph.add_edge("__start__", "load");
+        graph.add_edge("load", "greet");
+        graph.add_edge("greet", "__end__");
+
+        let saver = Arc::new(InMemoryCheckpointSaver::new());
+        let compiled = graph.compile().unwrap().with_checkpointer(saver.clone());
+        let mut config = langgraph_checkpoint::CheckpointConfig::new()
+            .with_thread_id("request-context-paths".to_string());
+        config.extra.insert("context".to_string(), json!("user-owned"));
+        let with_context =
+            |config: &langgraph_checkpoint::CheckpointConfig, user_id: &str| {
+                config.clone().with_context(json!({"user_id": user_id, "trace_id": "t-1"}))
+            };
+        assert_eq!(with_context(&config, "u-1").extra["context"], "user-owned");
+
+        let stream = compiled
+            .stream_chunks_with_modes(
+                json!({"name": "ada"}),
+                vec![StreamMode::Values],
+                Some(with_context(&config, "u-1")),
+            )
+            .await
+            .unwrap();
+        let _chunks: Vec<_> = stream.collect().await;
+
+        let snapshot = compiled.get_state(&config).await.unwrap().unwrap();
+        assert_eq!(snapshot.values["state"]["user_id"], "u-1");
+        assert!(!snapshot.values.to_string().contains("t-1"));
+        assert!(snapshot.config.context().is_none());
+
+        // Fork from before `greet` so it runs again
+        let history: Vec<_> = saver
+            .list(Some(&config), None, None, None)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        let fork_id = history
+            .into_iter()
+            .map(Result::unwrap)
+            .min_by_key(|tuple| tuple.metadata.step)
+            .and_then(|tuple| tuple.config.checkpoint_id)
+            .unwrap();
+        let result = compiled
+            .invoke_from_checkpoint(
+                &fork_id,
+                Some(json!({"name": "grace"})),
+                with_context(&config, "u-2"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result["user_id"], "u-2");
+
+        let snapshot = compiled.get_state(&config).await.unwrap().unwrap();
+        assert!(!snapshot.values.to_string().contains("t-1"));
+        assert!(snapshot.config.context().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_for_typed_resumes_through_compiled_graph() {
+        use crate::inline_interrupt::{interrupt_for_typed, InlineResumeValue, ResumeAction};
+
+        #[derive(serde::Deserialize)]
+        struct Approval {
+            approved: bool,
+            reviewer: String,
+        }
+
+        let mut graph = StateGraph::new();
+        graph.add_node("draft", |mut state| {
+            Box::pin(async move {
+                state["draft"] = json!("refund $20");
+                Ok(state)
+            })
+        });
+        graph.add_node("review", |mut state| {
+            let approval = interrupt_for_typed::<Approval>("Approve the refund?");
+            Box::pin(async move {
+                let approval = approval?;
+                state["approved"] = json!(approval.approved);
+                state["reviewer"] = json!(approval.reviewer);
+                Ok(state)
+            })
+        });
+        graph.add_edge("__start__", "draft");
+        graph.add_edge("draft", "review");
+        graph.add_edge("review", "__end__");
+
+        let compiled = graph
+            .compile()
+            .unwrap()
+            .with_checkpointer(Arc::new(InMemoryCheckpointSaver::new()));
+        let config = langgraph_checkpoint::CheckpointConfig::new()
+            .with_thread_id("typed-interrupt".to_string());
+
+        let err = compiled
+            .invoke_with_config(json!({"amount": 20}), Some(config.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GraphError::InlineInterrupt(_)), "{:?}", err);
+
+        let resume = InlineResumeValue {
+            action: ResumeAction::Continue,
+            updates: Some(json!({"approved": true, "reviewer": "ada"})),
+            inputs: None,
+            metadata: None,
+        };
+        let result = compiled.resume_from_interrupt(resume, config.clone()).await.unwrap();
+        assert_eq!(result["draft"], "refund $20");
+        assert_eq!(result["approved"], true);
+        assert_eq!(result["reviewer"], "ada");
+
+        // A payload of the wrong shape is reported, not silently accepted
+        let compiled_err = compiled
+            .invoke_with_config(json!({"amount": 5}), Some(config.clone().with_thread_id("typed-bad".to_string())))
+            .await
+            .unwrap_err();
+        assert!(matches!(compiled_err, GraphError::InlineInterrupt(_)));
+        let bad = InlineResumeValue {
+            action: ResumeAction::Continue,
+            updates: Some(json!({"approved": "yes"})),
+            inputs: None,
+            metadata: None,
+        };
+        let err = compiled
+            .resume_from_interrupt(bad, config.with_thread_id("typed-bad".to_string()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    /// Test chronological state history with checkpoint lineage
+    #[tokio::test]
+    async fn test_state_history_oldest_to_newest() {
+        use langgraph_checkpoint::CheckpointConfig;
+        use futures::stream::StreamExt;
+
+        let mut graph = StateGraph::new();
+        for (name, delta) in [("step1", 1), ("step2", 2), ("step3", 3)] {
+            graph.add_node(name, move |mut state| Box::pin(async move {
+                if let Some(obj) = state.as_object_mut() {
+                    let val = obj.get("counter").and_then(|v| v.as_i64()).unwrap_or(0);
+                    obj.insert("counter".to_string(), json!(val + delta));
+                }
+                Ok(state)
+            }));
+        }
+        graph.add_edge("__start__", "step1");
+        graph.add_edge("step1", "step2");
+        graph.add_edge("step2", "step3");
+        graph.add_edge("step3", "__end__");
+
+        let saver = Arc::new(InMemoryCheckpointSaver::new());
+        let compiled = graph.compile().unwrap().with_checkpointer(saver);
+
+        let config = CheckpointConfig::new().with_thread_id("history-thread".to_string());
+        compiled.invoke_with_config(json!({"counter": 0}), Some(config.clone())).await.unwrap();
+
+        let snapshots: Vec<_> = compiled.get_state_history_chronological(&config).await.unwrap()
+            .map(|s| s.unwrap())
+            .collect()
+            .await;
+        assert!(snapshots.len() >= 3, "Expected one checkpoint per superstep");
+
+        // Steps increase from oldest to newest
+        let steps: Vec<i32> = snapshots.iter()
+            .map(|s| s.metadata.as_ref().and_then(|m| m.step).unwrap())
+            .collect();
+        let mut sorted = steps.clone();
+        sorted.sort();
+        assert_eq!(steps, sorted);
+
+        // Every snapshot has its own id and links to the previous one
+        assert!(snapshots[0].parent_config.is_none());
+        for pair in snapshots.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            assert!(prev.config.checkpoint_id.is_some());
+            assert_ne!(prev.config.checkpoint_id, next.config.checkpoint_id);
+            assert_eq!(
+                next.parent_config.as_ref().and_then(|p| p.checkpoint_id.clone()),
+                prev.config.checkpoint_id
+            );
+        }
+
+        // Any historical snapshot can be fetched again by its config
+        let first = compiled.get_state(&snapshots[0].config).await.unwrap().unwrap();
+        assert_eq!(first.config.checkpoint_id, snapshots[0].config.checkpoint_id);
+    }
+
+    #[test]
+    fn test_per_thread_cache_metrics_are_bounded() {
+        use crate::compiled::graph::{RunCacheMetrics, MAX_THREAD_CACHE_METRICS};
+        use crate::cache::CacheMetricsRecorder;
+
+        let metrics = RunCacheMetrics::default();
+        let recorder = CacheMetricsRecorder::new();
+        recorder.record_hit();
+        for i in 0..MAX_THREAD_CACHE_METRICS {
+            metrics.publish(&recorder, Some(&format!("thread-{}", i)));
+        }
+        // Republishing keeps a thread from being the oldest
+        metrics.publish(&recorder, Some("thread-0"));
+        metrics.publish(&recorder, Some("one-too-many"));
+
+        assert!(metrics.for_thread("thread-0").is_some());
+        assert!(metrics.for_thread("thread-1").is_none());
+        assert_eq!(metrics.for_thread("one-too-many").unwrap().hits, 1);
+        assert_eq!(metrics.latest().hits, 1);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module contains the core types used throughout the compiled graph execution engine.
 
+use crate::cache::CacheMetrics;
 use crate::graph::NodeId;
 use crate::interrupt::InterruptWhen;
 use crate::stream::StreamChunk;
@@ -68,7 +69,8 @@ use std::pin::Pin;
 ///
 /// Emitted when execution pauses for human input. Contains:
 /// - Node where interrupt occurred
-/// - When (before/after node execution)
+/// - When (before/after node execution, or on a condition)
+/// - Name of the tripped condition, for condition interrupts
 /// - Current state at interrupt point
 ///
 /// Use for:
@@ -217,6 +219,9 @@ pub enum ExecutionEvent {
         node: NodeId,
         /// Whether interrupt was before or after node execution
         when: InterruptWhen,
+        /// Name of the [`InterruptCondition`](crate::interrupt::InterruptCondition)
+        /// that tripped, for [`InterruptWhen::Condition`] interrupts
+        condition: Option<String>,
         /// Current state at the interrupt point
         state: Value,
     },
@@ -338,6 +343,7 @@ pub type StreamChunkStream = Pin<Box<dyn Stream<Item = StreamChunk> + Send>>;
 ///     created_at: Some("2024-01-15T10:30:00Z".to_string()),
 ///     parent_config: Some(CheckpointConfig::new("thread-1")
 ///         .with_checkpoint_id("checkpoint-455")),
+///     cache_metrics: None,
 /// };
 ///
 /// // Check if execution is complete
@@ -390,6 +396,14 @@ pub struct StateSnapshot {
     /// Links to the previous checkpoint in the execution chain.
     /// None for the initial checkpoint.
     pub parent_config: Option<CheckpointConfig>,
+
+    /// Node cache metrics for the run that produced this state.
+    ///
+    /// Only the node result cache is counted. Only set on the latest snapshot
+    /// from [`get_state`](super::CompiledGraph::get_state) when the graph has
+    /// cached nodes, and only for threads among the 1024 that most recently
+    /// finished a run.
+    pub cache_metrics: Option<CacheMetrics>,
 }
 
 /// Stream of state snapshots for history traversal
@@ -83,8 +83,12 @@ impl CompiledGraph {
                                 return None; // Skip this checkpoint
                             }
                         }
-                        // Convert to StateSnapshot
-                        Some(Ok(Self::checkpoint_tuple_to_snapshot_static(tuple)))
+                        // Convert to StateSnapshot, masking redacted channels
+                        let snapshot = Self::checkpoint_tuple_to_snapshot_static(tuple);
+                        Some(Ok(match filter {
+                            Some(ref f) => f.redact(snapshot),
+                            None => snapshot,
+                        }))
                     }
                     Err(e) => Some(Err(GraphError::Checkpoint(e))),
                 }
@@ -178,7 +182,9 @@ impl CompiledGraph {
 mod tests {
     use super::*;
     use crate::builder::StateGraph;
-    use langgraph_checkpoint::{InMemoryCheckpointSaver, checkpoint::CheckpointSource};
+    use langgraph_checkpoint::{
+        checkpoint::CheckpointSource, CheckpointSaver, InMemoryCheckpointSaver,
+    };
     use serde_json::json;
     use std::sync::Arc;
 
@@ -321,4 +327,45 @@ mod tests {
             assert_eq!(snapshot.values["value"], 42);
         }
     }
+    #[tokio::test]
+    async fn test_redacted_channels_masked_in_snapshots_only() {
+        let mut graph = StateGraph::new();
+        graph.add_node("process", |state| Box::pin(async move { Ok(state) }));
+        graph.add_edge("__start__", "process");
+        graph.add_edge("process", "__end__");
+
+        let checkpointer = Arc::new(InMemoryCheckpointSaver::new());
+        let compiled = graph
+            .compile()
+            .unwrap()
+            .with_checkpointer(checkpointer.clone());
+
+        let config = CheckpointConfig::new()
+            .with_thread_id("test-thread".to_string());
+
+        compiled
+            .invoke_with_config(json!({"api_key": "sk-secret", "query": "hello"}), Some(config.clone()))
+            .await
+            .unwrap();
+
+        let filter = StateHistoryFilter::new().redact_channels(&["api_key"]);
+        let mut history = compiled
+            .get_state_history_filtered(&config, Some(filter), None, None)
+            .await
+            .unwrap();
+
+        let mut snapshots = 0;
+        while let Some(snapshot) = history.next().await {
+            let values = serde_json::to_string(&snapshot.unwrap().values).unwrap();
+            assert!(!values.contains("sk-secret"), "api_key leaked: {}", values);
+            assert!(values.contains("hello"));
+            snapshots += 1;
+        }
+        assert!(snapshots > 0, "Should emit at least one snapshot");
+
+        // The stored checkpoint still holds the real value
+        let tuple = checkpointer.get_tuple(&config).await.unwrap().unwrap();
+        let stored = serde_json::to_string(&tuple.checkpoint.channel_values).unwrap();
+        assert!(stored.contains("sk-secret"));
+    }
 }
\ No newline at end of file
@@ -231,6 +231,7 @@
 //! - [`langgraph_checkpoint::CheckpointError`] - Checkpoint-specific errors
 
 use thiserror::Error;
+use crate::graph::NodeId;
 use crate::inline_interrupt::InlineInterruptState;
 
 /// Convenience result type using [`GraphError`]
@@ -330,6 +331,34 @@ pub enum GraphError {
     #[error("Graph validation failed: {0}")]
     Validation(String),
 
+    /// Graph contains a cycle that can never terminate
+    ///
+    /// Occurs during graph compilation when a cycle is made only of
+    /// unconditional edges, or its conditional edges never lead to `__end__`.
+    /// Such a graph would only fail at runtime by hitting the recursion limit.
+    /// Loops that have a conditional exit towards `__end__` are legitimate and
+    /// are not reported.
+    ///
+    /// **Recovery**: Replace one edge in the cycle with a conditional edge that
+    /// can route out of the loop, or, if a node leaves the loop with a
+    /// `Command` goto, declare it with `StateGraph::add_goto_destinations`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::error::GraphError;
+    ///
+    /// let err = GraphError::CycleDetected {
+    ///     path: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+    /// };
+    /// assert_eq!(format!("{}", err), "Cycle detected with no path to __end__: a -> b -> a");
+    /// ```
+    #[error("Cycle detected with no path to __end__: {}", .path.join(" -> "))]
+    CycleDetected {
+        /// Nodes forming the cycle, with the first node repeated at the end
+        path: Vec<NodeId>,
+    },
+
     /// Node execution failed with context
     ///
     /// Occurs when a node's executor function returns an error during execution.
@@ -526,6 +555,77 @@ pub enum GraphError {
         duration_ms: u64,
     },
 
+    /// Graph ran for more supersteps than the recursion limit allows
+    ///
+    /// Occurs when execution keeps scheduling nodes and never reaches a state
+    /// where no tasks remain.
+    ///
+    /// **Common causes**:
+    /// - A conditional loop whose router never routes to `__end__`
+    /// - A limit set too low for a legitimately long workflow
+    ///
+    /// **Recovery**: Fix the loop exit condition, or raise the limit with
+    /// [`CompileOptions::recursion_limit`](crate::builder::CompileOptions::recursion_limit)
+    /// or per invocation with `CheckpointConfig::with_recursion_limit`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::error::GraphError;
+    ///
+    /// let err = GraphError::RecursionLimitReached {
+    ///     limit: 25,
+    ///     last_node: Some("agent".to_string()),
+    /// };
+    /// assert_eq!(
+    ///     format!("{}", err),
+    ///     "Recursion limit of 25 reached without hitting a stop condition (last node: agent)"
+    /// );
+    /// ```
+    #[error(
+        "Recursion limit of {limit} reached without hitting a stop condition{}",
+        .last_node.as_ref().map(|n| format!(" (last node: {})", n)).unwrap_or_default()
+    )]
+    RecursionLimitReached {
+        /// The superstep limit that was exceeded
+        limit: usize,
+        /// Node executed in the last superstep before the limit was hit
+        last_node: Option<NodeId>,
+    },
+
+    /// A call to an external provider (such as an LLM API) failed
+    ///
+    /// Carries the provider's own retry classification, so retry logic can
+    /// act on it without parsing the message.
+    ///
+    /// **Recovery**: Retry when `retryable`, waiting at least `retry_after`
+    /// if the provider asked for it
+    ///
+    /// **Breaking change**: this variant is new, so code that matches
+    /// `GraphError` exhaustively needs an arm for it (or a wildcard).
+    /// Retryable `llm::LlmError`s used to convert to `Execution` and now
+    /// convert to this variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::error::GraphError;
+    /// use std::time::Duration;
+    ///
+    /// let err = GraphError::provider("Rate limit exceeded", true, Some(Duration::from_secs(5)));
+    /// assert!(err.is_retryable_provider_error());
+    /// assert_eq!(err.retry_after(), Some(Duration::from_secs(5)));
+    /// ```
+    #[error("{message}")]
+    Provider {
+        /// Error message from the provider client
+        message: String,
+        /// Whether the same request may succeed if retried
+        retryable: bool,
+        /// How long the provider asked callers to wait before retrying
+        retry_after: Option<std::time::Duration>,
+    },
+
     /// Custom application-defined error
     ///
     /// Used for application-specific errors not covered by other variants.
@@ -550,6 +650,32 @@ pub enum GraphError {
 }
 
 impl GraphError {
+    /// Create a provider error with its retry classification
+    pub fn provider(
+        message: impl Into<String>,
+        retryable: bool,
+        retry_after: Option<std::time::Duration>,
+    ) -> Self {
+        Self::Provider {
+            message: message.into(),
+            retryable,
+            retry_after,
+        }
+    }
+
+    /// Whether this is a [`Provider`](Self::Provider) error marked retryable
+    pub fn is_retryable_provider_error(&self) -> bool {
+        matches!(self, Self::Provider { retryable: true, .. })
+    }
+
+    /// How long a provider asked callers to wait before retrying, if it said
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::Provider { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Create a node execution error with context
     ///
     /// Helper constructor for creating node execution errors with node name and error message.
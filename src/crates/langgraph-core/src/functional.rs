@@ -45,7 +45,8 @@
 
 use crate::{StateGraph, CompiledGraph, Result as GraphResult};
 use crate::error::GraphError;
-use crate::retry::RetryPolicy;
+use crate::pregel::CachePolicy;
+use crate::retry::{RetryPolicy, RetryState};
 use serde_json::Value;
 use std::sync::Arc;
 use std::future::Future;
@@ -59,8 +60,8 @@ pub type TaskFn = Arc<dyn Fn(Value) -> Pin<Box<dyn Future<Output = GraphResult<V
 /// Tasks are the building blocks of functional workflows. Each task:
 /// - Has a unique name for identification
 /// - Executes an async function
-/// - Can have retry policies
-/// - Can be cached (future feature)
+/// - Can have a retry policy
+/// - Can cache its results across workflow runs
 ///
 /// Tasks are composable and can be chained together to form workflows.
 #[derive(Clone)]
@@ -72,10 +73,10 @@ pub struct Task {
     pub executor: TaskFn,
     
     /// Optional retry policy for this task
-    pub retry_policy: Option<Vec<RetryPolicy>>,
+    pub retry_policy: Option<RetryPolicy>,
     
-    /// Whether to cache results (future feature)
-    pub cache: bool,
+    /// Optional cache policy for this task
+    pub cache_policy: Option<CachePolicy>,
 }
 
 impl Task {
@@ -107,23 +108,54 @@ impl Task {
             name: name.into(),
             executor: Arc::new(executor),
             retry_policy: None,
-            cache: false,
+            cache_policy: None,
         }
     }
 
-    /// Set retry policy for this task
-    pub fn with_retry(mut self, policies: Vec<RetryPolicy>) -> Self {
-        self.retry_policy = Some(policies);
+    /// Retry failed executions of this task according to `policy`
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
         self
     }
 
-    /// Enable caching for this task (placeholder for future implementation)
-    pub fn with_cache(mut self) -> Self {
-        self.cache = true;
+    /// Cache this task's results according to `policy`
+    ///
+    /// Repeated runs of the workflow with the same task input reuse the
+    /// cached output instead of executing the task again.
+    pub fn with_cache(mut self, policy: CachePolicy) -> Self {
+        self.cache_policy = Some(policy);
         self
     }
 }
 
+/// Run `executor`, retrying retryable errors with backoff until `policy`
+/// runs out of attempts
+///
+/// Failed attempts and the delays slept between them are recorded in
+/// `retry_state`.
+async fn execute_with_retry(
+    executor: &TaskFn,
+    state: Value,
+    policy: &RetryPolicy,
+    retry_state: &mut RetryState,
+) -> GraphResult<Value> {
+    loop {
+        match executor(state.clone()).await {
+            Ok(result) => return Ok(result),
+            Err(e) => {
+                retry_state.record_attempt(Some(e.to_string()));
+                if retry_state.attempts >= policy.max_attempts || !policy.is_retryable(&e) {
+                    return Err(e);
+                }
+
+                let delay = policy.calculate_delay(retry_state.attempts - 1);
+                tokio::time::sleep(delay).await;
+                retry_state.record_delay(delay);
+            }
+        }
+    }
+}
+
 /// Convenience function to create a task
 ///
 /// # Example
@@ -195,12 +227,34 @@ impl WorkflowBuilder {
         // Add all tasks as nodes
         for task in &self.tasks {
             let executor = task.executor.clone();
+            let retry_policy = task.retry_policy.clone();
+            let name = task.name.clone();
             graph.add_node(&task.name, move |state| {
                 let exec = executor.clone();
+                let retry_policy = retry_policy.clone();
+                let name = name.clone();
                 Box::pin(async move {
-                    exec(state).await
+                    let Some(policy) = retry_policy else {
+                        return exec(state).await;
+                    };
+                    let mut retry_state = RetryState::new();
+                    let result = execute_with_retry(&exec, state, &policy, &mut retry_state).await;
+                    if retry_state.attempts > 0 {
+                        tracing::debug!(
+                            task = %name,
+                            failed_attempts = retry_state.attempts,
+                            last_delay_ms = retry_state.last_delay.map(|d| d.as_millis() as u64),
+                            total_delay_ms = retry_state.total_delay.as_millis() as u64,
+                            succeeded = result.is_ok(),
+                            "Task retried"
+                        );
+                    }
+                    result
                 })
             });
+            if let Some(policy) = &task.cache_policy {
+                graph.set_cache_policy(&task.name, policy.clone());
+            }
         }
 
         // Chain tasks sequentially
@@ -360,4 +414,95 @@ mod tests {
         let result = workflow.invoke(json!({"n": 3})).await.unwrap();
         assert_eq!(result["n"], 11);
     }
+
+    #[tokio::test]
+    async fn test_flaky_task_retries() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let flaky = task("flaky", move |mut state| {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            Box::pin(async move {
+                if attempt < 3 {
+                    return Err(GraphError::Execution("connection reset".to_string()));
+                }
+                state["attempt"] = json!(attempt);
+                Ok(state)
+            })
+        })
+        .with_retry(RetryPolicy::new(3).with_initial_interval(0.001).with_jitter(false));
+
+        let workflow = Workflow::builder().add_task(flaky).build().unwrap();
+        let result = workflow.invoke(json!({})).await.unwrap();
+
+        assert_eq!(result["attempt"], 3);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_with_retry_records_delays() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let exec: TaskFn = Arc::new(move |state| {
+            let call = counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if call < 2 {
+                    return Err(GraphError::Execution("connection reset".to_string()));
+                }
+                Ok(state)
+            })
+        });
+        let policy = RetryPolicy::new(3).with_initial_interval(1.0).with_seed(42);
+
+        let mut retry_state = RetryState::new();
+        let started = tokio::time::Instant::now();
+        execute_with_retry(&exec, json!({}), &policy, &mut retry_state).await.unwrap();
+
+        let expected = policy.calculate_delay(0) + policy.calculate_delay(1);
+        assert_eq!(retry_state.attempts, 2);
+        assert_eq!(retry_state.last_delay, Some(policy.calculate_delay(1)));
+        assert_eq!(retry_state.total_delay, expected);
+        // The paused clock advances in whole milliseconds per sleep
+        let elapsed = started.elapsed();
+        assert!(elapsed >= expected && elapsed <= expected + std::time::Duration::from_millis(2));
+    }
+
+    #[tokio::test]
+    async fn test_flaky_task_without_retry_fails() {
+        let flaky = task("flaky", |_state| Box::pin(async move {
+            Err(GraphError::Execution("connection reset".to_string()))
+        }));
+
+        let workflow = Workflow::builder().add_task(flaky).build().unwrap();
+        let err = workflow.invoke(json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("connection reset"));
+    }
+
+    #[tokio::test]
+    async fn test_cached_task_runs_once_across_invocations() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let square = task("square", move |mut state| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let x = state["x"].as_i64().unwrap_or(0);
+                state["squared"] = json!(x * x);
+                Ok(state)
+            })
+        })
+        .with_cache(CachePolicy::new());
+
+        let workflow = Workflow::builder().add_task(square).build().unwrap();
+        let first = workflow.invoke(json!({"x": 6})).await.unwrap();
+        let second = workflow.invoke(json!({"x": 6})).await.unwrap();
+
+        assert_eq!(first["squared"], 36);
+        assert_eq!(second, first);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
 }
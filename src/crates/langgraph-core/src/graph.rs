@@ -57,6 +57,7 @@
 //!     reads: vec!["input".to_string()],
 //!     writes: vec!["output".to_string()],
 //!     subgraph: None,
+//!     cache_policy: None,
 //! };
 //!
 //! graph.add_node("process".to_string(), node_spec);
@@ -316,6 +317,7 @@ impl std::fmt::Debug for Edge {
 ///     reads: vec![],
 ///     writes: vec![],
 ///     subgraph: None,
+///     cache_policy: None,
 /// };
 ///
 /// let node2 = NodeSpec {
@@ -324,6 +326,7 @@ impl std::fmt::Debug for Edge {
 ///     reads: vec![],
 ///     writes: vec![],
 ///     subgraph: None,
+///     cache_policy: None,
 /// };
 ///
 /// graph.add_node("step1".to_string(), node1);
@@ -451,6 +454,7 @@ impl Graph {
     ///     reads: vec!["input".to_string()],
     ///     writes: vec!["output".to_string()],
     ///     subgraph: None,
+    ///     cache_policy: None,
     /// };
     ///
     /// graph.add_node("processor".to_string(), node_spec);
@@ -584,6 +588,7 @@ impl Graph {
     ///     reads: vec![],
     ///     writes: vec![],
     ///     subgraph: None,
+    ///     cache_policy: None,
     /// };
     ///
     /// graph.add_node("custom_start".to_string(), node_spec);
@@ -628,6 +633,7 @@ impl Graph {
     ///     reads: vec![],
     ///     writes: vec![],
     ///     subgraph: None,
+    ///     cache_policy: None,
     /// };
     ///
     /// graph.add_node("processor".to_string(), node);
@@ -790,6 +796,7 @@ pub trait SubgraphExecutor: Send + Sync {
 ///     reads: vec!["input_data".to_string()],
 ///     writes: vec!["output_data".to_string()],
 ///     subgraph: None,
+///     cache_policy: None,
 /// };
 /// ```
 ///
@@ -811,6 +818,7 @@ pub trait SubgraphExecutor: Send + Sync {
 ///     reads: vec!["agent_input".to_string()],
 ///     writes: vec!["agent_output".to_string()],
 ///     subgraph: Some(subgraph.clone()),
+///     cache_policy: None,
 /// };
 /// # }
 /// ```
@@ -850,6 +858,14 @@ pub struct NodeSpec {
     /// When present, indicates this node represents a nested graph execution.
     /// The executor typically wraps calls to `subgraph.invoke()`.
     pub subgraph: Option<Arc<dyn SubgraphExecutor>>,
+
+    /// Optional result cache for this node
+    ///
+    /// When set, the node's input is hashed into a cache key before execution.
+    /// On a hit the cached output is returned without running the executor;
+    /// on a miss the executor runs and its output is stored. Only use this for
+    /// deterministic nodes without side effects that must repeat.
+    pub cache_policy: Option<crate::pregel::CachePolicy>,
 }
 
 impl std::fmt::Debug for NodeSpec {
@@ -860,6 +876,7 @@ impl std::fmt::Debug for NodeSpec {
             .field("reads", &self.reads)
             .field("writes", &self.writes)
             .field("subgraph", &self.subgraph.as_ref().map(|sg| sg.name()))
+            .field("cache_policy", &self.cache_policy)
             .finish()
     }
 }
@@ -1246,6 +1263,7 @@ mod tests {
             reads: vec!["input".to_string()],
             writes: vec!["output".to_string()],
             subgraph: None,
+            cache_policy: None,
         };
 
         graph.add_node("node1".to_string(), node_spec);
@@ -1268,6 +1286,7 @@ mod tests {
             reads: vec![],
             writes: vec![],
             subgraph: None,
+            cache_policy: None,
         };
 
         graph.add_node("node1".to_string(), node_spec);
@@ -1463,6 +1482,7 @@ mod tests {
                 reads: vec![],
                 writes: vec![],
                 subgraph: None,
+                cache_policy: None,
             },
         );
 
@@ -1698,6 +1718,7 @@ mod tests {
             reads: vec!["input".to_string(), "config".to_string()],
             writes: vec!["output".to_string(), "logs".to_string()],
             subgraph: None,
+            cache_policy: None,
         };
 
         assert_eq!(node.reads.len(), 2);
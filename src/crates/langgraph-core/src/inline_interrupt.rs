@@ -613,6 +613,7 @@
 
 use crate::error::{GraphError, Result};
 use crate::runtime::get_runtime;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -653,6 +654,14 @@ pub enum InterruptType {
         current_values: Value,
     },
 
+    /// Request a value of a specific Rust type
+    Typed {
+        /// Prompt to display to user
+        prompt: String,
+        /// Name of the type the resume payload must deserialize into
+        type_name: String,
+    },
+
     /// Custom interrupt type
     Custom {
         /// Custom type name
@@ -814,6 +823,59 @@ pub fn interrupt_for_edit(
     })
 }
 
+/// Request a typed value from the user
+///
+/// On first execution this pauses the graph with an [`InterruptType::Typed`]
+/// interrupt. When the node is re-run after resuming, the `updates` payload
+/// of the resume value is deserialized into `T` and returned.
+///
+/// # Errors
+///
+/// - [`GraphError::InlineInterrupt`] when no resume value is available yet
+/// - [`GraphError::Execution`] if the resume aborts, carries no payload, or
+///   the payload does not match `T`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Approval { approved: bool, reviewer: String }
+///
+/// // Inside a node:
+/// let approval: Approval = interrupt_for_typed("Approve the refund?")?;
+/// ```
+pub fn interrupt_for_typed<T: DeserializeOwned>(prompt: impl Into<String>) -> Result<T> {
+    let prompt = prompt.into();
+    let type_name = std::any::type_name::<T>();
+
+    let resume = get_runtime().and_then(|runtime| runtime.get_resume_value());
+    let Some(resume) = resume else {
+        interrupt(InterruptType::Typed {
+            prompt,
+            type_name: type_name.to_string(),
+        })?;
+        unreachable!("interrupt always returns an error");
+    };
+
+    if matches!(resume.action, ResumeAction::Abort) {
+        return Err(GraphError::Execution(format!("Interrupt '{}' was aborted", prompt)));
+    }
+
+    let payload = resume.updates.ok_or_else(|| {
+        GraphError::Execution(format!(
+            "Resume value for '{}' has no payload; expected {}",
+            prompt, type_name
+        ))
+    })?;
+
+    serde_json::from_value(payload).map_err(|e| {
+        GraphError::Execution(format!(
+            "Resume value for '{}' does not match {}: {}",
+            prompt, type_name, e
+        ))
+    })
+}
+
 /// Check if there's a pending interrupt request
 pub async fn has_interrupt_request() -> bool {
     INTERRUPT_REQUEST.with(|r| {
@@ -998,4 +1060,57 @@ mod tests {
             panic!("Expected Execution error for missing runtime");
         }
     }
-}
\ No newline at end of file
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct RefundApproval {
+        approved: bool,
+        amount: u32,
+    }
+
+    fn resume_with(updates: Value) -> InlineResumeValue {
+        InlineResumeValue {
+            action: ResumeAction::Continue,
+            updates: Some(updates),
+            inputs: None,
+            metadata: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_for_typed() {
+        let context = ExecutionContext::new(10);
+        let runtime = Runtime::new(context);
+        runtime.set_current_node(Some("review".to_string()));
+        set_runtime(runtime.clone());
+
+        // First run pauses and advertises the expected type
+        match interrupt_for_typed::<RefundApproval>("Approve refund?") {
+            Err(GraphError::InlineInterrupt(state)) => match state.interrupt_type {
+                InterruptType::Typed { prompt, type_name } => {
+                    assert_eq!(prompt, "Approve refund?");
+                    assert!(type_name.ends_with("RefundApproval"));
+                }
+                other => panic!("Expected Typed interrupt, got {:?}", other),
+            },
+            other => panic!("Expected InlineInterrupt error, got {:?}", other),
+        }
+
+        // Resuming with a matching payload yields the typed value
+        runtime.set_resume_value(Some(resume_with(serde_json::json!({
+            "approved": true,
+            "amount": 120
+        }))));
+        let approval: RefundApproval = interrupt_for_typed("Approve refund?").unwrap();
+        assert_eq!(approval, RefundApproval { approved: true, amount: 120 });
+
+        // A mismatched payload is a clear error
+        runtime.set_resume_value(Some(resume_with(serde_json::json!({"approved": "yes"}))));
+        match interrupt_for_typed::<RefundApproval>("Approve refund?") {
+            Err(GraphError::Execution(msg)) => {
+                assert!(msg.contains("does not match"), "unexpected message: {}", msg);
+                assert!(msg.contains("RefundApproval"));
+            }
+            other => panic!("Expected Execution error, got {:?}", other),
+        }
+    }
+}
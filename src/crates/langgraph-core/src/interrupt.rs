@@ -21,7 +21,8 @@
 //!
 //! - [`InterruptConfig`] - Configuration for when to interrupt (before/after nodes)
 //! - [`InterruptState`] - State of a paused execution with metadata
-//! - [`InterruptWhen`] - Timing: before or after node execution
+//! - [`InterruptWhen`] - Timing: before or after node execution, or a tripped condition
+//! - [`InterruptCondition`] - Named state predicate for conditional breakpoints
 //! - [`InterruptTracker`] - Runtime tracking of interrupt state
 //! - [`InterruptError`] - Errors during interrupt operations
 //!
@@ -606,7 +607,10 @@
 //! - [Checkpoint system](langgraph_checkpoint) - Persistence layer for interrupted state
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -645,6 +649,10 @@ pub struct InterruptConfig {
 
     /// Whether to interrupt after all nodes
     pub interrupt_after_all: bool,
+
+    /// State predicates checked before each node; not serialized
+    #[serde(skip)]
+    pub interrupt_conditions: Vec<InterruptCondition>,
 }
 
 impl InterruptConfig {
@@ -677,6 +685,19 @@ impl InterruptConfig {
         self
     }
 
+    /// Interrupt before any node whose input state satisfies `predicate`
+    ///
+    /// The resulting [`InterruptState`] has [`InterruptWhen::Condition`] and
+    /// reports `name` from [`InterruptState::condition`].
+    pub fn with_interrupt_condition<F>(mut self, name: impl Into<String>, predicate: F) -> Self
+    where
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        self.interrupt_conditions
+            .push(InterruptCondition::new(name, predicate));
+        self
+    }
+
     /// Check if should interrupt before a specific node
     pub fn should_interrupt_before(&self, node: &str) -> bool {
         self.interrupt_before_all || self.interrupt_before.iter().any(|n| n == node)
@@ -688,6 +709,44 @@ impl InterruptConfig {
     }
 }
 
+/// Named predicate over state that triggers a breakpoint when it returns `true`
+#[derive(Clone)]
+pub struct InterruptCondition {
+    /// Name reported by [`InterruptState::condition`] when the predicate trips
+    pub name: String,
+
+    predicate: Arc<dyn Fn(&Value) -> bool + Send + Sync>,
+}
+
+impl InterruptCondition {
+    /// Create a named condition
+    pub fn new<F>(name: impl Into<String>, predicate: F) -> Self
+    where
+        F: Fn(&Value) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            predicate: Arc::new(predicate),
+        }
+    }
+
+    /// Evaluate the predicate against `state`
+    pub fn matches(&self, state: &Value) -> bool {
+        (self.predicate)(state)
+    }
+}
+
+impl fmt::Debug for InterruptCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterruptCondition")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Metadata key holding the name of a tripped [`InterruptCondition`]
+const CONDITION_METADATA_KEY: &str = "condition";
+
 /// State of an interrupted execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterruptState {
@@ -742,6 +801,12 @@ impl InterruptState {
         self.metadata.insert(key, value);
         self
     }
+
+    /// Name of the [`InterruptCondition`] that tripped, for
+    /// [`InterruptWhen::Condition`] interrupts
+    pub fn condition(&self) -> Option<&str> {
+        self.metadata.get(CONDITION_METADATA_KEY)?.as_str()
+    }
 }
 
 /// When the interrupt occurred
@@ -752,6 +817,9 @@ pub enum InterruptWhen {
     Before,
     /// Interrupted after node execution
     After,
+    /// Interrupted before node execution because an [`InterruptCondition`]
+    /// matched the node's input state; see [`InterruptState::condition`]
+    Condition,
 }
 
 /// Tracks interrupt state across graph execution
@@ -797,6 +865,27 @@ impl InterruptTracker {
         self.current_interrupt = Some(interrupt);
     }
 
+    /// Record an interrupt caused by the named [`InterruptCondition`]
+    pub fn interrupt_on_condition(
+        &mut self,
+        thread_id: String,
+        node: NodeId,
+        condition: String,
+        step: usize,
+        checkpoint_id: Option<String>,
+    ) {
+        let interrupt = InterruptState::new(
+            thread_id,
+            node,
+            InterruptWhen::Condition,
+            step,
+            checkpoint_id,
+        )
+        .with_metadata(CONDITION_METADATA_KEY.to_string(), Value::String(condition));
+        self.interrupt_history.push(interrupt.clone());
+        self.current_interrupt = Some(interrupt);
+    }
+
     /// Get the current interrupt state
     pub fn current_interrupt(&self) -> Option<&InterruptState> {
         self.current_interrupt.as_ref()
@@ -845,6 +934,8 @@ pub fn should_interrupt(
     match when {
         InterruptWhen::Before => config.should_interrupt_before(node),
         InterruptWhen::After => config.should_interrupt_after(node),
+        // Conditions need the node's state; the Pregel loop evaluates them
+        InterruptWhen::Condition => false,
     }
 }
 
@@ -515,7 +515,7 @@ pub mod messages;
 pub mod llm;
 
 // Re-export main types
-pub use builder::StateGraph;
+pub use builder::{CompileOptions, StateGraph};
 pub use message_graph::MessageGraph;
 pub use compiled::{CompiledGraph, EventStream, ExecutionEvent, StateSnapshot, StateSnapshotStream, StreamChunkStream};
 pub use langgraph_checkpoint::CheckpointConfig;
@@ -529,21 +529,25 @@ pub use send::{ConditionalEdgeResult, Send};
 pub use command::{Command, CommandGraph, GotoTarget, ResumeValue, PARENT};
 pub use node_result::NodeResult;
 pub use cache::{
-    Cache as GraphCache, CacheConfig, CacheEntry, CacheMetrics, EvictionPolicy,
+    Cache as GraphCache, CacheConfig, CacheEntry, CacheMetrics, CacheMetricsRecorder, EvictionPolicy,
     NodeCache, ToolCache, CheckpointCache,
     create_node_cache, create_tool_cache, create_checkpoint_cache
 };
-pub use retry::{RetryPolicy, RetryState};
-pub use interrupt::{InterruptConfig, InterruptError, InterruptState, InterruptTracker, InterruptWhen};
+pub use retry::{JitterKind, RetryPolicy, RetryState};
+pub use interrupt::{
+    InterruptCondition, InterruptConfig, InterruptError, InterruptState, InterruptTracker,
+    InterruptWhen,
+};
 pub use inline_interrupt::{
-    interrupt, interrupt_for_approval, interrupt_for_input, interrupt_for_edit,
+    interrupt, interrupt_for_approval, interrupt_for_input, interrupt_for_edit, interrupt_for_typed,
     InterruptType, InlineResumeValue, ResumeAction, InlineInterruptState
 };
-pub use state::{StateSchema, Reducer, OverwriteReducer, AppendReducer, MergeReducer, SumReducer, StateError};
+pub use state::{StateSchema, Reducer, OverwriteReducer, AppendReducer, IndexedAppendReducer, MergeReducer, SumReducer, StateError};
 pub use state_filter::StateHistoryFilter;
 pub use parent_child::{
     ParentContext, ParentMessage, SubgraphConfig, GraphHierarchy,
-    send_to_parent, get_parent_context, set_parent_context, CommandParentExt
+    send_to_parent, send_to_parent_typed, get_parent_context, set_parent_context,
+    CommandParentExt
 };
 pub use subgraph::{
     CompiledSubgraph, create_subgraph_node, StateGraphSubgraphExt
@@ -553,10 +557,12 @@ pub use runtime::{Runtime, StreamWriter, get_runtime, get_store, get_stream_writ
 pub use tool::{Tool, ToolRuntime, ToolRegistry, ToolCall, ToolCallResult, ToolOutput, ToolError, ToolResult};
 pub use visualization::{visualize, VisualizationFormat, VisualizationOptions};
 pub use functional::{Task, Workflow, WorkflowBuilder, task};
-pub use llm_stream::{MessageChunk, TokenBuffer, TokenStream, MessageChunkStream, TokenStreamAdapter};
+pub use llm_stream::{MessageChunk, MessageChunkKind, TokenBuffer, TokenStream, MessageChunkStream, TokenStreamAdapter,
+    OpenAiSseAdapter, OpenAiSseParser};
 pub use messages::{
     Message, MessageRole, MessageContent, ContentPart, RemoveMessage, MessageLike,
     add_messages, add_message_likes, convert_to_messages, filter_by_role, get_last_message,
     get_messages_by_id, merge_consecutive_messages, truncate_messages,
-    push_message, push_messages, trim_messages, TrimOptions, TrimStrategy
+    push_message, push_messages, trim_messages, trim_messages_async, TrimOptions, TrimStrategy,
+    TokenCounter, CharsPerTokenCounter, MessageSummarizer
 };
@@ -4,6 +4,7 @@
 //! - Request configuration (temperature, tokens, etc.)
 //! - Reasoning modes for thinking models
 //! - Stop sequences and other generation parameters
+//! - Tool choice for function-calling models
 
 use crate::llm::tools::ToolDefinition;
 use crate::Message;
@@ -28,7 +29,7 @@ use serde::{Deserialize, Serialize};
 /// .with_max_tokens(1000)
 /// .with_reasoning(ReasoningMode::Separated);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatRequest {
     /// The conversation messages to send to the model.
     pub messages: Vec<Message>,
@@ -101,6 +102,15 @@ impl ChatRequest {
         self
     }
 
+    /// Control whether and which bound tool the model must call.
+    ///
+    /// Only takes effect when tools are bound via `with_tools()`. Each
+    /// provider translates this into its own wire format.
+    pub fn with_tool_choice(mut self, choice: ToolChoice) -> Self {
+        self.config.tool_choice = choice;
+        self
+    }
+
     /// Set top-p (nucleus) sampling parameter.
     ///
     /// An alternative to temperature. Only tokens with cumulative probability
@@ -141,7 +151,7 @@ impl ChatRequest {
 /// These parameters control the LLM's generation behavior. Not all parameters
 /// are supported by all providers - implementations should document which
 /// parameters they honor.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChatConfig {
     /// Sampling temperature (0.0-2.0, provider-dependent).
     ///
@@ -169,6 +179,11 @@ pub struct ChatConfig {
     /// in the response.
     pub tools: Vec<ToolDefinition>,
 
+    /// How the model should choose between the bound tools.
+    ///
+    /// Ignored when `tools` is empty.
+    pub tool_choice: ToolChoice,
+
     /// Top-p (nucleus) sampling parameter (0.0-1.0).
     ///
     /// Alternative to temperature for controlling randomness.
@@ -185,6 +200,33 @@ pub struct ChatConfig {
     pub presence_penalty: Option<f32>,
 }
 
+/// Controls how a function-calling model picks among the bound tools.
+///
+/// # Provider Mapping
+///
+/// | Variant      | OpenAI-compatible                      | Claude                 | Gemini                         |
+/// |--------------|----------------------------------------|------------------------|--------------------------------|
+/// | `Auto`       | `"auto"`                               | `{"type": "auto"}`     | `AUTO`                         |
+/// | `None`       | `"none"`                               | `{"type": "none"}`     | `NONE`                         |
+/// | `Required`   | `"required"`                           | `{"type": "any"}`      | `ANY`                          |
+/// | `Named(n)`   | `{"type": "function", "function": ..}` | `{"type": "tool", ..}` | `ANY` restricted to `n`        |
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolChoice {
+    /// The model decides whether to call a tool (default).
+    #[default]
+    Auto,
+
+    /// The model must not call any tool.
+    None,
+
+    /// The model must call at least one tool.
+    Required,
+
+    /// The model must call the tool with this name.
+    Named(String),
+}
+
 /// Controls how thinking/reasoning content is handled for capable models.
 ///
 /// Some models (OpenAI o1, DeepSeek R1, etc.) perform extended "thinking"
@@ -305,5 +347,19 @@ mod tests {
         assert_eq!(config.reasoning_mode, ReasoningMode::Default);
         assert!(config.stop_sequences.is_empty());
         assert!(config.tools.is_empty());
+        assert_eq!(config.tool_choice, ToolChoice::Auto);
+    }
+
+    #[test]
+    fn test_chat_request_with_tools() {
+        let request = ChatRequest::new(vec![Message::human("test")])
+            .with_tools(vec![ToolDefinition::new("search", "Search the web")])
+            .with_tool_choice(ToolChoice::Named("search".to_string()));
+
+        assert_eq!(request.config.tools.len(), 1);
+        assert_eq!(
+            request.config.tool_choice,
+            ToolChoice::Named("search".to_string())
+        );
     }
 }
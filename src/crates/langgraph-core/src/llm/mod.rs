@@ -92,7 +92,7 @@ pub mod tools;
 
 // Re-exports for convenient access
 pub use traits::ChatModel;
-pub use config::{ChatConfig, ChatRequest, ReasoningMode};
+pub use config::{ChatConfig, ChatRequest, ReasoningMode, ToolChoice};
 pub use response::{ChatResponse, ChatStreamResponse, ReasoningContent, UsageMetadata};
 pub use tools::{ToolCall, ToolDefinition, ToolResult};
 
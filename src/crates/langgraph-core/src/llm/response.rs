@@ -4,6 +4,7 @@
 //! `ChatModel::stream()`, including support for thinking models, token
 //! usage tracking, and streaming responses.
 
+use crate::llm::tools::ToolCall;
 use crate::llm_stream::MessageChunkStream;
 use crate::Message;
 use serde::{Deserialize, Serialize};
@@ -32,7 +33,7 @@ use std::collections::HashMap;
 ///     println!("Model thought: {}", reasoning.content);
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatResponse {
     /// The assistant's response message.
     ///
@@ -66,6 +67,30 @@ pub struct ChatResponse {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+impl ChatResponse {
+    /// Tool calls requested by the model, in the order it emitted them.
+    ///
+    /// Providers parse their native tool-call format into
+    /// `message.tool_calls`; this returns them as provider-agnostic
+    /// [`ToolCall`]s. Empty when the model answered directly.
+    pub fn tool_calls(&self) -> Vec<ToolCall> {
+        self.message
+            .tool_calls
+            .iter()
+            .flatten()
+            .map(|call| ToolCall::new(call.id.clone(), call.name.clone(), call.args.clone()))
+            .collect()
+    }
+
+    /// Whether the model requested any tool calls.
+    pub fn has_tool_calls(&self) -> bool {
+        self.message
+            .tool_calls
+            .as_ref()
+            .is_some_and(|calls| !calls.is_empty())
+    }
+}
+
 /// Response from a streaming chat completion request.
 ///
 /// Provides token-by-token streaming of the model's output, optionally
@@ -80,7 +105,7 @@ pub struct ChatResponse {
 ///
 /// // Stream the main response
 /// while let Some(chunk) = stream_response.stream.next().await {
-///     print!("{}", chunk.content);
+///     print!("{}", chunk?.content);
 ///     std::io::stdout().flush().unwrap();
 /// }
 ///
@@ -142,7 +167,7 @@ pub struct ChatStreamResponse {
 ///     println!("Total: {} tokens", usage.total_tokens);
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UsageMetadata {
     /// Number of tokens in the input/prompt.
     ///
@@ -196,6 +221,24 @@ impl UsageMetadata {
             total_tokens: input_tokens + output_tokens + reasoning_tokens,
         }
     }
+
+    /// Add another call's usage to this one.
+    ///
+    /// `reasoning_tokens` stays `None` only if neither side reported it.
+    /// Some providers leave `total_tokens` at zero; the merged total is never
+    /// less than the sum of the reported parts.
+    pub fn merge(&mut self, other: &UsageMetadata) {
+        let other_parts =
+            other.input_tokens + other.output_tokens + other.reasoning_tokens.unwrap_or(0);
+
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.reasoning_tokens = match (self.reasoning_tokens, other.reasoning_tokens) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+        };
+        self.total_tokens += other.total_tokens.max(other_parts);
+    }
 }
 
 /// Extended reasoning/thinking content from capable models.
@@ -305,6 +348,61 @@ impl ReasoningContent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_chat_response_tool_calls() {
+        let message = Message::assistant("")
+            .with_tool_calls(vec![crate::ToolCall::new(
+                "call_1",
+                "search",
+                serde_json::json!({"q": "rust"}),
+            )]);
+        let response = ChatResponse {
+            message,
+            usage: None,
+            reasoning: None,
+            metadata: HashMap::new(),
+        };
+
+        assert!(response.has_tool_calls());
+        let calls = response.tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].name, "search");
+        assert_eq!(calls[0].arguments["q"], "rust");
+
+        let plain = ChatResponse {
+            message: Message::assistant("hi"),
+            ..response
+        };
+        assert!(!plain.has_tool_calls());
+        assert!(plain.tool_calls().is_empty());
+    }
+
+    #[test]
+    fn test_usage_metadata_merge() {
+        let mut usage = UsageMetadata::new(100, 50);
+        usage.merge(&UsageMetadata::with_reasoning(10, 20, 5));
+
+        assert_eq!(usage.input_tokens, 110);
+        assert_eq!(usage.output_tokens, 70);
+        assert_eq!(usage.reasoning_tokens, Some(5));
+        assert_eq!(usage.total_tokens, 185);
+
+        // A provider that reports parts but no total
+        usage.merge(&UsageMetadata {
+            input_tokens: 3,
+            output_tokens: 4,
+            reasoning_tokens: None,
+            total_tokens: 0,
+        });
+        assert_eq!(usage.total_tokens, 192);
+        assert_eq!(usage.reasoning_tokens, Some(5));
+
+        let mut empty = UsageMetadata::default();
+        empty.merge(&UsageMetadata::default());
+        assert_eq!(empty, UsageMetadata::default());
+    }
+
     #[test]
     fn test_usage_metadata_new() {
         let usage = UsageMetadata::new(100, 50);
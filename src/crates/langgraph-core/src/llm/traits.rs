@@ -162,7 +162,7 @@ pub trait ChatModel: Send + Sync {
     /// let mut stream_response = model.stream(request).await?;
     ///
     /// while let Some(chunk) = stream_response.stream.next().await {
-    ///     print!("{}", chunk.content);
+    ///     print!("{}", chunk?.content);
     /// }
     /// ```
     async fn stream(&self, request: ChatRequest) -> Result<ChatStreamResponse>;
@@ -197,6 +197,16 @@ pub trait ChatModel: Send + Sync {
         Vec::new()
     }
 
+    /// Name of the model that answers requests, such as `"gpt-4"`.
+    ///
+    /// Used by wrappers that need to tell models apart, e.g. to keep cached
+    /// responses from different models separate.
+    ///
+    /// Default implementation returns `None` (unknown model).
+    fn model_name(&self) -> Option<String> {
+        None
+    }
+
     /// Clone this model into a boxed trait object.
     ///
     /// This method enables cloning of `Arc<dyn ChatModel>` and similar patterns.
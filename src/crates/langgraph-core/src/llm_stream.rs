@@ -3,6 +3,7 @@
 //! This module provides utilities for streaming LLM outputs token-by-token,
 //! which is essential for modern chat applications and agent workflows.
 
+use crate::error::Result;
 use crate::stream::StreamEvent;
 use futures::stream::{Stream, StreamExt};
 use serde_json::Value;
@@ -12,7 +13,21 @@ use std::pin::Pin;
 pub type TokenStream = Pin<Box<dyn Stream<Item = String> + Send>>;
 
 /// Type alias for a stream of message chunks with metadata
-pub type MessageChunkStream = Pin<Box<dyn Stream<Item = MessageChunk> + Send>>;
+///
+/// A transport failure part-way through a response is yielded as an `Err`
+/// item, after which the stream ends without a final chunk.
+pub type MessageChunkStream = Pin<Box<dyn Stream<Item = Result<MessageChunk>> + Send>>;
+
+/// What part of a model response a [`MessageChunk`] belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MessageChunkKind {
+    /// Answer text shown to the user
+    #[default]
+    Content,
+
+    /// Reasoning/thinking text from thinking models (e.g. DeepSeek R1)
+    Reasoning,
+}
 
 /// A chunk of a streaming message
 #[derive(Debug, Clone)]
@@ -20,6 +35,9 @@ pub struct MessageChunk {
     /// The content chunk (token or partial message)
     pub content: String,
 
+    /// Whether this chunk is answer content or reasoning
+    pub kind: MessageChunkKind,
+
     /// Optional message ID this chunk belongs to
     pub message_id: Option<String>,
 
@@ -35,12 +53,26 @@ impl MessageChunk {
     pub fn new(content: impl Into<String>) -> Self {
         Self {
             content: content.into(),
+            kind: MessageChunkKind::Content,
             message_id: None,
             is_final: false,
             metadata: None,
         }
     }
 
+    /// Create a reasoning chunk
+    pub fn reasoning(content: impl Into<String>) -> Self {
+        Self {
+            kind: MessageChunkKind::Reasoning,
+            ..Self::new(content)
+        }
+    }
+
+    /// Check whether this chunk carries reasoning rather than answer content
+    pub fn is_reasoning(&self) -> bool {
+        self.kind == MessageChunkKind::Reasoning
+    }
+
     /// Create a message chunk with metadata
     pub fn with_metadata(mut self, metadata: Value) -> Self {
         self.metadata = Some(metadata);
@@ -60,12 +92,27 @@ impl MessageChunk {
     }
 
     /// Convert to a StreamEvent for emission
+    ///
+    /// Reasoning chunks are tagged with `"chunk_kind": "reasoning"` in the
+    /// event metadata so consumers can route them to a separate pane.
     pub fn to_stream_event(&self, node: impl Into<String>) -> StreamEvent {
+        let metadata = match self.kind {
+            MessageChunkKind::Content => self.metadata.clone(),
+            MessageChunkKind::Reasoning => {
+                let mut metadata = match &self.metadata {
+                    Some(Value::Object(map)) => map.clone(),
+                    _ => serde_json::Map::new(),
+                };
+                metadata.insert("chunk_kind".to_string(), Value::from("reasoning"));
+                Some(Value::Object(metadata))
+            }
+        };
+
         StreamEvent::message_chunk_with_metadata(
             node.into(),
             self.content.clone(),
             self.message_id.clone(),
-            self.metadata.clone(),
+            metadata,
         )
     }
 }
@@ -73,12 +120,16 @@ impl MessageChunk {
 /// Token buffer for accumulating streaming tokens
 ///
 /// This helps manage token streaming by buffering chunks and providing
-/// utilities for reconstructing complete messages.
+/// utilities for reconstructing complete messages. Reasoning chunks are kept
+/// in a separate buffer from answer content.
 #[derive(Debug, Default)]
 pub struct TokenBuffer {
     /// Accumulated content
     buffer: String,
 
+    /// Accumulated reasoning
+    reasoning: String,
+
     /// Number of chunks received
     chunk_count: usize,
 
@@ -98,6 +149,25 @@ impl TokenBuffer {
         self.chunk_count += 1;
     }
 
+    /// Add a reasoning chunk to the reasoning buffer
+    pub fn add_reasoning_chunk(&mut self, chunk: &str) {
+        self.reasoning.push_str(chunk);
+        self.chunk_count += 1;
+    }
+
+    /// Add a message chunk to the buffer matching its kind
+    ///
+    /// Final chunks also mark the buffer as finished.
+    pub fn push(&mut self, chunk: &MessageChunk) {
+        match chunk.kind {
+            MessageChunkKind::Content => self.add_chunk(&chunk.content),
+            MessageChunkKind::Reasoning => self.add_reasoning_chunk(&chunk.content),
+        }
+        if chunk.is_final {
+            self.finish();
+        }
+    }
+
     /// Mark the stream as finished
     pub fn finish(&mut self) {
         self.finished = true;
@@ -108,6 +178,11 @@ impl TokenBuffer {
         &self.buffer
     }
 
+    /// Get the accumulated reasoning content
+    pub fn reasoning(&self) -> &str {
+        &self.reasoning
+    }
+
     /// Get the number of chunks received
     pub fn chunk_count(&self) -> usize {
         self.chunk_count
@@ -121,6 +196,7 @@ impl TokenBuffer {
     /// Clear the buffer
     pub fn clear(&mut self) {
         self.buffer.clear();
+        self.reasoning.clear();
         self.chunk_count = 0;
         self.finished = false;
     }
@@ -129,6 +205,11 @@ impl TokenBuffer {
     pub fn into_string(self) -> String {
         self.buffer
     }
+
+    /// Consume the buffer and return `(content, reasoning)`
+    pub fn into_parts(self) -> (String, String) {
+        (self.buffer, self.reasoning)
+    }
 }
 
 /// Stream adapter for converting token streams to message chunk streams
@@ -171,6 +252,267 @@ impl TokenStreamAdapter {
     }
 }
 
+/// Incremental parser for OpenAI `chat.completions` server-sent events
+///
+/// Feed raw bytes as they arrive with [`feed`](Self::feed); each complete
+/// `data:` event becomes a [`MessageChunk`]. Content deltas are returned as the
+/// chunk content, reasoning deltas (`reasoning_content` as sent by DeepSeek,
+/// or `reasoning`) become [`MessageChunkKind::Reasoning`] chunks, and
+/// tool-call deltas are surfaced under the
+/// `"tool_call_deltas"` metadata key. When the `[DONE]` sentinel arrives (or
+/// [`finish`](Self::finish) is called) a final chunk is produced whose
+/// metadata carries the `finish_reason` and the fully assembled `"tool_calls"`
+/// in [`ToolCall`](crate::tool::ToolCall) form.
+#[derive(Debug, Default)]
+pub struct OpenAiSseParser {
+    /// Bytes of the current, not yet terminated line
+    line: Vec<u8>,
+
+    /// `data:` payload lines of the current event
+    data: Vec<String>,
+
+    /// Message ID from the first chunk
+    message_id: Option<String>,
+
+    /// Most recent finish reason reported by the server
+    finish_reason: Option<String>,
+
+    /// Tool calls assembled so far, keyed by their stream index
+    tool_calls: std::collections::BTreeMap<u64, PartialToolCall>,
+
+    /// Whether the final chunk has been produced
+    done: bool,
+}
+
+/// Tool call being assembled from streamed deltas
+#[derive(Debug, Default, Clone)]
+struct PartialToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+impl OpenAiSseParser {
+    /// Create a new parser
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the `[DONE]` sentinel (or end of stream) has been seen
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Feed raw bytes from the response body and return any completed chunks
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<MessageChunk> {
+        let mut chunks = Vec::new();
+        for &byte in bytes {
+            if self.done {
+                break;
+            }
+            if byte != b'\n' {
+                self.line.push(byte);
+                continue;
+            }
+
+            let line = std::mem::take(&mut self.line);
+            let line = String::from_utf8_lossy(&line);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+
+            if line.is_empty() {
+                // A blank line terminates the event
+                chunks.extend(self.dispatch());
+            } else if let Some(data) = line.strip_prefix("data:") {
+                self.data.push(data.strip_prefix(' ').unwrap_or(data).to_string());
+            }
+            // Comments (":"), "event:", "id:" and "retry:" fields are ignored
+        }
+        chunks
+    }
+
+    /// Flush any buffered event and produce the final chunk if not yet emitted
+    pub fn finish(&mut self) -> Vec<MessageChunk> {
+        let mut chunks = Vec::new();
+        if self.done {
+            return chunks;
+        }
+        if !self.line.is_empty() {
+            chunks.extend(self.feed(b"\n"));
+        }
+        chunks.extend(self.dispatch());
+        if !self.done {
+            chunks.push(self.final_chunk());
+        }
+        chunks
+    }
+
+    /// Handle the accumulated `data:` lines of one event
+    fn dispatch(&mut self) -> Vec<MessageChunk> {
+        if self.data.is_empty() {
+            return Vec::new();
+        }
+        let payload = std::mem::take(&mut self.data).join("\n");
+
+        if payload.trim() == "[DONE]" {
+            return vec![self.final_chunk()];
+        }
+
+        let value: Value = match serde_json::from_str(&payload) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!(error = %e, "Skipping malformed SSE chunk");
+                return Vec::new();
+            }
+        };
+
+        if self.message_id.is_none() {
+            self.message_id = value.get("id").and_then(Value::as_str).map(str::to_string);
+        }
+
+        let choice = value.get("choices").and_then(|c| c.get(0));
+        let delta = choice.and_then(|c| c.get("delta"));
+
+        if let Some(reason) = choice
+            .and_then(|c| c.get("finish_reason"))
+            .and_then(Value::as_str)
+        {
+            self.finish_reason = Some(reason.to_string());
+        }
+
+        let content = delta
+            .and_then(|d| d.get("content"))
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+
+        let tool_deltas: Vec<Value> = delta
+            .and_then(|d| d.get("tool_calls"))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        for tool_delta in &tool_deltas {
+            let index = tool_delta.get("index").and_then(Value::as_u64).unwrap_or(0);
+            let entry = self.tool_calls.entry(index).or_default();
+            if let Some(id) = tool_delta.get("id").and_then(Value::as_str) {
+                entry.id = id.to_string();
+            }
+            if let Some(function) = tool_delta.get("function") {
+                if let Some(name) = function.get("name").and_then(Value::as_str) {
+                    entry.name.push_str(name);
+                }
+                if let Some(arguments) = function.get("arguments").and_then(Value::as_str) {
+                    entry.arguments.push_str(arguments);
+                }
+            }
+        }
+
+        let reasoning = delta
+            .and_then(|d| d.get("reasoning_content").or_else(|| d.get("reasoning")))
+            .and_then(Value::as_str)
+            .filter(|r| !r.is_empty());
+
+        let mut chunks = Vec::new();
+        if let Some(reasoning) = reasoning {
+            chunks.push(MessageChunk::reasoning(reasoning));
+        }
+        // Reasoning-only deltas don't produce an empty answer chunk
+        if chunks.is_empty() || !content.is_empty() || !tool_deltas.is_empty() {
+            let mut chunk = MessageChunk::new(content);
+            if !tool_deltas.is_empty() {
+                chunk = chunk.with_metadata(serde_json::json!({ "tool_call_deltas": tool_deltas }));
+            }
+            chunks.push(chunk);
+        }
+
+        match &self.message_id {
+            Some(id) => chunks
+                .into_iter()
+                .map(|chunk| chunk.with_message_id(id.clone()))
+                .collect(),
+            None => chunks,
+        }
+    }
+
+    /// Build the final chunk carrying the assembled tool calls
+    fn final_chunk(&mut self) -> MessageChunk {
+        self.done = true;
+
+        let tool_calls: Vec<Value> = self
+            .tool_calls
+            .values()
+            .map(|call| {
+                // Arguments arrive as a JSON string; keep the raw text if it never became valid JSON
+                let args = serde_json::from_str(&call.arguments)
+                    .unwrap_or_else(|_| Value::String(call.arguments.clone()));
+                serde_json::json!({ "id": call.id, "name": call.name, "args": args })
+            })
+            .collect();
+
+        let mut chunk = MessageChunk::new("").final_chunk().with_metadata(serde_json::json!({
+            "finish_reason": self.finish_reason,
+            "tool_calls": tool_calls,
+        }));
+        if let Some(id) = &self.message_id {
+            chunk = chunk.with_message_id(id.clone());
+        }
+        chunk
+    }
+}
+
+/// Stream adapter turning an OpenAI-compatible SSE byte stream into message chunks
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use futures::StreamExt;
+/// use langgraph_core::llm_stream::OpenAiSseAdapter;
+///
+/// // `response.bytes_stream()` from an HTTP client, mapped to byte buffers
+/// let mut chunks = OpenAiSseAdapter::new().adapt(body);
+/// while let Some(chunk) = chunks.next().await {
+///     print!("{}", chunk?.content);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct OpenAiSseAdapter {
+    parser: OpenAiSseParser,
+}
+
+impl OpenAiSseAdapter {
+    /// Create a new adapter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adapt a stream of response body bytes into a [`MessageChunkStream`]
+    ///
+    /// The returned stream ends after the final chunk, even if the body
+    /// continues past the `[DONE]` sentinel.
+    pub fn adapt<S, B>(self, body: S) -> MessageChunkStream
+    where
+        S: Stream<Item = B> + Send + Unpin + 'static,
+        B: AsRef<[u8]>,
+    {
+        let state = (body, self.parser, std::collections::VecDeque::new());
+
+        Box::pin(futures::stream::unfold(state, |(mut body, mut parser, mut pending)| async move {
+            loop {
+                if let Some(chunk) = pending.pop_front() {
+                    return Some((Ok(chunk), (body, parser, pending)));
+                }
+                if parser.is_done() {
+                    return None;
+                }
+                match body.next().await {
+                    Some(bytes) => pending.extend(parser.feed(bytes.as_ref())),
+                    None => pending.extend(parser.finish()),
+                }
+            }
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +569,166 @@ mod tests {
 
         assert_eq!(chunks, vec!["Hello", " ", "world"]);
     }
+
+    #[test]
+    fn test_token_buffer_separates_interleaved_reasoning() {
+        let chunks = vec![
+            MessageChunk::reasoning("Let me think. "),
+            MessageChunk::new("The answer"),
+            MessageChunk::reasoning("Double-check: 2+2=4. "),
+            MessageChunk::new(" is 4."),
+            MessageChunk::new("").final_chunk(),
+        ];
+
+        let mut buffer = TokenBuffer::new();
+        for chunk in &chunks {
+            buffer.push(chunk);
+        }
+
+        assert_eq!(buffer.content(), "The answer is 4.");
+        assert_eq!(buffer.reasoning(), "Let me think. Double-check: 2+2=4. ");
+        assert_eq!(buffer.chunk_count(), 5);
+        assert!(buffer.is_finished());
+
+        let (content, reasoning) = buffer.into_parts();
+        assert_eq!(content, "The answer is 4.");
+        assert!(reasoning.starts_with("Let me think."));
+    }
+
+    #[test]
+    fn test_reasoning_chunk_stream_event_is_tagged() {
+        let event = MessageChunk::reasoning("hmm")
+            .with_metadata(serde_json::json!({"model": "r1"}))
+            .to_stream_event("llm");
+
+        match event {
+            StreamEvent::MessageChunk { chunk, metadata, .. } => {
+                assert_eq!(chunk, "hmm");
+                let metadata = metadata.unwrap();
+                assert_eq!(metadata["chunk_kind"], "reasoning");
+                assert_eq!(metadata["model"], "r1");
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        // Answer chunks keep their metadata untouched
+        match MessageChunk::new("hi").to_stream_event("llm") {
+            StreamEvent::MessageChunk { metadata, .. } => assert!(metadata.is_none()),
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_openai_sse_adapter_interleaved_reasoning() {
+        let body = concat!(
+            "data: {\"id\":\"r1\",\"choices\":[{\"delta\":{\"reasoning_content\":\"Think \"}}]}\n\n",
+            "data: {\"id\":\"r1\",\"choices\":[{\"delta\":{\"content\":\"Ans\"}}]}\n\n",
+            "data: {\"id\":\"r1\",\"choices\":[{\"delta\":{\"reasoning_content\":\"more\"}}]}\n\n",
+            "data: {\"id\":\"r1\",\"choices\":[{\"delta\":{\"content\":\"wer\"},\"finish_reason\":\"stop\"}]}\n\n",
+            "data: [DONE]\n\n",
+        );
+
+        let chunks: Vec<MessageChunk> = OpenAiSseAdapter::new()
+            .adapt(stream::iter(vec![body.as_bytes().to_vec()]))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let kinds: Vec<_> = chunks.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                MessageChunkKind::Reasoning,
+                MessageChunkKind::Content,
+                MessageChunkKind::Reasoning,
+                MessageChunkKind::Content,
+                MessageChunkKind::Content,
+            ]
+        );
+
+        let mut buffer = TokenBuffer::new();
+        chunks.iter().for_each(|c| buffer.push(c));
+        assert_eq!(buffer.content(), "Answer");
+        assert_eq!(buffer.reasoning(), "Think more");
+        assert!(buffer.is_finished());
+    }
+
+    /// Recorded `chat.completions` stream with text followed by two tool calls
+    const RECORDED_SSE: &str = concat!(
+        ": keep-alive\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"\"},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Let me \"},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"check. \u{e9}\"},\"finish_reason\":null}]}\r\n\r\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"id\":\"call_a\",\"type\":\"function\",\"function\":{\"name\":\"get_weather\",\"arguments\":\"\"}}]},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"{\\\"city\\\":\"}}]},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\" \\\"Paris\\\"}\"}}]},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":1,\"id\":\"call_b\",\"type\":\"function\",\"function\":{\"name\":\"get_time\",\"arguments\":\"{}\"}}]},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"tool_calls\"}]}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    #[tokio::test]
+    async fn test_openai_sse_adapter_reconstructs_message() {
+        // Split the body at awkward boundaries, including inside a multi-byte character
+        let bytes = RECORDED_SSE.as_bytes();
+        let pieces: Vec<Vec<u8>> = bytes.chunks(7).map(|c| c.to_vec()).collect();
+
+        let chunks: Vec<MessageChunk> = OpenAiSseAdapter::new()
+            .adapt(stream::iter(pieces))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let text: String = chunks.iter().map(|c| c.content.as_str()).collect();
+        assert_eq!(text, "Let me check. é");
+        assert!(chunks.iter().all(|c| c.message_id.as_deref() == Some("chatcmpl-1")));
+
+        // Tool-call deltas are surfaced as they arrive
+        let delta_chunks = chunks
+            .iter()
+            .filter(|c| c.metadata.as_ref().is_some_and(|m| m.get("tool_call_deltas").is_some()))
+            .count();
+        assert_eq!(delta_chunks, 4);
+
+        let last = chunks.last().unwrap();
+        assert!(last.is_final);
+        assert_eq!(chunks.iter().filter(|c| c.is_final).count(), 1);
+
+        let metadata = last.metadata.as_ref().unwrap();
+        assert_eq!(metadata["finish_reason"], "tool_calls");
+        let tool_calls: Vec<crate::tool::ToolCall> =
+            serde_json::from_value(metadata["tool_calls"].clone()).unwrap();
+        assert_eq!(tool_calls.len(), 2);
+        assert_eq!(tool_calls[0].id, "call_a");
+        assert_eq!(tool_calls[0].name, "get_weather");
+        assert_eq!(tool_calls[0].args, serde_json::json!({"city": "Paris"}));
+        assert_eq!(tool_calls[1].name, "get_time");
+        assert_eq!(tool_calls[1].args, serde_json::json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_openai_sse_adapter_without_done_sentinel() {
+        let body = "data: {\"id\":\"x\",\"choices\":[{\"delta\":{\"content\":\"partial\"}}]}";
+
+        let chunks: Vec<MessageChunk> = OpenAiSseAdapter::new()
+            .adapt(stream::iter(vec![body.as_bytes().to_vec()]))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].content, "partial");
+        assert!(chunks[1].is_final);
+    }
+
+    #[test]
+    fn test_openai_sse_parser_ignores_data_after_done() {
+        let mut parser = OpenAiSseParser::new();
+        let chunks = parser.feed(b"data: [DONE]\n\ndata: {\"choices\":[]}\n\n");
+
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].is_final);
+        assert!(parser.is_done());
+        assert!(parser.finish().is_empty());
+    }
 }
@@ -31,6 +31,9 @@ pub enum ManagedValueType {
 
     /// Current step number (0-indexed)
     CurrentStep,
+
+    /// Request-scoped context passed to `invoke_with_config`; never checkpointed
+    RequestContext,
 }
 
 impl ManagedValueType {
@@ -40,6 +43,7 @@ impl ManagedValueType {
             ManagedValueType::RemainingSteps => "__remaining_steps__",
             ManagedValueType::IsLastStep => "__is_last_step__",
             ManagedValueType::CurrentStep => "__current_step__",
+            ManagedValueType::RequestContext => "__request_context__",
         }
     }
 }
@@ -52,6 +56,9 @@ pub struct ExecutionContext {
 
     /// Maximum number of steps allowed
     max_steps: usize,
+
+    /// Request-scoped context for the current invocation
+    request_context: Option<Value>,
 }
 
 impl ExecutionContext {
@@ -60,9 +67,21 @@ impl ExecutionContext {
         Self {
             current_step: Arc::new(RwLock::new(0)),
             max_steps,
+            request_context: None,
         }
     }
 
+    /// Attach a request-scoped context value
+    pub fn with_request_context(mut self, context: Value) -> Self {
+        self.request_context = Some(context);
+        self
+    }
+
+    /// Get the request-scoped context value, if any
+    pub fn request_context(&self) -> Option<&Value> {
+        self.request_context.as_ref()
+    }
+
     /// Get the current step number
     pub fn current_step(&self) -> usize {
         *self.current_step.read().unwrap()
@@ -113,6 +132,9 @@ impl ExecutionContext {
             ManagedValueType::CurrentStep => {
                 serde_json::json!(self.current_step())
             }
+            ManagedValueType::RequestContext => {
+                self.request_context.clone().unwrap_or(Value::Null)
+            }
         }
     }
 
@@ -131,6 +153,12 @@ impl ExecutionContext {
                 ManagedValueType::CurrentStep.state_key().to_string(),
                 self.get_managed_value(ManagedValueType::CurrentStep),
             );
+            if let Some(context) = &self.request_context {
+                obj.insert(
+                    ManagedValueType::RequestContext.state_key().to_string(),
+                    context.clone(),
+                );
+            }
             Ok(())
         } else {
             Err("State must be a JSON object to inject managed values".to_string())
@@ -143,6 +171,7 @@ impl ExecutionContext {
             obj.remove(ManagedValueType::RemainingSteps.state_key());
             obj.remove(ManagedValueType::IsLastStep.state_key());
             obj.remove(ManagedValueType::CurrentStep.state_key());
+            obj.remove(ManagedValueType::RequestContext.state_key());
         }
     }
 }
@@ -284,9 +284,19 @@
 use crate::builder::StateGraph;
 use crate::compiled::CompiledGraph;
 use crate::error::Result;
-use crate::graph::{ChannelType, NodeId};
-use crate::messages::{Message, add_messages};
-use serde_json::Value;
+use crate::graph::{ChannelType, NodeId, END};
+use crate::messages::{Message, MessageRole, add_messages};
+use crate::send::ConditionalEdgeResult;
+use crate::tool::{ToolCall, ToolCallResult, ToolOutput, ToolRegistry, ToolRuntime};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Agent node wired by [`MessageGraph::with_tools`]
+pub const AGENT_NODE: &str = "agent";
+
+/// Tool node wired by [`MessageGraph::with_tools`]
+pub const TOOLS_NODE: &str = "tools";
 
 /// Builder for constructing message-based conversation graphs
 ///
@@ -422,6 +432,83 @@ impl MessageGraph {
     pub fn compile(self) -> Result<CompiledGraph> {
         self.inner.compile()
     }
+
+    /// Build a compiled tool-calling agent loop
+    ///
+    /// Wires `agent` as the entry node ([`AGENT_NODE`]) and a tool node
+    /// ([`TOOLS_NODE`]) that runs the tool calls on the agent's latest
+    /// assistant message through `tools`, replying with one tool message per
+    /// call. After the agent runs, the graph continues to the tool node if
+    /// that message requested tools and ends otherwise; the tool node always
+    /// hands back to the agent.
+    ///
+    /// `agent` receives `{"messages": [...]}` and returns its new messages the
+    /// same way, e.g. `{"messages": [Message::assistant("...")]}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use langgraph_core::{Message, MessageGraph, ToolRegistry};
+    /// use serde_json::json;
+    ///
+    /// # fn example() -> langgraph_core::Result<()> {
+    /// let tools = ToolRegistry::new();
+    /// let agent = MessageGraph::with_tools(tools, |_state| {
+    ///     Box::pin(async move {
+    ///         Ok(json!({"messages": [Message::assistant("No tools needed.")]}))
+    ///     })
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tools<F>(tools: ToolRegistry, agent: F) -> Result<CompiledGraph>
+    where
+        F: Fn(Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let tools = Arc::new(tools);
+        let mut graph = Self::new();
+
+        graph.add_node(AGENT_NODE, agent);
+        graph.add_node(TOOLS_NODE, move |state: Value| {
+            let tools = tools.clone();
+            Box::pin(async move {
+                let tool_calls = pending_tool_calls(&state);
+                let runtime = ToolRuntime::new(state);
+                let messages: Vec<Message> = tools
+                    .execute_tool_calls(&tool_calls, Some(runtime))
+                    .await
+                    .into_iter()
+                    .map(tool_message)
+                    .collect();
+                Ok(json!({ "messages": messages }))
+            })
+        });
+
+        let branches: HashMap<String, NodeId> = [
+            (TOOLS_NODE.to_string(), TOOLS_NODE.to_string()),
+            (END.to_string(), END.to_string()),
+        ]
+        .into_iter()
+        .collect();
+        graph.add_conditional_edge(
+            AGENT_NODE,
+            |output: &Value| {
+                if pending_tool_calls(output).is_empty() {
+                    ConditionalEdgeResult::Node(END.to_string())
+                } else {
+                    ConditionalEdgeResult::Node(TOOLS_NODE.to_string())
+                }
+            },
+            branches,
+        );
+        graph.add_edge(TOOLS_NODE, AGENT_NODE);
+        graph.set_entry(AGENT_NODE);
+
+        graph.compile()
+    }
 }
 
 impl Default for MessageGraph {
@@ -431,16 +518,42 @@ impl Default for MessageGraph {
 }
 
 /// Convert a JSON Value to a vector of Messages
+///
+/// Accepts either a message list or a node's state update carrying one under
+/// `"messages"`.
 fn value_to_messages(value: Value) -> Vec<Message> {
     match value {
         Value::Array(arr) => arr
             .into_iter()
             .filter_map(|v| serde_json::from_value(v).ok())
             .collect(),
+        Value::Object(mut obj) => obj
+            .remove("messages")
+            .map(value_to_messages)
+            .unwrap_or_default(),
         _ => vec![],
     }
 }
 
+/// Tool calls requested by the last message in `state`, if it is from the assistant
+fn pending_tool_calls(state: &Value) -> Vec<ToolCall> {
+    value_to_messages(state["messages"].clone())
+        .last()
+        .filter(|message| message.role == MessageRole::Assistant)
+        .map(Message::extract_tool_calls)
+        .unwrap_or_default()
+}
+
+/// Tool message answering a single tool call
+fn tool_message(result: ToolCallResult) -> Message {
+    let content = match result.output {
+        ToolOutput::Success { content: Value::String(text) } => text,
+        ToolOutput::Success { content } => content.to_string(),
+        ToolOutput::Error { error } => format!("Error: {}", error),
+    };
+    Message::tool(content, result.id).with_name(result.name)
+}
+
 /// Convert a vector of Messages to a JSON Value
 fn messages_to_value(messages: Vec<Message>) -> Value {
     Value::Array(
@@ -572,6 +685,90 @@ mod tests {
         assert_eq!(merged[0].text(), Some("Updated"));
     }
 
+    #[tokio::test]
+    async fn test_message_reducer_accepts_state_update() {
+        // A node returning only `{"messages": [...]}` appends to the history
+        let mut graph = MessageGraph::new();
+        graph.add_node("agent", |_state| {
+            Box::pin(async move { Ok(json!({"messages": [Message::assistant("Hi!")]})) })
+        });
+        graph.add_edge("__start__", "agent");
+        graph.add_edge("agent", "__end__");
+
+        let result = graph
+            .compile()
+            .unwrap()
+            .invoke(json!({"messages": [Message::human("Hello")]}))
+            .await
+            .unwrap();
+
+        let messages = value_to_messages(result["messages"].clone());
+        let texts: Vec<_> = messages.iter().map(|m| m.text()).collect();
+        assert_eq!(texts, vec![Some("Hello"), Some("Hi!")]);
+
+        let update = json!({"messages": [Message::human("Hey")]});
+        assert_eq!(value_to_messages(update).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_with_tools_routes_through_tool_node() {
+        use crate::tool::Tool;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut tools = ToolRegistry::new();
+        tools.register(Tool::new(
+            "lookup",
+            "Look up a stock level",
+            json!({"type": "object"}),
+            Arc::new(|args, _runtime| {
+                Box::pin(async move { Ok(json!({"sku": args["sku"], "in_stock": 3})) })
+            }),
+        ));
+
+        let agent_calls = Arc::new(AtomicUsize::new(0));
+        let calls = agent_calls.clone();
+        let graph = MessageGraph::with_tools(tools, move |state| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let response = if call == 0 {
+                    Message::assistant("Checking stock.").with_tool_calls(vec![ToolCall::new(
+                        "call_1",
+                        "lookup",
+                        json!({"sku": "A-1"}),
+                    )])
+                } else {
+                    // Back from the tool node with its answer as the last message
+                    let messages = value_to_messages(state["messages"].clone());
+                    let last = messages.last().unwrap();
+                    assert_eq!(last.role, MessageRole::Tool);
+                    Message::assistant(format!("Stock: {}", last.text().unwrap()))
+                };
+                Ok(json!({ "messages": [response] }))
+            })
+        })
+        .unwrap();
+
+        let result = graph
+            .invoke(json!({ "messages": [Message::human("Is A-1 in stock?")] }))
+            .await
+            .unwrap();
+
+        assert_eq!(agent_calls.load(Ordering::SeqCst), 2);
+        let roles: Vec<MessageRole> = value_to_messages(result["messages"].clone())
+            .into_iter()
+            .map(|message| message.role)
+            .collect();
+        assert_eq!(
+            roles,
+            vec![
+                MessageRole::Human,
+                MessageRole::Assistant,
+                MessageRole::Tool,
+                MessageRole::Assistant,
+            ]
+        );
+    }
+
     #[test]
     fn test_message_graph_conditional_routing() {
         use std::collections::HashMap;
@@ -224,10 +224,12 @@
 //! - [`Command`](crate::Command) - Dynamic graph control
 //! - Python LangGraph Messages - <https://langchain-ai.github.io/langgraph/concepts/low_level/#messages>
 
-use crate::tool::ToolCall;
+use crate::tool::{ToolCall, ToolError};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// Role of the message sender in a conversation.
@@ -788,12 +790,278 @@ impl Message {
         }
     }
 
+    /// Tool calls requested by this message
+    ///
+    /// Returns the structured `tool_calls` when present; otherwise parses JSON
+    /// tool-call blocks embedded in the text content (see
+    /// [`ToolCall::parse_from_text`]). Malformed blocks are skipped; use
+    /// [`try_extract_tool_calls`](Self::try_extract_tool_calls) to inspect them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::messages::Message;
+    ///
+    /// let msg = Message::ai(r#"<tool_call>{"name": "search", "arguments": {"q": "rust"}}</tool_call>"#);
+    /// let calls = msg.extract_tool_calls();
+    /// assert_eq!(calls[0].name, "search");
+    /// ```
+    pub fn extract_tool_calls(&self) -> Vec<ToolCall> {
+        let (calls, errors) = self.try_extract_tool_calls();
+        for error in errors {
+            tracing::warn!(message_id = ?self.id, %error, "Skipping malformed tool call");
+        }
+        calls
+    }
+
+    /// Like [`extract_tool_calls`](Self::extract_tool_calls), but also returns
+    /// an error for every block that could not be parsed
+    pub fn try_extract_tool_calls(&self) -> (Vec<ToolCall>, Vec<ToolError>) {
+        if let Some(tool_calls) = self.tool_calls.as_ref().filter(|calls| !calls.is_empty()) {
+            return (tool_calls.clone(), Vec::new());
+        }
+
+        match &self.content {
+            MessageContent::Text(text) => ToolCall::parse_from_text(text),
+            MessageContent::Parts(parts) => {
+                let text = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        ContentPart::Text { text, .. } => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ToolCall::parse_from_text(&text)
+            }
+        }
+    }
+
     /// Ensure this message has an ID (generate one if missing)
     pub fn ensure_id(&mut self) {
         if self.id.is_none() {
             self.id = Some(Uuid::new_v4().to_string());
         }
     }
+
+    /// Parse a message in the OpenAI chat completions wire format
+    ///
+    /// Maps `user` to [`MessageRole::Human`] and any role other than
+    /// `system`, `assistant` and `tool` to [`MessageRole::Custom`]. Content
+    /// may be a string, `null`, or an array of `text`/`image_url` parts;
+    /// other part types are kept as [`ContentPart::Custom`]. Tool call
+    /// arguments are decoded from their JSON string, falling back to the raw
+    /// string if it is not valid JSON. The message gets a fresh ID, since the
+    /// wire format has none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::messages::{Message, MessageRole};
+    /// use serde_json::json;
+    ///
+    /// let msg = Message::from_openai_json(json!({
+    ///     "role": "assistant",
+    ///     "content": null,
+    ///     "tool_calls": [{
+    ///         "id": "call_1",
+    ///         "type": "function",
+    ///         "function": {"name": "search", "arguments": "{\"q\":\"rust\"}"}
+    ///     }]
+    /// })).unwrap();
+    ///
+    /// assert_eq!(msg.role, MessageRole::Assistant);
+    /// assert_eq!(msg.tool_calls.unwrap()[0].args["q"], "rust");
+    /// ```
+    pub fn from_openai_json(value: Value) -> crate::error::Result<Self> {
+        let wire: OpenAiMessage = serde_json::from_value(value)?;
+
+        let role = match wire.role.as_str() {
+            "system" => MessageRole::System,
+            "user" => MessageRole::Human,
+            "assistant" => MessageRole::Assistant,
+            "tool" => MessageRole::Tool,
+            custom => MessageRole::Custom(custom.to_string()),
+        };
+
+        let content = match wire.content {
+            None => MessageContent::Text(String::new()),
+            Some(OpenAiContent::Text(text)) => MessageContent::Text(text),
+            Some(OpenAiContent::Parts(parts)) => {
+                MessageContent::Parts(parts.into_iter().map(content_part_from_openai).collect())
+            }
+        };
+
+        let tool_calls = wire.tool_calls.map(|calls| {
+            calls
+                .into_iter()
+                .map(|call| {
+                    let args = serde_json::from_str(&call.function.arguments)
+                        .unwrap_or(Value::String(call.function.arguments));
+                    ToolCall::new(call.id, call.function.name, args)
+                })
+                .collect()
+        });
+
+        Ok(Self {
+            id: Some(Uuid::new_v4().to_string()),
+            role,
+            content,
+            name: wire.name,
+            tool_calls,
+            tool_call_id: wire.tool_call_id,
+            metadata: None,
+        })
+    }
+
+    /// Render this message in the OpenAI chat completions wire format
+    ///
+    /// The inverse of [`from_openai_json`](Self::from_openai_json). The
+    /// message ID and metadata have no place in the wire format and are
+    /// dropped. An assistant message with tool calls and no text gets
+    /// `null` content, as the API expects.
+    pub fn to_openai_json(&self) -> Value {
+        let role = match &self.role {
+            MessageRole::System => "system",
+            MessageRole::Human => "user",
+            MessageRole::Assistant => "assistant",
+            MessageRole::Tool => "tool",
+            MessageRole::Custom(role) => role.as_str(),
+        };
+
+        let has_tool_calls = self
+            .tool_calls
+            .as_ref()
+            .is_some_and(|calls| !calls.is_empty());
+        let content = match &self.content {
+            MessageContent::Text(text) if text.is_empty() && has_tool_calls => Value::Null,
+            MessageContent::Text(text) => Value::String(text.clone()),
+            MessageContent::Parts(parts) => {
+                Value::Array(parts.iter().map(content_part_to_openai).collect())
+            }
+        };
+
+        let mut message = serde_json::json!({
+            "role": role,
+            "content": content,
+        });
+
+        if let Some(name) = &self.name {
+            message["name"] = Value::String(name.clone());
+        }
+        if has_tool_calls {
+            let calls: Vec<Value> = self
+                .tool_calls
+                .iter()
+                .flatten()
+                .map(|call| {
+                    let arguments = match &call.args {
+                        Value::String(raw) => raw.clone(),
+                        args => args.to_string(),
+                    };
+                    serde_json::json!({
+                        "id": call.id,
+                        "type": "function",
+                        "function": {"name": call.name, "arguments": arguments},
+                    })
+                })
+                .collect();
+            message["tool_calls"] = Value::Array(calls);
+        }
+        if let Some(tool_call_id) = &self.tool_call_id {
+            message["tool_call_id"] = Value::String(tool_call_id.clone());
+        }
+
+        message
+    }
+}
+
+/// Chat message as sent to and returned by the OpenAI chat completions API
+#[derive(Deserialize)]
+struct OpenAiMessage {
+    role: String,
+    #[serde(default)]
+    content: Option<OpenAiContent>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    tool_calls: Option<Vec<OpenAiToolCall>>,
+    #[serde(default)]
+    tool_call_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OpenAiContent {
+    Text(String),
+    Parts(Vec<Value>),
+}
+
+#[derive(Deserialize)]
+struct OpenAiToolCall {
+    id: String,
+    function: OpenAiFunction,
+}
+
+#[derive(Deserialize)]
+struct OpenAiFunction {
+    name: String,
+    #[serde(default)]
+    arguments: String,
+}
+
+/// Map an OpenAI content part to a [`ContentPart`]
+///
+/// `data:` image URLs become base64 image parts so they match
+/// [`ContentPart::image_data`].
+fn content_part_from_openai(part: Value) -> ContentPart {
+    match part.get("type").and_then(Value::as_str) {
+        Some("text") => match part.get("text").and_then(Value::as_str) {
+            Some(text) => ContentPart::text(text),
+            None => ContentPart::Custom { data: part },
+        },
+        Some("image_url") => {
+            let url = part
+                .get("image_url")
+                .and_then(|image| image.get("url"))
+                .and_then(Value::as_str);
+            match url {
+                Some(url) => match parse_data_url(url) {
+                    Some((media_type, data)) => ContentPart::image_data(media_type, data),
+                    None => ContentPart::image_url(url),
+                },
+                None => ContentPart::Custom { data: part },
+            }
+        }
+        _ => ContentPart::Custom { data: part },
+    }
+}
+
+/// Map a [`ContentPart`] to an OpenAI content part
+fn content_part_to_openai(part: &ContentPart) -> Value {
+    match part {
+        ContentPart::Text { text, .. } => serde_json::json!({"type": "text", "text": text}),
+        ContentPart::Image { url, source } => {
+            let url = match (url, source) {
+                (Some(url), _) => url.clone(),
+                (None, Some(source)) => {
+                    let field =
+                        |key: &str| source.get(key).and_then(Value::as_str).unwrap_or_default();
+                    format!("data:{};base64,{}", field("media_type"), field("data"))
+                }
+                (None, None) => String::new(),
+            };
+            serde_json::json!({"type": "image_url", "image_url": {"url": url}})
+        }
+        ContentPart::Custom { data } => data.clone(),
+    }
+}
+
+/// Split a `data:<media type>;base64,<data>` URL
+fn parse_data_url(url: &str) -> Option<(&str, &str)> {
+    let rest = url.strip_prefix("data:")?;
+    let (media_type, data) = rest.split_once(";base64,")?;
+    Some((media_type, data))
 }
 
 /// Special marker for removing a message by ID
@@ -1197,12 +1465,115 @@ pub struct TrimOptions {
 }
 
 /// Strategy for trimming messages
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub enum TrimStrategy {
     /// Keep the first (oldest) messages
     First,
     /// Keep the last (newest) messages
     Last,
+    /// Keep the newest messages whose combined token estimate fits in `max_tokens`
+    ///
+    /// The preserved system message counts against the budget. `max_messages`
+    /// is ignored for this strategy.
+    TokenBudget {
+        /// Maximum number of tokens to keep
+        max_tokens: usize,
+        /// Counter used to estimate the size of each message
+        counter: Arc<dyn TokenCounter>,
+    },
+    /// Keep the last `max_messages` verbatim and replace everything older
+    /// with a single summary message
+    ///
+    /// Summarizing needs to await the summarizer, so only
+    /// [`trim_messages_async`] applies it; [`trim_messages`] falls back to
+    /// [`TrimStrategy::Last`].
+    Summarize {
+        /// Produces the message that stands in for the older history
+        summarizer: Arc<dyn MessageSummarizer>,
+    },
+}
+
+impl PartialEq for TrimStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::First, Self::First) | (Self::Last, Self::Last) => true,
+            (
+                Self::TokenBudget { max_tokens: a, counter: ca },
+                Self::TokenBudget { max_tokens: b, counter: cb },
+            ) => a == b && Arc::ptr_eq(ca, cb),
+            (Self::Summarize { summarizer: a }, Self::Summarize { summarizer: b }) => {
+                Arc::ptr_eq(a, b)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Estimates how many tokens a message occupies in a model's context window
+///
+/// Implement this to plug a real tokenizer into [`TrimStrategy::TokenBudget`].
+pub trait TokenCounter: Send + Sync + std::fmt::Debug {
+    /// Estimate the token count of a single message
+    fn count_tokens(&self, message: &Message) -> usize;
+}
+
+/// Condenses a run of messages into one message
+///
+/// Implement this (typically by calling an LLM) to use
+/// [`TrimStrategy::Summarize`]. Keeping it a trait leaves this crate free of
+/// any particular model client.
+#[async_trait]
+pub trait MessageSummarizer: Send + Sync + std::fmt::Debug {
+    /// Summarize `messages`, oldest first, into a single message
+    async fn summarize(&self, messages: &[Message]) -> crate::error::Result<Message>;
+}
+
+/// Heuristic token counter that assumes a fixed number of characters per token
+///
+/// Text content and tool-call arguments are counted; image and custom parts are
+/// ignored. Every message costs at least one token.
+#[derive(Debug, Clone, Copy)]
+pub struct CharsPerTokenCounter {
+    /// Characters that make up one token (defaults to 4)
+    pub chars_per_token: usize,
+}
+
+impl CharsPerTokenCounter {
+    /// Create a counter with a custom characters-per-token ratio
+    pub fn new(chars_per_token: usize) -> Self {
+        Self {
+            chars_per_token: chars_per_token.max(1),
+        }
+    }
+}
+
+impl Default for CharsPerTokenCounter {
+    fn default() -> Self {
+        Self { chars_per_token: 4 }
+    }
+}
+
+impl TokenCounter for CharsPerTokenCounter {
+    fn count_tokens(&self, message: &Message) -> usize {
+        let mut chars = match &message.content {
+            MessageContent::Text(text) => text.chars().count(),
+            MessageContent::Parts(parts) => parts
+                .iter()
+                .map(|part| match part {
+                    ContentPart::Text { text, .. } => text.chars().count(),
+                    _ => 0,
+                })
+                .sum(),
+        };
+
+        if let Some(tool_calls) = &message.tool_calls {
+            for call in tool_calls {
+                chars += call.name.chars().count() + call.args.to_string().chars().count();
+            }
+        }
+
+        chars.div_ceil(self.chars_per_token.max(1)).max(1)
+    }
 }
 
 impl Default for TrimOptions {
@@ -1235,6 +1606,42 @@ impl TrimOptions {
         }
     }
 
+    /// Create options to keep the newest messages that fit in `max_tokens`
+    ///
+    /// Uses [`CharsPerTokenCounter`] until a different counter is set with
+    /// [`with_token_counter`](Self::with_token_counter).
+    pub fn token_budget(max_tokens: usize) -> Self {
+        Self {
+            strategy: TrimStrategy::TokenBudget {
+                max_tokens,
+                counter: Arc::new(CharsPerTokenCounter::default()),
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Create options that keep the last `keep_last` messages and summarize
+    /// the rest with `summarizer`
+    ///
+    /// Apply these with [`trim_messages_async`].
+    pub fn summarize(keep_last: usize, summarizer: Arc<dyn MessageSummarizer>) -> Self {
+        Self {
+            max_messages: keep_last,
+            strategy: TrimStrategy::Summarize { summarizer },
+            ..Default::default()
+        }
+    }
+
+    /// Replace the token counter used by the [`TrimStrategy::TokenBudget`] strategy
+    ///
+    /// Has no effect for count-based strategies.
+    pub fn with_token_counter(mut self, counter: Arc<dyn TokenCounter>) -> Self {
+        if let TrimStrategy::TokenBudget { counter: current, .. } = &mut self.strategy {
+            *current = counter;
+        }
+        self
+    }
+
     /// Set whether to preserve the system message
     pub fn with_include_system(mut self, include: bool) -> Self {
         self.include_system = include;
@@ -1292,7 +1699,7 @@ pub fn trim_messages(messages: Vec<Message>, options: TrimOptions) -> Vec<Messag
 
     // Apply trimming strategy
     let trimmed = match options.strategy {
-        TrimStrategy::Last => {
+        TrimStrategy::Last | TrimStrategy::Summarize { .. } => {
             if working_messages.len() > options.max_messages {
                 let skip_count = working_messages.len() - options.max_messages;
                 working_messages.into_iter().skip(skip_count).collect()
@@ -1303,6 +1710,17 @@ pub fn trim_messages(messages: Vec<Message>, options: TrimOptions) -> Vec<Messag
         TrimStrategy::First => {
             working_messages.into_iter().take(options.max_messages).collect()
         }
+        TrimStrategy::TokenBudget { max_tokens, counter } => {
+            let reserved = system_message
+                .as_ref()
+                .map(|m| counter.count_tokens(m))
+                .unwrap_or(0);
+            trim_to_token_budget(
+                working_messages,
+                max_tokens.saturating_sub(reserved),
+                counter.as_ref(),
+            )
+        }
     };
 
     // Add system message back if it was preserved
@@ -1341,6 +1759,135 @@ pub fn trim_messages(messages: Vec<Message>, options: TrimOptions) -> Vec<Messag
     result
 }
 
+/// Trim messages, awaiting the summarizer for [`TrimStrategy::Summarize`]
+///
+/// Other strategies behave exactly like [`trim_messages`]. When summarizing,
+/// the preserved system message stays first, followed by the summary and the
+/// last `max_messages` messages. The cut is moved earlier if it would separate
+/// tool results from the assistant message that requested them.
+/// `start_on_human` is not applied, since the summary leads the history.
+/// Nothing is summarized if the history already fits.
+///
+/// # Example
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use langgraph_core::messages::{trim_messages_async, Message, MessageSummarizer, TrimOptions};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct CountingSummarizer;
+///
+/// #[async_trait]
+/// impl MessageSummarizer for CountingSummarizer {
+///     async fn summarize(&self, messages: &[Message]) -> langgraph_core::Result<Message> {
+///         Ok(Message::system(format!("{} earlier messages omitted", messages.len())))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let messages = vec![
+///     Message::human("Q1"),
+///     Message::ai("A1"),
+///     Message::human("Q2"),
+///     Message::ai("A2"),
+/// ];
+///
+/// let options = TrimOptions::summarize(2, Arc::new(CountingSummarizer));
+/// let trimmed = trim_messages_async(messages, options).await.unwrap();
+/// assert_eq!(trimmed.len(), 3);
+/// assert_eq!(trimmed[0].text(), Some("2 earlier messages omitted"));
+/// # }
+/// ```
+pub async fn trim_messages_async(
+    messages: Vec<Message>,
+    options: TrimOptions,
+) -> crate::error::Result<Vec<Message>> {
+    let summarizer = match &options.strategy {
+        TrimStrategy::Summarize { summarizer } => Arc::clone(summarizer),
+        _ => return Ok(trim_messages(messages, options)),
+    };
+
+    let mut working_messages = messages;
+    let system_message = if options.include_system
+        && working_messages
+            .first()
+            .is_some_and(|m| m.role == MessageRole::System)
+    {
+        Some(working_messages.remove(0))
+    } else {
+        None
+    };
+
+    // Keep tool results with the call that requested them. With
+    // `max_messages == 0` everything is summarized and `split` is the length.
+    let mut split = working_messages.len().saturating_sub(options.max_messages);
+    while split > 0
+        && working_messages
+            .get(split)
+            .is_some_and(|m| m.role == MessageRole::Tool)
+    {
+        split -= 1;
+    }
+
+    let mut result = Vec::with_capacity(working_messages.len() - split + 2);
+    result.extend(system_message);
+    if split > 0 {
+        let recent = working_messages.split_off(split);
+        result.push(summarizer.summarize(&working_messages).await?);
+        result.extend(recent);
+    } else {
+        result.extend(working_messages);
+    }
+
+    Ok(result)
+}
+
+/// Keep the newest messages that fit in `budget` tokens
+///
+/// An assistant message with tool calls and the tool results answering it are
+/// kept or dropped together, so the result never contains half of a tool exchange.
+fn trim_to_token_budget(
+    messages: Vec<Message>,
+    budget: usize,
+    counter: &dyn TokenCounter,
+) -> Vec<Message> {
+    // Group each tool-calling assistant message with the tool results that follow it
+    let mut groups: Vec<Vec<Message>> = Vec::new();
+    for message in messages {
+        let joins_previous = message.role == MessageRole::Tool
+            && groups.last().is_some_and(|group| {
+                group[0]
+                    .tool_calls
+                    .as_ref()
+                    .is_some_and(|calls| {
+                        calls
+                            .iter()
+                            .any(|c| message.tool_call_id.as_deref() == Some(c.id.as_str()))
+                    })
+            });
+
+        match groups.last_mut() {
+            Some(group) if joins_previous => group.push(message),
+            _ => groups.push(vec![message]),
+        }
+    }
+
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for group in groups.into_iter().rev() {
+        let cost: usize = group.iter().map(|m| counter.count_tokens(m)).sum();
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        kept.push(group);
+    }
+
+    kept.into_iter().rev().flatten().collect()
+}
+
 /// Manually emit a message to the streaming output during graph execution
 ///
 /// This function allows nodes to incrementally stream message updates rather than
@@ -1422,7 +1969,8 @@ pub fn push_messages(messages: Vec<Message>) -> Result<Vec<Message>, String> {
 /// The add_messages reducer function
 ///
 /// Intelligently merges message lists using ID-based deduplication.
-/// - Messages with the same ID are replaced
+/// - Messages with the same ID are replaced in place, keeping their original position
+/// - Duplicate IDs already present in `left` are collapsed the same way
 /// - RemoveMessage markers delete messages by ID
 /// - New messages are appended
 /// - Missing IDs are auto-generated
@@ -1646,84 +2194,42 @@ pub fn add_message_likes(
         _ => false,
     });
 
-    if let Some(idx) = remove_all_idx {
-        // Return only messages after the REMOVE_ALL marker
-        return right
-            .into_iter()
-            .skip(idx + 1)
-            .filter_map(|ml| match ml {
-                MessageLike::Message(mut m) => {
-                    m.ensure_id();
-                    Some(m)
-                }
-                MessageLike::Remove(_) => None,
-                MessageLike::Tuple((role, content)) => {
-                    let role = match role.as_str() {
-                        "system" => MessageRole::System,
-                        "human" | "user" => MessageRole::Human,
-                        "assistant" | "ai" => MessageRole::Assistant,
-                        "tool" => MessageRole::Tool,
-                        custom => MessageRole::Custom(custom.to_string()),
-                    };
-                    Some(Message::new(role, content))
-                }
-            })
-            .collect();
-    }
+    let (left, right) = match remove_all_idx {
+        // Discard history and keep only messages after the REMOVE_ALL marker
+        Some(idx) => (
+            Vec::new(),
+            right
+                .into_iter()
+                .skip(idx + 1)
+                .filter(|ml| !matches!(ml, MessageLike::Remove(_)))
+                .collect(),
+        ),
+        None => (left, right),
+    };
 
-    // Convert left to messages and ensure IDs
-    let mut merged: Vec<Message> = left
-        .into_iter()
-        .filter_map(|ml| match ml {
-            MessageLike::Message(mut m) => {
-                m.ensure_id();
-                Some(m)
-            }
-            MessageLike::Remove(_) => None, // Ignore remove markers in left
+    let mut merged: Vec<Message> = Vec::new();
+    let mut merged_by_id: HashMap<String, usize> = HashMap::new();
+
+    // Existing history may already contain duplicates (e.g. a message re-added
+    // when resuming from a checkpoint), so collapse those the same way as updates
+    for ml in left {
+        match ml {
+            MessageLike::Message(m) => upsert_message(&mut merged, &mut merged_by_id, m),
+            MessageLike::Remove(_) => {} // Ignore remove markers in left
             MessageLike::Tuple((role, content)) => {
-                let role = match role.as_str() {
-                    "system" => MessageRole::System,
-                    "human" | "user" => MessageRole::Human,
-                    "assistant" | "ai" => MessageRole::Assistant,
-                    "tool" => MessageRole::Tool,
-                    custom => MessageRole::Custom(custom.to_string()),
-                };
-                let mut m = Message::new(role, content);
-                m.ensure_id();
-                Some(m)
+                upsert_message(&mut merged, &mut merged_by_id, message_from_tuple(role, content))
             }
-        })
-        .collect();
-
-    // Build index of existing messages by ID
-    let mut merged_by_id: HashMap<String, usize> = merged
-        .iter()
-        .enumerate()
-        .filter_map(|(i, m)| m.id.clone().map(|id| (id, i)))
-        .collect();
+        }
+    }
 
     // Track IDs marked for removal
     let mut ids_to_remove = std::collections::HashSet::new();
 
     // Process right messages
     for ml in right {
-        match ml {
-            MessageLike::Message(mut m) => {
-                m.ensure_id();
-                let id = m.id.clone().unwrap(); // Safe because we called ensure_id
-
-                if let Some(&existing_idx) = merged_by_id.get(&id) {
-                    // Message with this ID exists
-                    // If it was marked for removal, unmark it (we're replacing it)
-                    ids_to_remove.remove(&id);
-                    // Replace the existing message
-                    merged[existing_idx] = m;
-                } else {
-                    // New message - append it
-                    merged_by_id.insert(id.clone(), merged.len());
-                    merged.push(m);
-                }
-            }
+        let m = match ml {
+            MessageLike::Message(m) => m,
+            MessageLike::Tuple((role, content)) => message_from_tuple(role, content),
             MessageLike::Remove(rm) => {
                 // Check if this ID exists in the merged messages
                 if merged_by_id.contains_key(&rm.id) {
@@ -1736,28 +2242,15 @@ pub fn add_message_likes(
                         rm.id
                     );
                 }
+                continue;
             }
-            MessageLike::Tuple((role, content)) => {
-                let role = match role.as_str() {
-                    "system" => MessageRole::System,
-                    "human" | "user" => MessageRole::Human,
-                    "assistant" | "ai" => MessageRole::Assistant,
-                    "tool" => MessageRole::Tool,
-                    custom => MessageRole::Custom(custom.to_string()),
-                };
-                let mut m = Message::new(role, content);
-                m.ensure_id();
-                let id = m.id.clone().unwrap();
+        };
 
-                if let Some(&existing_idx) = merged_by_id.get(&id) {
-                    ids_to_remove.remove(&id);
-                    merged[existing_idx] = m;
-                } else {
-                    merged_by_id.insert(id.clone(), merged.len());
-                    merged.push(m);
-                }
-            }
+        // If this ID was marked for removal, unmark it (we're replacing it)
+        if let Some(id) = &m.id {
+            ids_to_remove.remove(id);
         }
+        upsert_message(&mut merged, &mut merged_by_id, m);
     }
 
     // Filter out messages marked for removal
@@ -1772,6 +2265,35 @@ pub fn add_message_likes(
         .collect()
 }
 
+/// Replace the message with the same ID in place, or append it if the ID is new
+fn upsert_message(
+    merged: &mut Vec<Message>,
+    merged_by_id: &mut HashMap<String, usize>,
+    mut message: Message,
+) {
+    message.ensure_id();
+    let id = message.id.clone().unwrap(); // Safe because we called ensure_id
+
+    if let Some(&existing_idx) = merged_by_id.get(&id) {
+        merged[existing_idx] = message;
+    } else {
+        merged_by_id.insert(id, merged.len());
+        merged.push(message);
+    }
+}
+
+/// Build a message from a `(role, content)` tuple
+fn message_from_tuple(role: String, content: String) -> Message {
+    let role = match role.as_str() {
+        "system" => MessageRole::System,
+        "human" | "user" => MessageRole::Human,
+        "assistant" | "ai" => MessageRole::Assistant,
+        "tool" => MessageRole::Tool,
+        custom => MessageRole::Custom(custom.to_string()),
+    };
+    Message::new(role, content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1900,6 +2422,94 @@ mod tests {
         assert_eq!(result[0].id, Some("3".to_string()));
     }
 
+    #[test]
+    fn test_add_messages_new_id_appends() {
+        let history = vec![
+            Message::human("Hi").with_id("1"),
+            Message::assistant("Hello").with_id("2"),
+        ];
+
+        let result = add_messages(history, vec![Message::human("Bye").with_id("3")]);
+        let ids: Vec<_> = result.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_add_messages_existing_id_replaced_in_place() {
+        let history = vec![
+            Message::human("Hi").with_id("1"),
+            Message::assistant("Draft").with_id("2"),
+            Message::human("Thanks").with_id("3"),
+        ];
+
+        // Re-adding the same messages (e.g. when resuming) must not duplicate history
+        let result = add_messages(
+            history.clone(),
+            vec![Message::assistant("Final").with_id("2"), history[0].clone()],
+        );
+
+        let ids: Vec<_> = result.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        assert_eq!(result[1].text(), Some("Final"));
+    }
+
+    #[test]
+    fn test_add_messages_collapses_duplicates_in_history() {
+        let history = vec![
+            Message::human("Hi").with_id("1"),
+            Message::assistant("Hello").with_id("2"),
+            Message::human("Hi again").with_id("1"),
+        ];
+
+        let result = add_messages(history, vec![]);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id.as_deref(), Some("1"));
+        assert_eq!(result[0].text(), Some("Hi again"));
+    }
+
+    #[test]
+    fn test_add_message_likes_remove_by_id_after_collapse() {
+        // History holds id "1" twice; one removal must drop every copy
+        let history = vec![
+            MessageLike::Message(Message::human("Hi").with_id("1")),
+            MessageLike::Message(Message::assistant("Hello").with_id("2")),
+            MessageLike::Message(Message::human("Hi again").with_id("1")),
+        ];
+
+        let result = add_message_likes(history, vec![MessageLike::Remove(RemoveMessage::new("1"))]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id.as_deref(), Some("2"));
+
+        // A duplicate id within the same update collapses before the removal applies
+        let history = vec![MessageLike::Message(Message::human("Hi").with_id("1"))];
+        let update = vec![
+            MessageLike::Message(Message::assistant("Draft").with_id("2")),
+            MessageLike::Message(Message::assistant("Final").with_id("2")),
+            MessageLike::Remove(RemoveMessage::new("2")),
+        ];
+
+        let result = add_message_likes(history, update);
+        let ids: Vec<_> = result.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["1"]);
+    }
+
+    #[test]
+    fn test_add_messages_remove_all_dedupes_new_messages() {
+        let history = vec![Message::human("Old").with_id("1")];
+        let update = vec![
+            MessageLike::Remove(RemoveMessage::remove_all()),
+            MessageLike::Message(Message::human("New").with_id("2")),
+            MessageLike::Message(Message::human("Newer").with_id("2")),
+        ];
+
+        let result = add_message_likes(
+            history.into_iter().map(MessageLike::Message).collect(),
+            update,
+        );
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].text(), Some("Newer"));
+    }
+
     #[test]
     fn test_convert_to_messages() {
         let values = vec![
@@ -2326,6 +2936,235 @@ mod tests {
         assert_eq!(opts.start_on_human, false);
     }
 
+    #[test]
+    fn test_chars_per_token_counter() {
+        let counter = CharsPerTokenCounter::default();
+        assert_eq!(counter.count_tokens(&Message::human("abcdefgh")), 2);
+        assert_eq!(counter.count_tokens(&Message::human("abcdefghi")), 3);
+        // Empty messages still cost a token
+        assert_eq!(counter.count_tokens(&Message::human("")), 1);
+    }
+
+    #[test]
+    fn test_trim_messages_token_budget_long_history() {
+        // Each turn is 40 chars = 10 tokens with the default counter
+        let turn = "x".repeat(40);
+        let mut messages = vec![Message::system("sys!")]; // 1 token
+        for i in 0..20 {
+            messages.push(Message::human(turn.clone()).with_id(format!("h{}", i)));
+            messages.push(Message::assistant(turn.clone()).with_id(format!("a{}", i)));
+        }
+
+        let trimmed = trim_messages(messages, TrimOptions::token_budget(45));
+
+        // 1 (system) + 4 * 10 fits, a fifth message would not
+        assert_eq!(trimmed.len(), 5);
+        assert_eq!(trimmed[0].role, MessageRole::System);
+        assert_eq!(trimmed[1].id.as_deref(), Some("h18"));
+        assert_eq!(trimmed[4].id.as_deref(), Some("a19"));
+
+        let counter = CharsPerTokenCounter::default();
+        let total: usize = trimmed.iter().map(|m| counter.count_tokens(m)).sum();
+        assert!(total <= 45);
+    }
+
+    #[test]
+    fn test_trim_messages_token_budget_keeps_tool_pairs() {
+        let call = ToolCall {
+            id: "call_1".to_string(),
+            name: "search".to_string(),
+            args: serde_json::json!({}),
+        };
+        let messages = vec![
+            Message::human("a".repeat(40)).with_id("h1"),
+            Message::assistant("").with_id("a1").with_tool_calls(vec![call]),
+            Message::tool("r".repeat(40), "call_1").with_id("t1"),
+            Message::assistant("b".repeat(40)).with_id("a2"),
+        ];
+
+        // Budget fits the final answer and the tool result, but not the tool call too
+        let opts = TrimOptions::token_budget(21).with_start_on_human(false);
+        let trimmed = trim_messages(messages.clone(), opts);
+        assert_eq!(trimmed.len(), 1);
+        assert_eq!(trimmed[0].id.as_deref(), Some("a2"));
+
+        // A larger budget keeps the whole exchange intact
+        let opts = TrimOptions::token_budget(25).with_start_on_human(false);
+        let trimmed = trim_messages(messages, opts);
+        let ids: Vec<_> = trimmed.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["a1", "t1", "a2"]);
+    }
+
+    #[test]
+    fn test_extract_tool_calls_well_formed() {
+        let msg = Message::ai(
+            "Let me look that up.\n<tool_call>\n{\"id\": \"call_1\", \"name\": \"search\", \"arguments\": {\"query\": \"rust\"}}\n</tool_call>",
+        );
+
+        let calls = msg.extract_tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].name, "search");
+        assert_eq!(calls[0].args, serde_json::json!({"query": "rust"}));
+    }
+
+    #[test]
+    fn test_extract_tool_calls_multiple() {
+        // OpenAI-style array in a fenced block, with string-encoded arguments
+        let content = r#"```json
+[
+  {"id": "a", "type": "function", "function": {"name": "add", "arguments": "{\"x\": 1, \"y\": 2}"}},
+  {"name": "weather", "args": {"city": "Paris"}}
+]
+```"#;
+
+        let calls = Message::ai(content).extract_tool_calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].name, "add");
+        assert_eq!(calls[0].args, serde_json::json!({"x": 1, "y": 2}));
+        assert_eq!(calls[1].name, "weather");
+        assert!(!calls[1].id.is_empty(), "missing ids are generated");
+    }
+
+    #[test]
+    fn test_extract_tool_calls_skips_malformed_block() {
+        let content = concat!(
+            "<tool_call>{\"name\": \"search\", \"arguments\": {\"q\": 1}}</tool_call>",
+            "<tool_call>{\"name\": \"broken\", \"arguments\": </tool_call>",
+            "<tool_call>{\"arguments\": {}}</tool_call>",
+        );
+
+        let (calls, errors) = Message::ai(content).try_extract_tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].name, "search");
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| matches!(e, ToolError::MalformedCall(_))));
+
+        // The lenient variant still returns the valid call
+        assert_eq!(Message::ai(content).extract_tool_calls().len(), 1);
+    }
+
+    #[test]
+    fn test_extract_tool_calls_prefers_structured_and_ignores_prose() {
+        let structured = Message::ai("{\"name\": \"ignored\"}")
+            .with_tool_calls(vec![ToolCall::new("1", "search", serde_json::json!({}))]);
+        let calls = structured.extract_tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].name, "search");
+
+        let (calls, errors) = Message::ai("Just a normal answer.").try_extract_tool_calls();
+        assert!(calls.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[derive(Debug)]
+    struct WordCounter;
+
+    impl TokenCounter for WordCounter {
+        fn count_tokens(&self, message: &Message) -> usize {
+            message.text().map(|t| t.split_whitespace().count()).unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_trim_messages_token_budget_custom_counter() {
+        let messages = vec![
+            Message::system("be brief"),
+            Message::human("one two three"),
+            Message::assistant("four five"),
+            Message::human("six seven"),
+            Message::assistant("eight"),
+        ];
+
+        let opts = TrimOptions::token_budget(5).with_token_counter(Arc::new(WordCounter));
+        let trimmed = trim_messages(messages, opts);
+
+        // system (2) + "six seven" (2) + "eight" (1)
+        assert_eq!(trimmed.len(), 3);
+        assert_eq!(trimmed[1].text(), Some("six seven"));
+        assert_eq!(trimmed[2].text(), Some("eight"));
+    }
+
+    #[derive(Debug, Default)]
+    struct MockSummarizer {
+        summarized: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl MessageSummarizer for MockSummarizer {
+        async fn summarize(&self, messages: &[Message]) -> crate::error::Result<Message> {
+            let ids: Vec<String> = messages.iter().filter_map(|m| m.id.clone()).collect();
+            *self.summarized.lock().unwrap() = ids.clone();
+            Ok(Message::system(format!("Summary of {}", ids.join(","))).with_id("summary"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trim_messages_summarize_collapses_old_messages() {
+        let summarizer = Arc::new(MockSummarizer::default());
+        let call = ToolCall::new("call_1", "search", serde_json::json!({}));
+        let messages = vec![
+            Message::system("sys").with_id("sys"),
+            Message::human("Q1").with_id("h1"),
+            Message::assistant("A1").with_id("a1"),
+            Message::human("Q2").with_id("h2"),
+            Message::assistant("")
+                .with_id("a2")
+                .with_tool_calls(vec![call]),
+            Message::tool("result", "call_1").with_id("t2"),
+            Message::assistant("A2").with_id("a3"),
+        ];
+
+        // Keeping the last 2 would split the tool result from its call
+        let options = TrimOptions::summarize(2, summarizer.clone());
+        let trimmed = trim_messages_async(messages.clone(), options)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = trimmed.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["sys", "summary", "a2", "t2", "a3"]);
+        assert_eq!(
+            *summarizer.summarized.lock().unwrap(),
+            vec!["h1", "a1", "h2"]
+        );
+        assert_eq!(trimmed[1].text(), Some("Summary of h1,a1,h2"));
+
+        // A history that already fits is returned untouched
+        let options = TrimOptions::summarize(10, summarizer);
+        let trimmed = trim_messages_async(messages, options).await.unwrap();
+        assert_eq!(trimmed.len(), 7);
+
+        // The sync trim cannot summarize and keeps the newest messages
+        let options = TrimOptions::summarize(2, Arc::new(MockSummarizer::default()))
+            .with_start_on_human(false);
+        let trimmed = trim_messages(
+            vec![Message::human("a"), Message::ai("b"), Message::ai("c")],
+            options,
+        );
+        assert_eq!(trimmed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_trim_messages_summarize_with_zero_max_messages() {
+        let summarizer = Arc::new(MockSummarizer::default());
+        let messages = vec![
+            Message::system("sys").with_id("sys"),
+            Message::human("Q1").with_id("h1"),
+            Message::assistant("A1").with_id("a1"),
+        ];
+
+        let options = TrimOptions::summarize(0, summarizer.clone());
+        let trimmed = trim_messages_async(messages, options).await.unwrap();
+
+        let ids: Vec<_> = trimmed.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["sys", "summary"]);
+        assert_eq!(*summarizer.summarized.lock().unwrap(), vec!["h1", "a1"]);
+
+        // Nothing to summarize
+        let options = TrimOptions::summarize(0, summarizer);
+        assert!(trim_messages_async(vec![], options).await.unwrap().is_empty());
+    }
+
     // ========================================================================
     // Phase 10.1: Langgraph-Core Messages - Tool Call/Result Matching
     // ========================================================================
@@ -3061,4 +3900,83 @@ mod tests {
         assert_eq!(messages[0].text(), Some("Keep this"));
         assert_eq!(messages[1].text(), Some("Also keep"));
     }
+
+    #[test]
+    fn test_openai_user_message_round_trip() {
+        let wire = serde_json::json!({
+            "role": "user",
+            "content": [
+                {"type": "text", "text": "What's in this image?"},
+                {"type": "image_url", "image_url": {"url": "data:image/png;base64,iVBORw0K"}}
+            ],
+            "name": "alice"
+        });
+
+        let msg = Message::from_openai_json(wire.clone()).unwrap();
+        assert_eq!(msg.role, MessageRole::Human);
+        assert_eq!(msg.name.as_deref(), Some("alice"));
+        match &msg.content {
+            MessageContent::Parts(parts) => assert!(matches!(
+                &parts[1],
+                ContentPart::Image {
+                    source: Some(_),
+                    ..
+                }
+            )),
+            other => panic!("expected parts, got {:?}", other),
+        }
+
+        assert_eq!(msg.to_openai_json(), wire);
+
+        let plain = serde_json::json!({"role": "user", "content": "Hello"});
+        let msg = Message::from_openai_json(plain.clone()).unwrap();
+        assert_eq!(msg.text(), Some("Hello"));
+        assert_eq!(msg.to_openai_json(), plain);
+    }
+
+    #[test]
+    fn test_openai_assistant_tool_calls_round_trip() {
+        let wire = serde_json::json!({
+            "role": "assistant",
+            "content": null,
+            "tool_calls": [
+                {
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "search", "arguments": "{\"query\":\"rust\"}"}
+                },
+                {
+                    "id": "call_2",
+                    "type": "function",
+                    "function": {"name": "broken", "arguments": "{not json"}
+                }
+            ]
+        });
+
+        let msg = Message::from_openai_json(wire.clone()).unwrap();
+        assert_eq!(msg.role, MessageRole::Assistant);
+        let calls = msg.tool_calls.as_ref().unwrap();
+        assert_eq!(calls[0].name, "search");
+        assert_eq!(calls[0].args, serde_json::json!({"query": "rust"}));
+        assert_eq!(calls[1].args, Value::String("{not json".to_string()));
+
+        assert_eq!(msg.to_openai_json(), wire);
+    }
+
+    #[test]
+    fn test_openai_tool_result_round_trip() {
+        let wire = serde_json::json!({
+            "role": "tool",
+            "content": "{\"results\": 3}",
+            "tool_call_id": "call_1"
+        });
+
+        let msg = Message::from_openai_json(wire.clone()).unwrap();
+        assert_eq!(msg.role, MessageRole::Tool);
+        assert_eq!(msg.tool_call_id.as_deref(), Some("call_1"));
+        assert_eq!(msg.to_openai_json(), wire);
+
+        // A message without a role is rejected
+        assert!(Message::from_openai_json(serde_json::json!({"content": "hi"})).is_err());
+    }
 }
@@ -81,6 +81,46 @@ impl NodeResult {
             NodeResult::Command(cmd) => cmd,
         }
     }
+
+    /// Update state and issue a command in one result
+    ///
+    /// `state` becomes the command's update, with any update already on
+    /// `command` layered on top. The executor applies the update through
+    /// channel reducers before acting on the command's goto, so the target
+    /// node sees the reduced state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::{Command, NodeResult};
+    /// use serde_json::json;
+    ///
+    /// let result = NodeResult::with_command(json!({"count": 1}), Command::new().with_goto("review"));
+    /// assert_eq!(result.get_state_update(), Some(json!({"count": 1})));
+    /// assert!(result.has_goto());
+    /// ```
+    pub fn with_command(state: Value, command: Command) -> Self {
+        let update = match (state, command.update.clone()) {
+            (Value::Object(mut state), Some(Value::Object(patch))) => {
+                state.extend(patch);
+                Value::Object(state)
+            }
+            (state, patch) => patch.unwrap_or(state),
+        };
+        NodeResult::Command(command.with_update(update))
+    }
+
+    /// Convert into the value a node returns to the executor
+    ///
+    /// Commands are serialized so the executor can recognise and run them.
+    pub fn into_value(self) -> Value {
+        match self {
+            NodeResult::State(value) => value,
+            NodeResult::Command(cmd) => {
+                serde_json::to_value(cmd).expect("Command always serializes to JSON")
+            }
+        }
+    }
 }
 
 impl From<Value> for NodeResult {
@@ -153,6 +193,24 @@ mod tests {
         assert!(cmd.goto.is_some());
     }
 
+    #[test]
+    fn test_node_result_with_command_merges_update() {
+        let cmd = Command::new()
+            .with_update(json!({"status": "routed"}))
+            .with_goto("next");
+        let result = NodeResult::with_command(json!({"count": 1, "status": "new"}), cmd);
+
+        assert_eq!(
+            result.get_state_update(),
+            Some(json!({"count": 1, "status": "routed"}))
+        );
+        assert!(result.has_goto());
+        assert_eq!(
+            result.into_value(),
+            json!({"update": {"count": 1, "status": "routed"}, "goto": "next"})
+        );
+    }
+
     #[test]
     fn test_node_result_has_resume() {
         let cmd = Command::new().with_resume(json!({"approved": true}));
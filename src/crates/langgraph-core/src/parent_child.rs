@@ -46,11 +46,11 @@ use crate::{
     runtime::get_runtime,
     Command, CommandGraph,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Mutex};
 
 /// Message sent from child to parent graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +92,22 @@ impl ParentMessage {
         self.target_node = Some(node.into());
         self
     }
+
+    /// Deserialize the payload as `T`
+    ///
+    /// Fails with an execution error naming the message type, source, and
+    /// expected type if the payload does not match.
+    pub fn payload_as<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_value(self.payload.clone()).map_err(|e| {
+            GraphError::Execution(format!(
+                "Parent message '{}' from '{}' does not match {}: {}",
+                self.message_type,
+                self.source,
+                std::any::type_name::<T>(),
+                e
+            ))
+        })
+    }
 }
 
 /// Context passed from parent to child graph
@@ -106,6 +122,9 @@ pub struct ParentContext {
     /// Channel for sending messages to parent
     pub parent_channel: Option<mpsc::UnboundedSender<ParentMessage>>,
 
+    /// Parent-side receiver for messages sent on `parent_channel`
+    pub parent_receiver: Option<Arc<Mutex<mpsc::UnboundedReceiver<ParentMessage>>>>,
+
     /// Parent's checkpoint configuration
     pub parent_checkpoint: Option<crate::CheckpointConfig>,
 
@@ -123,6 +142,7 @@ impl ParentContext {
             parent_id: parent_id.into(),
             shared_state: Arc::new(RwLock::new(Value::Null)),
             parent_channel: None,
+            parent_receiver: None,
             parent_checkpoint: None,
             metadata: HashMap::new(),
             depth: 0,
@@ -141,6 +161,12 @@ impl ParentContext {
         self
     }
 
+    /// Set the receiver the parent reads child messages from
+    pub fn with_parent_receiver(mut self, rx: mpsc::UnboundedReceiver<ParentMessage>) -> Self {
+        self.parent_receiver = Some(Arc::new(Mutex::new(rx)));
+        self
+    }
+
     /// Set parent checkpoint configuration
     pub fn with_checkpoint(mut self, checkpoint: crate::CheckpointConfig) -> Self {
         self.parent_checkpoint = Some(checkpoint);
@@ -173,6 +199,27 @@ impl ParentContext {
         }
     }
 
+    /// Receive the next message from a child graph
+    ///
+    /// Returns `Ok(None)` once every sender has been dropped.
+    pub async fn recv(&self) -> Result<Option<ParentMessage>> {
+        let receiver = self.parent_receiver.as_ref().ok_or_else(|| {
+            GraphError::Configuration("No parent receiver available".to_string())
+        })?;
+        Ok(receiver.lock().await.recv().await)
+    }
+
+    /// Receive the next message from a child graph as `T`
+    ///
+    /// Fails if the payload does not match `T`; see
+    /// [`ParentMessage::payload_as`].
+    pub async fn recv_typed<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        match self.recv().await? {
+            Some(message) => message.payload_as().map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Get shared state from parent
     pub fn get_shared_state(&self) -> Value {
         self.shared_state.read().unwrap().clone()
@@ -254,6 +301,30 @@ pub fn send_to_parent(message_type: impl Into<String>, payload: Value) -> Result
     context.send_to_parent(message)
 }
 
+/// Send a typed message to the parent graph
+///
+/// Like [`send_to_parent`], with the payload serialized from `payload`. The
+/// parent reads it back with [`ParentContext::recv_typed`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use langgraph_core::parent_child::send_to_parent_typed;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Progress {
+///     percent: u8,
+/// }
+///
+/// // Inside a subgraph node:
+/// send_to_parent_typed("progress", &Progress { percent: 75 }).ok();
+/// ```
+pub fn send_to_parent_typed<T: Serialize>(message_type: impl Into<String>, payload: &T) -> Result<()> {
+    let payload = serde_json::to_value(payload)?;
+    send_to_parent(message_type, payload)
+}
+
 /// Extension trait for Command to support parent-child communication
 pub trait CommandParentExt {
     /// Send this command to the parent graph
@@ -295,6 +366,14 @@ pub struct SubgraphConfig {
 
     /// Maximum depth for nested subgraphs
     pub max_depth: Option<usize>,
+
+    /// Parent state key under which this subgraph's state is stored
+    ///
+    /// When set, the subgraph reads its state from `parent[namespace]` and its
+    /// output is written back under the same key, so parent and child fields
+    /// with the same name (e.g. `messages`) never collide. This is the state
+    /// counterpart of the stream [`Namespace`](crate::stream::Namespace).
+    pub namespace: Option<String>,
 }
 
 impl SubgraphConfig {
@@ -307,6 +386,7 @@ impl SubgraphConfig {
             state_filter: None,
             forward_messages: true,
             max_depth: None,
+            namespace: None,
         }
     }
 
@@ -340,6 +420,50 @@ impl SubgraphConfig {
         self
     }
 
+    /// Store this subgraph's state under a prefixed key in the parent
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Extract the subgraph's input from parent state.
+    ///
+    /// With a namespace, the subgraph state is unwrapped from `parent[namespace]`;
+    /// keys in `state_filter` that are missing there are seeded from the parent.
+    /// Without a namespace this is the same as [`filter_state`](Self::filter_state).
+    pub fn unwrap_namespaced(&self, parent_state: &Value) -> Value {
+        let Some(ref namespace) = self.namespace else {
+            return self.filter_state(parent_state);
+        };
+
+        let mut child = parent_state
+            .get(namespace)
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        if self.state_filter.is_some() {
+            if let Some(seed) = self.filter_state(parent_state).as_object() {
+                for (key, value) in seed {
+                    child.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+
+        Value::Object(child)
+    }
+
+    /// Write subgraph output back into parent state.
+    ///
+    /// With a namespace, the output replaces `parent[namespace]` and every other
+    /// parent key is left untouched. Returns `None` when no namespace is set.
+    pub fn wrap_namespaced(&self, parent_state: &Value, output: Value) -> Option<Value> {
+        let namespace = self.namespace.as_ref()?;
+        let mut parent = parent_state.as_object().cloned().unwrap_or_default();
+        parent.insert(namespace.clone(), output);
+        Some(Value::Object(parent))
+    }
+
     /// Filter state based on configuration
     pub fn filter_state(&self, state: &Value) -> Value {
         if let Some(ref filter) = self.state_filter {
@@ -507,6 +631,33 @@ mod tests {
         assert_eq!(cmd2.graph, Some(CommandGraph::Named("child".to_string())));
     }
 
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ReviewResult {
+        approved: bool,
+        score: u32,
+    }
+
+    #[tokio::test]
+    async fn test_typed_message_round_trip() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let parent = ParentContext::new("parent")
+            .with_parent_channel(tx)
+            .with_parent_receiver(rx);
+
+        // The subgraph sends through its thread-local parent context
+        set_parent_context(parent.clone());
+        send_to_parent_typed("review", &ReviewResult { approved: true, score: 9 }).unwrap();
+        send_to_parent("review", serde_json::json!({"approved": "maybe"})).unwrap();
+        clear_parent_context();
+
+        let review: Option<ReviewResult> = parent.recv_typed().await.unwrap();
+        assert_eq!(review, Some(ReviewResult { approved: true, score: 9 }));
+
+        let err = parent.recv_typed::<ReviewResult>().await.unwrap_err();
+        assert!(err.to_string().contains("Parent message 'review'"));
+        assert!(err.to_string().contains("ReviewResult"));
+    }
+
     #[test]
     fn test_shared_state_update() {
         let context = ParentContext::new("parent")
@@ -586,7 +586,11 @@ pub fn prepare_next_tasks(
                 for (idx, send_value) in send_array.iter().enumerate() {
                     // Try to deserialize as Send
                     if let Ok(send) = serde_json::from_value::<crate::send::Send>(send_value.clone()) {
-                        let (node_name, arg) = send.into_parts();
+                        let index = send.index();
+                        let (node_name, mut arg) = send.into_parts();
+                        if let (Some(index), Some(obj)) = (index, arg.as_object_mut()) {
+                            obj.insert(crate::send::SEND_INDEX_KEY.to_string(), serde_json::Value::from(index));
+                        }
 
                         // Find the node spec
                         if let Some(node_spec) = node_specs.get(&node_name) {
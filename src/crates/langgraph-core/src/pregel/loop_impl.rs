@@ -3,7 +3,8 @@
 use crate::error::{GraphError, Result};
 use crate::command::{Command, GotoTarget, ResumeValue};
 use crate::stream::{StreamMode, StreamEvent, StreamMultiplexer, StreamEventBuffer, Namespace};
-use crate::interrupt::{InterruptTracker, InterruptWhen, InterruptState};
+use crate::interrupt::{InterruptCondition, InterruptTracker, InterruptWhen, InterruptState};
+use crate::inline_interrupt::InlineResumeValue;
 use crate::managed::ExecutionContext;
 use crate::runtime::{Runtime, StreamWriter, set_runtime, clear_runtime};
 use crate::store::Store;
@@ -18,6 +19,7 @@ use langgraph_checkpoint::{
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use futures::future::join_all;
 use tokio::sync::mpsc;
 
@@ -134,6 +136,23 @@ pub struct PregelNodeSpec {
     pub executor: Arc<dyn NodeExecutor>,
 }
 
+/// Metrics for one completed superstep
+///
+/// Passed to the callback set with
+/// [`PregelLoop::with_on_superstep`] once the superstep's writes are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuperstepInfo {
+    /// Superstep number, starting at 0
+    pub step: usize,
+    /// Number of tasks that ran in the superstep
+    pub active_nodes: usize,
+    /// Time from task preparation to the end of the write barrier
+    pub elapsed: Duration,
+}
+
+/// Callback invoked with the [`SuperstepInfo`] of every completed superstep
+pub type SuperstepCallback = Arc<dyn Fn(SuperstepInfo) + Send + Sync>;
+
 /// The main Pregel execution loop implementing the superstep-based execution model.
 ///
 /// `PregelLoop` orchestrates the stateful execution of a compiled graph using
@@ -265,12 +284,18 @@ pub struct PregelLoop {
     trigger_to_nodes: HashMap<String, Vec<String>>,
     /// Current step number
     step: usize,
-    /// Maximum steps allowed
+    /// Step the current run started from (non-zero when resuming a checkpoint)
+    start_step: usize,
+    /// Maximum steps allowed per run, counted from `start_step`
     max_steps: usize,
+    /// Node executed in the most recent superstep (for error reporting)
+    last_node: Option<String>,
     /// Nodes to interrupt before
     interrupt_before: HashSet<String>,
     /// Nodes to interrupt after
     interrupt_after: HashSet<String>,
+    /// State predicates checked against each task's input before execution
+    interrupt_conditions: Vec<InterruptCondition>,
     /// Pending writes (for crash recovery)
     pending_writes: Vec<PendingWrite>,
     /// Stream modes enabled (deprecated - use stream_mux)
@@ -291,10 +316,16 @@ pub struct PregelLoop {
     interrupt_tracker: InterruptTracker,
     /// Resume value to apply when resuming from an interrupt
     resume_value: Option<ResumeValue>,
+    /// Resume value handed to nodes that paused with an inline interrupt
+    inline_resume: Option<InlineResumeValue>,
     /// Optional store for persistent state
     store: Option<Arc<dyn Store>>,
+    /// Request-scoped context exposed to nodes; never checkpointed
+    request_context: Option<Value>,
     /// Edges from the graph (for conditional routing)
     edges: HashMap<String, Vec<crate::graph::Edge>>,
+    /// Instrumentation callback run after each superstep's write barrier
+    on_superstep: Option<SuperstepCallback>,
 }
 
 impl PregelLoop {
@@ -333,9 +364,12 @@ impl PregelLoop {
             nodes,
             trigger_to_nodes,
             step: 0,
+            start_step: 0,
             max_steps,
+            last_node: None,
             interrupt_before: HashSet::new(),
             interrupt_after: HashSet::new(),
+            interrupt_conditions: Vec::new(),
             pending_writes: Vec::new(),
             stream_modes: vec![],
             stream_tx: None,
@@ -346,8 +380,11 @@ impl PregelLoop {
             checkpoint_config: None,
             interrupt_tracker: InterruptTracker::new(),
             resume_value: None,
+            inline_resume: None,
             store: None,
+            request_context: None,
             edges,
+            on_superstep: None,
         }
     }
 
@@ -480,10 +517,10 @@ impl PregelLoop {
             updated_channels: lc_checkpoint.updated_channels,
         };
 
-        // Restore channel values
+        // Restore channel values (serialized with `Channel::checkpoint` when saved)
         for (channel_name, value) in &pregel_checkpoint.channel_values {
             if let Some(channel) = channels.get_mut(channel_name) {
-                channel.update(vec![value.clone()]).map_err(|e| {
+                channel.from_checkpoint(value.clone()).map_err(|e| {
                     GraphError::Checkpoint(
                         langgraph_checkpoint::CheckpointError::Custom(format!(
                             "Failed to restore channel '{}': {}",
@@ -514,9 +551,12 @@ impl PregelLoop {
             nodes,
             trigger_to_nodes,
             step,
+            start_step: step,
             max_steps,
+            last_node: None,
             interrupt_before: HashSet::new(),
             interrupt_after: HashSet::new(),
+            interrupt_conditions: Vec::new(),
             pending_writes: Vec::new(),
             stream_modes: vec![],
             stream_tx: None,
@@ -527,8 +567,11 @@ impl PregelLoop {
             checkpoint_config: Some(config),
             interrupt_tracker: InterruptTracker::new(),
             resume_value: None,
+            inline_resume: None,
             store: None,
+            request_context: None,
             edges,
+            on_superstep: None,
         })
     }
 
@@ -631,6 +674,15 @@ impl PregelLoop {
         self
     }
 
+    /// Override the maximum number of supersteps (the recursion limit).
+    ///
+    /// When the limit is reached, [`run`](Self::run) returns
+    /// [`GraphError::RecursionLimitReached`].
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
     /// Attach a persistent store for cross-execution state sharing.
     ///
     /// The store provides key-value storage accessible to all nodes,
@@ -662,6 +714,16 @@ impl PregelLoop {
         self
     }
 
+    /// Attach a request-scoped context value for this run.
+    ///
+    /// Nodes read it with [`get_context`](crate::runtime::get_context). It
+    /// lives only in memory for the duration of the run and is never written
+    /// to checkpoints.
+    pub fn with_request_context(mut self, context: Value) -> Self {
+        self.request_context = Some(context);
+        self
+    }
+
     /// Configure nodes that trigger interrupts before execution.
     ///
     /// Execution pauses when any of these nodes are about to run,
@@ -711,6 +773,26 @@ impl PregelLoop {
         self
     }
 
+    /// Set conditional breakpoints.
+    ///
+    /// Before each superstep, every task's input (as the node will receive
+    /// it, managed values included) is checked against the conditions;
+    /// execution pauses only if one returns `true`, recording
+    /// [`InterruptWhen::Condition`] with the condition's name.
+    pub fn with_interrupt_conditions(mut self, conditions: Vec<InterruptCondition>) -> Self {
+        self.interrupt_conditions = conditions;
+        self
+    }
+
+    /// Set a callback invoked after every superstep.
+    ///
+    /// Runs once the superstep's writes are applied and before it is
+    /// checkpointed. The callback only receives metrics, never channel values.
+    pub fn with_on_superstep(mut self, callback: SuperstepCallback) -> Self {
+        self.on_superstep = Some(callback);
+        self
+    }
+
     /// Set a value to apply when resuming from an interrupt.
     ///
     /// The resume value updates the graph state before continuing
@@ -745,6 +827,17 @@ impl PregelLoop {
         self.interrupt_tracker.current_interrupt()
     }
 
+    /// Set the value returned to a node that paused with an inline interrupt.
+    ///
+    /// The value is visible through [`Runtime::get_resume_value`] during the
+    /// first superstep only, so the re-run node receives it (for example from
+    /// [`interrupt_for_typed`](crate::inline_interrupt::interrupt_for_typed))
+    /// while interrupts raised in later supersteps pause again.
+    pub fn with_inline_resume(mut self, resume: InlineResumeValue) -> Self {
+        self.inline_resume = Some(resume);
+        self
+    }
+
     /// Check if execution is currently interrupted.
     pub fn is_interrupted(&self) -> bool {
         self.interrupt_tracker.is_interrupted()
@@ -850,12 +943,12 @@ impl PregelLoop {
         }
 
         loop {
-            // Check if we've exceeded max steps
-            if self.step >= self.max_steps {
-                return Err(GraphError::Execution(format!(
-                    "Maximum steps ({}) exceeded",
-                    self.max_steps
-                )));
+            // Check if we've exceeded max steps for this run
+            if self.steps_taken() >= self.max_steps {
+                return Err(GraphError::RecursionLimitReached {
+                    limit: self.max_steps,
+                    last_node: self.last_node.clone(),
+                });
             }
 
             // Execute one superstep
@@ -1028,6 +1121,8 @@ impl PregelLoop {
     /// - [`apply_writes`](super::algo::apply_writes) - Write application
     /// - [`execute_with_retry`](Self::execute_with_retry) - Task execution with retry
     async fn execute_superstep(&mut self) -> Result<bool> {
+        let started = Instant::now();
+
         // 0. Apply resume value if resuming from interrupt
         let just_resumed = self.interrupt_tracker.is_resuming();
         if just_resumed {
@@ -1064,6 +1159,8 @@ impl PregelLoop {
             return Ok(false);
         }
 
+        self.last_node = tasks.values().map(|task| task.name.clone()).min();
+
         // 2. Track pending writes before execution (for crash recovery)
         self.pending_writes.clear();
         for (task_id, task) in &tasks {
@@ -1099,6 +1196,29 @@ impl PregelLoop {
             ));
         }
 
+        // 3b. Check conditional breakpoints against task inputs
+        if !just_resumed {
+            if let Some((node_name, condition)) = self.tripped_condition(&tasks) {
+                let thread_id = self.checkpoint_config.as_ref()
+                    .and_then(|c| c.thread_id.clone())
+                    .unwrap_or_else(|| "default".to_string());
+                let checkpoint_id = Some(self.checkpoint.id.clone());
+
+                self.interrupt_tracker.interrupt_on_condition(
+                    thread_id,
+                    node_name.clone(),
+                    condition.clone(),
+                    self.step,
+                    checkpoint_id,
+                );
+
+                return Err(GraphError::interrupted(
+                    node_name,
+                    format!("Interrupted by condition '{}'", condition)
+                ));
+            }
+        }
+
         // 4. Emit TaskStart events for streaming
         for (task_id, task) in &tasks {
             self.emit_stream_event(StreamMode::Tasks, StreamEvent::TaskStart {
@@ -1110,10 +1230,12 @@ impl PregelLoop {
 
         // 5. Execute tasks in parallel with retry
         // Create runtime context for nodes
-        let execution_context = ExecutionContext::new(self.max_steps);
-        execution_context.set_current_step(self.step);
+        let execution_context = self.execution_context();
 
         let mut runtime = Runtime::new(execution_context.clone());
+        if let Some(resume) = self.inline_resume.take() {
+            runtime.set_resume_value(Some(resume));
+        }
 
         // Add store if available
         if let Some(store) = &self.store {
@@ -1159,7 +1281,7 @@ impl PregelLoop {
         let results = join_all(task_futures).await;
 
         // Collect results into HashMap
-        let task_results: HashMap<String, Result<serde_json::Value>> =
+        let mut task_results: HashMap<String, Result<serde_json::Value>> =
             results.into_iter().collect();
 
         // 6. Emit TaskEnd/TaskError and Updates events
@@ -1226,6 +1348,17 @@ impl PregelLoop {
             }
         }
 
+        // 6b. A failed task fails the superstep before any writes are applied.
+        // Report the first failure by node name so the error is deterministic.
+        let failed_task = tasks
+            .iter()
+            .filter(|(task_id, _)| matches!(task_results.get(*task_id), Some(Err(_))))
+            .min_by(|(_, a), (_, b)| a.name.cmp(&b.name))
+            .map(|(task_id, _)| task_id.clone());
+        if let Some(Some(Err(error))) = failed_task.map(|task_id| task_results.remove(&task_id)) {
+            return Err(error);
+        }
+
         // 7. Process Command results and extract Send objects
         // Also evaluate conditional edges to route to successor nodes
         // Write Send objects to TASKS channel for execution in next superstep
@@ -1234,18 +1367,46 @@ impl PregelLoop {
         let mut sends_to_write: Vec<Send> = Vec::new();
 
         // 7.1. Extract Sends from Command results (map-reduce pattern)
-        for (task_id, _task) in &tasks {
-            if let Some(Ok(value)) = task_results.get(task_id) {
-                // Try to parse result as Command
-                if let Ok(cmd) = serde_json::from_value::<Command>(value.clone()) {
-                    // Check if Command has goto with Send commands
-                    if let Some(GotoTarget::Sends(sends)) = cmd.goto {
-                        sends_to_write.extend(sends);
-                    } else if let Some(GotoTarget::Send(send)) = cmd.goto {
-                        sends_to_write.push(send);
-                    }
+        // Node gotos carry their update as a patch. Only the patch is written
+        // to custom channels (see step 8); the target is dispatched once the
+        // patch has gone through the channels' reducers (see step 12)
+        let mut goto_patches: HashMap<String, serde_json::Map<String, Value>> = HashMap::new();
+        let mut goto_dispatches: Vec<(Vec<String>, Value, serde_json::Map<String, Value>)> = Vec::new();
+        for (task_id, task) in &tasks {
+            let Some(Ok(value)) = task_results.get(task_id) else {
+                continue;
+            };
+            // Try to parse result as Command
+            let Ok(cmd) = serde_json::from_value::<Command>(value.clone()) else {
+                continue;
+            };
+            let targets = match cmd.goto {
+                Some(GotoTarget::Sends(sends)) => {
+                    sends_to_write.extend(sends);
+                    continue;
                 }
-            }
+                Some(GotoTarget::Send(send)) => {
+                    sends_to_write.push(send);
+                    continue;
+                }
+                Some(GotoTarget::Node(node)) if is_command_output(value) => vec![node],
+                Some(GotoTarget::Nodes(nodes)) if is_command_output(value) => nodes,
+                _ => continue,
+            };
+
+            let patch = match cmd.update {
+                Some(Value::Object(patch)) => patch,
+                _ => serde_json::Map::new(),
+            };
+            // The node's own output is its input with the patch laid over it
+            let mut output = match &task.input {
+                Value::Object(obj) => obj.clone(),
+                _ => serde_json::Map::new(),
+            };
+            output.extend(patch.clone());
+            task_results.insert(task_id.clone(), Ok(Value::Object(output)));
+            goto_dispatches.push((targets, task.input.clone(), patch.clone()));
+            goto_patches.insert(task_id.clone(), patch);
         }
 
         // 7.2. Evaluate conditional edges for dynamic routing
@@ -1302,7 +1463,7 @@ impl PregelLoop {
 
         // 8. Collect writes from completed regular tasks
         // Use the task's write_channels specification to determine where to write output
-        let task_writes: Vec<_> = tasks
+        let mut task_writes: Vec<_> = tasks
             .iter()
             .filter_map(|(task_id, task)| {
                 task_results.get(task_id).and_then(|res| {
@@ -1328,7 +1489,8 @@ impl PregelLoop {
                                 writes.push((task.name.clone(), value.clone()));
 
                                 // Also write fields to custom channels if result is an object
-                                if let Some(obj) = value.as_object() {
+                                // (just the patch for a Command goto, so reducers see it once)
+                                if let Some(obj) = goto_patches.get(task_id).or(value.as_object()) {
                                     for (key, field_value) in obj {
                                         // Only write to custom channels (not node channels, not START/END)
                                         let is_custom_channel = key != &task.name
@@ -1357,6 +1519,18 @@ impl PregelLoop {
             })
             .collect();
 
+        // A node's own channel only signals that it ran. Send fan-out runs a
+        // node several times in one superstep, so keep one of those writes:
+        // the one from the lowest task path (the first Send), regardless of
+        // the order `tasks` iterates in.
+        task_writes.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut signalled = HashSet::new();
+        for tw in task_writes.iter_mut() {
+            if !signalled.insert(tw.name.clone()) {
+                tw.writes.retain(|(channel, _)| *channel != tw.name);
+            }
+        }
+
         // 11. Check interrupt_after (before apply_writes)
         if self.should_interrupt_after(&tasks) {
             // Record interrupt state
@@ -1396,6 +1570,14 @@ impl PregelLoop {
             .map(|tw| (tw.name.clone(), tw.writes.clone()))
             .collect();
 
+        // Auto-consumed channels whose value was visible to this superstep
+        let auto_consumed: Vec<String> = self
+            .channels
+            .iter()
+            .filter(|(_, channel)| channel.auto_consume() && channel.is_available())
+            .map(|(name, _)| name.clone())
+            .collect();
+
         let updated = apply_writes(
             &mut self.checkpoint,
             &mut self.channels,
@@ -1403,6 +1585,39 @@ impl PregelLoop {
             &self.trigger_to_nodes,
         )?;
 
+        // Clear them now that their readers have run, keeping fresh writes for
+        // the next superstep. Versions are left alone so nothing re-triggers.
+        for name in auto_consumed {
+            if all_writes.iter().any(|(channel, _)| *channel == name) {
+                continue;
+            }
+            if let Some(channel) = self.channels.get_mut(&name) {
+                if let Err(e) = channel.update(Vec::new()) {
+                    tracing::warn!(channel = %name, error = %e, "Failed to clear auto-consumed channel");
+                }
+            }
+        }
+
+        // Dispatch Command gotos now that their patches have been reduced
+        if !goto_dispatches.is_empty() {
+            let send_values: Vec<Value> = goto_dispatches
+                .iter()
+                .flat_map(|(targets, input, patch)| {
+                    let state = self.merge_goto_patch(input, patch);
+                    targets.iter().map(move |target| {
+                        serde_json::to_value(Send::new(target.clone(), state.clone()))
+                            .unwrap_or(Value::Null)
+                    })
+                })
+                .collect();
+
+            if let Some(tasks_channel) = self.channels.get_mut("__tasks__") {
+                if let Err(e) = tasks_channel.update(send_values) {
+                    tracing::warn!(error = %e, "Failed to write goto Sends to TASKS channel");
+                }
+            }
+        }
+
         // Emit stream events if modes are enabled
         if !all_writes.is_empty() {
             // Emit Values event (complete state)
@@ -1434,11 +1649,23 @@ impl PregelLoop {
         // 14. Clear pending writes after successful apply
         self.pending_writes.clear();
 
+        if let Some(on_superstep) = &self.on_superstep {
+            on_superstep(SuperstepInfo {
+                step: self.step,
+                active_nodes: tasks.len(),
+                elapsed: started.elapsed(),
+            });
+        }
+
         // 15. Emit Values event (complete state after step)
         self.emit_stream_event(StreamMode::Values, StreamEvent::Values {
             state: self.read_all_channels(),
         });
 
+        // Each superstep produces a distinct checkpoint so history can be traversed
+        self.checkpoint.id = uuid::Uuid::new_v4().to_string();
+        self.checkpoint.ts = chrono::Utc::now();
+
         // 16. Emit Checkpoint event
         self.emit_stream_event(StreamMode::Checkpoints, StreamEvent::Checkpoint {
             thread_id: self.checkpoint.id.clone(),
@@ -1447,7 +1674,16 @@ impl PregelLoop {
         });
 
         // 17. Save checkpoint if checkpointer is configured
+        let mut next_config = None;
         if let (Some(checkpointer), Some(config)) = (&self.checkpointer, &self.checkpoint_config) {
+            // Snapshot channel contents so the checkpoint can be restored later.
+            // Channels that refuse to checkpoint (e.g. untracked values) are skipped.
+            for (channel_name, channel) in &self.channels {
+                if let Ok(value) = channel.checkpoint() {
+                    self.checkpoint.channel_values.insert(channel_name.clone(), value);
+                }
+            }
+
             // Convert ChannelVersions from pregel to langgraph_checkpoint format
             let convert_versions = |versions: &HashMap<String, ChannelVersion>| -> HashMap<String, langgraph_checkpoint::checkpoint::ChannelVersion> {
                 versions.iter().map(|(k, v)| {
@@ -1487,7 +1723,10 @@ impl PregelLoop {
                 metadata.clone(),
                 convert_versions(&self.checkpoint.channel_versions),
             ).await {
-                Ok(_) => {
+                Ok(saved_config) => {
+                    // The next checkpoint records this one as its parent
+                    next_config = Some(saved_config);
+
                     // Emit Checkpoint event if mode is enabled
                     let thread_id = config.thread_id.clone().unwrap_or_else(|| "default".to_string());
                     let checkpoint_ns = config.checkpoint_ns.clone().unwrap_or_default();
@@ -1506,6 +1745,9 @@ impl PregelLoop {
                 }
             }
         }
+        if next_config.is_some() {
+            self.checkpoint_config = next_config;
+        }
 
         // Continue if we updated any channels
         Ok(!updated.is_empty())
@@ -1520,6 +1762,67 @@ impl PregelLoop {
         tasks.values().any(|task| self.interrupt_before.contains(&task.name))
     }
 
+    /// Merge a `Command` goto patch into a task's input.
+    ///
+    /// Called after writes are applied: keys naming a custom channel take
+    /// that channel's reduced value, other keys take the patch value.
+    fn merge_goto_patch(&self, input: &Value, patch: &serde_json::Map<String, Value>) -> Value {
+        let mut state = match input {
+            Value::Object(obj) => obj.clone(),
+            _ => serde_json::Map::new(),
+        };
+
+        for (key, value) in patch {
+            let is_custom_channel = !self.nodes.contains_key(key)
+                && key != "__start__"
+                && key != "__end__";
+            let merged = self
+                .channels
+                .get(key)
+                .filter(|_| is_custom_channel)
+                .and_then(|channel| channel.get().ok())
+                .unwrap_or_else(|| value.clone());
+            state.insert(key.clone(), merged);
+        }
+
+        Value::Object(state)
+    }
+
+    /// Find the first task (by node name) whose input trips a conditional
+    /// breakpoint, returning the node and condition names.
+    fn tripped_condition(&self, tasks: &HashMap<String, PregelExecutableTask>) -> Option<(String, String)> {
+        if self.interrupt_conditions.is_empty() {
+            return None;
+        }
+
+        let execution_context = self.execution_context();
+        let mut tasks: Vec<&PregelExecutableTask> = tasks.values().collect();
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+        tasks.into_iter().find_map(|task| {
+            let mut input = task.input.clone();
+            let _ = execution_context.inject_managed_values(&mut input);
+            self.interrupt_conditions
+                .iter()
+                .find(|condition| condition.matches(&input))
+                .map(|condition| (task.name.clone(), condition.name.clone()))
+        })
+    }
+
+    /// Managed values for the current superstep, as injected into node input.
+    fn execution_context(&self) -> ExecutionContext {
+        let mut execution_context = ExecutionContext::new(self.max_steps);
+        if let Some(context) = &self.request_context {
+            execution_context = execution_context.with_request_context(context.clone());
+        }
+        execution_context.set_current_step(self.steps_taken());
+        execution_context
+    }
+
+    /// Supersteps executed since this run started or resumed.
+    fn steps_taken(&self) -> usize {
+        self.step.saturating_sub(self.start_step)
+    }
+
     /// Check if we should interrupt after executing tasks.
     fn should_interrupt_after(&self, tasks: &HashMap<String, PregelExecutableTask>) -> bool {
         if self.interrupt_after.is_empty() {
@@ -1773,10 +2076,10 @@ impl PregelLoop {
     ) -> Result<Value> {
         let policy = retry_policy.unwrap_or_else(|| super::super::retry::RetryPolicy::new(1)); // Default: no retry
 
-        let mut attempts = 0;
+        let mut state = super::super::retry::RetryState::new();
         let mut last_error = None;
 
-        while attempts < policy.max_attempts {
+        while state.attempts < policy.max_attempts {
             // Set runtime context before execution
             if let Some(ref rt) = runtime {
                 rt.set_current_node(node_name.clone());
@@ -1792,14 +2095,24 @@ impl PregelLoop {
 
             match result {
                 Ok(result) => return Ok(result),
+                // Deterministic failures are not worth another attempt
+                Err(e) if !policy.is_retryable(&e) => return Err(e),
                 Err(e) => {
+                    state.record_attempt(Some(e.to_string()));
                     last_error = Some(e);
-                    attempts += 1;
 
-                    if attempts < policy.max_attempts {
+                    if state.attempts < policy.max_attempts {
                         // Calculate and wait for retry delay
-                        let delay = policy.calculate_delay(attempts - 1);
+                        let delay = policy.calculate_delay(state.attempts - 1);
                         tokio::time::sleep(delay).await;
+                        state.record_delay(delay);
+                        tracing::debug!(
+                            node = ?node_name,
+                            attempt = state.attempts,
+                            slept_ms = delay.as_millis() as u64,
+                            total_slept_ms = state.total_delay.as_millis() as u64,
+                            "Retrying node after backoff"
+                        );
                     }
                 }
             }
@@ -1810,6 +2123,17 @@ impl PregelLoop {
     }
 }
 
+/// Whether a node output is a serialized [`Command`] rather than state that
+/// happens to deserialize as one (every `Command` field is optional)
+fn is_command_output(value: &Value) -> bool {
+    value.as_object().is_some_and(|obj| {
+        obj.contains_key("goto")
+            && obj
+                .keys()
+                .all(|key| matches!(key.as_str(), "graph" | "update" | "resume" | "goto"))
+    })
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -2222,6 +2546,306 @@ pub mod tests {
         // TODO: Once dynamic task execution is implemented, verify all 3 tasks were executed
     }
 
+    /// Records every input it receives and writes nothing back
+    struct RecordingExecutor {
+        seen: Arc<std::sync::Mutex<Vec<Value>>>,
+    }
+
+    impl NodeExecutor for RecordingExecutor {
+        fn execute(&self, input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+            self.seen.lock().unwrap().push(input);
+            Box::pin(async move { Ok(serde_json::json!({})) })
+        }
+    }
+
+    /// Loop with a `triage` node reading `state` (`{"ticket": 7}`) and a
+    /// recording `review` node that is only reachable through a goto
+    ///
+    /// `extra` adds a channel the test's updates are reduced into.
+    fn triage_review_loop(
+        triage: Arc<dyn NodeExecutor>,
+        extra: (&str, Box<dyn Channel>),
+    ) -> (PregelLoop, Arc<std::sync::Mutex<Vec<Value>>>) {
+        use langgraph_checkpoint::{LastValueChannel, TopicChannel};
+
+        let mut cp = Checkpoint::new();
+        cp.channel_versions.insert("state".to_string(), ChannelVersion::Int(1));
+        cp.updated_channels = Some(vec!["state".to_string()]);
+
+        let mut channels: HashMap<String, Box<dyn Channel>> = HashMap::new();
+        channels.insert(
+            "state".to_string(),
+            Box::new(LastValueChannel::with_value(serde_json::json!({"ticket": 7}))),
+        );
+        channels.insert("__tasks__".to_string(), Box::new(TopicChannel::new()));
+        channels.insert("triage".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert("review".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert(extra.0.to_string(), extra.1);
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut nodes = HashMap::new();
+        nodes.insert(
+            "triage".to_string(),
+            PregelNodeSpec {
+                name: "triage".to_string(),
+                triggers: vec!["state".to_string()],
+                reads: vec!["state".to_string()],
+                writes: vec![],
+                executor: triage,
+            },
+        );
+        nodes.insert(
+            "review".to_string(),
+            PregelNodeSpec {
+                name: "review".to_string(),
+                triggers: vec!["__never__".to_string()],
+                reads: vec![],
+                writes: vec![],
+                executor: Arc::new(RecordingExecutor { seen: seen.clone() }),
+            },
+        );
+
+        (PregelLoop::new(cp, channels, nodes, 100), seen)
+    }
+
+    #[tokio::test]
+    async fn test_goto_with_update_patches_target_input() {
+        use langgraph_checkpoint::BinaryOperatorChannel;
+
+        let cmd = Command::goto_with_update(
+            "review",
+            serde_json::json!({"notes": ["flagged"], "priority": "high"}),
+        );
+        let mut notes = BinaryOperatorChannel::append();
+        notes.update(vec![serde_json::json!(["opened"])]).unwrap();
+
+        let (mut loop_inst, seen) = triage_review_loop(
+            Arc::new(CommandExecutor { command: cmd }),
+            ("notes", Box::new(notes)),
+        );
+        loop_inst.execute_superstep().await.unwrap();
+        loop_inst.execute_superstep().await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1, "review should run once, via the goto");
+        assert_eq!(seen[0]["ticket"], 7);
+        assert_eq!(seen[0]["notes"], serde_json::json!(["opened", "flagged"]));
+        assert_eq!(seen[0]["priority"], "high");
+        // The reducer channel received the patch exactly once
+        assert_eq!(
+            loop_inst.channels["notes"].get().unwrap(),
+            serde_json::json!(["opened", "flagged"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_node_result_with_command_updates_then_redirects() {
+        use crate::node_result::NodeResult;
+        use langgraph_checkpoint::BinaryOperatorChannel;
+
+        struct CountAndRedirect;
+
+        impl NodeExecutor for CountAndRedirect {
+            fn execute(&self, _input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+                Box::pin(async move {
+                    let result = NodeResult::with_command(
+                        serde_json::json!({"count": 1}),
+                        Command::new().with_goto("review"),
+                    );
+                    Ok(result.into_value())
+                })
+            }
+        }
+
+        let mut count = BinaryOperatorChannel::new(|a, b| {
+            Value::from(a.as_i64().unwrap_or(0) + b.as_i64().unwrap_or(0))
+        });
+        count.update(vec![serde_json::json!(4)]).unwrap();
+
+        let (mut loop_inst, seen) =
+            triage_review_loop(Arc::new(CountAndRedirect), ("count", Box::new(count)));
+        loop_inst.execute_superstep().await.unwrap();
+        loop_inst.execute_superstep().await.unwrap();
+
+        // The counter was reduced before the goto target ran
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1, "review should run once, via the goto");
+        assert_eq!(seen[0]["ticket"], 7);
+        assert_eq!(seen[0]["count"], 5);
+        assert_eq!(loop_inst.channels["count"].get().unwrap(), serde_json::json!(5));
+    }
+
+    #[tokio::test]
+    async fn test_auto_consume_channel_clears_after_reading_superstep() {
+        use langgraph_checkpoint::{EphemeralValueChannel, LastValueChannel};
+
+        struct SignalExecutor;
+
+        impl NodeExecutor for SignalExecutor {
+            fn execute(&self, _input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+                Box::pin(async move { Ok(serde_json::json!({"signal": "go"})) })
+            }
+        }
+
+        let mut cp = Checkpoint::new();
+        cp.channel_versions.insert("__start__".to_string(), ChannelVersion::Int(1));
+        cp.updated_channels = Some(vec!["__start__".to_string()]);
+
+        let mut channels: HashMap<String, Box<dyn Channel>> = HashMap::new();
+        channels.insert(
+            "__start__".to_string(),
+            Box::new(LastValueChannel::with_value(serde_json::json!({}))),
+        );
+        channels.insert("writer".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert("reader".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert(
+            "signal".to_string(),
+            Box::new(EphemeralValueChannel::new().with_auto_consume(true)),
+        );
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut nodes = HashMap::new();
+        nodes.insert(
+            "writer".to_string(),
+            PregelNodeSpec {
+                name: "writer".to_string(),
+                triggers: vec!["__start__".to_string()],
+                reads: vec!["__start__".to_string()],
+                writes: vec![],
+                executor: Arc::new(SignalExecutor),
+            },
+        );
+        nodes.insert(
+            "reader".to_string(),
+            PregelNodeSpec {
+                name: "reader".to_string(),
+                triggers: vec!["writer".to_string()],
+                reads: vec!["signal".to_string()],
+                writes: vec![],
+                executor: Arc::new(RecordingExecutor { seen: seen.clone() }),
+            },
+        );
+
+        let mut loop_inst = PregelLoop::new(cp, channels, nodes, 100);
+
+        // Written this superstep, so it survives into the next
+        loop_inst.execute_superstep().await.unwrap();
+        assert_eq!(
+            loop_inst.channels["signal"].get().unwrap(),
+            serde_json::json!("go")
+        );
+
+        // The reader sees it, then it is gone
+        loop_inst.execute_superstep().await.unwrap();
+        {
+            let seen = seen.lock().unwrap();
+            assert_eq!(seen.len(), 1);
+            assert_eq!(seen[0]["signal"], "go");
+        }
+        assert!(!loop_inst.channels["signal"].is_available());
+    }
+
+    #[tokio::test]
+    async fn test_indexed_sends_fan_in_in_dispatch_order() {
+        use crate::graph::Edge;
+        use crate::send::{ConditionalEdgeResult, Send as SendTask};
+        use crate::state::{IndexedAppendReducer, Reducer};
+        use langgraph_checkpoint::{BinaryOperatorChannel, LastValueChannel, TopicChannel};
+
+        struct UppercaseExecutor;
+
+        impl NodeExecutor for UppercaseExecutor {
+            fn execute(&self, input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+                Box::pin(async move {
+                    let mut item = input;
+                    let word = item["word"].as_str().unwrap_or_default().to_uppercase();
+                    item["word"] = Value::from(word);
+                    Ok(serde_json::json!({"results": [item]}))
+                })
+            }
+        }
+
+        let words = ["one", "two", "three", "four", "five"];
+
+        let mut cp = Checkpoint::new();
+        cp.channel_versions.insert("__start__".to_string(), ChannelVersion::Int(1));
+        cp.updated_channels = Some(vec!["__start__".to_string()]);
+
+        let mut channels: HashMap<String, Box<dyn Channel>> = HashMap::new();
+        channels.insert(
+            "__start__".to_string(),
+            Box::new(LastValueChannel::with_value(serde_json::json!({"words": words}))),
+        );
+        channels.insert("__tasks__".to_string(), Box::new(TopicChannel::new()));
+        channels.insert("split".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert("worker".to_string(), Box::new(LastValueChannel::new()));
+        channels.insert(
+            "results".to_string(),
+            Box::new(BinaryOperatorChannel::new(|current, update| {
+                IndexedAppendReducer.reduce(&current, &update).unwrap_or(current)
+            })),
+        );
+
+        let mut nodes = HashMap::new();
+        nodes.insert(
+            "split".to_string(),
+            PregelNodeSpec {
+                name: "split".to_string(),
+                triggers: vec!["__start__".to_string()],
+                reads: vec!["__start__".to_string()],
+                writes: vec![],
+                executor: Arc::new(DummyExecutor),
+            },
+        );
+        nodes.insert(
+            "worker".to_string(),
+            PregelNodeSpec {
+                name: "worker".to_string(),
+                triggers: vec![],
+                reads: vec![],
+                writes: vec![],
+                executor: Arc::new(UppercaseExecutor),
+            },
+        );
+
+        let mut edges = HashMap::new();
+        edges.insert(
+            "split".to_string(),
+            vec![Edge::Conditional {
+                router: Arc::new(|state: &Value| {
+                    let words = state["__start__"]["words"].as_array().cloned().unwrap_or_default();
+                    ConditionalEdgeResult::Sends(
+                        words
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, word)| {
+                                SendTask::new("worker", serde_json::json!({"word": word})).with_index(i)
+                            })
+                            .collect(),
+                    )
+                }),
+                branches: HashMap::from([("worker".to_string(), "worker".to_string())]),
+            }],
+        );
+
+        let mut loop_inst = PregelLoop::new_with_edges(cp, channels, nodes, 100, edges);
+        loop_inst.execute_superstep().await.unwrap();
+        loop_inst.execute_superstep().await.unwrap();
+
+        let results = loop_inst.channels["results"].get().unwrap();
+        let aggregated: Vec<&str> = results
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["word"].as_str().unwrap())
+            .collect();
+        assert_eq!(aggregated, vec!["ONE", "TWO", "THREE", "FOUR", "FIVE"]);
+
+        // The worker's own channel keeps the first Send's output every run
+        let signal = loop_inst.channels["worker"].get().unwrap();
+        assert_eq!(signal["results"][0]["word"], "ONE");
+    }
+
     #[tokio::test]
     async fn test_streaming_task_events() {
         use langgraph_checkpoint::LastValueChannel;
@@ -2727,6 +3351,49 @@ pub mod tests {
         assert_eq!(attempts.load(Ordering::SeqCst), 3, "Should have made 3 attempts");
     }
 
+    #[tokio::test]
+    async fn test_retry_logic_skips_non_retryable_errors() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Executor that fails with a deterministic error
+        struct ValidationFailExecutor {
+            attempts: Arc<AtomicUsize>,
+        }
+
+        impl NodeExecutor for ValidationFailExecutor {
+            fn execute(&self, _input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+                let attempts = self.attempts.clone();
+                Box::pin(async move {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    Err(GraphError::Validation("Missing required field".to_string()))
+                })
+            }
+        }
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let executor = Arc::new(ValidationFailExecutor {
+            attempts: attempts.clone(),
+        });
+
+        // Long backoff: if a retry happened the test would take seconds
+        let policy = Some(crate::retry::RetryPolicy::retry_on_transient()
+            .with_initial_interval(5.0)
+            .with_jitter(false));
+
+        let started = Instant::now();
+        let result = PregelLoop::execute_with_retry(
+            executor,
+            serde_json::json!({}),
+            policy,
+            None,
+            None,
+        ).await;
+
+        assert!(matches!(result, Err(GraphError::Validation(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1, "Should fail on the first attempt");
+        assert!(started.elapsed() < std::time::Duration::from_secs(1), "Should not back off");
+    }
+
     #[tokio::test]
     async fn test_checkpoint_save_and_restore() {
         use langgraph_checkpoint::{InMemoryCheckpointSaver, CheckpointConfig};
@@ -3048,6 +3715,75 @@ pub mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_interrupt_condition_trips_only_on_matching_state() {
+        use crate::interrupt::InterruptCondition;
+        use langgraph_checkpoint::LastValueChannel;
+
+        struct EchoExecutor;
+
+        impl NodeExecutor for EchoExecutor {
+            fn execute(&self, input: Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value>> + Send + '_>> {
+                Box::pin(async move { Ok(input) })
+            }
+        }
+
+        let build = |confidence: f64| {
+            let mut nodes = HashMap::new();
+            nodes.insert(
+                "answer".to_string(),
+                PregelNodeSpec {
+                    name: "answer".to_string(),
+                    triggers: vec!["state".to_string()],
+                    reads: vec!["state".to_string()],
+                    writes: vec![],
+                    executor: Arc::new(EchoExecutor),
+                },
+            );
+
+            // StateGraph nodes read the whole state from the "state" channel
+            let mut channels: HashMap<String, Box<dyn Channel>> = HashMap::new();
+            channels.insert(
+                "state".to_string(),
+                Box::new(LastValueChannel::with_value(serde_json::json!({"confidence": confidence}))),
+            );
+
+            let mut checkpoint = Checkpoint::new();
+            checkpoint.updated_channels = Some(vec!["state".to_string()]);
+            checkpoint.channel_versions.insert("state".to_string(), ChannelVersion::Int(1));
+
+            let low_confidence = InterruptCondition::new("low_confidence", |state: &Value| {
+                state["confidence"].as_f64().is_some_and(|c| c < 0.5)
+            });
+            PregelLoop::new(checkpoint, channels, nodes, 100)
+                .with_interrupt_conditions(vec![low_confidence])
+        };
+
+        // Confident state runs straight through
+        let mut pregel = build(0.9);
+        assert!(pregel.execute_superstep().await.is_ok());
+        assert!(!pregel.is_interrupted());
+
+        // Low-confidence state pauses before the node
+        let mut pregel = build(0.3);
+        match pregel.execute_superstep().await {
+            Err(GraphError::Interrupted { node, reason }) => {
+                assert_eq!(node, "answer");
+                assert!(reason.contains("low_confidence"));
+            }
+            other => panic!("Expected Interrupted error, got {:?}", other),
+        }
+
+        let interrupt = pregel.current_interrupt().unwrap();
+        assert_eq!(interrupt.node, "answer");
+        assert_eq!(interrupt.when, InterruptWhen::Condition);
+        assert_eq!(interrupt.condition(), Some("low_confidence"));
+
+        // Resuming skips the check for the paused step
+        pregel.resume().unwrap();
+        assert!(pregel.execute_superstep().await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_messages_streaming_mode() {
         use langgraph_checkpoint::LastValueChannel;
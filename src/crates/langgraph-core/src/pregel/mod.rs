@@ -262,4 +262,4 @@ pub use channel::{
 pub use algo::{apply_writes, prepare_next_tasks, increment};
 pub use executor::TaskExecutor;
 pub use checkpoint::{Checkpoint, ChannelVersions, ChannelVersion};
-pub use loop_impl::{PregelLoop, PregelNodeSpec};
+pub use loop_impl::{PregelLoop, PregelNodeSpec, SuperstepCallback, SuperstepInfo};
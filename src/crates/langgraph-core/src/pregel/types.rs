@@ -9,7 +9,9 @@ use std::future::Future;
 use crate::error::Result;
 
 /// A segment of a task path, used for tracking task execution hierarchy.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Paths order segment by segment, with `Int` segments compared numerically.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum PathSegment {
     /// String identifier (e.g., node name)
     String(String),
@@ -159,16 +161,43 @@ impl fmt::Debug for CachePolicy {
     }
 }
 
+impl CachePolicy {
+    /// Create a cache policy with the default input-hash key and no expiry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expire cached results after `seconds`
+    pub fn with_ttl(mut self, seconds: u64) -> Self {
+        self.ttl = Some(seconds);
+        self
+    }
+
+    /// Use a custom function to derive the cache key from node input
+    pub fn with_key_func<F>(mut self, key_func: F) -> Self
+    where
+        F: Fn(&serde_json::Value) -> String + Send + Sync + 'static,
+    {
+        self.key_func = Arc::new(key_func);
+        self
+    }
+
+    /// Build the cache key for `node` given its input
+    pub fn cache_key(&self, node: &str, input: &serde_json::Value) -> CacheKey {
+        CacheKey {
+            ns: vec![node.to_string()],
+            key: (self.key_func)(input),
+            ttl: self.ttl,
+        }
+    }
+}
+
 impl Default for CachePolicy {
     fn default() -> Self {
         Self {
+            // Stable hash: object key order does not affect the key
             key_func: Arc::new(|input| {
-                // Simple hash-based key
-                use std::hash::{Hash, Hasher};
-                use std::collections::hash_map::DefaultHasher;
-                let mut hasher = DefaultHasher::new();
-                input.to_string().hash(&mut hasher);
-                format!("{:x}", hasher.finish())
+                format!("{:016x}", tooling::serialization::generate_json_hash(input))
             }),
             ttl: None,
         }
@@ -313,6 +342,19 @@ mod tests {
         assert_eq!(seg.to_string(), "(a, 1)");
     }
 
+    #[test]
+    fn test_cache_policy_key_is_order_independent() {
+        let policy = CachePolicy::new().with_ttl(60);
+        let a = policy.cache_key("node", &serde_json::json!({"a": 1, "b": 2}));
+        let b = policy.cache_key("node", &serde_json::json!({"b": 2, "a": 1}));
+        assert_eq!(a, b);
+        assert_eq!(a.ns, vec!["node".to_string()]);
+        assert_eq!(a.ttl, Some(60));
+
+        let c = policy.cache_key("node", &serde_json::json!({"a": 2, "b": 2}));
+        assert_ne!(a.key, c.key);
+    }
+
     #[test]
     fn test_interrupt_creation() {
         let int = Interrupt::new(serde_json::json!({"reason": "user_input"}));
@@ -44,7 +44,7 @@
 //! │  • initial_interval: 0.5s                                │
 //! │  • backoff_factor: 2.0 (doubles each attempt)            │
 //! │  • max_interval: 128s                                    │
-//! │  • jitter: true (randomize timing)                       │
+//! │  • jitter: Full (randomize timing)                       │
 //! └─────────────┬────────────────────────────────────────────┘
 //!               │
 //!               ↓ Calculate delay
@@ -53,7 +53,8 @@
 //! │                                                          │
 //! │  delay = initial × (backoff_factor ^ attempt)            │
 //! │  delay = min(delay, max_interval)                        │
-//! │  if jitter: delay *= random(0.5..1.5)                    │
+//! │  Full:  delay = random(0..delay)                         │
+//! │  Equal: delay = delay/2 + random(0..delay/2)             │
 //! └─────────────┬────────────────────────────────────────────┘
 //!               │
 //!               ↓ Sleep then retry
@@ -99,6 +100,26 @@
 //!     .with_jitter(true);              // Add randomness
 //! ```
 //!
+//! ## Filtering Retryable Errors
+//!
+//! By default every error is retried. Use `retry_if` to skip retries for
+//! deterministic failures such as validation errors:
+//!
+//! ```rust
+//! use langgraph_core::error::GraphError;
+//! use langgraph_core::retry::RetryPolicy;
+//!
+//! // Built-in predicates
+//! let timeouts = RetryPolicy::retry_on_timeout_only();
+//! let transient = RetryPolicy::retry_on_transient();
+//!
+//! // Custom predicate
+//! let policy = RetryPolicy::new(5)
+//!     .with_retry_if(|err| matches!(err, GraphError::NodeExecution { .. }));
+//!
+//! assert!(!transient.is_retryable(&GraphError::Validation("bad input".to_string())));
+//! ```
+//!
 //! ## Retry State Tracking
 //!
 //! ```rust
@@ -233,13 +254,33 @@
 //! they'll all retry at the same time, causing a **thundering herd**:
 //!
 //! ```text
-//! Without Jitter:           With Jitter:
-//! All retry at 0.5s         Spread between 0.25s-0.75s
+//! Without Jitter:           With Full Jitter:
+//! All retry at 0.5s         Spread between 0s-0.5s
 //! ▼▼▼▼▼▼▼▼▼▼               ▼ ▼  ▼ ▼   ▼ ▼  ▼
 //! Server overloaded again   Load distributed smoothly
 //! ```
 //!
-//! Jitter multiplies delay by random factor (0.5x to 1.5x), spreading load.
+//! Four strategies are available via [`JitterKind`]:
+//! - `None` - Use the computed delay as-is
+//! - `Proportional` - Scale by a random factor: `[delay/2, 3·delay/2]` (default)
+//! - `Full` - Pick uniformly from `[0, delay]` (best spread)
+//! - `Equal` - Keep half the delay, randomize the other half: `[delay/2, delay]`
+//!
+//! `with_jitter(true)` and `with_jitter(false)` still work and select
+//! `Proportional` and `None`.
+//!
+//! For reproducible timings in tests, fix the random source with
+//! [`RetryPolicy::with_seed`]:
+//!
+//! ```rust
+//! use langgraph_core::retry::{JitterKind, RetryPolicy};
+//!
+//! let policy = RetryPolicy::new(5)
+//!     .with_jitter(JitterKind::Equal)
+//!     .with_seed(42);
+//!
+//! assert_eq!(policy.calculate_delay(2), policy.calculate_delay(2));
+//! ```
 //!
 //! # Performance Considerations
 //!
@@ -319,11 +360,44 @@
 //! - [tokio-retry](https://crates.io/crates/tokio-retry) - Alternative retry library
 //! - [backoff](https://crates.io/crates/backoff) - Another exponential backoff library
 
+use std::sync::Arc;
 use std::time::Duration;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::error::GraphError;
+
+/// Predicate deciding whether an error should be retried
+pub type RetryPredicate = Arc<dyn Fn(&GraphError) -> bool + Send + Sync>;
+
+/// Strategy for randomizing backoff delays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JitterKind {
+    /// No randomization
+    None,
+    /// Uniform in `[delay / 2, 3 * delay / 2]`, the original `jitter: true`
+    /// behaviour
+    #[default]
+    Proportional,
+    /// Uniform in `[0, delay]`
+    Full,
+    /// Uniform in `[delay / 2, delay]`
+    Equal,
+}
+
+impl From<bool> for JitterKind {
+    /// `true` enables [`JitterKind::Proportional`], `false` disables jitter
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            JitterKind::Proportional
+        } else {
+            JitterKind::None
+        }
+    }
+}
 
 /// Configuration for retrying failed node executions
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RetryPolicy {
     /// Maximum number of attempts (including the first)
     pub max_attempts: usize,
@@ -337,8 +411,28 @@ pub struct RetryPolicy {
     /// Maximum interval between retries in seconds
     pub max_interval: f64,
 
-    /// Whether to add random jitter to intervals
-    pub jitter: bool,
+    /// How to randomize retry intervals
+    pub jitter: JitterKind,
+
+    /// Seed for jitter randomness; makes delays reproducible when set
+    pub seed: Option<u64>,
+
+    /// Predicate checked before each retry; `None` retries every error
+    pub retry_if: Option<RetryPredicate>,
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_interval", &self.initial_interval)
+            .field("backoff_factor", &self.backoff_factor)
+            .field("max_interval", &self.max_interval)
+            .field("jitter", &self.jitter)
+            .field("seed", &self.seed)
+            .field("retry_if", &self.retry_if.as_ref().map(|_| "<predicate>"))
+            .finish()
+    }
 }
 
 impl RetryPolicy {
@@ -349,10 +443,27 @@ impl RetryPolicy {
             initial_interval: 0.5,
             backoff_factor: 2.0,
             max_interval: 128.0,
-            jitter: true,
+            jitter: JitterKind::default(),
+            seed: None,
+            retry_if: None,
         }
     }
 
+    /// Default policy that only retries [`GraphError::Timeout`]
+    pub fn retry_on_timeout_only() -> Self {
+        Self::default().with_retry_if(|err| matches!(err, GraphError::Timeout { .. }))
+    }
+
+    /// Default policy that only retries transient errors
+    ///
+    /// Timeouts and I/O errors are always transient. Node execution errors are
+    /// treated as transient when their message points at a network or capacity
+    /// problem (timeouts, connection failures, rate limits, unavailable
+    /// services, 429/502/503/504).
+    pub fn retry_on_transient() -> Self {
+        Self::default().with_retry_if(is_transient_error)
+    }
+
     /// Set the initial interval between retries
     pub fn with_initial_interval(mut self, seconds: f64) -> Self {
         self.initial_interval = seconds;
@@ -371,16 +482,26 @@ impl RetryPolicy {
         self
     }
 
-    /// Enable or disable jitter
-    pub fn with_jitter(mut self, jitter: bool) -> Self {
-        self.jitter = jitter;
+    /// Set the jitter strategy
+    ///
+    /// Accepts a [`JitterKind`] or a `bool` (`true` means
+    /// [`JitterKind::Proportional`], the default).
+    pub fn with_jitter(mut self, jitter: impl Into<JitterKind>) -> Self {
+        self.jitter = jitter.into();
+        self
+    }
+
+    /// Seed the jitter random source so delays are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
         self
     }
 
     /// Calculate the delay for a given attempt number (0-indexed)
     ///
     /// Uses exponential backoff: initial_interval * (backoff_factor ^ attempt)
-    /// Capped at max_interval, with optional jitter.
+    /// Capped at max_interval, then randomized according to [`JitterKind`].
+    /// With a seed, the same attempt always yields the same delay.
     pub fn calculate_delay(&self, attempt: usize) -> Duration {
         if attempt >= self.max_attempts {
             return Duration::from_secs(0);
@@ -392,22 +513,63 @@ impl RetryPolicy {
         // Cap at max_interval
         let capped_delay = base_delay.min(self.max_interval);
 
-        // Add jitter if enabled (random factor between 0.5 and 1.5)
-        let final_delay = if self.jitter {
-            let mut rng = rand::thread_rng();
-            let jitter_factor = rng.gen_range(0.5..=1.5);
-            capped_delay * jitter_factor
-        } else {
-            capped_delay
+        let final_delay = match self.jitter {
+            JitterKind::None => capped_delay,
+            JitterKind::Proportional => capped_delay * (0.5 + self.jitter_factor(attempt)),
+            JitterKind::Full => capped_delay * self.jitter_factor(attempt),
+            JitterKind::Equal => capped_delay / 2.0 + capped_delay / 2.0 * self.jitter_factor(attempt),
         };
 
         Duration::from_secs_f64(final_delay)
     }
 
+    /// Random factor in `[0, 1]`, seeded per attempt when a seed is set
+    fn jitter_factor(&self, attempt: usize) -> f64 {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(attempt as u64)).gen_range(0.0..=1.0),
+            None => rand::thread_rng().gen_range(0.0..=1.0),
+        }
+    }
+
+    /// Only retry errors for which `predicate` returns true
+    pub fn with_retry_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&GraphError) -> bool + Send + Sync + 'static,
+    {
+        self.retry_if = Some(Arc::new(predicate));
+        self
+    }
+
     /// Check if more retries are allowed
     pub fn should_retry(&self, attempt: usize) -> bool {
         attempt < self.max_attempts
     }
+
+    /// Check whether `error` may be retried under this policy
+    pub fn is_retryable(&self, error: &GraphError) -> bool {
+        match &self.retry_if {
+            Some(predicate) => predicate(error),
+            None => true,
+        }
+    }
+}
+
+/// Heuristic used by [`RetryPolicy::retry_on_transient`]
+fn is_transient_error(error: &GraphError) -> bool {
+    const TRANSIENT_HINTS: &[&str] = &[
+        "timeout", "timed out", "connection", "rate limit", "unavailable", "429", "502", "503",
+        "504",
+    ];
+
+    let message = match error {
+        GraphError::Timeout { .. } | GraphError::Io(_) => return true,
+        GraphError::Provider { retryable, .. } => return *retryable,
+        GraphError::NodeExecution { error, .. } => error,
+        GraphError::Execution(message) => message,
+        _ => return false,
+    };
+    let message = message.to_lowercase();
+    TRANSIENT_HINTS.iter().any(|hint| message.contains(hint))
 }
 
 impl Default for RetryPolicy {
@@ -424,6 +586,12 @@ pub struct RetryState {
 
     /// Last error message
     pub last_error: Option<String>,
+
+    /// Duration actually slept before the most recent retry
+    pub last_delay: Option<Duration>,
+
+    /// Total time slept across all retries
+    pub total_delay: Duration,
 }
 
 impl RetryState {
@@ -432,6 +600,8 @@ impl RetryState {
         Self {
             attempts: 0,
             last_error: None,
+            last_delay: None,
+            total_delay: Duration::ZERO,
         }
     }
 
@@ -441,10 +611,18 @@ impl RetryState {
         self.last_error = error;
     }
 
+    /// Record the delay slept before the next attempt
+    pub fn record_delay(&mut self, delay: Duration) {
+        self.last_delay = Some(delay);
+        self.total_delay += delay;
+    }
+
     /// Reset the retry state
     pub fn reset(&mut self) {
         self.attempts = 0;
         self.last_error = None;
+        self.last_delay = None;
+        self.total_delay = Duration::ZERO;
     }
 }
 
@@ -465,7 +643,8 @@ mod tests {
         assert_eq!(policy.initial_interval, 0.5);
         assert_eq!(policy.backoff_factor, 2.0);
         assert_eq!(policy.max_interval, 128.0);
-        assert!(policy.jitter);
+        assert_eq!(policy.jitter, JitterKind::Proportional);
+        assert_eq!(JitterKind::from(true), JitterKind::default());
     }
 
     #[test]
@@ -480,7 +659,7 @@ mod tests {
         assert_eq!(policy.initial_interval, 1.0);
         assert_eq!(policy.backoff_factor, 3.0);
         assert_eq!(policy.max_interval, 60.0);
-        assert!(!policy.jitter);
+        assert_eq!(policy.jitter, JitterKind::None);
     }
 
     #[test]
@@ -538,7 +717,7 @@ mod tests {
         let has_variation = delays.iter().any(|&d| (d - first_delay).abs() > 0.01);
         assert!(has_variation, "Jitter should produce varied delays");
 
-        // Check that delays are within the jitter range (0.5x to 1.5x base)
+        // `with_jitter(true)` keeps delays within [base/2, 3*base/2]
         let base_delay = 4.0; // 1.0 * 2^2
         for delay in delays {
             assert!(delay >= base_delay * 0.5);
@@ -546,6 +725,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_full_jitter_bounds_and_seed() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_interval(1.0)
+            .with_backoff_factor(2.0)
+            .with_jitter(JitterKind::Full)
+            .with_seed(7);
+
+        for attempt in 0..6 {
+            let ceiling = 2f64.powi(attempt as i32);
+            let delay = policy.calculate_delay(attempt).as_secs_f64();
+            assert!((0.0..=ceiling).contains(&delay), "attempt {} delay {}", attempt, delay);
+
+            // Same seed and attempt reproduce the same delay
+            assert_eq!(policy.calculate_delay(attempt), policy.calculate_delay(attempt));
+            assert_eq!(
+                policy.calculate_delay(attempt),
+                policy.clone().with_seed(7).calculate_delay(attempt)
+            );
+        }
+
+        // A different seed produces a different sequence
+        let other = policy.clone().with_seed(8);
+        assert!((0..6).any(|a| policy.calculate_delay(a) != other.calculate_delay(a)));
+    }
+
+    #[test]
+    fn test_equal_jitter_bounds() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_interval(1.0)
+            .with_jitter(JitterKind::Equal);
+
+        for _ in 0..20 {
+            let delay = policy.calculate_delay(2).as_secs_f64();
+            assert!((2.0..=4.0).contains(&delay));
+        }
+    }
+
     #[test]
     fn test_should_retry() {
         let policy = RetryPolicy::new(3);
@@ -572,8 +789,42 @@ mod tests {
         assert_eq!(state.attempts, 2);
         assert_eq!(state.last_error, Some("Error 2".to_string()));
 
+        state.record_delay(Duration::from_millis(100));
+        state.record_delay(Duration::from_millis(250));
+        assert_eq!(state.last_delay, Some(Duration::from_millis(250)));
+        assert_eq!(state.total_delay, Duration::from_millis(350));
+
         state.reset();
         assert_eq!(state.attempts, 0);
         assert!(state.last_error.is_none());
+        assert!(state.last_delay.is_none());
+        assert_eq!(state.total_delay, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_retry_if_defaults_to_all_errors() {
+        let policy = RetryPolicy::default();
+        assert!(policy.retry_if.is_none());
+        assert!(policy.is_retryable(&GraphError::Validation("bad".to_string())));
+        assert!(policy.is_retryable(&GraphError::Execution("boom".to_string())));
+    }
+
+    #[test]
+    fn test_retry_on_timeout_only() {
+        let policy = RetryPolicy::retry_on_timeout_only();
+        assert!(policy.is_retryable(&GraphError::Timeout {
+            operation: "llm".to_string(),
+            duration_ms: 100,
+        }));
+        assert!(!policy.is_retryable(&GraphError::Execution("connection reset".to_string())));
+    }
+
+    #[test]
+    fn test_retry_on_transient() {
+        let policy = RetryPolicy::retry_on_transient();
+        assert!(policy.is_retryable(&GraphError::node_execution("llm", "Rate limit exceeded")));
+        assert!(policy.is_retryable(&GraphError::Execution("HTTP 503 Service Unavailable".to_string())));
+        assert!(!policy.is_retryable(&GraphError::node_execution("llm", "invalid prompt")));
+        assert!(!policy.is_retryable(&GraphError::Validation("missing field".to_string())));
     }
 }
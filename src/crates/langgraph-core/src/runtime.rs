@@ -25,6 +25,7 @@ use crate::managed::ExecutionContext;
 use crate::store::Store;
 use crate::stream::StreamEvent;
 use crate::inline_interrupt::{InlineInterruptState, InlineResumeValue};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc;
@@ -148,6 +149,11 @@ impl Runtime {
         self.execution_context.is_last_step()
     }
 
+    /// Get the request-scoped context value (if any)
+    pub fn request_context(&self) -> Option<&Value> {
+        self.execution_context.request_context()
+    }
+
     /// Get previous values
     pub fn previous_values(&self) -> Vec<Value> {
         self.previous_values.read().unwrap().clone()
@@ -280,6 +286,20 @@ pub fn get_stream_writer() -> Option<StreamWriter> {
     get_runtime().and_then(|rt| rt.stream_writer().cloned())
 }
 
+/// Get the request-scoped context from the current runtime as `T`
+///
+/// The context is set with [`CheckpointConfig::with_context`] and passed to
+/// `invoke_with_config`. It is available to every node in that invocation
+/// but is never checkpointed. Returns `None` if no context was set or it
+/// does not deserialize as `T`.
+///
+/// [`CheckpointConfig::with_context`]: langgraph_checkpoint::CheckpointConfig::with_context
+pub fn get_context<T: DeserializeOwned>() -> Option<T> {
+    get_runtime()
+        .and_then(|rt| rt.request_context().cloned())
+        .and_then(|context| serde_json::from_value(context).ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,8 +226,15 @@ pub struct Send {
 
     /// State to pass to the target node
     arg: Value,
+
+    /// Dispatch ordinal, for restoring input order when results fan back in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
 }
 
+/// Input key holding the ordinal of a Send created with [`Send::with_index`]
+pub const SEND_INDEX_KEY: &str = "__send_index__";
+
 impl Send {
     /// Create a new Send command
     ///
@@ -247,9 +254,39 @@ impl Send {
         Self {
             node: node.into(),
             arg,
+            index: None,
         }
     }
 
+    /// Tag this Send with its position among the Sends of a fan-out
+    ///
+    /// When the task runs, an object input gets the index under
+    /// [`SEND_INDEX_KEY`]. A target that keeps the key in the items it writes
+    /// lets [`IndexedAppendReducer`](crate::state::IndexedAppendReducer)
+    /// aggregate them in dispatch order, whatever order the tasks finish in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use langgraph_core::send::{ConditionalEdgeResult, Send};
+    ///
+    /// let sends = ["a", "b", "c"]
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(i, item)| Send::new("process", serde_json::json!({"item": item})).with_index(i))
+    ///     .collect();
+    /// let result = ConditionalEdgeResult::Sends(sends);
+    /// ```
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Get the dispatch ordinal, if set with [`with_index`](Self::with_index)
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
     /// Get the target node name
     pub fn node(&self) -> &str {
         &self.node
@@ -541,6 +578,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_send_index_serialization() {
+        let send = Send::new("process", serde_json::json!({"item": "a"})).with_index(3);
+        let serialized = serde_json::to_value(&send).unwrap();
+        assert_eq!(serialized["index"], 3);
+
+        let restored: Send = serde_json::from_value(serialized).unwrap();
+        assert_eq!(restored.index(), Some(3));
+
+        // Unindexed Sends keep their previous shape
+        let plain = serde_json::to_value(Send::new("process", serde_json::json!({}))).unwrap();
+        assert!(plain.get("index").is_none());
+    }
+
     #[test]
     fn test_map_reduce_pattern() {
         // Simulate map-reduce: create multiple sends from a list
@@ -27,6 +27,7 @@
 //! | [`AppendReducer`] | Concatenate arrays | Message history, event logs |
 //! | [`MergeReducer`] | Deep merge objects | Combining partial updates |
 //! | [`SumReducer`] | Add numeric values | Counters, aggregations |
+//! | [`IndexedAppendReducer`] | Concatenate arrays in Send order | Ordered map-reduce results |
 //!
 //! ## [`StateSchema`]
 //!
@@ -245,6 +246,7 @@
 //! - [`Channel`](crate::pregel::Channel) - Low-level channel primitives
 //! - Python LangGraph State - <https://langchain-ai.github.io/langgraph/concepts/low_level/#state>
 
+use crate::send::SEND_INDEX_KEY;
 use serde_json::Value;
 use std::collections::HashMap;
 use thiserror::Error;
@@ -517,6 +519,61 @@ impl Reducer for AppendReducer {
     }
 }
 
+/// Indexed append reducer - concatenates arrays, ordered by Send index
+///
+/// Like [`AppendReducer`], but keeps the list sorted by each item's
+/// [`SEND_INDEX_KEY`] field, as set on the inputs of tasks created with
+/// [`Send::with_index`](crate::send::Send::with_index). Fan-out results then
+/// aggregate in dispatch order regardless of which task finished first.
+///
+/// Items without an index keep their relative order after the indexed ones.
+///
+/// # Example
+///
+/// ```rust
+/// use langgraph_core::state::{IndexedAppendReducer, Reducer};
+/// use serde_json::json;
+///
+/// let current = json!([{"__send_index__": 2, "word": "c"}]);
+/// let update = json!([{"__send_index__": 0, "word": "a"}]);
+///
+/// let result = IndexedAppendReducer.reduce(&current, &update).unwrap();
+/// assert_eq!(result[0]["word"], "a");
+/// assert_eq!(result[1]["word"], "c");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedAppendReducer;
+
+impl Reducer for IndexedAppendReducer {
+    fn reduce(&self, current: &Value, update: &Value) -> Result<Value> {
+        let mut items = match current {
+            Value::Array(curr_arr) => curr_arr.clone(),
+            Value::Null => Vec::new(),
+            _ => {
+                return Err(StateError::ReducerError(
+                    "IndexedAppendReducer requires array values".to_string(),
+                ))
+            }
+        };
+        match update {
+            Value::Array(upd_arr) => items.extend_from_slice(upd_arr),
+            single_value => items.push(single_value.clone()),
+        }
+
+        // Stable, so equal or missing indices keep their arrival order
+        items.sort_by_key(|item| {
+            item.get(SEND_INDEX_KEY)
+                .and_then(Value::as_u64)
+                .unwrap_or(u64::MAX)
+        });
+        Ok(Value::Array(items))
+    }
+
+    fn name(&self) -> &str {
+        "indexed_append"
+    }
+}
+
 /// Merge reducer - deep merges objects
 ///
 /// Combines objects by merging their keys. When both current and update contain
@@ -952,6 +1009,24 @@ mod tests {
         assert_eq!(result, json!([1, 2, 3]));
     }
 
+    #[test]
+    fn test_indexed_append_reducer_orders_by_index() {
+        let reducer = IndexedAppendReducer;
+        let current = json!([{"__send_index__": 1, "v": "b"}, "plain"]);
+        let update = json!([{"__send_index__": 0, "v": "a"}, {"__send_index__": 2, "v": "c"}]);
+
+        let result = reducer.reduce(&current, &update).unwrap();
+        assert_eq!(
+            result,
+            json!([
+                {"__send_index__": 0, "v": "a"},
+                {"__send_index__": 1, "v": "b"},
+                {"__send_index__": 2, "v": "c"},
+                "plain",
+            ])
+        );
+    }
+
     #[test]
     fn test_merge_reducer() {
         let reducer = MergeReducer;
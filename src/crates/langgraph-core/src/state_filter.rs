@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use serde_json::Value;
 use langgraph_checkpoint::{CheckpointMetadata, checkpoint::CheckpointSource};
+use crate::compiled::StateSnapshot;
+
+/// Placeholder that replaces redacted channel values in snapshots
+pub const REDACTED: &str = "[REDACTED]";
 
 /// Filter criteria for state history queries
 ///
@@ -43,6 +47,10 @@ pub struct StateHistoryFilter {
     /// Filter by node that created the checkpoint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub node: Option<String>,
+
+    /// Channels masked with [`REDACTED`] in emitted snapshots
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redacted_channels: Vec<String>,
 }
 
 impl StateHistoryFilter {
@@ -82,6 +90,43 @@ impl StateHistoryFilter {
         self
     }
 
+    /// Mask these channels in emitted snapshots
+    ///
+    /// Matching values are replaced with [`REDACTED`], both as top-level
+    /// snapshot values and as fields of object-valued channels (such as a
+    /// StateGraph's `state`). Stored checkpoints are left untouched.
+    pub fn redact_channels(mut self, channels: &[&str]) -> Self {
+        self.redacted_channels
+            .extend(channels.iter().map(|channel| channel.to_string()));
+        self
+    }
+
+    /// Apply [`redact_channels`](Self::redact_channels) to a snapshot
+    pub fn redact(&self, mut snapshot: StateSnapshot) -> StateSnapshot {
+        if self.redacted_channels.is_empty() {
+            return snapshot;
+        }
+
+        if let Some(values) = snapshot.values.as_object_mut() {
+            for (channel, value) in values.iter_mut() {
+                if self.is_redacted(channel) {
+                    *value = Value::String(REDACTED.to_string());
+                } else if let Some(fields) = value.as_object_mut() {
+                    for (field, field_value) in fields.iter_mut() {
+                        if self.is_redacted(field) {
+                            *field_value = Value::String(REDACTED.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        snapshot
+    }
+
+    fn is_redacted(&self, channel: &str) -> bool {
+        self.redacted_channels.iter().any(|c| c == channel)
+    }
+
     /// Check if a checkpoint metadata matches this filter
     pub fn matches(&self, metadata: &CheckpointMetadata) -> bool {
         // Check source filter
@@ -220,4 +265,30 @@ mod tests {
         metadata.extra.insert("status".to_string(), Value::String("rejected".to_string()));
         assert!(!filter.matches(&metadata));
     }
+
+    #[test]
+    fn test_redact_snapshot_values() {
+        let filter = StateHistoryFilter::new().redact_channels(&["api_key", "tool_input"]);
+        let snapshot = StateSnapshot {
+            values: serde_json::json!({
+                "api_key": "sk-123",
+                "state": {"tool_input": {"query": "raw"}, "answer": 42},
+            }),
+            next: vec![],
+            config: langgraph_checkpoint::CheckpointConfig::new(),
+            metadata: None,
+            created_at: None,
+            parent_config: None,
+            cache_metrics: None,
+        };
+
+        let redacted = filter.redact(snapshot);
+        assert_eq!(
+            redacted.values,
+            serde_json::json!({
+                "api_key": REDACTED,
+                "state": {"tool_input": REDACTED, "answer": 42},
+            })
+        );
+    }
 }
\ No newline at end of file
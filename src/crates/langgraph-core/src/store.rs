@@ -463,6 +463,25 @@ pub enum StoreError {
 /// Result type for Store operations
 pub type Result<T> = std::result::Result<T, StoreError>;
 
+/// Build the full key for `key` inside `namespace`
+///
+/// Namespaced operations such as [`Store::list_prefix`] and
+/// [`Store::compare_and_swap`] store entries under `"{namespace}:{key}"`.
+/// A `:` or `\` inside the namespace is escaped with a backslash, so
+/// namespace `a:b` with key `c` and namespace `a` with key `b:c` stay apart.
+pub fn namespaced_key(namespace: &str, key: &str) -> String {
+    let mut full = String::with_capacity(namespace.len() + key.len() + 1);
+    for c in namespace.chars() {
+        if c == ':' || c == '\\' {
+            full.push('\\');
+        }
+        full.push(c);
+    }
+    full.push(':');
+    full.push_str(key);
+    full
+}
+
 /// Store trait for key-value storage
 ///
 /// Implementations can use any backend: in-memory, Redis, database, etc.
@@ -518,6 +537,57 @@ pub trait Store: Send + Sync {
     ///
     /// * `prefix` - Optional prefix to filter keys to clear
     async fn clear(&self, prefix: Option<&str>) -> Result<usize>;
+
+    /// List entries in `namespace` whose key starts with `prefix`
+    ///
+    /// # Returns
+    ///
+    /// `(key, value)` pairs sorted by key, with the namespace stripped from
+    /// each key
+    async fn list_prefix(&self, namespace: &str, prefix: &str) -> Result<Vec<(String, Value)>> {
+        let ns_prefix = namespaced_key(namespace, "");
+        let mut keys = self.list_keys(Some(&namespaced_key(namespace, prefix))).await?;
+        keys.sort();
+
+        let mut entries = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(value) = self.get(&key).await? {
+                entries.push((key[ns_prefix.len()..].to_string(), value));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Atomically replace the value of `key` in `namespace` if it still
+    /// equals `expected`
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - Value the caller last read, or `None` if the key must
+    ///   not exist yet
+    /// * `new` - Value to store on success
+    ///
+    /// # Returns
+    ///
+    /// true if the value was swapped, false if the current value differed
+    ///
+    /// # Errors
+    ///
+    /// The default implementation cannot make the check and the write
+    /// atomic, so it fails with [`StoreError::Other`]; stores that support
+    /// the operation override it.
+    async fn compare_and_swap(
+        &self,
+        namespace: &str,
+        key: &str,
+        expected: Option<&Value>,
+        new: Value,
+    ) -> Result<bool> {
+        let _ = (namespace, key, expected, new);
+        Err(StoreError::Other(
+            "compare_and_swap is not supported by this store".to_string(),
+        ))
+    }
 }
 
 /// In-memory implementation of Store
@@ -613,6 +683,35 @@ impl Store for InMemoryStore {
             }
         }
     }
+
+    async fn list_prefix(&self, namespace: &str, prefix: &str) -> Result<Vec<(String, Value)>> {
+        let ns_prefix = namespaced_key(namespace, "");
+        let full_prefix = namespaced_key(namespace, prefix);
+        let data = self.data.read().unwrap();
+        let mut entries: Vec<(String, Value)> = data
+            .iter()
+            .filter(|(k, _)| k.starts_with(&full_prefix))
+            .map(|(k, v)| (k[ns_prefix.len()..].to_string(), v.clone()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(entries)
+    }
+
+    async fn compare_and_swap(
+        &self,
+        namespace: &str,
+        key: &str,
+        expected: Option<&Value>,
+        new: Value,
+    ) -> Result<bool> {
+        let full_key = namespaced_key(namespace, key);
+        let mut data = self.data.write().unwrap();
+        if data.get(&full_key) != expected {
+            return Ok(false);
+        }
+        data.insert(full_key, new);
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
@@ -705,6 +804,94 @@ mod tests {
         assert!(store.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_store_list_prefix_is_sorted_and_scoped() {
+        let store = InMemoryStore::new();
+
+        store.put("memories:topic:b", json!(2)).await.unwrap();
+        store.put("memories:topic:a", json!(1)).await.unwrap();
+        store.put("memories:other", json!(3)).await.unwrap();
+        store.put("other:topic:c", json!(4)).await.unwrap();
+
+        let entries = store.list_prefix("memories", "topic:").await.unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("topic:a".to_string(), json!(1)),
+                ("topic:b".to_string(), json!(2)),
+            ]
+        );
+
+        let all = store.list_prefix("memories", "").await.unwrap();
+        let keys: Vec<_> = all.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["other", "topic:a", "topic:b"]);
+    }
+
+    #[tokio::test]
+    async fn test_store_compare_and_swap() {
+        let store = InMemoryStore::new();
+
+        // Insert only if absent
+        assert!(store.compare_and_swap("ns", "counter", None, json!(1)).await.unwrap());
+        assert!(!store.compare_and_swap("ns", "counter", None, json!(5)).await.unwrap());
+
+        // Swap from the current value
+        let current = store.get("ns:counter").await.unwrap().unwrap();
+        assert!(store.compare_and_swap("ns", "counter", Some(&current), json!(2)).await.unwrap());
+
+        // A stale expected value is rejected and leaves the value untouched
+        let stale = json!(1);
+        assert!(!store.compare_and_swap("ns", "counter", Some(&stale), json!(3)).await.unwrap());
+        assert_eq!(store.get("ns:counter").await.unwrap(), Some(json!(2)));
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_keys_with_separator_do_not_collide() {
+        assert_ne!(namespaced_key("a:b", "c"), namespaced_key("a", "b:c"));
+        assert_ne!(namespaced_key("a\\", ":c"), namespaced_key("a\\:", "c"));
+
+        let store = InMemoryStore::new();
+        assert!(store.compare_and_swap("a:b", "c", None, json!(1)).await.unwrap());
+        assert!(store.compare_and_swap("a", "b:c", None, json!(2)).await.unwrap());
+
+        assert_eq!(store.list_prefix("a:b", "").await.unwrap(), vec![("c".to_string(), json!(1))]);
+        assert_eq!(store.list_prefix("a", "").await.unwrap(), vec![("b:c".to_string(), json!(2))]);
+    }
+
+    #[tokio::test]
+    async fn test_compare_and_swap_default_is_unsupported() {
+        /// Store that relies on the trait's default `compare_and_swap`
+        struct MinimalStore;
+
+        #[async_trait]
+        impl Store for MinimalStore {
+            async fn get(&self, _key: &str) -> Result<Option<Value>> {
+                Ok(None)
+            }
+            async fn put(&self, _key: &str, _value: Value) -> Result<()> {
+                Ok(())
+            }
+            async fn delete(&self, _key: &str) -> Result<bool> {
+                Ok(false)
+            }
+            async fn exists(&self, _key: &str) -> Result<bool> {
+                Ok(false)
+            }
+            async fn list_keys(&self, _prefix: Option<&str>) -> Result<Vec<String>> {
+                Ok(Vec::new())
+            }
+            async fn clear(&self, _prefix: Option<&str>) -> Result<usize> {
+                Ok(0)
+            }
+        }
+
+        let err = MinimalStore
+            .compare_and_swap("ns", "key", None, json!(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StoreError::Other(_)));
+    }
+
     #[tokio::test]
     async fn test_store_overwrite() {
         let store = InMemoryStore::new();
@@ -758,12 +945,37 @@ pub trait Cache: Send + Sync {
     ///
     /// Returns the number of entries cleared
     async fn clear_all(&self) -> Result<usize>;
+
+    /// Store a value under `key` in `namespace` that expires after `ttl`
+    ///
+    /// The entry is readable at [`namespaced_key`]`(namespace, key)`. The
+    /// default implementation rounds `ttl` up to whole seconds for [`put`](Self::put).
+    async fn put_with_ttl(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: Value,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let seconds = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+        self.put(&namespaced_key(namespace, key), value, Some(seconds)).await
+    }
+
+    /// Proactively drop every expired entry
+    ///
+    /// Reads already skip expired entries; sweeping reclaims their memory
+    /// without waiting for them to be read. Returns the number dropped.
+    async fn sweep(&self) -> Result<usize> {
+        self.clear_expired().await
+    }
 }
 
 /// In-memory implementation of Cache with TTL support
 ///
-/// This implementation uses tokio's time utilities for TTL checking.
-/// Entries are lazily expired on access, not actively cleaned up.
+/// Expiry uses the monotonic clock ([`tokio::time::Instant`], which follows
+/// tokio's paused clock in tests), so wall-clock jumps don't expire entries
+/// early or keep them alive. Entries are lazily
+/// expired on access; call [`Cache::sweep`] to drop them proactively.
 #[derive(Clone)]
 pub struct InMemoryCache {
     data: Arc<RwLock<HashMap<String, CacheEntry>>>,
@@ -772,7 +984,7 @@ pub struct InMemoryCache {
 #[derive(Clone)]
 struct CacheEntry {
     value: Value,
-    expires_at: Option<std::time::Instant>,
+    expires_at: Option<tokio::time::Instant>,
 }
 
 impl InMemoryCache {
@@ -796,7 +1008,7 @@ impl InMemoryCache {
     /// Check if an entry has expired
     fn is_expired(entry: &CacheEntry) -> bool {
         if let Some(expires_at) = entry.expires_at {
-            std::time::Instant::now() > expires_at
+            tokio::time::Instant::now() > expires_at
         } else {
             false
         }
@@ -831,7 +1043,7 @@ impl Cache for InMemoryCache {
         let mut data = self.data.write().unwrap();
 
         let expires_at = ttl.map(|seconds| {
-            std::time::Instant::now() + std::time::Duration::from_secs(seconds)
+            tokio::time::Instant::now() + std::time::Duration::from_secs(seconds)
         });
 
         let entry = CacheEntry {
@@ -843,6 +1055,23 @@ impl Cache for InMemoryCache {
         Ok(())
     }
 
+    async fn put_with_ttl(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: Value,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let entry = CacheEntry {
+            value,
+            expires_at: Some(tokio::time::Instant::now() + ttl),
+        };
+
+        let mut data = self.data.write().unwrap();
+        data.insert(namespaced_key(namespace, key), entry);
+        Ok(())
+    }
+
     async fn delete(&self, key: &str) -> Result<bool> {
         let mut data = self.data.write().unwrap();
         Ok(data.remove(key).is_some())
@@ -892,6 +1121,7 @@ impl Cache for InMemoryCache {
 mod cache_tests {
     use super::*;
     use serde_json::json;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn test_cache_put_get() {
@@ -1010,6 +1240,38 @@ mod cache_tests {
         assert!(result.is_some());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_put_with_ttl_expires_on_get() {
+        let cache = InMemoryCache::new();
+
+        cache
+            .put_with_ttl("scratch", "plan", json!("draft"), Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(cache.get("scratch:plan").await.unwrap(), Some(json!("draft")));
+
+        tokio::time::advance(Duration::from_millis(80)).await;
+
+        assert_eq!(cache.get("scratch:plan").await.unwrap(), None);
+        assert!(cache.is_empty(), "Expired entry should be dropped on read");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_sweep_reclaims_expired_entries() {
+        let cache = InMemoryCache::new();
+
+        cache.put_with_ttl("scratch", "a", json!(1), Duration::from_millis(20)).await.unwrap();
+        cache.put_with_ttl("scratch", "b", json!(2), Duration::from_millis(20)).await.unwrap();
+        cache.put_with_ttl("scratch", "c", json!(3), Duration::from_secs(60)).await.unwrap();
+        assert_eq!(cache.len(), 3);
+
+        tokio::time::advance(Duration::from_millis(50)).await;
+
+        assert_eq!(cache.sweep().await.unwrap(), 2);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("scratch:c").await.unwrap(), Some(json!(3)));
+    }
+
     #[tokio::test]
     async fn test_cache_clear_all() {
         let cache = InMemoryCache::new();
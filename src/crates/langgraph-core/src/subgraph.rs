@@ -566,7 +566,9 @@ impl SubgraphExecutor for CompiledSubgraph {
             }
 
             // Filter state if needed
-            let input_state = if config.inherit_state {
+            let input_state = if config.namespace.is_some() {
+                config.unwrap_namespaced(&state)
+            } else if config.inherit_state {
                 config.filter_state(&state)
             } else {
                 state.clone()
@@ -581,8 +583,10 @@ impl SubgraphExecutor for CompiledSubgraph {
             // Handle result and state sync
             let final_result = match result {
                 Ok(output) => {
-                    // Sync state back to parent if configured
-                    if config.sync_state_to_parent {
+                    if let Some(namespaced) = config.wrap_namespaced(&state, output.clone()) {
+                        // Namespaced subgraphs only ever write their own key
+                        Ok(namespaced)
+                    } else if config.sync_state_to_parent {
                         // Merge subgraph output with original state
                         if let (Some(state_obj), Some(output_obj)) =
                             (state.as_object(), output.as_object()) {
@@ -1178,4 +1182,72 @@ mod tests {
         assert_eq!(subgraph.name(), "test_sub");
         assert!(subgraph.hierarchy.is_some());
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_namespaced_subgraph_state_independent() {
+        let mut child = StateGraph::new();
+        child.add_node("child_inc", |state| {
+            Box::pin(async move {
+                let mut s = state.as_object().unwrap().clone();
+                let count = s.get("count").and_then(|v| v.as_i64()).unwrap_or(0);
+                s.insert("count".to_string(), serde_json::json!(count + 10));
+                Ok(Value::Object(s))
+            })
+        });
+        child.add_edge("__start__", "child_inc");
+        child.add_edge("child_inc", "__end__");
+        let compiled_child = child.compile().unwrap();
+
+        let config = SubgraphConfig::new("child").with_namespace("child");
+
+        let mut parent = StateGraph::new();
+        parent.add_node("parent_inc", |state| {
+            Box::pin(async move {
+                let mut s = state.as_object().unwrap().clone();
+                let count = s.get("count").and_then(|v| v.as_i64()).unwrap_or(0);
+                s.insert("count".to_string(), serde_json::json!(count + 1));
+                Ok(Value::Object(s))
+            })
+        });
+        parent.add_configured_subgraph("child", compiled_child, config);
+        parent.add_edge("__start__", "parent_inc");
+        parent.add_edge("parent_inc", "child");
+        parent.add_edge("child", "__end__");
+        let compiled_parent = parent.compile().unwrap();
+
+        let result = compiled_parent
+            .invoke(serde_json::json!({"count": 0}))
+            .await
+            .unwrap();
+
+        // Both graphs have a `count` field, but neither clobbers the other
+        assert_eq!(result.get("count"), Some(&serde_json::json!(1)));
+        assert_eq!(result["child"].get("count"), Some(&serde_json::json!(10)));
+    }
+
+    #[test]
+    fn test_namespace_wrap_and_unwrap() {
+        let config = SubgraphConfig::new("sub")
+            .with_namespace("sub")
+            .with_state_filter(vec!["query".to_string()]);
+
+        let parent = serde_json::json!({
+            "count": 1,
+            "query": "hello",
+            "sub": {"count": 5}
+        });
+
+        // Namespaced state is unwrapped, filtered keys seed missing fields
+        let child_input = config.unwrap_namespaced(&parent);
+        assert_eq!(child_input, serde_json::json!({"count": 5, "query": "hello"}));
+
+        // Output is written back under the namespace only
+        let merged = config
+            .wrap_namespaced(&parent, serde_json::json!({"count": 6}))
+            .unwrap();
+        assert_eq!(merged["count"], 1);
+        assert_eq!(merged["sub"], serde_json::json!({"count": 6}));
+
+        assert!(SubgraphConfig::new("plain").wrap_namespaced(&parent, Value::Null).is_none());
+    }
+}
@@ -392,6 +392,10 @@ pub enum ToolError {
     /// Validation error
     #[error("Validation error for tool '{tool}': {error}")]
     ValidationError { tool: String, error: String },
+
+    /// Tool call block embedded in message content could not be parsed
+    #[error("Malformed tool call: {0}")]
+    MalformedCall(String),
 }
 
 /// Runtime context bundle for tool execution
@@ -621,6 +625,150 @@ pub struct ToolCall {
     pub args: Value,
 }
 
+impl ToolCall {
+    /// Create a new tool call
+    pub fn new(id: impl Into<String>, name: impl Into<String>, args: Value) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            args,
+        }
+    }
+
+    /// Parse tool calls embedded as JSON in raw model output
+    ///
+    /// Recognizes calls wrapped in `<tool_call>...</tool_call>` tags, in fenced
+    /// code blocks, or given as the entire text. Each call may use the
+    /// `{"name", "arguments" | "args" | "parameters"}` shape, the OpenAI
+    /// `{"id", "function": {"name", "arguments"}}` shape (with arguments as a
+    /// JSON string), an array of either, or an object with a `tool_calls` array.
+    ///
+    /// Malformed blocks are skipped and reported as [`ToolError::MalformedCall`]
+    /// alongside the calls that did parse. Calls without an `id` get a
+    /// generated one.
+    pub fn parse_from_text(text: &str) -> (Vec<ToolCall>, Vec<ToolError>) {
+        let mut calls = Vec::new();
+        let mut errors = Vec::new();
+
+        let mut blocks = delimited_blocks(text, "<tool_call>", "</tool_call>");
+        if blocks.is_empty() {
+            blocks = fenced_blocks(text);
+        }
+        if blocks.is_empty() {
+            // Bare JSON is only considered when the whole text looks like JSON,
+            // so ordinary prose never produces errors
+            let trimmed = text.trim();
+            if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                blocks.push(trimmed);
+            }
+        }
+
+        for block in blocks {
+            match serde_json::from_str::<Value>(block) {
+                Ok(value) => collect_tool_calls(value, &mut calls, &mut errors),
+                Err(e) => errors.push(ToolError::MalformedCall(format!("invalid JSON: {}", e))),
+            }
+        }
+
+        (calls, errors)
+    }
+}
+
+/// Contents between each `open`/`close` pair; an unterminated block runs to the end
+fn delimited_blocks<'a>(text: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut blocks = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(open) {
+        let after = &rest[start + open.len()..];
+        match after.find(close) {
+            Some(end) => {
+                blocks.push(after[..end].trim());
+                rest = &after[end + close.len()..];
+            }
+            None => {
+                blocks.push(after.trim());
+                break;
+            }
+        }
+    }
+    blocks
+}
+
+/// Contents of ``` fenced code blocks that are untagged or tagged as JSON
+fn fenced_blocks(text: &str) -> Vec<&str> {
+    delimited_blocks(text, "```", "```")
+        .into_iter()
+        .filter_map(|block| {
+            let (tag, body) = match block.find('\n') {
+                Some(newline) => (block[..newline].trim(), block[newline + 1..].trim()),
+                None => ("", block),
+            };
+            if tag.starts_with(['{', '[']) {
+                // No tag line; the JSON starts on the fence line
+                return Some(block);
+            }
+            matches!(tag, "" | "json" | "tool_call").then_some(body)
+        })
+        .collect()
+}
+
+/// Interpret a parsed JSON value as one or more tool calls
+fn collect_tool_calls(value: Value, calls: &mut Vec<ToolCall>, errors: &mut Vec<ToolError>) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                collect_tool_calls(item, calls, errors);
+            }
+        }
+        Value::Object(mut obj) => {
+            if let Some(nested) = obj.remove("tool_calls") {
+                return collect_tool_calls(nested, calls, errors);
+            }
+
+            let id = obj.get("id").and_then(Value::as_str).map(str::to_string);
+            // OpenAI nests the name and arguments under "function"
+            let body = match obj.remove("function") {
+                Some(Value::Object(function)) => function,
+                _ => obj,
+            };
+
+            let name = match body.get("name").and_then(Value::as_str) {
+                Some(name) if !name.is_empty() => name.to_string(),
+                _ => {
+                    errors.push(ToolError::MalformedCall("missing tool name".to_string()));
+                    return;
+                }
+            };
+
+            let raw_args = ["arguments", "args", "parameters"]
+                .iter()
+                .find_map(|key| body.get(*key).cloned())
+                .unwrap_or_else(|| Value::Object(Default::default()));
+
+            let args = match raw_args {
+                Value::String(encoded) => match serde_json::from_str(&encoded) {
+                    Ok(args) => args,
+                    Err(e) => {
+                        errors.push(ToolError::InvalidArguments {
+                            tool: name,
+                            error: e.to_string(),
+                        });
+                        return;
+                    }
+                },
+                args => args,
+            };
+
+            let id = id.unwrap_or_else(|| format!("call_{}", uuid::Uuid::new_v4().simple()));
+            calls.push(ToolCall::new(id, name, args));
+        }
+        other => errors.push(ToolError::MalformedCall(format!(
+            "expected a JSON object, found {}",
+            other
+        ))),
+    }
+}
+
 /// Tool call result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCallResult {
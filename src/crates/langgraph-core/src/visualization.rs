@@ -36,6 +36,7 @@
 //! │  • include_details: show reads/writes                      │
 //! │  • title: optional graph label                             │
 //! │  • show_subgraphs: highlight nested graphs                 │
+//! │  • show_conditions: label conditional branches             │
 //! └─────────────┬──────────────────────────────────────────────┘
 //!               │
 //!               ↓ Format-specific rendering
@@ -153,7 +154,7 @@
 //!
 //! // Mermaid will show:
 //! // - Conditional nodes as diamonds (yellow)
-//! // - Branches as dashed lines with labels
+//! // - Branches labeled with their keys: check -->|positive| positive
 //! // - Node details (which channels they read/write)
 //! ```
 //!
@@ -252,12 +253,15 @@
 //! Graph Structure:
 //! ================
 //!
-//! START (process) ->
-//!
-//! [process]
-//!   -> __end__
-//!
-//! END
+//!  ( START )
+//!      │
+//!      ▼
+//! ┌─────────┐
+//! │ process │
+//! └─────────┘
+//!      │
+//!      ▼
+//!   ( END )
 //! ```
 //!
 //! # Visualization Options
@@ -279,6 +283,19 @@
 //! process["process\nreads: [input]\nwrites: [output]"]
 //! ```
 //!
+//! ## Conditional Edge Labels
+//!
+//! Conditional branches are labeled with their branch keys by default
+//! (`-->|key|` in Mermaid, `[label="key"]` in DOT). Turn labels off for a
+//! more compact diagram:
+//!
+//! ```rust,ignore
+//! let compact = visualize(
+//!     &compiled.graph,
+//!     &VisualizationOptions::mermaid().without_conditions()
+//! );
+//! ```
+//!
 //! ## Highlighting Subgraphs
 //!
 //! Subgraph nodes are automatically styled differently:
@@ -353,7 +370,8 @@
 //! - [Mermaid](https://mermaid.js.org/) - Interactive diagram tool
 //! - [GitHub Mermaid support](https://github.blog/2022-02-14-include-diagrams-markdown-files-mermaid/) - Native rendering
 
-use crate::graph::{Edge, Graph, END, START};
+use crate::graph::{Edge, Graph, NodeId, NodeSpec, END, START};
+use std::collections::HashMap;
 
 /// Graph visualization format
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -377,6 +395,8 @@ pub struct VisualizationOptions {
     pub title: Option<String>,
     /// Whether to show subgraphs
     pub show_subgraphs: bool,
+    /// Whether to label conditional edges with their branch keys
+    pub show_conditions: bool,
 }
 
 impl Default for VisualizationOptions {
@@ -386,6 +406,7 @@ impl Default for VisualizationOptions {
             include_details: false,
             title: None,
             show_subgraphs: true,
+            show_conditions: true,
         }
     }
 }
@@ -432,6 +453,12 @@ impl VisualizationOptions {
         self.show_subgraphs = true;
         self
     }
+
+    /// Omit branch labels on conditional edges
+    pub fn without_conditions(mut self) -> Self {
+        self.show_conditions = false;
+        self
+    }
 }
 
 /// Visualize a graph as a string
@@ -462,7 +489,7 @@ fn visualize_dot(graph: &Graph, options: &VisualizationOptions) -> String {
     output.push_str(&format!("    \"{}\" [shape=circle, style=filled, fillcolor=red];\n", END));
     
     // Regular nodes
-    for (node_id, node_spec) in &graph.nodes {
+    for (node_id, node_spec) in sorted_nodes(graph) {
         let label = if options.include_details {
             format!("{}\\nreads: {:?}\\nwrites: {:?}", 
                 node_spec.name,
@@ -485,7 +512,7 @@ fn visualize_dot(graph: &Graph, options: &VisualizationOptions) -> String {
     }
     
     // Edges
-    for (from, edges) in &graph.edges {
+    for (from, edges) in sorted_edges(graph) {
         for edge in edges {
             match edge {
                 Edge::Direct(to) => {
@@ -494,11 +521,17 @@ fn visualize_dot(graph: &Graph, options: &VisualizationOptions) -> String {
                         escape_dot(to)));
                 }
                 Edge::Conditional { branches, .. } => {
-                    for (label, to) in branches {
-                        output.push_str(&format!("    \"{}\" -> \"{}\" [label=\"{}\", style=dashed];\n",
-                            escape_dot(from),
-                            escape_dot(to),
-                            escape_dot(label)));
+                    for (label, to) in sorted_branches(branches) {
+                        if options.show_conditions {
+                            output.push_str(&format!("    \"{}\" -> \"{}\" [label=\"{}\", style=dashed];\n",
+                                escape_dot(from),
+                                escape_dot(to),
+                                escape_dot(label)));
+                        } else {
+                            output.push_str(&format!("    \"{}\" -> \"{}\" [style=dashed];\n",
+                                escape_dot(from),
+                                escape_dot(to)));
+                        }
                     }
                 }
             }
@@ -540,7 +573,7 @@ fn visualize_mermaid(graph: &Graph, options: &VisualizationOptions) -> String {
     }
 
     // Regular nodes with type-based styling
-    for (node_id, node_spec) in &graph.nodes {
+    for (node_id, node_spec) in sorted_nodes(graph) {
         let label = if options.include_details {
             format!("{}\\nreads: {:?}\\nwrites: {:?}",
                 node_spec.name,
@@ -586,7 +619,7 @@ fn visualize_mermaid(graph: &Graph, options: &VisualizationOptions) -> String {
     }
 
     // Edges with enhanced styling
-    for (from, edges) in &graph.edges {
+    for (from, edges) in sorted_edges(graph) {
         for edge in edges {
             match edge {
                 Edge::Direct(to) => {
@@ -595,12 +628,18 @@ fn visualize_mermaid(graph: &Graph, options: &VisualizationOptions) -> String {
                         sanitize_id(to)));
                 }
                 Edge::Conditional { branches, .. } => {
-                    // Conditional edges with dashed lines and labels
-                    for (label, to) in branches {
-                        output.push_str(&format!("    {} -.\"{}\"..-> {}\n",
-                            sanitize_id(from),
-                            escape_mermaid(label),
-                            sanitize_id(to)));
+                    // Conditional edges labeled with their branch keys
+                    for (label, to) in sorted_branches(branches) {
+                        if options.show_conditions {
+                            output.push_str(&format!("    {} -->|{}| {}\n",
+                                sanitize_id(from),
+                                escape_mermaid_label(label),
+                                sanitize_id(to)));
+                        } else {
+                            output.push_str(&format!("    {} --> {}\n",
+                                sanitize_id(from),
+                                sanitize_id(to)));
+                        }
                     }
                 }
             }
@@ -629,42 +668,219 @@ fn visualize_mermaid(graph: &Graph, options: &VisualizationOptions) -> String {
     output
 }
 
-/// Generate simple ASCII art visualization
-fn visualize_ascii(graph: &Graph, _options: &VisualizationOptions) -> String {
-    let mut output = String::new();
-    
-    output.push_str("Graph Structure:\n");
-    output.push_str("================\n\n");
-    
-    // Entry point
-    output.push_str(&format!("START ({}) ->\n", graph.entry));
-    
-    // Nodes and their connections
-    for (node_id, node_spec) in &graph.nodes {
-        output.push_str(&format!("\n[{}]", node_spec.name));
-        if node_spec.subgraph.is_some() {
-            output.push_str(" (subgraph)");
+/// A single element in an ASCII layer: a node box or a START/END marker
+struct AsciiCell {
+    rows: Vec<String>,
+    width: usize,
+}
+
+impl AsciiCell {
+    fn marker(label: &str) -> Self {
+        let row = format!("( {} )", label);
+        Self { width: row.chars().count(), rows: vec![row] }
+    }
+
+    fn node(label: &str, subgraph: bool) -> Self {
+        let (tl, tr, bl, br, h, v) = if subgraph {
+            ('╔', '╗', '╚', '╝', '═', '║')
+        } else {
+            ('┌', '┐', '└', '┘', '─', '│')
+        };
+        let bar: String = std::iter::repeat(h).take(label.chars().count() + 2).collect();
+        let rows = vec![
+            format!("{}{}{}", tl, bar, tr),
+            format!("{} {} {}", v, label, v),
+            format!("{}{}{}", bl, bar, br),
+        ];
+        Self { width: rows[0].chars().count(), rows }
+    }
+}
+
+/// Assign nodes to layers by shortest distance from START.
+///
+/// START is always the first layer and END the last; nodes not reachable from
+/// START get their own layer just before END. Every layer is sorted by node id,
+/// so the same graph always produces the same layout.
+fn ascii_layers(graph: &Graph) -> Vec<Vec<NodeId>> {
+    let mut depth: HashMap<NodeId, usize> = HashMap::new();
+    let mut queue = std::collections::VecDeque::new();
+    depth.insert(START.to_string(), 0);
+    queue.push_back(START.to_string());
+
+    while let Some(node) = queue.pop_front() {
+        let level = depth[&node];
+        for target in ascii_targets(graph, &node) {
+            if target == END || depth.contains_key(&target) {
+                continue;
+            }
+            depth.insert(target.clone(), level + 1);
+            queue.push_back(target);
         }
-        output.push_str("\n");
-        
-        if let Some(edges) = graph.edges.get(node_id) {
+    }
+
+    let deepest = depth.values().copied().max().unwrap_or(0);
+    let mut layers: Vec<Vec<NodeId>> = vec![Vec::new(); deepest + 1];
+    for (node, level) in &depth {
+        layers[*level].push(node.clone());
+    }
+
+    let mut unreachable: Vec<NodeId> = graph
+        .nodes
+        .keys()
+        .filter(|id| !depth.contains_key(*id))
+        .cloned()
+        .collect();
+    if !unreachable.is_empty() {
+        layers.push(std::mem::take(&mut unreachable));
+    }
+    layers.push(vec![END.to_string()]);
+
+    for layer in &mut layers {
+        layer.sort();
+    }
+    layers
+}
+
+/// Direct and conditional targets of a node, in edge order
+fn ascii_targets(graph: &Graph, node: &str) -> Vec<NodeId> {
+    let mut targets = Vec::new();
+    match graph.edges.get(node) {
+        Some(edges) => {
             for edge in edges {
                 match edge {
-                    Edge::Direct(to) => {
-                        output.push_str(&format!("  -> {}\n", to));
-                    }
+                    Edge::Direct(to) => targets.push(to.clone()),
                     Edge::Conditional { branches, .. } => {
-                        output.push_str("  -> (conditional)\n");
-                        for (label, to) in branches {
-                            output.push_str(&format!("     [{}] -> {}\n", label, to));
+                        targets.extend(sorted_branches(branches).into_iter().map(|(_, to)| to.clone()));
+                    }
+                }
+            }
+        }
+        // Graphs built with an entry point but no explicit START edge
+        None if node == START && !graph.entry.is_empty() => targets.push(graph.entry.clone()),
+        None => {}
+    }
+    targets
+}
+
+/// Display name for a node in ASCII output
+fn ascii_name(node: &str) -> String {
+    match node {
+        START => "START".to_string(),
+        END => "END".to_string(),
+        _ => node.to_string(),
+    }
+}
+
+/// Generate a top-down layered ASCII visualization.
+///
+/// Nodes are drawn as boxes (double-lined for subgraphs) grouped into layers by
+/// distance from START. A single direct edge between consecutive single-node
+/// layers is drawn as a vertical arrow; all other edges are listed below their
+/// source layer, solid (`──▶`) for direct edges and dashed (`╌╌▶`) for
+/// conditional ones.
+fn visualize_ascii(graph: &Graph, options: &VisualizationOptions) -> String {
+    let mut output = String::new();
+
+    let header = options.title.clone().unwrap_or_else(|| "Graph Structure:".to_string());
+    output.push_str(&header);
+    output.push('\n');
+    output.push_str(&"=".repeat(header.chars().count()));
+    output.push_str("\n\n");
+
+    let layers = ascii_layers(graph);
+    let cells: Vec<Vec<AsciiCell>> = layers
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .map(|id| match graph.nodes.get(id) {
+                    Some(spec) => AsciiCell::node(&spec.name, spec.subgraph.is_some() && options.show_subgraphs),
+                    None => AsciiCell::marker(&ascii_name(id)),
+                })
+                .collect()
+        })
+        .collect();
+
+    let layer_width = |row: &[AsciiCell]| -> usize {
+        row.iter().map(|c| c.width).sum::<usize>() + 2 * row.len().saturating_sub(1)
+    };
+    let total_width = cells.iter().map(|row| layer_width(row)).max().unwrap_or(0);
+    let center = total_width / 2;
+
+    for (index, row) in cells.iter().enumerate() {
+        // Draw the layer, centered
+        let pad = " ".repeat((total_width - layer_width(row)) / 2);
+        let height = row.iter().map(|c| c.rows.len()).max().unwrap_or(0);
+        for line in 0..height {
+            let parts: Vec<String> = row
+                .iter()
+                .map(|cell| {
+                    cell.rows
+                        .get(line)
+                        .cloned()
+                        .unwrap_or_else(|| " ".repeat(cell.width))
+                })
+                .collect();
+            output.push_str(format!("{}{}", pad, parts.join("  ")).trim_end());
+            output.push('\n');
+        }
+
+        let Some(next) = layers.get(index + 1) else {
+            break;
+        };
+
+        // Connect to the next layer
+        let layer = &layers[index];
+        let edges: Vec<(&NodeId, String, Option<&String>, bool)> = layer
+            .iter()
+            .flat_map(|from| {
+                let mut lines = Vec::new();
+                match graph.edges.get(from) {
+                    Some(edges) => {
+                        for edge in edges {
+                            match edge {
+                                Edge::Direct(to) => lines.push((from, to.clone(), None, false)),
+                                Edge::Conditional { branches, .. } => {
+                                    for (label, to) in sorted_branches(branches) {
+                                        lines.push((from, to.clone(), Some(label), true));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        for to in ascii_targets(graph, from) {
+                            lines.push((from, to, None, false));
                         }
                     }
                 }
+                lines
+            })
+            .collect();
+
+        let straight = layer.len() == 1
+            && next.len() == 1
+            && edges.len() == 1
+            && !edges[0].3
+            && edges[0].1 == next[0];
+
+        if straight {
+            output.push_str(&format!("{}│\n", " ".repeat(center)));
+            output.push_str(&format!("{}▼\n", " ".repeat(center)));
+        } else {
+            output.push('\n');
+            for (from, to, label, conditional) in edges {
+                let arrow = match (conditional, label) {
+                    (true, Some(label)) if options.show_conditions => format!("╌╌[{}]╌╌▶", label),
+                    (true, _) => "╌╌▶".to_string(),
+                    (false, _) => "──▶".to_string(),
+                };
+                output.push_str(&format!("  {} {} {}\n", ascii_name(from), arrow, ascii_name(&to)));
             }
+            output.push('\n');
         }
     }
-    
-    output.push_str("\nEND\n");
+
     output
 }
 
@@ -682,6 +898,32 @@ fn escape_mermaid(s: &str) -> String {
         .replace('>', "&gt;")
 }
 
+/// Escape an edge label for Mermaid's `-->|label|` syntax
+fn escape_mermaid_label(s: &str) -> String {
+    escape_mermaid(s).replace('|', "&#124;")
+}
+
+/// Nodes ordered by id so output is stable across runs
+fn sorted_nodes(graph: &Graph) -> Vec<(&String, &NodeSpec)> {
+    let mut nodes: Vec<_> = graph.nodes.iter().collect();
+    nodes.sort_by(|a, b| a.0.cmp(b.0));
+    nodes
+}
+
+/// Edge lists ordered by source node id
+fn sorted_edges(graph: &Graph) -> Vec<(&String, &Vec<Edge>)> {
+    let mut edges: Vec<_> = graph.edges.iter().collect();
+    edges.sort_by(|a, b| a.0.cmp(b.0));
+    edges
+}
+
+/// Conditional branches ordered by branch key
+fn sorted_branches(branches: &HashMap<String, NodeId>) -> Vec<(&String, &NodeId)> {
+    let mut branches: Vec<_> = branches.iter().collect();
+    branches.sort_by(|a, b| a.0.cmp(b.0));
+    branches
+}
+
 /// Sanitize node IDs for Mermaid (must be alphanumeric + underscore)
 fn sanitize_id(s: &str) -> String {
     s.replace("__", "")
@@ -822,12 +1064,9 @@ mod tests {
         assert!(mermaid.contains("router{"));
         assert!(mermaid.contains("#FFE4B5")); // Light yellow fill for conditional
 
-        // Should have dashed lines for conditional edges
-        assert!(mermaid.contains("-.\""));
-        assert!(mermaid.contains("\"..->"));
-
-        // Should have branch labels
-        assert!(mermaid.contains("\"a\"") || mermaid.contains("\"b\""));
+        // Should have labeled conditional edges
+        assert!(mermaid.contains("router -->|a| path_a"));
+        assert!(mermaid.contains("router -->|b| path_b"));
 
         // Should have legend for conditional nodes
         assert!(mermaid.contains("Legend") || mermaid.contains("legend_conditional"));
@@ -893,4 +1132,137 @@ mod tests {
         // Should have title
         assert!(mermaid.contains("My Test Graph"));
     }
+
+    /// The conditional-routing example from the crate docs
+    fn build_conditional_routing_example() -> StateGraph {
+        use crate::send::ConditionalEdgeResult;
+
+        let mut builder = StateGraph::new();
+        builder.add_node("check", |state| Box::pin(async move { Ok(state) }));
+        builder.add_node("positive", |state| Box::pin(async move { Ok(state) }));
+        builder.add_node("negative", |state| Box::pin(async move { Ok(state) }));
+
+        let mut branches = HashMap::new();
+        branches.insert("positive".to_string(), "positive".to_string());
+        branches.insert("negative".to_string(), "negative".to_string());
+        builder.add_conditional_edge(
+            "check",
+            |state| {
+                if state.get("value").and_then(|v| v.as_i64()).unwrap_or(0) > 0 {
+                    ConditionalEdgeResult::Node("positive".to_string())
+                } else {
+                    ConditionalEdgeResult::Node("negative".to_string())
+                }
+            },
+            branches,
+        );
+
+        builder.add_edge("__start__", "check");
+        builder.add_edge("positive", "__end__");
+        builder.add_edge("negative", "__end__");
+        builder
+    }
+
+    #[test]
+    fn test_mermaid_conditional_labels_snapshot() {
+        let compiled = build_conditional_routing_example().compile().unwrap();
+        let mermaid = visualize(&compiled.graph, &VisualizationOptions::mermaid());
+
+        let expected = "\
+%%{init: {'theme':'base', 'themeVariables': {'primaryColor':'#f4f4f4','primaryTextColor':'#333','primaryBorderColor':'#7C7C7C','lineColor':'#7C7C7C','secondaryColor':'#e8e8e8','tertiaryColor':'#fff'}}}%%
+graph TD
+    start((START))
+    style start fill:#90EE90,stroke:#228B22,stroke-width:3px
+    end((END))
+    style end fill:#FFB6C1,stroke:#DC143C,stroke-width:3px
+    check{\"check\"}
+    style check fill:#FFE4B5,stroke:#FF8C00,stroke-width:2px
+    negative[\"negative\"]
+    style negative fill:#F0F0F0,stroke:#666,stroke-width:2px
+    positive[\"positive\"]
+    style positive fill:#F0F0F0,stroke:#666,stroke-width:2px
+    start --> check
+    check -->|negative| negative
+    check -->|positive| positive
+    negative --> end
+    positive --> end
+
+    %% Legend
+";
+        assert_eq!(mermaid, expected);
+    }
+
+    #[test]
+    fn test_conditional_labels_can_be_hidden() {
+        let compiled = build_conditional_routing_example().compile().unwrap();
+
+        let mermaid = visualize(
+            &compiled.graph,
+            &VisualizationOptions::mermaid().without_conditions(),
+        );
+        assert!(mermaid.contains("check --> positive"));
+        assert!(!mermaid.contains("-->|"));
+
+        let dot = visualize(&compiled.graph, &VisualizationOptions::dot());
+        assert!(dot.contains("\"check\" -> \"positive\" [label=\"positive\", style=dashed];"));
+
+        let dot = visualize(&compiled.graph, &VisualizationOptions::dot().without_conditions());
+        assert!(dot.contains("\"check\" -> \"positive\" [style=dashed];"));
+        assert!(!dot.contains("\"check\" -> \"positive\" [label="));
+    }
+
+    #[test]
+    fn test_ascii_linear_graph_layout() {
+        let mut builder = StateGraph::new();
+        for name in ["step1", "step2", "step3"] {
+            builder.add_node(name, |state| Box::pin(async move { Ok(state) }));
+        }
+        builder.add_edge("__start__", "step1");
+        builder.add_edge("step1", "step2");
+        builder.add_edge("step2", "step3");
+        builder.add_edge("step3", "__end__");
+
+        let compiled = builder.compile().unwrap();
+        let ascii = visualize(&compiled.graph, &VisualizationOptions::ascii());
+
+        let expected = "\
+Graph Structure:
+================
+
+( START )
+    │
+    ▼
+┌───────┐
+│ step1 │
+└───────┘
+    │
+    ▼
+┌───────┐
+│ step2 │
+└───────┘
+    │
+    ▼
+┌───────┐
+│ step3 │
+└───────┘
+    │
+    ▼
+ ( END )
+";
+        assert_eq!(ascii, expected);
+
+        // Layout is deterministic across renders
+        assert_eq!(ascii, visualize(&compiled.graph, &VisualizationOptions::ascii()));
+    }
+
+    #[test]
+    fn test_ascii_conditional_edges_dashed() {
+        let compiled = build_conditional_routing_example().compile().unwrap();
+        let ascii = visualize(&compiled.graph, &VisualizationOptions::ascii());
+
+        assert!(ascii.contains("┌──────────┐  ┌──────────┐"));
+        assert!(ascii.contains("│ negative │  │ positive │"));
+        assert!(ascii.contains("  check ╌╌[negative]╌╌▶ negative\n  check ╌╌[positive]╌╌▶ positive\n"));
+        assert!(ascii.contains("  negative ──▶ END\n  positive ──▶ END\n"));
+    }
 }
@@ -1,10 +1,15 @@
 //! YAML-based graph definitions
 
-use crate::error::Result;
-use crate::graph::{ChannelType, NodeId};
+use crate::builder::StateGraph;
+use crate::compiled::CompiledGraph;
+use crate::error::{GraphError, Result};
+use crate::graph::{ChannelType, NodeExecutor, NodeId};
+use crate::send::ConditionalEdgeResult;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
 /// Top-level YAML graph definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +25,13 @@ pub struct YamlGraphDef {
     #[serde(default)]
     pub channels: HashMap<String, YamlChannelDef>,
 
+    /// Declared state schema
+    ///
+    /// When present, every channel read or written by a node or read by an
+    /// edge must be declared here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<HashMap<String, YamlChannelDef>>,
+
     /// Node definitions
     pub nodes: HashMap<String, YamlNodeDef>,
 
@@ -66,9 +78,20 @@ pub struct YamlNodeDef {
 }
 
 /// Edge definition in YAML
+///
+/// Variants are matched in declaration order, so `FanOut` comes before
+/// `Direct` (both have `from` and `to`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum YamlEdgeDef {
+    /// Fan-out edge: each element of the `map_over` array in the output of
+    /// `from` is dispatched to `to` as its own `Send` task
+    FanOut {
+        from: NodeId,
+        map_over: String,
+        to: NodeId,
+    },
+
     /// Direct edge
     Direct {
         from: NodeId,
@@ -80,9 +103,72 @@ pub enum YamlEdgeDef {
         from: NodeId,
         condition: String,
         branches: HashMap<String, NodeId>,
+        /// Channels the condition reads from
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        reads: Vec<String>,
     },
 }
 
+/// Router function for a YAML conditional edge
+pub type YamlRouter = Arc<dyn Fn(&Value) -> ConditionalEdgeResult + Send + Sync>;
+
+/// Node handlers and conditions resolved by name when building a YAML graph
+#[derive(Clone, Default)]
+pub struct YamlHandlers {
+    nodes: HashMap<String, NodeExecutor>,
+    conditions: HashMap<String, YamlRouter>,
+}
+
+impl YamlHandlers {
+    /// Create an empty set of handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a node handler under the name used in `handler:`
+    pub fn with_node(mut self, name: impl Into<String>, executor: NodeExecutor) -> Self {
+        self.nodes.insert(name.into(), executor);
+        self
+    }
+
+    /// Register a router under the name used in `condition:`
+    pub fn with_condition(mut self, name: impl Into<String>, router: YamlRouter) -> Self {
+        self.conditions.insert(name.into(), router);
+        self
+    }
+
+    /// Add every handler and condition from `other`, replacing any with the
+    /// same name
+    pub fn merge(mut self, other: YamlHandlers) -> Self {
+        self.nodes.extend(other.nodes);
+        self.conditions.extend(other.conditions);
+        self
+    }
+
+    /// Names of the registered node handlers, sorted
+    pub fn node_names(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self.nodes.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Names of the registered conditions, sorted
+    pub fn condition_names(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self.conditions.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl std::fmt::Debug for YamlHandlers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlHandlers")
+            .field("nodes", &self.node_names())
+            .field("conditions", &self.condition_names())
+            .finish()
+    }
+}
+
 /// Checkpoint configuration in YAML
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YamlCheckpointDef {
@@ -176,9 +262,33 @@ impl YamlGraphDef {
                         }
                     }
                 }
+                YamlEdgeDef::FanOut { from, map_over, to } => {
+                    if !self.nodes.contains_key(from) && from != "__start__" {
+                        return Err(crate::error::GraphError::Validation(format!(
+                            "Edge source '{}' does not exist",
+                            from
+                        )));
+                    }
+                    if !self.nodes.contains_key(to) {
+                        return Err(crate::error::GraphError::Validation(format!(
+                            "Fan-out target '{}' does not exist",
+                            to
+                        )));
+                    }
+                    if map_over.is_empty() {
+                        return Err(crate::error::GraphError::Validation(format!(
+                            "Fan-out edge from '{}' has an empty map_over key",
+                            from
+                        )));
+                    }
+                }
             }
         }
 
+        if let Some(state) = &self.state {
+            self.validate_state(state)?;
+        }
+
         // Check node names are unique
         let mut names = std::collections::HashSet::new();
         for name in self.nodes.keys() {
@@ -193,6 +303,184 @@ impl YamlGraphDef {
         Ok(())
     }
 
+    /// Check that everything referencing a channel uses a declared one
+    fn validate_state(&self, state: &HashMap<String, YamlChannelDef>) -> Result<()> {
+        let mut channels: Vec<_> = state.iter().collect();
+        channels.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, channel) in &channels {
+            if channel.channel_type == ChannelType::BinaryOp && channel.reducer.is_none() {
+                return Err(GraphError::Validation(format!(
+                    "State channel '{}' of type binary_op requires a reducer",
+                    name
+                )));
+            }
+        }
+
+        let undeclared = |channel: &str, referrer: String| {
+            let mut declared: Vec<_> = state.keys().map(String::as_str).collect();
+            declared.sort_unstable();
+            GraphError::Validation(format!(
+                "{} references undeclared state channel '{}' (declared: {})",
+                referrer,
+                channel,
+                declared.join(", ")
+            ))
+        };
+
+        let mut nodes: Vec<_> = self.nodes.iter().collect();
+        nodes.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, node) in nodes {
+            for channel in &node.reads {
+                if !state.contains_key(channel) {
+                    return Err(undeclared(channel, format!("Node '{}' (reads)", name)));
+                }
+            }
+            for channel in &node.writes {
+                if !state.contains_key(channel) {
+                    return Err(undeclared(channel, format!("Node '{}' (writes)", name)));
+                }
+            }
+        }
+
+        for edge in &self.edges {
+            match edge {
+                YamlEdgeDef::Conditional {
+                    from,
+                    condition,
+                    reads,
+                    ..
+                } => {
+                    for channel in reads {
+                        if !state.contains_key(channel) {
+                            return Err(undeclared(
+                                channel,
+                                format!("Condition '{}' on edge from '{}'", condition, from),
+                            ));
+                        }
+                    }
+                }
+                YamlEdgeDef::FanOut { from, map_over, .. } => {
+                    if !state.contains_key(map_over) {
+                        return Err(undeclared(map_over, format!("Fan-out edge from '{}'", from)));
+                    }
+                }
+                YamlEdgeDef::Direct { .. } => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build a [`StateGraph`] from the definition
+    ///
+    /// Node handlers and conditions are looked up by name in `handlers`.
+    /// Fan-out edges become conditional edges that dispatch one `Send` per
+    /// element of the `map_over` array in the source node's output.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the definition is invalid or a handler
+    /// or condition is not registered; the error lists the registered names
+    pub fn build(&self, handlers: &YamlHandlers) -> Result<StateGraph> {
+        self.validate()?;
+
+        let mut graph = StateGraph::new();
+
+        let mut node_names: Vec<_> = self.nodes.keys().collect();
+        node_names.sort();
+        for name in node_names {
+            let node = &self.nodes[name];
+            let executor = handlers.nodes.get(&node.handler).ok_or_else(|| {
+                GraphError::Validation(format!(
+                    "No handler registered for '{}' (node '{}'). Available handlers: {}",
+                    node.handler,
+                    name,
+                    handlers.node_names().join(", ")
+                ))
+            })?;
+            graph.add_node_with_executor(name.clone(), executor.clone());
+        }
+
+        for edge in &self.edges {
+            match edge {
+                YamlEdgeDef::Direct { from, to } => {
+                    graph.add_edge(from.clone(), to.clone());
+                }
+                YamlEdgeDef::Conditional {
+                    from,
+                    condition,
+                    branches,
+                    ..
+                } => {
+                    let router = handlers.conditions.get(condition).cloned().ok_or_else(|| {
+                        GraphError::Validation(format!(
+                            "No condition registered for '{}' (edge from '{}'). \
+                             Available conditions: {}",
+                            condition,
+                            from,
+                            handlers.condition_names().join(", ")
+                        ))
+                    })?;
+                    let targets = branches.clone();
+                    graph.add_conditional_edge(
+                        from.clone(),
+                        move |state| match router(state) {
+                            ConditionalEdgeResult::Node(key) => {
+                                let target = targets.get(&key).cloned().unwrap_or(key);
+                                ConditionalEdgeResult::Node(target)
+                            }
+                            other => other,
+                        },
+                        branches.clone(),
+                    );
+                }
+                YamlEdgeDef::FanOut { from, map_over, to } => {
+                    let key = map_over.clone();
+                    let target = to.clone();
+                    graph.add_conditional_edge(
+                        from.clone(),
+                        move |output| {
+                            let sends = output
+                                .get(&key)
+                                .and_then(Value::as_array)
+                                .map(|items| {
+                                    items
+                                        .iter()
+                                        .map(|item| {
+                                            crate::send::Send::new(target.clone(), item.clone())
+                                        })
+                                        .collect()
+                                })
+                                .unwrap_or_default();
+                            ConditionalEdgeResult::Sends(sends)
+                        },
+                        HashMap::from([(to.clone(), to.clone())]),
+                    );
+                }
+            }
+        }
+
+        // Explicit `__start__` edges already drive execution; otherwise wire
+        // the declared entry point
+        let has_start_edge = self.edges.iter().any(|edge| match edge {
+            YamlEdgeDef::Direct { from, .. }
+            | YamlEdgeDef::Conditional { from, .. }
+            | YamlEdgeDef::FanOut { from, .. } => from == "__start__",
+        });
+        if !has_start_edge && self.entry != "__start__" {
+            graph.set_entry(self.entry.clone());
+        }
+
+        Ok(graph)
+    }
+
+    /// Build and compile the definition into an executable graph
+    ///
+    /// See [`build`](Self::build) for how handlers are resolved.
+    pub fn compile(&self, handlers: &YamlHandlers) -> Result<CompiledGraph> {
+        self.build(handlers)?.compile()
+    }
+
     /// Convert to a YAML string
     pub fn to_yaml(&self) -> Result<String> {
         Ok(serde_yaml::to_string(self)?)
@@ -320,4 +608,172 @@ edges:
         assert_eq!(graph.channels["counter"].channel_type, ChannelType::BinaryOp);
         assert_eq!(graph.channels["counter"].reducer, Some("sum".to_string()));
     }
+
+    const FAN_OUT_YAML: &str = r#"
+name: fan_out_graph
+entry: split
+
+nodes:
+  split:
+    handler: "split_handler"
+  process:
+    handler: "process_handler"
+
+edges:
+  - from: "__start__"
+    to: "split"
+  - from: "split"
+    map_over: "items"
+    to: "process"
+  - from: "process"
+    to: "__end__"
+"#;
+
+    fn identity() -> NodeExecutor {
+        Arc::new(|state| Box::pin(async move { Ok(state) }))
+    }
+
+    #[test]
+    fn test_parse_and_validate_fan_out() {
+        let graph = YamlGraphDef::from_str(FAN_OUT_YAML).unwrap();
+        assert!(graph.validate().is_ok());
+
+        match &graph.edges[1] {
+            YamlEdgeDef::FanOut { from, map_over, to } => {
+                assert_eq!(from, "split");
+                assert_eq!(map_over, "items");
+                assert_eq!(to, "process");
+            }
+            other => panic!("expected fan-out edge, got {:?}", other),
+        }
+        assert!(matches!(graph.edges[0], YamlEdgeDef::Direct { .. }));
+
+        let invalid = FAN_OUT_YAML.replace(
+            "map_over: \"items\"\n    to: \"process\"",
+            "map_over: \"items\"\n    to: \"missing\"",
+        );
+        let err = YamlGraphDef::from_str(&invalid).unwrap().validate().unwrap_err();
+        assert!(err.to_string().contains("Fan-out target 'missing' does not exist"));
+    }
+
+    #[test]
+    fn test_compile_fan_out_dispatches_sends() {
+        let graph = YamlGraphDef::from_str(FAN_OUT_YAML).unwrap();
+        let handlers = YamlHandlers::new()
+            .with_node("split_handler", identity())
+            .with_node("process_handler", identity());
+
+        let built = graph.build(&handlers).unwrap();
+        let edges = &built.graph().edges["split"];
+        let router = match &edges[0] {
+            crate::graph::Edge::Conditional { router, branches } => {
+                assert_eq!(branches.get("process"), Some(&"process".to_string()));
+                router.clone()
+            }
+            other => panic!("expected conditional edge, got {:?}", other),
+        };
+
+        match router(&serde_json::json!({"items": [1, 2, 3]})) {
+            ConditionalEdgeResult::Sends(sends) => {
+                assert_eq!(sends.len(), 3);
+                assert!(sends.iter().all(|send| send.node() == "process"));
+                assert_eq!(sends[2].arg(), &serde_json::json!(3));
+            }
+            other => panic!("expected sends, got {:?}", other),
+        }
+
+        assert!(graph.compile(&handlers).is_ok());
+    }
+
+    #[test]
+    fn test_compile_fails_for_unregistered_handler() {
+        let graph = YamlGraphDef::from_str(FAN_OUT_YAML).unwrap();
+        let handlers = YamlHandlers::new().with_node("split_handler", identity());
+
+        let err = graph
+            .compile(&handlers)
+            .err()
+            .expect("compiling with a missing handler should fail");
+        assert!(err.to_string().contains(
+            "No handler registered for 'process_handler' (node 'process'). \
+             Available handlers: split_handler"
+        ));
+    }
+
+    #[test]
+    fn test_merge_replaces_handlers_by_name() {
+        let builtins = YamlHandlers::new()
+            .with_node("a", identity())
+            .with_node("b", identity());
+        let user = YamlHandlers::new()
+            .with_node("b", identity())
+            .with_condition(
+                "route",
+                Arc::new(|_: &Value| ConditionalEdgeResult::Node("a".into())),
+            );
+
+        let merged = builtins.merge(user);
+
+        assert_eq!(merged.node_names(), vec!["a", "b"]);
+        assert_eq!(merged.condition_names(), vec!["route"]);
+    }
+
+    const STATE_YAML: &str = r#"
+name: typed_graph
+entry: router
+
+state:
+  messages:
+    type: topic
+  route:
+    type: last_value
+
+nodes:
+  router:
+    handler: "router_handler"
+    reads: [messages]
+    writes: [route]
+  reply:
+    handler: "reply_handler"
+    reads: [messages]
+    writes: [messages]
+
+edges:
+  - from: "__start__"
+    to: "router"
+  - from: "router"
+    condition: "route_condition"
+    reads: [route]
+    branches:
+      reply: "reply"
+      done: "__end__"
+  - from: "reply"
+    to: "__end__"
+"#;
+
+    #[test]
+    fn test_state_declarations_validate() {
+        let graph = YamlGraphDef::from_str(STATE_YAML).unwrap();
+        let state = graph.state.as_ref().unwrap();
+        assert_eq!(state.len(), 2);
+        assert_eq!(state["messages"].channel_type, ChannelType::Topic);
+        assert!(graph.validate().is_ok());
+    }
+
+    #[test]
+    fn test_undeclared_state_channel_is_rejected() {
+        let yaml = STATE_YAML.replace("writes: [route]", "writes: [rout]");
+        let err = YamlGraphDef::from_str(&yaml).unwrap().validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Graph validation failed: Node 'router' (writes) references undeclared state \
+             channel 'rout' (declared: messages, route)"
+        );
+
+        let yaml = STATE_YAML.replace("reads: [route]", "reads: [next]");
+        let err = YamlGraphDef::from_str(&yaml).unwrap().validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Condition 'route_condition' on edge from 'router' references undeclared"));
+    }
 }
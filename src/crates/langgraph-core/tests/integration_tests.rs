@@ -790,6 +790,75 @@ async fn test_state_history() {
     }
 }
 
+/// Test forking a run from a historical checkpoint with edited state
+#[tokio::test]
+async fn test_invoke_from_checkpoint_forks_branch() {
+    use langgraph_core::{StateGraph, CheckpointConfig};
+    use langgraph_checkpoint::{CheckpointSaver, InMemoryCheckpointSaver};
+    use futures::stream::StreamExt;
+    use std::sync::Arc;
+
+    let mut graph = StateGraph::new();
+    for (name, delta) in [("step1", 1), ("step2", 10), ("step3", 100)] {
+        graph.add_node(name, move |mut state| Box::pin(async move {
+            if let Some(obj) = state.as_object_mut() {
+                let val = obj.get("counter").and_then(|v| v.as_i64()).unwrap_or(0);
+                obj.insert("counter".to_string(), json!(val + delta));
+            }
+            Ok(state)
+        }));
+    }
+    graph.add_edge("__start__", "step1");
+    graph.add_edge("step1", "step2");
+    graph.add_edge("step2", "step3");
+    graph.add_edge("step3", "__end__");
+
+    let saver = Arc::new(InMemoryCheckpointSaver::new());
+    let compiled = graph.compile().unwrap().with_checkpointer(saver.clone());
+
+    let config = CheckpointConfig::new().with_thread_id("fork-thread".to_string());
+    let original = compiled.invoke_with_config(json!({"counter": 0}), Some(config.clone())).await.unwrap();
+    assert_eq!(original["counter"], 111);
+
+    let history: Vec<_> = compiled.get_state_history_chronological(&config).await.unwrap()
+        .map(|s| s.unwrap())
+        .collect()
+        .await;
+    let original_final_id = history.last().unwrap().config.checkpoint_id.clone().unwrap();
+
+    // Fork right after step1 ran
+    let fork_point = history.iter()
+        .find(|s| s.values.get("step1").is_some() && s.values.get("step2").is_none())
+        .expect("checkpoint after step1");
+    let fork_id = fork_point.config.checkpoint_id.clone().unwrap();
+
+    let forked = compiled
+        .invoke_from_checkpoint(&fork_id, Some(json!({"counter": 50})), config.clone())
+        .await
+        .unwrap();
+    assert_eq!(forked["counter"], 160);
+
+    // The original branch's final checkpoint is untouched
+    let original_final = saver
+        .get_tuple(&config.clone().with_checkpoint_id(original_final_id.clone()))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(original_final.checkpoint.channel_values["step3"]["counter"], 111);
+
+    // The new branch traces back to the fork point
+    let latest = compiled.get_state(&config).await.unwrap().unwrap();
+    assert_ne!(latest.config.checkpoint_id, Some(original_final_id));
+    let mut lineage = Vec::new();
+    let mut cursor = latest.parent_config.clone();
+    while let Some(parent) = cursor {
+        let snapshot = compiled.get_state(&parent).await.unwrap().unwrap();
+        lineage.push(snapshot.config.checkpoint_id.clone().unwrap());
+        cursor = snapshot.parent_config.clone();
+    }
+    assert!(lineage.contains(&fork_id));
+}
+
 /// Test advanced streaming with token-level output
 #[tokio::test]
 async fn test_token_streaming() {
@@ -130,6 +130,6 @@ pub mod react;
 pub mod plan_execute;
 pub mod reflection;
 
-pub use react::create_react_agent;
-pub use plan_execute::{create_plan_execute_agent, PlanExecuteConfig, PlanExecuteState, PlanStep};
-pub use reflection::{create_reflection_agent, ReflectionConfig, ReflectionState, ReflectionCritique, QualityMetrics};
+pub use react::{create_react_agent, create_react_agent_with_config, ReactAgent, ReactConfig, StopCondition};
+pub use plan_execute::{create_plan_execute_agent, PlanExecuteAgent, PlanExecuteConfig, PlanExecuteState, PlanStep, PlanStepEvent, PlanStepStream};
+pub use reflection::{create_reflection_agent, ReflectionAgent, ReflectionConfig, ReflectionState, ReflectionCritique, ReflectionResult, QualityMetrics};
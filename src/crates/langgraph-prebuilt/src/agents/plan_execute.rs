@@ -213,11 +213,14 @@
 use crate::error::{PrebuiltError, Result};
 use crate::messages::Message;
 use crate::tools::Tool;
+use futures::stream::{Stream, StreamExt};
 use langgraph_core::StateGraph;
 use langgraph_core::compiled::CompiledGraph;
+use langgraph_core::stream::{StreamChunk, StreamEvent, StreamMode};
 use langgraph_core::messages::Message as CoreMessage;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::pin::Pin;
 use std::sync::Arc;
 
 /// Type alias for LLM functions
@@ -273,6 +276,93 @@ pub struct PlanExecuteState {
     pub final_answer: Option<String>,
 }
 
+/// State key the executor uses to hand step events to the stream
+///
+/// Other nodes drop it from their output so each event is emitted once.
+/// [`PlanExecuteAgent::invoke`] strips it from the final state.
+const CUSTOM_EVENT_KEY: &str = "__custom__";
+
+/// Stream event emitted after each plan step completes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanStepEvent {
+    /// Step number (1-indexed)
+    pub step_number: usize,
+
+    /// Description of the completed step
+    pub description: String,
+
+    /// Result produced by the executor
+    pub result: String,
+}
+
+impl PlanStepEvent {
+    /// Value of the `event` field identifying plan step events
+    pub const EVENT: &'static str = "plan_step";
+
+    /// Extract a step event from a streamed chunk, if it is one
+    pub fn from_chunk(chunk: &StreamChunk) -> Option<Self> {
+        match &chunk.event {
+            StreamEvent::Custom { data }
+                if data.get("event").and_then(|e| e.as_str()) == Some(Self::EVENT) =>
+            {
+                serde_json::from_value(data.clone()).ok()
+            }
+            _ => None,
+        }
+    }
+
+    fn to_custom_data(&self) -> Value {
+        json!({
+            "event": Self::EVENT,
+            "step_number": self.step_number,
+            "description": self.description,
+            "result": self.result,
+        })
+    }
+}
+
+/// Stream of [`PlanStepEvent`]s produced by [`PlanExecuteAgent::stream`]
+pub type PlanStepStream = Pin<Box<dyn Stream<Item = PlanStepEvent> + Send>>;
+
+/// A compiled Plan-Execute agent with step-level streaming
+pub struct PlanExecuteAgent {
+    graph: CompiledGraph,
+}
+
+impl PlanExecuteAgent {
+    /// Run the agent to completion and return the final state
+    ///
+    /// Internal channels such as the step event key are removed from the
+    /// returned state.
+    pub async fn invoke(&self, input: Value) -> Result<Value> {
+        let mut state = self.graph.invoke(input).await?;
+        if let Some(state_obj) = state.as_object_mut() {
+            state_obj.remove(CUSTOM_EVENT_KEY);
+        }
+        Ok(state)
+    }
+
+    /// Run the agent, yielding an event as each plan step completes
+    ///
+    /// Uses [`StreamMode::Custom`] on the underlying graph; the stream ends
+    /// when the agent finishes.
+    pub async fn stream(&self, input: Value) -> Result<PlanStepStream> {
+        let chunks = self
+            .graph
+            .stream_chunks_with_modes(input, vec![StreamMode::Custom], None)
+            .await?;
+
+        Ok(Box::pin(chunks.filter_map(|chunk| {
+            futures::future::ready(PlanStepEvent::from_chunk(&chunk))
+        })))
+    }
+
+    /// The underlying compiled graph
+    pub fn graph(&self) -> &CompiledGraph {
+        &self.graph
+    }
+}
+
 /// Configuration for Plan-Execute agent
 pub struct PlanExecuteConfig {
     /// LLM function for planning
@@ -343,6 +433,13 @@ impl PlanExecuteConfig {
     pub fn build(self) -> Result<CompiledGraph> {
         build_plan_execute_graph(self)
     }
+
+    /// Build a [`PlanExecuteAgent`] that can stream per-step progress
+    pub fn build_agent(self) -> Result<PlanExecuteAgent> {
+        Ok(PlanExecuteAgent {
+            graph: build_plan_execute_graph(self)?,
+        })
+    }
 }
 
 /// Build the Plan-Execute agent graph
@@ -362,6 +459,7 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
 
         Box::pin(async move {
             let mut state_obj = state.as_object().cloned().unwrap_or_default();
+            state_obj.remove(CUSTOM_EVENT_KEY);
 
             // Get objective and current plan state
             let objective = state_obj.get("objective")
@@ -440,6 +538,7 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
 
         Box::pin(async move {
             let mut state_obj = state.as_object().cloned().unwrap_or_default();
+            state_obj.remove(CUSTOM_EVENT_KEY);
 
             let mut plan = state_obj.get("plan")
                 .and_then(|v| serde_json::from_value::<Vec<PlanStep>>(v.clone()).ok())
@@ -456,7 +555,7 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
             }
 
             // Execute current step
-            let (step_number, step_result) = {
+            let (step_number, step_description, step_result) = {
                 let step = &mut plan[current_step];
 
                 // Create execution prompt
@@ -482,7 +581,11 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
                 step.result = Some(extract_result_from_response(&exec_response));
 
                 // Extract values we need before dropping the mutable borrow
-                (step.step_number, step.result.clone().unwrap_or_default())
+                (
+                    step.step_number,
+                    step.description.clone(),
+                    step.result.clone().unwrap_or_default(),
+                )
             };
 
             // Update state (now that mutable borrow is dropped)
@@ -502,6 +605,14 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
 
             state_obj.insert("messages".to_string(), Value::Array(messages));
 
+            // Surface step progress to StreamMode::Custom subscribers
+            let event = PlanStepEvent {
+                step_number,
+                description: step_description,
+                result: step_result,
+            };
+            state_obj.insert(CUSTOM_EVENT_KEY.to_string(), event.to_custom_data());
+
             Ok(Value::Object(state_obj))
         })
     });
@@ -511,6 +622,8 @@ fn build_plan_execute_graph(config: PlanExecuteConfig) -> Result<CompiledGraph>
     graph.add_node("evaluator", move |state: Value| {
         Box::pin(async move {
             let mut state_obj = state.as_object().cloned().unwrap_or_default();
+            // Drop the executor's step event so it is not re-emitted
+            state_obj.remove(CUSTOM_EVENT_KEY);
 
             let plan = state_obj.get("plan")
                 .and_then(|v| serde_json::from_value::<Vec<PlanStep>>(v.clone()).ok())
@@ -218,7 +218,7 @@
 //!
 //! | Method | Description | Default |
 //! |--------|-------------|---------|
-//! | `with_max_iterations(n)` | Maximum reasoning-acting cycles | 10 |
+//! | `with_max_iterations(n)` | Maximum reasoning-acting cycles | Unset (recursion limit applies) |
 //! | `with_system_prompt(s)` | System instructions for LLM | None |
 //!
 //! ## Max Iterations
@@ -382,7 +382,7 @@
 use crate::error::{PrebuiltError, Result};
 use crate::messages::Message;
 use crate::tool_node::ToolNode;
-use crate::tools::Tool;
+use crate::tools::{CancellationToken, Tool};
 use langgraph_core::builder::StateGraph;
 use langgraph_core::compiled::CompiledGraph;
 use langgraph_core::error::GraphError;
@@ -392,6 +392,40 @@ use std::sync::Arc;
 /// Type alias for LLM function that takes state and returns AI message
 pub type LlmFunction = Arc<dyn Fn(Value) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Message>> + Send>> + Send + Sync>;
 
+/// Predicate over the conversation that ends the agent loop when it returns `true`
+pub type StopCondition = Arc<dyn Fn(&[Message]) -> bool + Send + Sync>;
+
+/// Loop controls for [`create_react_agent_with_config`]
+#[derive(Clone)]
+pub struct ReactConfig {
+    /// Maximum number of LLM calls per invocation (default: 10)
+    pub max_iterations: usize,
+
+    /// Custom stop logic, checked after every LLM call
+    ///
+    /// When it returns `true` the agent ends normally, even if the last
+    /// message still requests tools.
+    pub stop_when: Option<StopCondition>,
+}
+
+impl Default for ReactConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: 10,
+            stop_when: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for ReactConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReactConfig")
+            .field("max_iterations", &self.max_iterations)
+            .field("stop_when", &self.stop_when.as_ref().map(|_| "<function>"))
+            .finish()
+    }
+}
+
 /// Configuration for React agent
 pub struct ReactAgentConfig {
     /// Function that calls the LLM
@@ -403,8 +437,20 @@ pub struct ReactAgentConfig {
     /// Maximum number of iterations (default: 10)
     max_iterations: usize,
 
+    /// Whether the graph enforces `max_iterations`
+    ///
+    /// Only set once a cap is given explicitly, so a default-built graph is
+    /// bounded by the recursion limit alone.
+    enforce_max_iterations: bool,
+
     /// System prompt to prepend to messages
     system_prompt: Option<String>,
+
+    /// Custom stop condition checked after every LLM call
+    stop_when: Option<StopCondition>,
+
+    /// Shutdown token passed to the agent's tool calls
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl ReactAgentConfig {
@@ -417,13 +463,17 @@ impl ReactAgentConfig {
             llm_function,
             tools,
             max_iterations: 10,
+            enforce_max_iterations: false,
             system_prompt: None,
+            stop_when: None,
+            cancellation_token: None,
         }
     }
 
-    /// Set maximum iterations
+    /// Stop the agent loop after `max` LLM calls
     pub fn with_max_iterations(mut self, max: usize) -> Self {
         self.max_iterations = max;
+        self.enforce_max_iterations = true;
         self
     }
 
@@ -433,10 +483,119 @@ impl ReactAgentConfig {
         self
     }
 
+    /// Set a custom stop condition
+    pub fn with_stop_when(mut self, stop_when: StopCondition) -> Self {
+        self.stop_when = Some(stop_when);
+        self
+    }
+
+    /// Cancel in-flight tool calls when `token` is tripped, e.g. on shutdown
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Apply loop controls from a [`ReactConfig`]
+    pub fn with_config(mut self, config: ReactConfig) -> Self {
+        self.max_iterations = config.max_iterations;
+        self.enforce_max_iterations = true;
+        self.stop_when = config.stop_when;
+        self
+    }
+
     /// Build the compiled React agent graph
+    ///
+    /// With a cap set by [`with_max_iterations`](Self::with_max_iterations)
+    /// or [`with_config`](Self::with_config), the graph stops after that many
+    /// LLM calls and the returned state ends with the unanswered tool calls.
+    /// Use [`build_agent`](Self::build_agent) to get an error in that case
+    /// instead. Without one, only the graph's recursion limit applies.
     pub fn build(self) -> Result<CompiledGraph> {
-        build_react_graph(self)
+        build_react_graph(self).map(|(graph, _)| graph)
+    }
+
+    /// Build a [`ReactAgent`] that reports a hit iteration cap as an error
+    pub fn build_agent(self) -> Result<ReactAgent> {
+        let max_iterations = self.enforce_max_iterations.then_some(self.max_iterations);
+        let stop_when = self.stop_when.clone();
+        let (graph, tool_node) = build_react_graph(self)?;
+        Ok(ReactAgent {
+            graph,
+            tool_node,
+            max_iterations,
+            stop_when,
+        })
+    }
+}
+
+/// A compiled React agent that reports runaway tool loops as errors
+pub struct ReactAgent {
+    graph: CompiledGraph,
+    tool_node: ToolNode,
+    max_iterations: Option<usize>,
+    stop_when: Option<StopCondition>,
+}
+
+impl ReactAgent {
+    /// Cancel the tool calls currently running in any invocation
+    ///
+    /// Cancelled calls are reported to the model as tool errors; later tool
+    /// calls run normally.
+    pub fn interrupt(&self) {
+        self.tool_node.interrupt();
+    }
+
+    /// Run the agent to completion
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PrebuiltError::MaxIterationsReached`] with the partial
+    /// conversation when the iteration cap is hit while the last message still
+    /// requests tools. A run ended by the stop condition is never an error.
+    pub async fn invoke(&self, input: Value) -> Result<Value> {
+        let result = self.graph.invoke(input).await?;
+
+        let messages: Vec<Message> = result
+            .get("messages")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()?
+            .unwrap_or_default();
+
+        let Some(max_iterations) = self.max_iterations else {
+            return Ok(result);
+        };
+        if self.stop_when.as_ref().is_some_and(|f| f(&messages)) {
+            return Ok(result);
+        }
+
+        let unanswered = messages
+            .last()
+            .map(|m| m.is_ai() && m.has_tool_calls())
+            .unwrap_or(false);
+        let iterations = iterations_since_last_human(&messages);
+
+        if unanswered && iterations >= max_iterations {
+            return Err(PrebuiltError::MaxIterationsReached { iterations, messages });
+        }
+
+        Ok(result)
     }
+
+    /// The underlying compiled graph
+    pub fn graph(&self) -> &CompiledGraph {
+        &self.graph
+    }
+}
+
+/// Number of AI turns since the most recent human message
+fn iterations_since_last_human(messages: &[Message]) -> usize {
+    messages
+        .iter()
+        .rev()
+        .take_while(|m| !m.is_human())
+        .filter(|m| m.is_ai())
+        .count()
 }
 
 /// Create a React agent with the given LLM function and tools
@@ -470,12 +629,45 @@ pub fn create_react_agent(
     ReactAgentConfig::new(llm_function, tools)
 }
 
+/// Create a React agent with explicit loop controls
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let agent = create_react_agent_with_config(
+///     llm_fn,
+///     tools,
+///     ReactConfig {
+///         max_iterations: 3,
+///         stop_when: Some(Arc::new(|messages| messages.len() > 20)),
+///     },
+/// )?;
+///
+/// match agent.invoke(input).await {
+///     Err(PrebuiltError::MaxIterationsReached { messages, .. }) => { /* partial run */ }
+///     other => { /* ... */ }
+/// }
+/// ```
+pub fn create_react_agent_with_config(
+    llm_function: LlmFunction,
+    tools: Vec<Box<dyn Tool>>,
+    config: ReactConfig,
+) -> Result<ReactAgent> {
+    ReactAgentConfig::new(llm_function, tools)
+        .with_config(config)
+        .build_agent()
+}
+
 /// Build the React agent graph
-fn build_react_graph(config: ReactAgentConfig) -> Result<CompiledGraph> {
+fn build_react_graph(config: ReactAgentConfig) -> Result<(CompiledGraph, ToolNode)> {
     let mut graph = StateGraph::new();
 
     // Create tool node
-    let tool_node = ToolNode::from_tools(config.tools);
+    let mut tool_node = ToolNode::from_tools(config.tools);
+    if let Some(token) = config.cancellation_token {
+        tool_node = tool_node.with_cancellation_token(token);
+    }
+    let agent_tool_node = tool_node.clone();
 
     // Clone for use in closures
     let llm_fn = config.llm_function.clone();
@@ -547,19 +739,26 @@ fn build_react_graph(config: ReactAgentConfig) -> Result<CompiledGraph> {
     });
 
     // Define conditional routing logic
-    let should_continue = |state: &Value| -> langgraph_core::send::ConditionalEdgeResult {
+    let max_iterations = config.enforce_max_iterations.then_some(config.max_iterations);
+    let stop_when = config.stop_when.clone();
+    let should_continue = move |state: &Value| -> langgraph_core::send::ConditionalEdgeResult {
         use langgraph_core::send::ConditionalEdgeResult;
 
-        // Get the last message
-        if let Some(messages) = state.get("messages").and_then(|m| m.as_array()) {
-            if let Some(last_msg) = messages.last() {
-                // Try to deserialize to Message to check for tool calls
-                if let Ok(msg) = serde_json::from_value::<Message>(last_msg.clone()) {
-                    // If AI message has tool calls, go to tools
-                    if msg.is_ai() && msg.has_tool_calls() {
-                        return ConditionalEdgeResult::Node("tools".to_string());
-                    }
-                }
+        let messages: Vec<Message> = state
+            .get("messages")
+            .cloned()
+            .and_then(|m| serde_json::from_value(m).ok())
+            .unwrap_or_default();
+
+        // Custom stop logic and the iteration cap both end the loop
+        let stop_requested = stop_when.as_ref().map(|f| f(&messages)).unwrap_or(false);
+        let cap_reached = max_iterations
+            .is_some_and(|max| iterations_since_last_human(&messages) >= max);
+
+        // If AI message has tool calls, go to tools
+        if let Some(msg) = messages.last() {
+            if msg.is_ai() && msg.has_tool_calls() && !stop_requested && !cap_reached {
+                return ConditionalEdgeResult::Node("tools".to_string());
             }
         }
 
@@ -581,7 +780,17 @@ fn build_react_graph(config: ReactAgentConfig) -> Result<CompiledGraph> {
     graph.add_edge("tools", "agent");
 
     // Compile the graph
-    graph.compile().map_err(|e| PrebuiltError::ToolExecution(e.to_string()))
+    let compiled = graph
+        .compile()
+        .map_err(|e| PrebuiltError::ToolExecution(e.to_string()))?;
+
+    // Each iteration takes an agent step and a tools step
+    let compiled = match max_iterations {
+        Some(max) => compiled.with_recursion_limit((max * 2 + 1).max(25)),
+        None => compiled,
+    };
+
+    Ok((compiled, agent_tool_node))
 }
 
 #[cfg(test)]
@@ -661,6 +870,158 @@ mod tests {
         assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 2);
     }
 
+    fn always_calls_tool(count: Arc<std::sync::atomic::AtomicUsize>) -> LlmFunction {
+        Arc::new(move |_state| {
+            let count = count.clone();
+            Box::pin(async move {
+                let n = count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let tool_call = ToolCall::new(
+                    format!("call_{}", n),
+                    "test_tool",
+                    serde_json::json!({"input": n}),
+                );
+                Ok(Message::ai("Calling the tool again").with_tool_calls(vec![tool_call]))
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_react_config_terminates_normally() {
+        let call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let count_clone = call_count.clone();
+        let llm_fn: LlmFunction = Arc::new(move |_state| {
+            let count = count_clone.clone();
+            Box::pin(async move {
+                if count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    let tool_call = ToolCall::new("call_1", "test_tool", serde_json::json!({}));
+                    Ok(Message::ai("Using the tool").with_tool_calls(vec![tool_call]))
+                } else {
+                    Ok(Message::ai("Done"))
+                }
+            })
+        });
+
+        let agent = create_react_agent_with_config(
+            llm_fn,
+            vec![Box::new(TestTool)],
+            ReactConfig { max_iterations: 3, ..Default::default() },
+        )
+        .unwrap();
+
+        let result = agent
+            .invoke(serde_json::json!({"messages": vec![Message::human("Go")]}))
+            .await
+            .unwrap();
+
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        assert_eq!(messages.last().unwrap().content, "Done");
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_react_config_max_iterations_reached() {
+        let call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let agent = create_react_agent_with_config(
+            always_calls_tool(call_count.clone()),
+            vec![Box::new(TestTool)],
+            ReactConfig { max_iterations: 3, ..Default::default() },
+        )
+        .unwrap();
+
+        let err = agent
+            .invoke(serde_json::json!({"messages": vec![Message::human("Loop forever")]}))
+            .await
+            .unwrap_err();
+
+        match err {
+            PrebuiltError::MaxIterationsReached { iterations, messages } => {
+                assert_eq!(iterations, 3);
+                // human + 3 AI turns + 2 tool results (the last call is never executed)
+                assert_eq!(messages.len(), 6);
+                assert!(messages.last().unwrap().has_tool_calls());
+            }
+            other => panic!("expected MaxIterationsReached, got {:?}", other),
+        }
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_react_config_stop_when() {
+        let call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        // Stop as soon as one tool result is in the conversation
+        let stop_when: StopCondition = Arc::new(|messages: &[Message]| messages.iter().any(|m| m.is_tool()));
+        let agent = create_react_agent_with_config(
+            always_calls_tool(call_count.clone()),
+            vec![Box::new(TestTool)],
+            ReactConfig { max_iterations: 10, stop_when: Some(stop_when) },
+        )
+        .unwrap();
+
+        let result = agent
+            .invoke(serde_json::json!({"messages": vec![Message::human("Go")]}))
+            .await
+            .unwrap();
+
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        assert_eq!(messages.iter().filter(|m| m.is_tool()).count(), 1);
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_react_config_stop_when_on_last_iteration() {
+        let call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        // Stop on the same LLM call that reaches the cap
+        let stop_when: StopCondition = Arc::new(|messages: &[Message]| messages.iter().filter(|m| m.is_ai()).count() >= 2);
+        let agent = create_react_agent_with_config(
+            always_calls_tool(call_count.clone()),
+            vec![Box::new(TestTool)],
+            ReactConfig { max_iterations: 2, stop_when: Some(stop_when) },
+        )
+        .unwrap();
+
+        let result = agent
+            .invoke(serde_json::json!({"messages": vec![Message::human("Go")]}))
+            .await
+            .unwrap();
+
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        assert!(messages.last().unwrap().has_tool_calls());
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_react_build_without_cap_runs_past_default() {
+        let call_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let count_clone = call_count.clone();
+        let llm_fn: LlmFunction = Arc::new(move |_state| {
+            let count = count_clone.clone();
+            Box::pin(async move {
+                let n = count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                // One past the default cap, and within the default recursion limit
+                if n < 11 {
+                    let tool_call = ToolCall::new(format!("call_{}", n), "test_tool", serde_json::json!({}));
+                    Ok(Message::ai("Using the tool").with_tool_calls(vec![tool_call]))
+                } else {
+                    Ok(Message::ai("Done"))
+                }
+            })
+        });
+
+        let graph = create_react_agent(llm_fn, vec![Box::new(TestTool)]).build().unwrap();
+
+        let result = graph
+            .invoke(serde_json::json!({"messages": vec![Message::human("Go")]}))
+            .await
+            .unwrap();
+
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        assert_eq!(messages.last().unwrap().content, "Done");
+        assert_eq!(call_count.load(std::sync::atomic::Ordering::SeqCst), 12);
+    }
+
     // ============================================================================
     // Phase 8.1: ReAct Agent - Comprehensive Tests
     // ============================================================================
@@ -283,6 +283,66 @@ pub struct QualityMetrics {
     pub threshold_met: bool,
 }
 
+/// Outcome of a reflection run, including the full generate→critique trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReflectionResult {
+    /// Final refined response
+    pub output: String,
+
+    /// Critique recorded for each iteration, in order
+    pub iterations: Vec<ReflectionCritique>,
+
+    /// Quality score of the final critique
+    pub final_score: f64,
+}
+
+impl ReflectionResult {
+    /// Extract the result from a finished Reflection agent state
+    ///
+    /// Returns an error if the state has no final response, e.g. when it was
+    /// taken from an interrupted run.
+    pub fn from_state(state: &Value) -> Result<Self> {
+        let output = state
+            .get("final_response")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| PrebuiltError::InvalidOutput("Reflection state has no final_response".into()))?
+            .to_string();
+
+        let iterations: Vec<ReflectionCritique> = state
+            .get("critique_history")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()?
+            .unwrap_or_default();
+
+        let final_score = iterations.last().map(|c| c.quality_score).unwrap_or(0.0);
+
+        Ok(Self {
+            output,
+            iterations,
+            final_score,
+        })
+    }
+}
+
+/// A compiled Reflection agent that returns a [`ReflectionResult`]
+pub struct ReflectionAgent {
+    graph: CompiledGraph,
+}
+
+impl ReflectionAgent {
+    /// Run the generate→critique loop to completion
+    pub async fn invoke(&self, input: Value) -> Result<ReflectionResult> {
+        let state = self.graph.invoke(input).await?;
+        ReflectionResult::from_state(&state)
+    }
+
+    /// The underlying compiled graph
+    pub fn graph(&self) -> &CompiledGraph {
+        &self.graph
+    }
+}
+
 /// Configuration for Reflection agent
 pub struct ReflectionConfig {
     /// LLM function for generating responses
@@ -363,6 +423,13 @@ impl ReflectionConfig {
     pub fn build(self) -> Result<CompiledGraph> {
         build_reflection_graph(self)
     }
+
+    /// Build a [`ReflectionAgent`] whose `invoke` returns the critique trail
+    pub fn build_agent(self) -> Result<ReflectionAgent> {
+        Ok(ReflectionAgent {
+            graph: build_reflection_graph(self)?,
+        })
+    }
 }
 
 /// Build the Reflection agent graph
@@ -557,12 +624,49 @@ fn build_reflection_graph(config: ReflectionConfig) -> Result<CompiledGraph> {
 }
 
 /// Helper function to parse critique from LLM response
+///
+/// Reads the JSON critique requested in the reflection prompt (optionally
+/// surrounded by prose). Responses without a `quality_score` fall back to a
+/// default critique.
 fn parse_critique_from_response(response: &Message, quality_threshold: f64) -> ReflectionCritique {
-    // Simplified parsing - in production, use proper JSON extraction from the LLM response
-    // This would parse the structured critique from the LLM's JSON output
+    let content = response.content.as_str();
+    let parsed = match (content.find('{'), content.rfind('}')) {
+        (Some(start), Some(end)) if start < end => {
+            serde_json::from_str::<Value>(&content[start..=end]).ok()
+        }
+        _ => None,
+    };
+
+    if let Some(quality_score) = parsed
+        .as_ref()
+        .and_then(|v| v.get("quality_score"))
+        .and_then(|v| v.as_f64())
+    {
+        let parsed = parsed.as_ref().unwrap();
+        let strings = |key: &str| -> Vec<String> {
+            parsed
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let quality_score = quality_score.clamp(0.0, 1.0);
+
+        return ReflectionCritique {
+            quality_score,
+            strengths: strings("strengths"),
+            weaknesses: strings("weaknesses"),
+            suggestions: strings("suggestions"),
+            is_satisfactory: quality_score >= quality_threshold,
+        };
+    }
 
-    // For now, create a mock critique
-    let quality_score = 0.7; // Would be extracted from response
+    let quality_score = 0.7;
 
     ReflectionCritique {
         quality_score,
@@ -738,6 +842,82 @@ mod tests {
 
     // ========== Quality Assessment Tests ==========
 
+    #[test]
+    fn test_parse_critique_from_json() {
+        let response = Message::ai(
+            r#"Here is my critique: {"quality_score": 0.9, "strengths": ["Accurate"], "weaknesses": [], "suggestions": ["Shorten"]}"#,
+        );
+        let critique = parse_critique_from_response(&response, 0.75);
+
+        assert_eq!(critique.quality_score, 0.9);
+        assert!(critique.is_satisfactory);
+        assert_eq!(critique.strengths, vec!["Accurate".to_string()]);
+        assert!(critique.weaknesses.is_empty());
+    }
+
+    /// Reflector that returns the given scores in order, one per call
+    fn scripted_reflector(scores: Vec<f64>) -> LlmFunction {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        Arc::new(move |_state| {
+            let n = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let score = scores[n.min(scores.len() - 1)];
+            Box::pin(async move {
+                Ok(Message::ai(format!(
+                    r#"{{"quality_score": {}, "strengths": [], "weaknesses": ["w{}"], "suggestions": []}}"#,
+                    score, n
+                )))
+            })
+        })
+    }
+
+    fn counting_generator() -> LlmFunction {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        Arc::new(move |_state| {
+            let n = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async move { Ok(Message::ai(format!("draft {}", n + 1))) })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_reflection_result_records_each_iteration() {
+        let agent = create_reflection_agent(
+            counting_generator(),
+            scripted_reflector(vec![0.4, 0.6, 0.9]),
+            vec![],
+        )
+        .with_max_iterations(5)
+        .with_quality_threshold(0.8)
+        .build_agent()
+        .unwrap();
+
+        let result = agent.invoke(json!({"query": "Explain borrowing"})).await.unwrap();
+
+        // Loop ran three times before the threshold was met
+        assert_eq!(result.iterations.len(), 3);
+        let scores: Vec<f64> = result.iterations.iter().map(|c| c.quality_score).collect();
+        assert_eq!(scores, vec![0.4, 0.6, 0.9]);
+        assert_eq!(result.final_score, 0.9);
+        assert_eq!(result.output, "draft 3");
+    }
+
+    #[tokio::test]
+    async fn test_reflection_result_first_pass_satisfactory() {
+        let agent = create_reflection_agent(
+            counting_generator(),
+            scripted_reflector(vec![0.95]),
+            vec![],
+        )
+        .build_agent()
+        .unwrap();
+
+        let result = agent.invoke(json!({"query": "Say hi"})).await.unwrap();
+
+        assert_eq!(result.iterations.len(), 1);
+        assert!(result.iterations[0].is_satisfactory);
+        assert_eq!(result.final_score, 0.95);
+        assert_eq!(result.output, "draft 1");
+    }
+
     #[test]
     fn test_parse_critique_default() {
         let response = Message::ai("This response needs improvement");
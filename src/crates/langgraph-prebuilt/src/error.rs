@@ -50,6 +50,10 @@ pub enum PrebuiltError {
     #[error("Invalid tool output: {0}")]
     InvalidOutput(String),
 
+    /// Tool execution was cancelled before it finished
+    #[error("Tool '{0}' was cancelled")]
+    Cancelled(String),
+
     /// Message parsing error
     #[error("Message parsing failed: {0}")]
     MessageParsing(String),
@@ -62,6 +66,15 @@ pub enum PrebuiltError {
     #[error("Graph error: {0}")]
     Graph(#[from] langgraph_core::GraphError),
 
+    /// Agent loop hit its iteration cap without producing a final answer
+    #[error("Agent stopped after {iterations} iterations without a final answer")]
+    MaxIterationsReached {
+        /// Number of LLM iterations that ran
+        iterations: usize,
+        /// Conversation so far, including the unanswered tool calls
+        messages: Vec<crate::messages::Message>,
+    },
+
     /// Custom error
     #[error("{0}")]
     Custom(String),
@@ -200,6 +200,6 @@ pub mod agents;
 // Re-export main types
 pub use error::{PrebuiltError, Result};
 pub use messages::{Message, MessageType, ToolCall};
-pub use tools::{Tool, ToolInput, ToolOutput, ToolRegistry};
+pub use tools::{CancellationToken, Tool, ToolInput, ToolOutput, ToolRegistry};
 pub use tool_node::ToolNode;
-pub use agents::create_react_agent;
+pub use agents::{create_react_agent, create_react_agent_with_config, ReactAgent, ReactConfig};
@@ -238,7 +238,7 @@
 //! ## Parallel Execution
 //!
 //! - Uses `futures::future::join_all` for parallelism
-//! - Each tool call runs concurrently
+//! - Each tool call runs concurrently, up to `with_max_concurrency` at once
 //! - Results collected in original order
 //!
 //! ## Error Handling Modes
@@ -326,9 +326,9 @@
 
 use crate::error::{PrebuiltError, Result};
 use crate::messages::{Message, ToolCall};
-use crate::tools::{Tool, ToolRegistry};
+use crate::tools::{CancellationToken, Tool, ToolRegistry};
 use serde_json::Value;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// ToolNode executes tools based on tool calls in messages
 #[derive(Clone)]
@@ -338,14 +338,33 @@ pub struct ToolNode {
 
     /// Whether to handle errors gracefully
     handle_tool_errors: bool,
+
+    /// Maximum number of tool calls executed at once (0 = unlimited)
+    max_concurrency: usize,
+
+    /// Tripped on shutdown; cancels this node's tool calls for good
+    shutdown: CancellationToken,
+
+    /// Tripped by [`interrupt`](Self::interrupt), then replaced with a fresh
+    /// child of `shutdown`; shared between clones
+    interrupts: Arc<Mutex<CancellationToken>>,
+
+    /// Whether to look for tool calls written into AI message text
+    parse_text_tool_calls: bool,
 }
 
 impl ToolNode {
     /// Create a new ToolNode with the given tool registry
     pub fn new(registry: ToolRegistry) -> Self {
+        let shutdown = CancellationToken::new();
+        let interrupts = shutdown.child_token();
         Self {
             registry: Arc::new(registry),
             handle_tool_errors: true,
+            max_concurrency: 0,
+            shutdown,
+            interrupts: Arc::new(Mutex::new(interrupts)),
+            parse_text_tool_calls: false,
         }
     }
 
@@ -364,6 +383,55 @@ impl ToolNode {
         self
     }
 
+    /// Limit how many tool calls run concurrently (default: 0, unlimited)
+    ///
+    /// Tool messages are still returned in the order of the original calls.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Also parse tool calls embedded in AI message text (default: false)
+    ///
+    /// For models without native tool calling that write calls as JSON in
+    /// `<tool_call>` tags, code fences or bare JSON. Only consulted when the
+    /// last AI message has no structured tool calls. Calls that fail to parse
+    /// are reported in one human message after the tool results, so the model
+    /// can retry.
+    pub fn with_text_tool_call_parsing(mut self, enabled: bool) -> Self {
+        self.parse_text_tool_calls = enabled;
+        self
+    }
+
+    /// Cancel tool calls when `token` is tripped
+    ///
+    /// Pass a token tied to agent shutdown so a long-running tool does not
+    /// hold it up. Once it trips, every later call is cancelled too.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.interrupts = Arc::new(Mutex::new(token.child_token()));
+        self.shutdown = token;
+        self
+    }
+
+    /// Shutdown token that cancels this node's tool calls
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.shutdown
+    }
+
+    /// Cancel the tool calls currently in flight on this node and its clones
+    ///
+    /// Executions started afterwards run normally.
+    pub fn interrupt(&self) {
+        let mut current = self.interrupts.lock().unwrap();
+        current.cancel();
+        *current = self.shutdown.child_token();
+    }
+
+    /// Token for one execution, tripped by shutdown or [`interrupt`](Self::interrupt)
+    fn execution_token(&self) -> CancellationToken {
+        self.interrupts.lock().unwrap().child_token()
+    }
+
     /// Execute tools from a state containing messages
     ///
     /// Expects the state to have a "messages" field containing a list of messages.
@@ -375,24 +443,38 @@ impl ToolNode {
         let messages = self.extract_messages(&state)?;
 
         // Find the last AI message with tool calls
-        let tool_calls = self.find_tool_calls(&messages)?;
+        let (tool_calls, parse_errors) = self.find_tool_calls_and_errors(&messages);
 
-        if tool_calls.is_empty() {
+        if tool_calls.is_empty() && parse_errors.is_empty() {
             // No tool calls to execute
             return Ok(serde_json::json!({
                 "messages": []
             }));
         }
 
+        // Dropping this execution, e.g. when the graph run is aborted or
+        // times out, cancels its tool calls
+        let cancel = self.execution_token();
+        let _cancel_on_drop = cancel.clone().drop_guard();
+
         // Execute all tool calls (in parallel)
-        let results = self.execute_tool_calls(tool_calls).await;
+        let results = self.execute_tool_calls(tool_calls, &cancel).await;
 
         // Convert results to tool messages
-        let tool_messages: Vec<Message> = results
+        let mut tool_messages: Vec<Message> = results
             .into_iter()
             .map(|(tool_call, result)| self.create_tool_message(tool_call, result))
             .collect();
 
+        // Tell the model which embedded calls could not be read. They have
+        // no usable call id, so this is not a tool message.
+        if !parse_errors.is_empty() {
+            tool_messages.push(Message::human(format!(
+                "Error: could not parse tool call(s): {}",
+                parse_errors.join("; ")
+            )));
+        }
+
         Ok(serde_json::json!({
             "messages": tool_messages
         }))
@@ -408,34 +490,48 @@ impl ToolNode {
             .map_err(|e| PrebuiltError::ToolExecution(format!("Failed to parse messages: {}", e)))
     }
 
-    /// Find tool calls in messages
-    fn find_tool_calls(&self, messages: &[Message]) -> Result<Vec<ToolCall>> {
+    /// Tool calls of the last AI message, plus errors for embedded calls
+    /// that could not be parsed
+    fn find_tool_calls_and_errors(&self, messages: &[Message]) -> (Vec<ToolCall>, Vec<String>) {
         // Find the last AI message
-        let last_ai_message = messages
-            .iter()
-            .rev()
-            .find(|msg| msg.is_ai());
-
-        if let Some(msg) = last_ai_message {
-            if let Some(tool_calls) = msg.get_tool_calls() {
-                return Ok(tool_calls.to_vec());
-            }
+        let Some(msg) = messages.iter().rev().find(|msg| msg.is_ai()) else {
+            return (Vec::new(), Vec::new());
+        };
+
+        if let Some(tool_calls) = msg.get_tool_calls().filter(|calls| !calls.is_empty()) {
+            return (tool_calls.to_vec(), Vec::new());
+        }
+        if !self.parse_text_tool_calls {
+            return (Vec::new(), Vec::new());
         }
 
-        Ok(Vec::new())
+        // Fall back to tool calls embedded as JSON in the message content
+        let (embedded, errors) = langgraph_core::ToolCall::parse_from_text(&msg.content);
+        let calls = embedded
+            .into_iter()
+            .map(|call| ToolCall::new(call.id, call.name, call.args))
+            .collect();
+        (calls, errors.into_iter().map(|e| e.to_string()).collect())
     }
 
     /// Execute all tool calls
-    async fn execute_tool_calls(&self, tool_calls: Vec<ToolCall>) -> Vec<(ToolCall, Result<Value>)> {
+    async fn execute_tool_calls(
+        &self,
+        tool_calls: Vec<ToolCall>,
+        cancel: &CancellationToken,
+    ) -> Vec<(ToolCall, Result<Value>)> {
         // Execute tools in parallel
         let futures: Vec<_> = tool_calls
             .into_iter()
             .map(|tool_call| {
                 let registry = self.registry.clone();
                 let handle_errors = self.handle_tool_errors;
+                let cancel = cancel.clone();
 
                 async move {
-                    let result = registry.execute(&tool_call.name, tool_call.args.clone()).await;
+                    let result = registry
+                        .execute_with_cancel(&tool_call.name, tool_call.args.clone(), cancel)
+                        .await;
 
                     let final_result = if handle_errors && result.is_err() {
                         // Convert error to error message
@@ -453,7 +549,17 @@ impl ToolNode {
             })
             .collect();
 
-        futures::future::join_all(futures).await
+        if self.max_concurrency == 0 || futures.len() <= self.max_concurrency {
+            futures::future::join_all(futures).await
+        } else {
+            // `buffered` keeps at most `max_concurrency` calls in flight and
+            // yields results in call order
+            use futures::stream::StreamExt;
+            futures::stream::iter(futures)
+                .buffered(self.max_concurrency)
+                .collect()
+                .await
+        }
     }
 
     /// Create a tool message from a tool call result
@@ -555,6 +661,46 @@ mod tests {
         }
     }
 
+    /// Works in 10ms steps and reports how far it got when cancelled
+    struct CooperativeTool {
+        steps: usize,
+    }
+
+    #[async_trait]
+    impl Tool for CooperativeTool {
+        fn name(&self) -> &str {
+            "cooperative_tool"
+        }
+
+        fn description(&self) -> &str {
+            "A long tool that stops when cancelled"
+        }
+
+        async fn execute(&self, input: Value) -> Result<Value> {
+            self.execute_with_cancel(input, CancellationToken::new())
+                .await
+        }
+
+        async fn execute_with_cancel(
+            &self,
+            _input: Value,
+            cancel: CancellationToken,
+        ) -> Result<Value> {
+            for step in 0..self.steps {
+                tokio::select! {
+                    _ = cancel.cancelled() => {
+                        return Ok(serde_json::json!({
+                            "status": "cancelled",
+                            "completed_steps": step
+                        }));
+                    }
+                    _ = tokio::time::sleep(tokio::time::Duration::from_millis(10)) => {}
+                }
+            }
+            Ok(serde_json::json!({"status": "done", "completed_steps": self.steps}))
+        }
+    }
+
     #[tokio::test]
     async fn test_tool_node_execution() {
         let tool_node = ToolNode::from_tools(vec![Box::new(TestTool)]);
@@ -623,6 +769,133 @@ mod tests {
         assert!(messages[0].content.contains("error"));
     }
 
+    #[tokio::test]
+    async fn test_tool_node_interrupt_stops_cooperative_tool() {
+        let tool_node = ToolNode::from_tools(vec![Box::new(CooperativeTool { steps: 500 })]);
+
+        let tool_call = ToolCall::new("call_1", "cooperative_tool", serde_json::json!({}));
+        let state = serde_json::json!({
+            "messages": vec![Message::ai("Working").with_tool_calls(vec![tool_call])]
+        });
+
+        let interrupter = tool_node.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            interrupter.interrupt();
+        });
+
+        let start = std::time::Instant::now();
+        let result = tool_node.execute(state).await.unwrap();
+
+        // Returned well before its 5s of work
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        let output: Value = serde_json::from_str(&messages[0].content).unwrap();
+        assert_eq!(output["status"], "cancelled");
+        assert!(output["completed_steps"].as_u64().unwrap() < 500);
+    }
+
+    #[tokio::test]
+    async fn test_tool_node_runs_normally_after_interrupt() {
+        let tool_node = ToolNode::from_tools(vec![Box::new(CooperativeTool { steps: 2 })]);
+        tool_node.interrupt();
+
+        let tool_call = ToolCall::new("call_1", "cooperative_tool", serde_json::json!({}));
+        let state = serde_json::json!({
+            "messages": vec![Message::ai("Working").with_tool_calls(vec![tool_call])]
+        });
+
+        let result = tool_node.clone().execute(state).await.unwrap();
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        let output: Value = serde_json::from_str(&messages[0].content).unwrap();
+        assert_ne!(output["status"], "cancelled");
+    }
+
+    /// Hands its token to background work that records when it is cancelled
+    struct BackgroundTool {
+        cancelled: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    #[async_trait]
+    impl Tool for BackgroundTool {
+        fn name(&self) -> &str {
+            "background_tool"
+        }
+
+        fn description(&self) -> &str {
+            "Starts background work that stops when cancelled"
+        }
+
+        async fn execute(&self, input: Value) -> Result<Value> {
+            self.execute_with_cancel(input, CancellationToken::new()).await
+        }
+
+        async fn execute_with_cancel(
+            &self,
+            _input: Value,
+            cancel: CancellationToken,
+        ) -> Result<Value> {
+            let cancelled = self.cancelled.clone();
+            tokio::spawn(async move {
+                cancel.cancelled().await;
+                cancelled.store(true, Ordering::SeqCst);
+            });
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            Ok(serde_json::json!({"status": "done"}))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropping_execution_cancels_tool_calls() {
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let tool_node = ToolNode::from_tools(vec![Box::new(BackgroundTool {
+            cancelled: cancelled.clone(),
+        })]);
+        let tool_call = ToolCall::new("call_1", "background_tool", serde_json::json!({}));
+        let state = serde_json::json!({
+            "messages": vec![Message::ai("Working").with_tool_calls(vec![tool_call])]
+        });
+
+        // A graph timeout drops the node's future mid-call
+        let timed_out = tokio::time::timeout(
+            std::time::Duration::from_millis(30),
+            tool_node.execute(state),
+        )
+        .await;
+        assert!(timed_out.is_err());
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+        assert!(cancelled.load(Ordering::SeqCst));
+        // The shutdown token itself is untouched
+        assert!(!tool_node.cancellation_token().is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_registry_cancels_non_cooperative_tool() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(SlowTool { delay_ms: 5_000 }));
+
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+            trigger.cancel();
+        });
+
+        let err = registry
+            .execute_with_cancel("slow_tool", serde_json::json!({}), cancel.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PrebuiltError::Cancelled(ref name) if name == "slow_tool"));
+
+        // An already-cancelled token fails before the tool starts
+        let err = registry
+            .execute_with_cancel("slow_tool", serde_json::json!({}), cancel)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PrebuiltError::Cancelled(_)));
+    }
+
     // ========== Tool Node Creation Tests ==========
 
     #[test]
@@ -754,11 +1027,57 @@ mod tests {
             Message::ai("Response").with_tool_calls(vec![tool_call.clone()]),
         ];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 1);
         assert_eq!(found_calls[0].id, "call_1");
     }
 
+    #[tokio::test]
+    async fn test_find_tool_calls_embedded_in_content() {
+        let tool_node = ToolNode::from_tools(vec![]).with_text_tool_call_parsing(true);
+
+        let messages = vec![
+            Message::human("Test"),
+            Message::ai(r#"<tool_call>{"id": "call_9", "name": "test", "arguments": {"x": 1}}</tool_call>"#),
+        ];
+
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
+        assert_eq!(found_calls.len(), 1);
+        assert_eq!(found_calls[0].id, "call_9");
+        assert_eq!(found_calls[0].args, serde_json::json!({"x": 1}));
+    }
+
+    #[tokio::test]
+    async fn test_text_tool_calls_ignored_by_default() {
+        let tool_node = ToolNode::from_tools(vec![Box::new(TestTool)]);
+        let state = serde_json::json!({
+            "messages": [
+                Message::ai(r#"{"name": "test_tool", "arguments": {"x": 1}}"#)
+            ]
+        });
+
+        let result = tool_node.execute(state).await.unwrap();
+        assert_eq!(result["messages"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_text_tool_call_reported_as_error_message() {
+        let tool_node = ToolNode::from_tools(vec![Box::new(TestTool)])
+            .with_text_tool_call_parsing(true);
+        let state = serde_json::json!({
+            "messages": [
+                Message::ai(r#"<tool_call>{"name": "test_tool", "arguments": </tool_call>"#)
+            ]
+        });
+
+        let result = tool_node.execute(state).await.unwrap();
+        let messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        assert_eq!(messages.len(), 1);
+        // No tool message with an id that matches no call
+        assert!(messages[0].is_human());
+        assert!(messages[0].content.starts_with("Error:"));
+    }
+
     #[tokio::test]
     async fn test_find_tool_calls_no_ai_messages() {
         let tool_node = ToolNode::from_tools(vec![]);
@@ -768,7 +1087,7 @@ mod tests {
             Message::human("World"),
         ];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 0);
     }
 
@@ -781,7 +1100,7 @@ mod tests {
             Message::ai("Response without tool calls"),
         ];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 0);
     }
 
@@ -798,7 +1117,7 @@ mod tests {
             Message::ai("Second").with_tool_calls(vec![tool_call2.clone()]),
         ];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 1);
         assert_eq!(found_calls[0].id, "call_2"); // Should use last AI message
     }
@@ -814,7 +1133,7 @@ mod tests {
             Message::ai("Second without calls"),
         ];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 0); // Last AI has no calls
     }
 
@@ -824,7 +1143,7 @@ mod tests {
 
         let messages: Vec<Message> = vec![];
 
-        let found_calls = tool_node.find_tool_calls(&messages).unwrap();
+        let (found_calls, _) = tool_node.find_tool_calls_and_errors(&messages);
         assert_eq!(found_calls.len(), 0);
     }
 
@@ -866,6 +1185,84 @@ mod tests {
         assert!(elapsed.as_millis() < 150); // Some buffer for CI
     }
 
+    struct NamedSlowTool {
+        name: &'static str,
+        delay_ms: u64,
+    }
+
+    #[async_trait]
+    impl Tool for NamedSlowTool {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn description(&self) -> &str {
+            "A named slow tool for testing concurrency limits"
+        }
+
+        async fn execute(&self, _input: Value) -> Result<Value> {
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.delay_ms)).await;
+            Ok(serde_json::json!({"tool": self.name}))
+        }
+    }
+
+    fn three_slow_tools_state() -> (Vec<Box<dyn Tool>>, Value) {
+        let tools: Vec<Box<dyn Tool>> = vec![
+            Box::new(NamedSlowTool { name: "slow_a", delay_ms: 100 }),
+            Box::new(NamedSlowTool { name: "slow_b", delay_ms: 60 }),
+            Box::new(NamedSlowTool { name: "slow_c", delay_ms: 80 }),
+        ];
+        let tool_calls = vec![
+            ToolCall::new("call_a", "slow_a", serde_json::json!({})),
+            ToolCall::new("call_b", "slow_b", serde_json::json!({})),
+            ToolCall::new("call_c", "slow_c", serde_json::json!({})),
+        ];
+        let state = serde_json::json!({
+            "messages": vec![Message::ai("Run").with_tool_calls(tool_calls)]
+        });
+        (tools, state)
+    }
+
+    // Paused time auto-advances to each sleep's deadline, so elapsed time is
+    // the simulated schedule rather than wall-clock time
+    #[tokio::test(start_paused = true)]
+    async fn test_three_slow_tools_run_concurrently() {
+        use tokio::time::Instant;
+
+        let (tools, state) = three_slow_tools_state();
+        let tool_node = ToolNode::from_tools(tools);
+
+        let start = Instant::now();
+        let result = tool_node.execute(state).await.unwrap();
+        let elapsed = start.elapsed();
+
+        let tool_messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        let ids: Vec<_> = tool_messages.iter().filter_map(|m| m.tool_call_id.as_deref()).collect();
+        assert_eq!(ids, vec!["call_a", "call_b", "call_c"]);
+
+        // The slowest tool (100ms), not the sum (240ms)
+        assert!((100..110).contains(&elapsed.as_millis()), "took {:?}", elapsed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_max_concurrency_limits_parallelism() {
+        use tokio::time::Instant;
+
+        let (tools, state) = three_slow_tools_state();
+        let tool_node = ToolNode::from_tools(tools).with_max_concurrency(1);
+
+        let start = Instant::now();
+        let result = tool_node.execute(state).await.unwrap();
+        let elapsed = start.elapsed();
+
+        let tool_messages: Vec<Message> = serde_json::from_value(result["messages"].clone()).unwrap();
+        let ids: Vec<_> = tool_messages.iter().filter_map(|m| m.tool_call_id.as_deref()).collect();
+        assert_eq!(ids, vec!["call_a", "call_b", "call_c"]);
+
+        // One call at a time means the delays add up
+        assert!((240..250).contains(&elapsed.as_millis()), "took {:?}", elapsed);
+    }
+
     #[tokio::test]
     async fn test_parallel_execution_order_preserved() {
         let counter = Arc::new(AtomicUsize::new(0));
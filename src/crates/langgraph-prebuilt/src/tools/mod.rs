@@ -66,7 +66,23 @@
 //!     json!({"query": "rust async"})).await?;
 //!
 //! // List available tools
-//! let tools = registry.list_tools(); // ["search", "calculator"]
+//! let tools = registry.list_tools(); // ["calculator", "search"]
+//! ```
+//!
+//! ## Aliases and Namespaces
+//!
+//! Agents with many tools can group them under a namespace and give common
+//! ones short aliases. Both resolve through [`ToolRegistry::execute`].
+//!
+//! ```rust,ignore
+//! registry.register_namespaced("git", Box::new(StatusTool)); // "git.status"
+//! registry.register_alias("ls", "fs_list")?;
+//!
+//! registry.execute("git.status", json!({})).await?;
+//! registry.execute("ls", json!({"path": "."})).await?;
+//!
+//! // Aliases are listed with their target
+//! let tools = registry.list_tools(); // ["fs_list", "git.status", "ls -> fs_list"]
 //! ```
 //!
 //! # Tool Implementation Patterns
@@ -396,11 +412,13 @@
 //! - [`crate::agents::create_react_agent`] - ReAct agent with tools
 //! - [`crate::messages::ToolCall`] - Tool invocation from LLM
 
-use crate::error::Result;
+use crate::error::{PrebuiltError, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+pub use tokio_util::sync::CancellationToken;
+
 pub mod validation;
 
 pub use validation::{
@@ -457,6 +475,25 @@ pub trait Tool: Send + Sync {
     /// Execute the tool with the given input
     async fn execute(&self, input: ToolInput) -> Result<ToolOutput>;
 
+    /// Execute the tool, stopping early if `cancel` is triggered
+    ///
+    /// The default runs [`execute`](Tool::execute) and abandons it at its
+    /// next `.await` once the token trips, returning
+    /// [`PrebuiltError::Cancelled`]. Tools that hold resources (a child
+    /// process, an open connection) should override this to observe the
+    /// token themselves and clean up before returning.
+    async fn execute_with_cancel(
+        &self,
+        input: ToolInput,
+        cancel: CancellationToken,
+    ) -> Result<ToolOutput> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(PrebuiltError::Cancelled(self.name().to_string())),
+            result = self.execute(input) => result,
+        }
+    }
+
     /// Validate input (optional)
     fn validate_input(&self, _input: &ToolInput) -> Result<()> {
         Ok(())
@@ -481,9 +518,17 @@ pub struct ToolMetadata {
     pub extra: std::collections::HashMap<String, Value>,
 }
 
+/// Separator between a namespace and a tool name, as in `git.status`
+pub const NAMESPACE_SEPARATOR: char = '.';
+
 /// Tool registry for managing multiple tools
+///
+/// Tools are registered under their own name or under `namespace.name`, and
+/// aliases map extra names onto registered ones. Registered names win over
+/// aliases when both exist.
 pub struct ToolRegistry {
     tools: std::collections::HashMap<String, Box<dyn Tool>>,
+    aliases: std::collections::HashMap<String, String>,
 }
 
 impl ToolRegistry {
@@ -491,6 +536,7 @@ impl ToolRegistry {
     pub fn new() -> Self {
         Self {
             tools: std::collections::HashMap::new(),
+            aliases: std::collections::HashMap::new(),
         }
     }
 
@@ -500,40 +546,201 @@ impl ToolRegistry {
         self.tools.insert(name, tool);
     }
 
-    /// Get a tool by name
+    /// Register a tool under `namespace`, as `namespace.name`
+    pub fn register_namespaced(&mut self, namespace: &str, tool: Box<dyn Tool>) {
+        let name = format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, tool.name());
+        self.tools.insert(name, tool);
+    }
+
+    /// Register `alias` as another name for the tool registered as `target`
+    ///
+    /// `target` may be namespaced and need not be registered yet; an alias
+    /// whose target is still missing fails when executed. Fails if `alias`
+    /// is already a tool name or points at itself.
+    pub fn register_alias(
+        &mut self,
+        alias: impl Into<String>,
+        target: impl Into<String>,
+    ) -> Result<()> {
+        let alias = alias.into();
+        let target = target.into();
+
+        if self.tools.contains_key(&alias) {
+            return Err(PrebuiltError::ToolValidation(format!(
+                "Alias '{}' conflicts with a registered tool",
+                alias
+            )));
+        }
+        if alias == target {
+            return Err(PrebuiltError::ToolValidation(format!(
+                "Alias '{}' cannot point to itself",
+                alias
+            )));
+        }
+
+        self.aliases.insert(alias, target);
+        Ok(())
+    }
+
+    /// Target of `alias`, if it is one
+    pub fn alias_target(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(alias).map(String::as_str)
+    }
+
+    /// Get a tool by name, namespaced name, or alias
     pub fn get(&self, name: &str) -> Option<&dyn Tool> {
-        self.tools.get(name).map(|t| t.as_ref())
+        self.resolve(name).ok()
     }
 
-    /// List all tool names
+    /// Resolve a name, namespaced name, or alias to its tool
+    ///
+    /// Aliases are followed one level, so an alias of an alias is reported
+    /// as dangling rather than risking a cycle.
+    pub fn resolve(&self, name: &str) -> Result<&dyn Tool> {
+        if let Some(tool) = self.tools.get(name) {
+            return Ok(tool.as_ref());
+        }
+
+        match self.aliases.get(name) {
+            Some(target) => self.tools.get(target).map(|t| t.as_ref()).ok_or_else(|| {
+                PrebuiltError::ToolExecution(format!(
+                    "Alias '{}' points to unknown tool '{}'",
+                    name, target
+                ))
+            }),
+            None => Err(PrebuiltError::ToolExecution(format!(
+                "Tool not found: {}",
+                name
+            ))),
+        }
+    }
+
+    /// List all tool names, sorted
+    ///
+    /// Aliases are listed after the tools as `alias -> target`.
     pub fn list_tools(&self) -> Vec<String> {
-        self.tools.keys().cloned().collect()
+        let mut names: Vec<String> = self.tools.keys().cloned().collect();
+        names.sort();
+
+        let mut aliases: Vec<String> = self
+            .aliases
+            .iter()
+            .map(|(alias, target)| format!("{} -> {}", alias, target))
+            .collect();
+        aliases.sort();
+
+        names.extend(aliases);
+        names
+    }
+
+    /// Names of the tools registered under `namespace`, without the prefix
+    pub fn list_namespace(&self, namespace: &str) -> Vec<String> {
+        let prefix = format!("{}{}", namespace, NAMESPACE_SEPARATOR);
+        let mut names: Vec<String> = self
+            .tools
+            .keys()
+            .filter_map(|name| name.strip_prefix(&prefix).map(str::to_string))
+            .collect();
+        names.sort();
+        names
     }
 
     /// Get metadata for all tools
+    ///
+    /// Names are the ones the tools are registered under, so namespaced tools
+    /// report `namespace.name`. Each alias with a registered target gets its
+    /// own entry with an `alias_of` field.
     pub fn list_metadata(&self) -> Vec<ToolMetadata> {
-        self.tools
-            .values()
-            .map(|tool| ToolMetadata {
-                name: tool.name().to_string(),
-                description: tool.description().to_string(),
-                input_schema: tool.input_schema(),
-                extra: std::collections::HashMap::new(),
-            })
-            .collect()
+        let metadata = |name: &str, tool: &dyn Tool| ToolMetadata {
+            name: name.to_string(),
+            description: tool.description().to_string(),
+            input_schema: tool.input_schema(),
+            extra: std::collections::HashMap::new(),
+        };
+
+        let tools = self
+            .tools
+            .iter()
+            .map(|(name, tool)| metadata(name, tool.as_ref()));
+        let aliases = self.aliases.iter().filter_map(|(alias, target)| {
+            let tool = self.tools.get(target)?;
+            let mut entry = metadata(alias, tool.as_ref());
+            entry
+                .extra
+                .insert("alias_of".to_string(), Value::String(target.clone()));
+            Some(entry)
+        });
+
+        tools.chain(aliases).collect()
     }
 
-    /// Execute a tool by name
+    /// Execute a tool by name, namespaced name, or alias
+    ///
+    /// When the tool declares an [`input_schema`](Tool::input_schema), the
+    /// input is checked against it before the tool's own `validate_input`.
     pub async fn execute(&self, name: &str, input: ToolInput) -> Result<ToolOutput> {
-        let tool = self.get(name).ok_or_else(|| {
-            crate::error::PrebuiltError::ToolExecution(format!("Tool not found: {}", name))
-        })?;
+        self.execute_with_cancel(name, input, CancellationToken::new())
+            .await
+    }
 
+    /// Execute a tool like [`execute`](Self::execute), cancelling it when
+    /// `cancel` is triggered
+    ///
+    /// A token that is already cancelled fails before the tool starts.
+    pub async fn execute_with_cancel(
+        &self,
+        name: &str,
+        input: ToolInput,
+        cancel: CancellationToken,
+    ) -> Result<ToolOutput> {
+        let tool = self.resolve(name)?;
+
+        if let Some(schema) = tool.input_schema() {
+            validate_against_schema(tool.name(), &schema, &input)?;
+        }
         tool.validate_input(&input)?;
-        tool.execute(input).await
+
+        if cancel.is_cancelled() {
+            return Err(PrebuiltError::Cancelled(tool.name().to_string()));
+        }
+        tool.execute_with_cancel(input, cancel).await
     }
 }
 
+/// Check `input` against a tool's JSON Schema
+///
+/// Returns a [`PrebuiltError::ToolValidation`] listing every violation, each
+/// prefixed with the JSON pointer of the offending value. An invalid schema
+/// is reported the same way so a broken tool never runs unchecked.
+pub fn validate_against_schema(tool: &str, schema: &Value, input: &ToolInput) -> Result<()> {
+    let compiled = jsonschema::JSONSchema::compile(schema).map_err(|e| {
+        PrebuiltError::ToolValidation(format!(
+            "Tool '{}' has an invalid input schema: {}",
+            tool, e
+        ))
+    })?;
+
+    let violations: Vec<String> = match compiled.validate(input) {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors
+            .map(|e| {
+                let path = e.instance_path.to_string();
+                if path.is_empty() {
+                    format!("/: {}", e)
+                } else {
+                    format!("{}: {}", path, e)
+                }
+            })
+            .collect(),
+    };
+
+    Err(PrebuiltError::ToolValidation(format!(
+        "Input for '{}' does not match its schema: {}",
+        tool,
+        violations.join("; ")
+    )))
+}
+
 impl Default for ToolRegistry {
     fn default() -> Self {
         Self::new()
@@ -586,6 +793,113 @@ mod tests {
         assert_eq!(output["echo"], input);
     }
 
+    #[tokio::test]
+    async fn test_execute_via_alias() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(MockTool));
+        registry.register_alias("m", "mock").unwrap();
+
+        let input = serde_json::json!({"test": "value"});
+        let output = registry.execute("m", input.clone()).await.unwrap();
+
+        assert_eq!(output["echo"], input);
+        assert_eq!(registry.list_tools(), vec!["mock", "m -> mock"]);
+        assert!(registry.register_alias("mock", "other").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dangling_alias_errors() {
+        let mut registry = ToolRegistry::new();
+        registry.register_alias("ls", "fs_list").unwrap();
+
+        let err = registry
+            .execute("ls", serde_json::json!({}))
+            .await
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("Alias 'ls' points to unknown tool 'fs_list'"));
+        assert!(registry.get("ls").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_resolution() {
+        let mut registry = ToolRegistry::new();
+        registry.register_namespaced("git", Box::new(MockTool));
+        registry.register_alias("gm", "git.mock").unwrap();
+
+        let input = serde_json::json!({"test": "value"});
+        let output = registry.execute("git.mock", input.clone()).await.unwrap();
+        assert_eq!(output["echo"], input);
+        assert!(registry.execute("gm", input).await.is_ok());
+
+        assert!(registry.get("mock").is_none());
+        assert_eq!(registry.list_namespace("git"), vec!["mock"]);
+
+        let metadata = registry.list_metadata();
+        let alias = metadata.iter().find(|m| m.name == "gm").unwrap();
+        assert_eq!(alias.extra["alias_of"], "git.mock");
+    }
+
+    struct WeatherTool;
+
+    #[async_trait]
+    impl Tool for WeatherTool {
+        fn name(&self) -> &str {
+            "weather"
+        }
+
+        fn description(&self) -> &str {
+            "Weather for a city"
+        }
+
+        fn input_schema(&self) -> Option<Value> {
+            Some(serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "city": {"type": "string"},
+                    "days": {"type": "integer", "minimum": 1}
+                },
+                "required": ["city"]
+            }))
+        }
+
+        async fn execute(&self, input: ToolInput) -> Result<ToolOutput> {
+            Ok(serde_json::json!({"city": input["city"]}))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_accepts_valid_input() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(WeatherTool));
+
+        let output = registry
+            .execute("weather", serde_json::json!({"city": "Oslo", "days": 3}))
+            .await
+            .unwrap();
+
+        assert_eq!(output["city"], "Oslo");
+    }
+
+    #[tokio::test]
+    async fn test_schema_rejects_invalid_input() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(WeatherTool));
+
+        let err = registry
+            .execute("weather", serde_json::json!({"days": 0}))
+            .await
+            .unwrap_err();
+
+        let PrebuiltError::ToolValidation(message) = err else {
+            panic!("expected a validation error, got {:?}", err);
+        };
+        assert!(message.contains("\"city\" is a required property"));
+        assert!(message.contains("/days:"));
+    }
+
     #[test]
     fn test_tool_metadata() {
         let registry = {
@@ -0,0 +1,63 @@
+//! Integration tests for Plan-Execute step-level streaming
+
+use futures::StreamExt;
+use langgraph_prebuilt::agents::plan_execute::LlmFunction;
+use langgraph_prebuilt::agents::{create_plan_execute_agent, PlanStep, PlanStepEvent};
+use langgraph_prebuilt::Message;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn planner() -> LlmFunction {
+    Arc::new(|_state| Box::pin(async { Ok(Message::ai("1. Research\n2. Draft\n3. Review")) }))
+}
+
+fn executor(calls: Arc<AtomicUsize>) -> LlmFunction {
+    Arc::new(move |_state| {
+        let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+        Box::pin(async move { Ok(Message::ai(format!("result {}", n))) })
+    })
+}
+
+fn input() -> Value {
+    json!({
+        "objective": "Write a short report",
+        "plan": [],
+        "messages": [],
+        "current_step": 0,
+        "replan_count": 0
+    })
+}
+
+#[tokio::test]
+async fn test_stream_emits_one_event_per_plan_step() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let agent = create_plan_execute_agent(planner(), executor(calls.clone()), vec![])
+        .build_agent()
+        .unwrap();
+
+    let events: Vec<PlanStepEvent> = agent.stream(input()).await.unwrap().collect().await;
+
+    // One event per executed step, in order
+    assert_eq!(events.len(), calls.load(Ordering::SeqCst));
+    assert_eq!(events.len(), 3);
+    for (i, event) in events.iter().enumerate() {
+        assert_eq!(event.step_number, i + 1);
+        assert!(!event.description.is_empty());
+        assert_eq!(event.result, format!("result {}", i + 1));
+    }
+}
+
+#[tokio::test]
+async fn test_invoke_omits_internal_event_channel() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let agent = create_plan_execute_agent(planner(), executor(calls.clone()), vec![])
+        .build_agent()
+        .unwrap();
+
+    let final_state = agent.invoke(input()).await.unwrap();
+
+    assert!(final_state.get("__custom__").is_none());
+    let plan: Vec<PlanStep> = serde_json::from_value(final_state["plan"].clone()).unwrap();
+    assert_eq!(plan.len(), calls.load(Ordering::SeqCst));
+}
@@ -0,0 +1,364 @@
+//! Response cache wrapper for any `ChatModel`.
+//!
+//! [`CachedModel`] keys each request by a stable hash of the model name
+//! (see [`ChatModel::model_name`]), its messages and generation parameters
+//! (see [`generate_json_hash`]) and serves repeated requests from a
+//! [`Cache`] instead of calling the provider again. Message ids and metadata
+//! are left out of the key, so a rebuilt conversation with the same content
+//! hits the same entry.
+//!
+//! Sampled output is not reproducible, so requests with a temperature above
+//! zero are not cached unless caching is forced with
+//! [`CachedModel::with_force_caching`]. Requests that leave the temperature
+//! unset are cached.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use langgraph_core::store::InMemoryCache;
+//! use llm::remote::OpenAiClient;
+//! use llm::CachedModel;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let model = CachedModel::new(OpenAiClient::new(config))
+//!     .with_cache(Arc::new(InMemoryCache::new()))
+//!     .with_ttl(Duration::from_secs(3600));
+//!
+//! let response = model.chat(request.with_temperature(0.0)).await?;
+//! ```
+
+use async_trait::async_trait;
+use langgraph_core::error::Result as GraphResult;
+use langgraph_core::llm::{
+    ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, ToolDefinition,
+};
+use langgraph_core::store::{Cache, InMemoryCache};
+use std::sync::Arc;
+use std::time::Duration;
+use tooling::serialization::generate_json_hash;
+
+/// Default prefix for cache keys written by [`CachedModel`].
+pub const DEFAULT_KEY_PREFIX: &str = "llm:chat";
+
+/// A `ChatModel` that caches responses from an inner model.
+///
+/// Only `chat()` is cached; `stream()` always goes to the inner model.
+/// Cache read and write failures are logged and treated as a miss, so a
+/// broken cache never fails a request the inner model could answer.
+#[derive(Clone)]
+pub struct CachedModel<M> {
+    inner: M,
+    cache: Arc<dyn Cache>,
+    key_prefix: String,
+    ttl: Option<Duration>,
+    force: bool,
+}
+
+impl<M: ChatModel> CachedModel<M> {
+    /// Wrap a model with a fresh in-memory cache and no expiry.
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(InMemoryCache::new()),
+            key_prefix: DEFAULT_KEY_PREFIX.to_string(),
+            ttl: None,
+            force: false,
+        }
+    }
+
+    /// Store responses in this cache.
+    ///
+    /// Models that report a [`model_name`](ChatModel::model_name) can share
+    /// one cache; give models without one a distinct
+    /// [`with_key_prefix`](Self::with_key_prefix).
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Set the prefix prepended to every cache key.
+    pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.key_prefix = prefix.into();
+        self
+    }
+
+    /// Expire cached responses after `ttl`.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Cache requests even when their temperature is above zero.
+    pub fn with_force_caching(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Get the wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap into the inner model.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    /// Whether `request` is eligible for caching.
+    ///
+    /// Only a temperature above zero opts out; an unset temperature is cached.
+    pub fn is_cacheable(&self, request: &ChatRequest) -> bool {
+        self.force || !matches!(request.config.temperature, Some(t) if t > 0.0)
+    }
+
+    /// Cache key for `request`, or `None` if it cannot be serialized.
+    ///
+    /// Only what determines the answer is hashed: the model name, each
+    /// message's role, name, content and tool calls, plus the generation
+    /// config.
+    pub fn cache_key(&self, request: &ChatRequest) -> Option<String> {
+        let messages: Vec<_> = request
+            .messages
+            .iter()
+            .map(|message| {
+                serde_json::json!({
+                    "role": message.role,
+                    "name": message.name,
+                    "content": message.content,
+                    "tool_calls": message.tool_calls,
+                    "tool_call_id": message.tool_call_id,
+                })
+            })
+            .collect();
+        let value = serde_json::json!({
+            "model": self.inner.model_name(),
+            "messages": messages,
+            "config": serde_json::to_value(&request.config).ok()?,
+        });
+        Some(format!(
+            "{}:{:016x}",
+            self.key_prefix,
+            generate_json_hash(&value)
+        ))
+    }
+
+    async fn lookup(&self, key: &str) -> Option<ChatResponse> {
+        let value = match self.cache.get(key).await {
+            Ok(value) => value?,
+            Err(e) => {
+                tracing::warn!("LLM cache read failed for {}: {}", key, e);
+                return None;
+            }
+        };
+        match serde_json::from_value(value) {
+            Ok(response) => Some(response),
+            Err(e) => {
+                tracing::warn!("Discarding unreadable LLM cache entry {}: {}", key, e);
+                None
+            }
+        }
+    }
+
+    async fn store(&self, key: &str, response: &ChatResponse) {
+        let value = match serde_json::to_value(response) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!("Could not serialize LLM response for caching: {}", e);
+                return;
+            }
+        };
+        let ttl = self.ttl.map(|ttl| ttl.as_secs().max(1));
+        if let Err(e) = self.cache.put(key, value, ttl).await {
+            tracing::warn!("LLM cache write failed for {}: {}", key, e);
+        }
+    }
+}
+
+#[async_trait]
+impl<M> ChatModel for CachedModel<M>
+where
+    M: ChatModel + Clone + 'static,
+{
+    async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
+        let key = if self.is_cacheable(&request) {
+            self.cache_key(&request)
+        } else {
+            None
+        };
+        let Some(key) = key else {
+            return self.inner.chat(request).await;
+        };
+
+        if let Some(response) = self.lookup(&key).await {
+            tracing::debug!("LLM cache hit: {}", key);
+            return Ok(response);
+        }
+
+        let response = self.inner.chat(request).await?;
+        self.store(&key, &response).await;
+        Ok(response)
+    }
+
+    async fn stream(&self, request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+        self.inner.stream(request).await
+    }
+
+    async fn is_available(&self) -> GraphResult<bool> {
+        self.inner.is_available().await
+    }
+
+    fn bound_tools(&self) -> Vec<ToolDefinition> {
+        self.inner.bound_tools()
+    }
+
+    fn model_name(&self) -> Option<String> {
+        self.inner.model_name()
+    }
+
+    fn clone_box(&self) -> Box<dyn ChatModel> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LlmError;
+    use langgraph_core::Message;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Answers every call with a numbered reply.
+    #[derive(Clone, Default)]
+    struct CountingModel {
+        calls: Arc<AtomicUsize>,
+        model: Option<String>,
+    }
+
+    impl CountingModel {
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl ChatModel for CountingModel {
+        async fn chat(&self, _request: ChatRequest) -> GraphResult<ChatResponse> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(ChatResponse {
+                message: Message::assistant(format!("reply {}", call)),
+                usage: None,
+                reasoning: None,
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn stream(&self, _request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+            Err(LlmError::Other("not supported".to_string()).into())
+        }
+
+        fn model_name(&self) -> Option<String> {
+            self.model.clone()
+        }
+
+        fn clone_box(&self) -> Box<dyn ChatModel> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn request(text: &str) -> ChatRequest {
+        ChatRequest::new(vec![Message::human(text)]).with_temperature(0.0)
+    }
+
+    #[tokio::test]
+    async fn test_identical_request_hits_cache() {
+        let inner = CountingModel::default();
+        let model = CachedModel::new(inner.clone());
+
+        let first = model.chat(request("hi")).await.unwrap();
+        let second = model.chat(request("hi")).await.unwrap();
+
+        assert_eq!(inner.calls(), 1);
+        assert_eq!(second.message.text(), first.message.text());
+    }
+
+    #[tokio::test]
+    async fn test_cache_key_ignores_message_ids() {
+        let model = CachedModel::new(CountingModel::default());
+        let mut tagged = request("hi");
+        tagged.messages[0].id = Some("another-id".to_string());
+
+        assert_eq!(model.cache_key(&tagged), model.cache_key(&request("hi")));
+    }
+
+    #[tokio::test]
+    async fn test_unset_temperature_is_cached() {
+        let inner = CountingModel::default();
+        let model = CachedModel::new(inner.clone());
+        let unset = || ChatRequest::new(vec![Message::human("hi")]);
+
+        model.chat(unset()).await.unwrap();
+        model.chat(unset()).await.unwrap();
+
+        assert_eq!(inner.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_models_sharing_a_cache_do_not_collide() {
+        let cache = Arc::new(InMemoryCache::new());
+        let model = |name: &str| CountingModel {
+            model: Some(name.to_string()),
+            ..Default::default()
+        };
+        let (gpt, claude) = (model("gpt-4"), model("claude-3"));
+        let cached_gpt = CachedModel::new(gpt.clone()).with_cache(cache.clone());
+        let cached_claude = CachedModel::new(claude.clone()).with_cache(cache.clone());
+
+        assert_ne!(
+            cached_gpt.cache_key(&request("hi")),
+            cached_claude.cache_key(&request("hi"))
+        );
+        cached_gpt.chat(request("hi")).await.unwrap();
+        cached_claude.chat(request("hi")).await.unwrap();
+
+        assert_eq!(gpt.calls(), 1);
+        assert_eq!(claude.calls(), 1);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_changed_message_misses_cache() {
+        let inner = CountingModel::default();
+        let model = CachedModel::new(inner.clone());
+
+        model.chat(request("hi")).await.unwrap();
+        let other = model.chat(request("bye")).await.unwrap();
+
+        assert_eq!(inner.calls(), 2);
+        assert_eq!(other.message.text(), Some("reply 1"));
+        assert_ne!(
+            model.cache_key(&request("hi")),
+            model.cache_key(&request("bye"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sampled_requests_bypass_cache_unless_forced() {
+        let inner = CountingModel::default();
+        let cache = Arc::new(InMemoryCache::new());
+        let model = CachedModel::new(inner.clone()).with_cache(cache.clone());
+        let sampled = || request("hi").with_temperature(0.7);
+
+        model.chat(sampled()).await.unwrap();
+        model.chat(sampled()).await.unwrap();
+        assert_eq!(inner.calls(), 2);
+        assert!(cache.is_empty());
+
+        let forced = model.with_force_caching(true);
+        forced.chat(sampled()).await.unwrap();
+        forced.chat(sampled()).await.unwrap();
+        assert_eq!(inner.calls(), 3);
+        assert_eq!(cache.len(), 1);
+    }
+}
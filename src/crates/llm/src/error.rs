@@ -1,5 +1,14 @@
 //! Error types for LLM provider implementations.
-
+//!
+//! Providers build errors for failed HTTP calls with
+//! [`LlmError::from_status`] and `From<reqwest::Error>`, so every provider
+//! classifies the same failure the same way and retry logic can rely on
+//! [`LlmError::is_retryable`] and [`LlmError::retry_after`].
+
+use langgraph_core::error::GraphError;
+use reqwest::StatusCode;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type for LLM operations.
@@ -10,7 +19,7 @@ pub type Result<T> = std::result::Result<T, LlmError>;
 pub enum LlmError {
     /// HTTP request failed.
     #[error("HTTP request failed: {0}")]
-    HttpError(#[from] reqwest::Error),
+    HttpError(reqwest::Error),
 
     /// Failed to serialize/deserialize data.
     #[error("Serialization error: {0}")]
@@ -29,12 +38,22 @@ pub enum LlmError {
     ModelNotFound(String),
 
     /// Provider service unavailable (e.g., Ollama not running).
-    #[error("Service unavailable: {0}")]
-    ServiceUnavailable(String),
+    #[error("Service unavailable: {message}")]
+    ServiceUnavailable {
+        /// Error detail
+        message: String,
+        /// `Retry-After` from the response, if any
+        retry_after: Option<Duration>,
+    },
 
     /// Rate limit exceeded.
-    #[error("Rate limit exceeded: {0}")]
-    RateLimitExceeded(String),
+    #[error("Rate limit exceeded: {message}")]
+    RateLimitExceeded {
+        /// Error detail
+        message: String,
+        /// `Retry-After` from the response, if any
+        retry_after: Option<Duration>,
+    },
 
     /// Invalid request parameters.
     #[error("Invalid request: {0}")]
@@ -63,14 +82,84 @@ pub enum LlmError {
 
 impl LlmError {
     /// Check if this error is retryable.
+    ///
+    /// Rate limits, timeouts and unavailable services (429, 408 and 5xx
+    /// responses, connection failures) are transient. Everything else,
+    /// including 400/401/403 responses, fails the same way on every attempt.
     pub fn is_retryable(&self) -> bool {
-        matches!(
-            self,
-            LlmError::HttpError(_)
-                | LlmError::ServiceUnavailable(_)
-                | LlmError::Timeout(_)
-                | LlmError::RateLimitExceeded(_)
-        )
+        match self {
+            LlmError::ServiceUnavailable { .. }
+            | LlmError::Timeout(_)
+            | LlmError::RateLimitExceeded { .. } => true,
+            LlmError::HttpError(err) => match err.status() {
+                Some(status) => Self::is_retryable_status(status),
+                None => !err.is_builder() && !err.is_decode(),
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether a response with this status is worth retrying.
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::REQUEST_TIMEOUT
+            || status.is_server_error()
+    }
+
+    /// Build the error for a non-success response from `provider`.
+    ///
+    /// | Status      | Error                  |
+    /// |-------------|------------------------|
+    /// | 401, 403    | `AuthenticationError`  |
+    /// | 404         | `ModelNotFound`        |
+    /// | 408         | `Timeout`              |
+    /// | 429         | `RateLimitExceeded`    |
+    /// | other 4xx   | `InvalidRequest`       |
+    /// | 5xx         | `ServiceUnavailable`   |
+    ///
+    /// `retry_after` is kept for 429 and 5xx responses, see
+    /// [`retry_after`](Self::retry_after).
+    pub fn from_status(
+        provider: &str,
+        status: StatusCode,
+        body: impl AsRef<str>,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let detail = format!("{} API error {}: {}", provider, status, body.as_ref());
+        match status.as_u16() {
+            401 | 403 => LlmError::AuthenticationError(detail),
+            404 => LlmError::ModelNotFound(detail),
+            408 => LlmError::Timeout(detail),
+            429 => LlmError::rate_limited(detail, retry_after),
+            400..=499 => LlmError::InvalidRequest(detail),
+            500..=599 => LlmError::service_unavailable(detail, retry_after),
+            _ => LlmError::ProviderError(detail),
+        }
+    }
+
+    /// Build a rate-limit error from a provider response.
+    pub fn rate_limited(message: impl Into<String>, retry_after: Option<Duration>) -> Self {
+        LlmError::RateLimitExceeded {
+            message: message.into(),
+            retry_after,
+        }
+    }
+
+    /// Build a service-unavailable error.
+    pub fn service_unavailable(message: impl Into<String>, retry_after: Option<Duration>) -> Self {
+        LlmError::ServiceUnavailable {
+            message: message.into(),
+            retry_after,
+        }
+    }
+
+    /// How long the provider asked us to wait before retrying, if it said.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            LlmError::RateLimitExceeded { retry_after, .. }
+            | LlmError::ServiceUnavailable { retry_after, .. } => *retry_after,
+            _ => None,
+        }
     }
 
     /// Check if this error is due to authentication.
@@ -82,16 +171,69 @@ impl LlmError {
     }
 }
 
+impl From<reqwest::Error> for LlmError {
+    /// Timeouts and connection failures get their own variants so they are
+    /// classified like the equivalent HTTP responses.
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            LlmError::Timeout(err.to_string())
+        } else if err.is_connect() {
+            LlmError::service_unavailable(err.to_string(), None)
+        } else {
+            LlmError::HttpError(err)
+        }
+    }
+}
+
 impl From<serde_json::Error> for LlmError {
     fn from(err: serde_json::Error) -> Self {
         LlmError::SerializationError(err.to_string())
     }
 }
 
+/// Read a `Retry-After` header, given either in seconds or as an HTTP date.
+pub(crate) fn retry_after_from_headers(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+/// Parse a `Retry-After` value relative to `now`; a date in the past means
+/// no wait.
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&Utc) - now).to_std().unwrap_or(Duration::ZERO))
+}
+
 /// Convert LlmError to langgraph_core::error::GraphError for trait implementation.
-impl From<LlmError> for langgraph_core::error::GraphError {
+///
+/// Retryable errors become a retryable [`GraphError::Provider`] carrying
+/// [`LlmError::retry_after`], and permanent ones [`GraphError::Validation`],
+/// so the classification survives the conversion; see
+/// [`is_retryable_graph_error`].
+impl From<LlmError> for GraphError {
     fn from(err: LlmError) -> Self {
-        langgraph_core::error::GraphError::Validation(err.to_string())
+        if err.is_retryable() {
+            GraphError::provider(err.to_string(), true, err.retry_after())
+        } else {
+            GraphError::Validation(err.to_string())
+        }
+    }
+}
+
+/// Whether a `GraphError` returned by a provider is worth retrying.
+///
+/// Uses the classification a converted [`LlmError`] carries, and also
+/// accepts timeouts and I/O failures. Pass it to
+/// `RetryPolicy::with_retry_if` to retry LLM nodes only on transient failures.
+pub fn is_retryable_graph_error(error: &GraphError) -> bool {
+    match error {
+        GraphError::Provider { retryable, .. } => *retryable,
+        GraphError::Timeout { .. } | GraphError::Io(_) => true,
+        _ => false,
     }
 }
 
@@ -125,7 +267,7 @@ mod tests {
 
     #[test]
     fn test_service_unavailable_display() {
-        let err = LlmError::ServiceUnavailable("Ollama not running".to_string());
+        let err = LlmError::service_unavailable("Ollama not running", None);
         assert_eq!(
             err.to_string(),
             "Service unavailable: Ollama not running"
@@ -134,7 +276,7 @@ mod tests {
 
     #[test]
     fn test_rate_limit_exceeded_display() {
-        let err = LlmError::RateLimitExceeded("60 requests per minute".to_string());
+        let err = LlmError::rate_limited("60 requests per minute", None);
         assert_eq!(
             err.to_string(),
             "Rate limit exceeded: 60 requests per minute"
@@ -181,14 +323,138 @@ mod tests {
     fn test_is_retryable_service_and_network_errors() {
         // HttpError would be retryable, but we can't easily construct it without reqwest internals
         // So we verify the pattern matches correctly with what we can test
-        assert!(LlmError::ServiceUnavailable("test".to_string()).is_retryable());
+        assert!(LlmError::service_unavailable("test", None).is_retryable());
         assert!(LlmError::Timeout("test".to_string()).is_retryable());
-        assert!(LlmError::RateLimitExceeded("test".to_string()).is_retryable());
+        assert!(LlmError::rate_limited("test", None).is_retryable());
+    }
+
+    #[test]
+    fn test_rate_limited_keeps_retry_after() {
+        let hint = Duration::from_millis(7_500);
+        let err = LlmError::rate_limited("slow down", Some(hint));
+        assert_eq!(err.retry_after(), Some(hint));
+        assert_eq!(err.to_string(), "Rate limit exceeded: slow down");
+
+        // The hint survives conversion into GraphError
+        let graph_err: langgraph_core::error::GraphError = err.into();
+        assert_eq!(graph_err.retry_after(), Some(hint));
+
+        // A hint-like message is just text
+        let err = LlmError::rate_limited("retry after 3600 seconds", None);
+        assert_eq!(err.retry_after(), None);
+
+        let err = LlmError::rate_limited("slow down", None);
+        assert_eq!(err.retry_after(), None);
+        assert_eq!(err.to_string(), "Rate limit exceeded: slow down");
+    }
+
+    #[test]
+    fn test_retry_after_from_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after_from_headers(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "12".parse().unwrap());
+        assert_eq!(retry_after_from_headers(&headers), Some(Duration::from_secs(12)));
+
+        // A date in the past means no wait
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after_from_headers(&headers), Some(Duration::ZERO));
+
+        headers.insert(reqwest::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after_from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:29:30 GMT", now),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(parse_retry_after(" 5 ", now), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_from_status_classification() {
+        let cases = [
+            (StatusCode::BAD_REQUEST, false),
+            (StatusCode::UNAUTHORIZED, false),
+            (StatusCode::FORBIDDEN, false),
+            (StatusCode::NOT_FOUND, false),
+            (StatusCode::UNPROCESSABLE_ENTITY, false),
+            (StatusCode::REQUEST_TIMEOUT, true),
+            (StatusCode::TOO_MANY_REQUESTS, true),
+            (StatusCode::INTERNAL_SERVER_ERROR, true),
+            (StatusCode::BAD_GATEWAY, true),
+            (StatusCode::SERVICE_UNAVAILABLE, true),
+            (StatusCode::GATEWAY_TIMEOUT, true),
+        ];
+
+        for (status, retryable) in cases {
+            let err = LlmError::from_status("OpenAI", status, "body", None);
+            assert_eq!(err.is_retryable(), retryable, "{} -> {:?}", status, err);
+            assert_eq!(LlmError::is_retryable_status(status), retryable);
+            assert!(err.to_string().contains("OpenAI API error"));
+        }
+
+        assert!(LlmError::from_status("Claude", StatusCode::FORBIDDEN, "", None).is_auth_error());
+        assert!(matches!(
+            LlmError::from_status("Ollama", StatusCode::NOT_FOUND, "model 'x' not found", None),
+            LlmError::ModelNotFound(_)
+        ));
+        assert!(matches!(
+            LlmError::from_status("Gemini", StatusCode::BAD_REQUEST, "bad", None),
+            LlmError::InvalidRequest(_)
+        ));
+    }
+
+    #[test]
+    fn test_from_status_retry_after() {
+        let hint = Some(Duration::from_secs(20));
+
+        let err = LlmError::from_status("Grok", StatusCode::TOO_MANY_REQUESTS, "slow", hint);
+        assert_eq!(err.retry_after(), hint);
+
+        let err = LlmError::from_status("Grok", StatusCode::SERVICE_UNAVAILABLE, "busy", hint);
+        assert_eq!(err.retry_after(), hint);
+
+        // Permanent errors never ask for a retry
+        let err = LlmError::from_status("Grok", StatusCode::BAD_REQUEST, "bad", hint);
+        assert_eq!(err.retry_after(), None);
+
+        let err = LlmError::from_status("Grok", StatusCode::BAD_GATEWAY, "busy", None);
+        assert_eq!(err.retry_after(), None);
+    }
+
+    #[test]
+    fn test_graph_error_keeps_classification() {
+        let transient: GraphError = LlmError::Timeout("slow".to_string()).into();
+        assert!(matches!(transient, GraphError::Provider { retryable: true, .. }));
+        assert!(is_retryable_graph_error(&transient));
+
+        let permanent: GraphError = LlmError::AuthenticationError("bad key".to_string()).into();
+        assert!(!is_retryable_graph_error(&permanent));
+
+        // The graph's transient retry policy agrees
+        let policy = langgraph_core::retry::RetryPolicy::retry_on_transient();
+        for err in [
+            LlmError::Timeout("slow".to_string()),
+            LlmError::rate_limited("slow down", None),
+            LlmError::from_status("OpenAI", StatusCode::INTERNAL_SERVER_ERROR, "oops", None),
+        ] {
+            assert!(policy.is_retryable(&err.into()));
+        }
+        assert!(!policy.is_retryable(&LlmError::InvalidRequest("bad".to_string()).into()));
     }
 
     #[test]
     fn test_is_retryable_service_unavailable() {
-        let err = LlmError::ServiceUnavailable("down".to_string());
+        let err = LlmError::service_unavailable("down", None);
         assert!(err.is_retryable());
     }
 
@@ -200,7 +466,7 @@ mod tests {
 
     #[test]
     fn test_is_retryable_rate_limit() {
-        let err = LlmError::RateLimitExceeded("limit".to_string());
+        let err = LlmError::rate_limited("limit", None);
         assert!(err.is_retryable());
     }
 
@@ -246,7 +512,7 @@ mod tests {
         let err = LlmError::Timeout("test".to_string());
         assert!(!err.is_auth_error());
 
-        let err2 = LlmError::ServiceUnavailable("test".to_string());
+        let err2 = LlmError::service_unavailable("test", None);
         assert!(!err2.is_auth_error());
     }
 
@@ -277,8 +543,8 @@ mod tests {
         let _auth = LlmError::AuthenticationError("test".to_string());
         let _key = LlmError::ApiKeyNotFound("test".to_string());
         let _model = LlmError::ModelNotFound("test".to_string());
-        let _service = LlmError::ServiceUnavailable("test".to_string());
-        let _rate = LlmError::RateLimitExceeded("test".to_string());
+        let _service = LlmError::service_unavailable("test", None);
+        let _rate = LlmError::rate_limited("test", None);
         let _request = LlmError::InvalidRequest("test".to_string());
         let _response = LlmError::InvalidResponse("test".to_string());
         let _timeout = LlmError::Timeout("test".to_string());
@@ -326,9 +592,9 @@ mod tests {
     #[test]
     fn test_retryable_classification_comprehensive() {
         let retryable_errors = vec![
-            LlmError::ServiceUnavailable("test".to_string()),
+            LlmError::service_unavailable("test", None),
             LlmError::Timeout("test".to_string()),
-            LlmError::RateLimitExceeded("test".to_string()),
+            LlmError::rate_limited("test", None),
         ];
 
         for err in retryable_errors {
@@ -364,9 +630,9 @@ mod tests {
         }
 
         let non_auth_errors = vec![
-            LlmError::ServiceUnavailable("test".to_string()),
+            LlmError::service_unavailable("test", None),
             LlmError::Timeout("test".to_string()),
-            LlmError::RateLimitExceeded("test".to_string()),
+            LlmError::rate_limited("test", None),
             LlmError::ModelNotFound("test".to_string()),
             LlmError::InvalidRequest("test".to_string()),
             LlmError::InvalidResponse("test".to_string()),
@@ -398,7 +664,7 @@ mod tests {
     #[test]
     fn test_error_context_preservation() {
         let original_msg = "connection refused on port 11434";
-        let err = LlmError::ServiceUnavailable(original_msg.to_string());
+        let err = LlmError::service_unavailable(original_msg, None);
         let err_string = err.to_string();
 
         assert!(err_string.contains(original_msg));
@@ -136,9 +136,12 @@
 //! }
 //! ```
 
+pub mod cached;
 pub mod config;
 pub mod error;
 pub mod provider_utils;
+pub mod rate_limited;
+pub mod usage;
 
 #[macro_use]
 mod provider_macros;
@@ -150,14 +153,17 @@ pub mod local;
 pub mod remote;
 
 // Re-export commonly used types
+pub use cached::CachedModel;
 pub use config::{LocalLlmConfig, RemoteLlmConfig};
 pub use error::{LlmError, Result};
-pub use provider_utils::{ModelInfo, ProviderUtils};
+pub use provider_utils::{ModelInfo, ProviderSpec, ProviderUtils};
+pub use rate_limited::RateLimitedModel;
+pub use usage::{UsageAccumulator, UsageTrackingModel};
 
 // Re-export langgraph-core types for convenience
 pub use langgraph_core::llm::{
     ChatConfig, ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, ReasoningContent,
-    ReasoningMode, ToolCall, ToolDefinition, ToolResult, UsageMetadata,
+    ReasoningMode, ToolCall, ToolChoice, ToolDefinition, ToolResult, UsageMetadata,
 };
 pub use langgraph_core::Message;
 
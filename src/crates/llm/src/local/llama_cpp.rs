@@ -19,7 +19,7 @@
 //! ```
 
 use crate::config::LocalLlmConfig;
-use crate::error::{LlmError, Result};
+use crate::error::{retry_after_from_headers, LlmError, Result};
 use crate::provider_utils::{ModelInfo, ProviderUtils};
 use async_trait::async_trait;
 use langgraph_core::error::Result as GraphResult;
@@ -147,16 +147,13 @@ impl ChatModel for LlamaCppClient {
             .json(&req_body)
             .send()
             .await
-            .map_err(|e| LlmError::HttpError(e))?;
+            .map_err(LlmError::from)?;
 
         if !response.status().is_success() {
             let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
             let error_text = response.text().await.unwrap_or_default();
-            return Err(LlmError::ProviderError(format!(
-                "llama.cpp API error {}: {}",
-                status, error_text
-            ))
-            .into());
+            return Err(LlmError::from_status("llama.cpp", status, error_text, retry_after).into());
         }
 
         let cpp_resp: LlamaCppResponse = response
@@ -176,6 +173,10 @@ impl ChatModel for LlamaCppClient {
         Ok(self.check_health().await.unwrap_or(false))
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
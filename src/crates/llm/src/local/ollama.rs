@@ -19,17 +19,19 @@
 //! ```
 
 use crate::config::LocalLlmConfig;
-use crate::error::{LlmError, Result};
+use crate::error::{retry_after_from_headers, LlmError, Result};
 use crate::provider_utils::{ModelInfo, ProviderUtils};
 use async_trait::async_trait;
+use futures::{Stream, StreamExt};
 use langgraph_core::error::Result as GraphResult;
 use langgraph_core::llm::{
-    ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, UsageMetadata,
+    ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, MessageChunk, MessageChunkStream,
+    UsageMetadata,
 };
 use langgraph_core::{Message, MessageContent, MessageRole};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 /// Ollama client for local LLM inference.
 #[derive(Clone)]
@@ -74,8 +76,60 @@ impl OllamaClient {
         }
     }
 
+    /// Build the `/api/chat` request body.
+    fn build_request(&self, request: &ChatRequest, stream: bool) -> OllamaRequest {
+        let messages: Vec<OllamaMessage> = request
+            .messages
+            .iter()
+            .map(|m| self.convert_message(m))
+            .collect();
+
+        let mut options = HashMap::new();
+        if let Some(temp) = request.config.temperature {
+            options.insert("temperature", serde_json::Value::from(temp));
+        }
+        if let Some(top_p) = request.config.top_p {
+            options.insert("top_p", serde_json::Value::from(top_p));
+        }
+
+        OllamaRequest {
+            model: self.config.model.clone(),
+            messages,
+            stream,
+            options: if options.is_empty() {
+                None
+            } else {
+                Some(options)
+            },
+        }
+    }
+
+    /// Send a chat request and fail on non-success status codes.
+    async fn send(&self, req_body: &OllamaRequest) -> GraphResult<reqwest::Response> {
+        let url = format!("{}/api/chat", self.config.base_url);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(req_body)
+            .send()
+            .await
+            .map_err(LlmError::from)?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(LlmError::from_status("Ollama", status, error_text, retry_after).into());
+        }
+
+        Ok(response)
+    }
+
     /// Convert Ollama response to ChatResponse.
     fn convert_response(&self, ollama_resp: OllamaResponse) -> ChatResponse {
+        let usage = ollama_resp.usage();
+
         let message = Message {
             id: None,
             role: MessageRole::Assistant,
@@ -86,19 +140,6 @@ impl OllamaClient {
             metadata: None,
         };
 
-        let usage = if ollama_resp.prompt_eval_count.is_some() || ollama_resp.eval_count.is_some()
-        {
-            Some(UsageMetadata {
-                input_tokens: ollama_resp.prompt_eval_count.unwrap_or(0),
-                output_tokens: ollama_resp.eval_count.unwrap_or(0),
-                reasoning_tokens: None,
-                total_tokens: ollama_resp.prompt_eval_count.unwrap_or(0)
-                    + ollama_resp.eval_count.unwrap_or(0),
-            })
-        } else {
-            None
-        };
-
         let mut metadata = HashMap::new();
         metadata.insert(
             "model".to_string(),
@@ -123,50 +164,7 @@ impl OllamaClient {
 #[async_trait]
 impl ChatModel for OllamaClient {
     async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
-        let url = format!("{}/api/chat", self.config.base_url);
-
-        let messages: Vec<OllamaMessage> = request
-            .messages
-            .iter()
-            .map(|m| self.convert_message(m))
-            .collect();
-
-        let mut options = HashMap::new();
-        if let Some(temp) = request.config.temperature {
-            options.insert("temperature", serde_json::Value::from(temp));
-        }
-        if let Some(top_p) = request.config.top_p {
-            options.insert("top_p", serde_json::Value::from(top_p));
-        }
-
-        let req_body = OllamaRequest {
-            model: self.config.model.clone(),
-            messages,
-            stream: false,
-            options: if options.is_empty() {
-                None
-            } else {
-                Some(options)
-            },
-        };
-
-        let response = self
-            .client
-            .post(&url)
-            .json(&req_body)
-            .send()
-            .await
-            .map_err(|e| LlmError::HttpError(e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(LlmError::ProviderError(format!(
-                "Ollama API error {}: {}",
-                status, error_text
-            ))
-            .into());
-        }
+        let response = self.send(&self.build_request(&request, false)).await?;
 
         let ollama_resp: OllamaResponse = response
             .json()
@@ -176,15 +174,47 @@ impl ChatModel for OllamaClient {
         Ok(self.convert_response(ollama_resp))
     }
 
-    async fn stream(&self, _request: ChatRequest) -> GraphResult<ChatStreamResponse> {
-        // TODO: Implement streaming support
-        Err(LlmError::Other("Streaming not yet implemented for Ollama".to_string()).into())
+    /// Stream the response as Ollama generates it.
+    ///
+    /// `/api/chat` with `stream: true` returns one JSON object per line. Each
+    /// line becomes a content chunk; the closing `done: true` line becomes the
+    /// final chunk, whose metadata carries the `usage` (a serialized
+    /// `UsageMetadata`), `model`, and `done_reason`. An in-stream `error` line
+    /// ends the stream with a final chunk carrying `error` in its metadata; a
+    /// dropped connection is yielded as an `Err` item instead.
+    async fn stream(&self, request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+        let response = self.send(&self.build_request(&request, true)).await?;
+
+        let body = Box::pin(futures::stream::unfold(response, |mut response| async move {
+            match response.chunk().await {
+                Ok(Some(bytes)) => Some((Ok(bytes), response)),
+                Ok(None) => None,
+                Err(e) => Some((Err(LlmError::from(e)), response)),
+            }
+        }));
+
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "model".to_string(),
+            serde_json::Value::String(self.config.model.clone()),
+        );
+
+        Ok(ChatStreamResponse {
+            stream: ndjson_chunk_stream(body),
+            reasoning_stream: None,
+            usage: None,
+            metadata,
+        })
     }
 
     async fn is_available(&self) -> GraphResult<bool> {
         Ok(self.check_health().await.unwrap_or(false))
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
@@ -213,6 +243,8 @@ struct OllamaResponse {
     #[serde(default)]
     done: bool,
     #[serde(default)]
+    done_reason: Option<String>,
+    #[serde(default)]
     total_duration: Option<u64>,
     #[serde(default)]
     prompt_eval_count: Option<usize>,
@@ -220,6 +252,139 @@ struct OllamaResponse {
     eval_count: Option<usize>,
 }
 
+impl OllamaResponse {
+    /// Token counts, if Ollama reported any.
+    fn usage(&self) -> Option<UsageMetadata> {
+        if self.prompt_eval_count.is_none() && self.eval_count.is_none() {
+            return None;
+        }
+        Some(UsageMetadata::new(
+            self.prompt_eval_count.unwrap_or(0),
+            self.eval_count.unwrap_or(0),
+        ))
+    }
+}
+
+/// Incremental parser for Ollama's newline-delimited JSON stream.
+///
+/// Body chunks may split lines anywhere, so partial lines are buffered
+/// until their newline arrives.
+#[derive(Debug, Default)]
+struct NdjsonParser {
+    line: Vec<u8>,
+    done: bool,
+}
+
+impl NdjsonParser {
+    fn feed(&mut self, bytes: &[u8]) -> Vec<MessageChunk> {
+        let mut chunks = Vec::new();
+        for &byte in bytes {
+            if self.done {
+                break;
+            }
+            if byte == b'\n' {
+                let line = std::mem::take(&mut self.line);
+                chunks.extend(self.parse_line(&line));
+            } else {
+                self.line.push(byte);
+            }
+        }
+        chunks
+    }
+
+    /// Flush a trailing line without a newline, and close the stream if
+    /// Ollama never sent `done: true`.
+    fn finish(&mut self) -> Vec<MessageChunk> {
+        let line = std::mem::take(&mut self.line);
+        let mut chunks: Vec<MessageChunk> = self.parse_line(&line).into_iter().collect();
+        if !self.done {
+            self.done = true;
+            chunks.push(MessageChunk::new("").final_chunk());
+        }
+        chunks
+    }
+
+    fn parse_line(&mut self, line: &[u8]) -> Option<MessageChunk> {
+        let line = std::str::from_utf8(line).ok()?.trim();
+        if line.is_empty() || self.done {
+            return None;
+        }
+
+        let value: serde_json::Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!("Skipping malformed Ollama stream line: {}", e);
+                return None;
+            }
+        };
+
+        if let Some(error) = value.get("error").and_then(|e| e.as_str()) {
+            self.done = true;
+            return Some(
+                MessageChunk::new("")
+                    .with_metadata(serde_json::json!({ "error": error }))
+                    .final_chunk(),
+            );
+        }
+
+        let response: OllamaResponse = match serde_json::from_value(value) {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::warn!("Skipping unexpected Ollama stream line: {}", e);
+                return None;
+            }
+        };
+
+        let chunk = MessageChunk::new(response.message.content.clone());
+        if !response.done {
+            return Some(chunk);
+        }
+
+        self.done = true;
+        Some(
+            chunk
+                .with_metadata(serde_json::json!({
+                    "model": response.model,
+                    "done_reason": response.done_reason,
+                    "total_duration_ns": response.total_duration,
+                    "usage": response.usage(),
+                }))
+                .final_chunk(),
+        )
+    }
+}
+
+/// Turn a raw `/api/chat` response body into a chunk stream.
+///
+/// A body error ends the stream with that error rather than a final chunk,
+/// so a truncated response is not mistaken for a complete one.
+fn ndjson_chunk_stream<S, B>(body: S) -> MessageChunkStream
+where
+    S: Stream<Item = Result<B>> + Send + Unpin + 'static,
+    B: AsRef<[u8]>,
+{
+    let state = (body, NdjsonParser::default(), VecDeque::new());
+
+    Box::pin(futures::stream::unfold(state, |(mut body, mut parser, mut pending)| async move {
+        loop {
+            if let Some(chunk) = pending.pop_front() {
+                return Some((Ok(chunk), (body, parser, pending)));
+            }
+            if parser.done {
+                return None;
+            }
+            match body.next().await {
+                Some(Ok(bytes)) => pending.extend(parser.feed(bytes.as_ref())),
+                Some(Err(e)) => {
+                    parser.done = true;
+                    return Some((Err(e.into()), (body, parser, pending)));
+                }
+                None => pending.extend(parser.finish()),
+            }
+        }
+    }))
+}
+
 #[async_trait]
 impl ProviderUtils for OllamaClient {
     async fn ping(&self) -> Result<bool> {
@@ -400,6 +565,7 @@ mod tests {
                 content: "Hello there!".to_string(),
             },
             done: true,
+            done_reason: None,
             total_duration: Some(1500000000), // 1.5 seconds in nanoseconds
             prompt_eval_count: Some(10),
             eval_count: Some(25),
@@ -428,6 +594,7 @@ mod tests {
                 content: "Response without usage".to_string(),
             },
             done: true,
+            done_reason: None,
             total_duration: None,
             prompt_eval_count: None,
             eval_count: None,
@@ -451,6 +618,7 @@ mod tests {
                 content: "Partial usage data".to_string(),
             },
             done: true,
+            done_reason: None,
             total_duration: Some(2000000000),
             prompt_eval_count: Some(15),
             eval_count: None, // Only prompt eval available
@@ -510,6 +678,115 @@ mod tests {
         assert_eq!(client.config.base_url, "http://192.168.1.100:11434");
     }
 
+    /// Recorded `/api/chat` stream, one JSON object per line.
+    const RECORDED_NDJSON: &str = concat!(
+        r#"{"model":"llama3","created_at":"2024-05-01T10:00:00Z","message":{"role":"assistant","content":"The"},"done":false}"#, "\n",
+        r#"{"model":"llama3","created_at":"2024-05-01T10:00:00Z","message":{"role":"assistant","content":" sky"},"done":false}"#, "\n",
+        r#"{"model":"llama3","created_at":"2024-05-01T10:00:00Z","message":{"role":"assistant","content":" is blue."},"done":false}"#, "\n",
+        r#"{"model":"llama3","created_at":"2024-05-01T10:00:01Z","message":{"role":"assistant","content":""},"done":true,"done_reason":"stop","total_duration":512000000,"prompt_eval_count":26,"eval_count":4}"#, "\n",
+    );
+
+    async fn collect_chunks(body: Vec<Vec<u8>>) -> Vec<MessageChunk> {
+        ndjson_chunk_stream(futures::stream::iter(body.into_iter().map(Ok)))
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await
+    }
+
+    #[test]
+    fn test_stream_request_body() {
+        let config = LocalLlmConfig::new("http://localhost:11434", "llama3");
+        let client = OllamaClient::new(config);
+
+        let request = ChatRequest::new(vec![Message::human("Hi")]).with_temperature(0.2);
+        let body = serde_json::to_value(client.build_request(&request, true)).unwrap();
+
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["model"], "llama3");
+        assert_eq!(body["messages"][0]["content"], "Hi");
+        assert!((body["options"]["temperature"].as_f64().unwrap() - 0.2).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_stream_reconstructs_text_and_usage() {
+        // Split into small pieces so lines straddle body chunks
+        let body: Vec<Vec<u8>> = RECORDED_NDJSON
+            .as_bytes()
+            .chunks(11)
+            .map(|c| c.to_vec())
+            .collect();
+
+        let chunks = collect_chunks(body).await;
+
+        let mut buffer = langgraph_core::TokenBuffer::new();
+        chunks.iter().for_each(|c| buffer.push(c));
+        assert_eq!(buffer.content(), "The sky is blue.");
+        assert!(buffer.is_finished());
+
+        let last = chunks.last().unwrap();
+        assert!(last.is_final);
+        assert_eq!(chunks.iter().filter(|c| c.is_final).count(), 1);
+
+        let metadata = last.metadata.as_ref().unwrap();
+        let usage: UsageMetadata = serde_json::from_value(metadata["usage"].clone()).unwrap();
+        assert_eq!(usage, UsageMetadata::new(26, 4));
+        assert_eq!(metadata["done_reason"], "stop");
+        assert_eq!(metadata["model"], "llama3");
+    }
+
+    #[tokio::test]
+    async fn test_stream_without_done_line_still_finishes() {
+        // Connection drops mid-generation; trailing line has no newline
+        let body = vec![
+            br#"{"model":"llama3","message":{"role":"assistant","content":"Hel"},"done":false}"#.to_vec(),
+            b"\n".to_vec(),
+            br#"{"model":"llama3","message":{"role":"assistant","content":"lo"},"done":false}"#.to_vec(),
+        ];
+
+        let chunks = collect_chunks(body).await;
+        let text: String = chunks.iter().map(|c| c.content.as_str()).collect();
+
+        assert_eq!(text, "Hello");
+        assert!(chunks.last().unwrap().is_final);
+        assert!(chunks.last().unwrap().metadata.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_error_line() {
+        let body = vec![
+            b"{\"error\":\"model 'nope' not found\"}\n".to_vec(),
+            br#"{"model":"llama3","message":{"role":"assistant","content":"ignored"},"done":false}"#.to_vec(),
+        ];
+
+        let chunks = collect_chunks(body).await;
+
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].is_final);
+        assert_eq!(
+            chunks[0].metadata.as_ref().unwrap()["error"],
+            "model 'nope' not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_transport_error_is_yielded() {
+        let body: Vec<Result<Vec<u8>>> = vec![
+            Ok(br#"{"model":"llama3","message":{"role":"assistant","content":"Hel"},"done":false}"#.to_vec()),
+            Ok(b"\n".to_vec()),
+            Err(LlmError::service_unavailable("connection reset", None)),
+            Ok(br#"{"model":"llama3","message":{"role":"assistant","content":"lo"},"done":true}"#.to_vec()),
+        ];
+
+        let items: Vec<_> = ndjson_chunk_stream(futures::stream::iter(body)).collect().await;
+
+        assert_eq!(items.len(), 2);
+        let first = items[0].as_ref().unwrap();
+        assert_eq!(first.content, "Hel");
+        assert!(!first.is_final);
+        let err = items[1].as_ref().unwrap_err();
+        assert!(err.to_string().contains("connection reset"), "{}", err);
+    }
+
     // ============================================================
     // Future Implementation Tests (Marked #[ignore])
     // ============================================================
@@ -518,8 +795,7 @@ mod tests {
     ///
     /// Verifies that Ollama streaming returns token-by-token responses.
     ///
-    /// NOTE: Currently ignored - streaming not yet implemented for Ollama.
-    /// See line 179-182 in chat implementation.
+    /// NOTE: Currently ignored - requires running Ollama server with the model pulled.
     #[tokio::test]
     #[ignore]
     async fn test_streaming_basic() {
@@ -528,19 +804,11 @@ mod tests {
 
         let request = ChatRequest::new(vec![Message::human("Count to 5")]);
 
-        // TODO: Currently returns error "Streaming not yet implemented"
-        // Once implemented, should work like:
-        // let stream = client.stream(request).await.unwrap();
-        // while let Some(result) = stream.receiver.recv().await {
-        //     match result {
-        //         Ok(event) => { /* process streaming event */ },
-        //         Err(_) => break,
-        //     }
-        // }
-
-        // For now, just verify it returns an error
-        let result = client.stream(request).await;
-        assert!(result.is_err());
+        let response = client.stream(request).await.unwrap();
+        let chunks: Vec<MessageChunk> = response.stream.map(|chunk| chunk.unwrap()).collect().await;
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.last().unwrap().is_final);
     }
 
     /// Test: Health check / connection retry
@@ -1,7 +1,11 @@
 //! Provider utility functions for connection testing and model management.
+//!
+//! Also provides [`from_env`], which builds a boxed `ChatModel` for a
+//! provider named at runtime using the conventional environment variables.
 
-use crate::error::Result;
+use crate::error::{LlmError, Result};
 use async_trait::async_trait;
+use langgraph_core::llm::ChatModel;
 use serde::{Deserialize, Serialize};
 
 /// Information about an available model.
@@ -117,3 +121,325 @@ pub trait ProviderUtils: Send + Sync {
     fn current_model(&self) -> &str;
 }
 
+
+/// How [`from_env`] configures one provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderSpec {
+    /// Canonical provider name.
+    pub name: &'static str,
+
+    /// Alternative names accepted by [`from_env`].
+    pub aliases: &'static [&'static str],
+
+    /// Environment variable holding the API key (`None` for local servers).
+    pub api_key_env: Option<&'static str>,
+
+    /// Environment variable that overrides the base URL.
+    pub base_url_env: &'static str,
+
+    /// Environment variable that overrides the model.
+    pub model_env: &'static str,
+
+    /// Base URL used when `base_url_env` is unset.
+    pub default_base_url: &'static str,
+
+    /// Model used when `model_env` is unset.
+    pub default_model: &'static str,
+}
+
+/// Every provider [`from_env`] can build in this build of the crate.
+pub const SUPPORTED_PROVIDERS: &[ProviderSpec] = &[
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "openai",
+        aliases: &[],
+        api_key_env: Some("OPENAI_API_KEY"),
+        base_url_env: "OPENAI_BASE_URL",
+        model_env: "OPENAI_MODEL",
+        default_base_url: "https://api.openai.com/v1",
+        default_model: "gpt-4",
+    },
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "claude",
+        aliases: &["anthropic"],
+        api_key_env: Some("ANTHROPIC_API_KEY"),
+        base_url_env: "ANTHROPIC_BASE_URL",
+        model_env: "ANTHROPIC_MODEL",
+        default_base_url: "https://api.anthropic.com",
+        default_model: "claude-3-opus-20240229",
+    },
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "gemini",
+        aliases: &["google"],
+        api_key_env: Some("GOOGLE_API_KEY"),
+        base_url_env: "GEMINI_BASE_URL",
+        model_env: "GEMINI_MODEL",
+        default_base_url: "https://generativelanguage.googleapis.com/v1beta",
+        default_model: "gemini-pro",
+    },
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "grok",
+        aliases: &["xai"],
+        api_key_env: Some("XAI_API_KEY"),
+        base_url_env: "XAI_BASE_URL",
+        model_env: "XAI_MODEL",
+        default_base_url: "https://api.x.ai/v1",
+        default_model: "grok-beta",
+    },
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "deepseek",
+        aliases: &[],
+        api_key_env: Some("DEEPSEEK_API_KEY"),
+        base_url_env: "DEEPSEEK_BASE_URL",
+        model_env: "DEEPSEEK_MODEL",
+        default_base_url: "https://api.deepseek.com",
+        default_model: "deepseek-chat",
+    },
+    #[cfg(feature = "remote")]
+    ProviderSpec {
+        name: "openrouter",
+        aliases: &[],
+        api_key_env: Some("OPENROUTER_API_KEY"),
+        base_url_env: "OPENROUTER_BASE_URL",
+        model_env: "OPENROUTER_MODEL",
+        default_base_url: "https://openrouter.ai/api/v1",
+        default_model: "anthropic/claude-3-opus",
+    },
+    #[cfg(feature = "local")]
+    ProviderSpec {
+        name: "ollama",
+        aliases: &[],
+        api_key_env: None,
+        base_url_env: "OLLAMA_HOST",
+        model_env: "OLLAMA_MODEL",
+        default_base_url: "http://localhost:11434",
+        default_model: "llama2",
+    },
+    #[cfg(feature = "local")]
+    ProviderSpec {
+        name: "llama_cpp",
+        aliases: &["llama.cpp", "llamacpp"],
+        api_key_env: None,
+        base_url_env: "LLAMA_CPP_BASE_URL",
+        model_env: "LLAMA_CPP_MODEL",
+        default_base_url: "http://localhost:8080",
+        default_model: "default",
+    },
+    #[cfg(feature = "local")]
+    ProviderSpec {
+        name: "lmstudio",
+        aliases: &["lm_studio"],
+        api_key_env: None,
+        base_url_env: "LMSTUDIO_BASE_URL",
+        model_env: "LMSTUDIO_MODEL",
+        default_base_url: "http://localhost:1234/v1",
+        default_model: "local-model",
+    },
+];
+
+/// Canonical names of every provider [`from_env`] accepts.
+pub fn supported_providers() -> Vec<&'static str> {
+    SUPPORTED_PROVIDERS.iter().map(|spec| spec.name).collect()
+}
+
+impl ProviderSpec {
+    /// Look up a provider by name or alias, ignoring case and `-`/`_`.
+    pub fn find(provider: &str) -> Result<&'static ProviderSpec> {
+        let wanted = normalize(provider);
+        SUPPORTED_PROVIDERS
+            .iter()
+            .find(|spec| {
+                normalize(spec.name) == wanted
+                    || spec.aliases.iter().any(|alias| normalize(alias) == wanted)
+            })
+            .ok_or_else(|| {
+                LlmError::ConfigError(format!(
+                    "Unknown provider '{}'. Supported providers: {}",
+                    provider,
+                    supported_providers().join(", ")
+                ))
+            })
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_ascii_lowercase().replace('-', "_")
+}
+
+/// Build a client for `provider` from environment variables.
+///
+/// Reads the provider's API key from its conventional variable (for example
+/// `OPENAI_API_KEY` or `DEEPSEEK_API_KEY`); the base URL and model can be
+/// overridden with the variables listed in its [`ProviderSpec`]. Errors with
+/// [`LlmError::ApiKeyNotFound`] if the key is unset and
+/// [`LlmError::ConfigError`] if the provider is unknown.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use llm::provider_utils::from_env;
+///
+/// let model = from_env("deepseek")?;
+/// let response = model.chat(request).await?;
+/// ```
+pub fn from_env(provider: &str) -> Result<Box<dyn ChatModel>> {
+    build_from_lookup(provider, |var| std::env::var(var).ok())
+}
+
+fn build_from_lookup(
+    provider: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<Box<dyn ChatModel>> {
+    let spec = ProviderSpec::find(provider)?;
+    let base_url = lookup(spec.base_url_env).unwrap_or_else(|| spec.default_base_url.to_string());
+    let model = lookup(spec.model_env).unwrap_or_else(|| spec.default_model.to_string());
+
+    match spec.api_key_env {
+        Some(key_env) => {
+            let api_key = lookup(key_env)
+                .filter(|key| !key.is_empty())
+                .ok_or_else(|| {
+                    LlmError::ApiKeyNotFound(format!("Environment variable: {}", key_env))
+                })?;
+            build_remote(spec.name, api_key, base_url, model)
+        }
+        None => build_local(spec.name, base_url, model),
+    }
+}
+
+#[cfg(feature = "remote")]
+fn build_remote(
+    name: &str,
+    api_key: String,
+    base_url: String,
+    model: String,
+) -> Result<Box<dyn ChatModel>> {
+    use crate::config::RemoteLlmConfig;
+    use crate::remote::*;
+
+    let config = RemoteLlmConfig::new(api_key, base_url, model);
+    Ok(match name {
+        "openai" => Box::new(OpenAiClient::new(config)),
+        "claude" => Box::new(ClaudeClient::new(config)),
+        "gemini" => Box::new(GeminiClient::new(config)),
+        "grok" => Box::new(GrokClient::new(config)),
+        "deepseek" => Box::new(DeepseekClient::new(config)),
+        "openrouter" => Box::new(OpenRouterClient::new(config)),
+        other => unreachable!("remote provider '{}' has no client", other),
+    })
+}
+
+#[cfg(not(feature = "remote"))]
+fn build_remote(name: &str, _: String, _: String, _: String) -> Result<Box<dyn ChatModel>> {
+    unreachable!("remote provider '{}' listed without the remote feature", name)
+}
+
+#[cfg(feature = "local")]
+fn build_local(name: &str, base_url: String, model: String) -> Result<Box<dyn ChatModel>> {
+    use crate::config::LocalLlmConfig;
+    use crate::local::*;
+
+    // OLLAMA_HOST is commonly set without a scheme, e.g. "127.0.0.1:11434"
+    let base_url = if base_url.contains("://") {
+        base_url
+    } else {
+        format!("http://{}", base_url)
+    };
+    let config = LocalLlmConfig::new(base_url, model);
+    Ok(match name {
+        "ollama" => Box::new(OllamaClient::new(config)),
+        "llama_cpp" => Box::new(LlamaCppClient::new(config)),
+        "lmstudio" => Box::new(LmStudioClient::new(config)),
+        other => unreachable!("local provider '{}' has no client", other),
+    })
+}
+
+#[cfg(not(feature = "local"))]
+fn build_local(name: &str, _: String, _: String) -> Result<Box<dyn ChatModel>> {
+    unreachable!("local provider '{}' listed without the local feature", name)
+}
+
+#[cfg(all(test, feature = "local", feature = "remote"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |var| vars.get(var).cloned()
+    }
+
+    #[test]
+    fn test_supported_providers_lists_every_client() {
+        assert_eq!(
+            supported_providers(),
+            vec![
+                "openai", "claude", "gemini", "grok", "deepseek", "openrouter", "ollama",
+                "llama_cpp", "lmstudio"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_names_and_aliases() {
+        assert_eq!(ProviderSpec::find("openai").unwrap().name, "openai");
+        assert_eq!(ProviderSpec::find("Anthropic").unwrap().name, "claude");
+        assert_eq!(ProviderSpec::find("google").unwrap().name, "gemini");
+        assert_eq!(ProviderSpec::find("xAI").unwrap().name, "grok");
+        assert_eq!(ProviderSpec::find("llama-cpp").unwrap().name, "llama_cpp");
+        assert_eq!(
+            ProviderSpec::find(" DeepSeek ").unwrap().api_key_env,
+            Some("DEEPSEEK_API_KEY")
+        );
+    }
+
+    #[test]
+    fn test_unknown_provider_error_lists_supported() {
+        let err = build_from_lookup("mistral", env(&[])).err().unwrap();
+
+        assert!(matches!(err, LlmError::ConfigError(_)));
+        let message = err.to_string();
+        assert!(message.contains("Unknown provider 'mistral'"));
+        assert!(message.contains("openai"));
+        assert!(message.contains("ollama"));
+    }
+
+    #[test]
+    fn test_missing_api_key_names_variable() {
+        let err = build_from_lookup("deepseek", env(&[("OPENAI_API_KEY", "sk-other")]))
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, LlmError::ApiKeyNotFound(_)));
+        assert!(err.to_string().contains("DEEPSEEK_API_KEY"));
+    }
+
+    #[test]
+    fn test_builds_each_provider_from_its_key() {
+        for spec in SUPPORTED_PROVIDERS {
+            let vars: Vec<(&str, &str)> = spec
+                .api_key_env
+                .map(|key| (key, "test-key"))
+                .into_iter()
+                .collect();
+            assert!(
+                build_from_lookup(spec.name, env(&vars)).is_ok(),
+                "failed to build {}",
+                spec.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_api_key_is_rejected() {
+        let result = build_from_lookup("openai", env(&[("OPENAI_API_KEY", "")]));
+        assert!(matches!(result, Err(LlmError::ApiKeyNotFound(_))));
+    }
+}
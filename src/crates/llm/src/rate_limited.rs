@@ -0,0 +1,276 @@
+//! Retry and rate-limit wrapper for any `ChatModel`.
+//!
+//! Providers report throttling and transient failures as errors but leave
+//! recovery to the caller. [`RateLimitedModel`] centralizes that: it waits on
+//! a [`RateLimiter`] before each call and retries transient failures using a
+//! [`RetryPolicy`], honoring the provider's `Retry-After` hint when the error
+//! carries one (see [`LlmError::rate_limited`](crate::LlmError::rate_limited)).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use llm::remote::OpenAiClient;
+//! use llm::RateLimitedModel;
+//! use std::time::Duration;
+//! use tooling::async_utils::retry::RetryPolicy;
+//! use tooling::rate_limit::RateLimiter;
+//!
+//! let model = RateLimitedModel::new(OpenAiClient::new(config))
+//!     .with_rate_limiter(RateLimiter::new(60, Duration::from_secs(60)))
+//!     .with_retry_policy(RetryPolicy::new(5).with_initial_interval(1.0));
+//!
+//! let response = model.chat(request).await?;
+//! ```
+
+use async_trait::async_trait;
+use langgraph_core::error::{GraphError, Result as GraphResult};
+use langgraph_core::llm::{
+    ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, ToolDefinition,
+};
+use std::future::Future;
+use crate::error::is_retryable_graph_error;
+use tooling::async_utils::retry::RetryPolicy;
+use tooling::rate_limit::RateLimiter;
+
+/// A `ChatModel` that rate-limits and retries calls to an inner model.
+///
+/// Only errors [`LlmError::is_retryable`](crate::LlmError::is_retryable)
+/// classifies as transient (rate limits, timeouts, connection and 5xx
+/// failures) are retried; authentication and validation errors are
+/// returned immediately. For `stream()`, only establishing the stream is
+/// retried, not failures partway through it.
+#[derive(Clone)]
+pub struct RateLimitedModel<M> {
+    inner: M,
+    limiter: Option<RateLimiter>,
+    policy: RetryPolicy,
+}
+
+impl<M: ChatModel> RateLimitedModel<M> {
+    /// Wrap a model with the default retry policy (3 attempts) and no rate limit.
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            limiter: None,
+            policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Wait on this limiter before every attempt, including retries.
+    ///
+    /// `RateLimiter` is cheaply cloneable, so one limiter can be shared
+    /// between several models hitting the same account.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Set the retry policy used for transient failures.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Unwrap into the inner model.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    async fn call_with_retry<T, F, Fut>(&self, operation: F) -> GraphResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = GraphResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = &self.limiter {
+                limiter.acquire().await;
+            }
+
+            let error: GraphError = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            attempt += 1;
+            if !is_retryable_graph_error(&error) || !self.policy.should_retry(attempt) {
+                return Err(error);
+            }
+
+            let delay = error
+                .retry_after()
+                .unwrap_or_else(|| self.policy.calculate_delay(attempt - 1));
+            tracing::debug!(
+                "LLM call attempt {} failed: {}. Retrying in {:?}",
+                attempt,
+                error,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<M> ChatModel for RateLimitedModel<M>
+where
+    M: ChatModel + Clone + 'static,
+{
+    async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
+        self.call_with_retry(|| self.inner.chat(request.clone())).await
+    }
+
+    async fn stream(&self, request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+        self.call_with_retry(|| self.inner.stream(request.clone())).await
+    }
+
+    async fn is_available(&self) -> GraphResult<bool> {
+        self.inner.is_available().await
+    }
+
+    fn bound_tools(&self) -> Vec<ToolDefinition> {
+        self.inner.bound_tools()
+    }
+
+    fn model_name(&self) -> Option<String> {
+        self.inner.model_name()
+    }
+
+    fn clone_box(&self) -> Box<dyn ChatModel> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LlmError;
+    use langgraph_core::Message;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Fails with the given error for the first `failures` calls, then answers.
+    #[derive(Clone)]
+    struct FlakyModel {
+        calls: Arc<AtomicUsize>,
+        failures: usize,
+        error: fn() -> LlmError,
+    }
+
+    impl FlakyModel {
+        fn new(failures: usize, error: fn() -> LlmError) -> Self {
+            Self {
+                calls: Arc::new(AtomicUsize::new(0)),
+                failures,
+                error,
+            }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl ChatModel for FlakyModel {
+        async fn chat(&self, _request: ChatRequest) -> GraphResult<ChatResponse> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                return Err((self.error)().into());
+            }
+            Ok(ChatResponse {
+                message: Message::assistant("ok"),
+                usage: None,
+                reasoning: None,
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn stream(&self, _request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+            Err(LlmError::Other("not supported".to_string()).into())
+        }
+
+        fn clone_box(&self) -> Box<dyn ChatModel> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn fast_policy(max_attempts: usize) -> RetryPolicy {
+        RetryPolicy::new(max_attempts)
+            .with_initial_interval(0.001)
+            .with_jitter(false)
+    }
+
+    fn request() -> ChatRequest {
+        ChatRequest::new(vec![Message::human("hi")])
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let inner = FlakyModel::new(2, || LlmError::rate_limited("too many requests", None));
+        let limiter = RateLimiter::new(10, Duration::from_secs(60));
+        let model = RateLimitedModel::new(inner.clone())
+            .with_rate_limiter(limiter.clone())
+            .with_retry_policy(fast_policy(3));
+
+        let response = model.chat(request()).await.unwrap();
+
+        assert_eq!(response.message.text(), Some("ok"));
+        assert_eq!(inner.calls(), 3);
+        // Every attempt, including retries, went through the limiter
+        assert_eq!(limiter.available().await, 7);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let inner = FlakyModel::new(5, || LlmError::Timeout("upstream".to_string()));
+        let model = RateLimitedModel::new(inner.clone()).with_retry_policy(fast_policy(3));
+
+        let err = model.chat(request()).await.unwrap_err();
+
+        assert!(err.to_string().contains("Request timeout"));
+        assert_eq!(inner.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_permanent_errors() {
+        let inner = FlakyModel::new(1, || LlmError::AuthenticationError("bad key".to_string()));
+        let model = RateLimitedModel::new(inner.clone()).with_retry_policy(fast_policy(3));
+
+        assert!(model.chat(request()).await.is_err());
+        assert_eq!(inner.calls(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_honors_retry_after() {
+        let inner = FlakyModel::new(2, || {
+            LlmError::rate_limited("slow down", Some(Duration::from_secs(30)))
+        });
+        // The policy's own backoff is much shorter than the hint
+        let model = RateLimitedModel::new(inner.clone()).with_retry_policy(fast_policy(3));
+
+        let start = tokio::time::Instant::now();
+        model.chat(request()).await.unwrap();
+
+        assert_eq!(inner.calls(), 3);
+        assert!(start.elapsed() >= Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_boxed_clone_keeps_wrapper() {
+        let inner = FlakyModel::new(1, || LlmError::service_unavailable("down", None));
+        let model: Box<dyn ChatModel> =
+            Box::new(RateLimitedModel::new(inner.clone()).with_retry_policy(fast_policy(2)));
+
+        let cloned = model.clone();
+        assert!(cloned.chat(request()).await.is_ok());
+        assert_eq!(inner.calls(), 2);
+    }
+}
@@ -25,16 +25,18 @@
 //! let response = client.chat(request).await?;
 //! ```
 
+use super::tool_format;
 use crate::config::RemoteLlmConfig;
-use crate::error::LlmError;
+use crate::error::{retry_after_from_headers, LlmError};
 use async_trait::async_trait;
 use langgraph_core::error::Result as GraphResult;
 use langgraph_core::llm::{
     ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, UsageMetadata,
 };
-use langgraph_core::{Message, MessageContent, MessageRole};
+use langgraph_core::{Message, MessageContent, MessageRole, ToolCall};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 
 const ANTHROPIC_VERSION: &str = "2023-06-01";
@@ -76,26 +78,52 @@ impl ClaudeClient {
                 MessageRole::Human => {
                     claude_messages.push(ClaudeMessage {
                         role: "user".to_string(),
-                        content: msg.text().unwrap_or("").to_string(),
+                        content: Value::String(msg.text().unwrap_or("").to_string()),
                     });
                 }
                 MessageRole::Assistant => {
                     claude_messages.push(ClaudeMessage {
                         role: "assistant".to_string(),
-                        content: msg.text().unwrap_or("").to_string(),
+                        content: Self::assistant_content(msg),
                     });
                 }
                 MessageRole::Tool => {
-                    // Tool messages are converted to user messages with context
-                    claude_messages.push(ClaudeMessage {
-                        role: "user".to_string(),
-                        content: format!("[Tool Result] {}", msg.text().unwrap_or("")),
+                    let Some(tool_use_id) = &msg.tool_call_id else {
+                        // Without an id there is no tool_use block to answer
+                        claude_messages.push(ClaudeMessage {
+                            role: "user".to_string(),
+                            content: Value::String(format!(
+                                "[Tool Result] {}",
+                                msg.text().unwrap_or("")
+                            )),
+                        });
+                        continue;
+                    };
+                    let block = json!({
+                        "type": "tool_result",
+                        "tool_use_id": tool_use_id,
+                        "content": msg.text().unwrap_or(""),
                     });
+                    // Results for one assistant turn must share a single user message
+                    match claude_messages.last_mut() {
+                        Some(ClaudeMessage {
+                            role,
+                            content: Value::Array(blocks),
+                        }) if role == "user"
+                            && blocks.iter().all(|b| b["type"] == "tool_result") =>
+                        {
+                            blocks.push(block);
+                        }
+                        _ => claude_messages.push(ClaudeMessage {
+                            role: "user".to_string(),
+                            content: Value::Array(vec![block]),
+                        }),
+                    }
                 }
                 MessageRole::Custom(role) => {
                     claude_messages.push(ClaudeMessage {
                         role: role.clone(),
-                        content: msg.text().unwrap_or("").to_string(),
+                        content: Value::String(msg.text().unwrap_or("").to_string()),
                     });
                 }
             }
@@ -104,6 +132,52 @@ impl ClaudeClient {
         (system_prompt, claude_messages)
     }
 
+    /// Assistant content: plain text, or text plus `tool_use` blocks when
+    /// the message carries tool calls.
+    fn assistant_content(msg: &Message) -> Value {
+        let text = msg.text().unwrap_or("");
+        let calls = match &msg.tool_calls {
+            Some(calls) if !calls.is_empty() => calls,
+            _ => return Value::String(text.to_string()),
+        };
+
+        let mut blocks = Vec::with_capacity(calls.len() + 1);
+        if !text.is_empty() {
+            blocks.push(json!({"type": "text", "text": text}));
+        }
+        blocks.extend(calls.iter().map(|call| {
+            json!({
+                "type": "tool_use",
+                "id": call.id,
+                "name": call.name,
+                "input": call.args,
+            })
+        }));
+        Value::Array(blocks)
+    }
+
+    /// Build the `/v1/messages` request body.
+    fn build_request(&self, request: &ChatRequest) -> ClaudeRequest {
+        let (system, messages) = self.convert_messages(&request.messages);
+
+        ClaudeRequest {
+            model: self.config.model.clone(),
+            messages,
+            system,
+            max_tokens: request.config.max_tokens.unwrap_or(4096),
+            temperature: request.config.temperature,
+            top_p: request.config.top_p,
+            stop_sequences: if request.config.stop_sequences.is_empty() {
+                None
+            } else {
+                Some(request.config.stop_sequences.clone())
+            },
+            tools: tool_format::claude_tools(&request.config),
+            tool_choice: tool_format::claude_tool_choice(&request.config),
+            stream: false,
+        }
+    }
+
     /// Convert Claude response to ChatResponse.
     fn convert_response(&self, claude_resp: ClaudeResponse) -> ChatResponse {
         let content_text = claude_resp
@@ -119,12 +193,25 @@ impl ClaudeClient {
             .collect::<Vec<_>>()
             .join("");
 
+        let tool_calls: Vec<ToolCall> = claude_resp
+            .content
+            .iter()
+            .filter(|c| c.content_type == "tool_use")
+            .filter_map(|c| {
+                Some(ToolCall::new(
+                    c.id.clone()?,
+                    c.name.clone()?,
+                    c.input.clone().unwrap_or_else(|| serde_json::json!({})),
+                ))
+            })
+            .collect();
+
         let message = Message {
             id: Some(claude_resp.id),
             role: MessageRole::Assistant,
             content: MessageContent::Text(content_text),
             name: None,
-            tool_calls: None,
+            tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
             tool_call_id: None,
             metadata: None,
         };
@@ -158,22 +245,7 @@ impl ChatModel for ClaudeClient {
     async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
         let url = format!("{}/v1/messages", self.config.base_url);
 
-        let (system, messages) = self.convert_messages(&request.messages);
-
-        let req_body = ClaudeRequest {
-            model: self.config.model.clone(),
-            messages,
-            system,
-            max_tokens: request.config.max_tokens.unwrap_or(4096),
-            temperature: request.config.temperature,
-            top_p: request.config.top_p,
-            stop_sequences: if request.config.stop_sequences.is_empty() {
-                None
-            } else {
-                Some(request.config.stop_sequences.clone())
-            },
-            stream: false,
-        };
+        let req_body = self.build_request(&request);
 
         let response = self
             .client
@@ -184,20 +256,14 @@ impl ChatModel for ClaudeClient {
             .json(&req_body)
             .send()
             .await
-            .map_err(|e| LlmError::HttpError(e))?;
+            .map_err(LlmError::from)?;
 
         if !response.status().is_success() {
             let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
             let error_text = response.text().await.unwrap_or_default();
 
-            return Err(if status.as_u16() == 401 {
-                LlmError::AuthenticationError(error_text)
-            } else if status.as_u16() == 429 {
-                LlmError::RateLimitExceeded(error_text)
-            } else {
-                LlmError::ProviderError(format!("Claude API error {}: {}", status, error_text))
-            }
-            .into());
+            return Err(LlmError::from_status("Claude", status, error_text, retry_after).into());
         }
 
         let claude_resp: ClaudeResponse = response
@@ -213,6 +279,10 @@ impl ChatModel for ClaudeClient {
         Err(LlmError::Other("Streaming not yet implemented for Claude".to_string()).into())
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
@@ -232,13 +302,18 @@ struct ClaudeRequest {
     top_p: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<serde_json::Value>,
     stream: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ClaudeMessage {
     role: String,
-    content: String,
+    /// A plain string, or an array of content blocks.
+    content: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -258,7 +333,15 @@ struct ClaudeResponse {
 struct ClaudeContent {
     #[serde(rename = "type")]
     content_type: String,
+    #[serde(default)]
     text: Option<String>,
+    /// Set on `tool_use` blocks.
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    input: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -369,17 +452,60 @@ mod tests {
         );
         let client = ClaudeClient::new(config);
 
-        let mut tool_msg = Message::human("weather data");
-        tool_msg.role = MessageRole::Tool;
-
-        let messages = vec![tool_msg];
+        let messages = vec![
+            Message::human("Weather in Paris and Rome?"),
+            Message::assistant("").with_tool_calls(vec![
+                ToolCall::new("toolu_1", "get_weather", json!({"city": "Paris"})),
+                ToolCall::new("toolu_2", "get_weather", json!({"city": "Rome"})),
+            ]),
+            Message::tool("sunny", "toolu_1"),
+            Message::tool("rainy", "toolu_2"),
+        ];
 
         let (system, claude_msgs) = client.convert_messages(&messages);
 
         assert_eq!(system, None);
-        assert_eq!(claude_msgs.len(), 1);
-        assert_eq!(claude_msgs[0].role, "user");
-        assert_eq!(claude_msgs[0].content, "[Tool Result] weather data");
+        assert_eq!(claude_msgs.len(), 3);
+        assert_eq!(claude_msgs[1].role, "assistant");
+        assert_eq!(
+            claude_msgs[1].content,
+            json!([
+                {"type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": {"city": "Paris"}},
+                {"type": "tool_use", "id": "toolu_2", "name": "get_weather", "input": {"city": "Rome"}},
+            ])
+        );
+        assert_eq!(claude_msgs[2].role, "user");
+        assert_eq!(
+            claude_msgs[2].content,
+            json!([
+                {"type": "tool_result", "tool_use_id": "toolu_1", "content": "sunny"},
+                {"type": "tool_result", "tool_use_id": "toolu_2", "content": "rainy"},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_message_conversion_assistant_text_with_tool_call() {
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://api.anthropic.com",
+            "claude-3-sonnet-20240229",
+        );
+        let client = ClaudeClient::new(config);
+
+        let messages = vec![Message::assistant("Let me check.").with_tool_calls(vec![
+            ToolCall::new("toolu_1", "get_weather", json!({"city": "Paris"})),
+        ])];
+
+        let (_, claude_msgs) = client.convert_messages(&messages);
+
+        assert_eq!(
+            claude_msgs[0].content,
+            json!([
+                {"type": "text", "text": "Let me check."},
+                {"type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": {"city": "Paris"}},
+            ])
+        );
     }
 
     #[test]
@@ -424,6 +550,9 @@ mod tests {
             content: vec![ClaudeContent {
                 content_type: "text".to_string(),
                 text: Some("Hello there!".to_string()),
+                id: None,
+                name: None,
+                input: None,
             }],
             model: "claude-3-sonnet-20240229".to_string(),
             stop_reason: Some("end_turn".to_string()),
@@ -462,10 +591,16 @@ mod tests {
                 ClaudeContent {
                     content_type: "text".to_string(),
                     text: Some("First part. ".to_string()),
+                    id: None,
+                    name: None,
+                    input: None,
                 },
                 ClaudeContent {
                     content_type: "text".to_string(),
                     text: Some("Second part.".to_string()),
+                    id: None,
+                    name: None,
+                    input: None,
                 },
             ],
             model: "claude-3-opus-20240229".to_string(),
@@ -499,6 +634,9 @@ mod tests {
             content: vec![ClaudeContent {
                 content_type: "text".to_string(),
                 text: Some("Response".to_string()),
+                id: None,
+                name: None,
+                input: None,
             }],
             model: "claude-3-sonnet-20240229".to_string(),
             stop_reason: Some("max_tokens".to_string()),
@@ -538,6 +676,93 @@ mod tests {
         assert_eq!(ANTHROPIC_VERSION, "2023-06-01");
     }
 
+    #[test]
+    fn test_request_serialization_with_tools() {
+        use langgraph_core::llm::{ToolChoice, ToolDefinition};
+
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://api.anthropic.com",
+            "claude-3-sonnet-20240229",
+        );
+        let client = ClaudeClient::new(config);
+
+        let request = ChatRequest::new(vec![
+            Message::system("Use tools when helpful"),
+            Message::human("Weather in Paris?"),
+        ])
+        .with_max_tokens(512)
+        .with_tools(vec![ToolDefinition::new("get_weather", "Get current weather")
+            .with_parameters(serde_json::json!({
+                "type": "object",
+                "properties": {"city": {"type": "string"}},
+                "required": ["city"]
+            }))])
+        .with_tool_choice(ToolChoice::Named("get_weather".to_string()));
+
+        let body = serde_json::to_value(client.build_request(&request)).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "claude-3-sonnet-20240229",
+                "messages": [{"role": "user", "content": "Weather in Paris?"}],
+                "system": "Use tools when helpful",
+                "max_tokens": 512,
+                "tools": [{
+                    "name": "get_weather",
+                    "description": "Get current weather",
+                    "input_schema": {
+                        "type": "object",
+                        "properties": {"city": {"type": "string"}},
+                        "required": ["city"]
+                    }
+                }],
+                "tool_choice": {"type": "tool", "name": "get_weather"},
+                "stream": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_response_with_tool_use() {
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://api.anthropic.com",
+            "claude-3-sonnet-20240229",
+        );
+        let client = ClaudeClient::new(config);
+
+        let claude_resp: ClaudeResponse = serde_json::from_value(serde_json::json!({
+            "id": "msg_tools",
+            "type": "message",
+            "role": "assistant",
+            "content": [
+                {"type": "text", "text": "Checking the weather."},
+                {
+                    "type": "tool_use",
+                    "id": "toolu_01",
+                    "name": "get_weather",
+                    "input": {"city": "Paris"}
+                }
+            ],
+            "model": "claude-3-sonnet-20240229",
+            "stop_reason": "tool_use",
+            "stop_sequence": null,
+            "usage": {"input_tokens": 20, "output_tokens": 15}
+        }))
+        .unwrap();
+
+        let response = client.convert_response(claude_resp);
+
+        assert_eq!(response.message.text(), Some("Checking the weather."));
+        let calls = response.tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "toolu_01");
+        assert_eq!(calls[0].name, "get_weather");
+        assert_eq!(calls[0].arguments, serde_json::json!({"city": "Paris"}));
+    }
+
     // ============================================================
     // Future Implementation Tests (Marked #[ignore])
     // ============================================================
@@ -30,8 +30,9 @@
 //! }
 //! ```
 
+use super::tool_format::{self, OpenAiToolCall};
 use crate::config::RemoteLlmConfig;
-use crate::error::LlmError;
+use crate::error::{retry_after_from_headers, LlmError};
 use async_trait::async_trait;
 use langgraph_core::error::Result as GraphResult;
 use langgraph_core::llm::{
@@ -72,10 +73,14 @@ impl DeepseekClient {
                 MessageRole::System => "system".to_string(),
                 MessageRole::Human => "user".to_string(),
                 MessageRole::Assistant => "assistant".to_string(),
+                // Tool results without a call ID can't be matched to a call
+                MessageRole::Tool if msg.tool_call_id.is_some() => "tool".to_string(),
                 MessageRole::Tool => "user".to_string(),
                 MessageRole::Custom(role) => role.clone(),
             },
             content: msg.text().unwrap_or("").to_string(),
+            tool_calls: tool_format::openai_tool_calls(msg.tool_calls.as_ref()),
+            tool_call_id: msg.tool_call_id.clone(),
         }
     }
 
@@ -97,6 +102,33 @@ impl DeepseekClient {
         (content.to_string(), None)
     }
 
+    /// Build the `chat/completions` request body.
+    fn build_request(&self, request: &ChatRequest) -> DeepseekRequest {
+        let messages: Vec<DeepseekMessage> = request
+            .messages
+            .iter()
+            .map(|m| self.convert_message(m))
+            .collect();
+
+        DeepseekRequest {
+            model: self.config.model.clone(),
+            messages,
+            temperature: request.config.temperature,
+            max_tokens: request.config.max_tokens,
+            top_p: request.config.top_p,
+            frequency_penalty: request.config.frequency_penalty,
+            presence_penalty: request.config.presence_penalty,
+            stop: if request.config.stop_sequences.is_empty() {
+                None
+            } else {
+                Some(request.config.stop_sequences.clone())
+            },
+            tools: tool_format::openai_tools(&request.config),
+            tool_choice: tool_format::openai_tool_choice(&request.config),
+            stream: false,
+        }
+    }
+
     /// Convert Deepseek response to ChatResponse.
     fn convert_response(&self, request: &ChatRequest, deepseek_resp: DeepseekResponse) -> ChatResponse {
         let choice = &deepseek_resp.choices[0];
@@ -114,7 +146,7 @@ impl DeepseekClient {
             role: MessageRole::Assistant,
             content: MessageContent::Text(message_content),
             name: None,
-            tool_calls: None,
+            tool_calls: tool_format::parse_openai_tool_calls(choice.message.tool_calls.as_ref()),
             tool_call_id: None,
             metadata: None,
         };
@@ -155,27 +187,7 @@ impl ChatModel for DeepseekClient {
     async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
         let url = format!("{}/v1/chat/completions", self.config.base_url);
 
-        let messages: Vec<DeepseekMessage> = request
-            .messages
-            .iter()
-            .map(|m| self.convert_message(m))
-            .collect();
-
-        let req_body = DeepseekRequest {
-            model: self.config.model.clone(),
-            messages,
-            temperature: request.config.temperature,
-            max_tokens: request.config.max_tokens,
-            top_p: request.config.top_p,
-            frequency_penalty: request.config.frequency_penalty,
-            presence_penalty: request.config.presence_penalty,
-            stop: if request.config.stop_sequences.is_empty() {
-                None
-            } else {
-                Some(request.config.stop_sequences.clone())
-            },
-            stream: false,
-        };
+        let req_body = self.build_request(&request);
 
         let response = self
             .client
@@ -184,20 +196,14 @@ impl ChatModel for DeepseekClient {
             .json(&req_body)
             .send()
             .await
-            .map_err(|e| LlmError::HttpError(e))?;
+            .map_err(LlmError::from)?;
 
         if !response.status().is_success() {
             let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
             let error_text = response.text().await.unwrap_or_default();
 
-            return Err(if status.as_u16() == 401 {
-                LlmError::AuthenticationError(error_text)
-            } else if status.as_u16() == 429 {
-                LlmError::RateLimitExceeded(error_text)
-            } else {
-                LlmError::ProviderError(format!("Deepseek API error {}: {}", status, error_text))
-            }
-            .into());
+            return Err(LlmError::from_status("Deepseek", status, error_text, retry_after).into());
         }
 
         let deepseek_resp: DeepseekResponse = response
@@ -213,6 +219,10 @@ impl ChatModel for DeepseekClient {
         Err(LlmError::Other("Streaming not yet implemented for Deepseek".to_string()).into())
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
@@ -235,13 +245,22 @@ struct DeepseekRequest {
     presence_penalty: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<serde_json::Value>,
     stream: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct DeepseekMessage {
     role: String,
+    #[serde(default, deserialize_with = "tool_format::nullable_string")]
     content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_calls: Option<Vec<OpenAiToolCall>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_call_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -500,6 +519,8 @@ mod tests {
                 message: DeepseekMessage {
                     role: "assistant".to_string(),
                     content: "Hi there!".to_string(),
+                    tool_calls: None,
+                    tool_call_id: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -543,6 +564,8 @@ mod tests {
                 message: DeepseekMessage {
                     role: "assistant".to_string(),
                     content: "<think>Analyzing the problem...</think>Solution found.".to_string(),
+                    tool_calls: None,
+                    tool_call_id: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -589,6 +612,8 @@ mod tests {
                 message: DeepseekMessage {
                     role: "assistant".to_string(),
                     content: "<think>Hidden thinking</think>Answer".to_string(),
+                    tool_calls: None,
+                    tool_call_id: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -627,6 +652,8 @@ mod tests {
                 message: DeepseekMessage {
                     role: "assistant".to_string(),
                     content: "<think>Some text</think>Answer".to_string(),
+                    tool_calls: None,
+                    tool_call_id: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -720,5 +747,86 @@ mod tests {
         // let stream = client.stream(request).await.unwrap();
         // Verify reasoning is streamed before answer
     }
-}
 
+    #[test]
+    fn test_request_serialization_with_tools() {
+        use langgraph_core::llm::{ToolChoice, ToolDefinition};
+
+        let config = RemoteLlmConfig::new("test-key", "https://api.deepseek.com", "deepseek-chat");
+        let client = DeepseekClient::new(config);
+
+        let request = ChatRequest::new(vec![Message::human("What time is it?")])
+            .with_tools(vec![ToolDefinition::new("current_time", "Get the current time")])
+            .with_tool_choice(ToolChoice::Required);
+
+        let body = serde_json::to_value(client.build_request(&request)).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "deepseek-chat",
+                "messages": [{"role": "user", "content": "What time is it?"}],
+                "tools": [{
+                    "type": "function",
+                    "function": {
+                        "name": "current_time",
+                        "description": "Get the current time",
+                        "parameters": {"type": "object", "properties": {}}
+                    }
+                }],
+                "tool_choice": "required",
+                "stream": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_tool_result_message_conversion() {
+        let config = RemoteLlmConfig::new("test-key", "https://api.deepseek.com", "deepseek-chat");
+        let client = DeepseekClient::new(config);
+
+        let msg = Message::tool("12:00", "call_1");
+        let wire = serde_json::to_value(client.convert_message(&msg)).unwrap();
+
+        assert_eq!(
+            wire,
+            serde_json::json!({"role": "tool", "content": "12:00", "tool_call_id": "call_1"})
+        );
+    }
+
+    #[test]
+    fn test_response_with_tool_calls() {
+        let config = RemoteLlmConfig::new("test-key", "https://api.deepseek.com", "deepseek-chat");
+        let client = DeepseekClient::new(config);
+
+        let resp: DeepseekResponse = serde_json::from_value(serde_json::json!({
+            "id": "resp-1",
+            "object": "chat.completion",
+            "created": 1234567890,
+            "model": "deepseek-chat",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": {"name": "current_time", "arguments": "{}"}
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        }))
+        .unwrap();
+
+        let response = client.convert_response(&ChatRequest::new(vec![Message::human("What time is it?")]), resp);
+
+        let calls = response.tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].name, "current_time");
+        assert_eq!(calls[0].arguments, serde_json::json!({}));
+        assert_eq!(response.message.text(), Some(""));
+    }
+}
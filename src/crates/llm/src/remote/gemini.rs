@@ -22,14 +22,15 @@
 //! let response = client.chat(request).await?;
 //! ```
 
+use super::tool_format;
 use crate::config::RemoteLlmConfig;
-use crate::error::LlmError;
+use crate::error::{retry_after_from_headers, LlmError};
 use async_trait::async_trait;
 use langgraph_core::error::Result as GraphResult;
 use langgraph_core::llm::{
     ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, UsageMetadata,
 };
-use langgraph_core::{Message, MessageContent, MessageRole};
+use langgraph_core::{Message, MessageContent, MessageRole, ToolCall};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -57,6 +58,12 @@ impl GeminiClient {
     fn convert_messages(&self, messages: &[Message]) -> Vec<GeminiMessage> {
         let mut gemini_messages = Vec::new();
         let mut system_instruction = None;
+        let call_names: HashMap<&str, String> = messages
+            .iter()
+            .filter_map(|msg| msg.tool_calls.as_ref())
+            .flatten()
+            .map(|call| (call.id.as_str(), call.name.clone()))
+            .collect();
 
         for msg in messages {
             match &msg.role {
@@ -69,32 +76,63 @@ impl GeminiClient {
                         role: "user".to_string(),
                         parts: vec![GeminiPart {
                             text: msg.text().unwrap_or("").to_string(),
-                        }],
+                            function_call: None,
+                            function_response: None,                        }],
                     });
                 }
                 MessageRole::Assistant => {
                     gemini_messages.push(GeminiMessage {
                         role: "model".to_string(),
-                        parts: vec![GeminiPart {
-                            text: msg.text().unwrap_or("").to_string(),
-                        }],
+                        parts: Self::model_parts(msg),
                     });
                 }
                 MessageRole::Tool => {
-                    // Tool results are added as user messages with context
-                    gemini_messages.push(GeminiMessage {
-                        role: "user".to_string(),
-                        parts: vec![GeminiPart {
-                            text: format!("[Tool Result] {}", msg.text().unwrap_or("")),
-                        }],
+                    // Gemini matches responses to calls by function name
+                    let name = msg.name.clone().or_else(|| {
+                        msg.tool_call_id
+                            .as_ref()
+                            .and_then(|id| call_names.get(id.as_str()).cloned())
                     });
+                    let Some(name) = name else {
+                        gemini_messages.push(GeminiMessage {
+                            role: "user".to_string(),
+                            parts: vec![GeminiPart {
+                                text: format!("[Tool Result] {}", msg.text().unwrap_or("")),
+                                function_call: None,
+                                function_response: None,
+                            }],
+                        });
+                        continue;
+                    };
+                    let part = GeminiPart {
+                        text: String::new(),
+                        function_call: None,
+                        function_response: Some(GeminiFunctionResponse {
+                            name,
+                            response: Self::function_response_body(msg.text().unwrap_or("")),
+                        }),
+                    };
+                    // Responses to one model turn must share a single message
+                    match gemini_messages.last_mut() {
+                        Some(last)
+                            if last.role == "user"
+                                && last.parts.iter().all(|p| p.function_response.is_some()) =>
+                        {
+                            last.parts.push(part);
+                        }
+                        _ => gemini_messages.push(GeminiMessage {
+                            role: "user".to_string(),
+                            parts: vec![part],
+                        }),
+                    }
                 }
                 MessageRole::Custom(role) => {
                     gemini_messages.push(GeminiMessage {
                         role: role.clone(),
                         parts: vec![GeminiPart {
                             text: msg.text().unwrap_or("").to_string(),
-                        }],
+                            function_call: None,
+                            function_response: None,                        }],
                     });
                 }
             }
@@ -108,7 +146,8 @@ impl GeminiClient {
                     role: "user".to_string(),
                     parts: vec![GeminiPart {
                         text: format!("[System] {}", instruction),
-                    }],
+                        function_call: None,
+                        function_response: None,                    }],
                 },
             );
         }
@@ -116,6 +155,62 @@ impl GeminiClient {
         gemini_messages
     }
 
+    /// Model parts: the text, followed by one `functionCall` part per tool call.
+    fn model_parts(msg: &Message) -> Vec<GeminiPart> {
+        let text = msg.text().unwrap_or("");
+        let calls = msg.tool_calls.as_deref().unwrap_or_default();
+
+        let mut parts = Vec::with_capacity(calls.len() + 1);
+        if !text.is_empty() || calls.is_empty() {
+            parts.push(GeminiPart {
+                text: text.to_string(),
+                function_call: None,
+                function_response: None,
+            });
+        }
+        parts.extend(calls.iter().map(|call| GeminiPart {
+            text: String::new(),
+            function_call: Some(GeminiFunctionCall {
+                name: call.name.clone(),
+                args: call.args.clone(),
+            }),
+            function_response: None,
+        }));
+        parts
+    }
+
+    /// `functionResponse.response` must be an object: JSON object results are
+    /// passed through, anything else is wrapped as `{"content": ...}`.
+    fn function_response_body(text: &str) -> serde_json::Value {
+        match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(value @ serde_json::Value::Object(_)) => value,
+            _ => serde_json::json!({ "content": text }),
+        }
+    }
+
+    /// Build the `generateContent` request body.
+    fn build_request(&self, request: &ChatRequest) -> GeminiRequest {
+        let contents = self.convert_messages(&request.messages);
+
+        let generation_config = GeminiGenerationConfig {
+            temperature: request.config.temperature,
+            max_output_tokens: request.config.max_tokens,
+            top_p: request.config.top_p,
+            stop_sequences: if request.config.stop_sequences.is_empty() {
+                None
+            } else {
+                Some(request.config.stop_sequences.clone())
+            },
+        };
+
+        GeminiRequest {
+            contents,
+            generation_config: Some(generation_config),
+            tools: tool_format::gemini_tools(&request.config),
+            tool_config: tool_format::gemini_tool_config(&request.config),
+        }
+    }
+
     /// Convert Gemini response to ChatResponse.
     fn convert_response(&self, gemini_resp: GeminiResponse) -> ChatResponse {
         let candidate = &gemini_resp.candidates[0];
@@ -128,12 +223,22 @@ impl GeminiClient {
             .collect::<Vec<_>>()
             .join("");
 
+        // Gemini doesn't assign call IDs, so number them in emission order
+        let tool_calls: Vec<ToolCall> = candidate
+            .content
+            .parts
+            .iter()
+            .filter_map(|p| p.function_call.as_ref())
+            .enumerate()
+            .map(|(i, call)| ToolCall::new(format!("call_{}", i), call.name.clone(), call.args.clone()))
+            .collect();
+
         let message = Message {
             id: None,
             role: MessageRole::Assistant,
             content: MessageContent::Text(content_text),
             name: None,
-            tool_calls: None,
+            tool_calls: if tool_calls.is_empty() { None } else { Some(tool_calls) },
             tool_call_id: None,
             metadata: None,
         };
@@ -172,23 +277,7 @@ impl ChatModel for GeminiClient {
             self.config.base_url, self.config.model
         );
 
-        let contents = self.convert_messages(&request.messages);
-
-        let generation_config = GeminiGenerationConfig {
-            temperature: request.config.temperature,
-            max_output_tokens: request.config.max_tokens,
-            top_p: request.config.top_p,
-            stop_sequences: if request.config.stop_sequences.is_empty() {
-                None
-            } else {
-                Some(request.config.stop_sequences.clone())
-            },
-        };
-
-        let req_body = GeminiRequest {
-            contents,
-            generation_config: Some(generation_config),
-        };
+        let req_body = self.build_request(&request);
 
         // Gemini uses API key as query parameter
         let response = self
@@ -198,20 +287,14 @@ impl ChatModel for GeminiClient {
             .json(&req_body)
             .send()
             .await
-            .map_err(|e| LlmError::HttpError(e))?;
+            .map_err(LlmError::from)?;
 
         if !response.status().is_success() {
             let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
             let error_text = response.text().await.unwrap_or_default();
 
-            return Err(if status.as_u16() == 401 || status.as_u16() == 403 {
-                LlmError::AuthenticationError(error_text)
-            } else if status.as_u16() == 429 {
-                LlmError::RateLimitExceeded(error_text)
-            } else {
-                LlmError::ProviderError(format!("Gemini API error {}: {}", status, error_text))
-            }
-            .into());
+            return Err(LlmError::from_status("Gemini", status, error_text, retry_after).into());
         }
 
         let gemini_resp: GeminiResponse = response
@@ -227,6 +310,10 @@ impl ChatModel for GeminiClient {
         Err(LlmError::Other("Streaming not yet implemented for Gemini".to_string()).into())
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
@@ -238,6 +325,10 @@ struct GeminiRequest {
     contents: Vec<GeminiMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     generation_config: Option<GeminiGenerationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_config: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -248,7 +339,25 @@ struct GeminiMessage {
 
 #[derive(Debug, Serialize, Deserialize)]
 struct GeminiPart {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     text: String,
+    #[serde(rename = "functionCall", default, skip_serializing_if = "Option::is_none")]
+    function_call: Option<GeminiFunctionCall>,
+    #[serde(rename = "functionResponse", default, skip_serializing_if = "Option::is_none")]
+    function_response: Option<GeminiFunctionResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiFunctionCall {
+    name: String,
+    #[serde(default)]
+    args: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiFunctionResponse {
+    name: String,
+    response: serde_json::Value,
 }
 
 #[derive(Debug, Serialize)]
@@ -396,17 +505,62 @@ mod tests {
         );
         let client = GeminiClient::new(config);
 
-        let mut tool_msg = Message::human("weather data");
-        tool_msg.role = MessageRole::Tool;
+        let messages = vec![
+            Message::human("Weather in Paris and Rome?"),
+            Message::assistant("").with_tool_calls(vec![
+                ToolCall::new("call_0", "get_weather", serde_json::json!({"city": "Paris"})),
+                ToolCall::new("call_1", "get_weather", serde_json::json!({"city": "Rome"})),
+            ]),
+            Message::tool(r#"{"forecast": "sunny"}"#, "call_0"),
+            Message::tool("rainy", "call_1"),
+        ];
+
+        let gemini_msgs = client.convert_messages(&messages);
+
+        assert_eq!(gemini_msgs.len(), 3);
+        assert_eq!(
+            serde_json::to_value(&gemini_msgs[1]).unwrap(),
+            serde_json::json!({
+                "role": "model",
+                "parts": [
+                    {"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}},
+                    {"functionCall": {"name": "get_weather", "args": {"city": "Rome"}}},
+                ]
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&gemini_msgs[2]).unwrap(),
+            serde_json::json!({
+                "role": "user",
+                "parts": [
+                    {"functionResponse": {"name": "get_weather", "response": {"forecast": "sunny"}}},
+                    {"functionResponse": {"name": "get_weather", "response": {"content": "rainy"}}},
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_message_conversion_model_text_with_function_call() {
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "gemini-pro",
+        );
+        let client = GeminiClient::new(config);
 
-        let messages = vec![tool_msg];
+        let messages = vec![Message::assistant("Let me check.").with_tool_calls(vec![
+            ToolCall::new("call_0", "get_weather", serde_json::json!({"city": "Paris"})),
+        ])];
 
         let gemini_msgs = client.convert_messages(&messages);
 
-        // Tool results are converted to user messages with [Tool Result] prefix
-        assert_eq!(gemini_msgs.len(), 1);
-        assert_eq!(gemini_msgs[0].role, "user");
-        assert_eq!(gemini_msgs[0].parts[0].text, "[Tool Result] weather data");
+        assert_eq!(gemini_msgs[0].parts.len(), 2);
+        assert_eq!(gemini_msgs[0].parts[0].text, "Let me check.");
+        assert_eq!(
+            gemini_msgs[0].parts[1].function_call.as_ref().unwrap().name,
+            "get_weather"
+        );
     }
 
     #[test]
@@ -471,7 +625,8 @@ mod tests {
                 content: GeminiContent {
                     parts: vec![GeminiPart {
                         text: "Hello there!".to_string(),
-                    }],
+                        function_call: None,
+                        function_response: None,                    }],
                     role: "model".to_string(),
                 },
                 finish_reason: Some("STOP".to_string()),
@@ -508,10 +663,12 @@ mod tests {
                     parts: vec![
                         GeminiPart {
                             text: "First part. ".to_string(),
-                        },
+                            function_call: None,
+                            function_response: None,                        },
                         GeminiPart {
                             text: "Second part.".to_string(),
-                        },
+                            function_call: None,
+                            function_response: None,                        },
                     ],
                     role: "model".to_string(),
                 },
@@ -541,7 +698,8 @@ mod tests {
                 content: GeminiContent {
                     parts: vec![GeminiPart {
                         text: "Response".to_string(),
-                    }],
+                        function_call: None,
+                        function_response: None,                    }],
                     role: "model".to_string(),
                 },
                 finish_reason: Some("MAX_TOKENS".to_string()),
@@ -587,6 +745,82 @@ mod tests {
         assert_eq!(client.config.model, "gemini-pro-vision");
     }
 
+    #[test]
+    fn test_request_serialization_with_tools() {
+        use langgraph_core::llm::{ToolChoice, ToolDefinition};
+
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "gemini-pro",
+        );
+        let client = GeminiClient::new(config);
+
+        let request = ChatRequest::new(vec![Message::human("Weather in Paris?")])
+            .with_tools(vec![ToolDefinition::new("get_weather", "Get current weather")
+                .with_parameters(serde_json::json!({
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}}
+                }))])
+            .with_tool_choice(ToolChoice::Required);
+
+        let body = serde_json::to_value(client.build_request(&request)).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "contents": [{"role": "user", "parts": [{"text": "Weather in Paris?"}]}],
+                "generation_config": {},
+                "tools": [{
+                    "functionDeclarations": [{
+                        "name": "get_weather",
+                        "description": "Get current weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": {"city": {"type": "string"}}
+                        }
+                    }]
+                }],
+                "tool_config": {"functionCallingConfig": {"mode": "ANY"}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_response_with_function_calls() {
+        let config = RemoteLlmConfig::new(
+            "test-key",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "gemini-pro",
+        );
+        let client = GeminiClient::new(config);
+
+        let gemini_resp: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{
+                "content": {
+                    "role": "model",
+                    "parts": [
+                        {"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}},
+                        {"functionCall": {"name": "get_weather", "args": {"city": "Rome"}}}
+                    ]
+                },
+                "finishReason": "STOP"
+            }]
+        }))
+        .unwrap();
+
+        let response = client.convert_response(gemini_resp);
+
+        let calls = response.tool_calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_0");
+        assert_eq!(calls[0].name, "get_weather");
+        assert_eq!(calls[0].arguments, serde_json::json!({"city": "Paris"}));
+        assert_eq!(calls[1].id, "call_1");
+        assert_eq!(calls[1].arguments, serde_json::json!({"city": "Rome"}));
+        assert_eq!(response.message.text(), Some(""));
+    }
+
     // ============================================================
     // Future Implementation Tests (Marked #[ignore])
     // ============================================================
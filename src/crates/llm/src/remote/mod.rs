@@ -23,6 +23,8 @@ pub mod grok;
 pub mod deepseek;
 pub mod openrouter;
 
+pub(crate) mod tool_format;
+
 pub use claude::ClaudeClient;
 pub use openai::OpenAiClient;
 pub use gemini::GeminiClient;
@@ -24,8 +24,9 @@
 //! let response = client.chat(request).await?;
 //! ```
 
+use super::tool_format::{self, OpenAiToolCall};
 use crate::config::RemoteLlmConfig;
-use crate::error::LlmError;
+use crate::error::{retry_after_from_headers, LlmError};
 use async_trait::async_trait;
 use langgraph_core::error::Result as GraphResult;
 use langgraph_core::llm::{
@@ -67,6 +68,34 @@ impl OpenAiClient {
             content: Some(msg.text().unwrap_or("").to_string()),
             name: msg.name.clone(),
             tool_call_id: msg.tool_call_id.clone(),
+            tool_calls: tool_format::openai_tool_calls(msg.tool_calls.as_ref()),
+        }
+    }
+
+    /// Build the `chat.completions` request body.
+    fn build_request(&self, request: &ChatRequest) -> OpenAiRequest {
+        let messages: Vec<OpenAiMessage> = request
+            .messages
+            .iter()
+            .map(|m| self.convert_message(m))
+            .collect();
+
+        OpenAiRequest {
+            model: self.config.model.clone(),
+            messages,
+            temperature: request.config.temperature,
+            max_tokens: request.config.max_tokens,
+            top_p: request.config.top_p,
+            frequency_penalty: request.config.frequency_penalty,
+            presence_penalty: request.config.presence_penalty,
+            stop: if request.config.stop_sequences.is_empty() {
+                None
+            } else {
+                Some(request.config.stop_sequences.clone())
+            },
+            tools: tool_format::openai_tools(&request.config),
+            tool_choice: tool_format::openai_tool_choice(&request.config),
+            stream: false,
         }
     }
 
@@ -106,7 +135,7 @@ impl OpenAiClient {
             role: MessageRole::Assistant,
             content: MessageContent::Text(message_content),
             name: None,
-            tool_calls: None,
+            tool_calls: tool_format::parse_openai_tool_calls(choice.message.tool_calls.as_ref()),
             tool_call_id: None,
             metadata: None,
         };
@@ -147,27 +176,7 @@ impl ChatModel for OpenAiClient {
     async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
         let url = format!("{}/chat/completions", self.config.base_url);
 
-        let messages: Vec<OpenAiMessage> = request
-            .messages
-            .iter()
-            .map(|m| self.convert_message(m))
-            .collect();
-
-        let req_body = OpenAiRequest {
-            model: self.config.model.clone(),
-            messages,
-            temperature: request.config.temperature,
-            max_tokens: request.config.max_tokens,
-            top_p: request.config.top_p,
-            frequency_penalty: request.config.frequency_penalty,
-            presence_penalty: request.config.presence_penalty,
-            stop: if request.config.stop_sequences.is_empty() {
-                None
-            } else {
-                Some(request.config.stop_sequences.clone())
-            },
-            stream: false,
-        };
+        let req_body = self.build_request(&request);
 
         let mut req = self.client.post(&url).json(&req_body);
         
@@ -182,20 +191,14 @@ impl ChatModel for OpenAiClient {
         let response = req
             .send()
             .await
-            .map_err(|e| LlmError::HttpError(e))?;
+            .map_err(LlmError::from)?;
 
         if !response.status().is_success() {
             let status = response.status();
+            let retry_after = retry_after_from_headers(response.headers());
             let error_text = response.text().await.unwrap_or_default();
             
-            return Err(if status.as_u16() == 401 {
-                LlmError::AuthenticationError(error_text)
-            } else if status.as_u16() == 429 {
-                LlmError::RateLimitExceeded(error_text)
-            } else {
-                LlmError::ProviderError(format!("OpenAI API error {}: {}", status, error_text))
-            }
-            .into());
+            return Err(LlmError::from_status("OpenAI", status, error_text, retry_after).into());
         }
 
         let openai_resp: OpenAiResponse = response
@@ -211,6 +214,10 @@ impl ChatModel for OpenAiClient {
         Err(LlmError::Other("Streaming not yet implemented for OpenAI".to_string()).into())
     }
 
+    fn model_name(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
     fn clone_box(&self) -> Box<dyn ChatModel> {
         Box::new(self.clone())
     }
@@ -233,6 +240,10 @@ struct OpenAiRequest {
     presence_penalty: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<serde_json::Value>,
     stream: bool,
 }
 
@@ -245,6 +256,8 @@ struct OpenAiMessage {
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_call_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_calls: Option<Vec<OpenAiToolCall>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -406,6 +419,7 @@ mod tests {
                     content: Some("Hi there!".to_string()),
                     name: None,
                     tool_call_id: None,
+                    tool_calls: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -446,6 +460,7 @@ mod tests {
                     content: Some("The answer is 42".to_string()),
                     name: None,
                     tool_call_id: None,
+                    tool_calls: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -488,6 +503,7 @@ mod tests {
                     content: Some("<think>Let me analyze this step by step</think>The solution is X".to_string()),
                     name: None,
                     tool_call_id: None,
+                    tool_calls: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -524,6 +540,7 @@ mod tests {
                     content: Some("Simple answer without thinking".to_string()),
                     name: None,
                     tool_call_id: None,
+                    tool_calls: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -559,6 +576,7 @@ mod tests {
                     content: Some("<think>Hidden reasoning</think>Answer".to_string()),
                     name: None,
                     tool_call_id: None,
+                    tool_calls: None,
                 },
                 finish_reason: Some("stop".to_string()),
             }],
@@ -572,6 +590,118 @@ mod tests {
         assert_eq!(response.message.text(), Some("<think>Hidden reasoning</think>Answer"));
     }
 
+    #[test]
+    fn test_request_serialization_with_tools() {
+        use langgraph_core::llm::{ToolChoice, ToolDefinition};
+
+        let config = RemoteLlmConfig::new("test-key", "https://api.openai.com/v1", "gpt-4");
+        let client = OpenAiClient::new(config);
+
+        let request = ChatRequest::new(vec![Message::human("Weather in Paris?")])
+            .with_tools(vec![ToolDefinition::new("get_weather", "Get current weather")
+                .with_parameters(serde_json::json!({
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}},
+                    "required": ["city"]
+                }))])
+            .with_tool_choice(ToolChoice::Named("get_weather".to_string()));
+
+        let body = serde_json::to_value(client.build_request(&request)).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "gpt-4",
+                "messages": [{"role": "user", "content": "Weather in Paris?"}],
+                "tools": [{
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "description": "Get current weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": {"city": {"type": "string"}},
+                            "required": ["city"]
+                        }
+                    }
+                }],
+                "tool_choice": {"type": "function", "function": {"name": "get_weather"}},
+                "stream": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_request_serialization_without_tools() {
+        let config = RemoteLlmConfig::new("test-key", "https://api.openai.com/v1", "gpt-4");
+        let client = OpenAiClient::new(config);
+
+        let request = ChatRequest::new(vec![Message::human("Hi")]);
+        let body = serde_json::to_value(client.build_request(&request)).unwrap();
+
+        assert!(body.get("tools").is_none());
+        assert!(body.get("tool_choice").is_none());
+    }
+
+    #[test]
+    fn test_assistant_tool_calls_are_serialized() {
+        let config = RemoteLlmConfig::new("test-key", "https://api.openai.com/v1", "gpt-4");
+        let client = OpenAiClient::new(config);
+
+        let msg = Message::assistant("").with_tool_calls(vec![langgraph_core::ToolCall::new(
+            "call_1",
+            "get_weather",
+            serde_json::json!({"city": "Paris"}),
+        )]);
+        let wire = serde_json::to_value(client.convert_message(&msg)).unwrap();
+
+        assert_eq!(
+            wire["tool_calls"],
+            serde_json::json!([{
+                "id": "call_1",
+                "type": "function",
+                "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+            }])
+        );
+    }
+
+    #[test]
+    fn test_response_with_tool_calls() {
+        let config = RemoteLlmConfig::new("test-key", "https://api.openai.com/v1", "gpt-4");
+        let client = OpenAiClient::new(config);
+        let request = ChatRequest::new(vec![Message::human("Weather in Paris?")]);
+
+        let openai_response: OpenAiResponse = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-tools",
+            "object": "chat.completion",
+            "created": 1234567890,
+            "model": "gpt-4",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_abc",
+                        "type": "function",
+                        "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }],
+            "usage": null
+        }))
+        .unwrap();
+
+        let response = client.convert_response(&request, openai_response);
+
+        let calls = response.tool_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id, "call_abc");
+        assert_eq!(calls[0].name, "get_weather");
+        assert_eq!(calls[0].arguments, serde_json::json!({"city": "Paris"}));
+    }
+
     // ============================================================
     // Future Implementation Tests (Marked #[ignore])
     // ============================================================
@@ -0,0 +1,295 @@
+//! Provider wire formats for tool calling.
+//!
+//! `ChatRequest` carries provider-agnostic `ToolDefinition`s and a
+//! `ToolChoice`; each remote client translates them with the helpers here.
+//! Tool calls in responses are parsed back into `langgraph_core::ToolCall`s
+//! on the returned message so callers see the same shape for every provider.
+//!
+//! Three wire families are covered:
+//! - **OpenAI-compatible** (OpenAI, Grok, Deepseek, OpenRouter)
+//! - **Claude** (`tools` with `input_schema`, `tool_use` content blocks)
+//! - **Gemini** (`functionDeclarations`, `functionCall` parts)
+
+use langgraph_core::llm::{ChatConfig, ToolChoice, ToolDefinition};
+use langgraph_core::ToolCall;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Value};
+
+fn parameters_or_empty(tool: &ToolDefinition) -> Value {
+    tool.parameters
+        .clone()
+        .unwrap_or_else(|| json!({"type": "object", "properties": {}}))
+}
+
+/// Deserialize a string field that providers send as `null` when a message
+/// only carries tool calls.
+pub(crate) fn nullable_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+// ============================================================
+// OpenAI-compatible
+// ============================================================
+
+/// A tool call in OpenAI's `chat.completions` format.
+///
+/// `arguments` is a JSON-encoded string on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct OpenAiToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "function_type")]
+    pub call_type: String,
+    pub function: OpenAiFunctionCall,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct OpenAiFunctionCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: String,
+}
+
+fn function_type() -> String {
+    "function".to_string()
+}
+
+impl OpenAiToolCall {
+    /// Convert an outgoing assistant tool call to the wire format.
+    pub fn from_tool_call(call: &ToolCall) -> Self {
+        Self {
+            id: call.id.clone(),
+            call_type: function_type(),
+            function: OpenAiFunctionCall {
+                name: call.name.clone(),
+                arguments: call.args.to_string(),
+            },
+        }
+    }
+
+    /// Parse the wire format into a `ToolCall`.
+    ///
+    /// Arguments that are not valid JSON are kept as a JSON string so the
+    /// call is not lost; the tool will reject them when validating.
+    pub fn to_tool_call(&self) -> ToolCall {
+        let args = if self.function.arguments.trim().is_empty() {
+            json!({})
+        } else {
+            serde_json::from_str(&self.function.arguments)
+                .unwrap_or_else(|_| Value::String(self.function.arguments.clone()))
+        };
+        ToolCall::new(self.id.clone(), self.function.name.clone(), args)
+    }
+}
+
+/// `tools` array for OpenAI-compatible requests, or `None` when no tools are bound.
+pub(crate) fn openai_tools(config: &ChatConfig) -> Option<Vec<Value>> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    Some(
+        config
+            .tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "parameters": parameters_or_empty(tool),
+                    }
+                })
+            })
+            .collect(),
+    )
+}
+
+/// `tool_choice` for OpenAI-compatible requests, or `None` when no tools are bound.
+pub(crate) fn openai_tool_choice(config: &ChatConfig) -> Option<Value> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    Some(match &config.tool_choice {
+        ToolChoice::Auto => json!("auto"),
+        ToolChoice::None => json!("none"),
+        ToolChoice::Required => json!("required"),
+        ToolChoice::Named(name) => json!({"type": "function", "function": {"name": name}}),
+    })
+}
+
+/// Convert outgoing assistant tool calls, if any.
+pub(crate) fn openai_tool_calls(calls: Option<&Vec<ToolCall>>) -> Option<Vec<OpenAiToolCall>> {
+    calls
+        .filter(|calls| !calls.is_empty())
+        .map(|calls| calls.iter().map(OpenAiToolCall::from_tool_call).collect())
+}
+
+/// Parse response tool calls into `ToolCall`s, if any.
+pub(crate) fn parse_openai_tool_calls(calls: Option<&Vec<OpenAiToolCall>>) -> Option<Vec<ToolCall>> {
+    calls
+        .filter(|calls| !calls.is_empty())
+        .map(|calls| calls.iter().map(OpenAiToolCall::to_tool_call).collect())
+}
+
+// ============================================================
+// Claude
+// ============================================================
+
+/// `tools` array for Claude requests, or `None` when no tools are bound.
+pub(crate) fn claude_tools(config: &ChatConfig) -> Option<Vec<Value>> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    Some(
+        config
+            .tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "input_schema": parameters_or_empty(tool),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// `tool_choice` for Claude requests, or `None` when no tools are bound.
+pub(crate) fn claude_tool_choice(config: &ChatConfig) -> Option<Value> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    Some(match &config.tool_choice {
+        ToolChoice::Auto => json!({"type": "auto"}),
+        ToolChoice::None => json!({"type": "none"}),
+        ToolChoice::Required => json!({"type": "any"}),
+        ToolChoice::Named(name) => json!({"type": "tool", "name": name}),
+    })
+}
+
+// ============================================================
+// Gemini
+// ============================================================
+
+/// `tools` array for Gemini requests, or `None` when no tools are bound.
+pub(crate) fn gemini_tools(config: &ChatConfig) -> Option<Vec<Value>> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    let declarations: Vec<Value> = config
+        .tools
+        .iter()
+        .map(|tool| {
+            let mut declaration = json!({
+                "name": tool.name,
+                "description": tool.description,
+            });
+            if let Some(parameters) = &tool.parameters {
+                declaration["parameters"] = parameters.clone();
+            }
+            declaration
+        })
+        .collect();
+    Some(vec![json!({ "functionDeclarations": declarations })])
+}
+
+/// `toolConfig` for Gemini requests, or `None` when no tools are bound.
+pub(crate) fn gemini_tool_config(config: &ChatConfig) -> Option<Value> {
+    if config.tools.is_empty() {
+        return None;
+    }
+    let calling_config = match &config.tool_choice {
+        ToolChoice::Auto => json!({"mode": "AUTO"}),
+        ToolChoice::None => json!({"mode": "NONE"}),
+        ToolChoice::Required => json!({"mode": "ANY"}),
+        ToolChoice::Named(name) => json!({"mode": "ANY", "allowedFunctionNames": [name]}),
+    };
+    Some(json!({ "functionCallingConfig": calling_config }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openai_tool_call_round_trip() {
+        let call = ToolCall::new("call_1", "search", json!({"q": "rust"}));
+        let wire = OpenAiToolCall::from_tool_call(&call);
+
+        assert_eq!(wire.call_type, "function");
+        assert_eq!(wire.function.arguments, r#"{"q":"rust"}"#);
+
+        let parsed = wire.to_tool_call();
+        assert_eq!(parsed.id, "call_1");
+        assert_eq!(parsed.name, "search");
+        assert_eq!(parsed.args, json!({"q": "rust"}));
+    }
+
+    #[test]
+    fn test_openai_tool_call_tolerates_bad_arguments() {
+        let wire: OpenAiToolCall = serde_json::from_value(json!({
+            "id": "call_1",
+            "function": {"name": "search", "arguments": "{not json"}
+        }))
+        .unwrap();
+
+        assert_eq!(wire.to_tool_call().args, json!("{not json"));
+
+        let empty: OpenAiToolCall = serde_json::from_value(json!({
+            "id": "call_2",
+            "type": "function",
+            "function": {"name": "now", "arguments": ""}
+        }))
+        .unwrap();
+        assert_eq!(empty.to_tool_call().args, json!({}));
+    }
+
+    #[test]
+    fn test_no_tools_omits_everything() {
+        let config = ChatConfig {
+            tool_choice: ToolChoice::Required,
+            ..Default::default()
+        };
+
+        assert!(openai_tools(&config).is_none());
+        assert!(openai_tool_choice(&config).is_none());
+        assert!(claude_tools(&config).is_none());
+        assert!(claude_tool_choice(&config).is_none());
+        assert!(gemini_tools(&config).is_none());
+        assert!(gemini_tool_config(&config).is_none());
+    }
+
+    #[test]
+    fn test_tool_choice_mappings() {
+        let mut config = ChatConfig {
+            tools: vec![ToolDefinition::new("search", "Search")],
+            ..Default::default()
+        };
+
+        let cases = [
+            (ToolChoice::Auto, json!("auto"), json!({"type": "auto"}), json!({"mode": "AUTO"})),
+            (ToolChoice::None, json!("none"), json!({"type": "none"}), json!({"mode": "NONE"})),
+            (ToolChoice::Required, json!("required"), json!({"type": "any"}), json!({"mode": "ANY"})),
+            (
+                ToolChoice::Named("search".to_string()),
+                json!({"type": "function", "function": {"name": "search"}}),
+                json!({"type": "tool", "name": "search"}),
+                json!({"mode": "ANY", "allowedFunctionNames": ["search"]}),
+            ),
+        ];
+
+        for (choice, openai, claude, gemini) in cases {
+            config.tool_choice = choice;
+            assert_eq!(openai_tool_choice(&config), Some(openai));
+            assert_eq!(claude_tool_choice(&config), Some(claude));
+            assert_eq!(
+                gemini_tool_config(&config),
+                Some(json!({ "functionCallingConfig": gemini }))
+            );
+        }
+    }
+}
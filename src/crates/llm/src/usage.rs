@@ -0,0 +1,281 @@
+//! Token usage aggregation across many LLM calls.
+//!
+//! Each `ChatResponse` reports its own `UsageMetadata`; an agent loop makes
+//! many calls and usually wants the running total. [`UsageAccumulator`] sums
+//! usage from any number of calls and is cheaply cloneable, so the same
+//! totals can be shared between graph nodes and read once `invoke` returns.
+//!
+//! The simplest way to feed it is to wrap the model in a
+//! [`UsageTrackingModel`], which records every response automatically.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use llm::usage::{UsageAccumulator, UsageTrackingModel};
+//!
+//! let usage = UsageAccumulator::new();
+//! let model = Arc::new(UsageTrackingModel::new(client, usage.clone()));
+//!
+//! // ... build a graph whose nodes call `model` ...
+//! let result = graph.invoke(input).await?;
+//!
+//! let total = usage.total();
+//! println!("{} calls, {} tokens", usage.calls(), total.total_tokens);
+//! ```
+//!
+//! To keep the total in graph state instead, write `usage.total()` (which
+//! serializes like any `UsageMetadata`) under a state key from the node that
+//! makes the call, and read it back with [`UsageAccumulator::from_state`].
+
+use async_trait::async_trait;
+use langgraph_core::error::Result as GraphResult;
+use langgraph_core::llm::{
+    ChatModel, ChatRequest, ChatResponse, ChatStreamResponse, ToolDefinition, UsageMetadata,
+};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct UsageTotals {
+    usage: UsageMetadata,
+    calls: usize,
+    calls_without_usage: usize,
+}
+
+/// Running total of token usage, shared between clones.
+#[derive(Debug, Clone, Default)]
+pub struct UsageAccumulator {
+    totals: Arc<Mutex<UsageTotals>>,
+}
+
+impl UsageAccumulator {
+    /// Default graph state key used by [`from_state`](Self::from_state).
+    pub const STATE_KEY: &'static str = "usage";
+
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one call's usage.
+    ///
+    /// `None` still counts as a call, so providers that don't report usage
+    /// show up in [`calls_without_usage`](Self::calls_without_usage) rather
+    /// than silently skewing the totals.
+    pub fn record(&self, usage: Option<&UsageMetadata>) {
+        let mut totals = self.totals.lock().unwrap();
+        totals.calls += 1;
+        match usage {
+            Some(usage) => totals.usage.merge(usage),
+            None => totals.calls_without_usage += 1,
+        }
+    }
+
+    /// Record the usage reported by a chat response.
+    pub fn record_response(&self, response: &ChatResponse) {
+        self.record(response.usage.as_ref());
+    }
+
+    /// Summed usage across every recorded call.
+    pub fn total(&self) -> UsageMetadata {
+        self.totals.lock().unwrap().usage
+    }
+
+    /// Number of calls recorded.
+    pub fn calls(&self) -> usize {
+        self.totals.lock().unwrap().calls
+    }
+
+    /// Number of recorded calls that reported no usage.
+    pub fn calls_without_usage(&self) -> usize {
+        self.totals.lock().unwrap().calls_without_usage
+    }
+
+    /// Clear all recorded usage.
+    pub fn reset(&self) {
+        *self.totals.lock().unwrap() = UsageTotals::default();
+    }
+
+    /// Read a usage total stored in graph state under [`STATE_KEY`](Self::STATE_KEY).
+    pub fn from_state(state: &Value) -> Option<UsageMetadata> {
+        state
+            .get(Self::STATE_KEY)
+            .and_then(|usage| serde_json::from_value(usage.clone()).ok())
+    }
+}
+
+/// A `ChatModel` that records the usage of every call into a [`UsageAccumulator`].
+#[derive(Clone)]
+pub struct UsageTrackingModel<M> {
+    inner: M,
+    usage: UsageAccumulator,
+}
+
+impl<M: ChatModel> UsageTrackingModel<M> {
+    /// Wrap a model, recording into `usage`.
+    pub fn new(inner: M, usage: UsageAccumulator) -> Self {
+        Self { inner, usage }
+    }
+
+    /// The accumulator this model records into.
+    pub fn usage(&self) -> &UsageAccumulator {
+        &self.usage
+    }
+
+    /// Get the wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<M> ChatModel for UsageTrackingModel<M>
+where
+    M: ChatModel + Clone + 'static,
+{
+    async fn chat(&self, request: ChatRequest) -> GraphResult<ChatResponse> {
+        let response = self.inner.chat(request).await?;
+        self.usage.record_response(&response);
+        Ok(response)
+    }
+
+    async fn stream(&self, request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+        let response = self.inner.stream(request).await?;
+        self.usage.record(response.usage.as_ref());
+        Ok(response)
+    }
+
+    async fn is_available(&self) -> GraphResult<bool> {
+        self.inner.is_available().await
+    }
+
+    fn bound_tools(&self) -> Vec<ToolDefinition> {
+        self.inner.bound_tools()
+    }
+
+    fn model_name(&self) -> Option<String> {
+        self.inner.model_name()
+    }
+
+    fn clone_box(&self) -> Box<dyn ChatModel> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LlmError;
+    use langgraph_core::Message;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn response(usage: Option<UsageMetadata>) -> ChatResponse {
+        ChatResponse {
+            message: Message::assistant("ok"),
+            usage,
+            reasoning: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Replays a fixed list of usages, one per call.
+    #[derive(Clone)]
+    struct ScriptedModel {
+        usages: Arc<Vec<Option<UsageMetadata>>>,
+        next: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ChatModel for ScriptedModel {
+        async fn chat(&self, _request: ChatRequest) -> GraphResult<ChatResponse> {
+            let i = self.next.fetch_add(1, Ordering::SeqCst);
+            Ok(response(self.usages[i]))
+        }
+
+        async fn stream(&self, _request: ChatRequest) -> GraphResult<ChatStreamResponse> {
+            Err(LlmError::Other("not supported".to_string()).into())
+        }
+
+        fn clone_box(&self) -> Box<dyn ChatModel> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_sums_several_responses() {
+        let usage = UsageAccumulator::new();
+        usage.record_response(&response(Some(UsageMetadata::new(100, 20))));
+        usage.record_response(&response(Some(UsageMetadata::new(150, 30))));
+        usage.record_response(&response(Some(UsageMetadata::with_reasoning(50, 10, 40))));
+
+        let total = usage.total();
+        assert_eq!(total.input_tokens, 300);
+        assert_eq!(total.output_tokens, 60);
+        assert_eq!(total.reasoning_tokens, Some(40));
+        assert_eq!(total.total_tokens, 400);
+        assert_eq!(usage.calls(), 3);
+        assert_eq!(usage.calls_without_usage(), 0);
+    }
+
+    #[test]
+    fn test_missing_usage_is_counted_not_summed() {
+        let usage = UsageAccumulator::new();
+        usage.record_response(&response(None));
+        usage.record_response(&response(Some(UsageMetadata::new(10, 5))));
+        usage.record(None);
+
+        assert_eq!(usage.total(), UsageMetadata::new(10, 5));
+        assert_eq!(usage.calls(), 3);
+        assert_eq!(usage.calls_without_usage(), 2);
+
+        usage.reset();
+        assert_eq!(usage.total(), UsageMetadata::default());
+        assert_eq!(usage.calls(), 0);
+    }
+
+    #[test]
+    fn test_clones_share_totals() {
+        let usage = UsageAccumulator::new();
+        let node_handle = usage.clone();
+        node_handle.record(Some(&UsageMetadata::new(1, 2)));
+
+        assert_eq!(usage.total().total_tokens, 3);
+    }
+
+    #[test]
+    fn test_from_state() {
+        let usage = UsageAccumulator::new();
+        usage.record(Some(&UsageMetadata::new(7, 3)));
+
+        let mut state = serde_json::json!({ "messages": [] });
+        state[UsageAccumulator::STATE_KEY] = serde_json::to_value(usage.total()).unwrap();
+        assert_eq!(UsageAccumulator::from_state(&state), Some(UsageMetadata::new(7, 3)));
+        assert_eq!(UsageAccumulator::from_state(&serde_json::json!({})), None);
+    }
+
+    #[tokio::test]
+    async fn test_tracking_model_records_every_call() {
+        let inner = ScriptedModel {
+            usages: Arc::new(vec![
+                Some(UsageMetadata::new(10, 1)),
+                None,
+                Some(UsageMetadata::new(20, 2)),
+            ]),
+            next: Arc::new(AtomicUsize::new(0)),
+        };
+        let usage = UsageAccumulator::new();
+        let model: Arc<dyn ChatModel> = Arc::new(UsageTrackingModel::new(inner, usage.clone()));
+
+        for _ in 0..3 {
+            model
+                .chat(ChatRequest::new(vec![Message::human("hi")]))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(usage.total(), UsageMetadata::new(30, 3));
+        assert_eq!(usage.calls(), 3);
+        assert_eq!(usage.calls_without_usage(), 1);
+    }
+}
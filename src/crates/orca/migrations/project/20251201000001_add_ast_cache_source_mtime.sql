@@ -0,0 +1,3 @@
+-- Source file modification time (Unix milliseconds) recorded when the AST was cached.
+-- NULL for entries cached before this column existed; those are validated by content hash alone.
+ALTER TABLE ast_cache ADD COLUMN source_mtime INTEGER;
@@ -245,7 +245,7 @@ enum BugCommands {
     List,
     /// List bugs by status
     ListStatus {
-        /// Status to filter by: open, in_progress, fixed, wontfix, duplicate
+        /// Status to filter by: open, in_progress, fixed, wontfix, duplicate, closed, reopened
         status: String,
     },
     /// Show bug details
@@ -257,7 +257,7 @@ enum BugCommands {
     UpdateStatus {
         /// Bug ID
         id: String,
-        /// New status: open, in_progress, fixed, wontfix, duplicate
+        /// New status: open, in_progress, fixed, wontfix, duplicate, closed, reopened
         status: String,
     },
     /// Assign bug to someone
@@ -355,6 +355,19 @@ enum WorkflowCommands {
         /// Workflow ID
         id: String,
     },
+    /// Export a workflow and its tasks to a TOON file
+    Export {
+        /// Workflow ID
+        id: String,
+        /// Output file path
+        #[arg(short, long)]
+        out: std::path::PathBuf,
+    },
+    /// Import a workflow and its tasks from a TOON file
+    Import {
+        /// TOON file path
+        file: std::path::PathBuf,
+    },
 }
 
 #[tokio::main]
@@ -502,6 +515,12 @@ async fn main() -> anyhow::Result<()> {
                 WorkflowCommands::Resume { id } => {
                     orca::cli::workflow::handle_resume(db_manager, id).await?;
                 }
+                WorkflowCommands::Export { id, out } => {
+                    orca::cli::workflow::handle_export(db_manager, id, out).await?;
+                }
+                WorkflowCommands::Import { file } => {
+                    orca::cli::workflow::handle_import(db_manager, file).await?;
+                }
             }
             Ok(())
         }
@@ -265,14 +265,11 @@ pub async fn handle_update_status(
     let mut bug = repo.find_by_id(&id).await?;
 
     let old_status = bug.status.clone();
-    bug.status = status.clone();
-
-    // If marking as fixed, set resolved_at
-    if status == BugStatus::Fixed.as_str() {
-        bug.resolved_at = Some(Utc::now().timestamp());
+    let new_status = BugStatus::from(status.as_str());
+    if new_status.as_str() != status {
+        return Err(OrcaError::Other(format!("Unknown bug status '{}'", status)));
     }
-
-    bug.updated_at = Utc::now().timestamp();
+    bug.transition(new_status)?;
     repo.update(&bug).await?;
 
     println!("{}", "✓ Bug status updated".green().bold());
@@ -313,7 +310,7 @@ pub async fn handle_close(db_manager: Arc<DatabaseManager>, id: String) -> Resul
     let repo = BugRepository::new(project_db.clone());
     let mut bug = repo.find_by_id(&id).await?;
 
-    bug.mark_fixed();
+    bug.transition(BugStatus::Fixed)?;
     repo.update(&bug).await?;
 
     println!("{}", "✓ Bug marked as fixed".green().bold());
@@ -355,6 +352,8 @@ fn colorize_status(status: &str) -> colored::ColoredString {
         "fixed" => status.green(),
         "wontfix" => status.bright_black(),
         "duplicate" => status.bright_black(),
+        "closed" => status.bright_black(),
+        "reopened" => status.red(),
         _ => status.normal(),
     }
 }
@@ -4,6 +4,7 @@
 
 use crate::config::ConfigLoader;
 use crate::context::{ContextBuilder, ExecutionContext};
+use crate::db::manager::DatabaseManager;
 use crate::db::Database;
 use crate::error::{OrcaError, Result};
 use crate::init;
@@ -46,14 +47,19 @@ pub async fn get_or_create_context() -> Result<ExecutionContext> {
         .or_else(|| std::env::current_dir().ok())
         .ok_or_else(|| OrcaError::Config("Unable to determine workspace root".to_string()))?;
 
+    // Tool permissions live in the project database, if there is one
+    let db_manager = DatabaseManager::new(&workspace_root).await?;
+
     // Build execution context
     info!("Building execution context");
-    let context = ContextBuilder::new()
+    let mut builder = ContextBuilder::new()
         .with_database(database)
         .with_config(config)
-        .with_workspace_root(workspace_root)
-        .build()
-        .await?;
+        .with_workspace_root(workspace_root);
+    if let Some(project_db) = db_manager.project_db() {
+        builder = builder.with_project_database(project_db.clone());
+    }
+    let context = builder.build().await?;
 
     info!("Execution context ready");
     Ok(context)
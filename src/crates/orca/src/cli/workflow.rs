@@ -6,6 +6,7 @@ use crate::workflow::Workflow;
 use crate::DatabaseManager;
 use chrono::Utc;
 use colored::Colorize;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::info;
 
@@ -337,3 +338,70 @@ pub async fn handle_resume(db_manager: Arc<DatabaseManager>, id: String) -> Resu
 
     Ok(())
 }
+
+/// Handle workflow export command
+pub async fn handle_export(db_manager: Arc<DatabaseManager>, id: String, out: PathBuf) -> Result<()> {
+    let project_db = db_manager
+        .project_db()
+        .ok_or_else(|| OrcaError::Other("No project database. Run 'orca init' in a project directory.".to_string()))?;
+
+    let workflow_repo = WorkflowRepository::new(project_db.clone());
+    let task_repo = TaskRepository::new(project_db.clone());
+
+    // Load workflow and its tasks in sequence order
+    let workflow = workflow_repo.find_by_id(&id).await?;
+    let task_ids = workflow_repo.get_task_ids(&workflow.id).await?;
+
+    let mut tasks = Vec::with_capacity(task_ids.len());
+    for task_id in &task_ids {
+        tasks.push(task_repo.find_by_id(task_id).await?);
+    }
+
+    let toon = workflow.to_toon(&tasks)?;
+    std::fs::write(&out, toon)?;
+
+    println!("{}", "✓ Workflow exported successfully".green().bold());
+    println!("  Workflow: {}", workflow.id);
+    println!("  Tasks: {}", tasks.len());
+    println!("  File: {}", out.display());
+
+    Ok(())
+}
+
+/// Handle workflow import command
+pub async fn handle_import(db_manager: Arc<DatabaseManager>, file: PathBuf) -> Result<()> {
+    let project_db = db_manager
+        .project_db()
+        .ok_or_else(|| OrcaError::Other("No project database. Run 'orca init' in a project directory.".to_string()))?;
+
+    let workflow_repo = WorkflowRepository::new(project_db.clone());
+    let task_repo = TaskRepository::new(project_db.clone());
+
+    let input = std::fs::read_to_string(&file)?;
+    let export = Workflow::from_toon(&input)?;
+
+    // Refuse to overwrite an existing workflow
+    if workflow_repo.exists(&export.workflow.id).await? {
+        println!("{}", format!("✗ Workflow already exists: {}", export.workflow.id).red());
+        return Ok(());
+    }
+
+    workflow_repo.save(&export.workflow).await?;
+
+    // Save tasks and link them in their exported order
+    for (sequence, task) in export.tasks.iter().enumerate() {
+        if task_repo.exists(&task.id).await? {
+            task_repo.update(task).await?;
+        } else {
+            task_repo.save(task).await?;
+        }
+        workflow_repo.add_task(&export.workflow.id, &task.id, sequence as i32).await?;
+    }
+
+    println!("{}", "✓ Workflow imported successfully".green().bold());
+    println!("  ID: {}", export.workflow.id);
+    println!("  Name: {}", export.workflow.name);
+    println!("  Tasks: {}", export.tasks.len());
+
+    Ok(())
+}
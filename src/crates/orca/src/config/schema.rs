@@ -200,6 +200,10 @@ pub struct LoggingConfig {
 
     /// Show timestamps
     pub timestamps: bool,
+
+    /// Where execution events are written
+    #[serde(default)]
+    pub event_sink: EventSinkConfig,
 }
 
 impl Default for LoggingConfig {
@@ -209,10 +213,35 @@ impl Default for LoggingConfig {
             format: "compact".to_string(),
             colored: true,
             timestamps: true,
+            event_sink: EventSinkConfig::default(),
         }
     }
 }
 
+/// Destination for execution events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventSinkConfig {
+    /// Log through `tracing` alongside other log output
+    #[default]
+    Tracing,
+
+    /// Print one JSON object per event to stdout
+    StdoutJson,
+
+    /// Append one JSON object per event to a file
+    File {
+        /// File to append to (created if missing)
+        path: PathBuf,
+    },
+
+    /// Keep the most recent events in memory
+    Memory {
+        /// Maximum number of events kept
+        capacity: usize,
+    },
+}
+
 /// Budget configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BudgetConfig {
@@ -509,4 +538,41 @@ mod tests {
         assert_eq!(config.max_retry_delay_secs, 60);
         assert_eq!(config.retry_multiplier, 2.0);
     }
+
+    #[test]
+    fn test_event_sink_config_deserializes() {
+        let toml = r#"
+            level = "info"
+            format = "json"
+            colored = false
+            timestamps = true
+
+            [event_sink]
+            type = "file"
+            path = "/var/log/orca/events.jsonl"
+        "#;
+
+        let config: LoggingConfig = toml::from_str(toml).unwrap();
+
+        assert_eq!(
+            config.event_sink,
+            EventSinkConfig::File {
+                path: PathBuf::from("/var/log/orca/events.jsonl")
+            }
+        );
+    }
+
+    #[test]
+    fn test_event_sink_defaults_to_tracing() {
+        let toml = r#"
+            level = "info"
+            format = "compact"
+            colored = true
+            timestamps = true
+        "#;
+
+        let config: LoggingConfig = toml::from_str(toml).unwrap();
+
+        assert_eq!(config.event_sink, EventSinkConfig::Tracing);
+    }
 }
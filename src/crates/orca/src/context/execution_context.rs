@@ -3,17 +3,20 @@
 //! Provides unified access to all resources needed during execution.
 
 use crate::config::OrcaConfig;
-use crate::context::SessionInfo;
+use crate::context::{ContextFile, SessionInfo, TokenBudget};
 use crate::db::Database;
 use crate::error::{OrcaError, Result};
 use crate::events::EventLogger;
 use crate::executor::{LlmProvider, TaskExecutor};
-use crate::repositories::{TaskRepository, WorkflowRepository};
+use crate::repositories::{
+    ProjectRuleRepository, TaskRepository, ToolPermissionRepository, WorkflowRepository,
+};
 use crate::shutdown::ShutdownCoordinator;
-use crate::tools::DirectToolBridge;
+use crate::tools::{DirectToolBridge, RuleEngine, SessionPermissions};
+use langgraph_core::messages::Message;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{debug, info, warn};
 
 /// Execution context that provides access to all resources
 #[derive(Clone, Debug)]
@@ -47,6 +50,12 @@ pub struct ExecutionContext {
 
     /// Event logger for execution observability
     event_logger: EventLogger,
+
+    /// Conversation history, trimmed to the token budget
+    history: Vec<Message>,
+
+    /// Included files, trimmed to the token budget
+    files: Vec<ContextFile>,
 }
 
 impl ExecutionContext {
@@ -114,14 +123,33 @@ impl ExecutionContext {
     pub fn event_logger(&self) -> &EventLogger {
         &self.event_logger
     }
+
+    /// Get the conversation history included in the context
+    pub fn history(&self) -> &[Message] {
+        &self.history
+    }
+
+    /// Get the files included in the context
+    pub fn files(&self) -> &[ContextFile] {
+        &self.files
+    }
+
+    /// Tokens left in the session budget, or `None` when unlimited
+    pub fn remaining_budget(&self) -> Option<usize> {
+        self.session.remaining_budget()
+    }
 }
 
 /// Builder for creating execution contexts
 pub struct ContextBuilder {
     session: Option<SessionInfo>,
     database: Option<Arc<Database>>,
+    project_database: Option<Arc<Database>>,
     config: Option<OrcaConfig>,
     workspace_root: Option<PathBuf>,
+    history: Vec<Message>,
+    files: Vec<ContextFile>,
+    token_budget: Option<TokenBudget>,
 }
 
 impl ContextBuilder {
@@ -130,8 +158,12 @@ impl ContextBuilder {
         Self {
             session: None,
             database: None,
+            project_database: None,
             config: None,
             workspace_root: None,
+            history: Vec::new(),
+            files: Vec::new(),
+            token_budget: None,
         }
     }
 
@@ -147,6 +179,15 @@ impl ContextBuilder {
         self
     }
 
+    /// Set the project database that tool permissions and rules are loaded from
+    ///
+    /// Without one, or if it configures no tool permissions, every tool is allowed
+    /// (project rules still apply).
+    pub fn with_project_database(mut self, database: Arc<Database>) -> Self {
+        self.project_database = Some(database);
+        self
+    }
+
     /// Set configuration
     pub fn with_config(mut self, config: OrcaConfig) -> Self {
         self.config = Some(config);
@@ -159,8 +200,32 @@ impl ContextBuilder {
         self
     }
 
+    /// Include conversation history, oldest first
+    pub fn with_history(mut self, history: Vec<Message>) -> Self {
+        self.history.extend(history);
+        self
+    }
+
+    /// Include a file
+    pub fn with_file(mut self, file: ContextFile) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Limit the tokens used by included files and history
+    ///
+    /// Defaults to the session's token budget, if it has one.
+    pub fn with_token_budget(mut self, budget: TokenBudget) -> Self {
+        self.token_budget = Some(budget);
+        self
+    }
+
     /// Build the execution context
     ///
+    /// When a token budget is set, files are charged first and history gets
+    /// what is left; within each, the oldest content is dropped first. The
+    /// session records the budget and the tokens used.
+    ///
     /// # Returns
     /// A fully initialized ExecutionContext
     ///
@@ -168,7 +233,7 @@ impl ContextBuilder {
     /// Returns error if required components are missing or initialization fails
     pub async fn build(self) -> Result<ExecutionContext> {
         // Get or create session
-        let session = self.session.unwrap_or_else(SessionInfo::new);
+        let mut session = self.session.unwrap_or_else(SessionInfo::new);
 
         // Get database (required)
         let database = self.database
@@ -190,11 +255,51 @@ impl ContextBuilder {
             "Initializing execution context"
         );
 
-        // Create tool bridge
-        let tool_bridge = Arc::new(
-            DirectToolBridge::new(workspace_root, session.session_id.clone())
-                .map_err(|e| OrcaError::ToolExecution(format!("Failed to create tool bridge: {}", e)))?
-        );
+        // Trim included content to the token budget
+        let budget = self
+            .token_budget
+            .or_else(|| session.remaining_budget().map(TokenBudget::new));
+        let (files, history) = match budget {
+            Some(mut budget) => {
+                let (file_count, message_count) = (self.files.len(), self.history.len());
+                let files = budget.trim_files(self.files);
+                let history = budget.trim_history(self.history);
+                if files.len() < file_count || history.len() < message_count {
+                    warn!(
+                        dropped_files = file_count - files.len(),
+                        dropped_messages = message_count - history.len(),
+                        max_tokens = budget.max_tokens(),
+                        "Trimmed execution context to token budget"
+                    );
+                }
+                session.token_budget = Some(session.tokens_used + budget.max_tokens());
+                session.record_tokens(budget.used());
+                (files, history)
+            }
+            None => (self.files, self.history),
+        };
+
+        // Create shutdown coordinator
+        let shutdown_coordinator = Arc::new(ShutdownCoordinator::new());
+
+        // Create tool bridge, registering executions for shutdown draining
+        let mut tool_bridge = DirectToolBridge::new(workspace_root, session.session_id.clone())
+            .map_err(|e| OrcaError::ToolExecution(format!("Failed to create tool bridge: {}", e)))?
+            .with_shutdown_coordinator((*shutdown_coordinator).clone());
+        match &self.project_database {
+            Some(project_db) => {
+                let permissions =
+                    SessionPermissions::load(&ToolPermissionRepository::new(project_db.clone())).await?;
+                let rules = RuleEngine::load(&ProjectRuleRepository::new(project_db.clone())).await?;
+                // Permissions are only enforced once at least one is configured
+                if !permissions.is_empty() {
+                    tool_bridge = tool_bridge.with_permissions(permissions);
+                }
+                tool_bridge = tool_bridge.with_rule_engine(rules);
+            }
+            None => debug!("No project database; tool permissions are not enforced"),
+        }
+        let tool_bridge = Arc::new(tool_bridge);
 
         // Create LLM provider
         let llm_provider = Arc::new(LlmProvider::from_config(&config)?);
@@ -206,11 +311,8 @@ impl ContextBuilder {
         let task_repository = TaskRepository::new(database.clone());
         let workflow_repository = WorkflowRepository::new(database.clone());
 
-        // Create shutdown coordinator
-        let shutdown_coordinator = Arc::new(ShutdownCoordinator::new());
-
-        // Create event logger (enabled by default)
-        let event_logger = EventLogger::new(true);
+        // Create event logger with the configured sink
+        let event_logger = EventLogger::from_config(&config.logging)?;
 
         info!(session_id = %session.session_id, "Execution context initialized");
 
@@ -225,6 +327,8 @@ impl ContextBuilder {
             config,
             shutdown_coordinator,
             event_logger,
+            history,
+            files,
         })
     }
 }
@@ -267,6 +371,7 @@ mod tests {
                 format: "json".to_string(),
                 colored: false,
                 timestamps: true,
+                ..Default::default()
             },
             budget: Default::default(),
             workflow: Default::default(),
@@ -344,6 +449,120 @@ mod tests {
         assert!(!context.tool_bridge().list_tools().is_empty());
     }
 
+    #[tokio::test]
+    async fn test_context_builder_loads_permissions_and_rules() {
+        use crate::models::{PermissionLevel, ProjectRule, ToolPermission};
+        use serde_json::json;
+        use sqlx::sqlite::SqlitePoolOptions;
+
+        let temp_dir = TempDir::new().unwrap();
+        let memory_db = || async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .unwrap();
+            Arc::new(Database { pool: Arc::new(pool) })
+        };
+        let project_db = memory_db().await;
+        project_db.run_migrations_from("migrations/project").await.unwrap();
+        ToolPermissionRepository::new(project_db.clone())
+            .save(&ToolPermission::new("file_read".to_string(), PermissionLevel::Allowed))
+            .await
+            .unwrap();
+        ProjectRuleRepository::new(project_db.clone())
+            .save(&ProjectRule::new(
+                "no-secrets".to_string(),
+                "security".to_string(),
+                json!({"tools": ["file_read"], "paths": ["secrets/**"]}).to_string(),
+            ))
+            .await
+            .unwrap();
+        // A malformed rule is skipped instead of failing the build
+        ProjectRuleRepository::new(project_db.clone())
+            .save(&ProjectRule::new(
+                "broken".to_string(),
+                "security".to_string(),
+                json!({"tools": "file_read"}).to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let build = |project_db: Option<Arc<Database>>| {
+            let database = memory_db();
+            let workspace_root = temp_dir.path().to_path_buf();
+            async move {
+                let mut builder = ContextBuilder::new()
+                    .with_database(database.await)
+                    .with_config(create_test_config())
+                    .with_workspace_root(workspace_root);
+                if let Some(project_db) = project_db {
+                    builder = builder.with_project_database(project_db);
+                }
+                builder.build().await.unwrap()
+            }
+        };
+
+        let context = build(Some(project_db)).await;
+        let bridge = context.tool_bridge();
+        assert!(bridge.check_permission("file_read", &json!({})).is_ok());
+        assert!(bridge.check_permission("shell_exec", &json!({})).is_err());
+        assert!(bridge
+            .check_permission("file_read", &json!({"path": "src/../secrets/key"}))
+            .is_err());
+
+        // Nothing configured: nothing is enforced
+        let context = build(None).await;
+        assert!(context.tool_bridge().check_permission("shell_exec", &json!({})).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_context_builder_trims_to_token_budget() {
+        use sqlx::sqlite::SqlitePoolOptions;
+
+        let temp_dir = TempDir::new().unwrap();
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let db = Arc::new(Database {
+            pool: Arc::new(pool),
+        });
+
+        // 40 characters = 10 tokens per message
+        let turn = |n: usize| format!("message {:02} {}", n, "x".repeat(29));
+        let mut history = vec![Message::system("You are a helpful agent.")];
+        for n in 0..6 {
+            history.push(Message::human(turn(n * 2)));
+            history.push(Message::ai(turn(n * 2 + 1)));
+        }
+
+        let context = ContextBuilder::new()
+            .with_database(db)
+            .with_config(create_test_config())
+            .with_workspace_root(temp_dir.path().to_path_buf())
+            .with_history(history)
+            .with_token_budget(TokenBudget::new(50))
+            .build()
+            .await
+            .unwrap();
+
+        let kept: Vec<&str> = context.history().iter().filter_map(|m| m.text()).collect();
+        assert_eq!(
+            kept,
+            vec![
+                "You are a helpful agent.",
+                turn(8).as_str(),
+                turn(9).as_str(),
+                turn(10).as_str(),
+                turn(11).as_str(),
+            ]
+        );
+        assert_eq!(context.session().token_budget, Some(50));
+        assert_eq!(context.remaining_budget(), Some(4));
+    }
+
     #[tokio::test]
     async fn test_context_accessors() {
         use sqlx::sqlite::SqlitePoolOptions;
@@ -7,9 +7,12 @@
 //! - **ExecutionContext** - Main context struct with database, tools, LLM, and config
 //! - **SessionInfo** - Session tracking information
 //! - **ContextBuilder** - Fluent builder for creating contexts
+//! - **TokenBudget** - Running token count used to trim history and files
 
 mod execution_context;
 mod session_info;
+mod token_budget;
 
 pub use execution_context::{ExecutionContext, ContextBuilder};
 pub use session_info::SessionInfo;
+pub use token_budget::{ContextFile, TokenBudget};
@@ -24,6 +24,14 @@ pub struct SessionInfo {
 
     /// Additional session metadata
     pub metadata: HashMap<String, serde_json::Value>,
+
+    /// Maximum tokens the assembled context may use, if limited
+    #[serde(default)]
+    pub token_budget: Option<usize>,
+
+    /// Tokens used by the assembled context
+    #[serde(default)]
+    pub tokens_used: usize,
 }
 
 impl SessionInfo {
@@ -35,6 +43,8 @@ impl SessionInfo {
             description: None,
             user_id: None,
             metadata: HashMap::new(),
+            token_budget: None,
+            tokens_used: 0,
         }
     }
 
@@ -46,6 +56,8 @@ impl SessionInfo {
             description: None,
             user_id: None,
             metadata: HashMap::new(),
+            token_budget: None,
+            tokens_used: 0,
         }
     }
 
@@ -67,6 +79,23 @@ impl SessionInfo {
         self
     }
 
+    /// Limit the tokens the assembled context may use
+    pub fn with_token_budget(mut self, max_tokens: usize) -> Self {
+        self.token_budget = Some(max_tokens);
+        self
+    }
+
+    /// Record tokens used against the budget
+    pub fn record_tokens(&mut self, tokens: usize) {
+        self.tokens_used += tokens;
+    }
+
+    /// Tokens left in the budget, or `None` when unlimited
+    pub fn remaining_budget(&self) -> Option<usize> {
+        self.token_budget
+            .map(|budget| budget.saturating_sub(self.tokens_used))
+    }
+
     /// Get session age in seconds
     pub fn age_seconds(&self) -> i64 {
         (Utc::now() - self.created_at).num_seconds()
@@ -110,6 +139,18 @@ mod tests {
         assert_eq!(session.metadata.get("key1").unwrap(), &serde_json::json!("value1"));
     }
 
+    #[test]
+    fn test_session_remaining_budget() {
+        let mut session = SessionInfo::new();
+        assert_eq!(session.remaining_budget(), None);
+
+        session = session.with_token_budget(100);
+        session.record_tokens(60);
+        assert_eq!(session.remaining_budget(), Some(40));
+        session.record_tokens(60);
+        assert_eq!(session.remaining_budget(), Some(0));
+    }
+
     #[test]
     fn test_session_age() {
         let session = SessionInfo::new();
@@ -0,0 +1,148 @@
+//! Token budget accounting
+//!
+//! Tracks how many tokens the assembled context uses so history and files
+//! can be trimmed before a request overflows the model's context window.
+
+use langgraph_core::messages::{
+    trim_messages, CharsPerTokenCounter, Message, TokenCounter, TrimOptions,
+};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A file included in the execution context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextFile {
+    /// Path of the file, as shown to the model
+    pub path: PathBuf,
+
+    /// File contents
+    pub content: String,
+}
+
+impl ContextFile {
+    /// Create a context file
+    pub fn new(path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            content: content.into(),
+        }
+    }
+
+    /// Render the file as a system message
+    pub fn to_message(&self) -> Message {
+        Message::system(format!("File: {}\n\n{}", self.path.display(), self.content))
+    }
+}
+
+/// Running token budget
+///
+/// Tokens are estimated with the same [`TokenCounter`] that
+/// [`trim_messages`] uses for its token budget strategy.
+#[derive(Debug, Clone)]
+pub struct TokenBudget {
+    max_tokens: usize,
+    used: usize,
+    counter: Arc<dyn TokenCounter>,
+}
+
+impl TokenBudget {
+    /// Create a budget of `max_tokens`, estimated with [`CharsPerTokenCounter`]
+    pub fn new(max_tokens: usize) -> Self {
+        Self {
+            max_tokens,
+            used: 0,
+            counter: Arc::new(CharsPerTokenCounter::default()),
+        }
+    }
+
+    /// Builder: Estimate tokens with a different counter
+    pub fn with_token_counter(mut self, counter: Arc<dyn TokenCounter>) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Configured limit
+    pub fn max_tokens(&self) -> usize {
+        self.max_tokens
+    }
+
+    /// Tokens consumed so far
+    pub fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Tokens left before the limit
+    pub fn remaining(&self) -> usize {
+        self.max_tokens.saturating_sub(self.used)
+    }
+
+    /// Estimate the tokens of a message
+    pub fn count(&self, message: &Message) -> usize {
+        self.counter.count_tokens(message)
+    }
+
+    /// Consume `tokens` if they fit in the remaining budget
+    pub fn try_consume(&mut self, tokens: usize) -> bool {
+        if tokens > self.remaining() {
+            return false;
+        }
+        self.used += tokens;
+        true
+    }
+
+    /// Keep the newest files that fit, dropping the oldest first
+    ///
+    /// Kept files stay in their original order.
+    pub fn trim_files(&mut self, files: Vec<ContextFile>) -> Vec<ContextFile> {
+        let mut kept = Vec::new();
+        for file in files.into_iter().rev() {
+            let tokens = self.count(&file.to_message());
+            if !self.try_consume(tokens) {
+                break;
+            }
+            kept.push(file);
+        }
+        kept.reverse();
+        kept
+    }
+
+    /// Keep the newest history that fits, dropping the oldest first
+    ///
+    /// Uses the [`trim_messages`] token budget strategy, so a leading system
+    /// message is always kept and tool calls stay with their results.
+    pub fn trim_history(&mut self, history: Vec<Message>) -> Vec<Message> {
+        let options =
+            TrimOptions::token_budget(self.remaining()).with_token_counter(self.counter.clone());
+        let kept = trim_messages(history, options);
+        let tokens: usize = kept.iter().map(|m| self.count(m)).sum();
+        self.used += tokens;
+        kept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_consume_stops_at_limit() {
+        let mut budget = TokenBudget::new(10);
+        assert!(budget.try_consume(6));
+        assert!(!budget.try_consume(5));
+        assert!(budget.try_consume(4));
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[test]
+    fn test_trim_files_drops_oldest_first() {
+        let file = |name: &str| ContextFile::new(name, "x".repeat(36));
+        let mut budget = TokenBudget::new(25);
+        let per_file = budget.count(&file("a.rs").to_message());
+
+        let kept = budget.trim_files(vec![file("a.rs"), file("b.rs"), file("c.rs")]);
+
+        let paths: Vec<_> = kept.iter().map(|f| f.path.to_str().unwrap()).collect();
+        assert_eq!(paths, vec!["b.rs", "c.rs"]);
+        assert_eq!(budget.used(), per_file * 2);
+    }
+}
@@ -4,12 +4,16 @@
 //! for persistent state storage in ~/.orca/orca.db
 
 pub mod manager;
+pub mod migrator;
+
+pub use migrator::{MigrationReport, Migrator};
 
 use crate::error::{OrcaError, Result};
-use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use futures::future::BoxFuture;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions};
 use std::path::Path;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Type alias for the database connection pool
 pub type DatabasePool = SqlitePool;
@@ -43,12 +47,16 @@ impl Database {
             }
         }
 
-        let database_url = format!("sqlite:{}", path_str);
-        debug!(url = %database_url, "Connecting to database");
+        debug!(path = %path_str, "Connecting to database");
+
+        // A missing file is created, matching the parent directory above
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
 
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
-            .connect(&database_url)
+            .connect_with(options)
             .await
             .map_err(|e| OrcaError::Database(format!("Failed to connect to database: {}", e)))?;
 
@@ -102,14 +110,12 @@ impl Database {
 
     /// Run migrations on the database
     ///
-    /// Migrations are embedded in the binary and located in ./migrations
+    /// Migrations are embedded in the binary and located in ./migrations.
+    /// See [`Migrator::run`] for versioning rules.
     pub async fn run_migrations(&self) -> Result<()> {
         info!("Running database migrations");
 
-        sqlx::migrate!("./migrations")
-            .run(self.pool.as_ref())
-            .await
-            .map_err(|e| OrcaError::Database(format!("Migration failed: {}", e)))?;
+        Migrator::main().run(self).await?;
 
         info!("Database migrations completed successfully");
         Ok(())
@@ -126,31 +132,76 @@ impl Database {
     pub async fn run_migrations_from(&self, migration_path: &str) -> Result<()> {
         info!(path = %migration_path, "Running database migrations from custom path");
 
-        match migration_path {
-            "migrations/user" => {
-                sqlx::migrate!("./migrations/user")
-                    .run(self.pool.as_ref())
-                    .await
-                    .map_err(|e| OrcaError::Database(format!("User migration failed: {}", e)))?;
-            }
-            "migrations/project" => {
-                sqlx::migrate!("./migrations/project")
-                    .run(self.pool.as_ref())
-                    .await
-                    .map_err(|e| OrcaError::Database(format!("Project migration failed: {}", e)))?;
-            }
+        let migrator = match migration_path {
+            "migrations/user" => Migrator::user(),
+            "migrations/project" => Migrator::project(),
             _ => {
                 return Err(OrcaError::Database(format!(
                     "Unsupported migration path: {}. Supported paths: migrations/user, migrations/project",
                     migration_path
                 )));
             }
-        }
+        };
+        migrator.run(self).await?;
 
         info!(path = %migration_path, "Database migrations completed successfully");
         Ok(())
     }
 
+    /// Run `f` inside a transaction, committing if it returns `Ok`
+    ///
+    /// Any error from `f` rolls the transaction back and is returned as is.
+    /// Pass the connection to repository `*_in` methods so writes across
+    /// several repositories commit or roll back together:
+    ///
+    /// ```no_run
+    /// # use orca::db::Database;
+    /// # use orca::repositories::{TaskRepository, WorkflowRepository};
+    /// # use orca::workflow::{Task, Workflow};
+    /// # use std::sync::Arc;
+    /// # async fn example(db: Arc<Database>) -> orca::Result<()> {
+    /// let tasks = TaskRepository::new(db.clone());
+    /// let workflows = WorkflowRepository::new(db.clone());
+    /// let workflow = Workflow::new("Import", "react");
+    /// let task = Task::new("Imported task");
+    ///
+    /// db.with_transaction(move |conn| {
+    ///     Box::pin(async move {
+    ///         workflows.save_in(conn, &workflow).await?;
+    ///         tasks.save_in(conn, &task).await?;
+    ///         workflows.add_task_in(conn, &workflow.id, &task.id, 0).await
+    ///     })
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_transaction<T, F>(&self, f: F) -> Result<T>
+    where
+        F: for<'c> FnOnce(&'c mut SqliteConnection) -> BoxFuture<'c, Result<T>>,
+    {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to begin transaction: {}", e)))?;
+
+        match f(&mut tx).await {
+            Ok(value) => {
+                tx.commit().await.map_err(|e| {
+                    OrcaError::Database(format!("Failed to commit transaction: {}", e))
+                })?;
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    warn!(error = %rollback_err, "Failed to roll back transaction");
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// Perform a health check by running a simple query
     pub async fn health_check(&self) -> Result<()> {
         sqlx::query("SELECT 1")
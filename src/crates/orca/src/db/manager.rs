@@ -6,6 +6,8 @@
 
 use crate::db::Database;
 use crate::error::{OrcaError, Result};
+use futures::future::BoxFuture;
+use sqlx::sqlite::SqliteConnection;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
@@ -45,7 +47,7 @@ impl DatabaseManager {
         let user_db_path = Self::get_user_db_path()?;
         debug!("Initializing user database at: {}", user_db_path.display());
 
-        let user_db = Arc::new(Database::initialize(&user_db_path).await?);
+        let user_db = Arc::new(Database::new(&user_db_path).await?);
 
         // Run user migrations
         Self::run_migrations(&user_db, DatabaseType::User).await?;
@@ -102,6 +104,21 @@ impl DatabaseManager {
         self.project_root.as_deref()
     }
 
+    /// Run `f` in a transaction on the project database
+    ///
+    /// Tasks, workflows and other project data live in the project database,
+    /// so writes made through repository `*_in` methods inside `f` commit or
+    /// roll back together. See [`Database::with_transaction`].
+    pub async fn with_transaction<T, F>(&self, f: F) -> Result<T>
+    where
+        F: for<'c> FnOnce(&'c mut SqliteConnection) -> BoxFuture<'c, Result<T>>,
+    {
+        let db = self.project_db.as_ref().ok_or_else(|| {
+            OrcaError::Database("No project database; run inside an orca project".to_string())
+        })?;
+        db.with_transaction(f).await
+    }
+
     /// Ensure project database exists, creating it if necessary
     ///
     /// Call this before any project-specific operation
@@ -178,7 +195,7 @@ impl DatabaseManager {
             .map_err(|e| OrcaError::Other(format!("Failed to create .orca directory: {}", e)))?;
 
         // Initialize database
-        let db = Database::initialize(&project_db_path).await?;
+        let db = Database::new(&project_db_path).await?;
 
         // Run project migrations
         Self::run_migrations(&db, DatabaseType::Project).await?;
@@ -264,6 +281,41 @@ mod tests {
         assert!(temp_workspace.path().join(".orca").exists());
     }
 
+    #[tokio::test]
+    async fn test_with_transaction_rolls_back_across_repositories() {
+        use crate::repositories::{TaskRepository, WorkflowRepository};
+        use crate::workflow::{Task, Workflow};
+
+        let temp_workspace = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_workspace.path().join(".orca")).unwrap();
+        let manager = DatabaseManager::new(temp_workspace.path()).await.unwrap();
+        let db = manager.project_db().unwrap().clone();
+
+        let tasks = TaskRepository::new(db.clone());
+        let workflows = WorkflowRepository::new(db);
+        let workflow = Workflow::new("Import", "react");
+        let task = Task::new("Imported task");
+
+        let result = manager
+            .with_transaction({
+                let (tasks, workflows) = (tasks.clone(), workflows.clone());
+                let (workflow, task) = (workflow.clone(), task.clone());
+                move |conn| {
+                    Box::pin(async move {
+                        workflows.save_in(conn, &workflow).await?;
+                        tasks.save_in(conn, &task).await?;
+                        // Same id again violates the primary key
+                        tasks.save_in(conn, &task).await
+                    })
+                }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert!(!workflows.exists(&workflow.id).await.unwrap());
+        assert!(!tasks.exists(&task.id).await.unwrap());
+    }
+
     #[test]
     fn test_find_project_root() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,225 @@
+//! Versioned schema migrations
+//!
+//! Wraps the embedded sqlx migration sets so every open records applied
+//! versions in `_sqlx_migrations`, applies pending migrations in version
+//! order, and refuses a database whose schema is newer than this build.
+
+use crate::db::Database;
+use crate::error::{OrcaError, Result};
+use tracing::{debug, info};
+
+/// Table sqlx records applied migrations in
+pub const MIGRATIONS_TABLE: &str = "_sqlx_migrations";
+
+static MAIN_MIGRATIONS: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+static USER_MIGRATIONS: sqlx::migrate::Migrator = sqlx::migrate!("./migrations/user");
+static PROJECT_MIGRATIONS: sqlx::migrate::Migrator = sqlx::migrate!("./migrations/project");
+
+/// Outcome of a [`Migrator::run`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Highest applied version before running, `None` for a fresh database
+    pub from_version: Option<i64>,
+    /// Highest applied version after running
+    pub to_version: Option<i64>,
+    /// Versions applied by this run, in order
+    pub applied: Vec<i64>,
+}
+
+impl MigrationReport {
+    /// Whether the run applied nothing
+    pub fn is_noop(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+/// Ordered set of migrations for one database kind
+#[derive(Debug, Clone, Copy)]
+pub struct Migrator {
+    label: &'static str,
+    source: &'static sqlx::migrate::Migrator,
+}
+
+impl Migrator {
+    /// Migrations for the standalone database (`./migrations`)
+    pub fn main() -> Self {
+        Self {
+            label: "Migration",
+            source: &MAIN_MIGRATIONS,
+        }
+    }
+
+    /// Migrations for the user database (`./migrations/user`)
+    pub fn user() -> Self {
+        Self {
+            label: "User migration",
+            source: &USER_MIGRATIONS,
+        }
+    }
+
+    /// Migrations for the project database (`./migrations/project`)
+    pub fn project() -> Self {
+        Self {
+            label: "Project migration",
+            source: &PROJECT_MIGRATIONS,
+        }
+    }
+
+    /// Versions of this set, ascending
+    pub fn versions(&self) -> Vec<i64> {
+        self.source.iter().map(|m| m.version).collect()
+    }
+
+    /// Latest version in this set
+    pub fn latest_version(&self) -> Option<i64> {
+        self.source.iter().map(|m| m.version).max()
+    }
+
+    /// Latest version across every migration set in this build
+    ///
+    /// The user database shares its version table with the standalone set,
+    /// so "newer than this build" is judged against all of them.
+    pub fn latest_known_version() -> Option<i64> {
+        [&MAIN_MIGRATIONS, &USER_MIGRATIONS, &PROJECT_MIGRATIONS]
+            .iter()
+            .flat_map(|source| source.iter().map(|m| m.version))
+            .max()
+    }
+
+    /// Versions recorded as successfully applied, ascending
+    ///
+    /// Empty for a database that has never been migrated.
+    pub async fn applied_versions(db: &Database) -> Result<Vec<i64>> {
+        let table: Option<String> =
+            sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?")
+                .bind(MIGRATIONS_TABLE)
+                .fetch_optional(db.pool())
+                .await
+                .map_err(|e| {
+                    OrcaError::Database(format!("Failed to read schema version: {}", e))
+                })?;
+        if table.is_none() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_scalar(&format!(
+            "SELECT version FROM {} WHERE success = 1 ORDER BY version",
+            MIGRATIONS_TABLE
+        ))
+        .fetch_all(db.pool())
+        .await
+        .map_err(|e| OrcaError::Database(format!("Failed to read schema version: {}", e)))
+    }
+
+    /// Apply every pending migration in version order
+    ///
+    /// Fails without touching the schema if the database records a version
+    /// newer than any migration in this build.
+    pub async fn run(&self, db: &Database) -> Result<MigrationReport> {
+        let before = Self::applied_versions(db).await?;
+        let from_version = before.last().copied();
+
+        if let (Some(current), Some(latest)) = (from_version, Self::latest_known_version()) {
+            if current > latest {
+                return Err(OrcaError::Database(format!(
+                    "Database schema version {} is newer than this build supports (latest {}); \
+                     upgrade orca to open this database",
+                    current, latest
+                )));
+            }
+        }
+
+        debug!(pending = ?self.pending(&before), "Running migrations");
+        self.source
+            .run(db.pool())
+            .await
+            .map_err(|e| OrcaError::Database(format!("{} failed: {}", self.label, e)))?;
+
+        let after = Self::applied_versions(db).await?;
+        let applied: Vec<i64> = after
+            .iter()
+            .copied()
+            .filter(|v| !before.contains(v))
+            .collect();
+        if !applied.is_empty() {
+            info!(versions = ?applied, "Applied migrations");
+        }
+
+        Ok(MigrationReport {
+            from_version,
+            to_version: after.last().copied(),
+            applied,
+        })
+    }
+
+    fn pending(&self, applied: &[i64]) -> Vec<i64> {
+        self.versions()
+            .into_iter()
+            .filter(|v| !applied.contains(v))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_fresh_database_migrates_to_latest() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = Database::new(temp_dir.path().join("orca.db"))
+            .await
+            .unwrap();
+
+        let migrator = Migrator::main();
+        let report = migrator.run(&db).await.unwrap();
+
+        assert_eq!(report.from_version, None);
+        assert_eq!(report.to_version, migrator.latest_version());
+        assert_eq!(report.applied, migrator.versions());
+        db.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_reopen_is_noop() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("orca.db");
+
+        let db = Database::new(&path).await.unwrap();
+        Migrator::main().run(&db).await.unwrap();
+        db.close().await;
+
+        let db = Database::new(&path).await.unwrap();
+        let report = Migrator::main().run(&db).await.unwrap();
+
+        assert!(report.is_noop());
+        assert_eq!(report.from_version, report.to_version);
+        assert_eq!(report.to_version, Migrator::main().latest_version());
+        db.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_newer_database_is_refused() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = Database::new(temp_dir.path().join("orca.db"))
+            .await
+            .unwrap();
+        Migrator::main().run(&db).await.unwrap();
+
+        let future = Migrator::latest_known_version().unwrap() + 1;
+        sqlx::query(&format!(
+            "INSERT INTO {} (version, description, success, checksum, execution_time) \
+             VALUES (?, 'from the future', 1, x'00', 0)",
+            MIGRATIONS_TABLE
+        ))
+        .bind(future)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+        let err = Migrator::main().run(&db).await.unwrap_err();
+        assert!(err.to_string().contains("newer than this build"));
+        db.close().await;
+    }
+}
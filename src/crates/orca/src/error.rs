@@ -31,6 +31,9 @@ pub enum OrcaError {
     /// Task timeout error
     Timeout { task_id: String, duration_secs: u64 },
 
+    /// Tool execution exceeded its timeout and was cancelled
+    ToolTimeout { tool: String, timeout_ms: u64 },
+
     /// Feature not yet implemented
     NotImplemented(String),
 
@@ -40,6 +43,12 @@ pub enum OrcaError {
     /// Budget exceeded error
     BudgetExceeded(String),
 
+    /// Tool execution not permitted for the current session
+    PermissionDenied(String),
+
+    /// Status change not allowed by the record's state machine
+    InvalidTransition { from: String, to: String },
+
     /// LLM error
     LlmError(String),
 
@@ -68,9 +77,16 @@ impl fmt::Display for OrcaError {
             Self::Timeout { task_id, duration_secs } => {
                 write!(f, "Task {} timed out after {} seconds", task_id, duration_secs)
             }
+            Self::ToolTimeout { tool, timeout_ms } => {
+                write!(f, "Tool '{}' timed out after {}ms", tool, timeout_ms)
+            }
             Self::NotImplemented(msg) => write!(f, "Not implemented: {}", msg),
             Self::NotFound(msg) => write!(f, "Not found: {}", msg),
             Self::BudgetExceeded(msg) => write!(f, "Budget exceeded: {}", msg),
+            Self::PermissionDenied(msg) => write!(f, "Permission denied: {}", msg),
+            Self::InvalidTransition { from, to } => {
+                write!(f, "Invalid status transition from '{}' to '{}'", from, to)
+            }
             Self::LlmError(msg) => write!(f, "LLM error: {}", msg),
             Self::Io(err) => write!(f, "IO error: {}", err),
             Self::Serde(err) => write!(f, "Serialization error: {}", err),
@@ -2,9 +2,21 @@
 //!
 //! This module provides event types and logging infrastructure for tracking
 //! task and workflow execution events.
+//!
+//! [`EventLogger`] hands events to an [`EventSink`], so where events end up
+//! (tracing, stdout JSON, a file, or memory) is chosen by
+//! [`EventSinkConfig`] rather than by the code producing them.
 
+use crate::config::{EventSinkConfig, LoggingConfig};
+use crate::error::Result;
 use chrono::Utc;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Event types for execution tracking
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -74,6 +86,13 @@ pub enum ExecutionEvent {
         timestamp: i64,
         duration_ms: u64,
     },
+    /// A timed execution phase (LLM call, tool call, total) completed
+    PhaseCompleted {
+        task_id: String,
+        phase: String,
+        timestamp: i64,
+        duration_ms: u64,
+    },
 }
 
 impl ExecutionEvent {
@@ -89,7 +108,8 @@ impl ExecutionEvent {
             | ExecutionEvent::WorkflowPaused { timestamp, .. }
             | ExecutionEvent::WorkflowResumed { timestamp, .. }
             | ExecutionEvent::PatternExecutionStarted { timestamp, .. }
-            | ExecutionEvent::PatternExecutionCompleted { timestamp, .. } => *timestamp,
+            | ExecutionEvent::PatternExecutionCompleted { timestamp, .. }
+            | ExecutionEvent::PhaseCompleted { timestamp, .. } => *timestamp,
         }
     }
 
@@ -127,6 +147,9 @@ impl ExecutionEvent {
                 format!("Pattern execution completed: {} ({}) - {} iterations in {}ms",
                     task_id, pattern, iterations, duration_ms)
             }
+            ExecutionEvent::PhaseCompleted { task_id, phase, duration_ms, .. } => {
+                format!("Phase completed: {} {} in {}ms", task_id, phase, duration_ms)
+            }
         }
     }
 
@@ -248,18 +271,185 @@ impl ExecutionEvent {
             duration_ms,
         }
     }
+
+    /// Create a PhaseCompleted event
+    pub fn phase_completed(
+        task_id: impl Into<String>,
+        phase: impl Into<String>,
+        duration_ms: u64,
+    ) -> Self {
+        ExecutionEvent::PhaseCompleted {
+            task_id: task_id.into(),
+            phase: phase.into(),
+            timestamp: Utc::now().timestamp(),
+            duration_ms,
+        }
+    }
+}
+
+/// Destination for execution events
+pub trait EventSink: Send + Sync + std::fmt::Debug {
+    /// Record an event
+    fn log(&self, event: &ExecutionEvent);
+
+    /// This sink as a [`MemorySink`], if it is one
+    fn as_memory(&self) -> Option<&MemorySink> {
+        None
+    }
+}
+
+/// Sink that logs events through `tracing`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingSink;
+
+impl EventSink for TracingSink {
+    fn log(&self, event: &ExecutionEvent) {
+        tracing::info!(
+            event_type = ?event,
+            timestamp = event.timestamp(),
+            description = %event.description(),
+            "Execution event"
+        );
+    }
+}
+
+/// Sink that prints one JSON object per event to stdout
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutJsonSink;
+
+impl EventSink for StdoutJsonSink {
+    fn log(&self, event: &ExecutionEvent) {
+        match serde_json::to_string(event) {
+            Ok(line) => println!("{}", line),
+            Err(e) => tracing::warn!(error = %e, "Failed to serialize execution event"),
+        }
+    }
+}
+
+/// Sink that appends one JSON object per event to a file
+#[derive(Debug)]
+pub struct FileSink {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl FileSink {
+    /// Open `path` for appending, creating it if missing
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Path events are appended to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl EventSink for FileSink {
+    fn log(&self, event: &ExecutionEvent) {
+        let result = serde_json::to_string(event)
+            .map_err(std::io::Error::from)
+            .and_then(|line| {
+                let mut file = self.file.lock();
+                writeln!(file, "{}", line)?;
+                file.flush()
+            });
+        if let Err(e) = result {
+            tracing::warn!(
+                path = %self.path.display(),
+                error = %e,
+                "Failed to write execution event"
+            );
+        }
+    }
+}
+
+/// Sink that keeps the most recent events in memory
+///
+/// Clones share the same buffer, so a clone kept by a test sees every event
+/// logged through the original.
+#[derive(Debug, Clone)]
+pub struct MemorySink {
+    events: Arc<Mutex<VecDeque<ExecutionEvent>>>,
+    capacity: usize,
+}
+
+impl MemorySink {
+    /// Create a sink keeping at most `capacity` events
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Snapshot of the kept events, oldest first
+    pub fn events(&self) -> Vec<ExecutionEvent> {
+        self.events.lock().iter().cloned().collect()
+    }
+
+    /// Drop all kept events
+    pub fn clear(&self) {
+        self.events.lock().clear();
+    }
+}
+
+impl EventSink for MemorySink {
+    fn log(&self, event: &ExecutionEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut events = self.events.lock();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event.clone());
+    }
+
+    fn as_memory(&self) -> Option<&MemorySink> {
+        Some(self)
+    }
 }
 
 /// Event logger for recording execution events
 #[derive(Debug, Clone)]
 pub struct EventLogger {
     enabled: bool,
+    sink: Arc<dyn EventSink>,
 }
 
 impl EventLogger {
-    /// Create a new event logger
+    /// Create a new event logger that logs through `tracing`
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            sink: Arc::new(TracingSink),
+        }
+    }
+
+    /// Create an enabled event logger writing to `sink`
+    pub fn with_sink(sink: impl EventSink + 'static) -> Self {
+        Self {
+            enabled: true,
+            sink: Arc::new(sink),
+        }
+    }
+
+    /// Create an enabled event logger with the sink selected in config
+    ///
+    /// Fails if a file sink cannot be opened.
+    pub fn from_config(config: &LoggingConfig) -> Result<Self> {
+        Ok(match &config.event_sink {
+            EventSinkConfig::Tracing => Self::with_sink(TracingSink),
+            EventSinkConfig::StdoutJson => Self::with_sink(StdoutJsonSink),
+            EventSinkConfig::File { path } => Self::with_sink(FileSink::new(path.clone())?),
+            EventSinkConfig::Memory { capacity } => Self::with_sink(MemorySink::new(*capacity)),
+        })
     }
 
     /// Log an execution event
@@ -268,19 +458,21 @@ impl EventLogger {
             return;
         }
 
-        // Log using tracing infrastructure
-        tracing::info!(
-            event_type = ?event,
-            timestamp = event.timestamp(),
-            description = %event.description(),
-            "Execution event"
-        );
+        self.sink.log(event);
     }
 
     /// Check if logging is enabled
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
+
+    /// Sink events are written to
+    ///
+    /// Use [`EventSink::as_memory`] to read back the events kept by a
+    /// logger built from [`EventSinkConfig::Memory`].
+    pub fn sink(&self) -> &Arc<dyn EventSink> {
+        &self.sink
+    }
 }
 
 impl Default for EventLogger {
@@ -406,6 +598,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_phase_completed_event() {
+        let event = ExecutionEvent::phase_completed("task-789", "llm_call", 420);
+
+        match &event {
+            ExecutionEvent::PhaseCompleted { task_id, phase, duration_ms, .. } => {
+                assert_eq!(task_id, "task-789");
+                assert_eq!(phase, "llm_call");
+                assert_eq!(*duration_ms, 420);
+            }
+            _ => panic!("Expected PhaseCompleted event"),
+        }
+
+        assert!(event.description().contains("llm_call in 420ms"));
+    }
+
     #[test]
     fn test_event_timestamp() {
         let event = ExecutionEvent::task_started("task-123", "Test");
@@ -435,6 +643,66 @@ mod tests {
         assert!(logger.is_enabled());
     }
 
+    #[test]
+    fn test_memory_sink_keeps_most_recent() {
+        let sink = MemorySink::new(2);
+        let logger = EventLogger::with_sink(sink.clone());
+
+        for id in ["task-1", "task-2", "task-3"] {
+            logger.log(&ExecutionEvent::task_cancelled(id));
+        }
+
+        let ids: Vec<String> = sink
+            .events()
+            .into_iter()
+            .map(|event| match event {
+                ExecutionEvent::TaskCancelled { task_id, .. } => task_id,
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(ids, vec!["task-2", "task-3"]);
+    }
+
+    #[test]
+    fn test_memory_sink_from_config_is_reachable() {
+        let config = LoggingConfig {
+            event_sink: EventSinkConfig::Memory { capacity: 10 },
+            ..Default::default()
+        };
+        let logger = EventLogger::from_config(&config).unwrap();
+
+        let event = ExecutionEvent::task_cancelled("task-123");
+        logger.log(&event);
+
+        let sink = logger.sink().as_memory().unwrap();
+        assert_eq!(sink.events(), vec![event]);
+        assert!(EventLogger::new(true).sink().as_memory().is_none());
+    }
+
+    #[test]
+    fn test_file_sink_appends_json_lines() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = LoggingConfig {
+            event_sink: EventSinkConfig::File {
+                path: temp_dir.path().join("events.jsonl"),
+            },
+            ..Default::default()
+        };
+        let logger = EventLogger::from_config(&config).unwrap();
+
+        let event = ExecutionEvent::task_started("task-123", "Test task");
+        logger.log(&event);
+        logger.log(&ExecutionEvent::task_cancelled("task-123"));
+
+        let content = std::fs::read_to_string(temp_dir.path().join("events.jsonl")).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<ExecutionEvent>(lines[0]).unwrap(),
+            event
+        );
+    }
+
     #[test]
     fn test_event_serialization() {
         let event = ExecutionEvent::task_started("task-123", "Test task");
@@ -6,7 +6,10 @@
 use crate::config::OrcaConfig;
 use crate::error::{OrcaError, Result};
 use langgraph_core::llm::ChatRequest;
+use langgraph_core::tool::ToolCall as CoreToolCall;
+use langgraph_core::{ContentPart, MessageContent, MessageRole};
 use langgraph_prebuilt::Message; // Use the re-exported Message from langgraph_prebuilt
+use langgraph_prebuilt::messages::{MessageType, ToolCall};
 use langgraph_prebuilt::agents::react::LlmFunction;
 use llm::config::{LocalLlmConfig, RemoteLlmConfig};
 use llm::ChatModel; // Trait for chat method
@@ -194,21 +197,10 @@ pub fn create_llm_function(provider: Arc<LlmProvider>) -> LlmFunction {
 
             // Convert langgraph_prebuilt::Message to langgraph_core::Message
             let core_messages: Vec<langgraph_core::Message> = prebuilt_messages
-                .into_iter()
-                .filter_map(|msg| {
-                    // Serialize and deserialize to convert between types
-                    serde_json::to_value(&msg)
-                        .ok()
-                        .and_then(|v| serde_json::from_value(v).ok())
-                })
+                .iter()
+                .map(to_core_message)
                 .collect();
 
-            if core_messages.is_empty() {
-                return Err(langgraph_prebuilt::error::PrebuiltError::ToolExecution(
-                    "Failed to convert messages for LLM".to_string()
-                ));
-            }
-
             // Create chat request
             let request = ChatRequest::new(core_messages);
 
@@ -226,29 +218,95 @@ pub fn create_llm_function(provider: Arc<LlmProvider>) -> LlmFunction {
             debug!("LLM response received");
 
             // Convert response message from langgraph_core::Message to langgraph_prebuilt::Message
-            // We do this by serializing to JSON and deserializing since they have compatible structures
-            let core_message = response.message;
-            let message_json = serde_json::to_value(&core_message)
-                .map_err(|e| {
-                    warn!("Failed to serialize message: {}", e);
-                    langgraph_prebuilt::error::PrebuiltError::ToolExecution(
-                        format!("Failed to serialize LLM response: {}", e)
-                    )
-                })?;
-
-            let prebuilt_message: Message = serde_json::from_value(message_json)
-                .map_err(|e| {
-                    warn!("Failed to deserialize message: {}", e);
-                    langgraph_prebuilt::error::PrebuiltError::ToolExecution(
-                        format!("Failed to convert LLM response: {}", e)
-                    )
-                })?;
-
-            Ok(prebuilt_message)
+            from_core_message(response.message).map_err(|e| {
+                langgraph_prebuilt::error::PrebuiltError::ToolExecution(format!(
+                    "Failed to keep multimodal LLM response content: {}",
+                    e
+                ))
+            })
         })
     })
 }
 
+/// Metadata key holding the multimodal parts of an LLM message
+///
+/// Agent messages only carry text content, so image and other non-text parts
+/// are kept here and restored when the message is sent back to the LLM.
+const CONTENT_PARTS_KEY: &str = "content_parts";
+
+/// Convert an agent message to the message type sent to the LLM
+///
+/// The two types serialize differently (`type` vs `role`), so fields are
+/// mapped one by one rather than round-tripped through JSON.
+fn to_core_message(message: &Message) -> langgraph_core::Message {
+    let role = match message.message_type {
+        MessageType::Human => MessageRole::Human,
+        MessageType::AI => MessageRole::Assistant,
+        MessageType::System => MessageRole::System,
+        MessageType::Tool | MessageType::Function => MessageRole::Tool,
+    };
+
+    let content = message
+        .metadata
+        .get(CONTENT_PARTS_KEY)
+        .and_then(|parts| serde_json::from_value::<Vec<ContentPart>>(parts.clone()).ok())
+        .map(MessageContent::Parts)
+        .unwrap_or_else(|| MessageContent::Text(message.content.clone()));
+
+    let mut core = langgraph_core::Message::new(role, content);
+    core.name = message.name.clone();
+    core.tool_call_id = message.tool_call_id.clone();
+    core.tool_calls = message.tool_calls.as_ref().map(|calls| {
+        calls
+            .iter()
+            .map(|call| CoreToolCall::new(call.id.clone(), call.name.clone(), call.args.clone()))
+            .collect()
+    });
+    core
+}
+
+/// Convert an LLM response message back to an agent message
+///
+/// Multimodal content keeps its text parts in `content` and the full part
+/// list under [`CONTENT_PARTS_KEY`] in the message metadata.
+fn from_core_message(
+    message: langgraph_core::Message,
+) -> std::result::Result<Message, serde_json::Error> {
+    let message_type = match message.role {
+        MessageRole::Human => MessageType::Human,
+        MessageRole::System => MessageType::System,
+        MessageRole::Tool => MessageType::Tool,
+        MessageRole::Assistant | MessageRole::Custom(_) => MessageType::AI,
+    };
+
+    let mut prebuilt = match message.content {
+        MessageContent::Text(text) => Message::new(message_type, text),
+        MessageContent::Parts(parts) => {
+            let text = parts
+                .iter()
+                .filter_map(|part| match part {
+                    ContentPart::Text { text, .. } => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let parts = serde_json::to_value(&parts)?;
+            let mut prebuilt = Message::new(message_type, text);
+            prebuilt.metadata.insert(CONTENT_PARTS_KEY.to_string(), parts);
+            prebuilt
+        }
+    };
+    prebuilt.name = message.name;
+    prebuilt.tool_call_id = message.tool_call_id;
+    prebuilt.tool_calls = message.tool_calls.map(|calls| {
+        calls
+            .into_iter()
+            .map(|call| ToolCall::new(call.id, call.name, call.args))
+            .collect()
+    });
+    Ok(prebuilt)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +369,50 @@ mod tests {
         let result = LlmProvider::from_config(&config);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_message_conversion_round_trip() {
+        let call = ToolCall::new("call_1", "read_file", serde_json::json!({"path": "a.txt"}));
+        let message = Message::ai("Reading").with_tool_calls(vec![call]);
+
+        let core = to_core_message(&message);
+        assert_eq!(core.role, MessageRole::Assistant);
+        assert_eq!(core.text(), Some("Reading"));
+        assert_eq!(core.tool_calls.as_ref().unwrap()[0].name, "read_file");
+
+        let back = from_core_message(core).unwrap();
+        assert!(back.is_ai());
+        assert_eq!(back.content, "Reading");
+        let calls = back.tool_calls.unwrap();
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].args, serde_json::json!({"path": "a.txt"}));
+
+        let human = to_core_message(&Message::human("hi"));
+        assert_eq!(human.role, MessageRole::Human);
+    }
+
+    #[test]
+    fn test_multimodal_response_keeps_content_parts() {
+        let core = langgraph_core::Message::new(
+            MessageRole::Assistant,
+            MessageContent::Parts(vec![
+                ContentPart::text("Here is the chart"),
+                ContentPart::image_url("https://example.com/chart.png"),
+            ]),
+        );
+
+        let back = from_core_message(core).unwrap();
+        assert_eq!(back.content, "Here is the chart");
+        assert!(back.metadata.contains_key(CONTENT_PARTS_KEY));
+
+        // Sending the message back to the LLM restores the image part
+        let restored = to_core_message(&back);
+        match restored.content {
+            MessageContent::Parts(parts) => {
+                assert_eq!(parts.len(), 2);
+                assert!(matches!(&parts[1], ContentPart::Image { url: Some(url), .. } if url == "https://example.com/chart.png"));
+            }
+            MessageContent::Text(text) => panic!("expected parts, got text {:?}", text),
+        }
+    }
 }
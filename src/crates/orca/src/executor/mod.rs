@@ -8,11 +8,13 @@
 //! - **Adapter** - Bridges DirectToolBridge to langgraph-prebuilt Tool trait
 //! - **LLM Integration** - Wraps llm crate providers as LlmFunction
 //! - **Task Executor** - Main execution engine for tasks
+//! - **Timing** - Per-phase durations (LLM call, tool call, total)
 //! - **State Management** - Checkpointing and state tracking
 
 mod adapter;
 mod llm_provider;
 mod task_executor;
+pub mod timing;
 pub mod retry;
 pub mod budget_tracked_llm;
 pub mod multi_llm_provider;
@@ -21,5 +23,6 @@ pub use adapter::ToolAdapter;
 pub use llm_provider::{LlmProvider, create_llm_function};
 pub use task_executor::{TaskExecutor, ExecutionResult};
 pub use retry::{RetryConfig, with_retry};
+pub use timing::PhaseTimings;
 pub use budget_tracked_llm::BudgetTrackedLlm;
 pub use multi_llm_provider::{MultiLlmProvider, BudgetTrackedMultiLlmProvider};
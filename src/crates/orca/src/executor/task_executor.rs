@@ -4,14 +4,16 @@
 
 use crate::config::OrcaConfig;
 use crate::error::{OrcaError, Result};
+use crate::events::{EventLogger, ExecutionEvent};
+use crate::executor::timing::{PhaseTimings, PHASE_TOTAL};
 use crate::executor::{LlmProvider, ToolAdapter, create_llm_function};
 use crate::pattern::PatternType;
 use crate::tools::DirectToolBridge;
 use crate::workflow::Task;
-use langgraph_prebuilt::agents::{create_react_agent, create_plan_execute_agent, create_reflection_agent};
 use langgraph_core::{StreamMode, StreamEvent};
 use serde_json::{json, Value};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use futures::StreamExt;
 use tracing::{debug, info};
 
@@ -32,6 +34,9 @@ pub struct ExecutionResult {
 
     /// Message history
     pub messages: Vec<Value>,
+
+    /// Per-phase durations in completion order (LLM calls, tool calls, total)
+    pub timings: Vec<(String, Duration)>,
 }
 
 impl ExecutionResult {
@@ -43,6 +48,7 @@ impl ExecutionResult {
             error: None,
             final_state,
             messages,
+            timings: Vec::new(),
         }
     }
 
@@ -54,8 +60,15 @@ impl ExecutionResult {
             error: Some(error),
             final_state,
             messages,
+            timings: Vec::new(),
         }
     }
+
+    /// Attach per-phase timings
+    pub fn with_timings(mut self, timings: Vec<(String, Duration)>) -> Self {
+        self.timings = timings;
+        self
+    }
 }
 
 /// Task executor that runs tasks using LangGraph agents
@@ -68,6 +81,9 @@ pub struct TaskExecutor {
 
     /// Configuration
     config: OrcaConfig,
+
+    /// Logger for per-phase execution events
+    event_logger: EventLogger,
 }
 
 impl std::fmt::Debug for TaskExecutor {
@@ -97,9 +113,16 @@ impl TaskExecutor {
             bridge,
             llm_provider,
             config,
+            event_logger: EventLogger::default(),
         })
     }
 
+    /// Set the event logger used for per-phase execution events
+    pub fn with_event_logger(mut self, event_logger: EventLogger) -> Self {
+        self.event_logger = event_logger;
+        self
+    }
+
     /// Execute a task
     ///
     /// # Arguments
@@ -158,16 +181,6 @@ impl TaskExecutor {
             "Using pattern for execution"
         );
 
-        // Execute based on pattern
-        match pattern {
-            PatternType::React => self.execute_react(task).await,
-            PatternType::PlanExecute => self.execute_plan_execute(task).await,
-            PatternType::Reflection => self.execute_reflection(task).await,
-        }
-    }
-
-    /// Execute a task using the ReAct pattern
-    async fn execute_react(&self, task: &Task) -> Result<ExecutionResult> {
         // Create tools from bridge
         let tools = ToolAdapter::from_bridge(self.bridge.clone());
 
@@ -177,14 +190,12 @@ impl TaskExecutor {
             "Created tool adapters"
         );
 
-        // Create LLM function
-        let llm_fn = create_llm_function(self.llm_provider.clone());
-
-        // Create ReAct agent
-        let agent = create_react_agent(llm_fn, tools)
-            .with_max_iterations(self.config.execution.max_iterations)
-            .build()
-            .map_err(|e| OrcaError::Execution(format!("Failed to build agent: {}", e)))?;
+        // Build the agent for this pattern, timing each LLM and tool call
+        let start = Instant::now();
+        let timings = PhaseTimings::new();
+        let llm_fn = timings.wrap_llm(create_llm_function(self.llm_provider.clone()));
+        let tools = timings.wrap_tools(tools);
+        let agent = pattern.build_agent(llm_fn, tools, &self.config.execution)?;
 
         // Prepare initial state with task description
         let initial_state = json!({
@@ -198,15 +209,15 @@ impl TaskExecutor {
 
         // Execute with or without streaming based on config
         let (final_state, messages) = if self.config.execution.streaming {
-            debug!(task_id = %task.id, "Streaming agent execution");
+            debug!(task_id = %task.id, pattern = %pattern, "Streaming agent execution");
             self.execute_with_streaming(&agent, initial_state, task).await?
         } else {
-            debug!(task_id = %task.id, "Invoking agent");
+            debug!(task_id = %task.id, pattern = %pattern, "Invoking agent");
 
             let final_state = agent
                 .invoke(initial_state)
                 .await
-                .map_err(|e| OrcaError::Execution(format!("Agent execution failed: {}", e)))?;
+                .map_err(|e| OrcaError::Execution(format!("{} agent execution failed: {}", pattern, e)))?;
 
             // Extract messages from final state
             let messages = final_state
@@ -235,165 +246,27 @@ impl TaskExecutor {
 
         debug!(
             task_id = %task.id,
+            pattern = %pattern,
             message_count = messages.len(),
             "Agent execution completed"
         );
 
-        Ok(ExecutionResult::success(result, final_state, messages))
-    }
-
-    /// Execute a task using the Plan-Execute pattern
-    async fn execute_plan_execute(&self, task: &Task) -> Result<ExecutionResult> {
-        // Create tools from bridge
-        let tools = ToolAdapter::from_bridge(self.bridge.clone());
-
-        debug!(
-            task_id = %task.id,
-            tool_count = tools.len(),
-            "Created tool adapters for Plan-Execute"
-        );
-
-        // Create LLM function (used for both planner and executor)
-        let llm_fn = create_llm_function(self.llm_provider.clone());
-        let llm_fn_executor = create_llm_function(self.llm_provider.clone());
-
-        // Create Plan-Execute agent
-        let agent = create_plan_execute_agent(llm_fn, llm_fn_executor, tools)
-            .with_max_steps(self.config.execution.max_iterations)
-            .build()
-            .map_err(|e| OrcaError::Execution(format!("Failed to build Plan-Execute agent: {}", e)))?;
+        timings.record(PHASE_TOTAL, start.elapsed());
+        let timings = timings.entries();
+        self.log_timings(task, &timings);
 
-        // Prepare initial state with task description
-        let initial_state = json!({
-            "messages": vec![
-                json!({
-                    "type": "human",
-                    "content": task.description.clone()
-                })
-            ]
-        });
-
-        // Execute with or without streaming based on config
-        let (final_state, messages) = if self.config.execution.streaming {
-            debug!(task_id = %task.id, "Streaming Plan-Execute agent execution");
-            self.execute_with_streaming(&agent, initial_state, task).await?
-        } else {
-            debug!(task_id = %task.id, "Invoking Plan-Execute agent");
-
-            let final_state = agent
-                .invoke(initial_state)
-                .await
-                .map_err(|e| OrcaError::Execution(format!("Plan-Execute agent execution failed: {}", e)))?;
-
-            // Extract messages from final state
-            let messages = final_state
-                .get("messages")
-                .and_then(|m| m.as_array())
-                .cloned()
-                .unwrap_or_default();
-
-            (final_state, messages)
-        };
-
-        // Extract final AI message as result
-        let result = messages
-            .iter()
-            .rev()
-            .find(|msg| {
-                msg.get("type")
-                    .and_then(|t| t.as_str())
-                    .map(|t| t == "ai")
-                    .unwrap_or(false)
-            })
-            .and_then(|msg| msg.get("content"))
-            .and_then(|c| c.as_str())
-            .unwrap_or("No response generated")
-            .to_string();
-
-        debug!(
-            task_id = %task.id,
-            message_count = messages.len(),
-            "Plan-Execute agent execution completed"
-        );
-
-        Ok(ExecutionResult::success(result, final_state, messages))
+        Ok(ExecutionResult::success(result, final_state, messages).with_timings(timings))
     }
 
-    /// Execute a task using the Reflection pattern
-    async fn execute_reflection(&self, task: &Task) -> Result<ExecutionResult> {
-        // Create tools from bridge
-        let tools = ToolAdapter::from_bridge(self.bridge.clone());
-
-        debug!(
-            task_id = %task.id,
-            tool_count = tools.len(),
-            "Created tool adapters for Reflection"
-        );
-
-        // Create LLM functions (used for both generator and reflector)
-        let llm_fn_generator = create_llm_function(self.llm_provider.clone());
-        let llm_fn_reflector = create_llm_function(self.llm_provider.clone());
-
-        // Create Reflection agent
-        let agent = create_reflection_agent(llm_fn_generator, llm_fn_reflector, tools)
-            .with_max_iterations(self.config.execution.max_iterations)
-            .build()
-            .map_err(|e| OrcaError::Execution(format!("Failed to build Reflection agent: {}", e)))?;
-
-        // Prepare initial state with task description
-        let initial_state = json!({
-            "messages": vec![
-                json!({
-                    "type": "human",
-                    "content": task.description.clone()
-                })
-            ]
-        });
-
-        // Execute with or without streaming based on config
-        let (final_state, messages) = if self.config.execution.streaming {
-            debug!(task_id = %task.id, "Streaming Reflection agent execution");
-            self.execute_with_streaming(&agent, initial_state, task).await?
-        } else {
-            debug!(task_id = %task.id, "Invoking Reflection agent");
-
-            let final_state = agent
-                .invoke(initial_state)
-                .await
-                .map_err(|e| OrcaError::Execution(format!("Reflection agent execution failed: {}", e)))?;
-
-            // Extract messages from final state
-            let messages = final_state
-                .get("messages")
-                .and_then(|m| m.as_array())
-                .cloned()
-                .unwrap_or_default();
-
-            (final_state, messages)
-        };
-
-        // Extract final AI message as result
-        let result = messages
-            .iter()
-            .rev()
-            .find(|msg| {
-                msg.get("type")
-                    .and_then(|t| t.as_str())
-                    .map(|t| t == "ai")
-                    .unwrap_or(false)
-            })
-            .and_then(|msg| msg.get("content"))
-            .and_then(|c| c.as_str())
-            .unwrap_or("No response generated")
-            .to_string();
-
-        debug!(
-            task_id = %task.id,
-            message_count = messages.len(),
-            "Reflection agent execution completed"
-        );
-
-        Ok(ExecutionResult::success(result, final_state, messages))
+    /// Log one `PhaseCompleted` event per recorded phase
+    fn log_timings(&self, task: &Task, timings: &[(String, Duration)]) {
+        for (phase, duration) in timings {
+            self.event_logger.log(&ExecutionEvent::phase_completed(
+                task.id.clone(),
+                phase.clone(),
+                duration.as_millis() as u64,
+            ));
+        }
     }
 
     /// Execute agent with streaming output
@@ -532,6 +405,7 @@ mod tests {
                 format: "json".to_string(),
                 colored: false,
                 timestamps: true,
+                ..Default::default()
             },
             budget: Default::default(),
             workflow: Default::default(),
@@ -604,6 +478,114 @@ mod tests {
         assert!(result.error.is_none());
     }
 
+    #[test]
+    fn test_execution_result_timings() {
+        let result = ExecutionResult::success(
+            "Task completed".to_string(),
+            json!({"messages": []}),
+            vec![],
+        );
+        assert!(result.timings.is_empty());
+
+        let result = result.with_timings(vec![
+            ("llm_call".to_string(), Duration::from_millis(40)),
+            ("tool_call".to_string(), Duration::from_millis(5)),
+            (PHASE_TOTAL.to_string(), Duration::from_millis(50)),
+        ]);
+
+        let phases: Vec<&str> = result.timings.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(phases, vec!["llm_call", "tool_call", PHASE_TOTAL]);
+        // Total covers every individual phase
+        let total = result.timings.last().unwrap().1;
+        assert!(result.timings.iter().all(|(_, d)| *d <= total));
+    }
+
+    /// Serve every request with a canned Ollama chat reply
+    async fn stub_ollama(reply: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                // Read the headers, then the body they announce
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request).to_lowercase();
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text[..end]
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                }
+
+                let body = json!({
+                    "model": "stub",
+                    "message": {"role": "assistant", "content": reply},
+                    "done": true
+                })
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_execute_task_logs_phases_to_memory_sink() {
+        use crate::config::EventSinkConfig;
+
+        let temp_dir = TempDir::new().unwrap();
+        let bridge = Arc::new(
+            DirectToolBridge::new(
+                temp_dir.path().to_path_buf(),
+                "test-session".to_string(),
+            )
+            .unwrap()
+        );
+
+        let mut config = create_test_config();
+        config.llm.api_base = Some(stub_ollama("All done").await);
+        config.logging.event_sink = EventSinkConfig::Memory { capacity: 100 };
+        let event_logger = EventLogger::from_config(&config.logging).unwrap();
+
+        let executor = TaskExecutor::new(bridge, config)
+            .unwrap()
+            .with_event_logger(event_logger.clone());
+        let task = Task::new("Say you are done");
+        let result = executor.execute_task(&task).await.unwrap();
+        assert_eq!(result.result.as_deref(), Some("All done"));
+
+        let events = event_logger.sink().as_memory().unwrap().events();
+        let phases: Vec<&str> = events
+            .iter()
+            .map(|event| match event {
+                ExecutionEvent::PhaseCompleted { task_id, phase, .. } => {
+                    assert_eq!(task_id, &task.id);
+                    phase.as_str()
+                }
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(phases, vec!["llm_call", PHASE_TOTAL]);
+    }
+
     #[test]
     fn test_execution_result_failure() {
         let result = ExecutionResult::failure(
@@ -1000,9 +982,7 @@ mod tests {
         let _executor = TaskExecutor::new(bridge, config).unwrap();
 
         // If this compiles, all pattern methods exist with correct signatures:
-        // - execute_react()
-        // - execute_plan_execute()
-        // - execute_reflection()
+        // - execute_task_internal() (agents built via PatternType::build_agent)
         // - execute_with_streaming()
     }
 
@@ -0,0 +1,180 @@
+//! Phase Timings - Per-phase durations of a task execution
+//!
+//! Wraps the LLM function and tools handed to an agent so each LLM call and
+//! tool call is timed as it happens. Timings are kept in completion order.
+
+use async_trait::async_trait;
+use langgraph_prebuilt::agents::react::LlmFunction;
+use langgraph_prebuilt::tools::{Tool, ToolInput, ToolOutput};
+use langgraph_prebuilt::Result as PrebuiltResult;
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Phase name for a single LLM call
+pub const PHASE_LLM_CALL: &str = "llm_call";
+
+/// Phase name for a single tool call
+pub const PHASE_TOOL_CALL: &str = "tool_call";
+
+/// Phase name for the whole task execution
+pub const PHASE_TOTAL: &str = "total";
+
+/// Shared recorder of `(phase, duration)` entries
+#[derive(Debug, Clone, Default)]
+pub struct PhaseTimings {
+    entries: Arc<Mutex<Vec<(String, Duration)>>>,
+}
+
+impl PhaseTimings {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a completed phase
+    pub fn record(&self, phase: impl Into<String>, duration: Duration) {
+        self.entries.lock().push((phase.into(), duration));
+    }
+
+    /// Snapshot of the recorded phases, in completion order
+    pub fn entries(&self) -> Vec<(String, Duration)> {
+        self.entries.lock().clone()
+    }
+
+    /// Wrap an LLM function so every call is recorded as [`PHASE_LLM_CALL`]
+    pub fn wrap_llm(&self, llm: LlmFunction) -> LlmFunction {
+        let timings = self.clone();
+        Arc::new(move |state: Value| {
+            let llm = llm.clone();
+            let timings = timings.clone();
+            Box::pin(async move {
+                let start = Instant::now();
+                let response = llm(state).await;
+                timings.record(PHASE_LLM_CALL, start.elapsed());
+                response
+            })
+        })
+    }
+
+    /// Wrap tools so every execution is recorded as [`PHASE_TOOL_CALL`]
+    pub fn wrap_tools(&self, tools: Vec<Box<dyn Tool>>) -> Vec<Box<dyn Tool>> {
+        tools
+            .into_iter()
+            .map(|inner| {
+                Box::new(TimedTool {
+                    inner,
+                    timings: self.clone(),
+                }) as Box<dyn Tool>
+            })
+            .collect()
+    }
+}
+
+/// Tool wrapper that records its execution time
+struct TimedTool {
+    inner: Box<dyn Tool>,
+    timings: PhaseTimings,
+}
+
+#[async_trait]
+impl Tool for TimedTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn input_schema(&self) -> Option<Value> {
+        self.inner.input_schema()
+    }
+
+    async fn execute(&self, input: ToolInput) -> PrebuiltResult<ToolOutput> {
+        let start = Instant::now();
+        let output = self.inner.execute(input).await;
+        self.timings.record(PHASE_TOOL_CALL, start.elapsed());
+        output
+    }
+
+    fn validate_input(&self, input: &ToolInput) -> PrebuiltResult<()> {
+        self.inner.validate_input(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use langgraph_prebuilt::agents::create_react_agent;
+    use langgraph_prebuilt::{Message, ToolCall};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct EchoTool;
+
+    #[async_trait]
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn description(&self) -> &str {
+            "Echoes its input"
+        }
+
+        async fn execute(&self, input: ToolInput) -> PrebuiltResult<ToolOutput> {
+            Ok(input)
+        }
+    }
+
+    /// LLM that calls `echo` once, then answers
+    fn tool_calling_llm() -> LlmFunction {
+        let calls = Arc::new(AtomicUsize::new(0));
+        Arc::new(move |_state| {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if n == 0 {
+                    let call = ToolCall::new("call_1", "echo", serde_json::json!({"text": "hi"}));
+                    Ok(Message::ai("Echoing").with_tool_calls(vec![call]))
+                } else {
+                    Ok(Message::ai("Done"))
+                }
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_agent_phases_recorded_in_order() {
+        let timings = PhaseTimings::new();
+        let agent = create_react_agent(
+            timings.wrap_llm(tool_calling_llm()),
+            timings.wrap_tools(vec![Box::new(EchoTool)]),
+        )
+        .build()
+        .unwrap();
+
+        agent
+            .invoke(serde_json::json!({"messages": vec![Message::human("Echo hi")]}))
+            .await
+            .unwrap();
+
+        let phases: Vec<String> = timings.entries().into_iter().map(|(phase, _)| phase).collect();
+        assert_eq!(phases, vec![PHASE_LLM_CALL, PHASE_TOOL_CALL, PHASE_LLM_CALL]);
+    }
+
+    #[test]
+    fn test_record_keeps_order() {
+        let timings = PhaseTimings::new();
+        timings.record("a", Duration::from_millis(5));
+        timings.record("b", Duration::from_millis(1));
+
+        assert_eq!(
+            timings.entries(),
+            vec![
+                ("a".to_string(), Duration::from_millis(5)),
+                ("b".to_string(), Duration::from_millis(1)),
+            ]
+        );
+    }
+}
@@ -4,7 +4,13 @@
 
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// Default time limit for a single component check
+///
+/// A check that exceeds its limit reports the component as unhealthy instead
+/// of holding up the whole report.
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Health check status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -116,11 +122,63 @@ pub struct HealthChecker;
 impl HealthChecker {
     /// Check database health
     pub async fn check_database(db: &crate::db::Database) -> ComponentHealth {
+        Self::check_database_with_timeout(db, DEFAULT_CHECK_TIMEOUT).await
+    }
+
+    /// Check database health by running `SELECT 1` within a time limit
+    pub async fn check_database_with_timeout(
+        db: &crate::db::Database,
+        timeout: Duration,
+    ) -> ComponentHealth {
+        let start = Instant::now();
+
+        match tokio::time::timeout(timeout, db.health_check()).await {
+            Ok(Ok(_)) => ComponentHealth::healthy("database", start.elapsed().as_millis() as u64),
+            Ok(Err(e)) => ComponentHealth::unhealthy("database", format!("Database error: {}", e), start.elapsed().as_millis() as u64),
+            Err(_) => ComponentHealth::unhealthy(
+                "database",
+                format!("Database check timed out after {}ms", timeout.as_millis()),
+                start.elapsed().as_millis() as u64,
+            ),
+        }
+    }
+
+    /// Check LLM provider reachability
+    pub async fn check_llm_provider(llm: &crate::config::LlmConfig) -> ComponentHealth {
+        Self::check_llm_provider_with_timeout(llm, DEFAULT_CHECK_TIMEOUT).await
+    }
+
+    /// Check LLM provider reachability within a time limit
+    ///
+    /// This is a lightweight check: it opens a TCP connection to the provider
+    /// endpoint (`api_base`, or the provider's well-known host) without sending
+    /// a request, so it costs no tokens and needs no valid API key.
+    pub async fn check_llm_provider_with_timeout(
+        llm: &crate::config::LlmConfig,
+        timeout: Duration,
+    ) -> ComponentHealth {
         let start = Instant::now();
 
-        match db.health_check().await {
-            Ok(_) => ComponentHealth::healthy("database", start.elapsed().as_millis() as u64),
-            Err(e) => ComponentHealth::unhealthy("database", format!("Database error: {}", e), start.elapsed().as_millis() as u64),
+        let Some(address) = llm_endpoint_address(llm) else {
+            return ComponentHealth::degraded(
+                "llm_provider",
+                format!("No known endpoint for provider '{}'; set api_base", llm.provider),
+                start.elapsed().as_millis() as u64,
+            );
+        };
+
+        match tokio::time::timeout(timeout, tokio::net::TcpStream::connect(&address)).await {
+            Ok(Ok(_)) => ComponentHealth::healthy("llm_provider", start.elapsed().as_millis() as u64),
+            Ok(Err(e)) => ComponentHealth::unhealthy(
+                "llm_provider",
+                format!("Cannot reach {} at {}: {}", llm.provider, address, e),
+                start.elapsed().as_millis() as u64,
+            ),
+            Err(_) => ComponentHealth::unhealthy(
+                "llm_provider",
+                format!("Connecting to {} at {} timed out after {}ms", llm.provider, address, timeout.as_millis()),
+                start.elapsed().as_millis() as u64,
+            ),
         }
     }
 
@@ -200,6 +258,9 @@ impl HealthChecker {
         // Check configuration
         checks.push(Self::check_config(context.config()));
 
+        // Check LLM provider
+        checks.push(Self::check_llm_provider(&context.config().llm).await);
+
         // Check tool bridge
         checks.push(Self::check_tool_bridge(context.tool_bridge()));
 
@@ -210,6 +271,45 @@ impl HealthChecker {
     }
 }
 
+/// Resolve the `host:port` an LLM provider is reached at
+fn llm_endpoint_address(llm: &crate::config::LlmConfig) -> Option<String> {
+    let base = match llm.api_base.as_deref() {
+        Some(base) => base,
+        None => match llm.provider.as_str() {
+            "anthropic" => "https://api.anthropic.com",
+            "openai" => "https://api.openai.com",
+            "gemini" | "google" => "https://generativelanguage.googleapis.com",
+            "ollama" => "http://localhost:11434",
+            _ => return None,
+        },
+    };
+
+    let (default_port, rest) = if let Some(rest) = base.strip_prefix("https://") {
+        (443, rest)
+    } else if let Some(rest) = base.strip_prefix("http://") {
+        (80, rest)
+    } else {
+        (443, base)
+    };
+
+    let authority = rest.split('/').next().unwrap_or(rest);
+    if authority.is_empty() {
+        return None;
+    }
+
+    // Bracketed IPv6 literals contain ':' in the host itself
+    let has_port = match authority.rfind(']') {
+        Some(end) => authority[end..].contains(':'),
+        None => authority.contains(':'),
+    };
+
+    if has_port {
+        Some(authority.to_string())
+    } else {
+        Some(format!("{}:{}", authority, default_port))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,4 +382,84 @@ mod tests {
         let report = HealthReport::new(checks);
         assert_eq!(report.status, HealthStatus::Unhealthy);
     }
+
+    fn llm_config(provider: &str, api_base: Option<&str>) -> crate::config::LlmConfig {
+        crate::config::LlmConfig {
+            provider: provider.to_string(),
+            api_base: api_base.map(|s| s.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_llm_endpoint_address() {
+        assert_eq!(
+            llm_endpoint_address(&llm_config("anthropic", None)).as_deref(),
+            Some("api.anthropic.com:443")
+        );
+        assert_eq!(
+            llm_endpoint_address(&llm_config("ollama", None)).as_deref(),
+            Some("localhost:11434")
+        );
+        assert_eq!(
+            llm_endpoint_address(&llm_config("openai", Some("http://10.0.0.5:8080/v1"))).as_deref(),
+            Some("10.0.0.5:8080")
+        );
+        assert_eq!(
+            llm_endpoint_address(&llm_config("openai", Some("http://proxy.local/v1"))).as_deref(),
+            Some("proxy.local:80")
+        );
+        assert_eq!(llm_endpoint_address(&llm_config("custom", None)), None);
+    }
+
+    #[tokio::test]
+    async fn test_check_database_healthy() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::new(temp_dir.path().join("health.db"))
+            .await
+            .unwrap();
+
+        let health = HealthChecker::check_database(&db).await;
+        assert_eq!(health.name, "database");
+        assert_eq!(health.status, HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_check_llm_provider_unreachable() {
+        // Bind then drop a listener so the port is known to be closed
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let config = llm_config("ollama", Some(&format!("http://127.0.0.1:{}", port)));
+        let health =
+            HealthChecker::check_llm_provider_with_timeout(&config, Duration::from_secs(2)).await;
+
+        assert_eq!(health.name, "llm_provider");
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+    }
+
+    #[tokio::test]
+    async fn test_check_llm_provider_reachable() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let config = llm_config("ollama", Some(&format!("http://127.0.0.1:{}", port)));
+        let health =
+            HealthChecker::check_llm_provider_with_timeout(&config, Duration::from_secs(2)).await;
+
+        assert_eq!(health.status, HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires a network that drops packets to non-routable addresses
+    async fn test_check_llm_provider_timeout_is_unhealthy() {
+        // Non-routable address: the connect hangs until the check times out
+        let config = llm_config("openai", Some("http://10.255.255.1:81"));
+        let health =
+            HealthChecker::check_llm_provider_with_timeout(&config, Duration::from_millis(100)).await;
+
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert!(health.response_time_ms < 5_000);
+    }
 }
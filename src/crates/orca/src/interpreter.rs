@@ -47,6 +47,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
+pub mod expression;
+
+pub use expression::{evaluate, Expression};
+
 /// Parsed action call ready for execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionCall {
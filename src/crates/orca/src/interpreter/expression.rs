@@ -0,0 +1,583 @@
+//! Condition expressions over JSON state
+//!
+//! Lets workflows branch on declarative conditions such as
+//! `state.score > 0.8 && state.retries < 3` instead of compiled closures.
+//!
+//! # Syntax
+//!
+//! - Literals: numbers, `'single'` or `"double"` quoted strings, `true`,
+//!   `false` and `null`
+//! - Field access: `state.result.score`, `state.items[0]`
+//! - Comparison: `==`, `!=`, `<`, `<=`, `>`, `>=`
+//! - Boolean: `&&`, `||`, `!`, and parentheses for grouping
+//!
+//! A field that does not exist evaluates to `null`. Ordering comparisons
+//! only hold between two numbers or two strings, so `state.missing > 1` is
+//! false rather than an error. Non-boolean operands of `&&`, `||` and `!`
+//! are truthy unless they are `null`, `false`, `0`, or empty.
+//!
+//! Parentheses and prefix operators may nest at most [`MAX_NESTING_DEPTH`]
+//! levels deep; deeper expressions are rejected as syntax errors.
+
+use crate::error::{OrcaError, Result};
+use serde_json::Value;
+use std::fmt;
+
+/// Deepest nesting of parentheses and `!`/`-` prefixes the parser accepts
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// Parse and evaluate `expr` against `context` in one step
+///
+/// Use [`Expression::parse`] to evaluate the same condition repeatedly.
+pub fn evaluate(expr: &str, context: &Value) -> Result<bool> {
+    Ok(Expression::parse(expr)?.evaluate(context))
+}
+
+/// A parsed condition expression
+#[derive(Debug, Clone)]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+impl Expression {
+    /// Parse an expression, failing with [`OrcaError::Workflow`] on a syntax
+    /// error
+    pub fn parse(expr: &str) -> Result<Self> {
+        let syntax_error =
+            |msg: String| OrcaError::Workflow(format!("Invalid condition '{}': {}", expr, msg));
+
+        let tokens = tokenize(expr).map_err(syntax_error)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            depth: 0,
+        };
+        let root = parser.parse_or().map_err(syntax_error)?;
+        if let Some(token) = parser.peek() {
+            return Err(syntax_error(format!("unexpected {}", token)));
+        }
+
+        Ok(Self {
+            source: expr.to_string(),
+            root,
+        })
+    }
+
+    /// The expression as written
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluate against a JSON context
+    pub fn evaluate(&self, context: &Value) -> bool {
+        truthy(&self.root.eval(context))
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Str(String),
+    Ident(String),
+    Dot,
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    Not,
+    Minus,
+    And,
+    Or,
+    Op(CompareOp),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "number {}", n),
+            Self::Str(s) => write!(f, "string '{}'", s),
+            Self::Ident(name) => write!(f, "'{}'", name),
+            Self::Dot => f.write_str("'.'"),
+            Self::LBracket => f.write_str("'['"),
+            Self::RBracket => f.write_str("']'"),
+            Self::LParen => f.write_str("'('"),
+            Self::RParen => f.write_str("')'"),
+            Self::Not => f.write_str("'!'"),
+            Self::Minus => f.write_str("'-'"),
+            Self::And => f.write_str("'&&'"),
+            Self::Or => f.write_str("'||'"),
+            Self::Op(op) => write!(f, "'{}'", op.symbol()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    fn apply(&self, left: &Value, right: &Value) -> bool {
+        match self {
+            Self::Eq => values_equal(left, right),
+            Self::Ne => !values_equal(left, right),
+            _ => {
+                let ordering = match (left, right) {
+                    (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+                    (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+                    _ => None,
+                };
+                match ordering {
+                    Some(ordering) => match self {
+                        Self::Lt => ordering.is_lt(),
+                        Self::Le => ordering.is_le(),
+                        Self::Gt => ordering.is_gt(),
+                        _ => ordering.is_ge(),
+                    },
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+fn tokenize(expr: &str) -> std::result::Result<Vec<Token>, String> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '.' => {
+                tokens.push(Token::Dot);
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '&' if next == Some('&') => {
+                tokens.push(Token::And);
+                i += 2;
+            }
+            '|' if next == Some('|') => {
+                tokens.push(Token::Or);
+                i += 2;
+            }
+            '=' if next == Some('=') => {
+                tokens.push(Token::Op(CompareOp::Eq));
+                i += 2;
+            }
+            '!' if next == Some('=') => {
+                tokens.push(Token::Op(CompareOp::Ne));
+                i += 2;
+            }
+            '!' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '<' | '>' => {
+                let op = match (c, next == Some('=')) {
+                    ('<', false) => CompareOp::Lt,
+                    ('<', true) => CompareOp::Le,
+                    ('>', false) => CompareOp::Gt,
+                    _ => CompareOp::Ge,
+                };
+                tokens.push(Token::Op(op));
+                i += if next == Some('=') { 2 } else { 1 };
+            }
+            '\'' | '"' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&ch| ch == c)
+                    .ok_or_else(|| format!("unterminated string at position {}", i))?;
+                tokens.push(Token::Str(chars[i + 1..i + 1 + end].iter().collect()));
+                i += end + 2;
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text
+                    .parse()
+                    .map_err(|_| format!("invalid number '{}'", text))?;
+                tokens.push(Token::Number(n));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            _ => return Err(format!("unexpected character '{}' at position {}", c, i)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Literal(Value),
+    Path(Vec<PathSegment>),
+    Not(Box<Node>),
+    Negate(Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+    Compare(CompareOp, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn eval(&self, context: &Value) -> Value {
+        match self {
+            Self::Literal(value) => value.clone(),
+            Self::Path(segments) => {
+                let mut current = context;
+                for segment in segments {
+                    let next = match segment {
+                        PathSegment::Key(key) => current.get(key),
+                        PathSegment::Index(index) => current.get(*index),
+                    };
+                    match next {
+                        Some(value) => current = value,
+                        None => return Value::Null,
+                    }
+                }
+                current.clone()
+            }
+            Self::Not(inner) => Value::Bool(!truthy(&inner.eval(context))),
+            Self::Negate(inner) => match inner.eval(context).as_f64() {
+                Some(n) => number(-n),
+                None => Value::Null,
+            },
+            Self::And(left, right) => {
+                Value::Bool(truthy(&left.eval(context)) && truthy(&right.eval(context)))
+            }
+            Self::Or(left, right) => {
+                Value::Bool(truthy(&left.eval(context)) || truthy(&right.eval(context)))
+            }
+            Self::Compare(op, left, right) => {
+                Value::Bool(op.apply(&left.eval(context), &right.eval(context)))
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Current nesting of parentheses and prefix operators
+    depth: usize,
+}
+
+impl Parser {
+    /// Run `parse` one nesting level deeper, failing past [`MAX_NESTING_DEPTH`]
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> std::result::Result<Node, String>,
+    ) -> std::result::Result<Node, String> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(format!(
+                "expression nested more than {} levels deep",
+                MAX_NESTING_DEPTH
+            ));
+        }
+        self.depth += 1;
+        let node = parse(self);
+        self.depth -= 1;
+        node
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> std::result::Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("expected {}, found {}", expected, token)),
+            None => Err(format!("expected {}, found end of expression", expected)),
+        }
+    }
+
+    fn parse_or(&mut self) -> std::result::Result<Node, String> {
+        let mut node = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            node = Node::Or(Box::new(node), Box::new(self.parse_and()?));
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> std::result::Result<Node, String> {
+        let mut node = self.parse_comparison()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            node = Node::And(Box::new(node), Box::new(self.parse_comparison()?));
+        }
+        Ok(node)
+    }
+
+    fn parse_comparison(&mut self) -> std::result::Result<Node, String> {
+        let left = self.parse_unary()?;
+        match self.peek() {
+            Some(Token::Op(op)) => {
+                let op = *op;
+                self.pos += 1;
+                let right = self.parse_unary()?;
+                if let Some(Token::Op(next)) = self.peek() {
+                    return Err(format!(
+                        "comparisons cannot be chained; found '{}' after '{}'",
+                        next.symbol(),
+                        op.symbol()
+                    ));
+                }
+                Ok(Node::Compare(op, Box::new(left), Box::new(right)))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_unary(&mut self) -> std::result::Result<Node, String> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(Node::Not(Box::new(self.nested(Self::parse_unary)?)))
+            }
+            Some(Token::Minus) => {
+                self.pos += 1;
+                Ok(Node::Negate(Box::new(self.nested(Self::parse_unary)?)))
+            }
+            _ => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> std::result::Result<Node, String> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Node::Literal(number(n))),
+            Some(Token::Str(s)) => Ok(Node::Literal(Value::String(s))),
+            Some(Token::LParen) => {
+                let node = self.nested(Self::parse_or)?;
+                self.expect(Token::RParen)?;
+                Ok(node)
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Node::Literal(Value::Bool(true))),
+                "false" => Ok(Node::Literal(Value::Bool(false))),
+                "null" => Ok(Node::Literal(Value::Null)),
+                _ => self.parse_path(name),
+            },
+            Some(token) => Err(format!("unexpected {}", token)),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+
+    fn parse_path(&mut self, first: String) -> std::result::Result<Node, String> {
+        let mut segments = vec![PathSegment::Key(first)];
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Ident(key)) => segments.push(PathSegment::Key(key)),
+                        Some(token) => return Err(format!("expected field name, found {}", token)),
+                        None => return Err("expected field name after '.'".to_string()),
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    let index = match self.next() {
+                        Some(Token::Number(n)) if n >= 0.0 && n.fract() == 0.0 => n as usize,
+                        Some(Token::Str(key)) => {
+                            self.expect(Token::RBracket)?;
+                            segments.push(PathSegment::Key(key));
+                            continue;
+                        }
+                        Some(token) => return Err(format!("expected index, found {}", token)),
+                        None => return Err("expected index after '['".to_string()),
+                    };
+                    self.expect(Token::RBracket)?;
+                    segments.push(PathSegment::Index(index));
+                }
+                _ => return Ok(Node::Path(segments)),
+            }
+        }
+    }
+}
+
+fn number(n: f64) -> Value {
+    serde_json::Number::from_f64(n)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => left == right,
+    }
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(fields) => !fields.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn context() -> Value {
+        json!({
+            "state": {
+                "score": 0.92,
+                "retries": 1,
+                "status": "needs_review",
+                "approved": false,
+                "items": [{"name": "first"}]
+            }
+        })
+    }
+
+    #[test]
+    fn test_numeric_comparison() {
+        let ctx = context();
+        assert!(evaluate("state.score > 0.8", &ctx).unwrap());
+        assert!(!evaluate("state.score <= 0.8", &ctx).unwrap());
+        assert!(evaluate("state.retries == 1", &ctx).unwrap());
+        assert!(evaluate("state.retries >= 1.0", &ctx).unwrap());
+        assert!(evaluate("state.retries != 2", &ctx).unwrap());
+        assert!(evaluate("state.retries > -1", &ctx).unwrap());
+    }
+
+    #[test]
+    fn test_boolean_logic() {
+        let ctx = context();
+        assert!(evaluate("state.score > 0.8 && state.retries < 3", &ctx).unwrap());
+        assert!(!evaluate("state.score > 0.95 && state.retries < 3", &ctx).unwrap());
+        assert!(evaluate("state.approved || state.status == 'needs_review'", &ctx).unwrap());
+        assert!(evaluate("!state.approved", &ctx).unwrap());
+        assert!(evaluate(
+            "!(state.score < 0.5 || state.retries > 2) && state.items[0].name == \"first\"",
+            &ctx
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_missing_fields_are_null() {
+        let ctx = context();
+        assert!(!evaluate("state.missing > 1", &ctx).unwrap());
+        assert!(!evaluate("state.missing < 1", &ctx).unwrap());
+        assert!(evaluate("state.missing == null", &ctx).unwrap());
+        assert!(evaluate("state.score.deeper == null", &ctx).unwrap());
+        assert!(!evaluate("state.items[5].name", &ctx).unwrap());
+        assert!(!evaluate("other.field", &json!(null)).unwrap());
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let ctx = context();
+        for expr in [
+            "state.score >",
+            "state.score > 0.8 &&",
+            "(state.score > 0.8",
+            "state.score = 1",
+            "state.status == 'open",
+            "1 < state.score < 2",
+            "",
+        ]
+        .into_iter()
+        .map(String::from)
+        .chain([
+            format!("{}1{}", "(".repeat(MAX_NESTING_DEPTH + 1), ")".repeat(MAX_NESTING_DEPTH + 1)),
+            format!("{}true", "!".repeat(100_000)),
+        ]) {
+            let expr = expr.as_str();
+            let err = evaluate(expr, &ctx).unwrap_err();
+            assert!(
+                matches!(err, OrcaError::Workflow(ref msg) if msg.contains("Invalid condition")),
+                "expected syntax error for {:?}, got {:?}",
+                expr,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_nesting_up_to_the_limit_parses() {
+        let expr = format!(
+            "{}state.score > 0.8{}",
+            "(".repeat(MAX_NESTING_DEPTH),
+            ")".repeat(MAX_NESTING_DEPTH)
+        );
+        assert!(evaluate(&expr, &context()).unwrap());
+
+        let err = evaluate(&format!("({})", expr), &context()).unwrap_err();
+        assert!(err.to_string().contains("nested more than"));
+    }
+}
@@ -77,11 +77,11 @@ pub mod testing;
 
 // Re-export key types for convenience
 pub use tools::DirectToolBridge;
-pub use workflow::{Task, TaskStatus, Workflow, WorkflowStatus};
+pub use workflow::{Task, TaskStatus, Workflow, WorkflowExport, WorkflowStatus};
 pub use pattern::PatternType;
 pub use executor::{TaskExecutor, ExecutionResult, LlmProvider, ToolAdapter};
 pub use context::{ExecutionContext, ContextBuilder, SessionInfo};
-pub use shutdown::ShutdownCoordinator;
+pub use shutdown::{DrainReport, ShutdownCoordinator};
 
 // Error types
 pub use error::{OrcaError, Result};
@@ -112,7 +112,9 @@ pub use models::{
 pub use health::{HealthChecker, HealthReport, HealthStatus, ComponentHealth};
 
 // Re-export event types
-pub use events::{ExecutionEvent, EventLogger};
+pub use events::{
+    EventLogger, EventSink, ExecutionEvent, FileSink, MemorySink, StdoutJsonSink, TracingSink,
+};
 
 #[cfg(test)]
 mod tests {
@@ -2,7 +2,10 @@
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use sqlx::FromRow;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
 use uuid::Uuid;
 
 /// Abstract Syntax Tree cache entry
@@ -46,6 +49,9 @@ pub struct AstCache {
 
     /// Last access timestamp (Unix timestamp)
     pub accessed_at: i64,
+
+    /// Source file modification time when cached (Unix milliseconds)
+    pub source_mtime: Option<i64>,
 }
 
 impl AstCache {
@@ -65,6 +71,7 @@ impl AstCache {
             created_at: now,
             updated_at: now,
             accessed_at: now,
+            source_mtime: None,
         }
     }
 
@@ -92,6 +99,12 @@ impl AstCache {
         self
     }
 
+    /// Builder: Set source file modification time (Unix milliseconds)
+    pub fn with_source_mtime(mut self, mtime_ms: i64) -> Self {
+        self.source_mtime = Some(mtime_ms);
+        self
+    }
+
     /// Update access timestamp
     pub fn touch(&mut self) {
         self.accessed_at = Utc::now().timestamp();
@@ -102,3 +115,16 @@ impl AstCache {
         self.content_hash != current_hash
     }
 }
+
+/// Modification time (Unix milliseconds) of the file at `path`
+pub fn source_mtime(path: impl AsRef<Path>) -> std::io::Result<i64> {
+    let modified = std::fs::metadata(path)?.modified()?;
+    let since_epoch = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+    Ok(since_epoch.as_millis() as i64)
+}
+
+/// SHA-256 hex digest of the file at `path`, as stored in [`AstCache::content_hash`]
+pub fn source_content_hash(path: impl AsRef<Path>) -> std::io::Result<String> {
+    let content = std::fs::read(path)?;
+    Ok(format!("{:x}", Sha256::digest(&content)))
+}
@@ -1,5 +1,6 @@
 //! Bug tracking model
 
+use crate::error::{OrcaError, Result};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -19,6 +20,10 @@ pub enum BugStatus {
     Wontfix,
     /// Bug is a duplicate of another bug
     Duplicate,
+    /// Resolution has been verified and the bug is closed
+    Closed,
+    /// A resolved or closed bug has been reopened
+    Reopened,
 }
 
 impl BugStatus {
@@ -29,6 +34,27 @@ impl BugStatus {
             Self::Fixed => "fixed",
             Self::Wontfix => "wontfix",
             Self::Duplicate => "duplicate",
+            Self::Closed => "closed",
+            Self::Reopened => "reopened",
+        }
+    }
+
+    /// Whether the bug has a resolution (fixed, wontfix or duplicate)
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, Self::Fixed | Self::Wontfix | Self::Duplicate)
+    }
+
+    /// Whether a bug in this status may move to `to`
+    ///
+    /// Bugs are worked on and resolved, a resolution is then either closed
+    /// or reopened, and a closed bug can only come back by being reopened.
+    pub fn can_transition_to(&self, to: BugStatus) -> bool {
+        use BugStatus::*;
+        match self {
+            Open | Reopened => matches!(to, InProgress | Fixed | Wontfix | Duplicate),
+            InProgress => matches!(to, Open | Fixed | Wontfix | Duplicate),
+            Fixed | Wontfix | Duplicate => matches!(to, Closed | Reopened),
+            Closed => to == Reopened,
         }
     }
 }
@@ -47,6 +73,8 @@ impl From<&str> for BugStatus {
             "fixed" => Self::Fixed,
             "wontfix" => Self::Wontfix,
             "duplicate" => Self::Duplicate,
+            "closed" => Self::Closed,
+            "reopened" => Self::Reopened,
             _ => Self::Open,
         }
     }
@@ -174,6 +202,36 @@ impl Bug {
         self
     }
 
+    /// Get the bug status
+    pub fn status(&self) -> BugStatus {
+        BugStatus::from(self.status.as_str())
+    }
+
+    /// Move the bug to a new status
+    ///
+    /// Returns [`OrcaError::InvalidTransition`] and leaves the bug untouched
+    /// if the move is not allowed by [`BugStatus::can_transition_to`].
+    /// Resolving sets `resolved_at`; reopening clears it.
+    pub fn transition(&mut self, to: BugStatus) -> Result<()> {
+        let from = self.status();
+        if !from.can_transition_to(to) {
+            return Err(OrcaError::InvalidTransition {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+
+        let now = Utc::now().timestamp();
+        if to.is_resolved() {
+            self.resolved_at = Some(now);
+        } else if to == BugStatus::Reopened {
+            self.resolved_at = None;
+        }
+        self.status = to.as_str().to_string();
+        self.updated_at = now;
+        Ok(())
+    }
+
     /// Mark bug as fixed
     pub fn mark_fixed(&mut self) {
         self.status = BugStatus::Fixed.as_str().to_string();
@@ -187,3 +245,50 @@ impl Bug {
         self.updated_at = Utc::now().timestamp();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_lifecycle() {
+        let mut bug = Bug::new("Crash on startup".to_string());
+        assert_eq!(bug.status(), BugStatus::Open);
+
+        bug.transition(BugStatus::InProgress).unwrap();
+        bug.transition(BugStatus::Fixed).unwrap();
+        assert!(bug.resolved_at.is_some());
+
+        bug.transition(BugStatus::Closed).unwrap();
+        bug.transition(BugStatus::Reopened).unwrap();
+        assert_eq!(bug.status(), BugStatus::Reopened);
+        assert!(bug.resolved_at.is_none());
+
+        bug.transition(BugStatus::Wontfix).unwrap();
+        bug.transition(BugStatus::Closed).unwrap();
+        assert_eq!(bug.status, "closed");
+    }
+
+    #[test]
+    fn test_illegal_transition_rejected() {
+        let mut bug = Bug::new("Crash on startup".to_string());
+        bug.transition(BugStatus::Fixed).unwrap();
+        bug.transition(BugStatus::Closed).unwrap();
+        let updated_at = bug.updated_at;
+
+        let err = bug.transition(BugStatus::InProgress).unwrap_err();
+        match err {
+            OrcaError::InvalidTransition { from, to } => {
+                assert_eq!(from, "closed");
+                assert_eq!(to, "in_progress");
+            }
+            other => panic!("expected InvalidTransition, got {:?}", other),
+        }
+        assert_eq!(bug.status(), BugStatus::Closed);
+        assert_eq!(bug.updated_at, updated_at);
+
+        // Open bugs must be resolved before they can be closed
+        let mut bug = Bug::new("Typo".to_string());
+        assert!(bug.transition(BugStatus::Closed).is_err());
+    }
+}
@@ -99,6 +99,13 @@
 //! println!("Description: {}", pattern.description());
 //! ```
 
+use crate::config::ExecutionConfig;
+use crate::error::{OrcaError, Result};
+use langgraph_core::CompiledGraph;
+use langgraph_prebuilt::agents::react::LlmFunction;
+use langgraph_prebuilt::agents::{create_plan_execute_agent, create_react_agent, create_reflection_agent};
+use langgraph_prebuilt::tools::Tool;
+
 /// Pattern type for agent execution
 ///
 /// Represents the different agent patterns available from langgraph-prebuilt.
@@ -163,6 +170,34 @@ impl PatternType {
             Self::Reflection => "Generate, critique, and refine loop for quality output",
         }
     }
+
+    /// Build the langgraph-prebuilt agent for this pattern
+    ///
+    /// Patterns with several LLM roles (planner/executor, generator/reflector)
+    /// use `llm` for all of them. `config.max_iterations` caps ReAct and
+    /// Reflection iterations and Plan-Execute steps.
+    pub fn build_agent(
+        &self,
+        llm: LlmFunction,
+        tools: Vec<Box<dyn Tool>>,
+        config: &ExecutionConfig,
+    ) -> Result<CompiledGraph> {
+        let agent = match self {
+            Self::React => create_react_agent(llm, tools)
+                .with_max_iterations(config.max_iterations)
+                .build(),
+            Self::PlanExecute => create_plan_execute_agent(llm.clone(), llm, tools)
+                .with_max_steps(config.max_iterations)
+                .build(),
+            Self::Reflection => create_reflection_agent(llm.clone(), llm, tools)
+                .with_max_iterations(config.max_iterations)
+                .build(),
+        };
+
+        agent.map_err(|e| {
+            OrcaError::Execution(format!("Failed to build {} agent: {}", self.name(), e))
+        })
+    }
 }
 
 impl std::fmt::Display for PatternType {
@@ -173,12 +208,8 @@ impl std::fmt::Display for PatternType {
 
 // Implementation Note:
 //
-// All three patterns are fully implemented in the executor module:
-// - TaskExecutor::execute_react() - ReAct pattern implementation
-// - TaskExecutor::execute_plan_execute() - Plan-Execute pattern
-// - TaskExecutor::execute_reflection() - Reflection pattern
-//
-// Each pattern integrates with:
+// TaskExecutor builds every pattern through PatternType::build_agent(), which
+// integrates:
 // - DirectToolBridge for tool execution (15+ built-in tools)
 // - LangGraph prebuilt agents (create_react_agent, etc.)
 // - Multi-provider LLM support (Ollama, OpenAI, Claude, etc.)
@@ -238,5 +269,40 @@ mod tests {
         assert_eq!(format!("{}", PatternType::PlanExecute), "Plan-Execute");
         assert_eq!(format!("{}", PatternType::Reflection), "Reflection");
     }
-}
 
+    /// LLM that always answers without tool calls and with a passing critique
+    fn mock_llm() -> LlmFunction {
+        std::sync::Arc::new(|_state| {
+            Box::pin(async {
+                Ok(langgraph_prebuilt::Message::ai(
+                    r#"Done. {"quality_score": 0.95, "strengths": [], "weaknesses": [], "suggestions": []}"#,
+                ))
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_build_agent_for_each_pattern() {
+        let config = ExecutionConfig {
+            max_iterations: 3,
+            ..Default::default()
+        };
+
+        for pattern in [PatternType::React, PatternType::PlanExecute, PatternType::Reflection] {
+            let agent = pattern
+                .build_agent(mock_llm(), vec![], &config)
+                .unwrap_or_else(|e| panic!("{} agent failed to build: {}", pattern, e));
+
+            let state = agent
+                .invoke(serde_json::json!({
+                    "messages": [{"type": "human", "content": "Summarize the repo"}],
+                    "objective": "Summarize the repo",
+                    "query": "Summarize the repo",
+                }))
+                .await
+                .unwrap_or_else(|e| panic!("{} agent failed to run: {}", pattern, e));
+
+            assert!(state.is_object(), "{} agent returned {:?}", pattern, state);
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::db::Database;
 use crate::error::{OrcaError, Result};
+use crate::models::ast_cache::{source_content_hash, source_mtime};
 use crate::models::AstCache;
 use chrono::Utc;
 use sqlx::Row;
@@ -24,8 +25,8 @@ impl AstCacheRepository {
         sqlx::query(
             "INSERT INTO ast_cache (id, file_path, language, content_hash, ast_data, symbols,
                                     imports, file_size, parse_duration_ms, created_at,
-                                    updated_at, accessed_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+                                    updated_at, accessed_at, source_mtime)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(&ast.id)
         .bind(&ast.file_path)
@@ -39,6 +40,7 @@ impl AstCacheRepository {
         .bind(ast.created_at)
         .bind(ast.updated_at)
         .bind(ast.accessed_at)
+        .bind(ast.source_mtime)
         .execute(self.db.pool())
         .await
         .map_err(|e| OrcaError::Database(format!("Failed to save AST cache: {}", e)))?;
@@ -50,7 +52,7 @@ impl AstCacheRepository {
     pub async fn find_by_id(&self, id: &str) -> Result<AstCache> {
         let row = sqlx::query(
             "SELECT id, file_path, language, content_hash, ast_data, symbols, imports,
-                    file_size, parse_duration_ms, created_at, updated_at, accessed_at
+                    file_size, parse_duration_ms, created_at, updated_at, accessed_at, source_mtime
              FROM ast_cache WHERE id = ?"
         )
         .bind(id)
@@ -72,6 +74,7 @@ impl AstCacheRepository {
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
             accessed_at: row.get("accessed_at"),
+            source_mtime: row.get("source_mtime"),
         })
     }
 
@@ -79,7 +82,7 @@ impl AstCacheRepository {
     pub async fn find_by_file_path(&self, file_path: &str) -> Result<AstCache> {
         let row = sqlx::query(
             "SELECT id, file_path, language, content_hash, ast_data, symbols, imports,
-                    file_size, parse_duration_ms, created_at, updated_at, accessed_at
+                    file_size, parse_duration_ms, created_at, updated_at, accessed_at, source_mtime
              FROM ast_cache WHERE file_path = ?"
         )
         .bind(file_path)
@@ -104,14 +107,55 @@ impl AstCacheRepository {
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
             accessed_at: row.get("accessed_at"),
+            source_mtime: row.get("source_mtime"),
         })
     }
 
+    /// Find the cached AST for `file_path` if it still matches the file on disk
+    ///
+    /// An entry whose stored `source_mtime` equals the file's current mtime is
+    /// returned without reading the file. Otherwise the file is hashed: a
+    /// matching `content_hash` is still a hit (the stored mtime is refreshed),
+    /// anything else returns `None`. A missing entry or unreadable file is
+    /// also `None`.
+    pub async fn get_valid(&self, file_path: &str) -> Result<Option<AstCache>> {
+        if !self.has_cache_for_file(file_path).await? {
+            return Ok(None);
+        }
+
+        let Ok(current_mtime) = source_mtime(file_path) else {
+            return Ok(None);
+        };
+
+        let mut cached = self.find_by_file_path(file_path).await?;
+        if cached.source_mtime == Some(current_mtime) {
+            return Ok(Some(cached));
+        }
+
+        let Ok(current_hash) = source_content_hash(file_path) else {
+            return Ok(None);
+        };
+        if cached.is_stale(&current_hash) {
+            return Ok(None);
+        }
+
+        // Touched but unchanged; remember the new mtime for the fast path
+        sqlx::query("UPDATE ast_cache SET source_mtime = ? WHERE id = ?")
+            .bind(current_mtime)
+            .bind(&cached.id)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to update AST cache mtime: {}", e)))?;
+        cached.source_mtime = Some(current_mtime);
+
+        Ok(Some(cached))
+    }
+
     /// List all AST cache entries
     pub async fn list(&self) -> Result<Vec<AstCache>> {
         let rows = sqlx::query(
             "SELECT id, file_path, language, content_hash, ast_data, symbols, imports,
-                    file_size, parse_duration_ms, created_at, updated_at, accessed_at
+                    file_size, parse_duration_ms, created_at, updated_at, accessed_at, source_mtime
              FROM ast_cache
              ORDER BY accessed_at DESC"
         )
@@ -134,6 +178,7 @@ impl AstCacheRepository {
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
                 accessed_at: row.get("accessed_at"),
+                source_mtime: row.get("source_mtime"),
             })
             .collect();
 
@@ -144,7 +189,7 @@ impl AstCacheRepository {
     pub async fn list_by_language(&self, language: &str) -> Result<Vec<AstCache>> {
         let rows = sqlx::query(
             "SELECT id, file_path, language, content_hash, ast_data, symbols, imports,
-                    file_size, parse_duration_ms, created_at, updated_at, accessed_at
+                    file_size, parse_duration_ms, created_at, updated_at, accessed_at, source_mtime
              FROM ast_cache
              WHERE language = ?
              ORDER BY accessed_at DESC"
@@ -169,6 +214,7 @@ impl AstCacheRepository {
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
                 accessed_at: row.get("accessed_at"),
+                source_mtime: row.get("source_mtime"),
             })
             .collect();
 
@@ -182,7 +228,8 @@ impl AstCacheRepository {
         sqlx::query(
             "UPDATE ast_cache
              SET file_path = ?, language = ?, content_hash = ?, ast_data = ?, symbols = ?,
-                 imports = ?, file_size = ?, parse_duration_ms = ?, updated_at = ?, accessed_at = ?
+                 imports = ?, file_size = ?, parse_duration_ms = ?, updated_at = ?, accessed_at = ?,
+                 source_mtime = ?
              WHERE id = ?"
         )
         .bind(&ast.file_path)
@@ -195,6 +242,7 @@ impl AstCacheRepository {
         .bind(ast.parse_duration_ms)
         .bind(updated_at)
         .bind(ast.accessed_at)
+        .bind(ast.source_mtime)
         .bind(&ast.id)
         .execute(self.db.pool())
         .await
@@ -264,3 +312,88 @@ impl AstCacheRepository {
         Ok(count > 0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    async fn setup() -> (TempDir, AstCacheRepository) {
+        let temp_dir = TempDir::new().unwrap();
+        let db = Database::new(temp_dir.path().join("project.db")).await.unwrap();
+        db.run_migrations_from("migrations/project").await.unwrap();
+        (temp_dir, AstCacheRepository::new(Arc::new(db)))
+    }
+
+    async fn cache_file(repo: &AstCacheRepository, path: &str) -> AstCache {
+        let entry = AstCache::new(
+            path.to_string(),
+            "rust".to_string(),
+            source_content_hash(path).unwrap(),
+            r#"{"kind":"file"}"#.to_string(),
+        )
+        .with_source_mtime(source_mtime(path).unwrap());
+        repo.save(&entry).await.unwrap();
+        entry
+    }
+
+    #[tokio::test]
+    async fn test_get_valid_hits_unchanged_file() {
+        let (temp_dir, repo) = setup().await;
+        let file = temp_dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+        let path = file.to_str().unwrap();
+
+        let entry = cache_file(&repo, path).await;
+
+        let hit = repo.get_valid(path).await.unwrap().unwrap();
+        assert_eq!(hit.id, entry.id);
+        assert_eq!(hit.source_mtime, entry.source_mtime);
+    }
+
+    #[tokio::test]
+    async fn test_get_valid_misses_after_modification() {
+        let (temp_dir, repo) = setup().await;
+        let file = temp_dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+        let path = file.to_str().unwrap();
+
+        cache_file(&repo, path).await;
+
+        std::fs::write(&file, "fn main() { println!(\"changed\"); }").unwrap();
+        let later = SystemTime::now() + Duration::from_secs(10);
+        std::fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        assert!(repo.get_valid(path).await.unwrap().is_none());
+        assert!(repo.get_valid("/nonexistent/file.rs").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_valid_refreshes_mtime_when_content_unchanged() {
+        let (temp_dir, repo) = setup().await;
+        let file = temp_dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {}").unwrap();
+        let path = file.to_str().unwrap();
+
+        let entry = cache_file(&repo, path).await;
+
+        let later = SystemTime::now() + Duration::from_secs(10);
+        std::fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        let hit = repo.get_valid(path).await.unwrap().unwrap();
+        assert_eq!(hit.id, entry.id);
+        assert_eq!(hit.source_mtime, Some(source_mtime(path).unwrap()));
+        assert_ne!(hit.source_mtime, entry.source_mtime);
+    }
+}
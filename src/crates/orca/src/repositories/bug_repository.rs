@@ -2,7 +2,7 @@
 
 use crate::db::Database;
 use crate::error::{OrcaError, Result};
-use crate::models::Bug;
+use crate::models::{Bug, BugPriority, BugStatus};
 use chrono::Utc;
 use sqlx::Row;
 use std::sync::Arc;
@@ -151,6 +151,48 @@ impl BugRepository {
         Ok(bugs)
     }
 
+    /// List bugs in a status
+    pub async fn by_status(&self, status: BugStatus) -> Result<Vec<Bug>> {
+        self.list_by_status(status.as_str()).await
+    }
+
+    /// List bugs with a priority, newest first
+    pub async fn by_priority(&self, priority: BugPriority) -> Result<Vec<Bug>> {
+        let rows = sqlx::query(
+            "SELECT id, title, description, status, priority, severity, assignee, reporter,
+                    labels, related_files, created_at, updated_at, resolved_at, metadata
+             FROM bugs
+             WHERE priority = ?
+             ORDER BY created_at DESC"
+        )
+        .bind(priority as i64)
+        .fetch_all(self.db.pool())
+        .await
+        .map_err(|e| OrcaError::Database(format!("Failed to list bugs by priority: {}", e)))?;
+
+        let bugs = rows
+            .into_iter()
+            .map(|row| Bug {
+                id: row.get("id"),
+                title: row.get("title"),
+                description: row.get("description"),
+                status: row.get("status"),
+                priority: row.get("priority"),
+                severity: row.get("severity"),
+                assignee: row.get("assignee"),
+                reporter: row.get("reporter"),
+                labels: row.get("labels"),
+                related_files: row.get("related_files"),
+                created_at: row.get("created_at"),
+                updated_at: row.get("updated_at"),
+                resolved_at: row.get("resolved_at"),
+                metadata: row.get("metadata"),
+            })
+            .collect();
+
+        Ok(bugs)
+    }
+
     /// List bugs assigned to a specific user
     pub async fn list_by_assignee(&self, assignee: &str) -> Result<Vec<Bug>> {
         let rows = sqlx::query(
@@ -241,3 +283,37 @@ impl BugRepository {
         Ok(row.get("count"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn setup() -> (TempDir, BugRepository) {
+        let temp_dir = TempDir::new().unwrap();
+        let db = Database::new(temp_dir.path().join("project.db")).await.unwrap();
+        db.run_migrations_from("migrations/project").await.unwrap();
+        (temp_dir, BugRepository::new(Arc::new(db)))
+    }
+
+    #[tokio::test]
+    async fn test_by_status_and_priority() {
+        let (_temp_dir, repo) = setup().await;
+
+        let mut fixed = Bug::new("Fixed crash".to_string()).with_priority(BugPriority::Critical);
+        fixed.transition(BugStatus::Fixed).unwrap();
+        repo.save(&fixed).await.unwrap();
+        let open = Bug::new("Open typo".to_string()).with_priority(BugPriority::Trivial);
+        repo.save(&open).await.unwrap();
+
+        let bugs = repo.by_status(BugStatus::Fixed).await.unwrap();
+        assert_eq!(bugs.len(), 1);
+        assert_eq!(bugs[0].id, fixed.id);
+
+        let bugs = repo.by_priority(BugPriority::Trivial).await.unwrap();
+        assert_eq!(bugs.len(), 1);
+        assert_eq!(bugs[0].id, open.id);
+
+        assert!(repo.by_status(BugStatus::Closed).await.unwrap().is_empty());
+    }
+}
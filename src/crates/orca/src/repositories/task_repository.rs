@@ -4,7 +4,9 @@ use crate::db::Database;
 use crate::error::{OrcaError, Result};
 use crate::workflow::Task;
 use chrono::Utc;
-use sqlx::Row;
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteConnection};
+use sqlx::{Row, Sqlite};
 use std::sync::Arc;
 
 /// Repository for task database operations
@@ -21,8 +23,48 @@ impl TaskRepository {
 
     /// Save a task to the database
     pub async fn save(&self, task: &Task) -> Result<()> {
+        Self::insert_query(task, Utc::now().timestamp())
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to save task: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Save a task on `conn`, e.g. inside [`Database::with_transaction`]
+    pub async fn save_in(&self, conn: &mut SqliteConnection, task: &Task) -> Result<()> {
+        Self::insert_query(task, Utc::now().timestamp())
+            .execute(conn)
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to save task: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Save many tasks in a single transaction
+    ///
+    /// Either every task is saved or, if any insert fails, none are.
+    pub async fn insert_many(&self, tasks: &[Task]) -> Result<()> {
         let created_at = Utc::now().timestamp();
+        let tasks = tasks.to_vec();
+        self.db
+            .with_transaction(move |conn| {
+                Box::pin(async move {
+                    for task in &tasks {
+                        Self::insert_query(task, created_at)
+                            .execute(&mut *conn)
+                            .await
+                            .map_err(|e| {
+                                OrcaError::Database(format!("Failed to save task {}: {}", task.id, e))
+                            })?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+    }
 
+    fn insert_query(task: &Task, created_at: i64) -> Query<'_, Sqlite, SqliteArguments<'_>> {
         sqlx::query(
             "INSERT INTO tasks (id, description, status, priority, created_at, updated_at, metadata)
              VALUES (?, ?, ?, ?, ?, ?, ?)"
@@ -34,11 +76,6 @@ impl TaskRepository {
         .bind(created_at)
         .bind(created_at)
         .bind(&task.metadata)
-        .execute(self.db.pool())
-        .await
-        .map_err(|e| OrcaError::Database(format!("Failed to save task: {}", e)))?;
-
-        Ok(())
     }
 
     /// Load a task from the database by ID
@@ -256,6 +293,61 @@ mod tests {
         db
     }
 
+    async fn setup_file_db() -> (tempfile::TempDir, Arc<Database>) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db = Database::initialize(temp_dir.path().join("orca.db"))
+            .await
+            .unwrap();
+        (temp_dir, Arc::new(db))
+    }
+
+    #[tokio::test]
+    async fn test_insert_many_rolls_back_on_failure() {
+        let (_temp_dir, db) = setup_file_db().await;
+        let repo = TaskRepository::new(db);
+
+        let first = Task::new("Task 1");
+        let mut duplicate = Task::new("Task 2");
+        duplicate.id = first.id.clone();
+        let last = Task::new("Task 3");
+
+        let result = repo.insert_many(&[first.clone(), duplicate, last.clone()]).await;
+        assert!(result.is_err());
+        assert!(!repo.exists(&first.id).await.unwrap());
+        assert!(!repo.exists(&last.id).await.unwrap());
+
+        repo.insert_many(&[first, last]).await.unwrap();
+        assert_eq!(repo.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    #[ignore] // Wall-clock comparison, run manually
+    async fn test_insert_many_faster_than_per_row() {
+        let (_temp_dir, db) = setup_file_db().await;
+        let repo = TaskRepository::new(db);
+
+        let per_row: Vec<Task> = (0..200).map(|i| Task::new(format!("Row {}", i))).collect();
+        let bulk: Vec<Task> = (0..200).map(|i| Task::new(format!("Bulk {}", i))).collect();
+
+        let start = std::time::Instant::now();
+        for task in &per_row {
+            repo.save(task).await.unwrap();
+        }
+        let per_row_elapsed = start.elapsed();
+
+        let start = std::time::Instant::now();
+        repo.insert_many(&bulk).await.unwrap();
+        let bulk_elapsed = start.elapsed();
+
+        assert_eq!(repo.list().await.unwrap().len(), 400);
+        assert!(
+            bulk_elapsed < per_row_elapsed,
+            "bulk insert took {:?}, per-row took {:?}",
+            bulk_elapsed,
+            per_row_elapsed
+        );
+    }
+
     #[tokio::test]
     async fn test_save_and_find() {
         let db = setup_test_db().await;
@@ -4,7 +4,9 @@ use crate::db::Database;
 use crate::error::{OrcaError, Result};
 use crate::workflow::Workflow;
 use chrono::Utc;
-use sqlx::Row;
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteConnection};
+use sqlx::{Row, Sqlite};
 use std::sync::Arc;
 
 /// Repository for workflow database operations
@@ -21,8 +23,53 @@ impl WorkflowRepository {
 
     /// Save a workflow to the database
     pub async fn save(&self, workflow: &Workflow) -> Result<()> {
+        Self::insert_query(workflow, Utc::now().timestamp())
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to save workflow: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Save a workflow on `conn`, e.g. inside [`Database::with_transaction`]
+    pub async fn save_in(&self, conn: &mut SqliteConnection, workflow: &Workflow) -> Result<()> {
+        Self::insert_query(workflow, Utc::now().timestamp())
+            .execute(conn)
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to save workflow: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Save many workflows in a single transaction
+    ///
+    /// Either every workflow is saved or, if any insert fails, none are.
+    pub async fn insert_many(&self, workflows: &[Workflow]) -> Result<()> {
         let created_at = Utc::now().timestamp();
+        let mut tx = self
+            .db
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to begin transaction: {}", e)))?;
+
+        for workflow in workflows {
+            Self::insert_query(workflow, created_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    OrcaError::Database(format!("Failed to save workflow {}: {}", workflow.id, e))
+                })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| OrcaError::Database(format!("Failed to commit workflows: {}", e)))?;
+
+        Ok(())
+    }
 
+    fn insert_query(workflow: &Workflow, created_at: i64) -> Query<'_, Sqlite, SqliteArguments<'_>> {
         sqlx::query(
             "INSERT INTO workflows (id, name, description, status, pattern, created_at, updated_at, metadata)
              VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
@@ -35,11 +82,6 @@ impl WorkflowRepository {
         .bind(created_at)
         .bind(created_at)
         .bind(&workflow.metadata)
-        .execute(self.db.pool())
-        .await
-        .map_err(|e| OrcaError::Database(format!("Failed to save workflow: {}", e)))?;
-
-        Ok(())
     }
 
     /// Load a workflow from the database by ID
@@ -191,6 +233,29 @@ impl WorkflowRepository {
         Ok(())
     }
 
+    /// Add a task to a workflow on `conn`, e.g. inside [`Database::with_transaction`]
+    pub async fn add_task_in(
+        &self,
+        conn: &mut SqliteConnection,
+        workflow_id: &str,
+        task_id: &str,
+        sequence: i32,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO workflow_tasks (workflow_id, task_id, sequence, created_at)
+             VALUES (?, ?, ?, ?)"
+        )
+        .bind(workflow_id)
+        .bind(task_id)
+        .bind(sequence)
+        .bind(Utc::now().timestamp())
+        .execute(conn)
+        .await
+        .map_err(|e| OrcaError::Database(format!("Failed to add task to workflow: {}", e)))?;
+
+        Ok(())
+    }
+
     /// Remove a task from a workflow
     pub async fn remove_task(&self, workflow_id: &str, task_id: &str) -> Result<()> {
         sqlx::query(
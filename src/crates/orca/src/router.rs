@@ -24,7 +24,9 @@
 //!
 //! ## Conditional
 //! Tasks route based on the result of the previous task execution.
-//! Enables branching workflows with decision points.
+//! Enables branching workflows with decision points. Branches are
+//! declarative [`Expression`]s over the last result, such as
+//! `state.score > 0.8 && success` (see [`RoutingContext::condition_context`]).
 //!
 //! ```text
 //!          ┌─ success ─→ Task B
@@ -42,6 +44,12 @@
 //!          └─→ Task D ─┘
 //! ```
 //!
+//! ## Target Strategies
+//! A [`TargetStrategy`] (`RoundRobin`, `Weighted` or `LeastLoaded`) decides
+//! where a task runs rather than which task runs next, spreading work across
+//! executors or configured LLM providers through a [`TargetRouter`]. It is
+//! used alongside any routing strategy.
+//!
 //! # Integration with Executor
 //!
 //! The TaskExecutor uses the Router to:
@@ -52,10 +60,16 @@
 //!
 //! The Router does NOT execute tasks itself - it only determines routing decisions.
 
-use crate::error::Result;
+use crate::error::{OrcaError, Result};
+use crate::interpreter::Expression;
 use crate::workflow::{Task, Workflow};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+pub mod targets;
+
+pub use targets::{Target, TargetLease, TargetRouter, TargetStrategy};
 
 /// Routing decision for workflow execution
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -100,6 +114,22 @@ impl std::fmt::Display for RoutingStrategy {
     }
 }
 
+impl std::str::FromStr for RoutingStrategy {
+    type Err = OrcaError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "sequential" => Ok(Self::Sequential),
+            "conditional" => Ok(Self::Conditional),
+            "parallel" => Ok(Self::Parallel),
+            other => Err(OrcaError::Config(format!(
+                "Unknown routing strategy '{}'",
+                other
+            ))),
+        }
+    }
+}
+
 /// Routing context containing workflow state
 #[derive(Debug, Clone)]
 pub struct RoutingContext {
@@ -122,6 +152,35 @@ pub struct RoutingContext {
     pub last_task_result: Option<String>,
 }
 
+impl RoutingContext {
+    /// JSON context that routing conditions are evaluated against
+    ///
+    /// - `state`: the last task result, parsed as JSON when possible
+    /// - `success`: whether the last task succeeded
+    /// - `task`: ID of the task that just ran
+    /// - `workflow`: the workflow's `id` and `name`
+    pub fn condition_context(&self) -> Value {
+        let state = match &self.last_task_result {
+            Some(result) => serde_json::from_str(result).unwrap_or_else(|_| json!(result)),
+            None => Value::Null,
+        };
+        let task = self
+            .current_index
+            .and_then(|idx| self.task_ids.get(idx))
+            .cloned();
+
+        json!({
+            "state": state,
+            "success": self.last_task_success,
+            "task": task,
+            "workflow": {
+                "id": self.workflow.id,
+                "name": self.workflow.name,
+            },
+        })
+    }
+}
+
 /// Router trait for workflow routing decisions
 ///
 /// Implementors provide different routing strategies (sequential, conditional, parallel).
@@ -205,11 +264,23 @@ impl Router for SequentialRouter {
 /// Routes tasks based on the result of the previous task execution.
 /// Enables branching workflows with decision points.
 ///
-/// Conditions are evaluated based on the last task's success/failure state.
+/// After each task, routes added with [`ConditionalRouter::with_route`] are
+/// checked in order and the first whose condition holds picks the next task.
 /// If no explicit condition is met, falls back to sequential routing.
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct ConditionalRouter {
-    // Future: condition rules could be stored here for more complex scenarios
+    /// Condition routes, checked in order
+    routes: Vec<ConditionalRoute>,
+}
+
+/// Branch taken when its condition holds after a task completes
+#[derive(Debug, Clone)]
+pub struct ConditionalRoute {
+    /// Condition evaluated against [`RoutingContext::condition_context`]
+    pub condition: Expression,
+
+    /// Task to route to when the condition holds
+    pub target: String,
 }
 
 /// Condition evaluation result
@@ -223,17 +294,42 @@ pub enum ConditionResult {
 }
 
 impl ConditionalRouter {
+    /// Builder: Route to `target` when `condition` holds
+    ///
+    /// Fails if the condition is not a valid [`Expression`].
+    pub fn with_route(mut self, condition: &str, target: impl Into<String>) -> Result<Self> {
+        self.routes.push(ConditionalRoute {
+            condition: Expression::parse(condition)?,
+            target: target.into(),
+        });
+        Ok(self)
+    }
+
+    /// Configured condition routes, in evaluation order
+    pub fn routes(&self) -> &[ConditionalRoute] {
+        &self.routes
+    }
+
     /// Evaluate conditions based on context
     ///
-    /// Current simple implementation:
-    /// - If last task failed and there's a next task, route to it (error handling path)
-    /// - If last task succeeded, route to next sequential task
-    /// - Future: support metadata-based condition expressions
+    /// - The first configured route whose condition holds wins
+    /// - Otherwise route to the next sequential task
     fn evaluate_condition(&self, context: &RoutingContext) -> ConditionResult {
-        // For now, use simple success/failure routing
-        // Future versions will support metadata conditions like:
-        // - "result.status == 'needs_review'" -> route to review task
-        // - "result.score > 0.8" -> route to approval task
+        if context.current_index.is_some() && !self.routes.is_empty() {
+            let condition_context = context.condition_context();
+            if let Some(route) = self
+                .routes
+                .iter()
+                .find(|route| route.condition.evaluate(&condition_context))
+            {
+                tracing::debug!(
+                    condition = %route.condition,
+                    target = %route.target,
+                    "Routing condition matched"
+                );
+                return ConditionResult::Match(route.target.clone());
+            }
+        }
 
         let current_index = match context.current_index {
             Some(idx) => idx,
@@ -247,7 +343,6 @@ impl ConditionalRouter {
             return ConditionResult::NoMatch; // End of workflow
         }
 
-        // Route to next task (conditional logic can be enhanced later)
         let next_task_id = context.task_ids[next_index].clone();
         ConditionResult::Match(next_task_id)
     }
@@ -262,13 +357,15 @@ impl Router for ConditionalRouter {
             Some(idx) => idx + 1, // Move to next task
         };
 
+        // Evaluate conditions (a route may loop back from the last task)
+        let condition = self.evaluate_condition(context);
+
         // Check if we've reached the end
-        if next_index >= context.task_ids.len() {
+        if next_index >= context.task_ids.len() && condition == ConditionResult::NoMatch {
             return Ok(RoutingDecision::Complete);
         }
 
-        // Evaluate conditions
-        match self.evaluate_condition(context) {
+        match condition {
             ConditionResult::Match(task_id) => {
                 Ok(RoutingDecision::Continue(vec![task_id]))
             }
@@ -287,6 +384,23 @@ impl Router for ConditionalRouter {
     fn strategy(&self) -> RoutingStrategy {
         RoutingStrategy::Conditional
     }
+
+    fn validate_workflow(&self, _workflow: &Workflow, task_ids: &[String]) -> Result<()> {
+        if task_ids.is_empty() {
+            return Err(OrcaError::Workflow("Workflow has no tasks".to_string()));
+        }
+
+        for route in &self.routes {
+            if !task_ids.contains(&route.target) {
+                return Err(OrcaError::Workflow(format!(
+                    "Condition '{}' routes to unknown task '{}'",
+                    route.condition, route.target
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Parallel routing implementation
@@ -405,6 +519,20 @@ mod tests {
         assert_eq!(RoutingStrategy::Parallel.to_string(), "parallel");
     }
 
+    #[test]
+    fn test_routing_strategy_round_trips_through_string() {
+        for strategy in [
+            RoutingStrategy::Sequential,
+            RoutingStrategy::Conditional,
+            RoutingStrategy::Parallel,
+        ] {
+            assert_eq!(strategy.to_string().parse::<RoutingStrategy>().unwrap(), strategy);
+            let json = serde_json::to_value(strategy).unwrap();
+            assert_eq!(json, serde_json::json!(strategy.to_string()));
+        }
+        assert!("weighted".parse::<RoutingStrategy>().is_err());
+    }
+
     #[test]
     fn test_routing_strategy_default() {
         assert_eq!(RoutingStrategy::default(), RoutingStrategy::Sequential);
@@ -639,19 +767,78 @@ mod tests {
             true,
         );
 
-        // Add result data (for future condition evaluation)
+        // Result data without matching routes
         context.last_task_result = Some("{\"status\": \"needs_review\"}".to_string());
 
         let decision = router.route(&context).await.unwrap();
 
-        // Currently routes sequentially, but infrastructure is ready for
-        // metadata-based conditions in future
+        // No routes configured, so routes sequentially
         assert_eq!(
             decision,
             RoutingDecision::Continue(vec!["task-2".to_string()])
         );
     }
 
+    #[tokio::test]
+    async fn test_conditional_router_expression_routes() {
+        let task_ids = vec![
+            "draft".to_string(),
+            "review".to_string(),
+            "publish".to_string(),
+        ];
+        let router = ConditionalRouter::default()
+            .with_route("state.score > 0.8 && state.retries < 3", "publish")
+            .unwrap()
+            .with_route("state.status == 'needs_review'", "review")
+            .unwrap()
+            .with_route("task == 'publish' && !success", "draft")
+            .unwrap();
+        let workflow = create_test_workflow();
+        assert!(router.validate_workflow(&workflow, &task_ids).is_ok());
+
+        let mut context = create_routing_context(task_ids.clone(), Some(0), true);
+        context.last_task_result = Some(r#"{"score": 0.9, "retries": 1}"#.to_string());
+        assert_eq!(
+            router.route(&context).await.unwrap(),
+            RoutingDecision::Continue(vec!["publish".to_string()])
+        );
+
+        context.last_task_result = Some(r#"{"score": 0.5, "status": "needs_review"}"#.to_string());
+        assert_eq!(
+            router.route(&context).await.unwrap(),
+            RoutingDecision::Continue(vec!["review".to_string()])
+        );
+
+        // No route matches: sequential fallback
+        context.last_task_result = Some(r#"{"score": 0.5}"#.to_string());
+        assert_eq!(
+            router.route(&context).await.unwrap(),
+            RoutingDecision::Continue(vec!["review".to_string()])
+        );
+
+        // A route can loop back from the last task
+        let mut context = create_routing_context(task_ids.clone(), Some(2), false);
+        assert_eq!(
+            router.route(&context).await.unwrap(),
+            RoutingDecision::Continue(vec!["draft".to_string()])
+        );
+        context.last_task_success = true;
+        assert_eq!(router.route(&context).await.unwrap(), RoutingDecision::Complete);
+    }
+
+    #[test]
+    fn test_conditional_router_rejects_bad_routes() {
+        assert!(ConditionalRouter::default().with_route("state.score >", "x").is_err());
+
+        let router = ConditionalRouter::default()
+            .with_route("success", "missing")
+            .unwrap();
+        let err = router
+            .validate_workflow(&create_test_workflow(), &["task-1".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown task 'missing'"));
+    }
+
     #[tokio::test]
     async fn test_parallel_router_start() {
         let router = ParallelRouter::default();
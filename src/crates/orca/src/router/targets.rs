@@ -0,0 +1,267 @@
+//! Target selection for spreading work across executors or LLM providers
+//!
+//! Where the workflow [`Router`](super::Router) decides which task runs next,
+//! a [`TargetRouter`] decides where it runs, using a [`TargetStrategy`]:
+//!
+//! - **RoundRobin**: cycle through the targets in order
+//! - **Weighted**: smooth weighted round-robin, so over any run of draws each
+//!   target is picked in proportion to its weight
+//! - **LeastLoaded**: pick the target with the fewest in-flight tasks, ties
+//!   going to the earliest configured target
+//!
+//! Every selection returns a [`TargetLease`] that counts as in flight until
+//! it is dropped.
+
+use crate::error::{OrcaError, Result};
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Name of an executor or LLM provider that work can be routed to
+pub type Target = String;
+
+/// How a [`TargetRouter`] picks among its targets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetStrategy {
+    /// Cycle through targets in order
+    RoundRobin,
+
+    /// Spread across targets in proportion to their weights
+    Weighted(Vec<(Target, u32)>),
+
+    /// Pick the target with the fewest tasks in flight
+    LeastLoaded,
+}
+
+impl std::fmt::Display for TargetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RoundRobin => write!(f, "round_robin"),
+            Self::Weighted(_) => write!(f, "weighted"),
+            Self::LeastLoaded => write!(f, "least_loaded"),
+        }
+    }
+}
+
+/// Distributes tasks across a fixed set of targets
+#[derive(Debug)]
+pub struct TargetRouter {
+    strategy: TargetStrategy,
+    targets: Vec<Target>,
+    weights: Vec<i64>,
+    /// Next index for round-robin
+    cursor: AtomicUsize,
+    /// Running weights for smooth weighted round-robin
+    current_weights: Mutex<Vec<i64>>,
+    in_flight: Arc<Vec<AtomicUsize>>,
+}
+
+impl TargetRouter {
+    /// Create a router over `targets` using `strategy`
+    ///
+    /// A `Weighted` strategy routes to the targets named in its weights and
+    /// ignores `targets`. Fails if there is nothing to route to.
+    pub fn new(strategy: TargetStrategy, targets: Vec<Target>) -> Result<Self> {
+        let (targets, weights): (Vec<Target>, Vec<i64>) = match &strategy {
+            TargetStrategy::RoundRobin | TargetStrategy::LeastLoaded => {
+                let weights = vec![1; targets.len()];
+                (targets, weights)
+            }
+            TargetStrategy::Weighted(weighted) => weighted
+                .iter()
+                .filter(|(_, weight)| *weight > 0)
+                .map(|(target, weight)| (target.clone(), i64::from(*weight)))
+                .unzip(),
+        };
+
+        if targets.is_empty() {
+            return Err(OrcaError::Config(format!(
+                "{} routing needs at least one target with a non-zero weight",
+                strategy
+            )));
+        }
+
+        Ok(Self {
+            current_weights: Mutex::new(vec![0; targets.len()]),
+            in_flight: Arc::new(targets.iter().map(|_| AtomicUsize::new(0)).collect()),
+            cursor: AtomicUsize::new(0),
+            strategy,
+            targets,
+            weights,
+        })
+    }
+
+    /// Round-robin over `targets`
+    pub fn round_robin(targets: Vec<Target>) -> Result<Self> {
+        Self::new(TargetStrategy::RoundRobin, targets)
+    }
+
+    /// Weighted distribution over `(target, weight)` pairs
+    pub fn weighted(weights: Vec<(Target, u32)>) -> Result<Self> {
+        Self::new(TargetStrategy::Weighted(weights), Vec::new())
+    }
+
+    /// Route to whichever of `targets` has the fewest tasks in flight
+    pub fn least_loaded(targets: Vec<Target>) -> Result<Self> {
+        Self::new(TargetStrategy::LeastLoaded, targets)
+    }
+
+    /// The strategy in use
+    pub fn strategy(&self) -> &TargetStrategy {
+        &self.strategy
+    }
+
+    /// Targets being routed to, in configured order
+    pub fn targets(&self) -> &[Target] {
+        &self.targets
+    }
+
+    /// Number of leases currently held for `target`
+    pub fn in_flight(&self, target: &str) -> usize {
+        self.targets
+            .iter()
+            .position(|t| t == target)
+            .map(|idx| self.in_flight[idx].load(Ordering::SeqCst))
+            .unwrap_or(0)
+    }
+
+    /// Pick the target for the next task
+    ///
+    /// The target counts as in flight until the returned lease is dropped.
+    pub fn select(&self) -> TargetLease {
+        let index = match self.strategy {
+            TargetStrategy::Weighted(_) => self.next_weighted(),
+            TargetStrategy::LeastLoaded => self.least_loaded_index(),
+            TargetStrategy::RoundRobin => {
+                self.cursor.fetch_add(1, Ordering::SeqCst) % self.targets.len()
+            }
+        };
+
+        self.in_flight[index].fetch_add(1, Ordering::SeqCst);
+        TargetLease {
+            target: self.targets[index].clone(),
+            index,
+            in_flight: Arc::clone(&self.in_flight),
+        }
+    }
+
+    fn next_weighted(&self) -> usize {
+        let mut current = self.current_weights.lock();
+        let total: i64 = self.weights.iter().sum();
+
+        let mut best = 0;
+        for (idx, weight) in self.weights.iter().enumerate() {
+            current[idx] += weight;
+            if current[idx] > current[best] {
+                best = idx;
+            }
+        }
+        current[best] -= total;
+        best
+    }
+
+    fn least_loaded_index(&self) -> usize {
+        self.in_flight
+            .iter()
+            .enumerate()
+            .min_by_key(|(idx, count)| (count.load(Ordering::SeqCst), *idx))
+            .map(|(idx, _)| idx)
+            .unwrap_or(0)
+    }
+}
+
+/// A selected target, counted as in flight until dropped
+#[derive(Debug)]
+pub struct TargetLease {
+    target: Target,
+    index: usize,
+    in_flight: Arc<Vec<AtomicUsize>>,
+}
+
+impl TargetLease {
+    /// The selected target
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
+
+impl Drop for TargetLease {
+    fn drop(&mut self) {
+        self.in_flight[self.index].fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn targets(names: &[&str]) -> Vec<Target> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_round_robin_cycles_targets() {
+        let router = TargetRouter::round_robin(targets(&["ollama", "openai", "claude"])).unwrap();
+
+        let picks: Vec<String> = (0..7)
+            .map(|_| router.select().target().to_string())
+            .collect();
+        assert_eq!(
+            picks,
+            vec!["ollama", "openai", "claude", "ollama", "openai", "claude", "ollama"]
+        );
+    }
+
+    #[test]
+    fn test_weighted_respects_distribution() {
+        let router = TargetRouter::weighted(vec![
+            ("primary".to_string(), 5),
+            ("secondary".to_string(), 3),
+            ("fallback".to_string(), 2),
+            ("disabled".to_string(), 0),
+        ])
+        .unwrap();
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for _ in 0..1000 {
+            *counts
+                .entry(router.select().target().to_string())
+                .or_default() += 1;
+        }
+        assert_eq!(counts["primary"], 500);
+        assert_eq!(counts["secondary"], 300);
+        assert_eq!(counts["fallback"], 200);
+        assert!(!counts.contains_key("disabled"));
+
+        // Picks are interleaved rather than bursty
+        let first: Vec<String> = (0..5)
+            .map(|_| router.select().target().to_string())
+            .collect();
+        assert!(first.windows(3).all(|w| !(w[0] == w[1] && w[1] == w[2])));
+    }
+
+    #[test]
+    fn test_least_loaded_tracks_in_flight() {
+        let router = TargetRouter::least_loaded(targets(&["a", "b"])).unwrap();
+
+        let first = router.select();
+        let second = router.select();
+        assert_eq!(first.target(), "a");
+        assert_eq!(second.target(), "b");
+        assert_eq!(router.in_flight("a"), 1);
+
+        drop(first);
+        assert_eq!(router.in_flight("a"), 0);
+        let third = router.select();
+        assert_eq!(third.target(), "a");
+        assert_eq!(router.in_flight("b"), 1);
+    }
+
+    #[test]
+    fn test_invalid_configurations() {
+        assert!(TargetRouter::round_robin(Vec::new()).is_err());
+        assert!(TargetRouter::weighted(vec![("a".to_string(), 0)]).is_err());
+        assert!(TargetRouter::new(TargetStrategy::LeastLoaded, Vec::new()).is_err());
+    }
+}
@@ -1,12 +1,51 @@
 //! Graceful shutdown handling
 //!
 //! Provides signal handling and coordination for graceful shutdown of workflows and tasks.
+//!
+//! In-flight work (tool executions) is registered through
+//! [`ShutdownCoordinator::track`] so that [`ShutdownCoordinator::drain`] can
+//! wait for it before the process exits.
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use crate::error::{OrcaError, Result};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::Notify;
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
 use tracing::{info, warn};
 
+/// Outcome of draining in-flight work
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrainReport {
+    /// Executions that finished on their own before the deadline
+    pub completed: usize,
+    /// Executions still running at the deadline that were cancelled
+    pub force_cancelled: usize,
+}
+
+/// Bookkeeping for executions registered with the coordinator
+struct InFlight {
+    /// Number of executions currently running
+    count: AtomicUsize,
+    /// Number of executions cancelled by a drain
+    force_cancelled: AtomicUsize,
+    /// Notified whenever an execution finishes
+    finished: Notify,
+    /// Set to `true` to cancel all running executions
+    cancel: watch::Sender<bool>,
+}
+
+/// Decrements the in-flight count when an execution ends, even if its future
+/// is dropped mid-way
+struct InFlightGuard(Arc<InFlight>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.count.fetch_sub(1, Ordering::SeqCst);
+        self.0.finished.notify_waiters();
+    }
+}
+
 /// Shutdown coordinator for graceful termination
 #[derive(Clone)]
 pub struct ShutdownCoordinator {
@@ -14,12 +53,15 @@ pub struct ShutdownCoordinator {
     shutdown_requested: Arc<AtomicBool>,
     /// Notifier for shutdown signal
     shutdown_notify: Arc<Notify>,
+    /// Executions registered via `track`
+    in_flight: Arc<InFlight>,
 }
 
 impl std::fmt::Debug for ShutdownCoordinator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ShutdownCoordinator")
             .field("shutdown_requested", &self.shutdown_requested.load(Ordering::SeqCst))
+            .field("in_flight", &self.in_flight_count())
             .finish()
     }
 }
@@ -27,9 +69,94 @@ impl std::fmt::Debug for ShutdownCoordinator {
 impl ShutdownCoordinator {
     /// Create a new shutdown coordinator
     pub fn new() -> Self {
+        let (cancel, _) = watch::channel(false);
         Self {
             shutdown_requested: Arc::new(AtomicBool::new(false)),
             shutdown_notify: Arc::new(Notify::new()),
+            in_flight: Arc::new(InFlight {
+                count: AtomicUsize::new(0),
+                force_cancelled: AtomicUsize::new(0),
+                finished: Notify::new(),
+                cancel,
+            }),
+        }
+    }
+
+    /// Number of registered executions currently running
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.count.load(Ordering::SeqCst)
+    }
+
+    /// Run an execution registered as in-flight
+    ///
+    /// Fails without running `execution` once shutdown has been requested.
+    /// If a [`drain`](Self::drain) deadline passes while the execution is
+    /// still running, it is dropped (cancelling it at its next `.await`) and
+    /// an error is returned.
+    pub async fn track<F, T>(&self, name: &str, execution: F) -> Result<T>
+    where
+        F: Future<Output = T>,
+    {
+        // Subscribe before checking the flag so a drain can't slip in between
+        let mut cancel = self.in_flight.cancel.subscribe();
+        self.in_flight.count.fetch_add(1, Ordering::SeqCst);
+        let _guard = InFlightGuard(self.in_flight.clone());
+
+        if self.is_shutdown_requested() {
+            return Err(OrcaError::Execution(format!(
+                "Shutting down, not starting '{}'",
+                name
+            )));
+        }
+
+        tokio::select! {
+            output = execution => Ok(output),
+            _ = cancel.wait_for(|cancelled| *cancelled) => {
+                self.in_flight.force_cancelled.fetch_add(1, Ordering::SeqCst);
+                warn!(execution = name, "Execution force-cancelled during shutdown");
+                Err(OrcaError::Execution(format!(
+                    "'{}' was cancelled during shutdown",
+                    name
+                )))
+            }
+        }
+    }
+
+    /// Stop accepting new executions and wait for in-flight ones
+    ///
+    /// Waits up to `timeout` for registered executions to finish, then
+    /// force-cancels whatever is still running.
+    pub async fn drain(&self, timeout: Duration) -> DrainReport {
+        self.request_shutdown();
+
+        let outstanding = self.in_flight_count();
+        info!(outstanding, timeout_ms = timeout.as_millis() as u64, "Draining in-flight executions");
+
+        let drained = tokio::time::timeout(timeout, self.wait_for_idle()).await.is_ok();
+
+        if !drained {
+            warn!(remaining = self.in_flight_count(), "Drain deadline passed, force-cancelling");
+            self.in_flight.cancel.send_replace(true);
+            self.wait_for_idle().await;
+        }
+
+        let force_cancelled = self.in_flight.force_cancelled.load(Ordering::SeqCst);
+        let report = DrainReport {
+            completed: outstanding.saturating_sub(force_cancelled),
+            force_cancelled,
+        };
+        info!(completed = report.completed, force_cancelled = report.force_cancelled, "Drain complete");
+        report
+    }
+
+    /// Wait until no registered executions are running
+    async fn wait_for_idle(&self) {
+        loop {
+            let finished = self.in_flight.finished.notified();
+            if self.in_flight_count() == 0 {
+                return;
+            }
+            finished.await;
         }
     }
 
@@ -165,4 +292,63 @@ mod tests {
         // Clone should see the same shutdown state
         assert!(coordinator_clone.is_shutdown_requested());
     }
+
+    #[tokio::test]
+    async fn test_track_runs_execution() {
+        let coordinator = ShutdownCoordinator::new();
+
+        let value = coordinator.track("fast", async { 42 }).await.unwrap();
+        assert_eq!(value, 42);
+        assert_eq!(coordinator.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_track_rejects_after_shutdown() {
+        let coordinator = ShutdownCoordinator::new();
+        coordinator.request_shutdown();
+
+        let result = coordinator.track("late", async { 42 }).await;
+        assert!(result.is_err());
+        assert_eq!(coordinator.in_flight_count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_waits_for_slow_tool() {
+        let coordinator = ShutdownCoordinator::new();
+
+        let tracked = coordinator.clone();
+        let tool = tokio::spawn(async move {
+            tracked
+                .track("slow_tool", tokio::time::sleep(Duration::from_millis(50)))
+                .await
+        });
+        tokio::task::yield_now().await;
+        assert_eq!(coordinator.in_flight_count(), 1);
+
+        let report = coordinator.drain(Duration::from_secs(1)).await;
+
+        assert_eq!(report, DrainReport { completed: 1, force_cancelled: 0 });
+        assert!(tool.await.unwrap().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_force_cancels_after_deadline() {
+        let coordinator = ShutdownCoordinator::new();
+
+        let tracked = coordinator.clone();
+        let tool = tokio::spawn(async move {
+            tracked
+                .track("stuck_tool", tokio::time::sleep(Duration::from_secs(3600)))
+                .await
+        });
+        tokio::task::yield_now().await;
+        assert_eq!(coordinator.in_flight_count(), 1);
+
+        let report = coordinator.drain(Duration::from_millis(100)).await;
+
+        assert_eq!(report, DrainReport { completed: 0, force_cancelled: 1 });
+        assert!(tool.await.unwrap().is_err());
+        assert_eq!(coordinator.in_flight_count(), 0);
+        assert!(coordinator.is_shutdown_requested());
+    }
 }
@@ -5,6 +5,7 @@
 
 use crate::DatabaseManager;
 use crate::error::{OrcaError, Result};
+use crate::models::ast_cache::source_mtime;
 use crate::models::AstCache;
 use crate::repositories::AstCacheRepository;
 use sha2::{Digest, Sha256};
@@ -151,10 +152,11 @@ impl AstCacheService {
         // Compute file hash
         let content_hash = self.compute_file_hash(file_path)?;
 
-        // Get file size
+        // Get file size and modification time
         let file_size = fs::metadata(file_path)
             .map(|m| m.len() as i64)
             .ok();
+        let mtime = source_mtime(file_path).ok();
 
         // Check if entry already exists
         let exists = repo.has_cache_for_file(file_path).await?;
@@ -169,6 +171,7 @@ impl AstCacheService {
             cached.imports = imports;
             cached.file_size = file_size;
             cached.parse_duration_ms = Some(parse_duration_ms);
+            cached.source_mtime = mtime;
             cached.touch();
 
             repo.update(&cached).await?;
@@ -193,6 +196,10 @@ impl AstCacheService {
                 cached = cached.with_file_size(size);
             }
 
+            if let Some(mtime) = mtime {
+                cached = cached.with_source_mtime(mtime);
+            }
+
             cached = cached.with_parse_duration(parse_duration_ms);
 
             repo.save(&cached).await?;
@@ -0,0 +1,188 @@
+//! Tool execution limits
+//!
+//! Orca runs tools in-process, so a runaway tool (for example a shell command
+//! that never exits) would otherwise block the whole orchestrator. Every tool
+//! call made by the bridge goes through [`run_with_timeout`].
+
+use crate::error::OrcaError;
+use serde_json::Value;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// Default per-tool timeout (5 minutes)
+pub const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Limits applied to each tool execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolExecutionConfig {
+    /// Maximum time a single tool call may run (`None` = unlimited)
+    pub timeout: Option<Duration>,
+}
+
+impl Default for ToolExecutionConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(DEFAULT_TOOL_TIMEOUT),
+        }
+    }
+}
+
+impl ToolExecutionConfig {
+    /// Create a config with the default timeout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the per-tool timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Let tools run for as long as they need
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+}
+
+/// Run one tool execution under the configured timeout
+///
+/// On timeout the execution future is dropped, which cancels the tool at its
+/// next `.await` point and runs its destructors (child processes spawned with
+/// `kill_on_drop`, temp files, locks). Tools should therefore make their side
+/// effects at await boundaries they can abandon safely.
+///
+/// Returns [`OrcaError::ToolTimeout`] (wrapped in `anyhow::Error`, so callers
+/// can `downcast_ref`) when the limit is exceeded.
+pub async fn run_with_timeout<F>(
+    tool_name: &str,
+    config: &ToolExecutionConfig,
+    execution: F,
+) -> anyhow::Result<Value>
+where
+    F: Future<Output = anyhow::Result<Value>>,
+{
+    let Some(limit) = config.timeout else {
+        return execution.await;
+    };
+
+    match tokio::time::timeout(limit, execution).await {
+        Ok(result) => result,
+        Err(_) => {
+            warn!(
+                tool = tool_name,
+                timeout_ms = limit.as_millis() as u64,
+                "Tool execution timed out and was cancelled"
+            );
+            Err(OrcaError::ToolTimeout {
+                tool: tool_name.to_string(),
+                timeout_ms: limit.as_millis() as u64,
+            }
+            .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Flags when the mock tool's future is dropped
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// A tool that sleeps before answering and counts completed calls
+    async fn sleeping_tool(
+        sleep: Duration,
+        completed: Arc<AtomicUsize>,
+        dropped: Arc<AtomicBool>,
+    ) -> anyhow::Result<Value> {
+        let _guard = DropFlag(dropped);
+        tokio::time::sleep(sleep).await;
+        completed.fetch_add(1, Ordering::SeqCst);
+        Ok(json!({"slept_ms": sleep.as_millis() as u64}))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_sleeping_tool_exceeds_timeout() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicBool::new(false));
+        let config = ToolExecutionConfig::new().with_timeout(Duration::from_millis(100));
+
+        let err = run_with_timeout(
+            "slow_tool",
+            &config,
+            sleeping_tool(Duration::from_secs(60), completed.clone(), dropped.clone()),
+        )
+        .await
+        .unwrap_err();
+
+        match err.downcast_ref::<OrcaError>() {
+            Some(OrcaError::ToolTimeout { tool, timeout_ms }) => {
+                assert_eq!(tool, "slow_tool");
+                assert_eq!(*timeout_ms, 100);
+            }
+            other => panic!("expected ToolTimeout, got {:?}", other),
+        }
+        // The tool was cancelled rather than left running in the background
+        assert!(dropped.load(Ordering::SeqCst));
+        tokio::time::sleep(Duration::from_secs(120)).await;
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fast_tool_completes() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let config = ToolExecutionConfig::new().with_timeout(Duration::from_secs(1));
+
+        let value = run_with_timeout(
+            "fast_tool",
+            &config,
+            sleeping_tool(
+                Duration::from_millis(10),
+                completed.clone(),
+                Arc::new(AtomicBool::new(false)),
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(value["slept_ms"], 10);
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_without_timeout_waits() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let config = ToolExecutionConfig::new().without_timeout();
+
+        run_with_timeout(
+            "slow_tool",
+            &config,
+            sleeping_tool(
+                DEFAULT_TOOL_TIMEOUT * 2,
+                completed.clone(),
+                Arc::new(AtomicBool::new(false)),
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_default_config() {
+        assert_eq!(ToolExecutionConfig::default().timeout, Some(DEFAULT_TOOL_TIMEOUT));
+    }
+}
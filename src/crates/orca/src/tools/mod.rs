@@ -7,12 +7,20 @@
 // mod direct_bridge;
 mod permission_enforcer;
 mod ast_cache_service;
+mod execution;
+mod rule_engine;
 
 // pub use direct_bridge::DirectToolBridge;
-pub use permission_enforcer::{ToolPermissionEnforcer, ExecutionDecision, ExecutionResult};
+pub use permission_enforcer::{
+    is_destructive_tool, ExecutionDecision, ExecutionResult, SessionPermissions,
+    ToolPermissionEnforcer, DESTRUCTIVE_TOOLS,
+};
 pub use ast_cache_service::{AstCacheService, CacheStats};
+pub use execution::{run_with_timeout, ToolExecutionConfig, DEFAULT_TOOL_TIMEOUT};
+pub use rule_engine::{glob_match, RuleAction, RuleDecision, RuleEngine, PATH_ARG_KEYS};
 
 // Placeholder stub for DirectToolBridge until tooling crate tools are implemented
+use crate::shutdown::ShutdownCoordinator;
 use std::path::PathBuf;
 use serde_json::Value;
 
@@ -22,6 +30,10 @@ use serde_json::Value;
 pub struct DirectToolBridge {
     session_id: String,
     workspace_root: PathBuf,
+    execution_config: ToolExecutionConfig,
+    permissions: Option<SessionPermissions>,
+    rules: Option<RuleEngine>,
+    shutdown: Option<ShutdownCoordinator>,
 }
 
 impl DirectToolBridge {
@@ -30,12 +42,88 @@ impl DirectToolBridge {
         Ok(Self {
             session_id,
             workspace_root,
+            execution_config: ToolExecutionConfig::default(),
+            permissions: None,
+            rules: None,
+            shutdown: None,
         })
     }
 
+    /// Set per-tool execution limits
+    pub fn with_execution_config(mut self, config: ToolExecutionConfig) -> Self {
+        self.execution_config = config;
+        self
+    }
+
+    /// Get the per-tool execution limits
+    pub fn execution_config(&self) -> &ToolExecutionConfig {
+        &self.execution_config
+    }
+
+    /// Enforce tool permissions for this session
+    ///
+    /// Once set, tools without a configured permission are denied.
+    pub fn with_permissions(mut self, permissions: SessionPermissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Enforce project rules for this session
+    ///
+    /// Paths under the workspace root are matched relative to it.
+    pub fn with_rule_engine(mut self, rules: RuleEngine) -> Self {
+        self.rules = Some(rules.with_workspace_root(self.workspace_root.clone()));
+        self
+    }
+
+    /// Register every tool execution with a shutdown coordinator
+    ///
+    /// Executions are then refused once shutdown starts and awaited (or
+    /// force-cancelled) by [`ShutdownCoordinator::drain`].
+    pub fn with_shutdown_coordinator(mut self, coordinator: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(coordinator);
+        self
+    }
+
+    /// Get the enforced tool permissions, if any
+    pub fn permissions(&self) -> Option<&SessionPermissions> {
+        self.permissions.as_ref()
+    }
+
+    /// Get the enforced project rules, if any
+    pub fn rules(&self) -> Option<&RuleEngine> {
+        self.rules.as_ref()
+    }
+
+    /// Check whether this session may execute a tool
+    ///
+    /// Checks the configured permissions, then the project rules. Always
+    /// succeeds when neither is configured.
+    pub fn check_permission(&self, tool_name: &str, args: &Value) -> crate::error::Result<()> {
+        if let Some(permissions) = &self.permissions {
+            permissions.check(tool_name, args)?;
+        }
+        match &self.rules {
+            Some(rules) => rules.check(tool_name, args),
+            None => Ok(()),
+        }
+    }
+
     /// Stub execute_tool - returns error
-    pub async fn execute_tool(&self, _tool_name: &str, _args: Value) -> anyhow::Result<Value> {
-        Err(anyhow::anyhow!("DirectToolBridge not yet implemented - requires tooling crate tools modules"))
+    ///
+    /// Denied tools fail with [`crate::error::OrcaError::PermissionDenied`]
+    /// before anything is executed.
+    pub async fn execute_tool(&self, tool_name: &str, args: Value) -> anyhow::Result<Value> {
+        self.check_permission(tool_name, &args)?;
+
+        let execution = run_with_timeout(tool_name, &self.execution_config, async {
+            Err(anyhow::anyhow!("DirectToolBridge not yet implemented - requires tooling crate tools modules"))
+        });
+
+        match &self.shutdown {
+            Some(coordinator) => coordinator.track(tool_name, execution).await?,
+            None => execution.await,
+        }
     }
 
     /// Stub list_tools
@@ -63,3 +151,132 @@ impl DirectToolBridge {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OrcaError;
+    use crate::models::{PermissionLevel, ToolPermission};
+    use serde_json::json;
+
+    fn bridge_with(permissions: SessionPermissions) -> DirectToolBridge {
+        DirectToolBridge::new(PathBuf::from("/tmp"), "test-session".to_string())
+            .unwrap()
+            .with_permissions(permissions)
+    }
+
+    fn allow_all(tools: &[&str]) -> SessionPermissions {
+        SessionPermissions::new(
+            tools
+                .iter()
+                .map(|tool| ToolPermission::new(tool.to_string(), PermissionLevel::Allowed)),
+        )
+    }
+
+    fn is_permission_denied(err: &anyhow::Error) -> bool {
+        matches!(err.downcast_ref::<OrcaError>(), Some(OrcaError::PermissionDenied(_)))
+    }
+
+    #[tokio::test]
+    async fn test_execute_allowed_tool_passes_permission_check() {
+        let bridge = bridge_with(SessionPermissions::new(vec![ToolPermission::new(
+            "file_read".to_string(),
+            PermissionLevel::Allowed,
+        )]));
+
+        assert!(bridge.check_permission("file_read", &json!({})).is_ok());
+        // Reaches the (stubbed) tool rather than being rejected
+        let err = bridge.execute_tool("file_read", json!({})).await.unwrap_err();
+        assert!(!is_permission_denied(&err));
+    }
+
+    #[tokio::test]
+    async fn test_execute_denied_tool_returns_permission_denied() {
+        let bridge = bridge_with(SessionPermissions::new(vec![ToolPermission::new(
+            "shell_exec".to_string(),
+            PermissionLevel::Denied,
+        )]));
+
+        let err = bridge
+            .execute_tool("shell_exec", json!({"command": "rm -rf /"}))
+            .await
+            .unwrap_err();
+        assert!(is_permission_denied(&err));
+    }
+
+    #[tokio::test]
+    async fn test_execute_unconfigured_tool_defaults_to_deny() {
+        let bridge = bridge_with(SessionPermissions::new(vec![ToolPermission::new(
+            "file_read".to_string(),
+            PermissionLevel::Allowed,
+        )]));
+
+        let err = bridge
+            .execute_tool("fs_delete", json!({"path": "/tmp/x"}))
+            .await
+            .unwrap_err();
+        assert!(is_permission_denied(&err));
+    }
+
+    #[tokio::test]
+    async fn test_check_without_permissions_is_allowed() {
+        let bridge = DirectToolBridge::new(PathBuf::from("/tmp"), "test-session".to_string()).unwrap();
+
+        assert!(bridge.check_permission("file_read", &json!({})).is_ok());
+        assert!(bridge.check_permission("shell_exec", &json!({})).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_execute_denied_by_project_rule() {
+        let rule = crate::models::ProjectRule::new(
+            "no-shell-in-deploy".to_string(),
+            "security".to_string(),
+            json!({"tools": ["shell_exec"], "paths": ["deploy/**"]}).to_string(),
+        );
+        let bridge = bridge_with(allow_all(&["shell_exec"]))
+            .with_rule_engine(RuleEngine::new(vec![rule]));
+
+        let err = bridge
+            .execute_tool("shell_exec", json!({"cwd": "/tmp/deploy/prod"}))
+            .await
+            .unwrap_err();
+        assert!(is_permission_denied(&err));
+        assert!(err.to_string().contains("no-shell-in-deploy"));
+
+        let err = bridge
+            .execute_tool("shell_exec", json!({"cwd": "/tmp/src"}))
+            .await
+            .unwrap_err();
+        assert!(!is_permission_denied(&err));
+    }
+
+    #[tokio::test]
+    async fn test_execute_refused_while_draining() {
+        let coordinator = ShutdownCoordinator::new();
+        let bridge = bridge_with(allow_all(&["file_read"]))
+            .with_shutdown_coordinator(coordinator.clone());
+
+        let report = coordinator.drain(std::time::Duration::from_millis(10)).await;
+        assert_eq!(report.force_cancelled, 0);
+
+        let err = bridge.execute_tool("file_read", json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("Shutting down"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_destructive_tool_in_read_only_session() {
+        let bridge = bridge_with(
+            SessionPermissions::new(vec![ToolPermission::new(
+                "shell_exec".to_string(),
+                PermissionLevel::Allowed,
+            )])
+            .read_only(),
+        );
+
+        let err = bridge
+            .execute_tool("shell_exec", json!({"command": "ls"}))
+            .await
+            .unwrap_err();
+        assert!(is_permission_denied(&err));
+    }
+}
@@ -8,6 +8,7 @@ use crate::models::{PermissionLevel, ToolPermission};
 use crate::repositories::ToolPermissionRepository;
 use chrono::Utc;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
@@ -115,54 +116,7 @@ impl ToolPermissionEnforcer {
         tool_name: &str,
         args: &Value,
     ) -> Result<ExecutionDecision> {
-        // Check if denied
-        if permission.is_denied() {
-            return Ok(ExecutionDecision::Deny(format!(
-                "Tool '{}' is explicitly denied by project permissions",
-                tool_name
-            )));
-        }
-
-        // Check if requires approval
-        if permission.requires_approval() {
-            return Ok(ExecutionDecision::RequiresApproval(format!(
-                "Tool '{}' requires user approval before execution",
-                tool_name
-            )));
-        }
-
-        // Check path restrictions (if configured)
-        if let Some(restrictions) = &permission.path_restrictions {
-            if !self.check_path_restrictions(args, restrictions)? {
-                return Ok(ExecutionDecision::Deny(format!(
-                    "Tool '{}' execution violates path restrictions",
-                    tool_name
-                )));
-            }
-        }
-
-        // Check argument whitelist (if configured)
-        if let Some(whitelist) = &permission.arg_whitelist {
-            if !self.check_arg_whitelist(args, whitelist)? {
-                return Ok(ExecutionDecision::Deny(format!(
-                    "Tool '{}' arguments not in whitelist",
-                    tool_name
-                )));
-            }
-        }
-
-        // Check argument blacklist (if configured)
-        if let Some(blacklist) = &permission.arg_blacklist {
-            if !self.check_arg_blacklist(args, blacklist)? {
-                return Ok(ExecutionDecision::Deny(format!(
-                    "Tool '{}' arguments match blacklist pattern",
-                    tool_name
-                )));
-            }
-        }
-
-        // All checks passed
-        Ok(ExecutionDecision::Allow)
+        evaluate_permission(permission, tool_name, args)
     }
 
     /// Apply default behavior when no permission is configured
@@ -189,72 +143,21 @@ impl ToolPermissionEnforcer {
     }
 
     /// Check if execution satisfies path restrictions
+    #[cfg(test)]
     fn check_path_restrictions(&self, args: &Value, restrictions: &str) -> Result<bool> {
-        // Parse restrictions as JSON array of path patterns
-        let patterns: Vec<String> = serde_json::from_str(restrictions)
-            .map_err(|e| OrcaError::Other(format!("Invalid path restrictions JSON: {}", e)))?;
-
-        // Extract path from arguments (commonly 'path', 'file', 'directory', etc.)
-        let path_value = args.get("path")
-            .or_else(|| args.get("file"))
-            .or_else(|| args.get("directory"))
-            .or_else(|| args.get("source"))
-            .or_else(|| args.get("target"));
-
-        if let Some(path) = path_value.and_then(|v| v.as_str()) {
-            // Check if path matches any allowed pattern
-            for pattern in patterns {
-                if path.starts_with(&pattern.replace("/*", "")) {
-                    return Ok(true);
-                }
-            }
-            return Ok(false);
-        }
-
-        // No path in arguments - allow (path restrictions don't apply)
-        Ok(true)
+        check_path_restrictions(args, restrictions)
     }
 
     /// Check if arguments satisfy whitelist
+    #[cfg(test)]
     fn check_arg_whitelist(&self, args: &Value, whitelist: &str) -> Result<bool> {
-        // Parse whitelist as JSON array of patterns
-        let patterns: Vec<String> = serde_json::from_str(whitelist)
-            .map_err(|e| OrcaError::Other(format!("Invalid whitelist JSON: {}", e)))?;
-
-        if patterns.is_empty() {
-            return Ok(true); // Empty whitelist allows everything
-        }
-
-        // Convert args to string for pattern matching
-        let args_str = args.to_string();
-
-        // Check if any pattern matches
-        for pattern in patterns {
-            if args_str.contains(&pattern) {
-                return Ok(true);
-            }
-        }
-
-        Ok(false)
+        check_arg_whitelist(args, whitelist)
     }
 
     /// Check if arguments violate blacklist
+    #[cfg(test)]
     fn check_arg_blacklist(&self, args: &Value, blacklist: &str) -> Result<bool> {
-        // Parse blacklist as JSON array of patterns
-        let patterns: Vec<String> = serde_json::from_str(blacklist)
-            .map_err(|e| OrcaError::Other(format!("Invalid blacklist JSON: {}", e)))?;
-
-        // Convert args to string for pattern matching
-        let args_str = args.to_string();
-
-        // Check if any pattern matches (violation)
-        for pattern in patterns {
-            if args_str.contains(&pattern) {
-                return Ok(false); // Match found - blacklist violated
-            }
-        }
-
-        Ok(true) // No matches - blacklist satisfied
+        check_arg_blacklist(args, blacklist)
     }
 
     /// Log tool execution to audit log
@@ -322,6 +225,234 @@ impl ToolPermissionEnforcer {
     }
 }
 
+/// Tools that modify the workspace or run arbitrary commands
+///
+/// Read-only sessions may never execute these, whatever their configured
+/// permission level.
+pub const DESTRUCTIVE_TOOLS: &[&str] = &[
+    "fs_delete",
+    "fs_move",
+    "fs_copy",
+    "file_write",
+    "file_patch",
+    "shell_exec",
+    "git_add",
+    "git_commit",
+    "ast_edit",
+];
+
+/// Check whether a tool is destructive (see [`DESTRUCTIVE_TOOLS`])
+pub fn is_destructive_tool(tool_name: &str) -> bool {
+    DESTRUCTIVE_TOOLS.contains(&tool_name)
+}
+
+/// Tool permissions resolved for a single session
+///
+/// Unlike [`ToolPermissionEnforcer`], this holds the permission rules in
+/// memory so they can be checked on every tool call without a database
+/// round-trip. Tools without a configured permission are denied.
+#[derive(Debug, Clone, Default)]
+pub struct SessionPermissions {
+    /// Permissions indexed by tool name
+    permissions: HashMap<String, ToolPermission>,
+
+    /// Whether destructive tools are blocked for this session
+    read_only: bool,
+}
+
+impl SessionPermissions {
+    /// Create session permissions from a set of tool permissions
+    pub fn new(permissions: impl IntoIterator<Item = ToolPermission>) -> Self {
+        Self {
+            permissions: permissions
+                .into_iter()
+                .map(|p| (p.tool_name.clone(), p))
+                .collect(),
+            read_only: false,
+        }
+    }
+
+    /// Load all configured tool permissions from the project database
+    pub async fn load(repo: &ToolPermissionRepository) -> Result<Self> {
+        Ok(Self::new(repo.list().await?))
+    }
+
+    /// Builder: Block destructive tools for this session
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Whether no tool permissions are configured
+    pub fn is_empty(&self) -> bool {
+        self.permissions.is_empty()
+    }
+
+    /// Whether destructive tools are blocked for this session
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Get the permission configured for a tool
+    pub fn get(&self, tool_name: &str) -> Option<&ToolPermission> {
+        self.permissions.get(tool_name)
+    }
+
+    /// Check whether the session may execute a tool
+    ///
+    /// Returns [`OrcaError::PermissionDenied`] when the tool is destructive in
+    /// a read-only session, has no configured permission, or its permission
+    /// does not evaluate to [`ExecutionDecision::Allow`]. The bridge has no
+    /// approval flow, so tools that require approval are denied as well.
+    pub fn check(&self, tool_name: &str, args: &Value) -> Result<()> {
+        if self.read_only && is_destructive_tool(tool_name) {
+            return Err(OrcaError::PermissionDenied(format!(
+                "Tool '{}' is destructive and the session is read-only",
+                tool_name
+            )));
+        }
+
+        let permission = self.permissions.get(tool_name).ok_or_else(|| {
+            OrcaError::PermissionDenied(format!(
+                "Tool '{}' has no configured permission",
+                tool_name
+            ))
+        })?;
+
+        match evaluate_permission(permission, tool_name, args)? {
+            ExecutionDecision::Allow => Ok(()),
+            ExecutionDecision::Deny(reason) | ExecutionDecision::RequiresApproval(reason) => {
+                Err(OrcaError::PermissionDenied(reason))
+            }
+        }
+    }
+}
+
+/// Evaluate a permission against tool execution request
+fn evaluate_permission(
+    permission: &ToolPermission,
+    tool_name: &str,
+    args: &Value,
+) -> Result<ExecutionDecision> {
+    // Check if denied
+    if permission.is_denied() {
+        return Ok(ExecutionDecision::Deny(format!(
+            "Tool '{}' is explicitly denied by project permissions",
+            tool_name
+        )));
+    }
+
+    // Check if requires approval
+    if permission.requires_approval() {
+        return Ok(ExecutionDecision::RequiresApproval(format!(
+            "Tool '{}' requires user approval before execution",
+            tool_name
+        )));
+    }
+
+    // Check path restrictions (if configured)
+    if let Some(restrictions) = &permission.path_restrictions {
+        if !check_path_restrictions(args, restrictions)? {
+            return Ok(ExecutionDecision::Deny(format!(
+                "Tool '{}' execution violates path restrictions",
+                tool_name
+            )));
+        }
+    }
+
+    // Check argument whitelist (if configured)
+    if let Some(whitelist) = &permission.arg_whitelist {
+        if !check_arg_whitelist(args, whitelist)? {
+            return Ok(ExecutionDecision::Deny(format!(
+                "Tool '{}' arguments not in whitelist",
+                tool_name
+            )));
+        }
+    }
+
+    // Check argument blacklist (if configured)
+    if let Some(blacklist) = &permission.arg_blacklist {
+        if !check_arg_blacklist(args, blacklist)? {
+            return Ok(ExecutionDecision::Deny(format!(
+                "Tool '{}' arguments match blacklist pattern",
+                tool_name
+            )));
+        }
+    }
+
+    // All checks passed
+    Ok(ExecutionDecision::Allow)
+}
+
+/// Check if execution satisfies path restrictions
+fn check_path_restrictions(args: &Value, restrictions: &str) -> Result<bool> {
+    // Parse restrictions as JSON array of path patterns
+    let patterns: Vec<String> = serde_json::from_str(restrictions)
+        .map_err(|e| OrcaError::Other(format!("Invalid path restrictions JSON: {}", e)))?;
+
+    // Extract path from arguments (commonly 'path', 'file', 'directory', etc.)
+    let path_value = args.get("path")
+        .or_else(|| args.get("file"))
+        .or_else(|| args.get("directory"))
+        .or_else(|| args.get("source"))
+        .or_else(|| args.get("target"));
+
+    if let Some(path) = path_value.and_then(|v| v.as_str()) {
+        // Check if path matches any allowed pattern
+        for pattern in patterns {
+            if path.starts_with(&pattern.replace("/*", "")) {
+                return Ok(true);
+            }
+        }
+        return Ok(false);
+    }
+
+    // No path in arguments - allow (path restrictions don't apply)
+    Ok(true)
+}
+
+/// Check if arguments satisfy whitelist
+fn check_arg_whitelist(args: &Value, whitelist: &str) -> Result<bool> {
+    // Parse whitelist as JSON array of patterns
+    let patterns: Vec<String> = serde_json::from_str(whitelist)
+        .map_err(|e| OrcaError::Other(format!("Invalid whitelist JSON: {}", e)))?;
+
+    if patterns.is_empty() {
+        return Ok(true); // Empty whitelist allows everything
+    }
+
+    // Convert args to string for pattern matching
+    let args_str = args.to_string();
+
+    // Check if any pattern matches
+    for pattern in patterns {
+        if args_str.contains(&pattern) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Check if arguments violate blacklist
+fn check_arg_blacklist(args: &Value, blacklist: &str) -> Result<bool> {
+    // Parse blacklist as JSON array of patterns
+    let patterns: Vec<String> = serde_json::from_str(blacklist)
+        .map_err(|e| OrcaError::Other(format!("Invalid blacklist JSON: {}", e)))?;
+
+    // Convert args to string for pattern matching
+    let args_str = args.to_string();
+
+    // Check if any pattern matches (violation)
+    for pattern in patterns {
+        if args_str.contains(&pattern) {
+            return Ok(false); // Match found - blacklist violated
+        }
+    }
+
+    Ok(true) // No matches - blacklist satisfied
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -709,4 +840,68 @@ mod tests {
             _ => panic!("Expected Deny decision for Restricted"),
         }
     }
+
+    // ===== SESSION PERMISSIONS =====
+
+    #[test]
+    fn test_session_permissions_allowed() {
+        let permissions = SessionPermissions::new(vec![
+            ToolPermission::new("file_read".to_string(), PermissionLevel::Allowed),
+        ]);
+
+        let args = serde_json::json!({"path": "/project/src/main.rs"});
+        assert!(permissions.check("file_read", &args).is_ok());
+    }
+
+    #[test]
+    fn test_session_permissions_denied() {
+        let permissions = SessionPermissions::new(vec![
+            ToolPermission::new("shell_exec".to_string(), PermissionLevel::Denied),
+        ]);
+
+        let args = serde_json::json!({"command": "ls"});
+        match permissions.check("shell_exec", &args) {
+            Err(OrcaError::PermissionDenied(msg)) => assert!(msg.contains("shell_exec")),
+            other => panic!("Expected PermissionDenied, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_session_permissions_missing_defaults_to_deny() {
+        let permissions = SessionPermissions::default();
+
+        let args = serde_json::json!({"path": "/project/src/main.rs"});
+        match permissions.check("file_read", &args) {
+            Err(OrcaError::PermissionDenied(msg)) => assert!(msg.contains("no configured permission")),
+            other => panic!("Expected PermissionDenied, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_session_permissions_read_only_blocks_destructive() {
+        let permissions = SessionPermissions::new(vec![
+            ToolPermission::new("fs_delete".to_string(), PermissionLevel::Allowed),
+            ToolPermission::new("file_read".to_string(), PermissionLevel::Allowed),
+        ])
+        .read_only();
+
+        let args = serde_json::json!({"path": "/project/old.txt"});
+        assert!(matches!(
+            permissions.check("fs_delete", &args),
+            Err(OrcaError::PermissionDenied(_))
+        ));
+        assert!(permissions.check("file_read", &args).is_ok());
+    }
+
+    #[test]
+    fn test_session_permissions_requires_approval_denied() {
+        let permissions = SessionPermissions::new(vec![
+            ToolPermission::new("git_commit".to_string(), PermissionLevel::RequiresApproval),
+        ]);
+
+        assert!(matches!(
+            permissions.check("git_commit", &serde_json::json!({})),
+            Err(OrcaError::PermissionDenied(_))
+        ));
+    }
 }
@@ -0,0 +1,498 @@
+//! Project Rule Engine
+//!
+//! Evaluates enabled project rules against a tool request before it runs.
+//! A tool rule's `config` names the tools and paths it covers with globs:
+//!
+//! ```json
+//! {"action": "deny", "tools": ["shell_*"], "paths": ["deploy/**"], "priority": 10}
+//! ```
+//!
+//! `action` defaults to `"deny"`, an empty `tools` list covers every tool
+//! and an empty `paths` list covers every request. Rules whose config names
+//! neither tools nor paths (e.g. style rules) are not tool rules and are
+//! ignored, and tool rules with a malformed config are skipped with a
+//! warning naming them.
+
+use crate::error::{OrcaError, Result};
+use crate::models::ProjectRule;
+use crate::repositories::ProjectRuleRepository;
+use serde::Deserialize;
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::path::{Component, Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Argument keys that carry a path in tool requests, at any nesting depth
+pub const PATH_ARG_KEYS: &[&str] = &["path", "file", "directory", "source", "target", "cwd"];
+
+/// What a matching rule does to a tool request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleAction {
+    /// Let the request through
+    Allow,
+    /// Refuse the request
+    Deny,
+}
+
+/// Outcome of evaluating a tool request against the project rules
+#[derive(Debug, Clone)]
+pub enum RuleDecision {
+    /// The request may run; carries the allow rule that matched, if any
+    Allow(Option<ProjectRule>),
+    /// The request is refused by this rule
+    Deny(ProjectRule),
+}
+
+impl RuleDecision {
+    /// Whether the request may run
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Self::Allow(_))
+    }
+
+    /// The rule that decided the request, if any matched
+    pub fn matched_rule(&self) -> Option<&ProjectRule> {
+        match self {
+            Self::Allow(rule) => rule.as_ref(),
+            Self::Deny(rule) => Some(rule),
+        }
+    }
+}
+
+/// Tool rule settings parsed from [`ProjectRule::config`]
+#[derive(Debug, Clone, Deserialize)]
+struct ToolRuleConfig {
+    #[serde(default = "default_action")]
+    action: RuleAction,
+    #[serde(default)]
+    tools: Vec<String>,
+    #[serde(default)]
+    paths: Vec<String>,
+    #[serde(default)]
+    priority: i64,
+}
+
+fn default_action() -> RuleAction {
+    RuleAction::Deny
+}
+
+/// A project rule with its parsed tool rule settings
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    rule: ProjectRule,
+    config: ToolRuleConfig,
+}
+
+impl CompiledRule {
+    fn matches(&self, tool_name: &str, paths: &[String]) -> bool {
+        let tool_matches = self.config.tools.is_empty()
+            || self.config.tools.iter().any(|p| glob_match(p, tool_name));
+        let path_matches = self.config.paths.is_empty()
+            || paths
+                .iter()
+                .any(|path| self.config.paths.iter().any(|p| glob_match(p, path)));
+        tool_matches && path_matches
+    }
+}
+
+/// Evaluates project rules against tool requests
+///
+/// When several rules match, the one with the highest `priority` decides.
+/// Ties go to deny over allow, then to `error` severity rules, then to the
+/// rule name so the outcome never depends on storage order.
+#[derive(Debug, Clone, Default)]
+pub struct RuleEngine {
+    rules: Vec<CompiledRule>,
+    workspace_root: Option<PathBuf>,
+}
+
+impl RuleEngine {
+    /// Build an engine from project rules
+    ///
+    /// Disabled rules, rules that are not tool rules and tool rules whose
+    /// config is malformed are skipped; malformed ones are logged by name.
+    pub fn new(rules: impl IntoIterator<Item = ProjectRule>) -> Self {
+        let mut compiled = Vec::new();
+        for rule in rules.into_iter().filter(ProjectRule::is_enabled) {
+            let value: Value = match serde_json::from_str(&rule.config) {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!(rule = %rule.name, error = %e, "Skipping rule with non-JSON config");
+                    continue;
+                }
+            };
+            if value.get("tools").is_none() && value.get("paths").is_none() {
+                debug!(rule = %rule.name, "Skipping non-tool rule");
+                continue;
+            }
+            let config: ToolRuleConfig = match serde_json::from_value(value) {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!(rule = %rule.name, error = %e, "Skipping tool rule with invalid config");
+                    continue;
+                }
+            };
+            compiled.push(CompiledRule { rule, config });
+        }
+
+        compiled.sort_by_key(|c| {
+            (
+                Reverse(c.config.priority),
+                c.config.action != RuleAction::Deny,
+                !c.rule.is_error(),
+                c.rule.name.clone(),
+            )
+        });
+
+        Self {
+            rules: compiled,
+            workspace_root: None,
+        }
+    }
+
+    /// Load the enabled rules from the project database
+    ///
+    /// Only a failure to read the rules is an error; malformed rules are
+    /// skipped as in [`RuleEngine::new`].
+    pub async fn load(repo: &ProjectRuleRepository) -> Result<Self> {
+        Ok(Self::new(repo.list_enabled().await?))
+    }
+
+    /// Builder: Match absolute paths under this root by their relative path
+    pub fn with_workspace_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.workspace_root = Some(root.into());
+        self
+    }
+
+    /// Number of tool rules being enforced
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether there are no tool rules to enforce
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluate a tool request
+    ///
+    /// Requests that match no rule are allowed.
+    pub fn evaluate(&self, tool_name: &str, args: &Value) -> RuleDecision {
+        let paths = self.request_paths(args);
+        match self.rules.iter().find(|c| c.matches(tool_name, &paths)) {
+            Some(c) if c.config.action == RuleAction::Deny => RuleDecision::Deny(c.rule.clone()),
+            Some(c) => RuleDecision::Allow(Some(c.rule.clone())),
+            None => RuleDecision::Allow(None),
+        }
+    }
+
+    /// Evaluate a tool request, failing with [`OrcaError::PermissionDenied`]
+    /// naming the rule when it is denied
+    pub fn check(&self, tool_name: &str, args: &Value) -> Result<()> {
+        match self.evaluate(tool_name, args) {
+            RuleDecision::Allow(_) => Ok(()),
+            RuleDecision::Deny(rule) => Err(OrcaError::PermissionDenied(format!(
+                "Tool '{}' denied by rule '{}'",
+                tool_name, rule.name
+            ))),
+        }
+    }
+
+    /// Paths named by the request, normalized and relative to the workspace
+    /// root when they fall under it
+    ///
+    /// Path keys are found anywhere in the arguments, including nested
+    /// objects and arrays, and a path key may hold a list of paths. Relative
+    /// paths are resolved against the workspace root, and `.` and `..` are
+    /// collapsed lexically, so `src/../deploy/x` is matched as `deploy/x`.
+    fn request_paths(&self, args: &Value) -> Vec<String> {
+        let mut raw = Vec::new();
+        collect_path_args(args, &mut raw);
+        raw.into_iter()
+            .map(|path| {
+                let path = Path::new(path);
+                let path = match &self.workspace_root {
+                    Some(root) => normalize_lexically(&root.join(path)),
+                    None => normalize_lexically(path),
+                };
+                let relative = self
+                    .workspace_root
+                    .as_ref()
+                    .and_then(|root| path.strip_prefix(normalize_lexically(root)).ok());
+                relative.unwrap_or(&path).to_string_lossy().into_owned()
+            })
+            .collect()
+    }
+}
+
+/// Collect the string values stored under [`PATH_ARG_KEYS`] anywhere in
+/// `value`
+fn collect_path_args<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if PATH_ARG_KEYS.contains(&key.as_str()) {
+                    match value {
+                        Value::String(path) => out.push(path),
+                        Value::Array(items) => out.extend(items.iter().filter_map(Value::as_str)),
+                        _ => {}
+                    }
+                }
+                collect_path_args(value, out);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_path_args(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collapse `.` and `..` components without touching the filesystem
+///
+/// `..` at the root is dropped; leading `..` in a relative path is kept.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Match `text` against a glob pattern
+///
+/// `*` matches within a path segment, `**` matches across segments (and
+/// `**/` may match no segments at all) and `?` matches one character other
+/// than `/`. Each (pattern, text) position pair is evaluated at most once,
+/// so matching is `O(pattern * text^2)` even for patterns full of wildcards.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    GlobMatcher {
+        memo: vec![None; (pattern.len() + 1) * (text.len() + 1)],
+        pattern: &pattern,
+        text: &text,
+    }
+    .matches(0, 0)
+}
+
+/// Memoized backtracking over pattern and text positions
+struct GlobMatcher<'a> {
+    pattern: &'a [char],
+    text: &'a [char],
+    memo: Vec<Option<bool>>,
+}
+
+impl GlobMatcher<'_> {
+    fn matches(&mut self, p: usize, t: usize) -> bool {
+        let slot = p * (self.text.len() + 1) + t;
+        if let Some(hit) = self.memo[slot] {
+            return hit;
+        }
+        let result = self.matches_uncached(p, t);
+        self.memo[slot] = Some(result);
+        result
+    }
+
+    fn matches_uncached(&mut self, p: usize, t: usize) -> bool {
+        let (pattern, text) = (self.pattern, self.text);
+        match pattern.get(p) {
+            None => t == text.len(),
+            Some('*') if pattern.get(p + 1) == Some(&'*') => {
+                let rest = p + 2;
+                if pattern.get(rest) == Some(&'/') && self.matches(rest + 1, t) {
+                    return true;
+                }
+                (t..=text.len()).any(|i| self.matches(rest, i))
+            }
+            Some('*') => {
+                for i in t..=text.len() {
+                    if self.matches(p + 1, i) {
+                        return true;
+                    }
+                    if text.get(i) == Some(&'/') {
+                        break;
+                    }
+                }
+                false
+            }
+            Some('?') => match text.get(t) {
+                Some(c) if *c != '/' => self.matches(p + 1, t + 1),
+                _ => false,
+            },
+            Some(c) => text.get(t) == Some(c) && self.matches(p + 1, t + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule(name: &str, config: Value) -> ProjectRule {
+        ProjectRule::new(name.to_string(), "security".to_string(), config.to_string())
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("shell_*", "shell_exec"));
+        assert!(!glob_match("shell_*", "file_read"));
+        assert!(glob_match("deploy/**", "deploy/prod/run.sh"));
+        assert!(glob_match("src/**/*.rs", "src/main.rs"));
+        assert!(glob_match("src/**/*.rs", "src/a/b/lib.rs"));
+        assert!(!glob_match("src/*.rs", "src/a/lib.rs"));
+        assert!(glob_match("file_?ead", "file_read"));
+    }
+
+    #[test]
+    fn test_matching_deny_rule() {
+        let engine = RuleEngine::new(vec![rule(
+            "no-shell-in-deploy",
+            json!({"action": "deny", "tools": ["shell_*"], "paths": ["deploy/**"]}),
+        )])
+        .with_workspace_root("/work");
+
+        let decision = engine.evaluate("shell_exec", &json!({"cwd": "/work/deploy/prod"}));
+        assert!(!decision.is_allowed());
+        assert_eq!(decision.matched_rule().unwrap().name, "no-shell-in-deploy");
+
+        let err = engine
+            .check("shell_exec", &json!({"cwd": "deploy/prod"}))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("denied by rule 'no-shell-in-deploy'"));
+    }
+
+    #[test]
+    fn test_request_paths_are_normalized() {
+        let engine = RuleEngine::new(vec![rule(
+            "no-writes-in-deploy",
+            json!({"tools": ["file_write"], "paths": ["deploy/**"]}),
+        )])
+        .with_workspace_root("/work");
+
+        for path in [
+            "src/../deploy/x",
+            "deploy/./x",
+            "./deploy/x",
+            "/work/src/../deploy/x",
+            "/work/./deploy/x",
+        ] {
+            let decision = engine.evaluate("file_write", &json!({"path": path}));
+            assert!(!decision.is_allowed(), "{} should be denied", path);
+        }
+
+        // Escaping the workspace does not land back in a relative match
+        let decision = engine.evaluate("file_write", &json!({"path": "../deploy/x"}));
+        assert!(decision.is_allowed());
+    }
+
+    #[test]
+    fn test_non_matching_rule_allows() {
+        let engine = RuleEngine::new(vec![
+            rule(
+                "no-shell-in-deploy",
+                json!({"tools": ["shell_*"], "paths": ["deploy/**"]}),
+            ),
+            rule("style", json!({"max_line_length": 100})),
+        ]);
+        assert_eq!(engine.len(), 1);
+
+        // Right tool, wrong path
+        let decision = engine.evaluate("shell_exec", &json!({"cwd": "src"}));
+        assert!(decision.is_allowed());
+        assert!(decision.matched_rule().is_none());
+
+        // Right path, wrong tool
+        assert!(engine
+            .check("file_read", &json!({"path": "deploy/run.sh"}))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_precedence_when_multiple_rules_match() {
+        let deny_all_shell = rule("deny-shell", json!({"tools": ["shell_exec"]}));
+        let allow_scripts = rule(
+            "allow-scripts",
+            json!({"action": "allow", "tools": ["shell_exec"], "paths": ["scripts/**"], "priority": 10}),
+        );
+        let allow_shell = rule(
+            "allow-shell",
+            json!({"action": "allow", "tools": ["shell_*"]}),
+        );
+        let engine = RuleEngine::new(vec![allow_shell, allow_scripts, deny_all_shell]);
+
+        // Higher priority allow beats the deny
+        let decision = engine.evaluate("shell_exec", &json!({"cwd": "scripts/build"}));
+        assert!(decision.is_allowed());
+        assert_eq!(decision.matched_rule().unwrap().name, "allow-scripts");
+
+        // At equal priority deny beats allow
+        let decision = engine.evaluate("shell_exec", &json!({"cwd": "src"}));
+        assert_eq!(decision.matched_rule().unwrap().name, "deny-shell");
+        assert!(!decision.is_allowed());
+
+        // Between equal denies the error severity rule is reported
+        let engine = RuleEngine::new(vec![
+            rule("a-warn", json!({"tools": ["shell_exec"]})),
+            rule("b-error", json!({"tools": ["shell_*"]})).with_severity("error"),
+        ]);
+        let decision = engine.evaluate("shell_exec", &json!({}));
+        assert_eq!(decision.matched_rule().unwrap().name, "b-error");
+    }
+
+    #[test]
+    fn test_disabled_and_malformed_rules() {
+        let engine = RuleEngine::new(vec![rule("off", json!({"tools": ["*"]})).disabled()]);
+        assert!(engine.is_empty());
+
+        // A malformed rule is skipped without dropping the valid ones
+        let engine = RuleEngine::new(vec![
+            rule("bad", json!({"tools": "shell_exec"})),
+            rule("deny-shell", json!({"tools": ["shell_exec"]})),
+        ]);
+        assert_eq!(engine.len(), 1);
+        let decision = engine.evaluate("shell_exec", &json!({}));
+        assert_eq!(decision.matched_rule().unwrap().name, "deny-shell");
+    }
+
+    #[test]
+    fn test_nested_path_arguments_are_checked() {
+        let engine = RuleEngine::new(vec![rule(
+            "no-writes-in-deploy",
+            json!({"tools": ["file_write"], "paths": ["deploy/**"]}),
+        )]);
+
+        for args in [
+            json!({"options": {"target": "deploy/app.toml"}}),
+            json!({"edits": [{"file": "src/main.rs"}, {"file": "deploy/run.sh"}]}),
+            json!({"source": ["src/a.rs", "deploy/b.rs"]}),
+        ] {
+            assert!(!engine.evaluate("file_write", &args).is_allowed(), "{} should be denied", args);
+        }
+        assert!(engine
+            .evaluate("file_write", &json!({"edits": [{"file": "src/main.rs"}]}))
+            .is_allowed());
+    }
+
+    #[test]
+    fn test_glob_match_does_not_backtrack_exponentially() {
+        // Without memoization these take longer than the age of the universe
+        assert!(!glob_match("*a*a*a*a*a*a*a*a*a*a*a*a*b", &"a".repeat(60)));
+        assert!(!glob_match(&format!("{}b", "**/".repeat(12)), &"a/".repeat(40)));
+    }
+}
@@ -3,6 +3,7 @@
 //! Defines the core Task and Workflow types with status tracking,
 //! persistence, and lifecycle management.
 
+use crate::error::{OrcaError, Result};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -437,6 +438,36 @@ impl Workflow {
         self.set_routing_strategy(strategy);
         self
     }
+
+    /// Export the workflow and its ordered tasks as TOON
+    ///
+    /// TOON is a compact, human-editable text format, so exported workflows
+    /// can be reviewed, edited and shared between machines. Tasks are written
+    /// in the given order, which [`Workflow::from_toon`] preserves.
+    pub fn to_toon(&self, tasks: &[Task]) -> Result<String> {
+        let value = serde_json::to_value(WorkflowExport {
+            workflow: self.clone(),
+            tasks: tasks.to_vec(),
+        })?;
+        Ok(rtoon::encode(&value, None))
+    }
+
+    /// Import a workflow and its ordered tasks from TOON
+    pub fn from_toon(input: &str) -> Result<WorkflowExport> {
+        let value = rtoon::decode(input, None)
+            .map_err(|e| OrcaError::Other(format!("Invalid TOON workflow: {}", e)))?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+/// Portable workflow representation used for TOON export/import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowExport {
+    /// The workflow itself
+    pub workflow: Workflow,
+
+    /// Workflow tasks in execution order
+    pub tasks: Vec<Task>,
 }
 
 #[cfg(test)]
@@ -1187,5 +1218,52 @@ mod tests {
         assert_eq!(TaskStatus::from("cancelled"), TaskStatus::Cancelled);
         assert_eq!(TaskStatus::from("unknown"), TaskStatus::Pending);
     }
-}
 
+    #[test]
+    fn test_workflow_toon_round_trip() {
+        let workflow = Workflow::new("Release", "plan_execute")
+            .with_description("Cut and publish a release")
+            .with_routing_strategy("sequential");
+
+        let mut build = Task::new("Build artifacts")
+            .with_priority(5)
+            .with_metadata(r#"{"target":"x86_64","retries":2}"#);
+        build.mark_completed(Some(r#"{"artifacts":3}"#.to_string()));
+
+        let mut test = Task::new("Run tests: unit, integration");
+        test.mark_failed("2 tests failed");
+
+        let publish = Task::new("Publish").with_metadata("{}");
+
+        let tasks = vec![build, test, publish];
+        let toon = workflow.to_toon(&tasks).unwrap();
+        let imported = Workflow::from_toon(&toon).unwrap();
+
+        assert_eq!(imported.workflow.id, workflow.id);
+        assert_eq!(imported.workflow.name, workflow.name);
+        assert_eq!(imported.workflow.description, workflow.description);
+        assert_eq!(imported.workflow.metadata, workflow.metadata);
+        assert_eq!(imported.workflow.routing_strategy(), "sequential");
+
+        assert_eq!(imported.tasks.len(), tasks.len());
+        for (imported, original) in imported.tasks.iter().zip(&tasks) {
+            assert_eq!(imported.id, original.id);
+            assert_eq!(imported.description, original.description);
+            assert_eq!(imported.status, original.status);
+            assert_eq!(imported.priority, original.priority);
+            assert_eq!(imported.result, original.result);
+            assert_eq!(imported.error, original.error);
+            assert_eq!(imported.metadata, original.metadata);
+            assert_eq!(imported.started_at, original.started_at);
+            assert_eq!(imported.completed_at, original.completed_at);
+        }
+        assert_eq!(imported.tasks[0].status(), TaskStatus::Completed);
+        assert_eq!(imported.tasks[1].status(), TaskStatus::Failed);
+        assert_eq!(imported.tasks[2].status(), TaskStatus::Pending);
+    }
+
+    #[test]
+    fn test_workflow_from_invalid_toon() {
+        assert!(Workflow::from_toon("workflow:\n  name: missing fields").is_err());
+    }
+}
@@ -43,6 +43,51 @@ impl TaskRepository {
         .await
     }
 
+    /// Insert a task, or replace every column of an existing task with the same ID
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `task` - Task to write
+    ///
+    /// # Returns
+    /// Success or database error
+    pub async fn upsert(pool: &DatabasePool, task: &Task) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO tasks (id, title, description, task_type, status, config, metadata,
+                                workspace_path, created_at, updated_at, started_at, completed_at, error)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                task_type = excluded.task_type,
+                status = excluded.status,
+                config = excluded.config,
+                metadata = excluded.metadata,
+                workspace_path = excluded.workspace_path,
+                updated_at = excluded.updated_at,
+                started_at = excluded.started_at,
+                completed_at = excluded.completed_at,
+                error = excluded.error"
+        )
+        .bind(&task.id)
+        .bind(&task.title)
+        .bind(&task.description)
+        .bind(&task.task_type)
+        .bind(&task.status)
+        .bind(&task.config)
+        .bind(&task.metadata)
+        .bind(&task.workspace_path)
+        .bind(&task.created_at)
+        .bind(&task.updated_at)
+        .bind(&task.started_at)
+        .bind(&task.completed_at)
+        .bind(&task.error)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Get a task by ID
     ///
     /// # Arguments
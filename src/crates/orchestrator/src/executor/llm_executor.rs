@@ -262,6 +262,9 @@ Be concise and focus on completing the task effectively."#
 
         // Stream tokens
         while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| {
+                crate::OrchestratorError::General(format!("LLM stream failed: {}", e))
+            })?;
             content.push_str(&chunk.content);
 
             // Send token update if sender is active
@@ -17,14 +17,18 @@ pub mod proto;
 pub mod proto_conv;
 pub mod router;
 pub mod services;
+pub mod store;
 pub mod version;
 pub mod workflow;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::stream::{FuturesUnordered, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
+use tokio::sync::broadcast;
 use uuid::Uuid;
 
 /// Errors that can occur during orchestration
@@ -42,6 +46,14 @@ pub enum OrchestratorError {
     #[error("Invalid state transition from {from} to {to}")]
     InvalidStateTransition { from: String, to: String },
 
+    /// Task dependencies form a cycle
+    #[error("Dependency cycle among tasks: {}", tasks.join(", "))]
+    DependencyCycle { tasks: Vec<String> },
+
+    /// Task store error
+    #[error("Task store error: {0}")]
+    Storage(String),
+
     /// General error
     #[error("Orchestrator error: {0}")]
     General(String),
@@ -57,6 +69,7 @@ pub type Result<T> = std::result::Result<T, OrchestratorError>;
 // Re-export commonly used types
 pub use execution::{TaskExecutionEngine, WorkflowExecutionEngine, WorkflowExecutor};
 pub use executor::LlmTaskExecutor;
+pub use store::{InMemoryTaskStore, SqliteTaskStore, TaskStore};
 
 /// Task execution status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -102,6 +115,12 @@ pub struct Task {
     pub updated_at: DateTime<Utc>,
     /// Task metadata
     pub metadata: HashMap<String, String>,
+    /// Tasks that must complete before this one starts
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Number of times the task has been retried after failing
+    #[serde(default)]
+    pub attempt: u32,
 }
 
 impl Task {
@@ -116,6 +135,8 @@ impl Task {
             created_at: now,
             updated_at: now,
             metadata: HashMap::new(),
+            depends_on: Vec::new(),
+            attempt: 0,
         }
     }
 
@@ -131,6 +152,12 @@ impl Task {
         self
     }
 
+    /// Add a task that must complete before this one starts
+    pub fn with_dependency(mut self, id: Uuid) -> Self {
+        self.depends_on.push(id);
+        self
+    }
+
     /// Update task status
     pub fn update_status(&mut self, status: TaskStatus) -> Result<()> {
         // Validate state transition
@@ -148,6 +175,24 @@ impl Task {
         self.updated_at = Utc::now();
         Ok(())
     }
+
+    /// Reset a failed task to pending so it can run again
+    ///
+    /// This is the only way out of `Failed`; completed and cancelled tasks
+    /// cannot be retried.
+    pub fn retry(&mut self) -> Result<()> {
+        if self.status != TaskStatus::Failed {
+            return Err(OrchestratorError::InvalidStateTransition {
+                from: self.status.to_string(),
+                to: TaskStatus::Pending.to_string(),
+            });
+        }
+
+        self.status = TaskStatus::Pending;
+        self.attempt += 1;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
 }
 
 /// Trait for executing tasks
@@ -157,13 +202,91 @@ pub trait TaskExecutor: Send + Sync {
     async fn execute(&self, task: &Task) -> Result<()>;
 }
 
+/// Task lifecycle change published by the orchestrator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskEvent {
+    /// A task was added
+    Added {
+        task_id: Uuid,
+        timestamp: DateTime<Utc>,
+    },
+    /// A task moved between statuses
+    StatusChanged {
+        task_id: Uuid,
+        from: TaskStatus,
+        to: TaskStatus,
+        timestamp: DateTime<Utc>,
+    },
+    /// A task was removed
+    Removed {
+        task_id: Uuid,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl TaskEvent {
+    /// ID of the task the event is about
+    pub fn task_id(&self) -> Uuid {
+        match self {
+            TaskEvent::Added { task_id, .. }
+            | TaskEvent::StatusChanged { task_id, .. }
+            | TaskEvent::Removed { task_id, .. } => *task_id,
+        }
+    }
+
+    /// When the event happened
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            TaskEvent::Added { timestamp, .. }
+            | TaskEvent::StatusChanged { timestamp, .. }
+            | TaskEvent::Removed { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
+/// Number of events a slow subscriber can fall behind before it starts
+/// missing them
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Whether a task's dependencies allow it to run
+enum DependencyState {
+    /// Every dependency completed
+    Ready,
+    /// Some dependency has not finished yet
+    Waiting,
+    /// Some dependency failed or was cancelled
+    Unsatisfiable,
+}
+
 /// Workflow orchestrator
-#[derive(Debug)]
+///
+/// Tasks are held in memory. When created with [`Orchestrator::with_store`],
+/// the `*_persisted` methods write each change through to a [`TaskStore`]
+/// before returning, so tasks survive restarts, and
+/// [`get_task_persisted`](Orchestrator::get_task_persisted) falls back to the
+/// store for tasks not in memory. [`execute_all`](Orchestrator::execute_all)
+/// persists every status change. The synchronous methods only touch memory.
 pub struct Orchestrator {
     /// Active tasks
     tasks: HashMap<Uuid, Task>,
     /// Orchestrator configuration
     config: OrchestratorConfig,
+    /// Persistent task store, if any
+    store: Option<Arc<dyn TaskStore>>,
+    /// Lifecycle event channel
+    events: broadcast::Sender<TaskEvent>,
+}
+
+impl std::fmt::Debug for Orchestrator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Orchestrator")
+            .field("tasks", &self.tasks)
+            .field("config", &self.config)
+            .field("store", &self.store.is_some())
+            .field("subscribers", &self.events.receiver_count())
+            .finish()
+    }
 }
 
 /// Configuration for the orchestrator
@@ -214,14 +337,61 @@ impl Orchestrator {
         Self {
             tasks: HashMap::new(),
             config,
+            store: None,
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
         }
     }
 
+    /// Create an orchestrator backed by a task store
+    ///
+    /// Tasks already in the store are loaded, so a new orchestrator picks up
+    /// where a previous one left off.
+    pub async fn with_store(config: OrchestratorConfig, store: Arc<dyn TaskStore>) -> Result<Self> {
+        let tasks = store
+            .list()
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task))
+            .collect();
+        Ok(Self {
+            tasks,
+            config,
+            store: Some(store),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+        })
+    }
+
+    /// Subscribe to task lifecycle events
+    ///
+    /// The receiver sees every event published after this call. Subscribers
+    /// that fall more than a few hundred events behind get
+    /// `RecvError::Lagged` and skip ahead.
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskEvent> {
+        self.events.subscribe()
+    }
+
+    fn publish(&self, event: TaskEvent) {
+        // Sending only fails when nobody is subscribed
+        let _ = self.events.send(event);
+    }
+
+    /// Get the task store, if any
+    pub fn store(&self) -> Option<&Arc<dyn TaskStore>> {
+        self.store.as_ref()
+    }
+
     /// Add a task to the orchestrator
+    ///
+    /// Only changes memory; use [`add_task_persisted`](Self::add_task_persisted)
+    /// to also write the task to the store.
     pub fn add_task(&mut self, task: Task) -> Uuid {
         let id = task.id;
         self.tasks.insert(id, task);
         tracing::debug!("Added task {}", id);
+        self.publish(TaskEvent::Added {
+            task_id: id,
+            timestamp: Utc::now(),
+        });
         id
     }
 
@@ -231,15 +401,110 @@ impl Orchestrator {
     }
 
     /// Get a mutable task by ID
+    ///
+    /// Call [`persist_task`](Self::persist_task) to write the edits to the
+    /// store.
     pub fn get_task_mut(&mut self, id: &Uuid) -> Option<&mut Task> {
         self.tasks.get_mut(id)
     }
 
+    /// Update a task's status
+    ///
+    /// Only changes memory; use
+    /// [`update_status_persisted`](Self::update_status_persisted) to also
+    /// write the change to the store.
+    pub fn update_status(&mut self, id: &Uuid, status: TaskStatus) -> Result<()> {
+        let task = self
+            .tasks
+            .get_mut(id)
+            .ok_or_else(|| OrchestratorError::TaskNotFound(id.to_string()))?;
+        let from = task.status;
+        task.update_status(status)?;
+        let timestamp = task.updated_at;
+        self.publish(TaskEvent::StatusChanged {
+            task_id: *id,
+            from,
+            to: status,
+            timestamp,
+        });
+        Ok(())
+    }
+
     /// Remove a task
+    ///
+    /// Only changes memory; use
+    /// [`remove_task_persisted`](Self::remove_task_persisted) to also delete
+    /// the task from the store.
     pub fn remove_task(&mut self, id: &Uuid) -> Result<Task> {
-        self.tasks
+        let task = self
+            .tasks
             .remove(id)
-            .ok_or_else(|| OrchestratorError::TaskNotFound(id.to_string()))
+            .ok_or_else(|| OrchestratorError::TaskNotFound(id.to_string()))?;
+        self.publish(TaskEvent::Removed {
+            task_id: *id,
+            timestamp: Utc::now(),
+        });
+        Ok(task)
+    }
+
+    /// Add a task, writing it to the store first
+    ///
+    /// Without a store this is the same as [`add_task`](Self::add_task).
+    pub async fn add_task_persisted(&mut self, task: Task) -> Result<Uuid> {
+        if let Some(store) = &self.store {
+            store.save(&task).await?;
+        }
+        Ok(self.add_task(task))
+    }
+
+    /// Get a task by ID, loading it from the store if it is not in memory
+    ///
+    /// A task found in the store is kept in memory afterwards.
+    pub async fn get_task_persisted(&mut self, id: &Uuid) -> Result<Option<&Task>> {
+        if !self.tasks.contains_key(id) {
+            let Some(store) = &self.store else {
+                return Ok(None);
+            };
+            match store.load(id).await? {
+                Some(task) => {
+                    self.tasks.insert(*id, task);
+                }
+                None => return Ok(None),
+            }
+        }
+        Ok(self.tasks.get(id))
+    }
+
+    /// Update a task's status and write the task to the store
+    pub async fn update_status_persisted(&mut self, id: &Uuid, status: TaskStatus) -> Result<()> {
+        self.update_status(id, status)?;
+        self.persist_task(id).await
+    }
+
+    /// Remove a task, deleting it from the store first
+    pub async fn remove_task_persisted(&mut self, id: &Uuid) -> Result<Task> {
+        if self.get_task_persisted(id).await?.is_none() {
+            return Err(OrchestratorError::TaskNotFound(id.to_string()));
+        }
+        if let Some(store) = &self.store {
+            store.delete(id).await?;
+        }
+        self.remove_task(id)
+    }
+
+    /// Write a task's in-memory state to the store
+    ///
+    /// Use after editing a task through [`get_task_mut`](Self::get_task_mut).
+    /// Does nothing without a store.
+    pub async fn persist_task(&self, id: &Uuid) -> Result<()> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        let task = self
+            .tasks
+            .get(id)
+            .ok_or_else(|| OrchestratorError::TaskNotFound(id.to_string()))?;
+        store.save(task).await
     }
 
     /// Get all tasks
@@ -261,6 +526,170 @@ impl Orchestrator {
     pub fn can_accept_task(&self) -> bool {
         self.running_count() < self.config.max_concurrent_tasks
     }
+
+    /// Group all tasks into batches in dependency order
+    ///
+    /// Every task's dependencies appear in an earlier batch, so the tasks
+    /// within one batch are independent of each other. Fails with
+    /// `TaskNotFound` if a task depends on an unknown ID and with
+    /// `DependencyCycle` if the dependencies are not acyclic.
+    pub fn execution_order(&self) -> Result<Vec<Vec<Uuid>>> {
+        let mut in_degree: HashMap<Uuid, usize> = HashMap::new();
+        let mut dependents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for task in self.tasks.values() {
+            in_degree.entry(task.id).or_insert(0);
+            for dep in &task.depends_on {
+                if !self.tasks.contains_key(dep) {
+                    return Err(OrchestratorError::TaskNotFound(format!(
+                        "{} (dependency of '{}')",
+                        dep, task.name
+                    )));
+                }
+                *in_degree.entry(task.id).or_insert(0) += 1;
+                dependents.entry(*dep).or_default().push(task.id);
+            }
+        }
+
+        let mut batch: Vec<Uuid> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut order = Vec::new();
+        let mut ordered = 0;
+        while !batch.is_empty() {
+            self.sort_by_creation(&mut batch);
+            ordered += batch.len();
+            let mut next = Vec::new();
+            for id in &batch {
+                for dependent in dependents.get(id).into_iter().flatten() {
+                    let degree = in_degree.get_mut(dependent).expect("dependent is a task");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        next.push(*dependent);
+                    }
+                }
+            }
+            order.push(std::mem::replace(&mut batch, next));
+        }
+
+        if ordered < self.tasks.len() {
+            let mut tasks: Vec<String> = in_degree
+                .iter()
+                .filter(|(_, degree)| **degree > 0)
+                .map(|(id, _)| self.tasks[id].name.clone())
+                .collect();
+            tasks.sort();
+            return Err(OrchestratorError::DependencyCycle { tasks });
+        }
+
+        Ok(order)
+    }
+
+    /// Execute every pending task, respecting dependencies
+    ///
+    /// Tasks start as soon as all of their dependencies have completed, with
+    /// at most `max_concurrent_tasks` running at once. The dependency graph is
+    /// validated before anything runs. If a task fails, the tasks that depend
+    /// on it are cancelled while independent tasks keep running, and the first
+    /// failure is returned once everything has settled. Every status change is
+    /// written to the store, if any.
+    pub async fn execute_all(&mut self, executor: &dyn TaskExecutor) -> Result<()> {
+        self.execution_order()?;
+
+        let limit = self.config.max_concurrent_tasks.max(1);
+        let mut pending: Vec<Uuid> = self
+            .tasks_by_status(TaskStatus::Pending)
+            .map(|task| task.id)
+            .collect();
+        self.sort_by_creation(&mut pending);
+
+        let mut running = FuturesUnordered::new();
+        let mut first_error = None;
+        loop {
+            // Cancelling a task can make its dependents unsatisfiable, so
+            // rescan until nothing changes
+            let mut changed = true;
+            while changed {
+                changed = false;
+                let mut i = 0;
+                while i < pending.len() {
+                    let id = pending[i];
+                    match self.dependency_state(&self.tasks[&id]) {
+                        DependencyState::Ready if running.len() < limit => {
+                            self.update_status_persisted(&id, TaskStatus::Running).await?;
+                            let task = self.tasks[&id].clone();
+                            if self.config.verbose {
+                                tracing::info!("Starting task '{}' ({})", task.name, id);
+                            }
+                            running.push(async move {
+                                let result = executor.execute(&task).await;
+                                (task.id, result)
+                            });
+                            pending.remove(i);
+                        }
+                        DependencyState::Unsatisfiable => {
+                            tracing::warn!(
+                                "Cancelling task '{}' ({}): a dependency did not complete",
+                                self.tasks[&id].name,
+                                id
+                            );
+                            self.update_status_persisted(&id, TaskStatus::Cancelled).await?;
+                            pending.remove(i);
+                            changed = true;
+                        }
+                        _ => i += 1,
+                    }
+                }
+            }
+
+            let Some((id, result)) = running.next().await else {
+                break;
+            };
+            let name = self.tasks[&id].name.clone();
+            match result {
+                Ok(()) => {
+                    if self.config.verbose {
+                        tracing::info!("Task '{}' ({}) completed", name, id);
+                    }
+                    self.update_status_persisted(&id, TaskStatus::Completed).await?;
+                }
+                Err(err) => {
+                    tracing::error!("Task '{}' ({}) failed: {}", name, id, err);
+                    self.update_status_persisted(&id, TaskStatus::Failed).await?;
+                    first_error.get_or_insert_with(|| {
+                        OrchestratorError::ExecutionFailed(format!(
+                            "Task '{}' failed: {}",
+                            name, err
+                        ))
+                    });
+                }
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn dependency_state(&self, task: &Task) -> DependencyState {
+        let mut state = DependencyState::Ready;
+        for dep in &task.depends_on {
+            match self.tasks.get(dep).map(|dep| dep.status) {
+                Some(TaskStatus::Completed) => {}
+                Some(TaskStatus::Pending) | Some(TaskStatus::Running) => {
+                    state = DependencyState::Waiting;
+                }
+                _ => return DependencyState::Unsatisfiable,
+            }
+        }
+        state
+    }
+
+    fn sort_by_creation(&self, ids: &mut [Uuid]) {
+        ids.sort_by_key(|id| (self.tasks[id].created_at, *id));
+    }
 }
 
 impl Default for Orchestrator {
@@ -304,6 +733,42 @@ mod tests {
         assert!(task.update_status(TaskStatus::Running).is_err());
     }
 
+    #[test]
+    fn test_task_retry_after_failure() {
+        let mut task = Task::new("flaky");
+        assert_eq!(task.attempt, 0);
+
+        task.update_status(TaskStatus::Running).unwrap();
+        task.update_status(TaskStatus::Failed).unwrap();
+        assert!(task.update_status(TaskStatus::Pending).is_err());
+
+        task.retry().unwrap();
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.attempt, 1);
+
+        task.update_status(TaskStatus::Running).unwrap();
+        task.update_status(TaskStatus::Failed).unwrap();
+        task.retry().unwrap();
+        assert_eq!(task.attempt, 2);
+    }
+
+    #[test]
+    fn test_task_retry_rejected_unless_failed() {
+        let mut task = Task::new("done");
+        assert!(task.retry().is_err());
+
+        task.update_status(TaskStatus::Running).unwrap();
+        assert!(task.retry().is_err());
+
+        task.update_status(TaskStatus::Completed).unwrap();
+        assert!(matches!(
+            task.retry(),
+            Err(OrchestratorError::InvalidStateTransition { .. })
+        ));
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!(task.attempt, 0);
+    }
+
     #[test]
     fn test_orchestrator_add_task() {
         let mut orchestrator = Orchestrator::new();
@@ -369,6 +834,190 @@ mod tests {
         assert!(!orchestrator.can_accept_task());
     }
 
+    #[test]
+    fn test_orchestrator_publishes_lifecycle_events() {
+        let mut orchestrator = Orchestrator::new();
+        let mut events = orchestrator.subscribe();
+
+        let id = orchestrator.add_task(Task::new("observed"));
+        orchestrator.update_status(&id, TaskStatus::Running).unwrap();
+        orchestrator.update_status(&id, TaskStatus::Completed).unwrap();
+        // Rejected transitions publish nothing
+        assert!(orchestrator.update_status(&id, TaskStatus::Running).is_err());
+        orchestrator.remove_task(&id).unwrap();
+
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            assert_eq!(event.task_id(), id);
+            received.push(event);
+        }
+
+        assert_eq!(received.len(), 4);
+        assert!(matches!(received[0], TaskEvent::Added { .. }));
+        assert!(matches!(
+            received[1],
+            TaskEvent::StatusChanged {
+                from: TaskStatus::Pending,
+                to: TaskStatus::Running,
+                ..
+            }
+        ));
+        assert!(matches!(
+            received[2],
+            TaskEvent::StatusChanged {
+                from: TaskStatus::Running,
+                to: TaskStatus::Completed,
+                ..
+            }
+        ));
+        assert!(matches!(received[3], TaskEvent::Removed { .. }));
+        assert!(received
+            .windows(2)
+            .all(|pair| pair[0].timestamp() <= pair[1].timestamp()));
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_publishes_status_changes() {
+        let mut orchestrator = Orchestrator::new();
+        let [a, ..] = diamond(&mut orchestrator);
+        let mut events = orchestrator.subscribe();
+
+        orchestrator
+            .execute_all(&RecordingExecutor::default())
+            .await
+            .unwrap();
+
+        let mut changes = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let TaskEvent::StatusChanged { task_id, to, .. } = event {
+                changes.push((task_id, to));
+            }
+        }
+        // Running and Completed for each of the four tasks, starting with a
+        assert_eq!(changes.len(), 8);
+        assert_eq!(changes[0], (a, TaskStatus::Running));
+        assert_eq!(changes[1], (a, TaskStatus::Completed));
+    }
+
+    /// Records execution order and peak concurrency; fails tasks named in `fail`.
+    #[derive(Default)]
+    struct RecordingExecutor {
+        finished: std::sync::Mutex<Vec<String>>,
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+        fail: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl TaskExecutor for RecordingExecutor {
+        async fn execute(&self, task: &Task) -> Result<()> {
+            use std::sync::atomic::Ordering;
+
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            self.finished.lock().unwrap().push(task.name.clone());
+            if self.fail.contains(&task.name.as_str()) {
+                return Err(OrchestratorError::General("boom".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    /// a -> {b, c} -> d
+    fn diamond(orchestrator: &mut Orchestrator) -> [Uuid; 4] {
+        let a = orchestrator.add_task(Task::new("a"));
+        let b = orchestrator.add_task(Task::new("b").with_dependency(a));
+        let c = orchestrator.add_task(Task::new("c").with_dependency(a));
+        let d = orchestrator.add_task(Task::new("d").with_dependency(b).with_dependency(c));
+        [a, b, c, d]
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_diamond() {
+        let mut orchestrator = Orchestrator::new();
+        let [a, b, c, d] = diamond(&mut orchestrator);
+
+        let order = orchestrator.execution_order().unwrap();
+        assert_eq!(order, vec![vec![a], vec![b, c], vec![d]]);
+
+        let executor = RecordingExecutor::default();
+        orchestrator.execute_all(&executor).await.unwrap();
+
+        let finished = executor.finished.lock().unwrap().clone();
+        assert_eq!(finished.first().map(String::as_str), Some("a"));
+        assert_eq!(finished.last().map(String::as_str), Some("d"));
+        assert_eq!(finished.len(), 4);
+        // b and c are independent and ran side by side
+        assert_eq!(executor.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(orchestrator.tasks_by_status(TaskStatus::Completed).count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_respects_concurrency_limit() {
+        let config = OrchestratorConfig::new().with_max_concurrent_tasks(1);
+        let mut orchestrator = Orchestrator::with_config(config);
+        diamond(&mut orchestrator);
+
+        let executor = RecordingExecutor::default();
+        orchestrator.execute_all(&executor).await.unwrap();
+
+        assert_eq!(executor.peak.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(executor.finished.lock().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_rejects_cycle_before_running() {
+        let mut orchestrator = Orchestrator::new();
+        let mut a = Task::new("a");
+        let b = Task::new("b").with_dependency(a.id);
+        a = a.with_dependency(b.id);
+        orchestrator.add_task(a);
+        orchestrator.add_task(b);
+        orchestrator.add_task(Task::new("independent"));
+
+        let executor = RecordingExecutor::default();
+        let err = orchestrator.execute_all(&executor).await.unwrap_err();
+
+        match err {
+            OrchestratorError::DependencyCycle { tasks } => assert_eq!(tasks, vec!["a", "b"]),
+            other => panic!("expected a cycle error, got {other}"),
+        }
+        assert!(executor.finished.lock().unwrap().is_empty());
+        assert_eq!(orchestrator.tasks_by_status(TaskStatus::Pending).count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_cancels_dependents_of_failed_task() {
+        let mut orchestrator = Orchestrator::new();
+        let [a, b, c, d] = diamond(&mut orchestrator);
+
+        let executor = RecordingExecutor {
+            fail: vec!["b"],
+            ..Default::default()
+        };
+        let err = orchestrator.execute_all(&executor).await.unwrap_err();
+
+        assert!(err.to_string().contains("Task 'b' failed"));
+        assert_eq!(orchestrator.get_task(&a).unwrap().status, TaskStatus::Completed);
+        assert_eq!(orchestrator.get_task(&b).unwrap().status, TaskStatus::Failed);
+        assert_eq!(orchestrator.get_task(&c).unwrap().status, TaskStatus::Completed);
+        assert_eq!(orchestrator.get_task(&d).unwrap().status, TaskStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_execution_order_unknown_dependency() {
+        let mut orchestrator = Orchestrator::new();
+        orchestrator.add_task(Task::new("orphan").with_dependency(Uuid::new_v4()));
+
+        assert!(matches!(
+            orchestrator.execution_order(),
+            Err(OrchestratorError::TaskNotFound(_))
+        ));
+    }
+
     #[test]
     fn test_version() {
         let v = version();
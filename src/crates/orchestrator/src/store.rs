@@ -0,0 +1,388 @@
+//! Pluggable persistence for orchestrator tasks
+//!
+//! An [`Orchestrator`](crate::Orchestrator) created with
+//! [`Orchestrator::with_store`](crate::Orchestrator::with_store) writes each
+//! task change through to a [`TaskStore`] as it happens, so tasks survive
+//! restarts and crashes. Two stores are provided:
+//!
+//! - [`InMemoryTaskStore`]: process-local, shareable between orchestrators
+//! - [`SqliteTaskStore`]: backed by the `tasks` table of the [`db`](crate::db) module
+
+use crate::db::models::Task as TaskRow;
+use crate::db::repositories::TaskRepository;
+use crate::db::DatabaseConnection;
+use crate::{OrchestratorError, Result, Task, TaskStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Storage backend for orchestrator tasks
+#[async_trait]
+pub trait TaskStore: Send + Sync {
+    /// Insert or replace a task
+    async fn save(&self, task: &Task) -> Result<()>;
+
+    /// Load a task by ID
+    async fn load(&self, id: &Uuid) -> Result<Option<Task>>;
+
+    /// List all tasks with the given status
+    async fn list_by_status(&self, status: TaskStatus) -> Result<Vec<Task>>;
+
+    /// Delete a task, returning whether it existed
+    async fn delete(&self, id: &Uuid) -> Result<bool>;
+
+    /// List every stored task
+    async fn list(&self) -> Result<Vec<Task>> {
+        let mut tasks = Vec::new();
+        for status in [
+            TaskStatus::Pending,
+            TaskStatus::Running,
+            TaskStatus::Completed,
+            TaskStatus::Failed,
+            TaskStatus::Cancelled,
+        ] {
+            tasks.extend(self.list_by_status(status).await?);
+        }
+        Ok(tasks)
+    }
+}
+
+/// Process-local task store
+///
+/// Clones share the same tasks, so one store can back several orchestrators.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTaskStore {
+    tasks: Arc<Mutex<HashMap<Uuid, Task>>>,
+}
+
+impl InMemoryTaskStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TaskStore for InMemoryTaskStore {
+    async fn save(&self, task: &Task) -> Result<()> {
+        self.tasks.lock().unwrap().insert(task.id, task.clone());
+        Ok(())
+    }
+
+    async fn load(&self, id: &Uuid) -> Result<Option<Task>> {
+        Ok(self.tasks.lock().unwrap().get(id).cloned())
+    }
+
+    async fn list_by_status(&self, status: TaskStatus) -> Result<Vec<Task>> {
+        Ok(self
+            .tasks
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|task| task.status == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool> {
+        Ok(self.tasks.lock().unwrap().remove(id).is_some())
+    }
+}
+
+/// Task type recorded for rows written by the orchestrator
+const TASK_TYPE: &str = "orchestrator";
+
+/// Orchestrator fields with no dedicated column, stored in `config` as JSON
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoredConfig {
+    #[serde(default)]
+    depends_on: Vec<Uuid>,
+    #[serde(default)]
+    attempt: u32,
+}
+
+/// Task store backed by the SQLite `tasks` table
+#[derive(Clone)]
+pub struct SqliteTaskStore {
+    db: DatabaseConnection,
+}
+
+impl SqliteTaskStore {
+    /// Use an existing connection whose migrations have already run
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Connect to `database_url` and run migrations
+    ///
+    /// # Arguments
+    /// * `database_url` - SQLite connection string (e.g., "sqlite:tasks.db?mode=rwc")
+    pub async fn open(database_url: &str) -> Result<Self> {
+        let db = DatabaseConnection::new(database_url)
+            .await
+            .map_err(storage_error)?;
+        db.run_migrations().await.map_err(storage_error)?;
+        Ok(Self::new(db))
+    }
+}
+
+#[async_trait]
+impl TaskStore for SqliteTaskStore {
+    async fn save(&self, task: &Task) -> Result<()> {
+        TaskRepository::upsert(self.db.pool(), &to_row(task)?)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn load(&self, id: &Uuid) -> Result<Option<Task>> {
+        TaskRepository::get_by_id(self.db.pool(), &id.to_string())
+            .await
+            .map_err(storage_error)?
+            .map(from_row)
+            .transpose()
+    }
+
+    async fn list_by_status(&self, status: TaskStatus) -> Result<Vec<Task>> {
+        TaskRepository::list_by_status(self.db.pool(), status_to_db(status))
+            .await
+            .map_err(storage_error)?
+            .into_iter()
+            .filter(|row| row.task_type == TASK_TYPE)
+            .map(from_row)
+            .collect()
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool> {
+        let id = id.to_string();
+        let existed = TaskRepository::get_by_id(self.db.pool(), &id)
+            .await
+            .map_err(storage_error)?
+            .is_some();
+        TaskRepository::delete(self.db.pool(), &id)
+            .await
+            .map_err(storage_error)?;
+        Ok(existed)
+    }
+}
+
+fn storage_error(err: impl std::fmt::Display) -> OrchestratorError {
+    OrchestratorError::Storage(err.to_string())
+}
+
+fn status_to_db(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Pending => "pending",
+        TaskStatus::Running => "running",
+        TaskStatus::Completed => "completed",
+        TaskStatus::Failed => "failed",
+        TaskStatus::Cancelled => "cancelled",
+    }
+}
+
+fn status_from_db(status: &str) -> Result<TaskStatus> {
+    match status {
+        "pending" => Ok(TaskStatus::Pending),
+        "running" => Ok(TaskStatus::Running),
+        "completed" => Ok(TaskStatus::Completed),
+        "failed" => Ok(TaskStatus::Failed),
+        "cancelled" => Ok(TaskStatus::Cancelled),
+        other => Err(storage_error(format!("unknown task status '{}'", other))),
+    }
+}
+
+/// Parse a stored timestamp, accepting SQLite's `datetime('now')` format
+/// written by the `updated_at` trigger as well as RFC 3339
+fn timestamp_from_db(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|ts| ts.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map(|ts| ts.and_utc())
+        })
+        .map_err(|err| storage_error(format!("invalid timestamp '{}': {}", value, err)))
+}
+
+fn to_row(task: &Task) -> Result<TaskRow> {
+    let config = StoredConfig {
+        depends_on: task.depends_on.clone(),
+        attempt: task.attempt,
+    };
+    Ok(TaskRow {
+        id: task.id.to_string(),
+        title: task.name.clone(),
+        description: task.description.clone(),
+        task_type: TASK_TYPE.to_string(),
+        status: status_to_db(task.status).to_string(),
+        config: Some(serde_json::to_string(&config)?),
+        metadata: Some(serde_json::to_string(&task.metadata)?),
+        workspace_path: None,
+        created_at: task.created_at.to_rfc3339(),
+        updated_at: task.updated_at.to_rfc3339(),
+        started_at: None,
+        completed_at: None,
+        error: None,
+    })
+}
+
+fn from_row(row: TaskRow) -> Result<Task> {
+    let id = Uuid::parse_str(&row.id)
+        .map_err(|err| storage_error(format!("invalid task id '{}': {}", row.id, err)))?;
+    let config: StoredConfig = match row.config.as_deref() {
+        Some(config) => serde_json::from_str(config)?,
+        None => StoredConfig::default(),
+    };
+    let metadata = match row.metadata.as_deref() {
+        Some(metadata) => serde_json::from_str(metadata)?,
+        None => HashMap::new(),
+    };
+    Ok(Task {
+        id,
+        name: row.title,
+        description: row.description,
+        status: status_from_db(&row.status)?,
+        created_at: timestamp_from_db(&row.created_at)?,
+        updated_at: timestamp_from_db(&row.updated_at)?,
+        metadata,
+        depends_on: config.depends_on,
+        attempt: config.attempt,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Orchestrator, OrchestratorConfig};
+
+    async fn round_trip(store: Arc<dyn TaskStore>) {
+        let first = Task::new("first").with_metadata("owner", "ops");
+        let second = Task::new("second")
+            .with_description("depends on first")
+            .with_dependency(first.id);
+        let (first_id, second_id) = (first.id, second.id);
+
+        {
+            let mut orchestrator =
+                Orchestrator::with_store(OrchestratorConfig::default(), store.clone())
+                    .await
+                    .unwrap();
+            orchestrator.add_task_persisted(first).await.unwrap();
+            // Each change reaches the store before the call returns
+            assert!(store.load(&first_id).await.unwrap().is_some());
+            orchestrator.add_task_persisted(second).await.unwrap();
+            orchestrator
+                .update_status_persisted(&first_id, TaskStatus::Running)
+                .await
+                .unwrap();
+            assert_eq!(
+                store.load(&first_id).await.unwrap().unwrap().status,
+                TaskStatus::Running
+            );
+            orchestrator
+                .get_task_mut(&second_id)
+                .unwrap()
+                .metadata
+                .insert("owner".to_string(), "qa".to_string());
+            orchestrator.persist_task(&second_id).await.unwrap();
+        }
+
+        // A fresh orchestrator sees the tasks the first one persisted
+        let mut orchestrator = Orchestrator::with_store(OrchestratorConfig::default(), store)
+            .await
+            .unwrap();
+        assert_eq!(orchestrator.tasks().count(), 2);
+
+        let first = orchestrator.get_task(&first_id).unwrap();
+        assert_eq!(first.status, TaskStatus::Running);
+        assert_eq!(first.metadata.get("owner"), Some(&"ops".to_string()));
+
+        let second = orchestrator.get_task(&second_id).unwrap();
+        assert_eq!(second.description.as_deref(), Some("depends on first"));
+        assert_eq!(second.depends_on, vec![first_id]);
+        assert_eq!(second.metadata.get("owner"), Some(&"qa".to_string()));
+
+        orchestrator.remove_task_persisted(&second_id).await.unwrap();
+        assert!(orchestrator
+            .store()
+            .unwrap()
+            .load(&second_id)
+            .await
+            .unwrap()
+            .is_none());
+        let orchestrator = Orchestrator::with_store(
+            OrchestratorConfig::default(),
+            orchestrator.store().unwrap().clone(),
+        )
+        .await
+        .unwrap();
+        assert!(orchestrator.get_task(&second_id).is_none());
+        assert_eq!(orchestrator.tasks().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_persists_across_orchestrators() {
+        round_trip(Arc::new(InMemoryTaskStore::new())).await;
+    }
+
+    #[tokio::test]
+    async fn test_get_task_persisted_falls_back_to_store() {
+        let store: Arc<dyn TaskStore> = Arc::new(InMemoryTaskStore::new());
+        let mut reader = Orchestrator::with_store(OrchestratorConfig::default(), store.clone())
+            .await
+            .unwrap();
+
+        // Saved by another orchestrator after the reader loaded the store
+        let mut writer = Orchestrator::with_store(OrchestratorConfig::default(), store)
+            .await
+            .unwrap();
+        let id = writer.add_task_persisted(Task::new("late")).await.unwrap();
+
+        assert!(reader.get_task(&id).is_none());
+        let task = reader.get_task_persisted(&id).await.unwrap().unwrap();
+        assert_eq!(task.name, "late");
+        // Cached in memory once loaded
+        assert!(reader.get_task(&id).is_some());
+        assert!(reader
+            .get_task_persisted(&Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_persists_across_orchestrators() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let url = format!("sqlite:{}?mode=rwc", dir.path().join("tasks.db").display());
+
+        round_trip(Arc::new(SqliteTaskStore::open(&url).await.unwrap())).await;
+
+        // Reopening the file finds the surviving task
+        let store = SqliteTaskStore::open(&url).await.unwrap();
+        let tasks = store.list().await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "first");
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_save_overwrites() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let url = format!("sqlite:{}?mode=rwc", dir.path().join("tasks.db").display());
+        let store = SqliteTaskStore::open(&url).await.unwrap();
+
+        let mut task = Task::new("flaky");
+        store.save(&task).await.unwrap();
+        task.update_status(TaskStatus::Running).unwrap();
+        task.update_status(TaskStatus::Failed).unwrap();
+        task.retry().unwrap();
+        store.save(&task).await.unwrap();
+
+        let loaded = store.load(&task.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, TaskStatus::Pending);
+        assert_eq!(loaded.attempt, 1);
+        assert!(store.list_by_status(TaskStatus::Failed).await.unwrap().is_empty());
+
+        assert!(store.delete(&task.id).await.unwrap());
+        assert!(!store.delete(&task.id).await.unwrap());
+    }
+}
@@ -1,14 +1,15 @@
 //! Async utilities for common async patterns
 //!
 //! This module provides utilities for working with async operations:
-//! - Retry policies with exponential backoff
-//! - Timeout wrappers and guards
+//! - Retry policies with exponential backoff (`with_retry`, and the typed
+//!   `retry_with_backoff` / `retry_with_backoff_if`)
+//! - Timeout wrappers (`with_timeout`, `with_deadline`) and guards
 //!
 //! # Example
 //!
 //! ```rust,ignore
-//! use tooling::async_utils::retry::{RetryPolicy, with_retry};
-//! use tooling::async_utils::timeout::{with_timeout, TimeoutGuard};
+//! use tooling::async_utils::retry::{retry_with_backoff_if, with_retry, AsyncRetryPolicy, RetryPolicy};
+//! use tooling::async_utils::timeout::{is_timeout_error, with_timeout, TimeoutError};
 //! use std::time::Duration;
 //!
 //! // Retry with exponential backoff
@@ -29,25 +30,24 @@
 //!         Duration::from_secs(30),
 //!         async {
 //!             // Slow operation
-//!             Ok("done".to_string())
+//!             "done".to_string()
 //!         }
 //!     ).await
 //!     .map_err(|e| e.to_string())
 //! }
 //!
-//! // Combined retry + timeout
-//! async fn robust_call() -> Result<String, String> {
-//!     let policy = RetryPolicy::new(3);
+//! // Combined retry + timeout, retrying only timeouts
+//! async fn robust_call() -> Result<String, TimeoutError> {
+//!     let policy = AsyncRetryPolicy::new(3);
 //!
-//!     with_retry(&policy, || async {
+//!     retry_with_backoff_if(&policy, |e: &TimeoutError| is_timeout_error(e), || {
 //!         with_timeout(
 //!             Duration::from_secs(10),
 //!             async {
 //!                 // API call with timeout
-//!                 Ok("success".to_string())
+//!                 "success".to_string()
 //!             }
-//!         ).await
-//!         .map_err(|e| e.to_string())
+//!         )
 //!     }).await
 //! }
 //! ```
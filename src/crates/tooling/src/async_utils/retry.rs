@@ -216,6 +216,163 @@ where
     Err(last_error.expect("Should have error after exhausting retries"))
 }
 
+/// Backoff configuration for [`retry_with_backoff`]
+///
+/// Unlike [`RetryPolicy`], delays are expressed as [`Duration`]s and the
+/// policy is paired with a typed retry predicate, so callers keep their own
+/// error type end to end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsyncRetryPolicy {
+    /// Maximum number of attempts (including the first)
+    pub max_attempts: usize,
+
+    /// Delay before the first retry
+    pub base_delay: Duration,
+
+    /// Multiplier applied to the delay after each retry
+    pub multiplier: f64,
+
+    /// Upper bound on the delay between attempts
+    pub max_delay: Duration,
+
+    /// Whether to randomize delays (0.5x to 1.5x) to avoid thundering herds
+    pub jitter: bool,
+}
+
+impl AsyncRetryPolicy {
+    /// Create a policy with the given max attempts and default backoff
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tooling::async_utils::retry::AsyncRetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = AsyncRetryPolicy::new(4).with_base_delay(Duration::from_millis(100));
+    /// assert_eq!(policy.max_attempts, 4);
+    /// ```
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the backoff multiplier
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the maximum delay between attempts
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Enable or disable jitter
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Delay to wait after the given failed attempt (0-indexed)
+    ///
+    /// `base_delay * multiplier ^ attempt`, capped at `max_delay`, with
+    /// optional jitter applied after the cap.
+    pub fn delay_for(&self, attempt: usize) -> Duration {
+        let base = self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = base.min(self.max_delay.as_secs_f64());
+
+        let delay = if self.jitter {
+            capped * rand::thread_rng().gen_range(0.5..=1.5)
+        } else {
+            capped
+        };
+
+        Duration::from_secs_f64(delay)
+    }
+}
+
+impl Default for AsyncRetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Retry an async operation with exponential backoff, retrying every error
+///
+/// Returns the first success, or the last error once `max_attempts` is
+/// exhausted. See [`retry_with_backoff_if`] to stop early on permanent errors.
+pub async fn retry_with_backoff<F, Fut, T, E>(
+    policy: &AsyncRetryPolicy,
+    operation: F,
+) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, E>>,
+{
+    retry_with_backoff_if(policy, |_: &E| true, operation).await
+}
+
+/// Retry an async operation with exponential backoff while `is_retryable`
+/// accepts the error
+///
+/// An error rejected by `is_retryable` is returned immediately without
+/// further attempts.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use tooling::async_utils::retry::{retry_with_backoff_if, AsyncRetryPolicy};
+///
+/// let policy = AsyncRetryPolicy::new(3);
+/// let body = retry_with_backoff_if(
+///     &policy,
+///     |e: &HttpError| e.is_transient(),
+///     || client.get(url),
+/// )
+/// .await?;
+/// ```
+pub async fn retry_with_backoff_if<F, Fut, T, E, P>(
+    policy: &AsyncRetryPolicy,
+    mut is_retryable: P,
+    mut operation: F,
+) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, E>>,
+    P: FnMut(&E) -> bool,
+{
+    let mut attempt = 0;
+
+    loop {
+        match operation().await {
+            Ok(result) => return Ok(result),
+            Err(error) => {
+                attempt += 1;
+
+                if attempt >= policy.max_attempts || !is_retryable(&error) {
+                    return Err(error);
+                }
+
+                let delay = policy.delay_for(attempt - 1);
+                tracing::debug!("Attempt {} failed, retrying in {:?}", attempt, delay);
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
 /// Check if an error message indicates a transient error that should be retried
 ///
 /// Recognizes common transient error patterns:
@@ -561,4 +718,89 @@ mod tests {
         assert_eq!(result.unwrap_err(), "persistent error");
         assert_eq!(call_count.load(Ordering::SeqCst), 3);
     }
+
+    #[test]
+    fn test_async_retry_policy_delay() {
+        let policy = AsyncRetryPolicy::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_multiplier(2.0)
+            .with_max_delay(Duration::from_millis(300))
+            .with_jitter(false);
+
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(300)); // capped
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_with_backoff_success_after_two_failures() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let policy = AsyncRetryPolicy::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_jitter(false);
+        let calls = AtomicUsize::new(0);
+        let start = tokio::time::Instant::now();
+
+        let result = retry_with_backoff(&policy, || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call < 2 {
+                    Err(format!("failure {}", call))
+                } else {
+                    Ok(call)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        // Slept 100ms then 200ms
+        assert_eq!(start.elapsed(), Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_with_backoff_non_retryable_fails_immediately() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, PartialEq)]
+        enum ApiError {
+            Transient,
+            NotFound,
+        }
+
+        let policy = AsyncRetryPolicy::new(5).with_jitter(false);
+        let calls = AtomicUsize::new(0);
+
+        let result: Result<(), ApiError> = retry_with_backoff_if(
+            &policy,
+            |e| *e == ApiError::Transient,
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(ApiError::NotFound) }
+            },
+        )
+        .await;
+
+        assert_eq!(result, Err(ApiError::NotFound));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_with_backoff_returns_last_error() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let policy = AsyncRetryPolicy::new(3).with_jitter(false);
+        let calls = AtomicUsize::new(0);
+
+        let result: Result<(), usize> = retry_with_backoff(&policy, || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move { Err(call) }
+        })
+        .await;
+
+        assert_eq!(result, Err(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
 }
@@ -4,18 +4,12 @@
 
 use std::future::Future;
 use std::time::Duration;
-use tokio::time::timeout as tokio_timeout;
+use tokio::time::{timeout as tokio_timeout, Instant};
 
-/// Execute an async operation with a timeout
+/// Run a future with a time limit
 ///
-/// # Arguments
-///
-/// * `duration` - Maximum duration to wait
-/// * `operation` - Async operation to execute
-///
-/// # Returns
-///
-/// Result of the operation, or error if timeout exceeded
+/// Returns the future's output unchanged, or [`TimeoutError`] if it does not
+/// complete within `duration`. The future is dropped on timeout.
 ///
 /// # Example
 ///
@@ -23,57 +17,93 @@ use tokio::time::timeout as tokio_timeout;
 /// use tooling::async_utils::timeout::with_timeout;
 /// use std::time::Duration;
 ///
-/// async fn slow_operation() -> Result<String, String> {
+/// async fn slow_operation() -> String {
 ///     tokio::time::sleep(Duration::from_secs(10)).await;
-///     Ok("done".to_string())
+///     "done".to_string()
 /// }
 ///
-/// let result = with_timeout(
-///     Duration::from_secs(1),
-///     slow_operation()
-/// ).await;
+/// let result = with_timeout(Duration::from_secs(1), slow_operation()).await;
 ///
 /// assert!(result.is_err()); // Timeout
 /// ```
-pub async fn with_timeout<F, T, E>(
+pub async fn with_timeout<F>(
     duration: Duration,
     operation: F,
-) -> std::result::Result<T, TimeoutError<E>>
+) -> std::result::Result<F::Output, TimeoutError>
 where
-    F: Future<Output = std::result::Result<T, E>>,
+    F: Future,
 {
-    match tokio_timeout(duration, operation).await {
-        Ok(Ok(result)) => Ok(result),
-        Ok(Err(error)) => Err(TimeoutError::OperationFailed(error)),
-        Err(_elapsed) => Err(TimeoutError::Timeout(duration)),
-    }
+    tokio_timeout(duration, operation)
+        .await
+        .map_err(|_elapsed| TimeoutError::new(duration))
 }
 
-/// Error type for timeout operations
-#[derive(Debug)]
-pub enum TimeoutError<E> {
-    /// Operation completed but failed
-    OperationFailed(E),
-    /// Operation timed out
-    Timeout(Duration),
+/// Run a future that must complete before `deadline`
+///
+/// Like [`with_timeout`], but for callers sharing one deadline across several
+/// steps. The reported duration is the time that remained when the call was
+/// made.
+pub async fn with_deadline<F>(
+    deadline: Instant,
+    operation: F,
+) -> std::result::Result<F::Output, TimeoutError>
+where
+    F: Future,
+{
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    tokio::time::timeout_at(deadline, operation)
+        .await
+        .map_err(|_elapsed| TimeoutError::new(remaining))
 }
 
-impl<E: std::fmt::Display> std::fmt::Display for TimeoutError<E> {
+/// Error returned when an operation exceeds its time limit
+///
+/// A distinct type so retry predicates can recognize timeouts, either
+/// directly or anywhere in an error's source chain via [`is_timeout_error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError {
+    /// The time limit that was exceeded
+    pub duration: Duration,
+}
+
+impl TimeoutError {
+    /// Create a timeout error for the given limit
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+impl std::fmt::Display for TimeoutError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TimeoutError::OperationFailed(e) => write!(f, "Operation failed: {}", e),
-            TimeoutError::Timeout(d) => write!(f, "Operation timed out after {:?}", d),
-        }
+        write!(f, "Operation timed out after {:?}", self.duration)
     }
 }
 
-impl<E: std::error::Error + 'static> std::error::Error for TimeoutError<E> {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            TimeoutError::OperationFailed(e) => Some(e),
-            TimeoutError::Timeout(_) => None,
+impl std::error::Error for TimeoutError {}
+
+/// Check whether an error, or any error in its source chain, is a
+/// [`TimeoutError`]
+///
+/// Suitable as (part of) the predicate passed to
+/// [`retry_with_backoff_if`](super::retry::retry_with_backoff_if).
+pub fn is_timeout_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(error);
+    while let Some(err) = current {
+        if err.is::<TimeoutError>() {
+            return true;
+        }
+        // `io::Error::source` skips the error it wraps, so look inside it too
+        let wrapped = err
+            .downcast_ref::<std::io::Error>()
+            .and_then(|io| io.get_ref());
+        if let Some(inner) = wrapped {
+            if is_timeout_error(inner) {
+                return true;
+            }
         }
+        current = err.source();
     }
+    false
 }
 
 /// RAII timeout guard that ensures an operation completes within a duration
@@ -101,7 +131,7 @@ impl<E: std::error::Error + 'static> std::error::Error for TimeoutError<E> {
 /// }
 /// ```
 pub struct TimeoutGuard {
-    deadline: tokio::time::Instant,
+    deadline: Instant,
     duration: Duration,
 }
 
@@ -109,21 +139,21 @@ impl TimeoutGuard {
     /// Create a new timeout guard with the specified duration
     pub fn new(duration: Duration) -> Self {
         Self {
-            deadline: tokio::time::Instant::now() + duration,
+            deadline: Instant::now() + duration,
             duration,
         }
     }
 
     /// Check if the timeout has been exceeded
     pub fn is_expired(&self) -> bool {
-        tokio::time::Instant::now() >= self.deadline
+        Instant::now() >= self.deadline
     }
 
     /// Get the remaining time until timeout
     ///
     /// Returns None if timeout has already expired
     pub fn remaining(&self) -> Option<Duration> {
-        let now = tokio::time::Instant::now();
+        let now = Instant::now();
         if now >= self.deadline {
             None
         } else {
@@ -153,18 +183,17 @@ impl TimeoutGuard {
     ///
     /// # Returns
     ///
-    /// Result of the operation, or timeout error if remaining time exceeded
-    pub async fn execute<F, T, E>(
-        &self,
-        operation: F,
-    ) -> std::result::Result<T, TimeoutError<E>>
+    /// Output of the operation, or timeout error if remaining time exceeded
+    pub async fn execute<F>(&self, operation: F) -> std::result::Result<F::Output, TimeoutError>
     where
-        F: Future<Output = std::result::Result<T, E>>,
+        F: Future,
     {
-        match self.remaining() {
-            Some(remaining) => with_timeout(remaining, operation).await,
-            None => Err(TimeoutError::Timeout(self.duration)),
+        if self.is_expired() {
+            return Err(TimeoutError::new(self.duration));
         }
+        with_deadline(self.deadline, operation)
+            .await
+            .map_err(|_| TimeoutError::new(self.duration))
     }
 }
 
@@ -180,41 +209,81 @@ mod tests {
         })
         .await;
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "success");
+        assert_eq!(result, Ok(Ok("success")));
     }
 
     #[tokio::test]
     async fn test_with_timeout_exceeded() {
         let result = with_timeout(Duration::from_millis(10), async {
             tokio::time::sleep(Duration::from_millis(100)).await;
-            Ok::<_, String>("should not reach here")
+            "should not reach here"
         })
         .await;
 
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            TimeoutError::Timeout(d) => {
-                assert_eq!(d, Duration::from_millis(10));
-            }
-            _ => panic!("Expected timeout error"),
-        }
+        assert_eq!(result, Err(TimeoutError::new(Duration::from_millis(10))));
     }
 
     #[tokio::test]
-    async fn test_with_timeout_operation_fails() {
+    async fn test_with_timeout_passes_through_operation_error() {
         let result = with_timeout(Duration::from_millis(100), async {
             Err::<String, _>("operation error")
         })
         .await;
 
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            TimeoutError::OperationFailed(e) => {
-                assert_eq!(e, "operation error");
-            }
-            _ => panic!("Expected operation failed error"),
-        }
+        assert_eq!(result, Ok(Err("operation error")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_deadline() {
+        let deadline = Instant::now() + Duration::from_millis(50);
+
+        let fast = with_deadline(deadline, async { 1 }).await;
+        assert_eq!(fast, Ok(1));
+
+        let slow = with_deadline(deadline, tokio::time::sleep(Duration::from_secs(1))).await;
+        assert_eq!(slow, Err(TimeoutError::new(Duration::from_millis(50))));
+    }
+
+    #[test]
+    fn test_is_timeout_error() {
+        let timeout = TimeoutError::new(Duration::from_secs(1));
+        assert!(is_timeout_error(&timeout));
+
+        let io = std::io::Error::new(std::io::ErrorKind::Other, timeout);
+        assert!(is_timeout_error(&io));
+
+        let other = std::io::Error::new(std::io::ErrorKind::Other, "boom");
+        assert!(!is_timeout_error(&other));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_inside_retry() {
+        use crate::async_utils::retry::{retry_with_backoff_if, AsyncRetryPolicy};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let policy = AsyncRetryPolicy::new(3)
+            .with_base_delay(Duration::from_millis(10))
+            .with_jitter(false);
+        let calls = AtomicUsize::new(0);
+
+        // First attempt hangs past its timeout, second completes quickly
+        let result = retry_with_backoff_if(
+            &policy,
+            |e: &TimeoutError| is_timeout_error(e),
+            || {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                with_timeout(Duration::from_millis(50), async move {
+                    if call == 0 {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                    }
+                    call
+                })
+            },
+        )
+        .await;
+
+        assert_eq!(result, Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 
     #[tokio::test]
@@ -256,8 +325,7 @@ mod tests {
             })
             .await;
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "success");
+        assert_eq!(result, Ok(Ok("success")));
     }
 
     #[tokio::test]
@@ -272,7 +340,7 @@ mod tests {
             .await;
 
         assert!(result.is_err());
-        matches!(result.unwrap_err(), TimeoutError::Timeout(_));
+        assert_eq!(result.unwrap_err(), TimeoutError::new(guard.duration()));
     }
 
     #[tokio::test]
@@ -285,7 +353,7 @@ mod tests {
             .await;
 
         assert!(result.is_err());
-        matches!(result.unwrap_err(), TimeoutError::Timeout(_));
+        assert_eq!(result.unwrap_err(), TimeoutError::new(guard.duration()));
     }
 
     #[tokio::test]
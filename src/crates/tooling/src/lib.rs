@@ -10,7 +10,7 @@
 //! - `async_utils` - Retry policies and timeout utilities for async operations
 //! - `validation` - Fluent validation API for type-safe data validation
 //! - `serialization` - Stable JSON serialization and hashing utilities
-//! - `rate_limit` - Token bucket and sliding window rate limiters
+//! - `rate_limit` - Token bucket, per-key and sliding window rate limiters
 //! - `logging` - Structured logging helpers and formatters
 //! - `runtime` - Tool request/response types for runtime execution
 
@@ -2,6 +2,8 @@
 //!
 //! Provides helper functions and macros for structured logging with tracing.
 
+use serde_json::Value;
+use std::fmt;
 use std::time::Instant;
 use tracing::{debug, error, info, warn};
 
@@ -69,6 +71,45 @@ pub enum LogLevel {
     Error,
 }
 
+/// Key/value fields attached to a [`LogGuard`]
+///
+/// Recorded on the guard's events as one `fields` field holding a JSON object.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct LogFields(Vec<(String, String)>);
+
+impl LogFields {
+    /// Whether there are no fields
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Look up a field value by key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Fields as a JSON object; a repeated key keeps its last value
+    pub fn to_json(&self) -> Value {
+        Value::Object(
+            self.0
+                .iter()
+                .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Debug for LogFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
 /// RAII guard for logging function entry and exit
 ///
 /// Automatically logs when entering and exiting a scope.
@@ -83,10 +124,19 @@ pub enum LogLevel {
 ///     // Function logic here
 ///     // Guard will log exit when dropped
 /// }
+///
+/// fn handle_request(request_id: &str) {
+///     // Logs `name`, `fields` ({"request_id": ...}) and, on exit, `elapsed_ms`
+///     let _guard = LogGuard::with_fields(
+///         "handle_request",
+///         vec![("request_id", request_id.to_string())],
+///     );
+/// }
 /// ```
 pub struct LogGuard {
     name: String,
     start: Instant,
+    fields: LogFields,
 }
 
 impl LogGuard {
@@ -98,9 +148,49 @@ impl LogGuard {
         Self {
             name,
             start: Instant::now(),
+            fields: LogFields::default(),
         }
     }
 
+    /// Create a log guard carrying correlation fields (request id, session, ...)
+    ///
+    /// The fields are recorded as a JSON object in a single `fields` field on
+    /// entry, and again on exit alongside the elapsed time.
+    pub fn with_fields(name: impl Into<String>, fields: Vec<(&str, String)>) -> Self {
+        let guard = Self {
+            name: name.into(),
+            start: Instant::now(),
+            fields: LogFields(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+            ),
+        };
+        guard.log_with_fields(format!("Entering: {}", guard.name), None);
+        guard
+    }
+
+    /// Log `message` with the guard's name and its fields as JSON
+    fn log_with_fields(&self, message: String, elapsed_ms: Option<u64>) {
+        let fields = self.fields.to_json();
+        match elapsed_ms {
+            Some(elapsed_ms) => debug!(
+                name = %self.name,
+                fields = %fields,
+                elapsed_ms,
+                "{}",
+                message
+            ),
+            None => debug!(name = %self.name, fields = %fields, "{}", message),
+        }
+    }
+
+    /// Fields attached to this guard
+    pub fn fields(&self) -> &LogFields {
+        &self.fields
+    }
+
     /// Create a log guard with custom level
     pub fn with_level(name: impl Into<String>, level: LogLevel) -> Self {
         let name = name.into();
@@ -115,6 +205,7 @@ impl LogGuard {
         Self {
             name,
             start: Instant::now(),
+            fields: LogFields::default(),
         }
     }
 
@@ -127,7 +218,15 @@ impl LogGuard {
 impl Drop for LogGuard {
     fn drop(&mut self) {
         let elapsed = self.start.elapsed();
-        debug!("Exiting: {} (elapsed: {:?})", self.name, elapsed);
+
+        if self.fields.is_empty() {
+            debug!("Exiting: {} (elapsed: {:?})", self.name, elapsed);
+        } else {
+            self.log_with_fields(
+                format!("Exiting: {}", self.name),
+                Some(elapsed.as_millis() as u64),
+            );
+        }
     }
 }
 
@@ -224,6 +323,62 @@ pub fn sanitize_for_logging(input: &str) -> String {
     result
 }
 
+/// Keys commonly holding secrets, for use with [`sanitize_json`]
+pub const DEFAULT_SENSITIVE_KEYS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "password",
+    "passwd",
+    "token",
+    "access_token",
+    "refresh_token",
+    "secret",
+    "authorization",
+];
+
+/// Sanitize a JSON value for logging by redacting sensitive keys
+///
+/// Recursively walks objects and arrays and replaces the value of every key
+/// in `sensitive_keys` (compared case-insensitively) with `"[REDACTED]"`,
+/// whatever its type or nesting depth.
+///
+/// # Example
+///
+/// ```rust
+/// use tooling::logging::sanitize_json;
+/// use serde_json::json;
+///
+/// let value = json!({"user": "ada", "auth": {"api_key": "sk-abc123"}});
+/// let sanitized = sanitize_json(&value, &["api_key"]);
+/// assert_eq!(sanitized, json!({"user": "ada", "auth": {"api_key": "[REDACTED]"}}));
+/// ```
+pub fn sanitize_json(value: &Value, sensitive_keys: &[&str]) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| {
+                    let sensitive = sensitive_keys
+                        .iter()
+                        .any(|candidate| candidate.eq_ignore_ascii_case(key));
+                    let item = if sensitive {
+                        Value::String("[REDACTED]".to_string())
+                    } else {
+                        sanitize_json(item, sensitive_keys)
+                    };
+                    (key.clone(), item)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| sanitize_json(item, sensitive_keys))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +465,60 @@ mod tests {
         assert_eq!(input, sanitized);
     }
 
+    #[test]
+    fn test_sanitize_json_nested_objects() {
+        let value = serde_json::json!({
+            "provider": "openai",
+            "credentials": {
+                "API_KEY": "sk-abc123",
+                "oauth": {"token": {"value": "xyz789", "expires": 3600}}
+            },
+            "password": 12345
+        });
+
+        let sanitized = sanitize_json(&value, &["api_key", "token", "password"]);
+        assert_eq!(
+            sanitized,
+            serde_json::json!({
+                "provider": "openai",
+                "credentials": {
+                    "API_KEY": "[REDACTED]",
+                    "oauth": {"token": "[REDACTED]"}
+                },
+                "password": "[REDACTED]"
+            })
+        );
+    }
+
+    #[test]
+    fn test_sanitize_json_arrays_of_objects() {
+        let value = serde_json::json!({
+            "accounts": [
+                {"name": "alice", "secret": "s1"},
+                {"name": "bob", "nested": [{"secret": "s2"}]},
+                "plain string with secret: s3"
+            ]
+        });
+
+        let sanitized = sanitize_json(&value, DEFAULT_SENSITIVE_KEYS);
+        assert_eq!(
+            sanitized,
+            serde_json::json!({
+                "accounts": [
+                    {"name": "alice", "secret": "[REDACTED]"},
+                    {"name": "bob", "nested": [{"secret": "[REDACTED]"}]},
+                    "plain string with secret: s3"
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_sanitize_json_preserves_safe_data() {
+        let value = serde_json::json!({"user": "john@example.com", "tags": ["a", "b"], "count": 2});
+        assert_eq!(sanitize_json(&value, DEFAULT_SENSITIVE_KEYS), value);
+    }
+
     #[test]
     fn test_log_guard() {
         let _guard = LogGuard::new("test_function");
@@ -323,6 +532,110 @@ mod tests {
         assert!(guard.elapsed() >= Duration::from_millis(10));
     }
 
+    /// Subscriber that records the fields of every event
+    struct CaptureSubscriber {
+        events: std::sync::Arc<std::sync::Mutex<Vec<std::collections::HashMap<String, String>>>>,
+    }
+
+    struct FieldVisitor(std::collections::HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl tracing::Subscriber for CaptureSubscriber {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut visitor = FieldVisitor(std::collections::HashMap::new());
+            event.record(&mut visitor);
+            self.events.lock().unwrap().push(visitor.0);
+        }
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn test_log_guard_with_fields_records_structured_fields() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber = CaptureSubscriber {
+            events: events.clone(),
+        };
+
+        tracing::subscriber::with_default(subscriber, || {
+            let guard = LogGuard::with_fields(
+                "handle_request",
+                vec![
+                    ("request_id", "req-42".to_string()),
+                    ("session", "sess-7".to_string()),
+                ],
+            );
+            assert_eq!(guard.fields().get("request_id"), Some("req-42"));
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+
+        let (enter, exit) = (&events[0], &events[1]);
+        assert_eq!(enter["message"], "Entering: handle_request");
+        assert_eq!(enter["name"], "handle_request");
+        let fields: Value = serde_json::from_str(&enter["fields"]).unwrap();
+        assert_eq!(
+            fields,
+            serde_json::json!({"request_id": "req-42", "session": "sess-7"})
+        );
+        assert!(!enter.contains_key("elapsed_ms"));
+
+        assert_eq!(exit["message"], "Exiting: handle_request");
+        assert_eq!(exit["fields"], enter["fields"]);
+        assert!(exit["elapsed_ms"].parse::<u64>().is_ok());
+    }
+
+    #[test]
+    fn test_log_guard_with_fields_keeps_reserved_and_many_keys() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber = CaptureSubscriber {
+            events: events.clone(),
+        };
+
+        let mut fields = vec![
+            ("name", "shadow".to_string()),
+            ("message", "shadow".to_string()),
+        ];
+        let keys: Vec<String> = (0..40).map(|i| format!("key{}", i)).collect();
+        fields.extend(keys.iter().map(|key| (key.as_str(), "v".to_string())));
+        tracing::subscriber::with_default(subscriber, || {
+            LogGuard::with_fields("handle_request", fields);
+        });
+
+        let events = events.lock().unwrap();
+        let enter = &events[0];
+        // Caller keys never collide with the guard's own fields
+        assert_eq!(enter["name"], "handle_request");
+        assert_eq!(enter["message"], "Entering: handle_request");
+        let fields: Value = serde_json::from_str(&enter["fields"]).unwrap();
+        assert_eq!(fields["name"], "shadow");
+        assert_eq!(fields.as_object().unwrap().len(), 42);
+    }
+
     #[tokio::test]
     async fn test_timed() {
         let result = timed("test_operation", async { 42 }).await;
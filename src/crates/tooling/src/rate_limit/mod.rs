@@ -2,9 +2,14 @@
 //!
 //! Provides simple rate limiting for controlling operation frequency.
 
+use crate::{Result, ToolingError};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use tokio::sync::Mutex;
+// Same as `std::time::Instant` at runtime, but follows tokio's paused test clock
+use tokio::time::Instant;
 
 /// Simple token bucket rate limiter
 ///
@@ -80,11 +85,23 @@ impl RateLimiter {
     ///
     /// `true` if operation is allowed, `false` if rate limited
     pub async fn check(&self) -> bool {
+        self.try_acquire_n(1).await
+    }
+
+    /// Atomically take `n` tokens if they are all available (non-blocking)
+    ///
+    /// Either all `n` tokens are consumed or none are.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tokens were taken, `false` if fewer than `n` are available
+    pub async fn try_acquire_n(&self, n: usize) -> bool {
         let mut state = self.state.lock().await;
         state.refill();
 
-        if state.tokens >= 1.0 {
-            state.tokens -= 1.0;
+        let needed = n as f64;
+        if state.tokens >= needed {
+            state.tokens -= needed;
             true
         } else {
             false
@@ -105,6 +122,31 @@ impl RateLimiter {
         }
     }
 
+    /// Wait until `n` tokens can be taken at once (blocking)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error immediately if `n` exceeds the limiter's capacity,
+    /// since such a batch could never be satisfied.
+    pub async fn acquire_n(&self, n: usize) -> Result<()> {
+        let capacity = self.state.lock().await.capacity;
+        if n > capacity {
+            return Err(ToolingError::General(format!(
+                "Cannot acquire {} tokens from a rate limiter with capacity {}",
+                n, capacity
+            )));
+        }
+
+        loop {
+            if self.try_acquire_n(n).await {
+                return Ok(());
+            }
+
+            // Sleep for a short duration before checking again
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
     /// Check remaining capacity
     ///
     /// # Returns
@@ -147,9 +189,132 @@ impl RateLimiterState {
     }
 }
 
+/// Rate limiter with an independent token bucket per key
+///
+/// Limiters are created lazily the first time a key is seen, all sharing
+/// the same capacity and period. Keys that have not been used for the idle
+/// TTL are evicted so the map does not grow without bound.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use tooling::rate_limit::KeyedRateLimiter;
+/// use std::time::Duration;
+///
+/// // 10 requests per second per API key, forget keys idle for 10 minutes
+/// let limiter = KeyedRateLimiter::new(10, Duration::from_secs(1), Duration::from_secs(600));
+///
+/// if limiter.check(&api_key).await {
+///     // Operation allowed for this key
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyedRateLimiter<K> {
+    max_operations: usize,
+    period: Duration,
+    idle_ttl: Duration,
+    state: Arc<Mutex<KeyedState<K>>>,
+}
+
+struct KeyedState<K> {
+    /// Limiter and last use time per key
+    limiters: HashMap<K, (RateLimiter, Instant)>,
+
+    /// Last time idle keys were evicted
+    last_sweep: Instant,
+}
+
+impl<K: Hash + Eq + Clone> KeyedRateLimiter<K> {
+    /// Create a new keyed rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `max_operations` - Maximum number of operations allowed per key
+    /// * `period` - Time period for the limit
+    /// * `idle_ttl` - How long an unused key is kept before eviction
+    pub fn new(max_operations: usize, period: Duration, idle_ttl: Duration) -> Self {
+        Self {
+            max_operations,
+            period,
+            idle_ttl,
+            state: Arc::new(Mutex::new(KeyedState {
+                limiters: HashMap::new(),
+                last_sweep: Instant::now(),
+            })),
+        }
+    }
+
+    /// Check if an operation is allowed for `key` (non-blocking)
+    ///
+    /// # Returns
+    ///
+    /// `true` if operation is allowed, `false` if rate limited
+    pub async fn check(&self, key: &K) -> bool {
+        self.limiter_for(key).await.check().await
+    }
+
+    /// Wait until an operation is allowed for `key` (blocking)
+    pub async fn acquire(&self, key: &K) {
+        self.limiter_for(key).await.acquire().await
+    }
+
+    /// Check remaining capacity for `key`
+    ///
+    /// Keys that have not been seen yet report full capacity.
+    pub async fn available(&self, key: &K) -> usize {
+        let state = self.state.lock().await;
+        match state.limiters.get(key) {
+            Some((limiter, _)) => limiter.available().await,
+            None => self.max_operations,
+        }
+    }
+
+    /// Number of keys currently tracked
+    pub async fn len(&self) -> usize {
+        self.state.lock().await.limiters.len()
+    }
+
+    /// Whether no keys are currently tracked
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+
+    /// Evict every key that has been idle for longer than the TTL
+    pub async fn evict_idle(&self) {
+        let mut state = self.state.lock().await;
+        Self::sweep(&mut state, self.idle_ttl, Instant::now());
+    }
+
+    /// Get or create the limiter for `key`, marking it as used
+    async fn limiter_for(&self, key: &K) -> RateLimiter {
+        let mut state = self.state.lock().await;
+        let now = Instant::now();
+
+        if now.duration_since(state.last_sweep) >= self.idle_ttl {
+            Self::sweep(&mut state, self.idle_ttl, now);
+        }
+
+        let entry = state
+            .limiters
+            .entry(key.clone())
+            .or_insert_with(|| (RateLimiter::new(self.max_operations, self.period), now));
+        entry.1 = now;
+        entry.0.clone()
+    }
+
+    fn sweep(state: &mut KeyedState<K>, idle_ttl: Duration, now: Instant) {
+        state
+            .limiters
+            .retain(|_, (_, last_used)| now.duration_since(*last_used) < idle_ttl);
+        state.last_sweep = now;
+    }
+}
+
 /// Sliding window rate limiter
 ///
-/// Tracks operations in a sliding time window.
+/// Tracks operations in a sliding time window. Timestamps are kept in a ring
+/// buffer allocated once with room for `max_operations`, so memory stays
+/// bounded regardless of request rate.
 ///
 /// # Example
 ///
@@ -176,8 +341,8 @@ struct SlidingWindowState {
     /// Window duration
     window: Duration,
 
-    /// Timestamps of recent operations
-    operations: Vec<Instant>,
+    /// Timestamps of recent operations, oldest first
+    operations: VecDeque<Instant>,
 }
 
 impl SlidingWindowLimiter {
@@ -192,7 +357,7 @@ impl SlidingWindowLimiter {
             state: Arc::new(Mutex::new(SlidingWindowState {
                 max_operations,
                 window,
-                operations: Vec::new(),
+                operations: VecDeque::with_capacity(max_operations),
             })),
         }
     }
@@ -205,15 +370,11 @@ impl SlidingWindowLimiter {
     pub async fn check(&self) -> bool {
         let mut state = self.state.lock().await;
         let now = Instant::now();
-        let window = state.window;
-
-        // Remove operations outside the window
-        state
-            .operations
-            .retain(|&time| now.duration_since(time) < window);
+        state.expire(now);
 
+        // Never grow past the preallocated capacity
         if state.operations.len() < state.max_operations {
-            state.operations.push(now);
+            state.operations.push_back(now);
             true
         } else {
             false
@@ -223,12 +384,7 @@ impl SlidingWindowLimiter {
     /// Get count of operations in current window
     pub async fn count(&self) -> usize {
         let mut state = self.state.lock().await;
-        let now = Instant::now();
-        let window = state.window;
-
-        state
-            .operations
-            .retain(|&time| now.duration_since(time) < window);
+        state.expire(Instant::now());
         state.operations.len()
     }
 
@@ -239,6 +395,18 @@ impl SlidingWindowLimiter {
     }
 }
 
+impl SlidingWindowState {
+    /// Drop operations that have left the window
+    fn expire(&mut self, now: Instant) {
+        while let Some(&oldest) = self.operations.front() {
+            if now.duration_since(oldest) < self.window {
+                break;
+            }
+            self.operations.pop_front();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,6 +526,111 @@ mod tests {
         assert!(elapsed >= Duration::from_millis(40));
     }
 
+    #[tokio::test]
+    async fn test_try_acquire_n_within_capacity() {
+        let limiter = RateLimiter::new(10, Duration::from_secs(1));
+
+        assert!(limiter.try_acquire_n(4).await);
+        assert_eq!(limiter.available().await, 6);
+
+        // Not enough left for another batch of 7; nothing is consumed
+        assert!(!limiter.try_acquire_n(7).await);
+        assert_eq!(limiter.available().await, 6);
+
+        assert!(limiter.try_acquire_n(6).await);
+        assert_eq!(limiter.available().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_try_acquire_n_larger_than_capacity() {
+        let limiter = RateLimiter::new(5, Duration::from_millis(10));
+
+        assert!(!limiter.try_acquire_n(6).await);
+
+        // Still denied after a full refill
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!limiter.try_acquire_n(6).await);
+        assert_eq!(limiter.available().await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_n_larger_than_capacity_errors() {
+        let limiter = RateLimiter::new(5, Duration::from_secs(1));
+
+        assert!(limiter.acquire_n(6).await.is_err());
+        assert_eq!(limiter.available().await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_n_waits_for_refill() {
+        let limiter = RateLimiter::new(4, Duration::from_millis(100));
+        assert!(limiter.try_acquire_n(4).await);
+
+        let start = Instant::now();
+        limiter.acquire_n(2).await.unwrap();
+        let elapsed = start.elapsed();
+
+        // Two of four tokens take half the refill period
+        assert!(elapsed >= Duration::from_millis(40), "waited {:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_keyed_limiter_independent_budgets() {
+        let limiter = KeyedRateLimiter::new(2, Duration::from_secs(1), Duration::from_secs(60));
+
+        assert!(limiter.check(&"alice").await);
+        assert!(limiter.check(&"alice").await);
+        assert!(!limiter.check(&"alice").await);
+
+        // Bob's budget is untouched by Alice's usage
+        assert_eq!(limiter.available(&"bob").await, 2);
+        assert!(limiter.check(&"bob").await);
+        assert_eq!(limiter.available(&"bob").await, 1);
+        assert_eq!(limiter.len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_keyed_limiter_evicts_idle_keys() {
+        let limiter = KeyedRateLimiter::new(1, Duration::from_secs(60), Duration::from_millis(50));
+
+        assert!(limiter.check(&"idle").await);
+        assert!(!limiter.check(&"idle").await);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        // Touching another key sweeps the idle one
+        assert!(limiter.check(&"active").await);
+        assert_eq!(limiter.len().await, 1);
+
+        // An evicted key starts over with a fresh budget
+        assert!(limiter.check(&"idle").await);
+    }
+
+    #[tokio::test]
+    async fn test_keyed_limiter_evict_idle_keeps_recent_keys() {
+        let limiter = KeyedRateLimiter::new(5, Duration::from_secs(1), Duration::from_millis(50));
+
+        limiter.check(&1u64).await;
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        limiter.check(&2u64).await;
+
+        limiter.evict_idle().await;
+        assert_eq!(limiter.len().await, 1);
+        assert_eq!(limiter.available(&2u64).await, 4);
+    }
+
+    #[tokio::test]
+    async fn test_keyed_limiter_acquire() {
+        let limiter =
+            KeyedRateLimiter::new(1, Duration::from_millis(50), Duration::from_secs(60));
+        limiter.check(&"key").await;
+
+        let start = Instant::now();
+        limiter.acquire(&"key").await;
+
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_concurrent_access() {
         use tokio::task::JoinSet;
@@ -586,6 +859,50 @@ mod tests {
         assert_eq!(limiter.count().await, 100);
     }
 
+    #[tokio::test]
+    async fn test_sliding_window_memory_bounded_under_load() {
+        let limiter = SlidingWindowLimiter::new(50, Duration::from_secs(60));
+        let initial_capacity = limiter.state.lock().await.operations.capacity();
+
+        let mut allowed = 0;
+        let mut denied = 0;
+        for _ in 0..10_000 {
+            if limiter.check().await {
+                allowed += 1;
+            } else {
+                denied += 1;
+            }
+        }
+
+        assert_eq!(allowed, 50);
+        assert_eq!(denied, 9_950);
+        assert_eq!(limiter.count().await, 50);
+
+        let state = limiter.state.lock().await;
+        assert_eq!(state.operations.len(), 50);
+        assert_eq!(state.operations.capacity(), initial_capacity);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_sliding_window_memory_bounded_across_windows() {
+        let limiter = SlidingWindowLimiter::new(10, Duration::from_millis(20));
+        let initial_capacity = limiter.state.lock().await.operations.capacity();
+
+        let mut allowed = 0;
+        for _ in 0..3 {
+            for _ in 0..1_000 {
+                if limiter.check().await {
+                    allowed += 1;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(25)).await;
+        }
+
+        // Each window admits exactly its capacity
+        assert_eq!(allowed, 30);
+        assert_eq!(limiter.state.lock().await.operations.capacity(), initial_capacity);
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_multiple_resets() {
         let limiter = RateLimiter::new(5, Duration::from_secs(1));
@@ -3,6 +3,7 @@
 //! This module provides types for tool requests and responses used in
 //! communication between components.
 
+use crate::{Result, ToolingError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,7 +19,9 @@ pub enum MessageFormat {
     Json,
     /// TOON format for token-efficient serialization
     Toon,
-    /// Auto-select based on data structure
+    /// MessagePack binary format for in-process transport
+    MessagePack,
+    /// Auto-select based on data structure (text formats only)
     Auto,
 }
 
@@ -74,28 +77,83 @@ pub mod tool_responses {
     }
 }
 
+/// Thresholds used by [`FormatSelector::select_with_config`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectorConfig {
+    /// Minimum length of a uniform array before TOON is considered
+    pub min_array_len: usize,
+    /// Minimum fraction of bytes TOON must save over JSON (0.0 - 1.0)
+    pub min_savings_ratio: f64,
+    /// How many levels of nested objects/arrays to scan for uniform arrays
+    pub max_scan_depth: usize,
+}
+
+impl Default for SelectorConfig {
+    fn default() -> Self {
+        Self {
+            min_array_len: 4,
+            min_savings_ratio: 0.1,
+            max_scan_depth: 3,
+        }
+    }
+}
+
 /// Format selector for automatic format selection
 pub struct FormatSelector;
 
 impl FormatSelector {
     /// Select optimal format based on data structure
     pub fn select(data: &serde_json::Value) -> MessageFormat {
+        Self::select_with_config(data, &SelectorConfig::default())
+    }
+
+    /// Select optimal format using custom thresholds
+    ///
+    /// Data without a uniform array of at least `min_array_len` items within
+    /// `max_scan_depth` levels is JSON. Otherwise the TOON and JSON encodings
+    /// are measured with [`FormatSelector::encoded_size_ratio`] and TOON is
+    /// chosen only if it saves at least `min_savings_ratio`.
+    pub fn select_with_config(data: &serde_json::Value, config: &SelectorConfig) -> MessageFormat {
+        if !Self::has_uniform_array(data, config, 0) {
+            return MessageFormat::Json;
+        }
+
+        if 1.0 - Self::encoded_size_ratio(data) >= config.min_savings_ratio {
+            MessageFormat::Toon
+        } else {
+            MessageFormat::Json
+        }
+    }
+
+    /// Size of the TOON encoding relative to compact JSON
+    ///
+    /// Values below 1.0 mean TOON is smaller.
+    pub fn encoded_size_ratio(data: &serde_json::Value) -> f64 {
+        let json_len = serde_json::to_string(data).map(|s| s.len()).unwrap_or(0);
+        if json_len == 0 {
+            return 1.0;
+        }
+
+        rtoon::encode(data, None).len() as f64 / json_len as f64
+    }
+
+    /// Check for a qualifying uniform array at or below `depth`
+    fn has_uniform_array(data: &serde_json::Value, config: &SelectorConfig, depth: usize) -> bool {
+        if depth > config.max_scan_depth {
+            return false;
+        }
+
         match data {
-            serde_json::Value::Array(arr) if arr.len() > 3 && Self::is_uniform_array(arr) => {
-                MessageFormat::Toon
+            serde_json::Value::Array(arr) => {
+                (arr.len() >= config.min_array_len && Self::is_uniform_array(arr))
+                    || arr
+                        .iter()
+                        .any(|item| Self::has_uniform_array(item, config, depth + 1))
             }
-            serde_json::Value::Object(obj) => {
-                // Check if object contains large uniform arrays
-                for value in obj.values() {
-                    if let serde_json::Value::Array(arr) = value {
-                        if arr.len() > 3 && Self::is_uniform_array(arr) {
-                            return MessageFormat::Toon;
-                        }
-                    }
-                }
-                MessageFormat::Json
-            }
-            _ => MessageFormat::Json,
+            serde_json::Value::Object(obj) => obj
+                .values()
+                .any(|value| Self::has_uniform_array(value, config, depth + 1)),
+            _ => false,
         }
     }
 
@@ -247,6 +305,31 @@ impl ToolResponse {
         }
     }
 
+    /// Create a partially successful response
+    ///
+    /// For batch tools where some items succeeded and others failed: `result`
+    /// holds the successful items and `errors` one message per failed item.
+    /// `ok` is false so callers don't mistake the result for complete.
+    pub fn partial(
+        tool: impl Into<String>,
+        result: serde_json::Value,
+        errors: Vec<String>,
+    ) -> Self {
+        Self {
+            tool: tool.into(),
+            request_id: None,
+            status: ToolStatus::PartialSuccess,
+            ok: false,
+            result: Some(result.clone()),
+            data: Some(result),
+            error: None,
+            errors,
+            warnings: Vec::new(),
+            duration_ms: None,
+            metadata: HashMap::new(),
+        }
+    }
+
     /// Add metadata
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
@@ -254,7 +337,10 @@ impl ToolResponse {
     }
 
     /// Encode response to specified format
-    pub fn encode(&self, format: MessageFormat) -> String {
+    ///
+    /// Text formats are returned as UTF-8 bytes. `Auto` only ever picks a
+    /// text format; MessagePack must be requested explicitly.
+    pub fn encode(&self, format: MessageFormat) -> Vec<u8> {
         let format_to_use = match format {
             MessageFormat::Auto => {
                 if let Some(ref result) = self.result {
@@ -267,8 +353,10 @@ impl ToolResponse {
         };
 
         match format_to_use {
-            MessageFormat::Toon => self.to_toon(None),
-            MessageFormat::Json | MessageFormat::Auto => self.to_json(),
+            MessageFormat::Toon => self.to_toon(None).into_bytes(),
+            // Empty map on failure, mirroring `to_json`
+            MessageFormat::MessagePack => self.to_msgpack().unwrap_or_else(|_| vec![0x80]),
+            MessageFormat::Json | MessageFormat::Auto => self.to_json().into_bytes(),
         }
     }
 
@@ -283,6 +371,16 @@ impl ToolResponse {
         rtoon::encode(&json_value, options)
     }
 
+    /// Encode response as MessagePack
+    pub fn to_msgpack(&self) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(self).map_err(|e| ToolingError::General(e.to_string()))
+    }
+
+    /// Decode a response from MessagePack
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self> {
+        rmp_serde::from_slice(bytes).map_err(|e| ToolingError::General(e.to_string()))
+    }
+
     /// Encode only the result field as TOON (most common use case)
     pub fn result_to_toon(&self, options: Option<ToonEncodeOptions>) -> Option<String> {
         self.result.as_ref().map(|v| rtoon::encode(v, options))
@@ -314,6 +412,9 @@ pub enum ToolStatus {
     /// Tool executed successfully
     Success,
 
+    /// Some items succeeded and others failed
+    PartialSuccess,
+
     /// Tool execution failed
     Error,
 
@@ -362,6 +463,44 @@ mod tests {
         assert_eq!(response.error, Some("Test error".to_string()));
     }
 
+    #[test]
+    fn test_tool_response_partial() {
+        let response = ToolResponse::partial(
+            "fs_read",
+            json!({"src/main.rs": "fn main() {}"}),
+            vec![
+                "src/missing.rs: file not found".to_string(),
+                "/etc/shadow: permission denied".to_string(),
+            ],
+        );
+
+        assert_eq!(response.status, ToolStatus::PartialSuccess);
+        assert!(!response.ok);
+        assert_eq!(response.result, Some(json!({"src/main.rs": "fn main() {}"})));
+        assert_eq!(response.data, response.result);
+        assert_eq!(response.errors.len(), 2);
+        assert!(response.error.is_none());
+    }
+
+    #[test]
+    fn test_tool_response_partial_serialization() {
+        let response = ToolResponse::partial(
+            "fs_read",
+            json!(["a.rs"]),
+            vec!["b.rs: not found".to_string()],
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&response.to_json()).unwrap();
+        assert_eq!(value["status"], "partial_success");
+        assert_eq!(value["ok"], false);
+        assert_eq!(value["result"], json!(["a.rs"]));
+        assert_eq!(value["errors"], json!(["b.rs: not found"]));
+
+        let decoded: ToolResponse = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.status, ToolStatus::PartialSuccess);
+        assert_eq!(decoded.errors, vec!["b.rs: not found"]);
+    }
+
     #[test]
     fn test_toon_encoding_simple() {
         let response = ToolResponse::success("test_tool", json!({"name": "test", "value": 42}));
@@ -404,6 +543,50 @@ mod tests {
         assert_eq!(FormatSelector::select(&data), MessageFormat::Toon);
     }
 
+    #[test]
+    fn test_format_selector_borderline_small_arrays() {
+        let tiny = json!([{"a": 1}, {"a": 2}, {"a": 3}, {"a": 4}, {"a": 5}]);
+
+        // Long enough for the default threshold
+        assert_eq!(FormatSelector::select(&tiny), MessageFormat::Toon);
+
+        // Too short for a stricter length threshold
+        let strict_len = SelectorConfig {
+            min_array_len: 10,
+            ..SelectorConfig::default()
+        };
+        assert_eq!(FormatSelector::select_with_config(&tiny, &strict_len), MessageFormat::Json);
+
+        // Long enough, but the measured savings don't clear the bar
+        let savings = 1.0 - FormatSelector::encoded_size_ratio(&tiny);
+        let strict_savings = SelectorConfig {
+            min_savings_ratio: savings + 0.05,
+            ..SelectorConfig::default()
+        };
+        assert_eq!(
+            FormatSelector::select_with_config(&tiny, &strict_savings),
+            MessageFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_format_selector_deeply_nested_tabular_data() {
+        let rows: Vec<_> = (0..50)
+            .map(|i| json!({"file": format!("src/f{}.rs", i), "line": i, "content": "fn main()"}))
+            .collect();
+        let data = json!({"result": {"search": {"matches": rows}}});
+
+        // The array sits three levels down
+        assert_eq!(FormatSelector::select(&data), MessageFormat::Toon);
+
+        let shallow = SelectorConfig {
+            max_scan_depth: 2,
+            ..SelectorConfig::default()
+        };
+        assert_eq!(FormatSelector::select_with_config(&data, &shallow), MessageFormat::Json);
+        assert!(FormatSelector::encoded_size_ratio(&data) < 1.0);
+    }
+
     #[test]
     fn test_format_selector_chooses_json_for_small_arrays() {
         let data = json!([{"a": 1}, {"a": 2}]);
@@ -431,12 +614,65 @@ mod tests {
         });
 
         let response = ToolResponse::success("grep", grep_results);
-        let encoded = response.encode(MessageFormat::Auto);
+        let encoded = String::from_utf8(response.encode(MessageFormat::Auto)).unwrap();
 
         // Should select TOON for this uniform array data
         assert!(encoded.contains("matches"));
     }
 
+    fn sample_file_entries() -> serde_json::Value {
+        use tool_responses::FileEntry;
+
+        let entries: Vec<FileEntry> = (0..20)
+            .map(|i| FileEntry {
+                path: format!("src/module_{}.rs", i),
+                size: 1024 * (i + 1),
+                modified: "2025-01-15T10:30:00Z".to_string(),
+                is_dir: i % 5 == 0,
+            })
+            .collect();
+        json!({ "files": entries })
+    }
+
+    #[test]
+    fn test_msgpack_round_trip() {
+        let response = ToolResponse::success("fs_list", sample_file_entries())
+            .with_metadata("session", "abc");
+
+        let bytes = response.to_msgpack().unwrap();
+        let decoded = ToolResponse::from_msgpack(&bytes).unwrap();
+
+        assert_eq!(decoded.tool, "fs_list");
+        assert_eq!(decoded.status, ToolStatus::Success);
+        assert_eq!(decoded.result, response.result);
+        assert_eq!(decoded.metadata.get("session"), Some(&"abc".to_string()));
+        assert_eq!(response.encode(MessageFormat::MessagePack), bytes);
+    }
+
+    #[test]
+    fn test_msgpack_smaller_than_json() {
+        let response = ToolResponse::success("fs_list", sample_file_entries());
+
+        let json_len = response.encode(MessageFormat::Json).len();
+        let msgpack_len = response.encode(MessageFormat::MessagePack).len();
+
+        assert!(
+            msgpack_len < json_len,
+            "MessagePack ({} bytes) should be smaller than JSON ({} bytes)",
+            msgpack_len,
+            json_len
+        );
+    }
+
+    #[test]
+    fn test_auto_never_selects_msgpack() {
+        let response = ToolResponse::success("fs_list", sample_file_entries());
+        let encoded = response.encode(MessageFormat::Auto);
+
+        assert!(String::from_utf8(encoded).is_ok());
+        assert!(ToolResponse::from_msgpack(b"not msgpack").is_err());
+    }
+
     #[test]
     fn test_estimate_savings() {
         let files = json!({
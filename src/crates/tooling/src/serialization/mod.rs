@@ -3,11 +3,11 @@
 //! Provides utilities for consistent hashing, stable JSON serialization,
 //! and JSON manipulation.
 
-use crate::Result;
+use crate::{Result, ToolingError};
 use serde::Serialize;
-use serde_json::Value;
-use std::collections::BTreeMap;
+use serde_json::{Number, Value};
 use std::hash::{Hash, Hasher};
+use std::io::Write;
 
 /// Generate a stable hash for a value
 ///
@@ -62,8 +62,26 @@ pub fn generate_hash<T: Hash>(value: &T) -> u64 {
 /// assert_eq!(generate_json_hash(&val1), generate_json_hash(&val2));
 /// ```
 pub fn generate_json_hash(value: &Value) -> u64 {
+    generate_json_hash_with(value, &CanonicalizeOptions::default())
+}
+
+/// Generate a stable hash from JSON value with canonicalization options
+///
+/// # Example
+///
+/// ```rust
+/// use tooling::serialization::{generate_json_hash_with, CanonicalizeOptions};
+/// use serde_json::json;
+///
+/// let options = CanonicalizeOptions::new().with_normalize_integral_floats(true);
+/// assert_eq!(
+///     generate_json_hash_with(&json!({"n": 1.0}), &options),
+///     generate_json_hash_with(&json!({"n": 1}), &options),
+/// );
+/// ```
+pub fn generate_json_hash_with(value: &Value, options: &CanonicalizeOptions) -> u64 {
     // Use stable_json_string to ensure deterministic ordering
-    if let Ok(stable) = stable_json_string(value) {
+    if let Ok(stable) = stable_json_string_with(value, options) {
         generate_hash(&stable)
     } else {
         // Fallback to direct hashing
@@ -71,6 +89,29 @@ pub fn generate_json_hash(value: &Value) -> u64 {
     }
 }
 
+/// Options controlling how JSON is canonicalized for stable output
+///
+/// The default only sorts object keys; numbers keep `serde_json`'s own
+/// formatting, so `1.0` and `1` stay distinct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CanonicalizeOptions {
+    /// Write floats with no fractional part as integers (`1.0` becomes `1`)
+    pub normalize_integral_floats: bool,
+}
+
+impl CanonicalizeOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether integral floats are written as integers
+    pub fn with_normalize_integral_floats(mut self, normalize: bool) -> Self {
+        self.normalize_integral_floats = normalize;
+        self
+    }
+}
+
 /// Serialize JSON value to a stable string representation
 ///
 /// Ensures deterministic output by sorting object keys alphabetically.
@@ -95,8 +136,88 @@ pub fn generate_json_hash(value: &Value) -> u64 {
 /// assert_eq!(stable, r#"{"a":1,"b":2,"c":3}"#);
 /// ```
 pub fn stable_json_string(value: &Value) -> Result<String> {
-    let normalized = normalize_json(value.clone());
-    serde_json::to_string(&normalized).map_err(|e| e.into())
+    stable_json_string_with(value, &CanonicalizeOptions::default())
+}
+
+/// Serialize JSON value to a stable string with canonicalization options
+///
+/// # Example
+///
+/// ```rust
+/// use tooling::serialization::{stable_json_string_with, CanonicalizeOptions};
+/// use serde_json::json;
+///
+/// let options = CanonicalizeOptions::new().with_normalize_integral_floats(true);
+/// let stable = stable_json_string_with(&json!({"b": 2.0, "a": 1.5}), &options).unwrap();
+/// assert_eq!(stable, r#"{"a":1.5,"b":2}"#);
+/// ```
+pub fn stable_json_string_with(value: &Value, options: &CanonicalizeOptions) -> Result<String> {
+    let mut buffer = Vec::new();
+    write_stable_json_with(value, &mut buffer, options)?;
+    String::from_utf8(buffer).map_err(|e| ToolingError::General(e.to_string()))
+}
+
+/// Write JSON value to a writer in stable form
+///
+/// Streams the same output as [`stable_json_string`] without building a
+/// normalized copy of the value; object keys are sorted one object at a time
+/// as the tree is walked.
+///
+/// # Example
+///
+/// ```rust
+/// use tooling::serialization::write_stable_json;
+/// use serde_json::json;
+///
+/// let mut out = Vec::new();
+/// write_stable_json(&json!({"b": [2, 1], "a": null}), &mut out).unwrap();
+/// assert_eq!(out, br#"{"a":null,"b":[2,1]}"#);
+/// ```
+pub fn write_stable_json<W: Write>(value: &Value, writer: &mut W) -> Result<()> {
+    write_stable_json_with(value, writer, &CanonicalizeOptions::default())
+}
+
+/// Write JSON value to a writer in stable form with canonicalization options
+pub fn write_stable_json_with<W: Write>(
+    value: &Value,
+    writer: &mut W,
+    options: &CanonicalizeOptions,
+) -> Result<()> {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+
+            writer.write_all(b"{")?;
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                serde_json::to_writer(&mut *writer, key)?;
+                writer.write_all(b":")?;
+                write_stable_json_with(item, writer, options)?;
+            }
+            writer.write_all(b"}")?;
+        }
+        Value::Array(items) => {
+            writer.write_all(b"[")?;
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write_stable_json_with(item, writer, options)?;
+            }
+            writer.write_all(b"]")?;
+        }
+        Value::Number(number) if options.normalize_integral_floats => {
+            match integral_float_as_int(number) {
+                Some(int) => serde_json::to_writer(&mut *writer, &int)?,
+                None => serde_json::to_writer(&mut *writer, number)?,
+            }
+        }
+        scalar => serde_json::to_writer(&mut *writer, scalar)?,
+    }
+    Ok(())
 }
 
 /// Serialize value to stable JSON string
@@ -117,8 +238,12 @@ pub fn to_stable_json<T: Serialize>(value: &T) -> Result<String> {
 
 /// Normalize JSON value for stable serialization
 ///
-/// Recursively sorts all object keys alphabetically.
+/// Recursively sorts all object keys alphabetically. This is the reference
+/// behaviour [`write_stable_json`] is checked against.
+#[cfg(test)]
 fn normalize_json(value: Value) -> Value {
+    use std::collections::BTreeMap;
+
     match value {
         Value::Object(map) => {
             let mut sorted = BTreeMap::new();
@@ -132,6 +257,29 @@ fn normalize_json(value: Value) -> Value {
     }
 }
 
+/// Integer form of a float with no fractional part
+///
+/// Returns `None` for integers, fractional floats, and floats outside the
+/// `i64`/`u64` range.
+fn integral_float_as_int(number: &Number) -> Option<Number> {
+    if !number.is_f64() {
+        return None;
+    }
+
+    let float = number.as_f64()?;
+    if !float.is_finite() || float.fract() != 0.0 {
+        return None;
+    }
+
+    if float >= i64::MIN as f64 && float < i64::MAX as f64 {
+        Some(Number::from(float as i64))
+    } else if float >= 0.0 && float < u64::MAX as f64 {
+        Some(Number::from(float as u64))
+    } else {
+        None
+    }
+}
+
 /// Truncate JSON string to a maximum length
 ///
 /// Safely truncates JSON strings for logging or display, adding ellipsis
@@ -266,6 +414,75 @@ mod tests {
         assert!(stable.contains(r#"[3,2,1]"#));
     }
 
+    #[test]
+    fn test_integral_floats_distinct_by_default() {
+        let float = json!({"temperature": 1.0, "tokens": [2.0]});
+        let int = json!({"temperature": 1, "tokens": [2]});
+
+        assert_eq!(
+            stable_json_string(&float).unwrap(),
+            r#"{"temperature":1.0,"tokens":[2.0]}"#
+        );
+        assert_ne!(generate_json_hash(&float), generate_json_hash(&int));
+        assert_eq!(
+            generate_json_hash(&float),
+            generate_json_hash_with(&float, &CanonicalizeOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_integral_floats_normalized_when_enabled() {
+        let options = CanonicalizeOptions::new().with_normalize_integral_floats(true);
+        let float = json!({"nested": {"n": 1.0, "neg": -3.0, "zero": -0.0}, "list": [2.0]});
+        let int = json!({"nested": {"n": 1, "neg": -3, "zero": 0}, "list": [2]});
+
+        assert_eq!(
+            stable_json_string_with(&float, &options).unwrap(),
+            r#"{"list":[2],"nested":{"n":1,"neg":-3,"zero":0}}"#
+        );
+        assert_eq!(
+            generate_json_hash_with(&float, &options),
+            generate_json_hash_with(&int, &options)
+        );
+    }
+
+    #[test]
+    fn test_normalize_integral_floats_keeps_fractions_and_huge_values() {
+        let options = CanonicalizeOptions::new().with_normalize_integral_floats(true);
+        let val = json!({"frac": 1.5, "huge": 1e300, "big_unsigned": 1e19});
+
+        assert_eq!(
+            stable_json_string_with(&val, &options).unwrap(),
+            r#"{"big_unsigned":10000000000000000000,"frac":1.5,"huge":1e300}"#
+        );
+    }
+
+    #[test]
+    fn test_write_stable_json_matches_normalized_serialization() {
+        let values = vec![
+            json!({
+                "zeta": [{"b": 2, "a": [3, {"y": null, "x": true}]}, "text", 1.5],
+                "alpha": {"nested": {"d": 4, "c": "quote \" and \\ backslash"}, "empty": {}},
+                "Upper": [],
+                "num": -12,
+                "unicode": "héllo 🌍"
+            }),
+            json!([3, {"b": 1, "a": [2, 1]}, [], {}]),
+            json!("scalar"),
+            json!(null),
+        ];
+
+        for value in values {
+            let expected = serde_json::to_string(&normalize_json(value.clone())).unwrap();
+
+            let mut streamed = Vec::new();
+            write_stable_json(&value, &mut streamed).unwrap();
+
+            assert_eq!(streamed, expected.as_bytes());
+            assert_eq!(stable_json_string(&value).unwrap(), expected);
+        }
+    }
+
     #[test]
     fn test_to_stable_json() {
         #[derive(serde::Serialize)]
@@ -5,7 +5,7 @@
 //! # Example
 //!
 //! ```rust
-//! use tooling::validation::{Validator, ValidationRule};
+//! use tooling::validation::{StructValidator, Validator, ValidationRule};
 //!
 //! // Validate a number
 //! let age = 25;
@@ -21,7 +21,7 @@
 //!     .not_empty()
 //!     .min_length(3)
 //!     .max_length(100)
-//!     .matches(r"^[^@]+@[^@]+\.[^@]+$")
+//!     .email()
 //!     .validate()
 //!     .unwrap();
 //!
@@ -37,11 +37,27 @@
 //!     })
 //!     .validate()
 //!     .unwrap();
+//!
+//! // Struct validation with cross-field rules
+//! struct Window { start: u32, end: u32 }
+//! let window = Window { start: 1, end: 5 };
+//! StructValidator::new(&window)
+//!     .field(Validator::new(window.start, "start").min(1))
+//!     .cross_field(|w| {
+//!         if w.start < w.end {
+//!             Ok(())
+//!         } else {
+//!             Err("start must be less than end".to_string())
+//!         }
+//!     })
+//!     .validate_all()
+//!     .unwrap();
 //! ```
 
 use crate::{Result, ToolingError};
 use regex::Regex;
 use std::fmt::Display;
+use std::sync::OnceLock;
 
 /// Validation rule for a value
 pub trait ValidationRule<T> {
@@ -193,6 +209,77 @@ where
     }
 }
 
+/// Common string formats with built-in rules
+#[derive(Debug, Clone, Copy)]
+enum StringFormat {
+    Email,
+    Url,
+    Uuid,
+}
+
+impl StringFormat {
+    fn regex(self) -> &'static Regex {
+        static EMAIL: OnceLock<Regex> = OnceLock::new();
+        static URL: OnceLock<Regex> = OnceLock::new();
+        static UUID: OnceLock<Regex> = OnceLock::new();
+
+        match self {
+            StringFormat::Email => EMAIL.get_or_init(|| {
+                Regex::new(
+                    r"^[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+(\.[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+)*@[A-Za-z0-9]([A-Za-z0-9-]*[A-Za-z0-9])?(\.[A-Za-z0-9]([A-Za-z0-9-]*[A-Za-z0-9])?)+$",
+                )
+                .expect("valid email regex")
+            }),
+            StringFormat::Url => URL.get_or_init(|| {
+                Regex::new(r"^[A-Za-z][A-Za-z0-9+.-]*://[^\s/?#]+([/?#]\S*)?$")
+                    .expect("valid URL regex")
+            }),
+            StringFormat::Uuid => UUID.get_or_init(|| {
+                Regex::new(
+                    r"^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}$",
+                )
+                .expect("valid UUID regex")
+            }),
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            StringFormat::Email => "email address",
+            StringFormat::Url => "URL (e.g. https://example.com)",
+            StringFormat::Uuid => "UUID (e.g. 123e4567-e89b-12d3-a456-426614174000)",
+        }
+    }
+
+    fn check(self, value: &str, field_name: &str) -> std::result::Result<(), String> {
+        if self.regex().is_match(value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} must be a valid {} (got '{}')",
+                field_name,
+                self.description(),
+                value
+            ))
+        }
+    }
+}
+
+/// Rule checking a string against a [`StringFormat`]
+struct FormatRule(StringFormat);
+
+impl ValidationRule<&str> for FormatRule {
+    fn validate(&self, value: &&str, field_name: &str) -> std::result::Result<(), String> {
+        self.0.check(value, field_name)
+    }
+}
+
+impl ValidationRule<String> for FormatRule {
+    fn validate(&self, value: &String, field_name: &str) -> std::result::Result<(), String> {
+        self.0.check(value, field_name)
+    }
+}
+
 // String validators
 impl Validator<&str> {
     /// Ensure string is not empty
@@ -291,6 +378,24 @@ impl Validator<&str> {
         }));
         self
     }
+
+    /// Ensure string is a valid email address
+    pub fn email(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Email)));
+        self
+    }
+
+    /// Ensure string is a valid URL with a scheme and host
+    pub fn url(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Url)));
+        self
+    }
+
+    /// Ensure string is a valid hyphenated UUID
+    pub fn uuid(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Uuid)));
+        self
+    }
 }
 
 impl Validator<String> {
@@ -406,6 +511,24 @@ impl Validator<String> {
         }));
         self
     }
+
+    /// Ensure string is a valid email address
+    pub fn email(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Email)));
+        self
+    }
+
+    /// Ensure string is a valid URL with a scheme and host
+    pub fn url(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Url)));
+        self
+    }
+
+    /// Ensure string is a valid hyphenated UUID
+    pub fn uuid(mut self) -> Self {
+        self.rules.push(Box::new(FormatRule(StringFormat::Uuid)));
+        self
+    }
 }
 
 // Collection validators
@@ -491,6 +614,86 @@ impl<T> Validator<Vec<T>> {
     }
 }
 
+/// Validator for a whole struct
+///
+/// Collects the errors of field [`Validator`]s, cross-field rules and nested
+/// struct validators, so every problem is reported at once.
+pub struct StructValidator<'a, S> {
+    value: &'a S,
+    errors: Vec<String>,
+}
+
+impl<'a, S> StructValidator<'a, S> {
+    /// Create a new validator for a struct
+    pub fn new(value: &'a S) -> Self {
+        Self {
+            value,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Run a field validator and collect its errors
+    pub fn field<T: 'static>(mut self, validator: Validator<T>) -> Self {
+        if let Err(errors) = validator.validate_all() {
+            self.errors.extend(errors);
+        }
+        self
+    }
+
+    /// Check a rule spanning several fields of the struct
+    pub fn cross_field<F>(mut self, rule: F) -> Self
+    where
+        F: FnOnce(&S) -> std::result::Result<(), String>,
+    {
+        if let Err(error) = rule(self.value) {
+            self.errors.push(error);
+        }
+        self
+    }
+
+    /// Collect the errors of a nested struct validator
+    ///
+    /// Each error is prefixed with `name.`, so `port must be ...` reported
+    /// for the `database` field becomes `database.port must be ...`.
+    pub fn nested<N>(mut self, name: &str, validator: StructValidator<'_, N>) -> Self {
+        self.errors.extend(
+            validator
+                .errors
+                .into_iter()
+                .map(|error| format!("{}.{}", name, error)),
+        );
+        self
+    }
+
+    /// Errors collected so far
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Validate the struct
+    ///
+    /// # Returns
+    ///
+    /// Ok(value) if all rules pass, Err with all error messages joined
+    pub fn validate(self) -> Result<&'a S> {
+        self.validate_all()
+            .map_err(|errors| ToolingError::General(errors.join("; ")))
+    }
+
+    /// Validate the struct and collect all errors
+    ///
+    /// # Returns
+    ///
+    /// Ok(value) if all rules pass, Err with all error messages
+    pub fn validate_all(self) -> std::result::Result<&'a S, Vec<String>> {
+        if self.errors.is_empty() {
+            Ok(self.value)
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -561,6 +764,81 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_string_email() {
+        for email in ["user@example.com", "first.last+tag@sub.example.co.uk", "a_b@x-y.io"] {
+            assert!(Validator::new(email, "email").email().validate().is_ok(), "{}", email);
+        }
+
+        for email in [
+            "invalid-email",
+            "user@",
+            "@example.com",
+            "user@localhost",
+            "user@@example.com",
+            "user name@example.com",
+            "user.@example.com",
+            "user@-example.com",
+        ] {
+            assert!(Validator::new(email, "email").email().validate().is_err(), "{}", email);
+        }
+    }
+
+    #[test]
+    fn test_string_url() {
+        for url in [
+            "https://example.com",
+            "http://localhost:8080/api/v1?x=1#frag",
+            "ftp://files.example.com/pub",
+        ] {
+            assert!(Validator::new(url, "endpoint").url().validate().is_ok(), "{}", url);
+        }
+
+        for url in [
+            "example.com",
+            "https://",
+            "://example.com",
+            "https://exa mple.com",
+            "1http://x.io",
+        ] {
+            assert!(Validator::new(url, "endpoint").url().validate().is_err(), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_string_uuid() {
+        let owned = String::from("123e4567-e89b-12d3-a456-426614174000");
+        assert!(Validator::new(owned, "id").uuid().validate().is_ok());
+        assert!(Validator::new("123E4567-E89B-12D3-A456-426614174000", "id")
+            .uuid()
+            .validate()
+            .is_ok());
+
+        for id in [
+            "123e4567e89b12d3a456426614174000",
+            "123e4567-e89b-12d3-a456-42661417400",
+            "123e4567-e89b-12d3-a456-42661417400g",
+            "",
+        ] {
+            assert!(Validator::new(id, "id").uuid().validate().is_err(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_format_error_messages() {
+        let err = Validator::new(String::from("nope"), "contact_email")
+            .email()
+            .validate_all()
+            .unwrap_err();
+        assert_eq!(err, vec!["contact_email must be a valid email address (got 'nope')"]);
+
+        let err = Validator::new("nope", "base_url").url().validate().unwrap_err();
+        assert!(err.to_string().contains("base_url must be a valid URL"));
+
+        let err = Validator::new("nope", "task_id").uuid().validate().unwrap_err();
+        assert!(err.to_string().contains("task_id must be a valid UUID"));
+    }
+
     #[test]
     fn test_string_owned() {
         let value = String::from("hello");
@@ -634,6 +912,95 @@ mod tests {
         assert_eq!(errors.len(), 1); // Only min_length fails
     }
 
+    #[derive(Debug)]
+    struct DatabaseSettings {
+        url: String,
+        pool_size: u32,
+    }
+
+    #[derive(Debug)]
+    struct ScheduleSettings {
+        start_hour: u32,
+        end_hour: u32,
+        database: DatabaseSettings,
+    }
+
+    fn validate_database(db: &DatabaseSettings) -> StructValidator<'_, DatabaseSettings> {
+        StructValidator::new(db)
+            .field(Validator::new(db.url.clone(), "url").url())
+            .field(Validator::new(db.pool_size, "pool_size").range(1, 64))
+    }
+
+    fn validate_schedule(settings: &ScheduleSettings) -> StructValidator<'_, ScheduleSettings> {
+        StructValidator::new(settings)
+            .field(Validator::new(settings.start_hour, "start_hour").max(23))
+            .field(Validator::new(settings.end_hour, "end_hour").max(23))
+            .cross_field(|s| {
+                if s.start_hour < s.end_hour {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "start_hour ({}) must be before end_hour ({})",
+                        s.start_hour, s.end_hour
+                    ))
+                }
+            })
+            .nested("database", validate_database(&settings.database))
+    }
+
+    #[test]
+    fn test_struct_validator_reports_all_errors() {
+        let settings = ScheduleSettings {
+            start_hour: 18,
+            end_hour: 9,
+            database: DatabaseSettings {
+                url: "postgres://localhost/orca".to_string(),
+                pool_size: 0,
+            },
+        };
+
+        let errors = validate_schedule(&settings).validate_all().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "start_hour (18) must be before end_hour (9)".to_string(),
+                "database.pool_size must be at least 1 (got 0)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_validator_valid() {
+        let settings = ScheduleSettings {
+            start_hour: 9,
+            end_hour: 17,
+            database: DatabaseSettings {
+                url: "postgres://localhost/orca".to_string(),
+                pool_size: 8,
+            },
+        };
+
+        let validated = validate_schedule(&settings).validate().unwrap();
+        assert_eq!(validated.end_hour, 17);
+    }
+
+    #[test]
+    fn test_struct_validator_nested_prefix_composes() {
+        let db = DatabaseSettings {
+            url: "not a url".to_string(),
+            pool_size: 8,
+        };
+
+        let inner = StructValidator::new(&db).nested("primary", validate_database(&db));
+        let errors = StructValidator::new(&())
+            .nested("storage", inner)
+            .validate_all()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("storage.primary.url must be a valid URL"));
+    }
+
     #[test]
     fn test_chained_validations() {
         let age = 25;
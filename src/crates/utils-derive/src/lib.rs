@@ -0,0 +1,158 @@
+//! Derive macros for the `utils` crate.
+//!
+//! Use these through their re-exports in `utils::config` rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Field, Fields, Lit, LitStr, Type};
+
+/// Derive `utils::config::FromEnv` for a struct with named fields.
+///
+/// Each field is read from `PREFIX_FIELD`, where `FIELD` is the field name
+/// in upper case. `bool` fields accept the same values as `get_env_bool`;
+/// every other field is parsed with `get_env_parse`.
+///
+/// Field attributes:
+/// - `#[env(rename = "NAME")]` reads `PREFIX_NAME` instead.
+/// - `#[env(default = ...)]` is used when the variable is unset. A string
+///   literal is parsed like an environment value; any other expression is
+///   used as the field value directly.
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn derive_from_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_env(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_env(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "FromEnv can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromEnv can only be derived for structs",
+            ))
+        }
+    };
+
+    let initializers = fields
+        .iter()
+        .map(field_initializer)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::utils::config::FromEnv for #name #ty_generics #where_clause {
+            fn from_env(prefix: &str) -> ::utils::Result<Self> {
+                let env_key = |name: &str| -> ::std::string::String {
+                    if prefix.is_empty() {
+                        name.to_string()
+                    } else {
+                        format!("{}_{}", prefix, name)
+                    }
+                };
+
+                Ok(Self {
+                    #(#initializers,)*
+                })
+            }
+        }
+    })
+}
+
+/// Attributes collected from `#[env(...)]` on a field.
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<LitStr>,
+    default: Option<Expr>,
+}
+
+fn parse_field_attrs(field: &Field) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("env")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                attrs.rename = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                attrs.default = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename` or `default`"))
+            }
+        })?;
+    }
+
+    Ok(attrs)
+}
+
+fn field_initializer(field: &Field) -> syn::Result<TokenStream2> {
+    let ident = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| syn::Error::new_spanned(field, "expected a named field"))?;
+    let ty = &field.ty;
+    let attrs = parse_field_attrs(field)?;
+
+    let env_name = match &attrs.rename {
+        Some(rename) => rename.value(),
+        None => ident.to_string().trim_start_matches("r#").to_uppercase(),
+    };
+
+    let read = if is_bool(ty) {
+        quote! { ::utils::config::get_env_bool(&key)? }
+    } else {
+        quote! { ::utils::config::get_env_parse::<#ty>(&key)? }
+    };
+
+    let value = match &attrs.default {
+        None => read,
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(default),
+            ..
+        })) => quote! {
+            if ::std::env::var_os(&key).is_some() {
+                #read
+            } else {
+                #default.parse::<#ty>().map_err(|e| {
+                    ::utils::UtilsError::ConfigError(format!(
+                        "Invalid default for environment variable '{}': {}",
+                        key, e
+                    ))
+                })?
+            }
+        },
+        Some(default) => quote! {
+            if ::std::env::var_os(&key).is_some() {
+                #read
+            } else {
+                #default
+            }
+        },
+    };
+
+    Ok(quote! {
+        #ident: {
+            let key = env_key(#env_name);
+            #value
+        }
+    })
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
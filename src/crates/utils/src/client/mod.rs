@@ -19,10 +19,18 @@
 //! ```
 
 use crate::error::{Result, UtilsError};
-use reqwest::{Client, Method, Response};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, Method, Response, StatusCode};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::time::Duration;
 
+/// Header that marks a request as safe to retry regardless of its method.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
 /// Configuration for HTTP client.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfig {
@@ -42,12 +50,24 @@ pub struct ClientConfig {
     #[serde(default = "default_backoff_multiplier")]
     pub backoff_multiplier: f32,
 
+    /// Longest wait between retries, including waits requested by a
+    /// server's `Retry-After` header.
+    #[serde(default = "default_max_retry_delay")]
+    pub max_retry_delay: Duration,
+
     /// User agent string.
     pub user_agent: Option<String>,
 
     /// Default headers to include in all requests.
     #[serde(skip)]
     pub default_headers: Vec<(String, String)>,
+
+    /// Methods that are retried on failure.
+    ///
+    /// Requests with other methods are only retried when they carry an
+    /// [`IDEMPOTENCY_KEY_HEADER`].
+    #[serde(skip, default = "default_retry_methods")]
+    pub retry_methods: HashSet<Method>,
 }
 
 impl ClientConfig {
@@ -58,8 +78,10 @@ impl ClientConfig {
             max_retries: default_max_retries(),
             retry_delay: default_retry_delay(),
             backoff_multiplier: default_backoff_multiplier(),
+            max_retry_delay: default_max_retry_delay(),
             user_agent: None,
             default_headers: Vec::new(),
+            retry_methods: default_retry_methods(),
         }
     }
 
@@ -87,6 +109,12 @@ impl ClientConfig {
         self
     }
 
+    /// Set the longest wait between retries.
+    pub fn with_max_retry_delay(mut self, delay: Duration) -> Self {
+        self.max_retry_delay = delay;
+        self
+    }
+
     /// Set the user agent.
     pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
         self.user_agent = Some(user_agent.into());
@@ -98,6 +126,17 @@ impl ClientConfig {
         self.default_headers.push((key.into(), value.into()));
         self
     }
+
+    /// Set the methods that are retried on failure.
+    pub fn with_retry_methods(mut self, methods: impl IntoIterator<Item = Method>) -> Self {
+        self.retry_methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Whether a request with this method and headers may be retried.
+    pub fn allows_retry(&self, method: &Method, headers: &HeaderMap) -> bool {
+        self.retry_methods.contains(method) || headers.contains_key(IDEMPOTENCY_KEY_HEADER)
+    }
 }
 
 impl Default for ClientConfig {
@@ -122,6 +161,16 @@ fn default_backoff_multiplier() -> f32 {
     2.0
 }
 
+fn default_max_retry_delay() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_retry_methods() -> HashSet<Method> {
+    [Method::GET, Method::HEAD, Method::PUT, Method::DELETE]
+        .into_iter()
+        .collect()
+}
+
 /// HTTP client with retry and configuration support.
 pub struct HttpClient {
     config: ClientConfig,
@@ -168,6 +217,29 @@ impl HttpClient {
         self.send_with_retry(req).await
     }
 
+    /// Send a GET request and return the response body as a byte stream.
+    ///
+    /// Retries apply only while establishing the response; once the body
+    /// starts streaming, errors are yielded to the caller. Non-success
+    /// statuses are returned as an error instead of a stream.
+    pub async fn get_stream(&self, url: &str) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = self.get(url).await?;
+        body_stream(response)
+    }
+
+    /// Send a POST request with JSON body and return the response body as a
+    /// byte stream.
+    ///
+    /// See [`HttpClient::get_stream`] for retry and status semantics.
+    pub async fn post_json_stream<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = self.post_json(url, body).await?;
+        body_stream(response)
+    }
+
     /// Send a request with the given method.
     pub async fn request(&self, method: Method, url: &str) -> Result<Response> {
         let mut req = self.client.request(method, url);
@@ -181,28 +253,44 @@ impl HttpClient {
     }
 
     /// Send a request builder with retry logic.
+    ///
+    /// Only requests allowed by [`ClientConfig::allows_retry`] are retried.
     async fn send_with_retry(&self, req: reqwest::RequestBuilder) -> Result<Response> {
+        let template = req.build()?;
+        let max_retries = if self.config.allows_retry(template.method(), template.headers()) {
+            self.config.max_retries
+        } else {
+            0
+        };
+
         let mut attempts = 0;
         let mut delay = self.config.retry_delay;
 
         loop {
-            let request = req
+            let request = template
                 .try_clone()
                 .ok_or_else(|| UtilsError::ClientError("Failed to clone request".to_string()))?;
 
-            match request.send().await {
+            let wait = match self.client.execute(request).await {
                 Ok(response) => {
-                    if response.status().is_success() || attempts >= self.config.max_retries {
+                    if response.status().is_success() || attempts >= max_retries {
                         return Ok(response);
                     }
 
-                    // Check if error is retryable (5xx errors)
-                    if !response.status().is_server_error() {
+                    // Check if error is retryable (5xx errors and 429)
+                    if !is_retryable_status(response.status()) {
                         return Ok(response);
                     }
+
+                    retry_delay(
+                        response.headers(),
+                        delay,
+                        self.config.max_retry_delay,
+                        Utc::now(),
+                    )
                 }
                 Err(e) => {
-                    if attempts >= self.config.max_retries {
+                    if attempts >= max_retries {
                         return Err(UtilsError::HttpError(e));
                     }
 
@@ -210,16 +298,63 @@ impl HttpClient {
                     if !e.is_timeout() && !e.is_connect() {
                         return Err(UtilsError::HttpError(e));
                     }
+
+                    delay.min(self.config.max_retry_delay)
                 }
-            }
+            };
 
             attempts += 1;
-            tokio::time::sleep(delay).await;
+            tokio::time::sleep(wait).await;
             delay = Duration::from_secs_f32(delay.as_secs_f32() * self.config.backoff_multiplier);
         }
     }
 }
 
+/// Turn a response into a stream of body chunks.
+fn body_stream(response: Response) -> Result<impl Stream<Item = Result<Bytes>>> {
+    let response = response.error_for_status()?;
+
+    Ok(stream::try_unfold(response, |mut response| async move {
+        Ok(response.chunk().await?.map(|chunk| (chunk, response)))
+    }))
+}
+
+/// Whether a response status should be retried.
+///
+/// Server errors (5xx) and `429 Too Many Requests` are retryable.
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Parse a `Retry-After` header into a delay relative to `now`.
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds, or an
+/// HTTP-date. Dates in the past yield a zero delay. Returns `None` when the
+/// header is absent or malformed.
+pub fn parse_retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((date - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Delay before the next retry: the server's `Retry-After` when present,
+/// otherwise the computed backoff, capped at `max_delay`.
+fn retry_delay(
+    headers: &HeaderMap,
+    backoff: Duration,
+    max_delay: Duration,
+    now: DateTime<Utc>,
+) -> Duration {
+    parse_retry_after(headers, now)
+        .unwrap_or(backoff)
+        .min(max_delay)
+}
+
 /// Helper for building authenticated requests.
 pub struct AuthHelper;
 
@@ -310,6 +445,32 @@ mod tests {
         assert!(config.default_headers.contains(&("X-Request-ID".to_string(), "req-123".to_string())));
     }
 
+    #[test]
+    fn test_client_config_default_retry_methods() {
+        let config = ClientConfig::default();
+
+        for method in [Method::GET, Method::HEAD, Method::PUT, Method::DELETE] {
+            assert!(config.retry_methods.contains(&method));
+        }
+        assert!(!config.retry_methods.contains(&Method::POST));
+        assert!(!config.retry_methods.contains(&Method::PATCH));
+    }
+
+    #[test]
+    fn test_allows_retry_by_method_or_idempotency_key() {
+        let config = ClientConfig::new();
+        let mut keyed = HeaderMap::new();
+        keyed.insert(IDEMPOTENCY_KEY_HEADER, "req-123".parse().unwrap());
+
+        assert!(config.allows_retry(&Method::GET, &HeaderMap::new()));
+        assert!(!config.allows_retry(&Method::POST, &HeaderMap::new()));
+        assert!(config.allows_retry(&Method::POST, &keyed));
+
+        let config = config.with_retry_methods([Method::POST]);
+        assert!(config.allows_retry(&Method::POST, &HeaderMap::new()));
+        assert!(!config.allows_retry(&Method::GET, &HeaderMap::new()));
+    }
+
     #[test]
     fn test_client_config_zero_retries() {
         let config = ClientConfig::new().with_max_retries(0);
@@ -396,6 +557,81 @@ mod tests {
         assert_eq!(next_delay, Duration::from_secs(0));
     }
 
+    // ------------------------------------------------------------------------
+    // Retry-After Tests
+    // ------------------------------------------------------------------------
+
+    /// Default cap on retry waits
+    const MAX: Duration = Duration::from_secs(60);
+
+    fn retry_after_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_retryable_status_includes_429() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn test_retry_after_seconds_overrides_backoff() {
+        let headers = retry_after_headers("7");
+        let delay = retry_delay(&headers, Duration::from_millis(100), MAX, Utc::now());
+        assert_eq!(delay, Duration::from_secs(7));
+    }
+
+    #[test]
+    fn test_retry_after_is_capped_at_max_delay() {
+        let headers = retry_after_headers("86400");
+        let max = ClientConfig::new()
+            .with_max_retry_delay(Duration::from_secs(5))
+            .max_retry_delay;
+        assert_eq!(
+            retry_delay(&headers, Duration::from_millis(100), max, Utc::now()),
+            Duration::from_secs(5)
+        );
+
+        let far_future = retry_after_headers("Fri, 01 Jan 2100 00:00:00 GMT");
+        assert_eq!(
+            retry_delay(&far_future, Duration::from_millis(100), max, Utc::now()),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_http_date_overrides_backoff() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        let headers = retry_after_headers("Wed, 21 Oct 2015 07:28:30 GMT");
+
+        let delay = retry_delay(&headers, Duration::from_millis(100), MAX, now);
+        assert_eq!(delay, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_retry_after_date_in_past_is_zero() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        let headers = retry_after_headers("Wed, 21 Oct 2015 07:27:00 GMT");
+
+        assert_eq!(parse_retry_after(&headers, now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_retry_after_missing_or_malformed_uses_backoff() {
+        let backoff = Duration::from_millis(250);
+        assert_eq!(retry_delay(&HeaderMap::new(), backoff, MAX, Utc::now()), backoff);
+        assert_eq!(retry_delay(&retry_after_headers("soon"), backoff, MAX, Utc::now()), backoff);
+    }
+
     // ------------------------------------------------------------------------
     // HTTP Client Creation Tests
     // ------------------------------------------------------------------------
@@ -687,6 +923,159 @@ mod tests {
         // let result = client.request(Method::PATCH, "http://localhost:9999/resource").await;
     }
 
+    // ------------------------------------------------------------------------
+    // Streaming Tests
+    // ------------------------------------------------------------------------
+
+    /// Serve a single raw HTTP response on a local port and return its base URL.
+    async fn serve_once(response: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            socket.write_all(response.as_bytes()).await.unwrap();
+            let _ = socket.shutdown().await;
+        });
+
+        format!("http://{}", addr)
+    }
+
+    const CHUNKED_NDJSON: &str = "HTTP/1.1 200 OK\r\n\
+        Content-Type: application/x-ndjson\r\n\
+        Transfer-Encoding: chunked\r\n\
+        Connection: close\r\n\r\n\
+        8\r\n{\"n\":1}\n\r\n\
+        8\r\n{\"n\":2}\n\r\n\
+        0\r\n\r\n";
+
+    #[tokio::test]
+    async fn test_get_stream_reads_chunked_body() {
+        use futures::TryStreamExt;
+
+        let url = serve_once(CHUNKED_NDJSON).await;
+        let client = HttpClient::new(ClientConfig::new().with_max_retries(0)).unwrap();
+
+        let stream = client.get_stream(&url).await.unwrap();
+        let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+        let body: Vec<u8> = chunks.concat();
+
+        assert_eq!(body, b"{\"n\":1}\n{\"n\":2}\n");
+    }
+
+    #[tokio::test]
+    async fn test_post_json_stream_reads_chunked_body() {
+        use futures::TryStreamExt;
+
+        let url = serve_once(CHUNKED_NDJSON).await;
+        let client = HttpClient::new(ClientConfig::new().with_max_retries(0)).unwrap();
+
+        let stream = client
+            .post_json_stream(&url, &serde_json::json!({"stream": true}))
+            .await
+            .unwrap();
+        let body: Vec<u8> = stream.try_collect::<Vec<Bytes>>().await.unwrap().concat();
+
+        assert_eq!(body, b"{\"n\":1}\n{\"n\":2}\n");
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_error_status() {
+        let url =
+            serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .await;
+        let client = HttpClient::new(ClientConfig::new().with_max_retries(0)).unwrap();
+
+        assert!(client.get_stream(&url).await.is_err());
+    }
+
+    // ------------------------------------------------------------------------
+    // Retry Method Gating Tests
+    // ------------------------------------------------------------------------
+
+    /// Serve `503` to every connection and count the requests received.
+    async fn serve_unavailable() -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket
+                    .write_all(
+                        b"HTTP/1.1 503 Service Unavailable\r\n\
+                          Content-Length: 0\r\nConnection: close\r\n\r\n",
+                    )
+                    .await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (format!("http://{}", addr), hits)
+    }
+
+    fn fast_retry_client() -> HttpClient {
+        let config = ClientConfig::new()
+            .with_max_retries(2)
+            .with_retry_delay(Duration::ZERO);
+        HttpClient::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_is_retried() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_unavailable().await;
+        let response = fast_retry_client().get(&url).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_without_idempotency_key_is_not_retried() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_unavailable().await;
+        let response = fast_retry_client()
+            .post_json(&url, &serde_json::json!({"op": "create"}))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_post_with_idempotency_key_is_retried() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = serve_unavailable().await;
+        let config = ClientConfig::new()
+            .with_max_retries(2)
+            .with_retry_delay(Duration::ZERO)
+            .with_header(IDEMPOTENCY_KEY_HEADER, "req-123");
+        let client = HttpClient::new(config).unwrap();
+
+        client
+            .post_json(&url, &serde_json::json!({"op": "create"}))
+            .await
+            .unwrap();
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
     // ------------------------------------------------------------------------
     // Authentication Helper Tests
     // ------------------------------------------------------------------------
@@ -3,8 +3,8 @@
 //! This module provides utilities for loading and managing configuration including:
 //! - Environment variable loading with type parsing
 //! - Configuration validation
-//! - Configuration merging
-//! - YAML/JSON config file loading
+//! - Configuration merging (deep JSON merge and the `Mergeable` trait)
+//! - YAML/JSON/TOML config file loading
 //!
 //! # Example
 //!
@@ -28,6 +28,9 @@
 
 use crate::error::{Result, UtilsError};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::path::Path;
 
 /// Get an environment variable as a string.
@@ -106,9 +109,62 @@ pub fn load_json_config<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T
     })
 }
 
+/// Load configuration from a TOML file.
+pub fn load_toml_config<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
+    let content = std::fs::read_to_string(path.as_ref())?;
+    toml::from_str(&content).map_err(|e| {
+        UtilsError::ConfigError(format!(
+            "Failed to parse TOML config from {:?}: {}",
+            path.as_ref(),
+            e
+        ))
+    })
+}
+
+/// Config file extensions understood by [`load_config_file`].
+pub const SUPPORTED_CONFIG_EXTENSIONS: &[&str] = &["yaml", "yml", "json", "toml", "tml"];
+
 /// Load configuration from a file (auto-detect format from extension).
 pub fn load_config_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
     let path = path.as_ref();
+
+    match config_extension(path)?.as_str() {
+        "yaml" | "yml" => load_yaml_config(path),
+        "json" => load_json_config(path),
+        _ => load_toml_config(path),
+    }
+}
+
+/// Load configuration from a file, expanding environment placeholders first.
+///
+/// `${VAR}` is replaced with the value of `VAR` and fails if it is unset;
+/// `${VAR:-default}` falls back to `default` when `VAR` is unset or empty.
+/// `$$` yields a literal `$`. The format is detected from the extension as
+/// in [`load_config_file`].
+pub fn load_config_file_interpolated<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
+    let path = path.as_ref();
+    let extension = config_extension(path)?;
+    let content = interpolate_env(&std::fs::read_to_string(path)?)?;
+
+    let parsed = match extension.as_str() {
+        "yaml" | "yml" => serde_yaml::from_str(&content).map_err(|e| e.to_string()),
+        "json" => serde_json::from_str(&content).map_err(|e| e.to_string()),
+        _ => toml::from_str(&content).map_err(|e| e.to_string()),
+    };
+
+    parsed.map_err(|e| {
+        UtilsError::ConfigError(format!(
+            "Failed to parse {} config from {:?}: {}",
+            extension.to_uppercase(),
+            path,
+            e
+        ))
+    })
+}
+
+/// Lowercased extension of a config file, checked against
+/// [`SUPPORTED_CONFIG_EXTENSIONS`].
+fn config_extension(path: &Path) -> Result<String> {
     let extension = path
         .extension()
         .and_then(|e| e.to_str())
@@ -116,16 +172,90 @@ pub fn load_config_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T
             UtilsError::ConfigError(format!("Unable to determine file extension for {:?}", path))
         })?;
 
-    match extension.to_lowercase().as_str() {
-        "yaml" | "yml" => load_yaml_config(path),
-        "json" => load_json_config(path),
-        _ => Err(UtilsError::ConfigError(format!(
-            "Unsupported config file extension: {}",
-            extension
-        ))),
+    let normalized = extension.to_lowercase();
+    if !SUPPORTED_CONFIG_EXTENSIONS.contains(&normalized.as_str()) {
+        return Err(UtilsError::ConfigError(format!(
+            "Unsupported config file extension: {} (supported: {})",
+            extension,
+            SUPPORTED_CONFIG_EXTENSIONS.join(", ")
+        )));
+    }
+
+    Ok(normalized)
+}
+
+/// Expand `${VAR}`, `${VAR:-default}` and `$$` in `content` from the environment.
+pub fn interpolate_env(content: &str) -> Result<String> {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(pos) = rest.find('$') {
+        output.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+
+        if let Some(escaped) = after.strip_prefix('$') {
+            output.push('$');
+            rest = escaped;
+        } else if let Some(placeholder) = after.strip_prefix('{') {
+            let end = placeholder.find('}').ok_or_else(|| {
+                UtilsError::ConfigError(format!(
+                    "Unterminated placeholder in config: ${{{}",
+                    placeholder.lines().next().unwrap_or_default()
+                ))
+            })?;
+            let (name, default) = match placeholder[..end].split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (&placeholder[..end], None),
+            };
+
+            if name.is_empty() {
+                return Err(UtilsError::ConfigError(
+                    "Empty placeholder name in config".to_string(),
+                ));
+            }
+
+            match (std::env::var(name), default) {
+                (Ok(value), Some(default)) if value.is_empty() => output.push_str(default),
+                (Ok(value), _) => output.push_str(&value),
+                (Err(_), Some(default)) => output.push_str(default),
+                (Err(_), None) => {
+                    return Err(UtilsError::ConfigError(format!(
+                        "Environment variable '{}' referenced in config is not set",
+                        name
+                    )))
+                }
+            }
+            rest = &placeholder[end + 1..];
+        } else {
+            output.push('$');
+            rest = after;
+        }
     }
+
+    output.push_str(rest);
+    Ok(output)
 }
 
+/// Derive [`FromEnv`] for a struct, reading `PREFIX_FIELD` for each field.
+///
+/// ```rust,ignore
+/// use utils::config::FromEnv;
+///
+/// #[derive(FromEnv)]
+/// struct ServerEnv {
+///     host: String,
+///     port: u16,
+///     #[env(default = false)]
+///     debug: bool,
+///     #[env(rename = "DB_URL")]
+///     database_url: String,
+/// }
+///
+/// // Reads APP_HOST, APP_PORT, APP_DEBUG and APP_DB_URL
+/// let env = ServerEnv::from_env("APP")?;
+/// ```
+pub use utils_derive::FromEnv;
+
 /// Trait for types that can be loaded from environment variables.
 pub trait FromEnv: Sized {
     /// Load configuration from environment variables with the given prefix.
@@ -163,14 +293,109 @@ impl<T: Default> Default for ConfigBuilder<T> {
     }
 }
 
-/// Helper to merge two configuration objects.
+/// How arrays are combined when merging JSON configs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArrayMergeMode {
+    /// Overlay arrays replace base arrays.
+    #[default]
+    Replace,
+    /// Overlay array items are appended to base arrays.
+    Append,
+}
+
+/// Deep-merge `overlay` into `base`.
 ///
-/// This is a placeholder for more sophisticated merging logic.
-/// In practice, you might want to use a library like `merge` or implement
-/// custom merge logic for your specific config types.
-pub fn merge_configs<T: Clone>(base: T, _overlay: T) -> T {
-    // Simplified merge - in a real implementation, you'd merge fields
-    base
+/// Object keys from the overlay override the base recursively, and an
+/// overlay `null` removes the key from the base. Arrays are combined
+/// according to `arrays`. Any other overlay value replaces the base value.
+pub fn merge_json(base: Value, overlay: Value, arrays: ArrayMergeMode) -> Value {
+    match (base, overlay) {
+        (Value::Object(mut base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                if value.is_null() {
+                    base.remove(&key);
+                    continue;
+                }
+                let merged = match base.remove(&key) {
+                    Some(existing) => merge_json(existing, value, arrays),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            Value::Object(base)
+        }
+        (Value::Array(mut base), Value::Array(overlay)) if arrays == ArrayMergeMode::Append => {
+            base.extend(overlay);
+            Value::Array(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+/// Types whose values can be layered, with the overlay taking precedence.
+///
+/// Implement this for config structs by merging each field, so that e.g.
+/// user config can override project config field by field.
+pub trait Mergeable {
+    /// Merge `overlay` on top of `self`.
+    fn merge(self, overlay: Self) -> Self;
+}
+
+macro_rules! impl_mergeable_replace {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Mergeable for $ty {
+                fn merge(self, overlay: Self) -> Self {
+                    overlay
+                }
+            }
+        )*
+    };
+}
+
+impl_mergeable_replace!(
+    bool, char, String, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+    std::path::PathBuf, std::time::Duration,
+);
+
+impl<T> Mergeable for Vec<T> {
+    fn merge(self, overlay: Self) -> Self {
+        overlay
+    }
+}
+
+impl<T: Mergeable> Mergeable for Option<T> {
+    fn merge(self, overlay: Self) -> Self {
+        match (self, overlay) {
+            (Some(base), Some(overlay)) => Some(base.merge(overlay)),
+            (base, None) => base,
+            (None, overlay) => overlay,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Mergeable> Mergeable for HashMap<K, V> {
+    fn merge(mut self, overlay: Self) -> Self {
+        for (key, value) in overlay {
+            let merged = match self.remove(&key) {
+                Some(existing) => existing.merge(value),
+                None => value,
+            };
+            self.insert(key, merged);
+        }
+        self
+    }
+}
+
+impl Mergeable for Value {
+    fn merge(self, overlay: Self) -> Self {
+        merge_json(self, overlay, ArrayMergeMode::Replace)
+    }
+}
+
+/// Merge two configuration objects, with `overlay` taking precedence.
+pub fn merge_configs<T: Mergeable>(base: T, overlay: T) -> T {
+    base.merge(overlay)
 }
 
 #[cfg(test)]
@@ -319,6 +544,65 @@ mod tests {
         assert!(!value);
     }
 
+    // ------------------------------------------------------------------------
+    // FromEnv Derive Tests
+    // ------------------------------------------------------------------------
+
+    #[derive(Debug, FromEnv)]
+    struct DerivedEnvConfig {
+        name: String,
+        port: u16,
+        #[env(default = false)]
+        verbose: bool,
+        #[env(rename = "HOSTNAME", default = "localhost")]
+        host: String,
+    }
+
+    #[test]
+    fn test_derive_from_env_reads_prefixed_fields() {
+        env::set_var("TEST_DERIVE_A_NAME", "orca");
+        env::set_var("TEST_DERIVE_A_PORT", "8081");
+        env::set_var("TEST_DERIVE_A_VERBOSE", "yes");
+        env::set_var("TEST_DERIVE_A_HOSTNAME", "example.com");
+
+        let config = DerivedEnvConfig::from_env("TEST_DERIVE_A").unwrap();
+        assert_eq!(config.name, "orca");
+        assert_eq!(config.port, 8081);
+        assert!(config.verbose);
+        assert_eq!(config.host, "example.com");
+
+        for field in ["NAME", "PORT", "VERBOSE", "HOSTNAME"] {
+            env::remove_var(format!("TEST_DERIVE_A_{}", field));
+        }
+    }
+
+    #[test]
+    fn test_derive_from_env_uses_defaults() {
+        env::set_var("TEST_DERIVE_B_NAME", "orca");
+        env::set_var("TEST_DERIVE_B_PORT", "9000");
+
+        let config = DerivedEnvConfig::from_env("TEST_DERIVE_B").unwrap();
+        assert!(!config.verbose);
+        assert_eq!(config.host, "localhost");
+
+        env::remove_var("TEST_DERIVE_B_NAME");
+        env::remove_var("TEST_DERIVE_B_PORT");
+    }
+
+    #[test]
+    fn test_derive_from_env_missing_or_invalid() {
+        env::set_var("TEST_DERIVE_C_NAME", "orca");
+        let missing = DerivedEnvConfig::from_env("TEST_DERIVE_C").unwrap_err();
+        assert!(missing.to_string().contains("TEST_DERIVE_C_PORT"));
+
+        env::set_var("TEST_DERIVE_C_PORT", "not-a-port");
+        let invalid = DerivedEnvConfig::from_env("TEST_DERIVE_C").unwrap_err();
+        assert!(invalid.to_string().contains("TEST_DERIVE_C_PORT"));
+
+        env::remove_var("TEST_DERIVE_C_NAME");
+        env::remove_var("TEST_DERIVE_C_PORT");
+    }
+
     // ------------------------------------------------------------------------
     // YAML Config Loading Tests
     // ------------------------------------------------------------------------
@@ -544,15 +828,68 @@ enabled: true
     }
 
     #[test]
-    fn test_load_config_file_unsupported_extension() {
+    fn test_load_config_file_toml_extension() {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("config.toml");
 
-        fs::write(&config_path, "name = 'test'").unwrap();
+        let toml_content = r#"
+name = "toml_test"
+port = 9004
+enabled = true
+"#;
+
+        fs::write(&config_path, toml_content).unwrap();
+
+        let config: TestConfig = load_config_file(&config_path).unwrap();
+        assert_eq!(
+            config,
+            TestConfig {
+                name: "toml_test".to_string(),
+                port: 9004,
+                enabled: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_config_file_tml_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.tml");
+
+        fs::write(&config_path, "name = 'tml_test'\nport = 9005\nenabled = false\n").unwrap();
+
+        let config: TestConfig = load_config_file(&config_path).unwrap();
+        assert_eq!(config.name, "tml_test");
+        assert!(!config.enabled);
+    }
+
+    #[test]
+    fn test_load_toml_config_malformed() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("bad.toml");
+
+        fs::write(&config_path, "name = \"unterminated\nport = 1").unwrap();
+
+        let result: Result<TestConfig> = load_toml_config(&config_path);
+        assert!(result.unwrap_err().to_string().contains("TOML"));
+    }
+
+    #[test]
+    fn test_load_config_file_unsupported_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.ini");
+
+        fs::write(&config_path, "name = test").unwrap();
 
         let result: Result<TestConfig> = load_config_file(&config_path);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Unsupported"));
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Unsupported"));
+        assert!(message.contains("ini"));
+        for extension in SUPPORTED_CONFIG_EXTENSIONS {
+            assert!(message.contains(extension));
+        }
     }
 
     #[test]
@@ -584,6 +921,67 @@ enabled: true
         assert_eq!(config.name, "uppercase_ext");
     }
 
+    // ------------------------------------------------------------------------
+    // Environment Interpolation Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_load_config_file_interpolated_present_var() {
+        env::set_var("TEST_INTERP_APP_NAME", "from_env");
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+
+        fs::write(&config_path, "name: ${TEST_INTERP_APP_NAME}\nport: 8080\nenabled: true\n")
+            .unwrap();
+
+        let config: TestConfig = load_config_file_interpolated(&config_path).unwrap();
+        assert_eq!(config.name, "from_env");
+        env::remove_var("TEST_INTERP_APP_NAME");
+    }
+
+    #[test]
+    fn test_load_config_file_interpolated_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+
+        let json_content = r#"{
+  "name": "${TEST_INTERP_MISSING_NAME:-fallback}",
+  "port": ${TEST_INTERP_MISSING_PORT:-9100},
+  "enabled": true
+}"#;
+
+        fs::write(&config_path, json_content).unwrap();
+
+        let config: TestConfig = load_config_file_interpolated(&config_path).unwrap();
+        assert_eq!(config.name, "fallback");
+        assert_eq!(config.port, 9100);
+    }
+
+    #[test]
+    fn test_load_config_file_interpolated_undefined_var() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+
+        fs::write(&config_path, "name: ${TEST_INTERP_UNDEFINED_XYZ}\nport: 1\nenabled: true\n")
+            .unwrap();
+
+        let result: Result<TestConfig> = load_config_file_interpolated(&config_path);
+        assert!(result.unwrap_err().to_string().contains("TEST_INTERP_UNDEFINED_XYZ"));
+    }
+
+    #[test]
+    fn test_interpolate_env_escaped_dollar() {
+        env::set_var("TEST_INTERP_PRICE", "5");
+        let expanded = interpolate_env("cost: $$${TEST_INTERP_PRICE} and $$HOME, $ alone").unwrap();
+        assert_eq!(expanded, "cost: $5 and $HOME, $ alone");
+        env::remove_var("TEST_INTERP_PRICE");
+    }
+
+    #[test]
+    fn test_interpolate_env_unterminated_placeholder() {
+        assert!(interpolate_env("name: ${UNCLOSED").is_err());
+    }
+
     // ------------------------------------------------------------------------
     // Invalid File Path Tests
     // ------------------------------------------------------------------------
@@ -709,21 +1107,101 @@ message: "Hello 世界 🌍 مرحبا"
 
     #[test]
     fn test_merge_configs_basic() {
-        #[derive(Clone)]
         struct MergeConfig {
             field: String,
+            port: Option<u16>,
+        }
+
+        impl Mergeable for MergeConfig {
+            fn merge(self, overlay: Self) -> Self {
+                Self {
+                    field: self.field.merge(overlay.field),
+                    port: self.port.merge(overlay.port),
+                }
+            }
         }
 
         let base = MergeConfig {
             field: "base".to_string(),
+            port: Some(8080),
         };
         let overlay = MergeConfig {
             field: "overlay".to_string(),
+            port: None,
         };
 
         let merged = merge_configs(base, overlay);
-        // Current implementation returns base
-        assert_eq!(merged.field, "base");
+        assert_eq!(merged.field, "overlay");
+        assert_eq!(merged.port, Some(8080));
+    }
+
+    // ------------------------------------------------------------------------
+    // JSON Merge Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_merge_json_nested_override() {
+        let base = serde_json::json!({
+            "llm": {"provider": "openai", "model": "gpt-4", "temperature": 0.2},
+            "name": "project"
+        });
+        let overlay = serde_json::json!({
+            "llm": {"model": "gpt-4o"},
+            "verbose": true
+        });
+
+        let merged = merge_json(base, overlay, ArrayMergeMode::Replace);
+        assert_eq!(
+            merged,
+            serde_json::json!({
+                "llm": {"provider": "openai", "model": "gpt-4o", "temperature": 0.2},
+                "name": "project",
+                "verbose": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_json_array_replace_vs_append() {
+        let base = serde_json::json!({"tools": ["fs_read", "grep"]});
+        let overlay = serde_json::json!({"tools": ["shell_exec"]});
+
+        let replaced = merge_json(base.clone(), overlay.clone(), ArrayMergeMode::Replace);
+        assert_eq!(replaced, serde_json::json!({"tools": ["shell_exec"]}));
+
+        let appended = merge_json(base, overlay, ArrayMergeMode::Append);
+        assert_eq!(appended, serde_json::json!({"tools": ["fs_read", "grep", "shell_exec"]}));
+    }
+
+    #[test]
+    fn test_merge_json_null_deletes_key() {
+        let base = serde_json::json!({"llm": {"api_key": "secret", "model": "gpt-4"}});
+        let overlay = serde_json::json!({"llm": {"api_key": null}, "missing": null});
+
+        let merged = merge_json(base, overlay, ArrayMergeMode::Replace);
+        assert_eq!(merged, serde_json::json!({"llm": {"model": "gpt-4"}}));
+    }
+
+    #[test]
+    fn test_merge_json_scalar_overrides_object() {
+        let base = serde_json::json!({"timeout": {"secs": 30}});
+        let overlay = serde_json::json!({"timeout": 10});
+
+        let merged = merge_json(base, overlay, ArrayMergeMode::Replace);
+        assert_eq!(merged, serde_json::json!({"timeout": 10}));
+    }
+
+    #[test]
+    fn test_mergeable_hash_map() {
+        let base: HashMap<String, Option<u32>> =
+            [("a".to_string(), Some(1)), ("b".to_string(), Some(2))].into();
+        let overlay: HashMap<String, Option<u32>> =
+            [("b".to_string(), None), ("c".to_string(), Some(3))].into();
+
+        let merged = base.merge(overlay);
+        assert_eq!(merged["a"], Some(1));
+        assert_eq!(merged["b"], Some(2));
+        assert_eq!(merged["c"], Some(3));
     }
 }
 
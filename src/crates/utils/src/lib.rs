@@ -66,6 +66,9 @@
 //! - `client` - Client utilities (enabled by default)
 //! - `config` - Configuration utilities (enabled by default)
 
+// Lets the `utils-derive` macros refer to `::utils` from within this crate.
+extern crate self as utils;
+
 pub mod error;
 
 #[cfg(feature = "server")]
@@ -89,6 +92,8 @@ pub use client::{AuthHelper, ClientConfig, HttpClient};
 #[cfg(feature = "config")]
 pub use config::{
     get_env, get_env_bool, get_env_bool_or, get_env_or, get_env_parse, get_env_parse_or,
-    load_config_file, load_json_config, load_yaml_config, ConfigBuilder, FromEnv, ValidateConfig,
+    interpolate_env, load_config_file, load_config_file_interpolated, load_json_config,
+    load_toml_config, load_yaml_config, merge_configs, merge_json, ArrayMergeMode, ConfigBuilder,
+    FromEnv, Mergeable, ValidateConfig,
 };
 